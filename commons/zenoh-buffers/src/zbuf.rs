@@ -184,6 +184,17 @@ where
     }
 }
 
+/// Converts a [`ZBuf`] into [`bytes::Bytes`].
+///
+/// A buffer made of a single slice is converted without copying, see the conversion from
+/// [`ZSlice`]. The slices of any other buffer are copied into a new contiguous allocation.
+#[cfg(feature = "bytes")]
+impl From<ZBuf> for bytes::Bytes {
+    fn from(zbuf: ZBuf) -> Self {
+        zbuf.to_zslice().into()
+    }
+}
+
 // Reader
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ZBufPos {
@@ -656,6 +667,42 @@ mod tests {
         assert_eq!(zbuf1, zbuf2);
     }
 
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn zbuf_bytes() {
+        use super::{ZBuf, ZSlice};
+
+        // A `bytes::Bytes` buffer is shared in both directions.
+        let bytes = bytes::Bytes::from(vec![0u8, 1, 2, 3, 4, 5, 6, 7]);
+        let ptr = bytes.as_ptr();
+        let zbuf = ZBuf::from(bytes.clone());
+        assert_eq!(zbuf.zslices().next().unwrap().as_slice().as_ptr(), ptr);
+        assert_eq!(bytes::Bytes::from(zbuf).as_ptr(), ptr);
+
+        // A subslice of a `bytes::Bytes` buffer is converted into a subslice of that buffer.
+        let zslice = ZSlice::from(bytes.clone()).subslice(2..6).unwrap();
+        let subslice = bytes::Bytes::from(ZBuf::from(zslice));
+        assert_eq!(subslice, bytes.slice(2..6));
+        assert_eq!(subslice.as_ptr(), ptr.wrapping_add(2));
+
+        // Any other single slice becomes the owner of the returned `bytes::Bytes`.
+        let zbuf = ZBuf::from(vec![8u8, 9, 10]);
+        let ptr = zbuf.zslices().next().unwrap().as_slice().as_ptr();
+        assert_eq!(bytes::Bytes::from(zbuf).as_ptr(), ptr);
+
+        // Several slices are copied into a contiguous buffer.
+        let mut zbuf = ZBuf::empty();
+        zbuf.push_zslice(ZSlice::from(bytes.slice(..4)));
+        zbuf.push_zslice(vec![8u8, 9].into());
+        zbuf.push_zslice(ZSlice::from(bytes.slice(4..)));
+        assert_eq!(
+            bytes::Bytes::from(zbuf),
+            [0u8, 1, 2, 3, 8, 9, 4, 5, 6, 7][..]
+        );
+
+        assert!(bytes::Bytes::from(ZBuf::empty()).is_empty());
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn zbuf_seek() {
@@ -77,6 +77,21 @@ impl<const N: usize> ZSliceBuffer for [u8; N] {
     }
 }
 
+#[cfg(feature = "bytes")]
+impl ZSliceBuffer for bytes::Bytes {
+    fn as_slice(&self) -> &[u8] {
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
 /*************************************/
 /*               ZSLICE              */
 /*************************************/
@@ -268,6 +283,20 @@ where
     }
 }
 
+/// Converts a [`ZSlice`] into [`bytes::Bytes`] without copying.
+///
+/// A slice of a [`bytes::Bytes`] buffer is converted into a subslice of that buffer. Any other
+/// slice becomes the owner of the returned [`bytes::Bytes`].
+#[cfg(feature = "bytes")]
+impl From<ZSlice> for bytes::Bytes {
+    fn from(zslice: ZSlice) -> Self {
+        match zslice.downcast_ref::<bytes::Bytes>() {
+            Some(bytes) => bytes.slice(zslice.start..zslice.end),
+            None => bytes::Bytes::from_owner(zslice),
+        }
+    }
+}
+
 // Buffer
 impl Buffer for ZSlice {
     fn len(&self) -> usize {
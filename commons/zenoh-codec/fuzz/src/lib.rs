@@ -14,7 +14,7 @@
 //! Shared helpers for the `zenoh-codec` fuzz targets.
 //!
 //! The crate keeps the actual `libFuzzer` entrypoints thin and exposes:
-//! - target-specific harnesses for transport, structured network, and scouting,
+//! - target-specific harnesses for transport, structured network, bounded network, and scouting,
 //! - deterministic seed-corpus generation,
 //! - corpus verification for CI and local checks.
 
@@ -31,8 +31,9 @@ pub use corpus::{
     write_scouting_seed_corpus, write_transport_seed_corpus,
 };
 pub use network_message::{
-    analyze_network_message, exercise_network_message, exercise_network_message_model,
-    InterestModeModel, InterestOptionsModel, NetworkMessageAnalysis, NetworkMessageModel,
+    analyze_network_message, exercise_network_message, exercise_network_message_bounded,
+    exercise_network_message_model, InterestModeModel, InterestOptionsModel,
+    NetworkMessageAnalysis, NetworkMessageModel,
 };
 pub use scouting_message::{
     analyze_scouting_message, exercise_scouting_message, ScoutingMessageAnalysis,
@@ -17,7 +17,10 @@ use zenoh_buffers::{
     reader::{HasReader, Reader},
     writer::HasWriter,
 };
-use zenoh_codec::{RCodec, WCodec, Zenoh080};
+use zenoh_codec::{
+    limits::{DecodeBounded, DecodeError, Limits},
+    RCodec, WCodec, Zenoh080,
+};
 use zenoh_protocol::{
     common::ZExtBody,
     network::{
@@ -170,6 +173,71 @@ pub fn exercise_network_message_model(model: &NetworkMessageModel) {
     assert_network_message_roundtrip(&message);
 }
 
+/// Tight limits used to exercise the bounded decoder on small fuzzing inputs.
+const FUZZ_LIMITS: Limits = Limits {
+    max_message_size: 4096,
+    max_keyexpr_len: 64,
+    max_attachment_size: 64,
+    max_extensions: 4,
+    max_fragments: 4,
+};
+
+/// Fuzzes bounded decoding of raw bytes as a network message.
+///
+/// The bounded decoder must agree with the unbounded one: it only rejects inputs the unbounded
+/// decoder rejects, inputs with trailing bytes, or messages exceeding the limits.
+pub fn exercise_network_message_bounded(data: &[u8]) {
+    let codec = Zenoh080::new();
+    let mut reader = data.reader();
+    let unbounded: Result<NetworkMessage, _> = codec.read(&mut reader);
+
+    match NetworkMessage::decode_bounded(data, &FUZZ_LIMITS) {
+        Ok(message) => {
+            assert!(
+                unbounded.as_ref().is_ok_and(|m| *m == message) && !reader.can_read(),
+                "bounded decoding should only accept what unbounded decoding accepts"
+            );
+            assert_network_message_roundtrip(&message);
+        }
+        Err(DecodeError::MessageTooLarge { .. }) => {
+            assert!(data.len() > FUZZ_LIMITS.max_message_size)
+        }
+        Err(DecodeError::Malformed | DecodeError::LengthExceedsInput { .. }) => assert!(
+            unbounded.is_err(),
+            "bounded decoding should only report malformed inputs rejected by unbounded decoding"
+        ),
+        Err(DecodeError::TrailingBytes { count }) => {
+            assert!(unbounded.is_ok() && reader.remaining() == count)
+        }
+        // The limits are checked while walking the message, before it is fully validated, so
+        // they may be reported for inputs the unbounded decoder rejects as well.
+        Err(
+            DecodeError::KeyExprTooLong { .. }
+            | DecodeError::AttachmentTooLarge { .. }
+            | DecodeError::TooManyExtensions { .. },
+        ) => {}
+        Err(DecodeError::TooManyFragments { .. }) => {
+            panic!("decoding a single buffer should not report too many fragments")
+        }
+    }
+
+    // Reassembling the same bytes from fragments must give the same result, unless there are
+    // more fragments than allowed.
+    let fragments: Vec<&[u8]> = data.chunks(data.len().div_ceil(8).max(1)).collect();
+    let reassembled = NetworkMessage::decode_bounded_fragments(&fragments, &FUZZ_LIMITS);
+    if fragments.len() > FUZZ_LIMITS.max_fragments {
+        assert!(matches!(
+            reassembled,
+            Err(DecodeError::TooManyFragments { .. })
+        ));
+    } else {
+        assert_eq!(
+            reassembled,
+            NetworkMessage::decode_bounded(data, &FUZZ_LIMITS)
+        );
+    }
+}
+
 /// Summarizes how one arbitrary model input behaves as a network message.
 pub fn analyze_network_message(data: &[u8]) -> NetworkMessageAnalysis {
     match decode_network_message_model(data) {
@@ -53,7 +53,7 @@ pub(crate) fn sample_transport_message_seed_messages() -> Vec<(&'static str, Tra
         ("init_ack", sample_init_ack().into()),
         ("init_syn", sample_init_syn().into()),
         ("join", sample_join().into()),
-        ("keep_alive", zenoh_protocol::transport::KeepAlive.into()),
+        ("keep_alive", zenoh_protocol::transport::KeepAlive::new().into()),
         (
             "oam",
             zenoh_protocol::transport::TransportBody::OAM(sample_oam()).into(),
@@ -114,6 +114,7 @@ pub(crate) fn sample_response_network_message() -> NetworkMessage {
         ext_tstamp: None,
         ext_respid: None,
         ext_ts_stack: None,
+        ext_replier_qos: None,
     };
     NetworkBody::Response(response).into()
 }
@@ -0,0 +1,527 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+//! Decoding of untrusted buffers with explicit resource limits.
+//!
+//! [`DecodeBounded`] decodes a message in two steps. It first walks the wire representation of
+//! the message without allocating anything, and checks every declared length and count against
+//! the [`Limits`] as soon as it is read: a key expression or an attachment declaring a length
+//! above its limit is rejected before its bytes are looked at, and the walk stops at the first
+//! extension exceeding [`Limits::max_extensions`]. Only a buffer passing this walk is then decoded
+//! with the [`Zenoh080`] codec, so the decoder never allocates for a field exceeding its limit.
+//! Each violated limit is reported with a specific [`DecodeError`].
+//!
+//! A message received in several fragments is decoded with
+//! [`DecodeBounded::decode_bounded_fragments`], which checks the number of fragments and the
+//! size of the reassembled message before reassembling it.
+use alloc::vec::Vec;
+use core::fmt;
+
+use zenoh_buffers::reader::{DidntRead, HasReader, Reader};
+use zenoh_protocol::{
+    common::{iext, imsg},
+    network::{
+        declare::{self, keyexpr, queryable, subscriber, token},
+        id, interest, oam, push, request, response, NetworkMessage,
+    },
+    zenoh::{self, del, err, put, query, reply},
+};
+
+use crate::{RCodec, Zenoh080};
+
+/// Resource limits enforced by [`DecodeBounded::decode_bounded`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// Maximum size in bytes of the buffer holding the message.
+    pub max_message_size: usize,
+    /// Maximum length in bytes of the suffix of any key expression of the message.
+    pub max_keyexpr_len: usize,
+    /// Maximum size in bytes of the attachment of the message.
+    pub max_attachment_size: usize,
+    /// Maximum number of extensions carried by the message, counting the extensions of the
+    /// network message and of its body, whether known or not.
+    pub max_extensions: usize,
+    /// Maximum number of fragments a message may be reassembled from.
+    pub max_fragments: usize,
+}
+
+impl Limits {
+    /// The default limits: the maximum message size matches Zenoh's default maximum size of a
+    /// reassembled message (1 GiB).
+    pub const DEFAULT: Self = Self {
+        max_message_size: 1 << 30,
+        max_keyexpr_len: u16::MAX as usize,
+        max_attachment_size: 1 << 30,
+        max_extensions: 64,
+        max_fragments: u16::MAX as usize,
+    };
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// The reason why [`DecodeBounded::decode_bounded`] rejected a buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The buffer is larger than [`Limits::max_message_size`]; it has not been decoded.
+    MessageTooLarge { size: usize, max: usize },
+    /// The buffer does not hold a valid message.
+    Malformed,
+    /// A declared length exceeds the number of remaining bytes in the buffer.
+    LengthExceedsInput { len: usize, remaining: usize },
+    /// The buffer holds a valid message followed by unexpected bytes.
+    TrailingBytes { count: usize },
+    /// A key expression suffix is longer than [`Limits::max_keyexpr_len`].
+    KeyExprTooLong { len: usize, max: usize },
+    /// The attachment is larger than [`Limits::max_attachment_size`].
+    AttachmentTooLarge { size: usize, max: usize },
+    /// The message carries more than [`Limits::max_extensions`] extensions; `count` is the
+    /// number of extensions read when the limit was exceeded.
+    TooManyExtensions { count: usize, max: usize },
+    /// The message is split in more than [`Limits::max_fragments`] fragments; it has not been
+    /// reassembled.
+    TooManyFragments { count: usize, max: usize },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MessageTooLarge { size, max } => {
+                write!(
+                    f,
+                    "message of {size} bytes exceeds the limit of {max} bytes"
+                )
+            }
+            Self::Malformed => f.write_str("malformed message"),
+            Self::LengthExceedsInput { len, remaining } => {
+                write!(
+                    f,
+                    "declared length of {len} bytes exceeds the {remaining} remaining bytes"
+                )
+            }
+            Self::TrailingBytes { count } => write!(f, "{count} trailing bytes after message"),
+            Self::KeyExprTooLong { len, max } => {
+                write!(
+                    f,
+                    "key expression of {len} bytes exceeds the limit of {max} bytes"
+                )
+            }
+            Self::AttachmentTooLarge { size, max } => {
+                write!(
+                    f,
+                    "attachment of {size} bytes exceeds the limit of {max} bytes"
+                )
+            }
+            Self::TooManyExtensions { count, max } => {
+                write!(f, "{count} extensions exceed the limit of {max} extensions")
+            }
+            Self::TooManyFragments { count, max } => {
+                write!(f, "{count} fragments exceed the limit of {max} fragments")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+impl From<DidntRead> for DecodeError {
+    fn from(_: DidntRead) -> Self {
+        Self::Malformed
+    }
+}
+
+/// Decoding of a message from an untrusted buffer within explicit [`Limits`].
+pub trait DecodeBounded: Sized {
+    /// Decodes a message occupying the whole `bytes` buffer, within the provided `limits`.
+    fn decode_bounded(bytes: &[u8], limits: &Limits) -> Result<Self, DecodeError>;
+
+    /// Decodes a message split in the `fragments` buffers, within the provided `limits`.
+    ///
+    /// The number of fragments and the size of the reassembled message are checked before the
+    /// fragments are reassembled, then the reassembled message is decoded with
+    /// [`DecodeBounded::decode_bounded`].
+    fn decode_bounded_fragments(fragments: &[&[u8]], limits: &Limits) -> Result<Self, DecodeError> {
+        if fragments.len() > limits.max_fragments {
+            return Err(DecodeError::TooManyFragments {
+                count: fragments.len(),
+                max: limits.max_fragments,
+            });
+        }
+        let size = fragments
+            .iter()
+            .try_fold(0usize, |size, fragment| size.checked_add(fragment.len()))
+            .unwrap_or(usize::MAX);
+        if size > limits.max_message_size {
+            return Err(DecodeError::MessageTooLarge {
+                size,
+                max: limits.max_message_size,
+            });
+        }
+        let mut bytes = Vec::with_capacity(size);
+        for fragment in fragments {
+            bytes.extend_from_slice(fragment);
+        }
+        Self::decode_bounded(&bytes, limits)
+    }
+}
+
+impl DecodeBounded for NetworkMessage {
+    fn decode_bounded(bytes: &[u8], limits: &Limits) -> Result<Self, DecodeError> {
+        if bytes.len() > limits.max_message_size {
+            return Err(DecodeError::MessageTooLarge {
+                size: bytes.len(),
+                max: limits.max_message_size,
+            });
+        }
+
+        let mut walker = Walker {
+            bytes,
+            limits,
+            extensions: 0,
+        };
+        walker.network_message()?;
+
+        let mut reader = bytes.reader();
+        let message: NetworkMessage = Zenoh080::new().read(&mut reader)?;
+        if reader.can_read() {
+            return Err(DecodeError::TrailingBytes {
+                count: reader.remaining(),
+            });
+        }
+        Ok(message)
+    }
+}
+
+/// The extension of a message needing more than being skipped.
+#[derive(Clone, Copy)]
+enum Ext {
+    None,
+    /// The attachment extension, with the given id.
+    Attachment(u8),
+    /// The attachment and body extensions of queries.
+    Query,
+    /// The key expression extension of undeclarations.
+    WireExpr,
+}
+
+/// Walks the wire representation of a message without allocating, checking the [`Limits`].
+///
+/// The walk mirrors the [`Zenoh080`] decoders up to the last field that may violate a limit:
+/// the payloads, which are bounded by the size of the buffer, are not walked. Values that don't
+/// affect any limit, such as an unknown mandatory extension, are left to the decoder to reject.
+struct Walker<'a, 'b> {
+    bytes: &'a [u8],
+    limits: &'b Limits,
+    extensions: usize,
+}
+
+impl<'a> Walker<'a, '_> {
+    fn u8(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.bytes.read_u8()?)
+    }
+
+    fn zint(&mut self) -> Result<u64, DecodeError> {
+        let value: u64 = Zenoh080::new().read(&mut self.bytes)?;
+        Ok(value)
+    }
+
+    fn len(&mut self) -> Result<usize, DecodeError> {
+        usize::try_from(self.zint()?).map_err(|_| DecodeError::Malformed)
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        if len > self.bytes.len() {
+            return Err(DecodeError::LengthExceedsInput {
+                len,
+                remaining: self.bytes.len(),
+            });
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn keyexpr_len(&self, len: usize) -> Result<(), DecodeError> {
+        if len > self.limits.max_keyexpr_len {
+            return Err(DecodeError::KeyExprTooLong {
+                len,
+                max: self.limits.max_keyexpr_len,
+            });
+        }
+        Ok(())
+    }
+
+    fn wire_expr(&mut self, named: bool) -> Result<(), DecodeError> {
+        self.zint()?;
+        if named {
+            let len = self.len()?;
+            self.keyexpr_len(len)?;
+            self.take(len)?;
+        }
+        Ok(())
+    }
+
+    fn timestamp(&mut self) -> Result<(), DecodeError> {
+        self.zint()?;
+        let len = self.len()?;
+        self.take(len)?;
+        Ok(())
+    }
+
+    fn encoding(&mut self) -> Result<(), DecodeError> {
+        let id = self.zint()?;
+        if imsg::has_flag(id as u8, zenoh_protocol::core::encoding::flag::S as u8) {
+            let len = self.len()?;
+            self.take(len)?;
+        }
+        Ok(())
+    }
+
+    fn extensions(&mut self, mut has_ext: bool, special: Ext) -> Result<(), DecodeError> {
+        let attachment = match special {
+            Ext::Attachment(id) => Some(id),
+            Ext::Query => Some(query::ext::Attachment::ID),
+            Ext::None | Ext::WireExpr => None,
+        };
+        #[cfg(feature = "shared-memory")]
+        let mut shm = false;
+        while has_ext {
+            let header = self.u8()?;
+            self.extensions += 1;
+            if self.extensions > self.limits.max_extensions {
+                return Err(DecodeError::TooManyExtensions {
+                    count: self.extensions,
+                    max: self.limits.max_extensions,
+                });
+            }
+            match header & iext::ENC_MASK {
+                #[cfg(feature = "shared-memory")]
+                iext::ENC_UNIT
+                    if matches!(special, Ext::Query)
+                        && iext::eid(header) == query::ext::QueryBodyType::SID =>
+                {
+                    shm = true;
+                }
+                iext::ENC_UNIT => {}
+                iext::ENC_Z64 => {
+                    self.zint()?;
+                }
+                iext::ENC_ZBUF => {
+                    let len = self.len()?;
+                    match special {
+                        // The length of a shared memory query body is not reliable, the decoder
+                        // walks its sliced payload instead.
+                        #[cfg(feature = "shared-memory")]
+                        Ext::Query
+                            if shm && iext::eid(header) == query::ext::QueryBodyType::VID =>
+                        {
+                            self.sliced_value()?;
+                        }
+                        _ if attachment == Some(iext::eid(header)) => {
+                            if len > self.limits.max_attachment_size {
+                                return Err(DecodeError::AttachmentTooLarge {
+                                    size: len,
+                                    max: self.limits.max_attachment_size,
+                                });
+                            }
+                            self.take(len)?;
+                        }
+                        Ext::WireExpr
+                            if iext::eid(header) == declare::common::ext::WireExprExt::ID =>
+                        {
+                            let mut body = Walker {
+                                bytes: self.take(len)?,
+                                limits: self.limits,
+                                extensions: 0,
+                            };
+                            let flags = body.u8()?;
+                            body.zint()?;
+                            if imsg::has_flag(flags, declare::common::ext::flag::N) {
+                                self.keyexpr_len(body.bytes.len())?;
+                            }
+                        }
+                        _ => {
+                            self.take(len)?;
+                        }
+                    }
+                }
+                _ => return Err(DecodeError::Malformed),
+            }
+            has_ext = imsg::has_flag(header, iext::FLAG_Z);
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "shared-memory")]
+    fn sliced_value(&mut self) -> Result<(), DecodeError> {
+        self.encoding()?;
+        for _ in 0..self.zint()? {
+            match self.u8()? {
+                // Raw slice or shared memory slice info, both length-prefixed.
+                0 | 1 => {
+                    let len = self.len()?;
+                    self.take(len)?;
+                }
+                _ => return Err(DecodeError::Malformed),
+            }
+        }
+        Ok(())
+    }
+
+    fn network_message(&mut self) -> Result<(), DecodeError> {
+        let header = self.u8()?;
+        match imsg::mid(header) {
+            id::PUSH => {
+                self.wire_expr(imsg::has_flag(header, push::flag::N))?;
+                self.extensions(imsg::has_flag(header, push::flag::Z), Ext::None)?;
+                self.push_body()
+            }
+            id::REQUEST => {
+                self.zint()?;
+                self.wire_expr(imsg::has_flag(header, request::flag::N))?;
+                self.extensions(imsg::has_flag(header, request::flag::Z), Ext::None)?;
+                let header = self.u8()?;
+                if imsg::mid(header) != zenoh::id::QUERY {
+                    return Err(DecodeError::Malformed);
+                }
+                if imsg::has_flag(header, query::flag::C) {
+                    self.zint()?;
+                }
+                if imsg::has_flag(header, query::flag::P) {
+                    let len = self.len()?;
+                    self.take(len)?;
+                }
+                self.extensions(imsg::has_flag(header, query::flag::Z), Ext::Query)
+            }
+            id::RESPONSE => {
+                self.zint()?;
+                self.wire_expr(imsg::has_flag(header, response::flag::N))?;
+                self.extensions(imsg::has_flag(header, response::flag::Z), Ext::None)?;
+                let header = self.u8()?;
+                match imsg::mid(header) {
+                    zenoh::id::REPLY => {
+                        if imsg::has_flag(header, reply::flag::C) {
+                            self.zint()?;
+                        }
+                        self.extensions(imsg::has_flag(header, reply::flag::Z), Ext::None)?;
+                        self.push_body()
+                    }
+                    zenoh::id::ERR => {
+                        if imsg::has_flag(header, err::flag::E) {
+                            self.encoding()?;
+                        }
+                        self.extensions(imsg::has_flag(header, err::flag::Z), Ext::None)
+                    }
+                    _ => Err(DecodeError::Malformed),
+                }
+            }
+            id::RESPONSE_FINAL => {
+                self.zint()?;
+                self.extensions(imsg::has_flag(header, response::flag::Z), Ext::None)
+            }
+            id::INTEREST => {
+                self.zint()?;
+                if (header >> imsg::HEADER_BITS) & 0b11 != 0b00 {
+                    let options = interest::InterestOptions::from(self.u8()?);
+                    if options.restricted() {
+                        self.wire_expr(options.named())?;
+                    }
+                }
+                self.extensions(imsg::has_flag(header, interest::flag::Z), Ext::None)
+            }
+            id::DECLARE => {
+                if imsg::has_flag(header, declare::flag::I) {
+                    self.zint()?;
+                }
+                self.extensions(imsg::has_flag(header, declare::flag::Z), Ext::None)?;
+                self.declare_body()
+            }
+            id::OAM => {
+                self.zint()?;
+                self.extensions(imsg::has_flag(header, oam::flag::Z), Ext::None)
+            }
+            _ => Err(DecodeError::Malformed),
+        }
+    }
+
+    fn push_body(&mut self) -> Result<(), DecodeError> {
+        let header = self.u8()?;
+        match imsg::mid(header) {
+            zenoh::id::PUT => {
+                if imsg::has_flag(header, put::flag::T) {
+                    self.timestamp()?;
+                }
+                if imsg::has_flag(header, put::flag::E) {
+                    self.encoding()?;
+                }
+                self.extensions(
+                    imsg::has_flag(header, put::flag::Z),
+                    Ext::Attachment(put::ext::Attachment::ID),
+                )
+            }
+            zenoh::id::DEL => {
+                if imsg::has_flag(header, del::flag::T) {
+                    self.timestamp()?;
+                }
+                self.extensions(
+                    imsg::has_flag(header, del::flag::Z),
+                    Ext::Attachment(del::ext::Attachment::ID),
+                )
+            }
+            _ => Err(DecodeError::Malformed),
+        }
+    }
+
+    fn declare_body(&mut self) -> Result<(), DecodeError> {
+        use declare::id::*;
+
+        let header = self.u8()?;
+        match imsg::mid(header) {
+            D_KEYEXPR => {
+                self.zint()?;
+                self.wire_expr(imsg::has_flag(header, keyexpr::flag::N))?;
+                self.extensions(imsg::has_flag(header, keyexpr::flag::Z), Ext::None)
+            }
+            U_KEYEXPR => {
+                self.zint()?;
+                self.extensions(imsg::has_flag(header, keyexpr::flag::Z), Ext::None)
+            }
+            D_SUBSCRIBER => {
+                self.zint()?;
+                self.wire_expr(imsg::has_flag(header, subscriber::flag::N))?;
+                self.extensions(imsg::has_flag(header, subscriber::flag::Z), Ext::None)
+            }
+            D_QUERYABLE => {
+                self.zint()?;
+                self.wire_expr(imsg::has_flag(header, queryable::flag::N))?;
+                self.extensions(imsg::has_flag(header, queryable::flag::Z), Ext::None)
+            }
+            D_TOKEN => {
+                self.zint()?;
+                self.wire_expr(imsg::has_flag(header, token::flag::N))?;
+                self.extensions(imsg::has_flag(header, token::flag::Z), Ext::None)
+            }
+            U_SUBSCRIBER | U_QUERYABLE | U_TOKEN => {
+                self.zint()?;
+                self.extensions(imsg::has_flag(header, token::flag::Z), Ext::WireExpr)
+            }
+            D_FINAL => self.extensions(imsg::has_flag(header, token::flag::Z), Ext::None),
+            _ => Err(DecodeError::Malformed),
+        }
+    }
+}
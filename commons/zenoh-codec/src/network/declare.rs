@@ -924,10 +924,10 @@ where
 
         let mut flags: u8 = 0;
         if x.wire_expr.has_suffix() {
-            flags |= 1;
+            flags |= common::ext::flag::N;
         }
         if let Mapping::Sender = wire_expr.mapping {
-            flags |= 1 << 1;
+            flags |= common::ext::flag::M;
         }
         codec.write(&mut zriter, flags)?;
 
@@ -958,14 +958,14 @@ where
         let flags: u8 = self.codec.read(&mut zeader)?;
 
         let scope: ExprLen = self.codec.read(&mut zeader)?;
-        let suffix = if imsg::has_flag(flags, 1) {
+        let suffix = if imsg::has_flag(flags, common::ext::flag::N) {
             let mut buff = zenoh_buffers::vec::uninit(zeader.remaining());
             zeader.read_exact(&mut buff)?;
             String::from_utf8(buff).map_err(|_| DidntRead)?
         } else {
             String::new()
         };
-        let mapping = if imsg::has_flag(flags, 1 << 1) {
+        let mapping = if imsg::has_flag(flags, common::ext::flag::M) {
             Mapping::Sender
         } else {
             Mapping::Receiver
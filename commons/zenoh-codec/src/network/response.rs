@@ -46,6 +46,7 @@ where
             ext_tstamp,
             ext_respid,
             ext_ts_stack,
+            ext_replier_qos,
         } = x;
 
         // Header
@@ -53,7 +54,8 @@ where
         let mut n_exts = ((ext_qos != &ext::QoSType::DEFAULT) as u8)
             + (ext_tstamp.is_some() as u8)
             + (ext_respid.is_some() as u8)
-            + (ext_ts_stack.is_some() as u8);
+            + (ext_ts_stack.is_some() as u8)
+            + (ext_replier_qos.is_some() as u8);
         if n_exts != 0 {
             header |= flag::Z;
         }
@@ -86,6 +88,10 @@ where
             n_exts -= 1;
             self.write(&mut *writer, (ts_stack, n_exts != 0))?;
         }
+        if let Some(replier_qos) = ext_replier_qos.as_ref() {
+            n_exts -= 1;
+            self.write(&mut *writer, (replier_qos, n_exts != 0))?;
+        }
 
         // Payload
         self.write(&mut *writer, payload)?;
@@ -134,6 +140,7 @@ where
         let mut ext_tstamp = None;
         let mut ext_respid = None;
         let mut ext_ts_stack = None;
+        let mut ext_replier_qos = None;
 
         let mut has_ext = imsg::has_flag(self.header, flag::Z);
         while has_ext {
@@ -160,6 +167,11 @@ where
                     ext_ts_stack = Some(ts);
                     has_ext = ext;
                 }
+                ext::ReplierQoS::ID => {
+                    let (rq, ext): (ext::ReplierQoS, bool) = eodec.read(&mut *reader)?;
+                    ext_replier_qos = Some(rq);
+                    has_ext = ext;
+                }
                 _ => {
                     has_ext = extension::skip(reader, "Response", ext)?;
                 }
@@ -177,6 +189,7 @@ where
             ext_tstamp,
             ext_respid,
             ext_ts_stack,
+            ext_replier_qos,
         })
     }
 }
@@ -16,10 +16,10 @@ use zenoh_buffers::{
     writer::{DidntWrite, Writer},
 };
 use zenoh_protocol::{
-    common::imsg,
+    common::{iext, imsg},
     transport::{
         id,
-        keepalive::{flag, KeepAlive},
+        keepalive::{ext, flag, KeepAlive},
     },
 };
 
@@ -32,11 +32,20 @@ where
     type Output = Result<(), DidntWrite>;
 
     fn write(self, writer: &mut W, x: &KeepAlive) -> Self::Output {
-        let KeepAlive = x;
+        let KeepAlive { ext_timestamp } = x;
 
         // Header
-        let header = id::KEEP_ALIVE;
+        let mut header = id::KEEP_ALIVE;
+        if ext_timestamp.is_some() {
+            header |= flag::Z;
+        }
         self.write(&mut *writer, header)?;
+
+        // Extensions
+        if let Some(timestamp) = ext_timestamp.as_ref() {
+            self.write(&mut *writer, (timestamp, false))?;
+        }
+
         Ok(())
     }
 }
@@ -66,11 +75,24 @@ where
         }
 
         // Extensions
-        let has_ext = imsg::has_flag(self.header, flag::Z);
-        if has_ext {
-            extension::skip_all(reader, "Unknown KeepAlive ext")?;
+        let mut ext_timestamp = None;
+
+        let mut has_ext = imsg::has_flag(self.header, flag::Z);
+        while has_ext {
+            let ext: u8 = self.codec.read(&mut *reader)?;
+            let eodec = Zenoh080Header::new(ext);
+            match iext::eid(ext) {
+                ext::Timestamp::ID => {
+                    let (t, ext): (ext::Timestamp, bool) = eodec.read(&mut *reader)?;
+                    ext_timestamp = Some(t);
+                    has_ext = ext;
+                }
+                _ => {
+                    has_ext = extension::skip(reader, "KeepAlive", ext)?;
+                }
+            }
         }
 
-        Ok(KeepAlive)
+        Ok(KeepAlive { ext_timestamp })
     }
 }
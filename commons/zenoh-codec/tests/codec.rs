@@ -619,6 +619,25 @@ fn codec_reply() {
     run!(zenoh::Reply, zenoh::Reply::rand());
 }
 
+#[test]
+fn codec_reply_del() {
+    let x = zenoh::Reply {
+        consolidation: zenoh::ConsolidationMode::DEFAULT,
+        ext_unknown: vec![],
+        payload: zenoh::reply::ReplyBody::Del(zenoh::Del {
+            timestamp: None,
+            ext_sinfo: None,
+            ext_attachment: None,
+            ext_unknown: vec![],
+        }),
+    };
+
+    let mut bytes = vec![];
+    Zenoh080::new().write(&mut bytes.writer(), &x).unwrap();
+    assert_eq!(imsg::mid(bytes[0]), zenoh::id::REPLY);
+    assert_eq!(imsg::mid(bytes[1]), zenoh::id::DEL);
+}
+
 #[test]
 fn codec_err() {
     run!(zenoh::Err, zenoh::Err::rand());
@@ -12,10 +12,16 @@
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
 use zenoh_buffers::{reader::HasReader, writer::HasWriter};
-use zenoh_codec::{RCodec, WCodec, Zenoh080, Zenoh080Bounded};
+use zenoh_codec::{
+    limits::{DecodeBounded, DecodeError, Limits},
+    RCodec, WCodec, Zenoh080, Zenoh080Bounded,
+};
 use zenoh_protocol::{
-    core::{Locator, WhatAmI, ZenohIdProto},
+    common::{ZExtBody, ZExtUnknown},
+    core::{Locator, WhatAmI, WireExpr, ZenohIdProto},
+    network::{push, NetworkMessage, Push},
     scouting::{HelloProto, ScoutingMessage},
+    zenoh::{put, PushBody, Put},
 };
 
 fn fixed_zid() -> ZenohIdProto {
@@ -71,3 +77,273 @@ fn hello_rejects_locator_count_larger_than_remaining_input() {
     let decoded: Result<ScoutingMessage, _> = codec.read(&mut reader);
     assert!(decoded.is_err());
 }
+
+fn push_put_message(suffix: &str, put: Put) -> NetworkMessage {
+    NetworkMessage::from(Push {
+        wire_expr: WireExpr::from(suffix.to_string()),
+        ext_qos: push::ext::QoSType::DEFAULT,
+        ext_tstamp: None,
+        ext_nodeid: push::ext::NodeIdType::DEFAULT,
+        ext_ts_stack: None,
+        payload: PushBody::Put(put),
+    })
+}
+
+fn encode_network_message(message: &NetworkMessage) -> Vec<u8> {
+    let codec = Zenoh080::new();
+    let mut bytes = vec![];
+    let mut writer = bytes.writer();
+    codec.write(&mut writer, message).unwrap();
+    bytes
+}
+
+fn find(bytes: &[u8], pattern: &[u8]) -> usize {
+    bytes
+        .windows(pattern.len())
+        .position(|w| w == pattern)
+        .expect("pattern must be encoded in the message")
+}
+
+#[test]
+fn decode_bounded_accepts_message_within_limits() {
+    let message = push_put_message(
+        "demo/key",
+        Put {
+            payload: vec![1, 2, 3].into(),
+            ext_attachment: Some(put::ext::AttachmentType {
+                buffer: b"attachment".to_vec().into(),
+            }),
+            ..Put::default()
+        },
+    );
+    let bytes = encode_network_message(&message);
+
+    let decoded = NetworkMessage::decode_bounded(&bytes, &Limits::default()).unwrap();
+    assert_eq!(decoded, message);
+}
+
+#[test]
+fn decode_bounded_rejects_message_larger_than_limit_before_decoding() {
+    // Garbage bytes: the size limit must be checked before attempting to decode.
+    let bytes = [0xff; 64];
+    let limits = Limits {
+        max_message_size: 32,
+        ..Limits::default()
+    };
+
+    let decoded = NetworkMessage::decode_bounded(&bytes, &limits);
+    assert_eq!(
+        decoded,
+        Err(DecodeError::MessageTooLarge { size: 64, max: 32 })
+    );
+}
+
+#[test]
+fn decode_bounded_rejects_keyexpr_length_larger_than_input() {
+    // Corrupt the suffix length of the key expression into a 64 KiB length while only
+    // a few bytes follow: the decoder must fail instead of allocating the suffix.
+    let message = push_put_message("demo", Put::default());
+    let mut bytes = encode_network_message(&message);
+    let suffix = find(&bytes, b"demo");
+    assert_eq!(bytes[suffix - 1], 4);
+    bytes.splice(suffix - 1..suffix, [0xff, 0xff, 0x03]);
+
+    let decoded = NetworkMessage::decode_bounded(&bytes, &Limits::default());
+    assert_eq!(
+        decoded,
+        Err(DecodeError::LengthExceedsInput {
+            len: 0xffff,
+            remaining: bytes.len() - (suffix + 2),
+        })
+    );
+}
+
+#[test]
+fn decode_bounded_rejects_keyexpr_length_larger_than_limit_before_reading_it() {
+    // Truncate the message right after a declared suffix length above the limit: the
+    // declared length alone must be rejected with the key expression limit.
+    let message = push_put_message("demo", Put::default());
+    let mut bytes = encode_network_message(&message);
+    let suffix = find(&bytes, b"demo");
+    bytes.truncate(suffix - 1);
+    bytes.push(100);
+    let limits = Limits {
+        max_keyexpr_len: 64,
+        ..Limits::default()
+    };
+
+    let decoded = NetworkMessage::decode_bounded(&bytes, &limits);
+    assert_eq!(
+        decoded,
+        Err(DecodeError::KeyExprTooLong { len: 100, max: 64 })
+    );
+}
+
+#[test]
+fn decode_bounded_rejects_attachment_length_larger_than_input() {
+    // Corrupt the attachment length into a huge varint: the declared attachment is
+    // impossible and must be rejected without being preallocated.
+    let message = push_put_message(
+        "demo/key",
+        Put {
+            ext_attachment: Some(put::ext::AttachmentType {
+                buffer: b"ATTACHMENT".to_vec().into(),
+            }),
+            ..Put::default()
+        },
+    );
+    let mut bytes = encode_network_message(&message);
+    let attachment = find(&bytes, b"ATTACHMENT");
+    assert_eq!(bytes[attachment - 1], 10);
+    bytes.splice(attachment - 1..attachment, [0xff, 0xff, 0xff, 0xff, 0x0f]);
+
+    let decoded = NetworkMessage::decode_bounded(&bytes, &Limits::default());
+    assert_eq!(
+        decoded,
+        Err(DecodeError::AttachmentTooLarge {
+            size: u32::MAX as usize,
+            max: Limits::default().max_attachment_size,
+        })
+    );
+}
+
+#[test]
+fn decode_bounded_rejects_trailing_bytes() {
+    let message = push_put_message("demo/key", Put::default());
+    let mut bytes = encode_network_message(&message);
+    bytes.extend_from_slice(&[0, 0, 0]);
+
+    let decoded = NetworkMessage::decode_bounded(&bytes, &Limits::default());
+    assert_eq!(decoded, Err(DecodeError::TrailingBytes { count: 3 }));
+}
+
+#[test]
+fn decode_bounded_rejects_keyexpr_longer_than_limit() {
+    let message = push_put_message(&"a".repeat(100), Put::default());
+    let bytes = encode_network_message(&message);
+    let limits = Limits {
+        max_keyexpr_len: 64,
+        ..Limits::default()
+    };
+
+    let decoded = NetworkMessage::decode_bounded(&bytes, &limits);
+    assert_eq!(
+        decoded,
+        Err(DecodeError::KeyExprTooLong { len: 100, max: 64 })
+    );
+}
+
+#[test]
+fn decode_bounded_rejects_attachment_larger_than_limit() {
+    let message = push_put_message(
+        "demo/key",
+        Put {
+            ext_attachment: Some(put::ext::AttachmentType {
+                buffer: vec![0; 100].into(),
+            }),
+            ..Put::default()
+        },
+    );
+    let bytes = encode_network_message(&message);
+    let limits = Limits {
+        max_attachment_size: 64,
+        ..Limits::default()
+    };
+
+    let decoded = NetworkMessage::decode_bounded(&bytes, &limits);
+    assert_eq!(
+        decoded,
+        Err(DecodeError::AttachmentTooLarge { size: 100, max: 64 })
+    );
+}
+
+#[test]
+fn decode_bounded_rejects_too_many_extensions() {
+    // Each unknown unit extension costs a single byte on the wire but is retained by
+    // the decoder, so their number must be capped.
+    let message = push_put_message(
+        "demo/key",
+        Put {
+            ext_unknown: (0..100)
+                .map(|_| ZExtUnknown::new(0x0a, false, ZExtBody::Unit))
+                .collect(),
+            ..Put::default()
+        },
+    );
+    let bytes = encode_network_message(&message);
+    let limits = Limits {
+        max_extensions: 16,
+        ..Limits::default()
+    };
+
+    let decoded = NetworkMessage::decode_bounded(&bytes, &limits);
+    assert_eq!(
+        decoded,
+        Err(DecodeError::TooManyExtensions { count: 17, max: 16 })
+    );
+}
+
+#[test]
+fn decode_bounded_accepts_random_messages() {
+    for _ in 0..1_000 {
+        let message = NetworkMessage::rand();
+        let bytes = encode_network_message(&message);
+        let decoded = NetworkMessage::decode_bounded(&bytes, &Limits::default());
+        assert_eq!(decoded.as_ref(), Ok(&message), "{message:?}");
+    }
+}
+
+#[test]
+fn decode_bounded_fragments_reassembles_message() {
+    let message = push_put_message(
+        "demo/key",
+        Put {
+            payload: vec![1, 2, 3].into(),
+            ..Put::default()
+        },
+    );
+    let bytes = encode_network_message(&message);
+    let fragments: Vec<&[u8]> = bytes.chunks(4).collect();
+
+    let decoded = NetworkMessage::decode_bounded_fragments(&fragments, &Limits::default());
+    assert_eq!(decoded, Ok(message));
+}
+
+#[test]
+fn decode_bounded_fragments_rejects_too_many_fragments_before_reassembling() {
+    // Many empty fragments cost nothing to receive but would each need to be tracked, so their
+    // number must be capped whatever their content.
+    let fragments = vec![[].as_slice(); 1_000];
+    let limits = Limits {
+        max_fragments: 16,
+        ..Limits::default()
+    };
+
+    let decoded = NetworkMessage::decode_bounded_fragments(&fragments, &limits);
+    assert_eq!(
+        decoded,
+        Err(DecodeError::TooManyFragments {
+            count: 1_000,
+            max: 16
+        })
+    );
+}
+
+#[test]
+fn decode_bounded_fragments_rejects_reassembled_message_larger_than_limit() {
+    let fragment = [0xff; 64];
+    let fragments = [fragment.as_slice(); 4];
+    let limits = Limits {
+        max_message_size: 128,
+        ..Limits::default()
+    };
+
+    let decoded = NetworkMessage::decode_bounded_fragments(&fragments, &limits);
+    assert_eq!(
+        decoded,
+        Err(DecodeError::MessageTooLarge {
+            size: 256,
+            max: 128
+        })
+    );
+}
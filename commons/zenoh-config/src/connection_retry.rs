@@ -12,9 +12,13 @@
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
 
+use std::str::FromStr;
+
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use zenoh_core::zparse_default;
 use zenoh_protocol::core::{EndPoint, WhatAmI};
+use zenoh_result::{zerror, ZError};
 
 use crate::{defaults, mode_dependent::*, Config};
 
@@ -26,6 +30,32 @@ pub struct ConnectionRetryModeDependentConf {
     pub period_max_ms: Option<ModeDependentValue<i64>>,
     // increase factor for the next timeout until next try
     pub period_increase_factor: Option<ModeDependentValue<f64>>,
+    // maximum fraction of the timeout randomly added to or removed from it
+    pub jitter: Option<ModeDependentValue<f64>>,
+}
+
+/// When the failure to connect to the endpoints of a group makes the connection cycle fail,
+/// for the endpoints configured to exit on failure.
+#[derive(Debug, Default, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExitOnFailurePolicy {
+    /// Fail as soon as this endpoint fails.
+    #[default]
+    Any,
+    /// Fail only if all the endpoints of the group with this policy fail.
+    All,
+}
+
+impl FromStr for ExitOnFailurePolicy {
+    type Err = ZError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "any" => Ok(Self::Any),
+            "all" => Ok(Self::All),
+            _ => Err(zerror!("Unknown exit on failure policy: {s}")),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
@@ -34,6 +64,7 @@ pub struct ConnectionRetryConf {
     pub period_init_ms: i64,
     pub period_max_ms: i64,
     pub period_increase_factor: f64,
+    pub jitter: f64,
 }
 
 impl ConnectionRetryConf {
@@ -57,6 +88,10 @@ impl ConnectionRetryConf {
                 .period_increase_factor
                 .get(whatami)
                 .unwrap_or(default_retry.period_increase_factor.get(whatami).unwrap()),
+            jitter: *retry
+                .jitter
+                .get(whatami)
+                .unwrap_or(default_retry.jitter.get(whatami).unwrap()),
         }
     }
 
@@ -95,8 +130,21 @@ impl ConnectionRetryPeriod {
         std::time::Duration::from_millis(self.delay as u64)
     }
 
+    /// Returns the wait time until the next try and increases the timeout.
+    ///
+    /// The wait time is the current [`duration`](Self::duration) randomly shifted by at most the
+    /// configured jitter fraction of it.
     pub fn next_duration(&mut self) -> std::time::Duration {
-        let res = self.duration();
+        self.next_duration_shifted(rand::thread_rng().gen_range(-1.0..=1.0))
+    }
+
+    /// Returns the wait time until the next try and increases the timeout, like
+    /// [`next_duration`](Self::next_duration) but with the given `shift` instead of a random one.
+    ///
+    /// The wait time is the current [`duration`](Self::duration) shifted by `shift` times the
+    /// configured jitter fraction of it, `shift` being clamped to `[-1, 1]`.
+    pub fn next_duration_shifted(&mut self, shift: f64) -> std::time::Duration {
+        let res = self.jittered(self.duration(), shift.clamp(-1., 1.));
 
         self.delay = (self.delay as f64 * self.conf.period_increase_factor) as i64;
         if self.conf.period_max_ms > 0 && self.delay > self.conf.period_max_ms {
@@ -105,6 +153,14 @@ impl ConnectionRetryPeriod {
 
         res
     }
+
+    fn jittered(&self, duration: std::time::Duration, shift: f64) -> std::time::Duration {
+        let jitter = self.conf.jitter.clamp(0., 1.);
+        if jitter == 0. || duration.is_zero() || duration == std::time::Duration::MAX {
+            return duration;
+        }
+        duration.mul_f64(1. + shift * jitter)
+    }
 }
 
 fn ms_to_duration(ms: i64) -> std::time::Duration {
@@ -137,6 +193,20 @@ pub fn get_global_connect_timeout(config: &Config) -> std::time::Duration {
     )
 }
 
+pub fn get_connect_exit_on_failure_policy(
+    config: &Config,
+    endpoint: &EndPoint,
+) -> ExitOnFailurePolicy {
+    let policy = config
+        .connect()
+        .exit_on_failure_policy()
+        .unwrap_or(defaults::connect::exit_on_failure_policy);
+    match endpoint.config().get("exit_on_failure_policy") {
+        Some(val) => zparse_default!(val, policy),
+        None => policy,
+    }
+}
+
 pub fn get_retry_config(
     config: &Config,
     endpoint: Option<&EndPoint>,
@@ -189,6 +259,9 @@ pub fn get_retry_config(
         if let Some(val) = config.get("retry_period_increase_factor") {
             res.period_increase_factor = zparse_default!(val, res.period_increase_factor);
         }
+        if let Some(val) = config.get("retry_jitter") {
+            res.jitter = zparse_default!(val, res.jitter);
+        }
     }
     res
 }
@@ -33,7 +33,7 @@ pub const mode: WhatAmI = WhatAmI::Peer;
 #[allow(non_upper_case_globals)]
 #[allow(dead_code)]
 pub mod connect {
-    use super::{ModeDependentValue, ModeValues};
+    use super::{ExitOnFailurePolicy, ModeDependentValue, ModeValues};
 
     pub const timeout_ms: ModeDependentValue<i64> = ModeDependentValue::Dependent(ModeValues {
         router: Some(-1),
@@ -46,6 +46,7 @@ pub mod connect {
             peer: Some(false),
             client: Some(true),
         });
+    pub const exit_on_failure_policy: ExitOnFailurePolicy = ExitOnFailurePolicy::Any;
 }
 
 #[allow(non_upper_case_globals)]
@@ -190,6 +191,7 @@ impl Default for ConnectConfig {
             timeout_ms: None,
             endpoints: ModeDependentValue::Unique(vec![]),
             exit_on_failure: None,
+            exit_on_failure_policy: None,
             retry: None,
         }
     }
@@ -227,6 +229,15 @@ impl Default for QoSUnicastConf {
     }
 }
 
+impl Default for PingConf {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            rate_limit: 100,
+        }
+    }
+}
+
 #[allow(clippy::derivable_impls)]
 impl Default for QoSMulticastConf {
     fn default() -> Self {
@@ -256,6 +267,7 @@ impl Default for LinkTxConf {
             sequence_number_resolution: Bits::from(TransportSn::MAX),
             lease: 10_000,
             keep_alive: 4,
+            stall_timeout: None,
             batch_size: BatchSize::MAX,
             queue: QueueConf::default(),
             threads: num,
@@ -360,6 +372,7 @@ impl Default for ConnectionRetryModeDependentConf {
             period_init_ms: Some(ModeDependentValue::Unique(1000)),
             period_max_ms: Some(ModeDependentValue::Unique(4000)),
             period_increase_factor: Some(ModeDependentValue::Unique(2.)),
+            jitter: Some(ModeDependentValue::Unique(0.)),
         }
     }
 }
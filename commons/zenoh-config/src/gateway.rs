@@ -15,7 +15,7 @@ use nonempty_collections::NEVec;
 use serde::{Deserialize, Serialize};
 #[allow(unused_imports)]
 use zenoh_core::polyfill::*;
-use zenoh_protocol::core::{RegionName, WhatAmIMatcher};
+use zenoh_protocol::core::{RegionName, WhatAmI, WhatAmIMatcher};
 
 use crate::{Interface, ZenohId};
 
@@ -23,6 +23,23 @@ use crate::{Interface, ZenohId};
 #[serde(deny_unknown_fields)]
 pub struct GatewayConf {
     pub south: Option<GatewaySouthConf>,
+    /// Whether a client accepts sessions from other clients, acting as their gateway.
+    ///
+    /// Only applies to clients with the `auto` preset.
+    pub accept_clients: Option<bool>,
+}
+
+impl GatewayConf {
+    /// Returns `true` if a node in `mode` puts the clients connecting to it in its auto south
+    /// region, i.e. if it is a client configured with `accept_clients` and the `auto` preset.
+    pub fn accepts_clients(&self, mode: WhatAmI) -> bool {
+        mode.is_client()
+            && self.accept_clients.unwrap_or(false)
+            && matches!(
+                self.south,
+                None | Some(GatewaySouthConf::Preset(GatewayPresetConf::Auto))
+            )
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
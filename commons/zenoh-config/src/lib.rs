@@ -54,7 +54,8 @@ use validated_struct::ValidatedMapAssociatedTypes;
 pub use validated_struct::{GetError, ValidatedMap};
 pub use wrappers::ZenohId;
 pub use zenoh_protocol::core::{
-    whatami, EndPoint, EndPoints, Locator, WhatAmI, WhatAmIMatcher, WhatAmIMatcherVisitor,
+    whatami, EndPoint, EndPointBuilder, EndPoints, Locator, WhatAmI, WhatAmIMatcher,
+    WhatAmIMatcherVisitor,
 };
 use zenoh_protocol::{
     core::{
@@ -175,6 +176,13 @@ pub struct LowPassFilterConf {
     pub size_limit: usize,
 }
 
+#[derive(Serialize, Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct PayloadSizeLimitConf {
+    pub key_exprs: NEVec<OwnedKeyExpr>,
+    pub max_payload_size: usize,
+}
+
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, Eq, Hash, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum LowPassFilterMessage {
@@ -532,6 +540,8 @@ validated_struct::validator! {
             pub endpoints: ModeDependentValue<Vec<EndPoints>>,
             /// if connection timeout exceed, exit from application
             pub exit_on_failure: Option<ModeDependentValue<bool>>,
+            /// whether any or all the endpoints of a group must fail to exit from application
+            pub exit_on_failure_policy: Option<connection_retry::ExitOnFailurePolicy>,
             pub retry: Option<connection_retry::ConnectionRetryModeDependentConf>,
         },
         /// Which endpoints to listen on.
@@ -612,6 +622,9 @@ validated_struct::validator! {
             /// If set to false (default), messages with timestamps in the future are retimestamped.
             /// Timestamps are ignored if timestamping is disabled.
             drop_future_timestamp: Option<bool>,
+            /// The estimated clock skew with a remote HLC (in milliseconds) above which a warning is logged.
+            /// The skew is only monitored if timestamping is enabled. If not set, no warning is logged.
+            clock_skew_warning_threshold: Option<u64>,
         },
 
         /// The default timeout to apply to queries in milliseconds.
@@ -727,6 +740,9 @@ validated_struct::validator! {
                     lease: u64,
                     /// Number of keep-alive messages in a link lease duration (default: 4)
                     keep_alive: usize,
+                    /// Duration in milliseconds after which a link is considered failed and closed
+                    /// when its pending data cannot be written (default: null, i.e. disabled)
+                    stall_timeout: Option<u64>,
                     /// Zenoh's MTU equivalent (default: 2^16-1) (max: 2^16-1)
                     batch_size: BatchSize,
                     pub queue: #[derive(Default)]
@@ -909,6 +925,18 @@ validated_struct::validator! {
 
         },
 
+        /// Configuration of the diagnostics utilities.
+        pub diagnostics: #[derive(Default)]
+        DiagnosticsConf {
+            /// Configuration of the responder answering the probes of `zenoh::diagnostics::ping`.
+            pub ping: PingConf {
+                /// Whether the session answers the ping probes sent to `@/ping/<zid>` (default `true`).
+                enabled: bool,
+                /// The maximum number of probes answered per second, the extra probes are left unanswered (default `100`).
+                rate_limit: u32,
+            },
+        },
+
         /// Namespace prefix.
         /// If not None, all outgoing key expressions will be
         /// automatically prefixed with specified string,
@@ -934,6 +962,18 @@ validated_struct::validator! {
         /// Configuration of the low-pass filter
         pub low_pass_filter: Vec<LowPassFilterConf>,
 
+        /// Configuration of the payload size limits
+        pub limits: #[derive(Default)]
+        LimitsConf {
+            /// Inclusive maximum payload size of the publications, in bytes (unlimited by default).
+            pub max_payload_size: Option<usize>,
+            /// Whether the attachment size is added to the payload size (false by default).
+            #[serde(default = "set_false")]
+            pub include_attachment: bool,
+            /// Limits overriding `max_payload_size` on the publications matching their key expressions.
+            pub overrides: Vec<PayloadSizeLimitConf>,
+        },
+
         /// Configuration of the stats per keyexpr
         pub stats: #[derive(Default, PartialEq, Eq)] StatsConfig {
             filters: Vec<StatsFilterConfig>,
@@ -1484,7 +1524,7 @@ impl Config {
                     bail!(e)
                 }
                 if content.is_empty() {
-                    bail!("Empty config file");
+                    bail!(kind = ConfigInvalid, "Empty config file");
                 }
                 match path
                     .extension()
@@ -1492,28 +1532,28 @@ impl Config {
                 {
                     Some("json") | Some("json5") => match json5::Deserializer::from_str(&content) {
                         Ok(mut d) => Config::from_deserializer(&mut d).map_err(|e| match e {
-                            Ok(c) => zerror!("Invalid configuration: {}", c).into(),
-                            Err(e) => zerror!("JSON error: {:?}", e).into(),
+                            Ok(c) => zerror!(kind = ConfigInvalid, "Invalid configuration: {}", c).into(),
+                            Err(e) => zerror!(kind = ConfigInvalid, "JSON error: {:?}", e).into(),
                         }),
-                        Err(e) => bail!(e),
+                        Err(e) => bail!(kind = ConfigInvalid, e),
                     },
                     Some("yaml") | Some("yml") => Config::from_deserializer(serde_yaml::Deserializer::from_str(&content)).map_err(|e| match e {
-                        Ok(c) => zerror!("Invalid configuration: {}", c).into(),
-                        Err(e) => zerror!("YAML error: {:?}", e).into(),
+                        Ok(c) => zerror!(kind = ConfigInvalid, "Invalid configuration: {}", c).into(),
+                        Err(e) => zerror!(kind = ConfigInvalid, "YAML error: {:?}", e).into(),
                     }),
                     #[cfg(feature = "unstable")]
                     Some("toml") => {
                         tracing::warn!("The TOML configuration format is unstable and may be removed in a future release");
                         match toml::Deserializer::parse(&content) {
                             Ok(de) => Config::from_deserializer(de).map_err(|e| match e {
-                                Ok(c) => zerror!("Invalid configuration: {}", c).into(),
-                                Err(e) => zerror!("TOML deserization error: {:?}", e).into(),
+                                Ok(c) => zerror!(kind = ConfigInvalid, "Invalid configuration: {}", c).into(),
+                                Err(e) => zerror!(kind = ConfigInvalid, "TOML deserization error: {:?}", e).into(),
                             }),
-                            Err(e) => bail!("TOML parsing error: {:?}", e),
+                            Err(e) => bail!(kind = ConfigInvalid, "TOML parsing error: {:?}", e),
                         }
                     },
-                    Some(other) => bail!("Unsupported file type '.{}' (.json, .json5 and .yaml are supported)", other),
-                    None => bail!("Unsupported file type. Configuration files must have an extension (.json, .json5 and .yaml supported)")
+                    Some(other) => bail!(kind = ConfigInvalid, "Unsupported file type '.{}' (.json, .json5 and .yaml are supported)", other),
+                    None => bail!(kind = ConfigInvalid, "Unsupported file type. Configuration files must have an extension (.json, .json5 and .yaml supported)")
                 }
             }
             Err(e) => bail!(e),
@@ -77,6 +77,9 @@ impl From<CongestionControl> for CongestionControlConf {
             CongestionControl::Block => Self::Block,
             #[cfg(feature = "unstable")]
             CongestionControl::BlockFirst => Self::BlockFirst,
+            // The timeout is a publisher-local setting which can't be expressed in the config
+            #[cfg(feature = "unstable")]
+            CongestionControl::BlockWithTimeout => Self::Block,
         }
     }
 }
@@ -23,6 +23,7 @@ use zenoh_protocol::{
     core::{key_expr::OwnedKeyExpr, EntityGlobalIdProto, Locator, WhatAmI, ZenohIdProto},
     scouting::HelloProto,
 };
+use zenoh_result::bail;
 
 /// The global unique id of a Zenoh runtime
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Default)]
@@ -30,8 +31,10 @@ use zenoh_protocol::{
 pub struct ZenohId(ZenohIdProto);
 
 impl ZenohId {
-    /// Used by plugins for crating adminspace path
-    #[doc(hidden)]
+    /// Returns the key expression chunk identifying this [`ZenohId`], e.g. in admin space paths
+    /// (`@/<zid>/...`).
+    ///
+    /// The chunk is the hexadecimal string returned by [`Display`](fmt::Display).
     pub fn into_keyexpr(self) -> OwnedKeyExpr {
         self.into()
     }
@@ -39,6 +42,36 @@ impl ZenohId {
     pub fn to_le_bytes(self) -> [u8; uhlc::ID::MAX_SIZE] {
         self.0.to_le_bytes()
     }
+
+    /// Returns a short form of this [`ZenohId`] for logs and dashboards.
+    ///
+    /// The short form is the hexadecimal encoding of the `n_bytes` most significant bytes of
+    /// this [`ZenohId`], in the order of [`Display`](fmt::Display), with two digits per byte.
+    /// It is the whole [`ZenohId`] if it is not longer than `n_bytes`.
+    #[zenoh_macros::unstable]
+    pub fn short(&self, n_bytes: usize) -> impl fmt::Display {
+        ShortZenohId {
+            zid: *self,
+            n_bytes,
+        }
+    }
+}
+
+#[cfg(feature = "unstable")]
+struct ShortZenohId {
+    zid: ZenohId,
+    n_bytes: usize,
+}
+
+#[cfg(feature = "unstable")]
+impl fmt::Display for ShortZenohId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bytes = self.zid.to_le_bytes();
+        for byte in bytes[..self.zid.0.size()].iter().rev().take(self.n_bytes) {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
 }
 
 impl fmt::Debug for ZenohId {
@@ -61,7 +94,25 @@ impl From<ZenohIdProto> for ZenohId {
 impl TryFrom<&[u8]> for ZenohId {
     type Error = zenoh_result::Error;
 
+    /// Builds a [`ZenohId`] from its little endian bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `value` is empty, longer than 16 bytes or only made of zeros.
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            bail!("Invalid id: no bytes provided");
+        }
+        if value.len() > ZenohIdProto::MAX_SIZE {
+            bail!(
+                "Invalid id: {} bytes provided, at most {} bytes are accepted",
+                value.len(),
+                ZenohIdProto::MAX_SIZE
+            );
+        }
+        if value.iter().all(|b| *b == 0) {
+            bail!("Invalid id: all bytes are zero");
+        }
         let proto: ZenohIdProto = value.try_into()?;
         Ok(ZenohId::from(proto))
     }
@@ -94,8 +145,10 @@ impl From<&ZenohId> for OwnedKeyExpr {
 impl FromStr for ZenohId {
     type Err = zenoh_result::Error;
 
+    /// Parses a [`ZenohId`] from its hexadecimal string, optionally prefixed by `0x`.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        ZenohIdProto::from_str(s).map(|zid| zid.into())
+        let hex = s.strip_prefix("0x").unwrap_or(s);
+        ZenohIdProto::from_str(hex).map(|zid| zid.into())
     }
 }
 
@@ -204,3 +257,73 @@ impl From<EntityGlobalId> for EntityGlobalIdProto {
         value.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zenoh_id_from_str() {
+        let zid = ZenohId::from_str("a1b2c3d4e5f6").unwrap();
+        assert_eq!(zid.to_string(), "a1b2c3d4e5f6");
+        assert_eq!(ZenohId::from_str("0xa1b2c3d4e5f6").unwrap(), zid);
+        assert_eq!(ZenohId::from_str(&zid.to_string()).unwrap(), zid);
+
+        assert!(ZenohId::from_str("").is_err());
+        assert!(ZenohId::from_str("0x").is_err());
+        assert!(ZenohId::from_str("A1B2").is_err());
+        assert!(ZenohId::from_str("not-hex").is_err());
+    }
+
+    #[test]
+    fn zenoh_id_try_from_bytes() {
+        let zid = ZenohId::try_from([0x2a_u8, 0x01].as_slice()).unwrap();
+        assert_eq!(zid.to_string(), "12a");
+        assert_eq!(&zid.to_le_bytes()[..2], &[0x2a, 0x01]);
+
+        let zid = ZenohId::default();
+        assert_eq!(
+            ZenohId::try_from(zid.to_le_bytes().as_slice()).unwrap(),
+            zid
+        );
+
+        assert!(ZenohId::try_from([].as_slice()).is_err());
+        assert!(ZenohId::try_from([0_u8; 4].as_slice()).is_err());
+        assert!(ZenohId::try_from([1_u8; ZenohIdProto::MAX_SIZE + 1].as_slice()).is_err());
+    }
+
+    #[test]
+    fn zenoh_id_keyexpr() {
+        let zid = ZenohId::default();
+        let chunk = zid.into_keyexpr();
+        assert_eq!(chunk.as_str(), zid.to_string());
+
+        let admin_path = OwnedKeyExpr::try_from(format!("@/{chunk}/router")).unwrap();
+        let parsed = admin_path.as_str().split('/').nth(1).unwrap();
+        assert_eq!(ZenohId::from_str(parsed).unwrap(), zid);
+    }
+
+    #[test]
+    fn zenoh_id_serde() {
+        let zid = ZenohId::from_str("a1b2c3d4e5f6").unwrap();
+        let json = serde_json::to_string(&zid).unwrap();
+        assert_eq!(json, "\"a1b2c3d4e5f6\"");
+        assert_eq!(serde_json::from_str::<ZenohId>(&json).unwrap(), zid);
+        assert!(serde_json::from_str::<ZenohId>("\"A1B2\"").is_err());
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn zenoh_id_short() {
+        let zid = ZenohId::from_str("a1b2c3d4e5f6").unwrap();
+        assert_eq!(zid.short(2).to_string(), "a1b2");
+        assert_eq!(zid.short(0).to_string(), "");
+        assert_eq!(zid.short(16).to_string(), "a1b2c3d4e5f6");
+
+        // Each byte is encoded with two digits, even the most significant one
+        let zid = ZenohId::from_str("a1b2c3d4e").unwrap();
+        assert_eq!(zid.to_string(), "a1b2c3d4e");
+        assert_eq!(zid.short(1).to_string(), "0a");
+        assert_eq!(zid.short(2).to_string(), "0a1b");
+    }
+}
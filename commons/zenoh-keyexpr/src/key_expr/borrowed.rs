@@ -774,7 +774,7 @@ impl KeyExprError {
             Self::SharpOrQMark => anyhow!("Invalid Key Expr `{s}`: `#` and `?` are forbidden characters"),
             Self::UnboundDollar => anyhow!("Invalid Key Expr `{s}`: `$` is only allowed in `$*`")
         };
-        zerror!((self) error).into()
+        zerror!(kind = KeyExprInvalid, (self) error).into()
     }
 }
 
@@ -998,7 +998,10 @@ impl<'a> TryFrom<&'a keyexpr> for &'a nonwild_keyexpr {
     type Error = ZError;
     fn try_from(value: &'a keyexpr) -> Result<Self, Self::Error> {
         if value.is_wild_impl() {
-            bail!("nonwild_keyexpr can not contain any wild chunks")
+            bail!(
+                kind = KeyExprInvalid,
+                "nonwild_keyexpr can not contain any wild chunks"
+            )
         }
         // SAFETY: upheld by the surrounding invariants and prior validation.
         Ok(unsafe { core::mem::transmute::<&keyexpr, &nonwild_keyexpr>(value) })
@@ -233,6 +233,42 @@ impl<'s, Storage: IKeFormatStorage<'s> + 's> KeFormat<'s, Storage> {
             values: Storage::values_storage(&self.storage, |_| None),
         }
     }
+
+    /// Builds a key expression from `values`, a sequence of `id`-value pairs.
+    ///
+    /// This is a shorthand for setting each value on a [formatter](KeFormat::formatter) and
+    /// [building](KeFormatter::build) it. Specs with a default value may be omitted.
+    ///
+    /// # Errors
+    /// If an `id` isn't part of the format, if a value isn't included by its spec's pattern,
+    /// or if a spec without default value is missing from `values`.
+    ///
+    /// # Examples
+    /// ```
+    /// use zenoh_keyexpr::format::KeFormat;
+    ///
+    /// let format = KeFormat::new("factory/${line:*}/machine/${id:*}/state").unwrap();
+    /// let ke = format.format([("line", "3"), ("id", "m12")]).unwrap();
+    /// assert_eq!(ke.as_str(), "factory/3/machine/m12/state");
+    /// assert!(format.format([("line", "3")]).is_err());
+    /// ```
+    pub fn format<I, K, V>(&'s self, values: I) -> ZResult<OwnedKeyExpr>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: Display,
+    {
+        let mut formatter = self.formatter();
+        for (id, value) in values {
+            let id = id.as_ref();
+            if let Err(e) = formatter.set(id, &value) {
+                // Boxed explicitly, since `Error: From<FormatSetError>` requires `std`
+                let e: Error = Box::new(e);
+                bail!(e => "Invalid value `{value}` for field `{id}` of {self}")
+            }
+        }
+        formatter.build()
+    }
 }
 impl<'s, Storage: IKeFormatStorage<'s> + 's> TryFrom<&'s String> for KeFormat<'s, Storage> {
     type Error = Error;
@@ -630,5 +666,39 @@ fn formatting() {
     assert_eq!(ke.as_str(), "a/1/b/c");
 }
 
+#[test]
+fn formatting_from_values() {
+    let format = KeFormat::new("factory/${line:*}/machine/${id:*}/${rest:**}").unwrap();
+    let ke = format
+        .format([("line", "3"), ("id", "m12"), ("rest", "state/temp")])
+        .unwrap();
+    assert_eq!(ke.as_str(), "factory/3/machine/m12/state/temp");
+    let ke = format
+        .format([("line", "3"), ("id", "m12"), ("rest", "")])
+        .unwrap();
+    assert_eq!(ke.as_str(), "factory/3/machine/m12");
+    // Missing field
+    assert!(format.format([("line", "3"), ("id", "m12")]).is_err());
+    // Unknown field
+    assert!(format
+        .format([("line", "3"), ("id", "m12"), ("rest", ""), ("other", "x")])
+        .is_err());
+    // Values not matching their spec's pattern
+    assert!(format
+        .format([("line", "3/4"), ("id", "m12"), ("rest", "")])
+        .is_err());
+    assert!(format
+        .format([("line", ""), ("id", "m12"), ("rest", "")])
+        .is_err());
+    // Defaults may be omitted
+    let format = KeFormat::new("factory/${line:*#1}/state").unwrap();
+    let ke = format.format::<_, &str, &str>([]).unwrap();
+    assert_eq!(ke.as_str(), "factory/1/state");
+    // Specs must be valid patterns
+    assert!(KeFormat::new("factory/${line:}/state").is_err());
+    assert!(KeFormat::new("factory/${line:a//b}/state").is_err());
+    assert!(KeFormat::new("factory/${line:a?b}/state").is_err());
+}
+
 mod parsing;
 pub use parsing::{Iter, Parsed};
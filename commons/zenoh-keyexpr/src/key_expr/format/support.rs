@@ -32,7 +32,9 @@ impl<'a> TryFrom<&'a str> for Spec<'a> {
             bail!("Spec {spec} didn't contain `:`")
         };
         let pattern_start = id_end + 1;
-        let pattern_end = spec[pattern_start..].find('#').unwrap_or(u16::MAX as usize);
+        let pattern_end = spec[pattern_start..]
+            .find('#')
+            .map_or(u16::MAX as usize, |i| pattern_start + i);
         if pattern_start < spec.len() {
             let Ok(id_end) = id_end.try_into() else {
                 bail!("Spec {spec} contains an id longer than {}", u16::MAX)
@@ -288,7 +288,7 @@ fn keformat_support(source: &str) -> proc_macro2::TokenStream {
         let get_id = quote::format_ident!("{}", id);
         let pattern = unsafe {
             keyexpr::from_str_unchecked(if spec.pattern_end != u16::MAX {
-                &source[(spec.id_end as usize + 1)..(spec.spec_start + spec.pattern_end as usize)]
+                &source[(spec.id_end as usize + 1)..(spec.pattern_end as usize)]
             } else {
                 &source[(spec.id_end as usize + 1)..]
             })
@@ -197,6 +197,11 @@ impl<'a> Metadata<'a> {
     pub const PRIORITIES: &'static str = "prio";
     pub const MULTISTREAM: &'static str = "multistream";
     pub const MIXED_RELIABILITY: &'static str = "mixed_rel";
+    /// Whether the datagrams of a UDP unicast link are acknowledged and retransmitted.
+    ///
+    /// A listener advertises it in its locators. A connector must only set it when the listener
+    /// advertises it, as it prefixes its datagrams from the first one.
+    pub const FRAG_ACK: &'static str = "frag_ack";
 
     pub fn as_str(&self) -> &'a str {
         self.0
@@ -460,6 +465,9 @@ impl fmt::Debug for ConfigMut<'_> {
 /// to listen on or connect to.
 ///
 /// A full [`EndPoint`] string is hence in the form of `<proto>/<address>[?<metadata>][#config]`.
+/// The metadata and config keys being sorted, endpoints that only differ by the order of their
+/// entries are equal. [`EndPoint::builder`] writes the entries as is and rejects the characters
+/// that would break this structure.
 ///
 /// ## Metadata
 ///
@@ -564,6 +572,63 @@ impl EndPoint {
         self.clone().into()
     }
 
+    /// Returns an [`EndPointBuilder`] for an endpoint with the given protocol and address.
+    ///
+    /// # Examples
+    /// ```
+    /// use zenoh_protocol::core::EndPoint;
+    ///
+    /// let endpoint = EndPoint::builder("udp", "224.0.0.224:7447")
+    ///     .metadata("rel", "0")
+    ///     .config("iface", "eth0")
+    ///     .config("join", "224.0.0.1|224.0.0.2")
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     endpoint.as_str(),
+    ///     "udp/224.0.0.224:7447?rel=0#iface=eth0;join=224.0.0.1|224.0.0.2"
+    /// );
+    /// assert_eq!(endpoint.metadata_get::<u8>("rel").unwrap(), Some(0));
+    /// ```
+    pub fn builder<A, B>(protocol: A, address: B) -> EndPointBuilder
+    where
+        A: AsRef<str>,
+        B: AsRef<str>,
+    {
+        EndPointBuilder {
+            protocol: protocol.as_ref().to_owned(),
+            address: address.as_ref().to_owned(),
+            metadata: Vec::new(),
+            config: Vec::new(),
+        }
+    }
+
+    /// Returns the metadata value of the key parsed as a `T`, or `Ok(None)` if the key is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value can not be parsed as a `T`.
+    pub fn metadata_get<T>(&self, k: &str) -> ZResult<Option<T>>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        get_entry(self.metadata().as_str(), k)
+    }
+
+    /// Returns the config value of the key parsed as a `T`, or `Ok(None)` if the key is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value can not be parsed as a `T`.
+    pub fn config_get<T>(&self, k: &str) -> ZResult<Option<T>>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        get_entry(self.config().as_str(), k)
+    }
+
     /// Constructs an uninitialized empty EndPoint.
     #[zenoh_macros::internal]
     pub fn empty() -> Self {
@@ -573,6 +638,131 @@ impl EndPoint {
     }
 }
 
+fn get_entry<T>(s: &str, k: &str) -> ZResult<Option<T>>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let Some(value) = parameters::get(s, k) else {
+        return Ok(None);
+    };
+    value
+        .parse()
+        .map(Some)
+        .map_err(|e| zerror!("Invalid value `{value}` for `{k}`: {e}").into())
+}
+
+/// A builder of [`EndPoint`], returned by [`EndPoint::builder`].
+///
+/// The metadata and config entries are written as is, since the link protocols read them
+/// without decoding. `#`, `;` and `?` would break the structure of the endpoint and are thus
+/// rejected in both keys and values, as are `=` and `|` in keys, and `#` and `?` in the protocol
+/// and the address. `|` is kept in values as the separator of multiple values, and `=` is kept in
+/// values since an entry is split at its first `=`, e.g. for base64-encoded values.
+///
+/// Inserting an entry replaces any previous entry with the same key, and the entries are sorted
+/// by key when building, so the order of the calls has no effect on the built endpoint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EndPointBuilder {
+    protocol: String,
+    address: String,
+    metadata: Vec<(String, String)>,
+    config: Vec<(String, String)>,
+}
+
+impl EndPointBuilder {
+    /// Sets a metadata entry of the endpoint.
+    pub fn metadata<K, V>(mut self, k: K, v: V) -> Self
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        Self::insert(&mut self.metadata, k.as_ref(), v.as_ref());
+        self
+    }
+
+    /// Sets a config entry of the endpoint.
+    pub fn config<K, V>(mut self, k: K, v: V) -> Self
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        Self::insert(&mut self.config, k.as_ref(), v.as_ref());
+        self
+    }
+
+    /// Builds the [`EndPoint`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the protocol or the address are invalid, if a key is empty, if the
+    /// protocol, the address, a key or a value contains a reserved character, or if the endpoint
+    /// is too big.
+    pub fn build(self) -> ZResult<EndPoint> {
+        const RESERVED: [char; 2] = [METADATA_SEPARATOR, CONFIG_SEPARATOR];
+
+        if let Some(c) = self
+            .protocol
+            .chars()
+            .find(|c| *c == PROTO_SEPARATOR || RESERVED.contains(c))
+        {
+            bail!(
+                "Endpoint protocol `{}` contains the reserved character `{c}`",
+                self.protocol
+            );
+        }
+        if let Some(c) = self.address.chars().find(|c| RESERVED.contains(c)) {
+            bail!(
+                "Endpoint address `{}` contains the reserved character `{c}`",
+                self.address
+            );
+        }
+        EndPoint::new(
+            self.protocol,
+            self.address,
+            Self::encode(&self.metadata)?,
+            Self::encode(&self.config)?,
+        )
+    }
+
+    fn insert(entries: &mut Vec<(String, String)>, k: &str, v: &str) {
+        entries.retain(|(key, _)| key != k);
+        entries.push((k.to_owned(), v.to_owned()));
+    }
+
+    fn encode(entries: &[(String, String)]) -> ZResult<String> {
+        const RESERVED: [char; 3] = [
+            METADATA_SEPARATOR,
+            CONFIG_SEPARATOR,
+            parameters::LIST_SEPARATOR,
+        ];
+        const RESERVED_IN_KEY: [char; 2] =
+            [parameters::FIELD_SEPARATOR, parameters::VALUE_SEPARATOR];
+
+        let mut s = String::new();
+        for (k, v) in entries {
+            if let Some(c) = k
+                .chars()
+                .find(|c| RESERVED.contains(c) || RESERVED_IN_KEY.contains(c))
+            {
+                bail!("Endpoint key `{k}` contains the reserved character `{c}`");
+            }
+            if let Some(c) = v.chars().find(|c| RESERVED.contains(c)) {
+                bail!("Endpoint value `{v}` of `{k}` contains the reserved character `{c}`");
+            }
+            if !s.is_empty() {
+                s.push(parameters::LIST_SEPARATOR);
+            }
+            s.push_str(k);
+            if !v.is_empty() {
+                s.push(parameters::FIELD_SEPARATOR);
+                s.push_str(v);
+            }
+        }
+        Ok(s)
+    }
+}
+
 impl From<Locator> for EndPoint {
     fn from(val: Locator) -> Self {
         val.0
@@ -1052,3 +1242,238 @@ fn endpoint() {
     assert_eq!(i.next(), Some("224.0.0.3"));
     assert_eq!(i.next(), None);
 }
+
+#[test]
+fn endpoint_builder() {
+    type Entries<'a> = &'a [(&'a str, &'a str)];
+
+    const PEM: &str =
+        "-----BEGIN CERTIFICATE-----\nMIIB+zCCAaGgAwIBAgI=\n-----END CERTIFICATE-----";
+    const TLS: Entries = &[
+        ("root_ca_certificate_file", "/etc/zenoh/ca cert.pem"),
+        ("root_ca_certificate_raw", PEM),
+        ("root_ca_certificate_base64", "TUlJQit6Q0NBYUdn/A+=="),
+        ("listen_private_key_file", "C:\\zenoh\\key.pem"),
+        ("listen_private_key_raw", PEM),
+        ("listen_private_key_base64", "a2V5+/=="),
+        ("listen_certificate_file", "cert.pem"),
+        ("listen_certificate_raw", PEM),
+        ("listen_certificate_base64", "Y2VydA=="),
+        ("connect_private_key_file", "key.pem"),
+        ("connect_private_key_raw", PEM),
+        ("connect_private_key_base64", "a2V5"),
+        ("connect_certificate_file", "cert.pem"),
+        ("connect_certificate_raw", PEM),
+        ("connect_certificate_base64", "Y2VydA=="),
+        ("enable_mtls", "true"),
+        ("verify_name_on_connect", "false"),
+        ("close_link_on_expiration", "true"),
+        ("tls_handshake_timeout_ms", "5000"),
+    ];
+    const QUIC: Entries = &[
+        ("initial_mtu", "1200"),
+        ("mtu_discovery_interval_secs", "10"),
+    ];
+    const METADATA: Entries = &[
+        ("prio", "1-3"),
+        ("rel", "0"),
+        ("multistream", "true"),
+        ("mixed_rel", "false"),
+    ];
+    let quic: Vec<(&str, &str)> = TLS.iter().chain(QUIC).copied().collect();
+    let cases: &[(&str, &str, Entries, Entries)] = &[
+        (
+            "tcp",
+            "127.0.0.1:7447",
+            METADATA,
+            &[
+                ("iface", "Ethernet 2"),
+                ("bind", "127.0.0.1:0"),
+                ("so_sndbuf", "65536"),
+                ("so_rcvbuf", "65536"),
+                ("dscp", "0x08"),
+            ],
+        ),
+        ("tls", "localhost:7447", METADATA, TLS),
+        ("quic", "localhost:7447", METADATA, &quic),
+        ("quic_datagram", "localhost:7447", METADATA, &quic),
+        (
+            "udp",
+            "224.0.0.224:7447",
+            &[("rel", "0")],
+            &[
+                ("iface", "eth0"),
+                ("join", "224.0.0.1|224.0.0.2"),
+                ("ttl", "8"),
+            ],
+        ),
+        (
+            "serial",
+            "/dev/ttyUSB0",
+            &[],
+            &[
+                ("baudrate", "115200"),
+                ("exclusive", "true"),
+                ("tout", "50000"),
+                ("release_on_close", "true"),
+            ],
+        ),
+        ("unixpipe", "/tmp/zenoh", &[], &[("file_mask", "0o777")]),
+        ("unixsock-stream", "/tmp/zenoh.sock", METADATA, &[]),
+        ("vsock", "VMADDR_CID_ANY:VMADDR_PORT_ANY", &[], &[]),
+        ("ws", "127.0.0.1:8080", METADATA, &[("bind", "127.0.0.1:0")]),
+    ];
+
+    for (protocol, address, metadata, config) in cases {
+        let build = |reverse: bool| {
+            let mut builder = EndPoint::builder(protocol, address);
+            let mut metadata = metadata.to_vec();
+            let mut config = config.to_vec();
+            if reverse {
+                metadata.reverse();
+                config.reverse();
+            }
+            for (k, v) in metadata {
+                builder = builder.metadata(k, v);
+            }
+            for (k, v) in config {
+                builder = builder.config(k, v);
+            }
+            builder.build().unwrap()
+        };
+        let endpoint = build(false);
+        assert_eq!(endpoint, build(true), "{endpoint}");
+        assert_eq!(endpoint.protocol().as_str(), *protocol);
+        assert_eq!(endpoint.address().as_str(), *address);
+        assert_eq!(endpoint.to_string().parse::<EndPoint>().unwrap(), endpoint);
+        assert_eq!(
+            endpoint
+                .to_string()
+                .parse::<EndPoint>()
+                .unwrap()
+                .to_string(),
+            endpoint.to_string()
+        );
+        for (k, v) in metadata.iter() {
+            assert_eq!(
+                endpoint.metadata_get::<String>(k).unwrap().as_deref(),
+                Some(*v)
+            );
+            assert_eq!(endpoint.metadata().get(k), Some(*v));
+        }
+        for (k, v) in config.iter() {
+            assert_eq!(
+                endpoint.config_get::<String>(k).unwrap().as_deref(),
+                Some(*v)
+            );
+            // The link protocols read the values as is: they must not be escaped.
+            assert_eq!(endpoint.config().get(k), Some(*v));
+        }
+        let locator = endpoint.to_locator();
+        for (k, v) in metadata.iter() {
+            assert_eq!(
+                locator.metadata_get::<String>(k).unwrap().as_deref(),
+                Some(*v)
+            );
+        }
+    }
+
+    // Typed getters
+    let endpoint = EndPoint::builder("udp", "224.0.0.224:7447")
+        .metadata("rel", "1")
+        .config("ttl", "8")
+        .config("iface", "eth0")
+        .build()
+        .unwrap();
+    assert_eq!(endpoint.metadata_get::<u8>("rel").unwrap(), Some(1));
+    assert_eq!(endpoint.config_get::<u32>("ttl").unwrap(), Some(8));
+    assert_eq!(endpoint.config_get::<u32>("missing").unwrap(), None);
+    assert!(endpoint.config_get::<u32>("iface").is_err());
+
+    // Setting a key again replaces its value.
+    let endpoint = EndPoint::builder("tcp", "127.0.0.1:7447")
+        .config("iface", "eth0")
+        .config("iface", "eth1")
+        .build()
+        .unwrap();
+    assert_eq!(endpoint.as_str(), "tcp/127.0.0.1:7447#iface=eth1");
+
+    // The entries are written as is, as the link protocols read them.
+    let endpoint = EndPoint::builder("tcp", "127.0.0.1:7447")
+        .metadata("a", "100%")
+        .config("k", "x=y|z")
+        .config("root_ca_certificate_file", "C:\\%APPDATA%\\ca%20cert.pem")
+        .build()
+        .unwrap();
+    assert_eq!(
+        endpoint.as_str(),
+        "tcp/127.0.0.1:7447?a=100%#k=x=y|z;root_ca_certificate_file=C:\\%APPDATA%\\ca%20cert.pem"
+    );
+    assert_eq!(
+        endpoint.metadata_get::<String>("a").unwrap().as_deref(),
+        Some("100%")
+    );
+    assert_eq!(
+        endpoint.config().get("root_ca_certificate_file"),
+        Some("C:\\%APPDATA%\\ca%20cert.pem")
+    );
+    assert_eq!(endpoint.config().values("k").count(), 2);
+    assert_eq!(endpoint.to_string().parse::<EndPoint>().unwrap(), endpoint);
+
+    // An entry is split at its first `=`, so values may contain `=`.
+    let endpoint = EndPoint::builder("tls", "localhost:7447")
+        .metadata("a", "b=c")
+        .config("root_ca_certificate_base64", "Y2E==")
+        .build()
+        .unwrap();
+    assert_eq!(
+        endpoint.as_str(),
+        "tls/localhost:7447?a=b=c#root_ca_certificate_base64=Y2E=="
+    );
+    assert_eq!(
+        endpoint.metadata_get::<String>("a").unwrap().as_deref(),
+        Some("b=c")
+    );
+    assert_eq!(
+        endpoint.config().get("root_ca_certificate_base64"),
+        Some("Y2E==")
+    );
+
+    // The characters breaking the endpoint structure are rejected.
+    for (k, v) in [
+        ("k", "1;2"),
+        ("k", "1#2"),
+        ("k", "1?2"),
+        ("a=b", "1"),
+        ("a|b", "1"),
+        ("a;b", "1"),
+    ] {
+        assert!(EndPoint::builder("tcp", "127.0.0.1:7447")
+            .metadata(k, v)
+            .build()
+            .is_err());
+        assert!(EndPoint::builder("tcp", "127.0.0.1:7447")
+            .config(k, v)
+            .build()
+            .is_err());
+    }
+
+    // The characters breaking the endpoint structure are rejected in the protocol and address,
+    // instead of being parsed as metadata or config.
+    for (protocol, address) in [
+        ("tcp", "127.0.0.1:7447#iface=eth0"),
+        ("tcp", "127.0.0.1:7447?prio=1"),
+        ("tcp?a=b", "127.0.0.1:7447"),
+        ("tcp/udp", "127.0.0.1:7447"),
+    ] {
+        let error = EndPoint::builder(protocol, address).build().unwrap_err();
+        assert!(error.to_string().contains("reserved character"), "{error}");
+    }
+
+    // Invalid endpoints
+    assert!(EndPoint::builder("", "127.0.0.1:7447").build().is_err());
+    assert!(EndPoint::builder("tcp", "127.0.0.1:7447")
+        .config("", "1")
+        .build()
+        .is_err());
+}
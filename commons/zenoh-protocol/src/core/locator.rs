@@ -61,6 +61,19 @@ impl Locator {
         self.0.metadata_mut()
     }
 
+    /// Returns the metadata value of the key parsed as a `T`, or `Ok(None)` if the key is absent.
+    /// See [`EndPoint::metadata_get`].
+    ///
+    /// Locators with metadata can be built with [`EndPoint::builder`] and
+    /// [`EndPoint::to_locator`].
+    pub fn metadata_get<T>(&self, k: &str) -> ZResult<Option<T>>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.0.metadata_get(k)
+    }
+
     pub fn as_str(&self) -> &str {
         self.0.as_str()
     }
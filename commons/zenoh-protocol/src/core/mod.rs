@@ -56,7 +56,7 @@ pub mod resolution;
 pub use resolution::*;
 
 pub mod parameters;
-pub use parameters::Parameters;
+pub use parameters::{ParamSchema, Parameters};
 
 pub mod region;
 pub use region::*;
@@ -626,6 +626,11 @@ pub enum CongestionControl {
     /// progress, but only for the first message sent with this strategy; other messages will be
     /// dropped.
     BlockFirst = 2,
+    #[cfg(feature = "unstable")]
+    /// When transmitting a message in a node with a full queue, the node will wait for queue to
+    /// progress up to a timeout chosen by the publisher, after which the message is dropped and
+    /// the publication fails. This is a local policy: on the wire it is conveyed as [`Block`](Self::Block).
+    BlockWithTimeout = 3,
 }
 
 impl CongestionControl {
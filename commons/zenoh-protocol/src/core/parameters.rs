@@ -22,13 +22,19 @@
 /// `|` is the separator between multiple elements of the values.
 use alloc::{
     borrow::Cow,
+    boxed::Box,
+    collections::BTreeMap,
+    format,
     string::{String, ToString},
+    vec,
     vec::Vec,
 };
-use core::{borrow::Borrow, fmt};
+use core::{borrow::Borrow, fmt, ops::Range, str::FromStr};
 #[cfg(feature = "std")]
 use std::collections::HashMap;
 
+use zenoh_result::{bail, zerror, ZResult};
+
 pub(super) const LIST_SEPARATOR: char = ';';
 pub(super) const FIELD_SEPARATOR: char = '=';
 pub(super) const VALUE_SEPARATOR: char = '|';
@@ -197,6 +203,74 @@ where
     }
 }
 
+/// Decodes the `%XX` escapes of `s`, failing on a malformed escape or on a decoded sequence that
+/// is not valid UTF-8.
+fn percent_decode(s: &str) -> ZResult<String> {
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut iter = s.bytes();
+    while let Some(b) = iter.next() {
+        if b != b'%' {
+            bytes.push(b);
+            continue;
+        }
+        let hex = |b: Option<u8>| b.and_then(|b| char::from(b).to_digit(16));
+        match (hex(iter.next()), hex(iter.next())) {
+            (Some(h), Some(l)) => bytes.push((h << 4 | l) as u8),
+            _ => bail!("Malformed percent-encoding in `{s}`"),
+        }
+    }
+    String::from_utf8(bytes)
+        .map_err(|_| zerror!("Percent-decoding of `{s}` is not valid UTF-8").into())
+}
+
+/// Returns `true` for the characters escaped by the canonical percent-encoding of
+/// [`Parameters::reencode`]: the characters that are not printable ASCII characters, and `%`,
+/// `#`, `;`, `=`, `?` and `|`.
+fn is_canonically_escaped(c: char) -> bool {
+    !c.is_ascii_graphic() || matches!(c, '%' | '#' | ';' | '=' | '?' | '|')
+}
+
+/// Returns `true` for the characters escaped by [`Parameters::to_url_query`]: the characters
+/// allowed in an RFC 3986 query component are kept, except for `&`, `=`, `+` and `;`, which
+/// delimit or encode the fields of the usual query formats.
+fn is_url_query_escaped(c: char) -> bool {
+    !(c.is_ascii_alphanumeric()
+        || matches!(
+            c,
+            '-' | '.'
+                | '_'
+                | '~'
+                | '!'
+                | '$'
+                | '\''
+                | '('
+                | ')'
+                | '*'
+                | ','
+                | ':'
+                | '@'
+                | '/'
+                | '?'
+        ))
+}
+
+/// Writes `s` into `into`, percent-encoding with uppercase hexadecimal digits the UTF-8 bytes of
+/// every character for which `escape` returns `true`.
+fn percent_encode_into(s: &str, into: &mut String, escape: impl Fn(char) -> bool) {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+    for c in s.chars() {
+        if !escape(c) {
+            into.push(c);
+            continue;
+        }
+        for b in c.encode_utf8(&mut [0; 4]).bytes() {
+            into.push('%');
+            into.push(char::from(HEX[usize::from(b >> 4)]));
+            into.push(char::from(HEX[usize::from(b & 0xF)]));
+        }
+    }
+}
+
 #[cfg(feature = "test")]
 #[doc(hidden)]
 pub fn rand(into: &mut String) {
@@ -241,6 +315,13 @@ pub fn rand(into: &mut String) {
 /// (including across mutations of other keys). Mutating that key via [`Parameters::insert`] or
 /// [`Parameters::remove`] rebuilds the string and thus collapses/removes its entries.
 ///
+/// Iteration order is deterministic: [`Parameters::iter`] and the [`Display`](fmt::Display)
+/// implementation follow the order of the entries in the underlying string, i.e. their insertion
+/// order. [`Parameters::insert`] appends the entry at the end, moving it there if the key was
+/// already present, while [`Parameters::remove`], [`Parameters::remove_many`] and
+/// [`Parameters::retain`] preserve the relative order of the remaining entries. Use
+/// [`Parameters::iter_sorted`] for an order independent of the construction sequence.
+///
 /// Example:
 /// ```
 /// use zenoh_protocol::core::Parameters;
@@ -303,6 +384,134 @@ impl<'s> Parameters<'s> {
         super::parameters::get(self.as_str(), k.borrow())
     }
 
+    /// Returns the value corresponding to the key, or `None` if the key is absent or its value
+    /// is `wildcard`.
+    ///
+    /// This is intended for queryables (e.g. discovery ones) accepting a wildcard value meaning
+    /// "match all": both `key=*` and the absence of `key` then mean that no filter applies.
+    ///
+    /// # Examples
+    /// ```
+    /// use zenoh_protocol::core::Parameters;
+    ///
+    /// let parameters = Parameters::from("kind=router;zid=*");
+    /// assert_eq!(parameters.get_or_wildcard("kind", "*"), Some("router"));
+    /// assert_eq!(parameters.get_or_wildcard("zid", "*"), None);
+    /// assert_eq!(parameters.get_or_wildcard("locator", "*"), None);
+    /// ```
+    pub fn get_or_wildcard(&self, key: &str, wildcard: &str) -> Option<&str> {
+        super::parameters::get(self.as_str(), key).filter(|v| *v != wildcard)
+    }
+
+    /// Returns the range corresponding to the key, parsed from a value of the form `start..end`.
+    ///
+    /// Returns `Ok(None)` if the key is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not of the form `start..end`, if `start` or `end` can not
+    /// be parsed as a `T`, or if `start` is greater than `end`.
+    ///
+    /// # Examples
+    /// ```
+    /// use zenoh_protocol::core::Parameters;
+    ///
+    /// let parameters = Parameters::from("rows=5..20");
+    /// assert_eq!(parameters.get_range::<u32>("rows").unwrap(), Some(5..20));
+    /// assert_eq!(parameters.get_range::<u32>("cols").unwrap(), None);
+    /// ```
+    pub fn get_range<T>(&self, k: &str) -> ZResult<Option<Range<T>>>
+    where
+        T: FromStr + PartialOrd,
+        T::Err: fmt::Display,
+    {
+        let Some(value) = super::parameters::get(self.as_str(), k) else {
+            return Ok(None);
+        };
+        let Some((start, end)) = value.split_once("..") else {
+            bail!("Invalid range for parameter `{k}`: `{value}` is not of the form `start..end`");
+        };
+        let start: T = start
+            .parse()
+            .map_err(|e| zerror!("Invalid range start for parameter `{k}`: `{start}` - {e}"))?;
+        let end: T = end
+            .parse()
+            .map_err(|e| zerror!("Invalid range end for parameter `{k}`: `{end}` - {e}"))?;
+        if start > end {
+            bail!("Invalid range for parameter `{k}`: `{value}` has its start after its end");
+        }
+        Ok(Some(start..end))
+    }
+
+    /// Checks that all the `keys` are present in these parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the first of the `keys` that is missing.
+    ///
+    /// # Examples
+    /// ```
+    /// use zenoh_protocol::core::Parameters;
+    ///
+    /// let parameters = Parameters::from("id=42;verbose");
+    /// assert!(parameters.require(&["id", "verbose"]).is_ok());
+    /// assert!(parameters.require(&["id", "name"]).is_err());
+    /// ```
+    pub fn require(&self, keys: &[&str]) -> ZResult<()> {
+        match keys.iter().find(|k| !self.contains_key(**k)) {
+            Some(k) => bail!("Missing required parameter `{k}`"),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks these parameters against a [`ParamSchema`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error listing all the missing required keys, the keys absent from the schema
+    /// and the values rejected by their validator.
+    ///
+    /// # Examples
+    /// ```
+    /// use zenoh_protocol::core::{ParamSchema, Parameters};
+    ///
+    /// let schema = ParamSchema::new()
+    ///     .required("id")
+    ///     .validator("limit", |v| v.parse::<u32>().map(|_| ()).map_err(|e| e.into()));
+    /// assert!(Parameters::from("id=42;limit=10").validate(&schema).is_ok());
+    /// assert!(Parameters::from("limit=10").validate(&schema).is_err());
+    /// assert!(Parameters::from("id=42;verbose").validate(&schema).is_err());
+    /// assert!(Parameters::from("id=42;limit=ten").validate(&schema).is_err());
+    /// ```
+    pub fn validate(&self, schema: &ParamSchema) -> ZResult<()> {
+        let mut errors = Vec::new();
+        for k in schema
+            .required
+            .iter()
+            .filter(|k| !self.contains_key(k.as_str()))
+        {
+            errors.push(format!("missing required parameter `{k}`"));
+        }
+        let mut seen = Vec::new();
+        for (k, v) in self.iter() {
+            if seen.contains(&k) {
+                continue;
+            }
+            seen.push(k);
+            if !schema.contains_key(k) {
+                errors.push(format!("unexpected parameter `{k}`"));
+            } else if let Some((_, validator)) = schema.validators.iter().find(|(vk, _)| vk == k) {
+                if let Err(e) = validator(v) {
+                    errors.push(format!("invalid value `{v}` for parameter `{k}`: {e}"));
+                }
+            }
+        }
+        if !errors.is_empty() {
+            bail!("Invalid parameters: {}", errors.join(", "));
+        }
+        Ok(())
+    }
+
     /// Returns an iterator to the `&str`-values corresponding to the key.
     pub fn values<K>(&'s self, k: K) -> impl DoubleEndedIterator<Item = &'s str>
     where
@@ -316,6 +525,38 @@ impl<'s> Parameters<'s> {
         super::parameters::iter(self.as_str())
     }
 
+    /// Returns an iterator on the key-value pairs as `(&str, &str)`, sorted by key.
+    ///
+    /// The order of the underlying string is left untouched.
+    pub fn iter_sorted(&'s self) -> impl Iterator<Item = (&'s str, &'s str)> {
+        super::parameters::sort(self.iter())
+    }
+
+    /// Returns an iterator on the percent-decoded key-value pairs as `(String, String)`.
+    ///
+    /// Each pair is decoded lazily, when the iterator reaches it. A pair with a malformed escape,
+    /// or an escaped sequence that is not valid UTF-8, yields an error without affecting the
+    /// other pairs.
+    ///
+    /// ```
+    /// use zenoh_protocol::core::Parameters;
+    ///
+    /// let parameters = Parameters::from("a=x%20y;b=%G1;%63=3");
+    /// let mut iter = parameters.iter_decoded();
+    /// assert_eq!(iter.next().unwrap().unwrap(), ("a".to_string(), "x y".to_string()));
+    /// assert!(iter.next().unwrap().is_err());
+    /// assert_eq!(iter.next().unwrap().unwrap(), ("c".to_string(), "3".to_string()));
+    /// assert!(iter.next().is_none());
+    /// ```
+    pub fn iter_decoded(&'s self) -> impl Iterator<Item = ZResult<(String, String)>> + 's {
+        self.iter().map(|(k, v)| {
+            let k = percent_decode(k)?;
+            let v =
+                percent_decode(v).map_err(|e| zerror!("Invalid value for parameter `{k}`: {e}"))?;
+            Ok((k, v))
+        })
+    }
+
     /// Inserts a key-value pair into the map.
     /// If the map did not have this key present, [`None`] is returned.
     /// If the map did have this key present, the value is updated, and the old value is returned.
@@ -330,6 +571,38 @@ impl<'s> Parameters<'s> {
         item
     }
 
+    /// Inserts a key-value pair into the map, unless the serialized parameters would then exceed
+    /// `max_total_len` bytes.
+    ///
+    /// On success, behaves like [`Parameters::insert`]. On failure, an error is returned and the
+    /// parameters are left unchanged.
+    ///
+    /// ```
+    /// use zenoh_protocol::core::Parameters;
+    ///
+    /// let mut parameters = Parameters::from("a=1");
+    /// assert!(parameters.insert_bounded("b", "2", 7).is_ok());
+    /// assert!(parameters.insert_bounded("c", "3", 7).is_err());
+    /// assert_eq!(parameters.as_str(), "a=1;b=2");
+    /// ```
+    pub fn insert_bounded<K, V>(&mut self, k: K, v: V, max_total_len: usize) -> ZResult<()>
+    where
+        K: Borrow<str>,
+        V: Borrow<str>,
+    {
+        let (inner, _) = super::parameters::insert(self.as_str(), k.borrow(), v.borrow());
+        if inner.len() > max_total_len {
+            bail!(
+                "Inserting parameter `{}` would grow the parameters to {} bytes, over the limit of {} bytes",
+                k.borrow(),
+                inner.len(),
+                max_total_len
+            );
+        }
+        self.0 = Cow::Owned(inner);
+        Ok(())
+    }
+
     /// Removes a key from the map, returning the value at the key if the key was previously in the parameters.
     pub fn remove<K>(&mut self, k: K) -> Option<String>
     where
@@ -341,6 +614,43 @@ impl<'s> Parameters<'s> {
         item
     }
 
+    /// Removes every entry whose key is one of `keys`, preserving the order of the remaining entries.
+    ///
+    /// ```
+    /// use zenoh_protocol::core::Parameters;
+    ///
+    /// let mut parameters = Parameters::from("a=1;b=2;c=3;b=4");
+    /// parameters.remove_many(["b", "c", "missing"]);
+    /// assert_eq!(parameters.as_str(), "a=1");
+    /// ```
+    pub fn remove_many<I, K>(&mut self, keys: I)
+    where
+        I: IntoIterator<Item = K>,
+        K: Borrow<str>,
+    {
+        let keys = keys.into_iter().collect::<Vec<K>>();
+        self.retain(|k, _| !keys.iter().any(|key| key.borrow() == k));
+    }
+
+    /// Retains only the entries for which `f` returns `true`, preserving their order.
+    ///
+    /// `f` is called once per entry, in iteration order, including for every duplicate of a key.
+    ///
+    /// ```
+    /// use zenoh_protocol::core::Parameters;
+    ///
+    /// let mut parameters = Parameters::from("_internal=1;a=1;_hidden;b=2");
+    /// parameters.retain(|k, _| !k.starts_with('_'));
+    /// assert_eq!(parameters.as_str(), "a=1;b=2");
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&str, &str) -> bool,
+    {
+        let inner = super::parameters::from_iter(self.iter().filter(|(k, v)| f(k, v)));
+        self.0 = Cow::Owned(inner);
+    }
+
     /// Extend these parameters with other parameters.
     pub fn extend(&mut self, other: &Parameters) {
         self.extend_from_iter(other.iter());
@@ -365,10 +675,245 @@ impl<'s> Parameters<'s> {
         Parameters(Cow::Owned(self.0.into_owned()))
     }
 
+    /// Convert these parameters into owned parameters using the canonical percent-encoding.
+    ///
+    /// Each key and each `|`-separated value is percent-decoded, then re-encoded so that only
+    /// the bytes that are not printable ASCII characters, and the `%`, `#`, `;`, `=`, `?` and
+    /// `|` characters, are escaped, with uppercase hexadecimal digits. This normalizes parameters
+    /// that were encoded inconsistently by different sources, e.g. `%61=1` and `a=1`. The order
+    /// of the entries, including duplicates, is preserved.
+    ///
+    /// Fails if an entry contains a malformed escape, or an escaped sequence that is not valid
+    /// UTF-8.
+    ///
+    /// Example:
+    /// ```
+    /// use zenoh_protocol::core::Parameters;
+    ///
+    /// let p = Parameters::from("%61=1;b=x%7cy|z;c=%e2%82%ac");
+    /// assert_eq!(p.reencode().unwrap().as_str(), "a=1;b=x%7Cy|z;c=%E2%82%AC");
+    /// assert!(Parameters::from("a=%G1").reencode().is_err());
+    /// ```
+    pub fn reencode(&self) -> ZResult<Parameters<'static>> {
+        let mut inner = String::with_capacity(self.0.len());
+        for (k, v) in self.iter() {
+            let k = percent_decode(k)?;
+            if k.is_empty() {
+                continue;
+            }
+            if !inner.is_empty() {
+                inner.push(LIST_SEPARATOR);
+            }
+            percent_encode_into(&k, &mut inner, is_canonically_escaped);
+            if v.is_empty() {
+                continue;
+            }
+            inner.push(FIELD_SEPARATOR);
+            for (i, value) in v.split(VALUE_SEPARATOR).enumerate() {
+                if i > 0 {
+                    inner.push(VALUE_SEPARATOR);
+                }
+                percent_encode_into(&percent_decode(value)?, &mut inner, is_canonically_escaped);
+            }
+        }
+        Ok(Parameters(Cow::Owned(inner)))
+    }
+
+    /// Returns the RFC 3986 query component representing these parameters, without the leading `?`.
+    ///
+    /// Each key and each `|`-separated value is percent-decoded, then percent-encoded following
+    /// the RFC 3986 query rules: a space is encoded as `%20`, never as `+`, and `&`, `=`, `+`
+    /// and `;` are escaped. The entries are separated with `&`, and a key with several values
+    /// is repeated once per value. An entry that cannot be percent-decoded is encoded verbatim.
+    ///
+    /// See [`Parameters::from_url_query`] for the reverse conversion.
+    ///
+    /// Example:
+    /// ```
+    /// use zenoh_protocol::core::Parameters;
+    ///
+    /// let p = Parameters::from("q=hello%20world;tag=a|b;flag");
+    /// assert_eq!(p.to_url_query(), "q=hello%20world&tag=a&tag=b&flag");
+    /// assert_eq!(Parameters::from("a=1+1").to_url_query(), "a=1%2B1");
+    /// ```
+    pub fn to_url_query(&self) -> String {
+        let encode = |s: &str, into: &mut String| match percent_decode(s) {
+            Ok(decoded) => percent_encode_into(&decoded, into, is_url_query_escaped),
+            Err(_) => percent_encode_into(s, into, is_url_query_escaped),
+        };
+        let mut query = String::with_capacity(self.0.len());
+        for (k, v) in self.iter().filter(|(k, _)| !k.is_empty()) {
+            if v.is_empty() {
+                if !query.is_empty() {
+                    query.push('&');
+                }
+                encode(k, &mut query);
+                continue;
+            }
+            for value in v.split(VALUE_SEPARATOR) {
+                if !query.is_empty() {
+                    query.push('&');
+                }
+                encode(k, &mut query);
+                query.push('=');
+                encode(value, &mut query);
+            }
+        }
+        query
+    }
+
+    /// Parses the parameters from an RFC 3986 query component, with or without its leading `?`.
+    ///
+    /// The query is split into `&`-separated `key=value` fields, which are percent-decoded. As
+    /// the RFC 3986 query rules, and unlike the `application/x-www-form-urlencoded` format, a
+    /// `+` is kept as is rather than decoded as a space. The values of a key repeated in several
+    /// fields are gathered into its `|`-separated values, at the position of its first field.
+    /// Keys and values are stored with the canonical percent-encoding of [`Parameters::reencode`].
+    ///
+    /// Fails if a field contains a malformed escape, or an escaped sequence that is not valid
+    /// UTF-8.
+    ///
+    /// Example:
+    /// ```
+    /// use zenoh_protocol::core::Parameters;
+    ///
+    /// let p = Parameters::from_url_query("?q=hello%20world&tag=a&tag=b&sum=1+1").unwrap();
+    /// assert_eq!(p.as_str(), "q=hello%20world;tag=a|b;sum=1+1");
+    /// assert_eq!(p.values("tag").collect::<Vec<_>>(), ["a", "b"]);
+    /// assert!(Parameters::from_url_query("a=%G1").is_err());
+    /// ```
+    pub fn from_url_query(query: &str) -> ZResult<Parameters<'static>> {
+        let query = query.strip_prefix('?').unwrap_or(query);
+        let mut fields: Vec<(String, Vec<String>)> = Vec::new();
+        for field in query.split('&').filter(|f| !f.is_empty()) {
+            let (k, v) = split_once(field, FIELD_SEPARATOR);
+            let k = percent_decode(k)?;
+            if k.is_empty() {
+                continue;
+            }
+            let v = percent_decode(v)
+                .map_err(|e| zerror!("Invalid value for query field `{k}`: {e}"))?;
+            match fields.iter_mut().find(|(key, _)| *key == k) {
+                Some((_, values)) => values.push(v),
+                None => fields.push((k, vec![v])),
+            }
+        }
+
+        let mut inner = String::with_capacity(query.len());
+        for (k, values) in fields {
+            if !inner.is_empty() {
+                inner.push(LIST_SEPARATOR);
+            }
+            percent_encode_into(&k, &mut inner, is_canonically_escaped);
+            let values: Vec<String> = values.into_iter().filter(|v| !v.is_empty()).collect();
+            if values.is_empty() {
+                continue;
+            }
+            inner.push(FIELD_SEPARATOR);
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    inner.push(VALUE_SEPARATOR);
+                }
+                percent_encode_into(value, &mut inner, is_canonically_escaped);
+            }
+        }
+        Ok(Parameters(Cow::Owned(inner)))
+    }
+
     /// Returns `true` if all keys are sorted in alphabetical order.
     pub fn is_ordered(&self) -> bool {
         super::parameters::is_ordered(self.as_str())
     }
+
+    /// Returns the entries of these parameters that are absent from `other` or have a different value there.
+    ///
+    /// Keys are compared using the value returned by [`Parameters::get`], i.e. the first
+    /// occurrence of a key: later duplicates of a key are ignored in both `self` and `other`.
+    /// The entries are returned in the order they appear in `self`.
+    pub fn difference(&self, other: &Parameters) -> Parameters<'static> {
+        let mut seen = Vec::new();
+        let inner = super::parameters::from_iter(self.iter().filter(|(k, v)| {
+            if seen.contains(k) {
+                return false;
+            }
+            seen.push(*k);
+            other.get(*k) != Some(*v)
+        }));
+        Parameters(Cow::Owned(inner))
+    }
+}
+
+type ParamValidator = Box<dyn Fn(&str) -> ZResult<()> + Send + Sync>;
+
+/// The expected keys and values of [`Parameters`], checked by [`Parameters::validate`].
+///
+/// The keys declared with [`required`](ParamSchema::required), [`allowed`](ParamSchema::allowed)
+/// or [`validator`](ParamSchema::validator) are the only ones accepted.
+///
+/// # Examples
+/// ```
+/// use zenoh_protocol::core::ParamSchema;
+///
+/// let schema = ParamSchema::new()
+///     .required("id")
+///     .allowed("verbose")
+///     .validator("limit", |v| v.parse::<u32>().map(|_| ()).map_err(|e| e.into()));
+/// ```
+#[derive(Default)]
+pub struct ParamSchema {
+    required: Vec<String>,
+    allowed: Vec<String>,
+    validators: Vec<(String, ParamValidator)>,
+}
+
+impl ParamSchema {
+    /// Creates a schema accepting no key.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a key which must be present.
+    pub fn required<K: Into<String>>(mut self, k: K) -> Self {
+        self.required.push(k.into());
+        self
+    }
+
+    /// Adds a key which may be present.
+    pub fn allowed<K: Into<String>>(mut self, k: K) -> Self {
+        self.allowed.push(k.into());
+        self
+    }
+
+    /// Adds a `validator` checking the value of the key when it is present.
+    ///
+    /// The key is allowed, and may additionally be declared [`required`](ParamSchema::required).
+    pub fn validator<K, F>(mut self, k: K, validator: F) -> Self
+    where
+        K: Into<String>,
+        F: Fn(&str) -> ZResult<()> + Send + Sync + 'static,
+    {
+        self.validators.push((k.into(), Box::new(validator)));
+        self
+    }
+
+    fn contains_key(&self, k: &str) -> bool {
+        self.required.iter().any(|r| r == k)
+            || self.allowed.iter().any(|a| a == k)
+            || self.validators.iter().any(|(v, _)| v == k)
+    }
+}
+
+impl fmt::Debug for ParamSchema {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ParamSchema")
+            .field("required", &self.required)
+            .field("allowed", &self.allowed)
+            .field(
+                "validators",
+                &self.validators.iter().map(|(k, _)| k).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
 }
 
 impl<'s> From<&'s str> for Parameters<'s> {
@@ -486,6 +1031,22 @@ impl From<Parameters<'_>> for HashMap<String, String> {
     }
 }
 
+/// Collects the entries sorted by key.
+///
+/// When a key occurs several times, the last value wins, unlike [`Parameters::get`] which
+/// returns the first one.
+impl From<&Parameters<'_>> for BTreeMap<String, String> {
+    fn from(props: &Parameters<'_>) -> Self {
+        BTreeMap::from_iter(props.iter().map(|(k, v)| (k.to_string(), v.to_string())))
+    }
+}
+
+impl From<Parameters<'_>> for BTreeMap<String, String> {
+    fn from(props: Parameters) -> Self {
+        BTreeMap::from(&props)
+    }
+}
+
 impl fmt::Display for Parameters<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -551,6 +1112,57 @@ mod tests {
         assert_eq!(Parameters::from(hm), Parameters::from("p1=v1"));
     }
 
+    fn validation_schema() -> ParamSchema {
+        ParamSchema::new()
+            .required("id")
+            .allowed("verbose")
+            .validator("limit", |v| match v.parse::<u32>() {
+                Ok(_) => Ok(()),
+                Err(e) => bail!("{e}"),
+            })
+    }
+
+    #[test]
+    fn validate_accepts_valid_parameters() {
+        let schema = validation_schema();
+        assert!(Parameters::from("id=42").validate(&schema).is_ok());
+        assert!(Parameters::from("limit=10;verbose;id=42")
+            .validate(&schema)
+            .is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_unexpected_key() {
+        let error = Parameters::from("id=42;extra=1")
+            .validate(&validation_schema())
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("unexpected parameter `extra`"), "{error}");
+    }
+
+    #[test]
+    fn validate_rejects_missing_required_key() {
+        let error = Parameters::from("verbose")
+            .validate(&validation_schema())
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("missing required parameter `id`"), "{error}");
+    }
+
+    #[test]
+    fn validate_reports_all_errors() {
+        let error = Parameters::from("limit=ten;extra")
+            .validate(&validation_schema())
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("missing required parameter `id`"), "{error}");
+        assert!(
+            error.contains("invalid value `ten` for parameter `limit`"),
+            "{error}"
+        );
+        assert!(error.contains("unexpected parameter `extra`"), "{error}");
+    }
+
     #[test]
     fn values_iterator_for_non_existing_key_is_empty() {
         let params = Parameters::from("p1=1");
@@ -558,6 +1170,53 @@ mod tests {
         assert_eq!(params.values("p2").next(), None);
     }
 
+    #[test]
+    fn test_iter_sorted() {
+        let params = Parameters::from("c=3;a=1;d=4;b=2");
+
+        assert_eq!(
+            params.iter_sorted().collect::<Vec<_>>(),
+            vec![("a", "1"), ("b", "2"), ("c", "3"), ("d", "4")]
+        );
+        assert_eq!(params.as_str(), "c=3;a=1;d=4;b=2");
+        assert!(!params.is_ordered());
+    }
+
+    #[test]
+    fn test_into_btreemap() {
+        let params = Parameters::from("c=3;a=1;b;a=2");
+
+        // Entries are sorted by key, the last value of a duplicated key wins
+        let map = BTreeMap::from(&params);
+        assert_eq!(
+            map.iter()
+                .map(|(k, v)| (k.as_str(), v.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("a", "2"), ("b", ""), ("c", "3")]
+        );
+        assert_eq!(params.get("a"), Some("1"));
+        assert_eq!(BTreeMap::from(params), map);
+    }
+
+    #[test]
+    fn test_difference() {
+        let a = Parameters::from("a=1;b=2;c=3;d");
+        let b = Parameters::from("b=2;c=4;e=5");
+
+        // `a` and `d` are missing from `b`, `c` has a different value
+        assert_eq!(a.difference(&b).as_str(), "a=1;c=3;d");
+        // `c` has a different value, `e` is missing from `a`
+        assert_eq!(b.difference(&a).as_str(), "c=4;e=5");
+        assert!(a.difference(&a).is_empty());
+        assert_eq!(a.difference(&Parameters::empty()), a);
+
+        // Only the first occurrence of a key is considered
+        let a = Parameters::from("a=1;a=2;b=1");
+        let b = Parameters::from("a=1;b=2;b=1");
+        assert_eq!(a.difference(&b).as_str(), "b=1");
+        assert_eq!(b.difference(&a).as_str(), "b=2");
+    }
+
     #[test]
     fn test_remove() {
         // Entries preceding the removed key are preserved.
@@ -577,4 +1236,194 @@ mod tests {
         assert_eq!(params.remove("missing"), None);
         assert_eq!(params.as_str(), "b=2;c=3");
     }
+
+    #[test]
+    fn test_retain() {
+        // Reserved parameters are dropped, duplicates of retained keys are kept in order.
+        let mut params = Parameters::from("_ttl=1;a=1;_flag;b=2;a=3");
+        params.retain(|k, _| !k.starts_with('_'));
+        assert_eq!(params.as_str(), "a=1;b=2;a=3");
+        assert_eq!(params.get("a"), Some("1"));
+
+        // Values can be matched as well.
+        params.retain(|_, v| v != "2");
+        assert_eq!(params.as_str(), "a=1;a=3");
+
+        params.retain(|_, _| false);
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_remove_many() {
+        let mut params = Parameters::from("d=4;a=1;b=2;c=3;a=5");
+        params.remove_many(["a", "c", "missing"]);
+        assert_eq!(params.as_str(), "d=4;b=2");
+        params.remove_many(Vec::<String>::new());
+        assert_eq!(params.as_str(), "d=4;b=2");
+        params.remove_many(vec!["b".to_string(), "d".to_string()]);
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_display_is_deterministic() {
+        let build = || {
+            let mut params = Parameters::empty();
+            params.insert("z", "1");
+            params.insert("a", "2");
+            params.insert("m", "3");
+            params.insert("z", "4");
+            params.remove("m");
+            params.insert("_hidden", "5");
+            params.retain(|k, _| !k.starts_with('_'));
+            params
+        };
+        // Insertion order, a re-inserted key moving to the end.
+        assert_eq!(build().to_string(), "a=2;z=4");
+        assert_eq!(build().to_string(), build().to_string());
+        assert_eq!(
+            build().iter().collect::<Vec<_>>(),
+            vec![("a", "2"), ("z", "4")]
+        );
+    }
+
+    #[test]
+    fn test_insert_bounded() {
+        let mut params = Parameters::from("a=1");
+        // "a=1;b=2" is exactly 7 bytes long.
+        params.insert_bounded("b", "2", 7).unwrap();
+        assert_eq!(params.as_str(), "a=1;b=2");
+        // Replacing a value with a shorter one always fits.
+        params.insert_bounded("b", "", 7).unwrap();
+        assert_eq!(params.as_str(), "a=1;b");
+
+        let err = params.insert_bounded("c", "333", 7).unwrap_err();
+        assert!(err.to_string().contains("over the limit of 7 bytes"));
+        assert_eq!(params.as_str(), "a=1;b");
+        assert!(params.insert_bounded("a", "1111", 7).is_err());
+        assert_eq!(params.as_str(), "a=1;b");
+    }
+
+    #[test]
+    fn test_require() {
+        let params = Parameters::from("a=1;b;c=3");
+        assert!(params.require(&[]).is_ok());
+        assert!(params.require(&["a", "b", "c"]).is_ok());
+
+        let err = params.require(&["a", "d", "e"]).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Missing required parameter `d`"));
+        assert!(Parameters::empty().require(&["a"]).is_err());
+    }
+
+    #[test]
+    fn test_url_query() {
+        // Spaces are encoded as `%20`, and `+` is escaped rather than taken as a space.
+        assert_eq!(
+            Parameters::from("q=hello world;sum=1+1").to_url_query(),
+            "q=hello%20world&sum=1%2B1"
+        );
+        assert_eq!(
+            Parameters::from_url_query("q=hello%20world&sum=1+1")
+                .unwrap()
+                .get("sum"),
+            Some("1+1")
+        );
+        // Escaped parameters are decoded first, and the query delimiters are escaped.
+        assert_eq!(
+            Parameters::from("k%3Db=v%3B1&2;path=/a/b?c;é=€").to_url_query(),
+            "k%3Db=v%3B1%262&path=/a/b?c&%C3%A9=%E2%82%AC"
+        );
+        // Multiple values are repeated keys, gathered back at the first occurrence.
+        assert_eq!(
+            Parameters::from("a=1|2;flag;b=3").to_url_query(),
+            "a=1&a=2&flag&b=3"
+        );
+        assert_eq!(
+            Parameters::from_url_query("?a=1&b=3&a=2&&flag=")
+                .unwrap()
+                .as_str(),
+            "a=1|2;b=3;flag"
+        );
+        // Round-trips of canonically encoded parameters.
+        for p in [
+            "",
+            "a=1",
+            "q=hello%20world;tag=x|y%7Cz;flag;sum=1+1",
+            "k%3Db=v%3B1;path=/a/b%3Fc%23d;%C3%A9=%E2%82%AC%25",
+        ] {
+            let p = Parameters::from(p);
+            assert_eq!(Parameters::from_url_query(&p.to_url_query()).unwrap(), p);
+        }
+        // Malformed escapes are encoded verbatim, and rejected when parsing.
+        assert_eq!(Parameters::from("a=%G1").to_url_query(), "a=%25G1");
+        assert!(Parameters::from_url_query("a=%G1").is_err());
+        assert!(Parameters::from_url_query("a=%ff").is_err());
+    }
+
+    #[test]
+    fn test_reencode() {
+        // Over-encoded input normalizes to its canonical form.
+        assert_eq!(
+            Parameters::from("%61=1").reencode().unwrap(),
+            Parameters::from("a=1")
+        );
+        // Lowercase escapes are uppercased, and the reserved characters stay escaped.
+        assert_eq!(
+            Parameters::from("k%3db=v%3b1|v%7c2;flag;x=%20")
+                .reencode()
+                .unwrap()
+                .as_str(),
+            "k%3Db=v%3B1|v%7C2;flag;x=%20"
+        );
+        // Unescaped characters that must be escaped are encoded.
+        assert_eq!(
+            Parameters::from("p 2=v#1;é=€").reencode().unwrap().as_str(),
+            "p%202=v%231;%C3%A9=%E2%82%AC"
+        );
+        // Duplicates and order are preserved, and the result is stable.
+        let p = Parameters::from("b=%322;a=1;b=3").reencode().unwrap();
+        assert_eq!(p.as_str(), "b=22;a=1;b=3");
+        assert_eq!(p.reencode().unwrap(), p);
+        // Malformed escapes and invalid UTF-8 are rejected.
+        assert!(Parameters::from("a=%4").reencode().is_err());
+        assert!(Parameters::from("a=%zz").reencode().is_err());
+        assert!(Parameters::from("a=%FF").reencode().is_err());
+    }
+
+    #[test]
+    fn test_get_or_wildcard() {
+        let params = Parameters::from("kind=router;zid=*;any=all;empty");
+        // Concrete value
+        assert_eq!(params.get_or_wildcard("kind", "*"), Some("router"));
+        // Wildcard value
+        assert_eq!(params.get_or_wildcard("zid", "*"), None);
+        assert_eq!(params.get_or_wildcard("any", "all"), None);
+        assert_eq!(params.get_or_wildcard("any", "*"), Some("all"));
+        // Absent key
+        assert_eq!(params.get_or_wildcard("locator", "*"), None);
+        // A key without value is not a wildcard
+        assert_eq!(params.get_or_wildcard("empty", "*"), Some(""));
+    }
+
+    #[test]
+    fn test_get_range() {
+        let params = Parameters::from("rows=5..20;empty=3..3;inverted=20..5;limit=10;bad=a..b");
+        assert_eq!(params.get_range::<u32>("rows").unwrap(), Some(5..20));
+        assert_eq!(params.get_range::<u32>("empty").unwrap(), Some(3..3));
+        assert_eq!(params.get_range::<f64>("rows").unwrap(), Some(5.0..20.0));
+        assert_eq!(params.get_range::<u32>("missing").unwrap(), None);
+
+        // Inverted range
+        assert!(params.get_range::<u32>("inverted").is_err());
+        // Malformed values
+        assert!(params.get_range::<u32>("limit").is_err());
+        assert!(params.get_range::<u32>("bad").is_err());
+        assert!(Parameters::from("rows=5..")
+            .get_range::<u32>("rows")
+            .is_err());
+        assert!(Parameters::from("rows=-1..2")
+            .get_range::<u32>("rows")
+            .is_err());
+    }
 }
@@ -193,6 +193,12 @@ pub mod common {
         /// +---------------+
         /// ```
         pub type WireExprExt = zextzbuf!(0x0f, true);
+
+        pub mod flag {
+            pub const N: u8 = 1; // 0x01 Named         if N==1 then the key expr has name/suffix
+            pub const M: u8 = 1 << 1; // 0x02 Mapping       if M==1 then key expr mapping is the one declared by the sender, else it is the one declared by the receiver
+        }
+
         #[derive(Debug, Clone, PartialEq, Eq)]
         pub struct WireExprType {
             pub wire_expr: WireExpr<'static>,
@@ -336,8 +342,6 @@ pub mod subscriber {
     /// +---------------+
     /// ~  [decl_exts]  ~  if Z==1
     /// +---------------+
-    ///
-    /// - if R==1 then the subscription is reliable, else it is best effort    ///
     /// ```
     #[derive(Debug, Clone, PartialEq, Eq)]
     pub struct DeclareSubscriber {
@@ -426,7 +430,6 @@ pub mod queryable {
     /// ~  [decl_exts]  ~  if Z==1
     /// +---------------+
     ///
-    /// - if R==1 then the queryable is reliable, else it is best effort
     /// - if P==1 then the queryable is pull, else it is push
     /// - if C==1 then the queryable is complete and the N parameter is present
     /// - if D==1 then the queryable distance is present
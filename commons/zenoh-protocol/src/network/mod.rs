@@ -491,6 +491,8 @@ pub mod ext {
             match congestion_control {
                 CongestionControl::Block => inner |= Self::D_FLAG,
                 #[cfg(feature = "unstable")]
+                CongestionControl::BlockWithTimeout => inner |= Self::D_FLAG,
+                #[cfg(feature = "unstable")]
                 CongestionControl::BlockFirst => inner |= Self::F_FLAG,
                 _ => {}
             }
@@ -510,6 +512,11 @@ pub mod ext {
 
         pub fn set_congestion_control(&mut self, cctrl: CongestionControl) {
             match cctrl {
+                #[cfg(feature = "unstable")]
+                CongestionControl::BlockWithTimeout => {
+                    self.inner = imsg::set_flag(self.inner, Self::D_FLAG);
+                    self.inner = imsg::unset_flag(self.inner, Self::F_FLAG);
+                }
                 CongestionControl::Block => {
                     self.inner = imsg::set_flag(self.inner, Self::D_FLAG);
                     self.inner = imsg::unset_flag(self.inner, Self::F_FLAG);
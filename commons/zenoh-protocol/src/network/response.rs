@@ -57,10 +57,11 @@ pub struct Response {
     pub ext_tstamp: Option<ext::TimestampType>,
     pub ext_respid: Option<ext::ResponderIdType>,
     pub ext_ts_stack: Option<ext::TsStackType>,
+    pub ext_replier_qos: Option<ext::ReplierQoS>,
 }
 
 pub mod ext {
-    use crate::{zextz64, zextzbuf};
+    use crate::{zextunit, zextz64, zextzbuf};
     pub type QoS = zextz64!(0x1, false);
     pub type QoSType = crate::network::ext::QoSType<{ QoS::ID }>;
 
@@ -72,6 +73,11 @@ pub mod ext {
 
     pub type TsStack = zextzbuf!(0x7, false);
     pub type TsStackType = crate::network::timestamp_stack::TsStackType<{ TsStack::ID }>;
+
+    /// # Replier QoS extension
+    /// Indicate that the priority and express policy of the QoS extension were set by the
+    /// replier instead of inherited from the query
+    pub type ReplierQoS = zextunit!(0x4, false);
 }
 
 impl Response {
@@ -100,6 +106,7 @@ impl Response {
         let ext_tstamp = rng.gen_bool(0.5).then(ext::TimestampType::rand);
         let ext_respid = rng.gen_bool(0.5).then(ext::ResponderIdType::rand);
         let ext_ts_stack = rng.gen_bool(0.5).then(ext::TsStackType::rand);
+        let ext_replier_qos = rng.gen_bool(0.5).then(ext::ReplierQoS::rand);
 
         Self {
             rid,
@@ -109,6 +116,7 @@ impl Response {
             ext_tstamp,
             ext_respid,
             ext_ts_stack,
+            ext_replier_qos,
         }
     }
 }
@@ -75,19 +75,59 @@ pub mod flag {
 /// +---------------+
 /// ```
 ///
+/// The optional timestamp extension carries the time of the sender HLC when the [`KeepAlive`]
+/// was sent. It allows the receiver to estimate the skew between its clock and the sender one.
+///
+/// NOTE: [`KeepAlive`] used to be a unit struct. Since it carries the timestamp extension, it is
+///       built with [`KeepAlive::new`] (or [`Default`]) instead of `KeepAlive`, and matched with
+///       `KeepAlive { .. }` instead of `KeepAlive`. The wire format is unchanged for a
+///       [`KeepAlive`] without timestamp.
+///
 /// NOTE: 16 bits (2 bytes) may be prepended to the serialized message indicating the total length
 ///       in bytes of the message, resulting in the maximum length of a message being 65535 bytes.
 ///       This is necessary in those stream-oriented transports (e.g., TCP) that do not preserve
 ///       the boundary of the serialized messages. The length is encoded as little-endian.
 ///       In any case, the length of a message must not exceed 65535 bytes.
 ///
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct KeepAlive;
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct KeepAlive {
+    pub ext_timestamp: Option<ext::Timestamp>,
+}
+
+// Extensions
+pub mod ext {
+    use crate::zextz64;
+
+    /// # Timestamp extension
+    /// The NTP64 time of the sender HLC
+    pub type Timestamp = zextz64!(0x1, false);
+}
 
 impl KeepAlive {
+    /// Creates a [`KeepAlive`] without timestamp, as the former unit struct.
+    pub const fn new() -> Self {
+        Self {
+            ext_timestamp: None,
+        }
+    }
+
+    /// Creates a [`KeepAlive`] carrying the NTP64 `time` of the sender HLC.
+    pub const fn with_timestamp(time: u64) -> Self {
+        Self {
+            ext_timestamp: Some(ext::Timestamp::new(time)),
+        }
+    }
+
     #[cfg(feature = "test")]
     #[doc(hidden)]
     pub fn rand() -> Self {
-        Self
+        use rand::Rng;
+
+        use crate::common::ZExtZ64;
+
+        let mut rng = rand::thread_rng();
+        let ext_timestamp = rng.gen_bool(0.5).then_some(ZExtZ64::rand());
+
+        Self { ext_timestamp }
     }
 }
@@ -20,7 +20,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 extern crate alloc;
 
-use core::fmt;
+use core::{fmt, time::Duration};
 
 use anyhow::Error as AnyError;
 
@@ -118,11 +118,42 @@ impl NegativeI8 {
     pub const MIN: Self = Self::new(i8::MIN);
 }
 
+/// The category of a [`ZError`], letting the callers handle the failures programmatically.
+///
+/// The kind of an error wrapping another one with [`ZError::set_source`] defaults to the kind
+/// of its source, see [`ErrKind`].
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ZErrorKind {
+    /// An error with no specific category.
+    #[default]
+    Other,
+    /// A key expression or a selector is invalid.
+    KeyExprInvalid,
+    /// The operation cannot proceed because the session is closed.
+    SessionClosed,
+    /// An operation did not complete before its deadline.
+    Timeout,
+    /// A message was dropped because of congestion.
+    CongestionDrop,
+    /// A declaration or an undeclaration was refused.
+    DeclarationRefused,
+    /// A transport or a link could not be established or failed.
+    TransportFailure,
+    /// A plugin is incompatible with the host.
+    PluginIncompatible,
+    /// A configuration is invalid.
+    ConfigInvalid,
+    /// A quality of service cannot be used to send data.
+    QoSInvalid,
+}
+
 pub struct ZError {
     error: AnyError,
     file: &'static str,
     line: u32,
     errno: NegativeI8,
+    kind: ZErrorKind,
     source: Option<Error>,
 }
 
@@ -141,6 +172,7 @@ impl ZError {
             file,
             line,
             errno,
+            kind: ZErrorKind::Other,
             source: None,
         }
     }
@@ -148,6 +180,17 @@ impl ZError {
         self.source = Some(source.into());
         self
     }
+    pub fn with_kind(mut self, kind: ZErrorKind) -> Self {
+        self.kind = kind;
+        self
+    }
+    /// Returns the kind of this error, or the kind of its source if it has none.
+    pub fn kind(&self) -> ZErrorKind {
+        match (self.kind, &self.source) {
+            (ZErrorKind::Other, Some(source)) => source.kind(),
+            (kind, _) => kind,
+        }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -215,6 +258,69 @@ impl From<ShmError> for Error {
     }
 }
 
+// +---------------+
+// | SESSIONCLOSED |
+// +---------------+
+
+/// Error indicating the operation cannot proceed because the session is closed.
+///
+/// It may be returned by operations like `Session::get` or `Publisher::put` when
+/// `Session::close` has been called before.
+#[derive(Debug)]
+pub struct SessionClosedError;
+
+impl fmt::Display for SessionClosedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "session closed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SessionClosedError {}
+
+#[cfg(not(feature = "std"))]
+impl IError for SessionClosedError {}
+
+// +--------------+
+// | BLOCKTIMEOUT |
+// +--------------+
+
+/// The error returned when a blocking publication timed out.
+///
+/// A publication with `CongestionControl::BlockWithTimeout` could not be transmitted
+/// before its timeout expired: the message has been dropped for at least one destination.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockTimeoutError(Duration);
+
+impl BlockTimeoutError {
+    pub(crate) const fn new(timeout: Duration) -> Self {
+        Self(timeout)
+    }
+
+    /// Returns the timeout that expired.
+    pub fn timeout(&self) -> Duration {
+        self.0
+    }
+}
+
+impl fmt::Display for BlockTimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "publication blocked for more than {:?}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BlockTimeoutError {}
+
+#[cfg(not(feature = "std"))]
+impl IError for BlockTimeoutError {}
+
+/// Returns the error of a blocking publication which timed out after `timeout`, see
+/// [`BlockTimeoutError`].
+pub fn block_timeout_error(timeout: Duration) -> Error {
+    Box::new(BlockTimeoutError::new(timeout))
+}
+
 // +-------+
 // | ERRNO |
 // +-------+
@@ -295,6 +401,93 @@ impl ErrNo for dyn IError + Send + Sync {
     }
 }
 
+// +---------+
+// | ERRKIND |
+// +---------+
+
+/// Gives access to the [`ZErrorKind`] of the boxed errors returned by Zenoh.
+///
+/// The errors that are not categorized, or that do not come from Zenoh, are of kind
+/// [`ZErrorKind::Other`].
+pub trait ErrKind {
+    fn kind(&self) -> ZErrorKind;
+}
+
+impl ErrKind for ZError {
+    fn kind(&self) -> ZErrorKind {
+        ZError::kind(self)
+    }
+}
+
+impl ErrKind for ShmError {
+    fn kind(&self) -> ZErrorKind {
+        self.0.kind()
+    }
+}
+
+#[cfg(feature = "std")]
+impl ErrKind for dyn std::error::Error {
+    fn kind(&self) -> ZErrorKind {
+        if let Some(e) = self.downcast_ref::<ZError>() {
+            e.kind()
+        } else if let Some(e) = self.downcast_ref::<ShmError>() {
+            e.kind()
+        } else if self.is::<SessionClosedError>() {
+            ZErrorKind::SessionClosed
+        } else if self.is::<BlockTimeoutError>() {
+            ZErrorKind::CongestionDrop
+        } else {
+            // Foreign errors may wrap a categorized one
+            self.source().map_or(ZErrorKind::Other, |s| s.kind())
+        }
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl ErrKind for dyn IError {
+    fn kind(&self) -> ZErrorKind {
+        if let Some(e) = self.downcast_ref::<ZError>() {
+            e.kind()
+        } else if let Some(e) = self.downcast_ref::<ShmError>() {
+            e.kind()
+        } else if self.is::<SessionClosedError>() {
+            ZErrorKind::SessionClosed
+        } else if self.is::<BlockTimeoutError>() {
+            ZErrorKind::CongestionDrop
+        } else {
+            ZErrorKind::Other
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ErrKind for dyn std::error::Error + Send {
+    fn kind(&self) -> ZErrorKind {
+        (self as &dyn std::error::Error).kind()
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl ErrKind for dyn IError + Send {
+    fn kind(&self) -> ZErrorKind {
+        (self as &dyn IError).kind()
+    }
+}
+
+#[cfg(feature = "std")]
+impl ErrKind for dyn std::error::Error + Send + Sync {
+    fn kind(&self) -> ZErrorKind {
+        (self as &dyn std::error::Error).kind()
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl ErrKind for dyn IError + Send + Sync {
+    fn kind(&self) -> ZErrorKind {
+        (self as &dyn IError).kind()
+    }
+}
+
 // +--------+
 // | MACROS |
 // +--------+
@@ -302,6 +495,9 @@ impl ErrNo for dyn IError + Send + Sync {
 pub use anyhow::anyhow;
 #[macro_export]
 macro_rules! zerror {
+    (kind = $kind:ident, $($t: tt)*) => {
+        $crate::zerror!($($t)*).with_kind($crate::ZErrorKind::$kind)
+    };
     (($errno:expr) $source: expr => $($t: tt)*) => {
         $crate::ZError::new($crate::anyhow!($($t)*), file!(), line!(), $crate::NegativeI8::new($errno as i8)).set_source($source)
     };
@@ -18,18 +18,196 @@
 //!
 //! [Click here for Zenoh's documentation](https://docs.rs/zenoh/latest/zenoh)
 
-use std::{future::Future, time::Duration};
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, SystemTime},
+};
 
 use futures::future::FutureExt;
 use tokio::task::JoinHandle;
 use tokio_util::{sync::CancellationToken, task::TaskTracker};
-use zenoh_core::{ResolveFuture, Wait};
+use zenoh_core::{zlock, ResolveFuture, Wait};
 use zenoh_runtime::ZRuntime;
 
+/// The name of the tasks spawned without a name.
+pub const DEFAULT_TASK_NAME: &str = "zenoh:task";
+
+/// The number of independently locked shards of a [`TaskRegistry`].
+const REGISTRY_SHARDS: usize = 16;
+
+/// The name of a task, only formatted when the tasks are listed.
+#[derive(Debug, Clone)]
+pub struct TaskName(TaskNameInner);
+
+#[derive(Debug, Clone)]
+enum TaskNameInner {
+    Static(&'static str),
+    Owned(Arc<str>),
+    Hashed(&'static str, u64),
+}
+
+impl TaskName {
+    /// The name `prefix` followed by `hash` in hexadecimal, e.g. `zenoh:get:00f1c2a3b4d5e6f7`.
+    pub fn hashed(prefix: &'static str, hash: u64) -> Self {
+        Self(TaskNameInner::Hashed(prefix, hash))
+    }
+}
+
+impl fmt::Display for TaskName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            TaskNameInner::Static(name) => f.write_str(name),
+            TaskNameInner::Owned(name) => f.write_str(name),
+            TaskNameInner::Hashed(prefix, hash) => write!(f, "{prefix}{hash:016x}"),
+        }
+    }
+}
+
+impl From<&'static str> for TaskName {
+    fn from(name: &'static str) -> Self {
+        Self(TaskNameInner::Static(name))
+    }
+}
+
+impl From<String> for TaskName {
+    fn from(name: String) -> Self {
+        Self(TaskNameInner::Owned(name.into()))
+    }
+}
+
+impl From<Arc<str>> for TaskName {
+    fn from(name: Arc<str>) -> Self {
+        Self(TaskNameInner::Owned(name))
+    }
+}
+
+/// The name and spawn time of a live task listed by a [`TaskRegistry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskInfo {
+    name: Arc<str>,
+    spawned_at: SystemTime,
+}
+
+impl TaskInfo {
+    /// The name of the task, e.g. `zenoh:tx:tcp/127.0.0.1:7447`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The time the task was spawned at.
+    pub fn spawned_at(&self) -> SystemTime {
+        self.spawned_at
+    }
+}
+
+struct TaskEntry {
+    name: TaskName,
+    spawned_at: SystemTime,
+}
+
+#[derive(Default)]
+struct TaskRegistryInner {
+    next_id: AtomicU64,
+    shards: [Mutex<HashMap<u64, TaskEntry>>; REGISTRY_SHARDS],
+}
+
+impl TaskRegistryInner {
+    fn shard(&self, id: u64) -> &Mutex<HashMap<u64, TaskEntry>> {
+        &self.shards[id as usize % REGISTRY_SHARDS]
+    }
+}
+
+/// A registry of the live tasks, shared by the [`TaskController`]s of a Zenoh runtime.
+///
+/// A task is registered when spawned by a [`TaskController`] and unregistered when it
+/// completes or is aborted. The tasks are spread over several shards so that spawning tasks
+/// concurrently does not contend on a single lock.
+#[derive(Clone, Default)]
+pub struct TaskRegistry(Arc<TaskRegistryInner>);
+
+impl std::fmt::Debug for TaskRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TaskRegistry")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl TaskRegistry {
+    /// Registers a task named `name`, until the returned [`TaskRegistration`] is dropped.
+    pub fn register(&self, name: impl Into<TaskName>) -> TaskRegistration {
+        let id = self.0.next_id.fetch_add(1, Ordering::Relaxed);
+        zlock!(self.0.shard(id)).insert(
+            id,
+            TaskEntry {
+                name: name.into(),
+                spawned_at: SystemTime::now(),
+            },
+        );
+        TaskRegistration {
+            registry: self.clone(),
+            id,
+        }
+    }
+
+    /// Returns the live tasks, ordered by spawn time.
+    pub fn tasks(&self) -> Vec<TaskInfo> {
+        let mut tasks: Vec<(u64, TaskInfo)> = vec![];
+        for shard in &self.0.shards {
+            tasks.extend(zlock!(shard).iter().map(|(id, entry)| {
+                let info = TaskInfo {
+                    name: entry.name.to_string().into(),
+                    spawned_at: entry.spawned_at,
+                };
+                (*id, info)
+            }));
+        }
+        tasks.sort_unstable_by_key(|(id, _)| *id);
+        tasks.into_iter().map(|(_, info)| info).collect()
+    }
+
+    /// Returns the number of live tasks.
+    pub fn len(&self) -> usize {
+        self.0.shards.iter().map(|shard| zlock!(shard).len()).sum()
+    }
+
+    /// Returns `true` if there is no live task.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// The registration of a task in a [`TaskRegistry`], removed when dropped.
+pub struct TaskRegistration {
+    registry: TaskRegistry,
+    id: u64,
+}
+
+impl std::fmt::Debug for TaskRegistration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TaskRegistration")
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for TaskRegistration {
+    fn drop(&mut self) {
+        zlock!(self.registry.0.shard(self.id)).remove(&self.id);
+    }
+}
+
 #[derive(Clone)]
 pub struct TaskController {
     tracker: TaskTracker,
     token: CancellationToken,
+    registry: TaskRegistry,
 }
 
 impl std::fmt::Debug for TaskController {
@@ -42,14 +220,25 @@ impl std::fmt::Debug for TaskController {
 
 impl Default for TaskController {
     fn default() -> Self {
+        Self::with_registry(TaskRegistry::default())
+    }
+}
+
+impl TaskController {
+    /// Creates a controller registering its tasks in `registry`.
+    pub fn with_registry(registry: TaskRegistry) -> Self {
         TaskController {
             tracker: TaskTracker::new(),
             token: CancellationToken::new(),
+            registry,
         }
     }
-}
 
-impl TaskController {
+    /// The registry of the tasks spawned by this controller.
+    pub fn registry(&self) -> &TaskRegistry {
+        &self.registry
+    }
+
     /// Converts a task to abortable one, which can later be terminated by call to [`TaskController::terminate_all()`].
     pub fn into_abortable<'a, F, T>(&self, future: F) -> impl Future<Output = Option<T>> + Send + 'a
     where
@@ -59,6 +248,30 @@ impl TaskController {
         self.token.child_token().run_until_cancelled_owned(future)
     }
 
+    /// Spawns `future` registered as `name`, on `rt` or on the current runtime, naming the
+    /// tokio task as well when built with `--cfg tokio_unstable`.
+    fn spawn_registered<F, T>(
+        &self,
+        name: TaskName,
+        rt: Option<ZRuntime>,
+        future: F,
+    ) -> JoinHandle<T>
+    where
+        F: Future<Output = T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let registration = self.registry.register(name.clone());
+        let future = async move {
+            let _registration = registration;
+            future.await
+        };
+
+        #[cfg(feature = "tracing-instrument")]
+        let future = tracing::Instrument::instrument(future, tracing::Span::current());
+
+        spawn_tracked(&self.tracker, &name, rt, future)
+    }
+
     /// Spawns a task that can be later terminated by call to [`TaskController::terminate_all()`].
     /// Task output is ignored.
     pub fn spawn_abortable<F, T>(&self, future: F) -> JoinHandle<Option<T>>
@@ -66,10 +279,20 @@ impl TaskController {
         F: Future<Output = T> + Send + 'static,
         T: Send + 'static,
     {
-        #[cfg(feature = "tracing-instrument")]
-        let future = tracing::Instrument::instrument(future, tracing::Span::current());
+        self.spawn_abortable_named(DEFAULT_TASK_NAME, future)
+    }
 
-        self.tracker.spawn(self.into_abortable(future))
+    /// Same as [`TaskController::spawn_abortable()`], registering the task as `name`.
+    pub fn spawn_abortable_named<F, T>(
+        &self,
+        name: impl Into<TaskName>,
+        future: F,
+    ) -> JoinHandle<Option<T>>
+    where
+        F: Future<Output = T> + Send + 'static,
+        T: Send + 'static,
+    {
+        self.spawn_registered(name.into(), None, self.into_abortable(future))
     }
 
     /// Spawns a task using a specified runtime that can be later terminated by call to [`TaskController::terminate_all()`].
@@ -78,10 +301,21 @@ impl TaskController {
         F: Future<Output = T> + Send + 'static,
         T: Send + 'static,
     {
-        #[cfg(feature = "tracing-instrument")]
-        let future = tracing::Instrument::instrument(future, tracing::Span::current());
+        self.spawn_abortable_named_with_rt(DEFAULT_TASK_NAME, rt, future)
+    }
 
-        self.tracker.spawn_on(self.into_abortable(future), &rt)
+    /// Same as [`TaskController::spawn_abortable_with_rt()`], registering the task as `name`.
+    pub fn spawn_abortable_named_with_rt<F, T>(
+        &self,
+        name: impl Into<TaskName>,
+        rt: ZRuntime,
+        future: F,
+    ) -> JoinHandle<Option<T>>
+    where
+        F: Future<Output = T> + Send + 'static,
+        T: Send + 'static,
+    {
+        self.spawn_registered(name.into(), Some(rt), self.into_abortable(future))
     }
 
     pub fn get_cancellation_token(&self) -> CancellationToken {
@@ -97,10 +331,7 @@ impl TaskController {
         F: Future<Output = T> + Send + 'static,
         T: Send + 'static,
     {
-        #[cfg(feature = "tracing-instrument")]
-        let future = tracing::Instrument::instrument(future, tracing::Span::current());
-
-        self.tracker.spawn(future)
+        self.spawn_registered(TaskName::from(DEFAULT_TASK_NAME), None, future)
     }
 
     /// Spawns a task which can be cancelled via cancellation of a token obtained by [`TaskController::get_cancellation_token()`],
@@ -112,10 +343,21 @@ impl TaskController {
         F: Future<Output = T> + Send + 'static,
         T: Send + 'static,
     {
-        #[cfg(feature = "tracing-instrument")]
-        let future = tracing::Instrument::instrument(future, tracing::Span::current());
+        self.spawn_named_with_rt(DEFAULT_TASK_NAME, rt, future)
+    }
 
-        self.tracker.spawn_on(future, &rt)
+    /// Same as [`TaskController::spawn_with_rt()`], registering the task as `name`.
+    pub fn spawn_named_with_rt<F, T>(
+        &self,
+        name: impl Into<TaskName>,
+        rt: ZRuntime,
+        future: F,
+    ) -> JoinHandle<T>
+    where
+        F: Future<Output = T> + Send + 'static,
+        T: Send + 'static,
+    {
+        self.spawn_registered(name.into(), Some(rt), future)
     }
 
     /// Attempts tp terminate all previously spawned tasks
@@ -146,6 +388,39 @@ impl TaskController {
     }
 }
 
+/// Spawns `future` tracked by `tracker`, on `rt` or on the current runtime, naming the tokio
+/// task `name` when built with `--cfg tokio_unstable`.
+pub fn spawn_tracked<F, T>(
+    tracker: &TaskTracker,
+    name: &TaskName,
+    rt: Option<ZRuntime>,
+    future: F,
+) -> JoinHandle<T>
+where
+    F: Future<Output = T> + Send + 'static,
+    T: Send + 'static,
+{
+    let future = tracker.track_future(future);
+    #[cfg(tokio_unstable)]
+    {
+        let name = name.to_string();
+        let builder = tokio::task::Builder::new().name(&name);
+        match rt {
+            Some(rt) => builder.spawn_on(future, &rt),
+            None => builder.spawn(future),
+        }
+        .expect("failed to spawn task")
+    }
+    #[cfg(not(tokio_unstable))]
+    {
+        let _ = name;
+        match rt {
+            Some(rt) => rt.spawn(future),
+            None => tokio::spawn(future),
+        }
+    }
+}
+
 pub struct TerminatableTask {
     handle: Option<JoinHandle<()>>,
     token: CancellationToken,
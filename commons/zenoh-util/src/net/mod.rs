@@ -321,6 +321,41 @@ pub fn get_index_of_interface(addr: IpAddr) -> ZResult<u32> {
     }
 }
 
+/// Get the index of the network interface with the given name, e.g. to be used as the scope id of
+/// an IPv6 link-local address.
+pub fn get_index_of_interface_name(name: &str) -> ZResult<u32> {
+    #[cfg(unix)]
+    {
+        IFACES
+            .iter()
+            .find(|iface| iface.name == name)
+            .map(|iface| iface.index)
+            .ok_or_else(|| zerror!("Interface {name} not found").into())
+    }
+    #[cfg(windows)]
+    {
+        unsafe {
+            use winapi::um::iptypes::IP_ADAPTER_ADDRESSES_LH;
+
+            use crate::ffi;
+
+            let buffer = get_adapters_addresses(winapi::shared::ws2def::AF_UNSPEC)?;
+
+            let mut next_iface = (buffer.as_ptr() as *mut IP_ADAPTER_ADDRESSES_LH).as_ref();
+            while let Some(iface) = next_iface {
+                if name == ffi::pstr_to_string(iface.AdapterName)
+                    || name == ffi::pwstr_to_string(iface.FriendlyName)
+                    || name == ffi::pwstr_to_string(iface.Description)
+                {
+                    return Ok(iface.Ipv6IfIndex);
+                }
+                next_iface = iface.Next.as_ref();
+            }
+            bail!("Interface {name} not found")
+        }
+    }
+}
+
 pub fn get_interface_names_by_addr(addr: IpAddr) -> ZResult<Vec<String>> {
     #[cfg(unix)]
     {
@@ -445,13 +480,17 @@ pub fn get_ipv6_ipaddrs(interface: Option<&str>) -> Vec<IpAddr> {
 
 #[cfg(any(target_os = "linux", target_os = "android"))]
 pub fn set_bind_to_device_tcp_socket(socket: &TcpSocket, iface: &str) -> ZResult<()> {
-    socket.bind_device(Some(iface.as_bytes()))?;
+    socket
+        .bind_device(Some(iface.as_bytes()))
+        .map_err(|e| zerror!("Unable to bind the socket to interface {iface}: {e}"))?;
     Ok(())
 }
 
 #[cfg(any(target_os = "linux", target_os = "android"))]
 pub fn set_bind_to_device_udp_socket(socket: &UdpSocket, iface: &str) -> ZResult<()> {
-    socket.bind_device(Some(iface.as_bytes()))?;
+    socket
+        .bind_device(Some(iface.as_bytes()))
+        .map_err(|e| zerror!("Unable to bind the socket to interface {iface}: {e}"))?;
     Ok(())
 }
 
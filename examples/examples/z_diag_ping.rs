@@ -0,0 +1,89 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::time::Duration;
+
+use clap::Parser;
+use zenoh::{
+    config::ZenohId,
+    diagnostics::{ping, PingTarget},
+    key_expr::KeyExpr,
+    Config,
+};
+use zenoh_examples::CommonArgs;
+
+#[tokio::main]
+async fn main() {
+    // initiate logging
+    zenoh::init_log_from_env_or("error");
+
+    let (config, target, size, n, timeout) = parse_args();
+    let session = zenoh::open(config).await.unwrap();
+
+    println!("Pinging {target:?} with {n} probes of {size} bytes...");
+    let report = ping(&session, target, size, n)
+        .timeout(timeout)
+        .await
+        .unwrap();
+
+    for (i, rtt) in report.rtts().iter().enumerate() {
+        match rtt {
+            Some(rtt) => println!(
+                "{} bytes: seq={} rtt={:?}µs lat={:?}µs",
+                size,
+                i,
+                rtt.as_micros(),
+                rtt.as_micros() / 2
+            ),
+            None => println!("{size} bytes: seq={i} lost"),
+        }
+    }
+    println!(
+        "{} probes sent, {} lost ({:.1}% loss)",
+        report.sent(),
+        report.lost(),
+        report.loss() * 100.0
+    );
+}
+
+#[derive(Parser)]
+struct Args {
+    #[arg(long)]
+    /// The zid of the session to ping, all the sessions are pinged if not set
+    zid: Option<ZenohId>,
+    #[arg(short = 'n', long, default_value = "10")]
+    /// The number of probes to send
+    samples: usize,
+    #[arg(short, long, default_value = "1")]
+    /// The number of seconds after which a probe is considered lost (float)
+    timeout: f64,
+    /// Sets the size of the probe payload
+    payload_size: usize,
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+fn parse_args() -> (Config, PingTarget, usize, usize, Duration) {
+    let args = Args::parse();
+    let target = match args.zid {
+        Some(zid) => PingTarget::from(zid),
+        None => PingTarget::from(KeyExpr::try_from("@/ping/*").unwrap()),
+    };
+    (
+        args.common.into(),
+        target,
+        args.payload_size,
+        args.samples,
+        Duration::from_secs_f64(args.timeout),
+    )
+}
@@ -71,6 +71,9 @@ async fn main() {
             .await
             .unwrap();
         while let Ok(reply) = replies.recv_async().await {
+            let replier = reply
+                .replier_zid()
+                .map_or_else(|| "unknown".to_string(), |zid| zid.to_string());
             match reply.result() {
                 Ok(sample) => {
                     // Refer to z_bytes.rs to see how to deserialize different types of message
@@ -79,7 +82,7 @@ async fn main() {
                         .try_to_string()
                         .unwrap_or_else(|e| e.to_string().into());
                     println!(
-                        ">> Received ('{}': '{}')",
+                        ">> Received ('{}': '{}') from {replier}",
                         sample.key_expr().as_str(),
                         payload,
                     );
@@ -89,7 +92,7 @@ async fn main() {
                         .payload()
                         .try_to_string()
                         .unwrap_or_else(|e| e.to_string().into());
-                    println!(">> Received (ERROR: '{payload}')");
+                    println!(">> Received (ERROR: '{payload}') from {replier}");
                 }
             }
         }
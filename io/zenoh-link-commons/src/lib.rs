@@ -29,8 +29,15 @@ pub mod tcp;
 pub mod tls;
 mod unicast;
 
-use alloc::{borrow::ToOwned, boxed::Box, string::String, vec, vec::Vec};
-use core::{cmp::PartialEq, fmt, hash::Hash};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    format,
+    string::String,
+    vec,
+    vec::Vec,
+};
+use core::{cmp::PartialEq, fmt, hash::Hash, net::SocketAddr};
 
 use async_trait::async_trait;
 pub use dscp::*;
@@ -54,6 +61,43 @@ pub const TCP_SO_SND_BUF: &str = "so_sndbuf";
 pub const TCP_SO_RCV_BUF: &str = "so_rcvbuf";
 pub const DSCP: &str = "dscp";
 
+/// Replaces the interface name used as zone id of an IPv6 address (e.g. `[fe80::1%eth0]:7447`) by
+/// the index of this interface (e.g. `[fe80::1%2]:7447`), as socket address parsers only accept
+/// numeric zone ids.
+///
+/// Addresses without zone id or with a numeric zone id are returned untouched.
+pub fn resolve_ipv6_zone_id(address: &str) -> ZResult<Cow<'_, str>> {
+    let Some(start) = address.find('%') else {
+        return Ok(Cow::Borrowed(address));
+    };
+    let Some(len) = address[start..].find(']') else {
+        return Ok(Cow::Borrowed(address));
+    };
+    let zone = &address[start + 1..start + len];
+    if zone.is_empty() || zone.parse::<u32>().is_ok() {
+        return Ok(Cow::Borrowed(address));
+    }
+    let index = zenoh_util::net::get_index_of_interface_name(zone)?;
+    Ok(Cow::Owned(format!(
+        "{}{}{}",
+        &address[..=start],
+        index,
+        &address[start + len..]
+    )))
+}
+
+/// Returns the local address `bound` of a socket bound to `requested`, with the IPv6 zone id of
+/// `requested` if the system dropped it when binding (e.g. for the loopback address).
+pub fn keep_ipv6_zone_id(bound: SocketAddr, requested: &SocketAddr) -> SocketAddr {
+    match (bound, requested) {
+        (SocketAddr::V6(mut bound), SocketAddr::V6(requested)) if bound.scope_id() == 0 => {
+            bound.set_scope_id(requested.scope_id());
+            SocketAddr::V6(bound)
+        }
+        _ => bound,
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Hash, PartialEq, Eq)]
 pub struct Link {
     pub src: Locator,
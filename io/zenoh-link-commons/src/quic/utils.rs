@@ -44,7 +44,7 @@ use crate::{
         plaintext::{SkipServerVerification, SELF_SIGNED_CERT},
         unicast::MultiStreamConfig,
     },
-    tls::{config::*, WebPkiVerifierAnyServerName},
+    tls::{config::*, get_cert_identity, WebPkiVerifierAnyServerName},
     ConfigurationInspector, LinkAuthId,
 };
 
@@ -624,13 +624,8 @@ pub fn get_cert_common_name(conn: &quinn::Connection) -> ZResult<QuicAuthId> {
             .unwrap();
         if let Some(item) = serv_certs.iter().next() {
             let (_, cert) = X509Certificate::from_der(item.as_ref()).unwrap();
-            let subject_name = cert
-                .subject
-                .iter_common_name()
-                .next()
-                .and_then(|cn| cn.as_str().ok());
             auth_id = QuicAuthId {
-                auth_value: subject_name.map(|cn| cn.to_string()),
+                auth_value: get_cert_identity(&cert),
             };
         }
     }
@@ -16,7 +16,7 @@ use std::net::SocketAddr;
 use tokio::net::{TcpListener, TcpSocket, TcpStream};
 use zenoh_result::{zerror, ZResult};
 
-use crate::set_dscp;
+use crate::{keep_ipv6_zone_id, set_dscp};
 
 #[derive(Debug)]
 pub struct TcpSocketConfig<'a> {
@@ -59,6 +59,7 @@ impl<'a> TcpSocketConfig<'a> {
         let local_addr = listener
             .local_addr()
             .map_err(|e| zerror!("{}: {}", addr, e))?;
+        let local_addr = keep_ipv6_zone_id(local_addr, addr);
 
         Ok((listener, local_addr))
     }
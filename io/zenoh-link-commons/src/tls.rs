@@ -11,6 +11,7 @@ use rustls::{
     RootCertStore,
 };
 use webpki::ALL_VERIFICATION_ALGS;
+use x509_parser::{certificate::X509Certificate, extensions::GeneralName};
 
 pub mod config {
     pub const TLS_ROOT_CA_CERTIFICATE_FILE: &str = "root_ca_certificate_file";
@@ -47,6 +48,30 @@ pub mod config {
     pub const TLS_HANDSHAKE_TIMEOUT_MS_DEFAULT: u64 = 10_000;
 }
 
+/// Returns the identity carried by the given certificate.
+///
+/// This is the first Common Name of the certificate subject or, if the subject has no
+/// Common Name, the first DNS name of the Subject Alternative Name extension.
+pub fn get_cert_identity(cert: &X509Certificate) -> Option<String> {
+    if let Some(cn) = cert
+        .subject
+        .iter_common_name()
+        .next()
+        .and_then(|cn| cn.as_str().ok())
+    {
+        return Some(cn.to_string());
+    }
+    cert.subject_alternative_name()
+        .ok()
+        .flatten()
+        .and_then(|san| {
+            san.value.general_names.iter().find_map(|name| match name {
+                GeneralName::DNSName(dns) => Some(dns.to_string()),
+                _ => None,
+            })
+        })
+}
+
 impl ServerCertVerifier for WebPkiVerifierAnyServerName {
     /// Will verify the certificate is valid in the following ways:
     /// - Signed by a  trusted `RootCertStore` CA
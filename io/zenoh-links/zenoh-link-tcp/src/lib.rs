@@ -21,7 +21,7 @@ use std::{net::SocketAddr, str::FromStr};
 
 use async_trait::async_trait;
 use zenoh_core::zconfigurable;
-use zenoh_link_commons::LocatorInspector;
+use zenoh_link_commons::{resolve_ipv6_zone_id, LocatorInspector};
 use zenoh_protocol::{
     core::{endpoint::Address, Locator, Metadata, Reliability},
     transport::BatchSize,
@@ -85,7 +85,8 @@ zconfigurable! {
 }
 
 pub async fn get_tcp_addrs(address: Address<'_>) -> ZResult<impl Iterator<Item = SocketAddr>> {
-    let iter = tokio::net::lookup_host(address.as_str().to_string())
+    let address = resolve_ipv6_zone_id(address.as_str())?;
+    let iter = tokio::net::lookup_host(address.into_owned())
         .await
         .map_err(|e| zerror!("{}", e))?
         .filter(|x| !x.ip().is_multicast());
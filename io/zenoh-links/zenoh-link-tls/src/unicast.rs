@@ -35,7 +35,10 @@ use x509_parser::prelude::{FromDer, X509Certificate};
 use zenoh_core::{bail, zasynclock};
 use zenoh_link_commons::{
     get_ip_interface_names,
-    tls::expiration::{LinkCertExpirationManager, LinkWithCertExpiration},
+    tls::{
+        expiration::{LinkCertExpirationManager, LinkWithCertExpiration},
+        get_cert_identity,
+    },
     LinkAuthId, LinkManagerUnicastTrait, LinkUnicast, LinkUnicastTrait, ListenersUnicastIP,
     NewLinkChannelSender, BIND_INTERFACE, BIND_SOCKET,
 };
@@ -601,14 +604,8 @@ async fn accept_task(
 fn get_client_cert_common_name(tls_conn: &rustls::CommonState) -> ZResult<TlsAuthId> {
     if let Some(client_certs) = tls_conn.peer_certificates() {
         let (_, cert) = X509Certificate::from_der(client_certs[0].as_ref())?;
-        let subject_name = &cert
-            .subject
-            .iter_common_name()
-            .next()
-            .and_then(|cn| cn.as_str().ok());
-
         Ok(TlsAuthId {
-            auth_value: subject_name.map(|cn| cn.to_string()),
+            auth_value: get_cert_identity(&cert),
         })
     } else {
         Ok(TlsAuthId { auth_value: None })
@@ -622,14 +619,8 @@ fn get_server_cert_common_name(tls_conn: &rustls::ClientConnection) -> ZResult<T
     // Need the first certificate in the chain so no need for looping
     if let Some(item) = serv_certs.iter().next() {
         let (_, cert) = X509Certificate::from_der(item.as_ref())?;
-        let subject_name = &cert
-            .subject
-            .iter_common_name()
-            .next()
-            .and_then(|cn| cn.as_str().ok());
-
         auth_id = TlsAuthId {
-            auth_value: subject_name.map(|cn| cn.to_string()),
+            auth_value: get_cert_identity(&cert),
         };
         return Ok(auth_id);
     }
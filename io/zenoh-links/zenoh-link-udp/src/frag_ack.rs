@@ -0,0 +1,524 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+//! Acknowledgments of the datagrams sent on a UDP unicast link.
+//!
+//! A message larger than the link MTU is fragmented by the transport into several batches, each
+//! of them sent in its own datagram: losing any of them drops the whole message. When enabled
+//! with the `frag_ack=true` endpoint metadata, every datagram is prefixed with a sequence number
+//! and acknowledged by the receiver. The sender retransmits the unacknowledged datagrams up to
+//! `frag_ack_retries` times, every `frag_ack_timeout`, and the receiver delivers them in order,
+//! waiting for the missing ones at most for the lifetime of a datagram before skipping them.
+//!
+//! A data datagram is encoded as `MAGIC | DATA | sn (u32 LE) | payload`, and an acknowledgment
+//! as `MAGIC | ACK | next sn (u32 LE) | bitmap (u64 LE)`, where the bit `i` of the bitmap tells
+//! that the datagram `next + 1 + i` was received.
+//!
+//! The option is negotiated through the endpoint metadata: a listener with `frag_ack=true`
+//! advertises it in its locators, and a connector only prefixes its datagrams when its endpoint
+//! has it, i.e. when the listener advertises it. Such a listener still accepts the plain
+//! connectors: `MAGIC` is not a valid transport header, which tells a `frag_ack` connector from a
+//! plain one on its first datagram.
+use std::{
+    collections::VecDeque,
+    str::FromStr,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use zenoh_core::zlock;
+use zenoh_protocol::core::{endpoint::Config, EndPoint, Metadata};
+use zenoh_result::{bail, zerror, ZResult};
+
+use crate::{config, UDP_FRAG_ACK_RETRIES, UDP_FRAG_ACK_TIMEOUT, UDP_FRAG_ACK_WINDOW};
+
+const MAGIC: u8 = 0xff;
+const KIND_DATA: u8 = 0x01;
+const KIND_ACK: u8 = 0x02;
+
+/// The size of the header prefixed to the data datagrams.
+pub(crate) const HEADER_SIZE: usize = 6;
+const ACK_SIZE: usize = 14;
+const ACK_BITMAP_LEN: u32 = u64::BITS;
+
+/// The settings of the acknowledgments of a UDP unicast link.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct FragAckConf {
+    timeout: Duration,
+    retries: u8,
+    window: usize,
+}
+
+impl Default for FragAckConf {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_millis(*UDP_FRAG_ACK_TIMEOUT),
+            retries: *UDP_FRAG_ACK_RETRIES,
+            window: *UDP_FRAG_ACK_WINDOW,
+        }
+    }
+}
+
+impl FragAckConf {
+    /// Returns the settings of the acknowledgments enabled by the [`Metadata::FRAG_ACK`] metadata
+    /// of `endpoint`, tuned by its configuration, or `None` if they are not enabled.
+    pub(crate) fn from_endpoint(endpoint: &EndPoint) -> ZResult<Option<Self>> {
+        let enabled = match endpoint.metadata().get(Metadata::FRAG_ACK) {
+            Some(value) => value
+                .parse()
+                .map_err(|_| zerror!("Invalid `{}` metadata: {}", Metadata::FRAG_ACK, value))?,
+            None => false,
+        };
+        if !enabled {
+            return Ok(None);
+        }
+        let endpoint_config = endpoint.config();
+        let default = Self::default();
+        let conf = Self {
+            timeout: parse(&endpoint_config, config::UDP_FRAG_ACK_TIMEOUT)?
+                .map_or(default.timeout, Duration::from_millis),
+            retries: parse(&endpoint_config, config::UDP_FRAG_ACK_RETRIES)?
+                .unwrap_or(default.retries),
+            window: parse(&endpoint_config, config::UDP_FRAG_ACK_WINDOW)?.unwrap_or(default.window),
+        };
+        if conf.timeout.is_zero() || conf.window == 0 {
+            bail!(
+                "Invalid `{}` or `{}` configuration: must be greater than 0",
+                config::UDP_FRAG_ACK_TIMEOUT,
+                config::UDP_FRAG_ACK_WINDOW
+            );
+        }
+        Ok(Some(conf))
+    }
+}
+
+fn parse<T: FromStr>(config: &Config<'_>, key: &str) -> ZResult<Option<T>> {
+    config
+        .get(key)
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| zerror!("Invalid `{}` configuration: {}", key, value).into())
+        })
+        .transpose()
+}
+
+/// Returns `true` if `datagram` is a data datagram of a `frag_ack` link.
+pub(crate) fn is_data(datagram: &[u8]) -> bool {
+    datagram.len() >= HEADER_SIZE && datagram[0] == MAGIC && datagram[1] == KIND_DATA
+}
+
+/// The offset of `sn` from `base`, `None` if `sn` precedes `base`.
+fn offset(base: u32, sn: u32) -> Option<u32> {
+    let offset = sn.wrapping_sub(base);
+    (offset <= i32::MAX as u32).then_some(offset)
+}
+
+struct Pending {
+    sn: u32,
+    datagram: Vec<u8>,
+    sent_at: Instant,
+    retries: u8,
+}
+
+struct State {
+    // Sender side
+    next_tx: u32,
+    pending: VecDeque<Pending>,
+    // The number of datagrams given up without acknowledgment, because the window was full
+    evicted: u64,
+    // Receiver side
+    next_rx: u32,
+    reorder: VecDeque<Option<Vec<u8>>>,
+    gap_since: Option<Instant>,
+    ready: VecDeque<Vec<u8>>,
+}
+
+/// The acknowledgment state of a UDP unicast link.
+pub(crate) struct FragAck {
+    timeout: Duration,
+    retries: u8,
+    window: usize,
+    state: Mutex<State>,
+}
+
+impl FragAck {
+    pub(crate) fn new(conf: FragAckConf) -> Self {
+        Self {
+            timeout: conf.timeout,
+            retries: conf.retries,
+            window: conf.window,
+            state: Mutex::new(State {
+                next_tx: 0,
+                pending: VecDeque::new(),
+                evicted: 0,
+                next_rx: 0,
+                reorder: VecDeque::new(),
+                gap_since: None,
+                ready: VecDeque::new(),
+            }),
+        }
+    }
+
+    /// The time after which a datagram is given up, by the sender and by the receiver.
+    fn lifetime(&self) -> Duration {
+        self.timeout * (u32::from(self.retries) + 2)
+    }
+
+    /// Returns the datagram carrying `payload`, and keeps it until it is acknowledged.
+    ///
+    /// When `frag_ack_window` datagrams are already pending, the oldest one is given up
+    /// to make room for the new one: it is not retransmitted anymore.
+    pub(crate) fn wrap(&self, payload: &[u8]) -> Vec<u8> {
+        let mut state = zlock!(self.state);
+        let sn = state.next_tx;
+        state.next_tx = sn.wrapping_add(1);
+
+        let mut datagram = Vec::with_capacity(HEADER_SIZE + payload.len());
+        datagram.extend_from_slice(&[MAGIC, KIND_DATA]);
+        datagram.extend_from_slice(&sn.to_le_bytes());
+        datagram.extend_from_slice(payload);
+
+        if state.pending.len() >= self.window {
+            if let Some(evicted) = state.pending.pop_front() {
+                state.evicted += 1;
+                tracing::debug!(
+                    "Giving up UDP datagram {} as {} datagrams are pending acknowledgment ({} given up so far)",
+                    evicted.sn,
+                    self.window,
+                    state.evicted
+                );
+            }
+        }
+        state.pending.push_back(Pending {
+            sn,
+            datagram: datagram.clone(),
+            sent_at: Instant::now(),
+            retries: 0,
+        });
+        datagram
+    }
+
+    /// Processes a datagram received on the link, returning the acknowledgment to send back
+    /// if it carries data.
+    ///
+    /// A datagram without acknowledgment header is dropped. A datagram with the acknowledgment
+    /// header but a truncated content or an unknown kind is an error.
+    pub(crate) fn received(&self, datagram: &[u8], now: Instant) -> ZResult<Option<Vec<u8>>> {
+        if datagram.len() < 2 || datagram[0] != MAGIC {
+            tracing::trace!("Dropping a UDP datagram without acknowledgment header");
+            return Ok(None);
+        }
+        match datagram[1] {
+            KIND_DATA if datagram.len() >= HEADER_SIZE => {
+                let sn = u32::from_le_bytes(datagram[2..HEADER_SIZE].try_into().unwrap());
+                let mut state = zlock!(self.state);
+                self.received_data(&mut state, sn, &datagram[HEADER_SIZE..], now);
+                Ok(Some(Self::ack(&state)))
+            }
+            KIND_ACK if datagram.len() >= ACK_SIZE => {
+                let next = u32::from_le_bytes(datagram[2..6].try_into().unwrap());
+                let bitmap = u64::from_le_bytes(datagram[6..ACK_SIZE].try_into().unwrap());
+                let mut state = zlock!(self.state);
+                // Keep the datagrams neither preceding `next` nor in the bitmap
+                state.pending.retain(|p| match offset(next, p.sn) {
+                    None => false,
+                    Some(0) => true,
+                    Some(o) => o > ACK_BITMAP_LEN || bitmap & (1 << (o - 1)) == 0,
+                });
+                Ok(None)
+            }
+            KIND_DATA | KIND_ACK => bail!(
+                "Truncated UDP acknowledgment datagram of kind {:#04x}: {} bytes",
+                datagram[1],
+                datagram.len()
+            ),
+            kind => bail!("Invalid UDP acknowledgment datagram kind: {:#04x}", kind),
+        }
+    }
+
+    fn received_data(&self, state: &mut State, sn: u32, payload: &[u8], now: Instant) {
+        let Some(mut index) = offset(state.next_rx, sn).map(|o| o as usize) else {
+            // Duplicate of a delivered or skipped datagram
+            return;
+        };
+        if index >= self.window {
+            // Too far ahead: skip the datagrams preceding the window
+            let skip = index + 1 - self.window;
+            for _ in 0..skip {
+                if let Some(Some(payload)) = state.reorder.pop_front() {
+                    state.ready.push_back(payload);
+                }
+            }
+            state.next_rx = state.next_rx.wrapping_add(skip as u32);
+            index -= skip;
+        }
+        if state.reorder.len() <= index {
+            state.reorder.resize(index + 1, None);
+        }
+        if state.reorder[index].is_none() {
+            state.reorder[index] = Some(payload.to_vec());
+        }
+        Self::deliver(state);
+        if state.reorder.is_empty() {
+            state.gap_since = None;
+        } else if state.gap_since.is_none() {
+            state.gap_since = Some(now);
+        }
+    }
+
+    /// Moves the in-order datagrams to the ready queue.
+    fn deliver(state: &mut State) {
+        while let Some(Some(_)) = state.reorder.front() {
+            let payload = state.reorder.pop_front().flatten().unwrap();
+            state.ready.push_back(payload);
+            state.next_rx = state.next_rx.wrapping_add(1);
+        }
+    }
+
+    fn ack(state: &State) -> Vec<u8> {
+        let bitmap = state
+            .reorder
+            .iter()
+            .skip(1)
+            .take(ACK_BITMAP_LEN as usize)
+            .enumerate()
+            .filter(|(_, p)| p.is_some())
+            .fold(0u64, |bitmap, (i, _)| bitmap | (1 << i));
+        let mut ack = Vec::with_capacity(ACK_SIZE);
+        ack.extend_from_slice(&[MAGIC, KIND_ACK]);
+        ack.extend_from_slice(&state.next_rx.to_le_bytes());
+        ack.extend_from_slice(&bitmap.to_le_bytes());
+        ack
+    }
+
+    /// Pops the next payload to deliver, in order.
+    pub(crate) fn pop(&self) -> Option<Vec<u8>> {
+        zlock!(self.state).ready.pop_front()
+    }
+
+    /// Returns the datagrams to retransmit at `now`, and the next time to call it again.
+    ///
+    /// The datagrams exceeding their retry budget are given up, and so are the missing
+    /// datagrams the receiver waited for longer than their lifetime.
+    pub(crate) fn poll(&self, now: Instant) -> (Vec<Vec<u8>>, Option<Instant>) {
+        let mut state = zlock!(self.state);
+        let retries = self.retries;
+        let timeout = self.timeout;
+        let mut retransmissions = vec![];
+        state.pending.retain_mut(|p| {
+            if now < p.sent_at + timeout {
+                true
+            } else if p.retries < retries {
+                p.retries += 1;
+                p.sent_at = now;
+                retransmissions.push(p.datagram.clone());
+                true
+            } else {
+                false
+            }
+        });
+
+        if state
+            .gap_since
+            .is_some_and(|since| now >= since + self.lifetime())
+        {
+            // Skip the missing datagrams up to the next received one
+            while let Some(None) = state.reorder.front() {
+                state.reorder.pop_front();
+                state.next_rx = state.next_rx.wrapping_add(1);
+            }
+            Self::deliver(&mut state);
+            state.gap_since = (!state.reorder.is_empty()).then_some(now);
+        }
+
+        let deadline = state
+            .pending
+            .iter()
+            .map(|p| p.sent_at + timeout)
+            .chain(state.gap_since.map(|since| since + self.lifetime()))
+            .min();
+        (retransmissions, deadline)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sn(datagram: &[u8]) -> u32 {
+        u32::from_le_bytes(datagram[2..HEADER_SIZE].try_into().unwrap())
+    }
+
+    #[test]
+    fn ack_releases_pending_datagrams() {
+        let (sender, receiver) = (
+            FragAck::new(FragAckConf::default()),
+            FragAck::new(FragAckConf::default()),
+        );
+        let now = Instant::now();
+        for payload in [b"a", b"b", b"c"] {
+            let ack = receiver
+                .received(&sender.wrap(payload), now)
+                .unwrap()
+                .unwrap();
+            assert_eq!(sender.received(&ack, now).unwrap(), None);
+        }
+        assert!(zlock!(sender.state).pending.is_empty());
+        assert_eq!(receiver.pop().unwrap(), b"a");
+        assert_eq!(receiver.pop().unwrap(), b"b");
+        assert_eq!(receiver.pop().unwrap(), b"c");
+        assert_eq!(receiver.pop(), None);
+
+        let (retransmissions, deadline) = sender.poll(now + sender.lifetime());
+        assert!(retransmissions.is_empty());
+        assert_eq!(deadline, None);
+    }
+
+    #[test]
+    fn selective_ack_retransmits_missing_datagram() {
+        let (sender, receiver) = (
+            FragAck::new(FragAckConf::default()),
+            FragAck::new(FragAckConf::default()),
+        );
+        let now = Instant::now();
+        let datagrams: Vec<_> = [b"a", b"b", b"c"].iter().map(|p| sender.wrap(*p)).collect();
+        // The second datagram is lost
+        receiver.received(&datagrams[0], now).unwrap();
+        let ack = receiver.received(&datagrams[2], now).unwrap().unwrap();
+        sender.received(&ack, now).unwrap();
+        let pending: Vec<_> = zlock!(sender.state).pending.iter().map(|p| p.sn).collect();
+        assert_eq!(pending, [1]);
+        assert_eq!(receiver.pop().unwrap(), b"a");
+        assert_eq!(receiver.pop(), None);
+
+        let (retransmissions, _) = sender.poll(Instant::now() + sender.timeout);
+        assert_eq!(retransmissions, datagrams[1..2]);
+        let ack = receiver
+            .received(&retransmissions[0], now)
+            .unwrap()
+            .unwrap();
+        sender.received(&ack, now).unwrap();
+        assert!(zlock!(sender.state).pending.is_empty());
+        assert_eq!(receiver.pop().unwrap(), b"b");
+        assert_eq!(receiver.pop().unwrap(), b"c");
+    }
+
+    #[test]
+    fn retry_budget_gives_up_datagram() {
+        let sender = FragAck::new(FragAckConf::default());
+        let datagram = sender.wrap(b"a");
+        let mut now = Instant::now();
+        for _ in 0..sender.retries {
+            now += sender.timeout;
+            let (retransmissions, deadline) = sender.poll(now);
+            assert_eq!(retransmissions, std::slice::from_ref(&datagram));
+            assert_eq!(deadline, Some(now + sender.timeout));
+        }
+        now += sender.timeout;
+        let (retransmissions, deadline) = sender.poll(now);
+        assert!(retransmissions.is_empty());
+        assert_eq!(deadline, None);
+        assert!(zlock!(sender.state).pending.is_empty());
+    }
+
+    #[test]
+    fn receiver_skips_gap_after_lifetime() {
+        let (sender, receiver) = (
+            FragAck::new(FragAckConf::default()),
+            FragAck::new(FragAckConf::default()),
+        );
+        let now = Instant::now();
+        let lost = sender.wrap(b"a");
+        receiver.received(&sender.wrap(b"b"), now).unwrap();
+        assert_eq!(receiver.pop(), None);
+
+        let (_, deadline) = receiver.poll(now);
+        assert_eq!(deadline, Some(now + receiver.lifetime()));
+        receiver.poll(now + receiver.lifetime());
+        assert_eq!(receiver.pop().unwrap(), b"b");
+        // The skipped datagram is a duplicate once it arrives
+        receiver.received(&lost, now).unwrap();
+        assert_eq!(receiver.pop(), None);
+    }
+
+    #[test]
+    fn full_window_evicts_oldest_datagram() {
+        let sender = FragAck::new(FragAckConf::default());
+        for _ in 0..sender.window + 2 {
+            sender.wrap(b"a");
+        }
+        let state = zlock!(sender.state);
+        assert_eq!(state.evicted, 2);
+        assert_eq!(state.pending.len(), sender.window);
+        assert_eq!(state.pending.front().map(|p| p.sn), Some(2));
+    }
+
+    #[test]
+    fn malformed_datagrams_are_errors() {
+        let frag_ack = FragAck::new(FragAckConf::default());
+        let now = Instant::now();
+        let ack = {
+            let receiver = FragAck::new(FragAckConf::default());
+            receiver
+                .received(&frag_ack.wrap(b"a"), now)
+                .unwrap()
+                .unwrap()
+        };
+        assert!(frag_ack.received(&ack[..ACK_SIZE - 1], now).is_err());
+        assert!(frag_ack.received(&[MAGIC, KIND_DATA, 0], now).is_err());
+        assert!(frag_ack.received(&[MAGIC, 0x7f], now).is_err());
+        // The truncated acknowledgment did not release the datagram
+        assert_eq!(zlock!(frag_ack.state).pending.len(), 1);
+        frag_ack.received(&ack, now).unwrap();
+        assert!(zlock!(frag_ack.state).pending.is_empty());
+    }
+
+    #[test]
+    fn plain_datagram_is_dropped() {
+        let frag_ack = FragAck::new(FragAckConf::default());
+        let datagram = frag_ack.wrap(b"a");
+        assert_eq!(sn(&datagram), 0);
+        assert!(is_data(&datagram));
+        assert!(!is_data(b"plain"));
+        assert_eq!(frag_ack.received(b"plain", Instant::now()).unwrap(), None);
+        assert_eq!(frag_ack.pop(), None);
+        assert_eq!(zlock!(frag_ack.state).pending.len(), 1);
+    }
+
+    #[test]
+    fn conf_from_endpoint() {
+        let conf = |endpoint: &str| FragAckConf::from_endpoint(&endpoint.parse().unwrap());
+        assert_eq!(conf("udp/127.0.0.1:7447").unwrap(), None);
+        assert_eq!(conf("udp/127.0.0.1:7447?frag_ack=false").unwrap(), None);
+        assert_eq!(
+            conf("udp/127.0.0.1:7447?frag_ack=true").unwrap(),
+            Some(FragAckConf::default())
+        );
+        assert_eq!(
+            conf(
+                "udp/127.0.0.1:7447?frag_ack=true#frag_ack_timeout=50;frag_ack_retries=5;frag_ack_window=64"
+            )
+            .unwrap(),
+            Some(FragAckConf {
+                timeout: Duration::from_millis(50),
+                retries: 5,
+                window: 64,
+            })
+        );
+        assert!(conf("udp/127.0.0.1:7447?frag_ack=yes").is_err());
+        assert!(conf("udp/127.0.0.1:7447?frag_ack=true#frag_ack_retries=-1").is_err());
+        assert!(conf("udp/127.0.0.1:7447?frag_ack=true#frag_ack_window=0").is_err());
+    }
+}
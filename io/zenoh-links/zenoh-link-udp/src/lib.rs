@@ -17,6 +17,7 @@
 //! This crate is intended for Zenoh's internal use.
 //!
 //! [Click here for Zenoh's documentation](https://docs.rs/zenoh/latest/zenoh)
+mod frag_ack;
 mod multicast;
 mod pktinfo;
 mod reliability;
@@ -28,7 +29,7 @@ use async_trait::async_trait;
 pub use multicast::*;
 pub use unicast::*;
 use zenoh_core::zconfigurable;
-use zenoh_link_commons::LocatorInspector;
+use zenoh_link_commons::{resolve_ipv6_zone_id, LocatorInspector};
 use zenoh_protocol::{
     core::{endpoint::Address, Locator, Metadata, Reliability},
     transport::BatchSize,
@@ -75,6 +76,14 @@ zconfigurable! {
     // Amount of time in microseconds to throttle the accept loop upon an error.
     // Default set to 100 ms.
     static ref UDP_ACCEPT_THROTTLE_TIME: u64 = 100_000;
+    // Default amount of time in milliseconds before retransmitting an unacknowledged datagram
+    // on a link with fragment acknowledgments, see `config::UDP_FRAG_ACK_TIMEOUT`.
+    static ref UDP_FRAG_ACK_TIMEOUT: u64 = 10;
+    // Default maximum number of retransmissions of a datagram, see `config::UDP_FRAG_ACK_RETRIES`.
+    static ref UDP_FRAG_ACK_RETRIES: u8 = 3;
+    // Default maximum number of datagrams kept for retransmission, or for reordering,
+    // see `config::UDP_FRAG_ACK_WINDOW`.
+    static ref UDP_FRAG_ACK_WINDOW: usize = 1_024;
 }
 
 #[derive(Debug, Default, Clone, Copy)]
@@ -110,10 +119,20 @@ pub mod config {
     pub const UDP_MULTICAST_IFACE: &str = "iface";
     pub const UDP_MULTICAST_JOIN: &str = "join";
     pub const UDP_MULTICAST_TTL: &str = "ttl";
+    /// Amount of time in milliseconds before retransmitting an unacknowledged datagram on a
+    /// unicast link with fragment acknowledgments.
+    pub const UDP_FRAG_ACK_TIMEOUT: &str = "frag_ack_timeout";
+    /// Maximum number of retransmissions of a datagram on a unicast link with fragment
+    /// acknowledgments.
+    pub const UDP_FRAG_ACK_RETRIES: &str = "frag_ack_retries";
+    /// Maximum number of datagrams kept for retransmission, or for reordering, on a unicast link
+    /// with fragment acknowledgments.
+    pub const UDP_FRAG_ACK_WINDOW: &str = "frag_ack_window";
 }
 
 pub async fn get_udp_addrs(address: Address<'_>) -> ZResult<impl Iterator<Item = SocketAddr>> {
-    let iter = tokio::net::lookup_host(address.as_str().to_string())
+    let address = resolve_ipv6_zone_id(address.as_str())?;
+    let iter = tokio::net::lookup_host(address.into_owned())
         .await
         .map_err(|e| zerror!("{}", e))?;
     Ok(iter)
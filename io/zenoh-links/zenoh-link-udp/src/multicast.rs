@@ -23,8 +23,8 @@ use async_trait::async_trait;
 use socket2::{Domain, Protocol, Socket, Type};
 use tokio::net::UdpSocket;
 use zenoh_link_commons::{
-    parse_dscp, set_dscp, LinkAuthId, LinkManagerMulticastTrait, LinkMulticast, LinkMulticastTrait,
-    BIND_SOCKET,
+    parse_dscp, resolve_ipv6_zone_id, set_dscp, LinkAuthId, LinkManagerMulticastTrait,
+    LinkMulticast, LinkMulticastTrait, BIND_SOCKET,
 };
 use zenoh_protocol::{
     core::{Config, EndPoint, Locator},
@@ -199,7 +199,7 @@ impl LinkManagerMulticastUdp {
 
         // Get local unicast address to bind the socket on
         let mut local_addr = if let Some(bind_socket) = bind_socket {
-            let bind_addr = SocketAddr::from_str(bind_socket)?;
+            let bind_addr = SocketAddr::from_str(&resolve_ipv6_zone_id(bind_socket)?)?;
             match (bind_addr, mcast_addr) {
                 (SocketAddr::V6(local), SocketAddr::V4(dest)) => {
                     return Err(Box::from(format!(
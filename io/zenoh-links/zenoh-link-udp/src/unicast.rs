@@ -18,7 +18,7 @@ use std::{
     fmt,
     net::{Ipv4Addr, Ipv6Addr, SocketAddr},
     sync::{Arc, Mutex, Weak},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use async_trait::async_trait;
@@ -26,9 +26,10 @@ use tokio::{net::UdpSocket, sync::Mutex as AsyncMutex};
 use tokio_util::sync::CancellationToken;
 use zenoh_core::{zasynclock, zlock};
 use zenoh_link_commons::{
-    get_ip_interface_names, parse_dscp, set_dscp, ConstructibleLinkManagerUnicast, LinkAuthId,
-    LinkManagerUnicastTrait, LinkUnicast, LinkUnicastTrait, ListenersUnicastIP, LocatorInspector,
-    NewLinkChannelSender, BIND_INTERFACE, BIND_SOCKET,
+    get_ip_interface_names, keep_ipv6_zone_id, parse_dscp, set_dscp,
+    ConstructibleLinkManagerUnicast, LinkAuthId, LinkManagerUnicastTrait, LinkUnicast,
+    LinkUnicastTrait, ListenersUnicastIP, LocatorInspector, NewLinkChannelSender, BIND_INTERFACE,
+    BIND_SOCKET,
 };
 use zenoh_protocol::{
     core::{Address, EndPoint, Locator, Priority},
@@ -41,7 +42,11 @@ use super::{
     get_udp_addrs, socket_addr_to_udp_locator, UDP_ACCEPT_THROTTLE_TIME, UDP_DEFAULT_MTU,
     UDP_MAX_MTU,
 };
-use crate::{pktinfo, reliability::LinkUnicastQuicUnsecure};
+use crate::{
+    frag_ack::{self, FragAck, FragAckConf},
+    pktinfo,
+    reliability::LinkUnicastQuicUnsecure,
+};
 
 type LinkHashMap = Arc<Mutex<HashMap<(SocketAddr, SocketAddr), Weak<LinkUnicastUdpUnconnected>>>>;
 type LinkInput = (Vec<u8>, usize);
@@ -140,6 +145,8 @@ pub struct LinkUnicastUdp {
     dst_locator: Locator,
     // The UDP socket is connected to the peer
     variant: LinkUnicastUdpVariant,
+    // The acknowledgments of the sent datagrams, if enabled
+    frag_ack: Option<FragAck>,
 }
 
 impl LinkUnicastUdp {
@@ -154,6 +161,73 @@ impl LinkUnicastUdp {
             src_addr,
             dst_addr,
             variant,
+            frag_ack: None,
+        }
+    }
+
+    /// Enables the acknowledgments of the datagrams sent on this link, see [`FragAck`].
+    pub(crate) fn with_frag_ack(mut self, frag_ack: Option<FragAck>) -> Self {
+        self.frag_ack = frag_ack;
+        self
+    }
+
+    async fn write_datagram(&self, buffer: &[u8]) -> ZResult<usize> {
+        match &self.variant {
+            LinkUnicastUdpVariant::Connected(link) => link.write(buffer).await,
+            LinkUnicastUdpVariant::Unconnected(link) => link.write(buffer, self.dst_addr).await,
+            LinkUnicastUdpVariant::Reliable(_) => bail!("UDP link is reliable"),
+        }
+    }
+
+    async fn read_datagram(&self, buffer: &mut [u8]) -> ZResult<usize> {
+        match &self.variant {
+            LinkUnicastUdpVariant::Connected(link) => link.read(buffer).await,
+            LinkUnicastUdpVariant::Unconnected(link) => link.read(buffer).await,
+            LinkUnicastUdpVariant::Reliable(_) => bail!("UDP link is reliable"),
+        }
+    }
+
+    async fn write_frag_ack(&self, frag_ack: &FragAck, buffer: &[u8]) -> ZResult<usize> {
+        self.write_datagram(&frag_ack.wrap(buffer)).await?;
+        Ok(buffer.len())
+    }
+
+    async fn read_frag_ack(&self, frag_ack: &FragAck, buffer: &mut [u8]) -> ZResult<usize> {
+        let mut datagram = zenoh_buffers::vec::uninit(UDP_MAX_MTU as usize);
+        loop {
+            if let Some(payload) = frag_ack.pop() {
+                if payload.len() > buffer.len() {
+                    bail!(
+                        "UDP datagram of {} bytes does not fit in a read buffer of {} bytes",
+                        payload.len(),
+                        buffer.len()
+                    );
+                }
+                buffer[..payload.len()].copy_from_slice(&payload);
+                return Ok(payload.len());
+            }
+            // The datagrams are retransmitted by the reading task, which always runs
+            let (retransmissions, deadline) = frag_ack.poll(Instant::now());
+            for datagram in retransmissions {
+                self.write_datagram(&datagram).await?;
+            }
+            let n = match deadline {
+                Some(deadline) => {
+                    match tokio::time::timeout_at(
+                        deadline.into(),
+                        self.read_datagram(&mut datagram),
+                    )
+                    .await
+                    {
+                        Ok(n) => n?,
+                        Err(_) => continue,
+                    }
+                }
+                None => self.read_datagram(&mut datagram).await?,
+            };
+            if let Some(ack) = frag_ack.received(&datagram[..n], Instant::now())? {
+                self.write_datagram(&ack).await?;
+            }
         }
     }
 }
@@ -175,6 +249,9 @@ impl LinkUnicastTrait for LinkUnicastUdp {
     }
 
     async fn write(&self, buffer: &[u8], priority: Option<Priority>) -> ZResult<usize> {
+        if let Some(frag_ack) = &self.frag_ack {
+            return self.write_frag_ack(frag_ack, buffer).await;
+        }
         match &self.variant {
             LinkUnicastUdpVariant::Connected(link) => link.write(buffer).await,
             LinkUnicastUdpVariant::Unconnected(link) => link.write(buffer, self.dst_addr).await,
@@ -196,6 +273,9 @@ impl LinkUnicastTrait for LinkUnicastUdp {
     }
 
     async fn read(&self, buffer: &mut [u8], priority: Option<Priority>) -> ZResult<usize> {
+        if let Some(frag_ack) = &self.frag_ack {
+            return self.read_frag_ack(frag_ack, buffer).await;
+        }
         match &self.variant {
             LinkUnicastUdpVariant::Connected(link) => link.read(buffer).await,
             LinkUnicastUdpVariant::Unconnected(link) => link.read(buffer).await,
@@ -232,7 +312,10 @@ impl LinkUnicastTrait for LinkUnicastUdp {
         match &self.variant {
             LinkUnicastUdpVariant::Reliable(link) => link.get_mtu(),
             LinkUnicastUdpVariant::Connected(_) | LinkUnicastUdpVariant::Unconnected(_) => {
-                *UDP_DEFAULT_MTU
+                match self.frag_ack {
+                    Some(_) => *UDP_DEFAULT_MTU - frag_ack::HEADER_SIZE as BatchSize,
+                    None => *UDP_DEFAULT_MTU,
+                }
             }
         }
     }
@@ -275,6 +358,10 @@ impl LinkUnicastTrait for LinkUnicastUdp {
 
     #[cfg(all(feature = "uring", target_os = "linux"))]
     fn get_fd(&self) -> ZResult<RawFd> {
+        if self.frag_ack.is_some() {
+            // The datagrams carry an acknowledgment header that io_uring would not strip
+            bail!("FD unavailable for UDP with fragment acknowledgments");
+        }
         let fd = match &self.variant {
             LinkUnicastUdpVariant::Connected(link_unicast_udp_connected) => {
                 link_unicast_udp_connected.socket.as_raw_fd()
@@ -436,6 +523,7 @@ impl LinkManagerUnicastUdp {
             tracing::warn!("{}", e);
             e
         })?;
+        let local_addr = keep_ipv6_zone_id(local_addr, addr);
 
         Ok((socket, local_addr))
     }
@@ -457,19 +545,24 @@ impl LinkManagerUnicastUdp {
 
         let bind_socket = config.get(BIND_SOCKET);
         let dscp = parse_dscp(&config)?;
+        // The endpoint only enables the acknowledgments if the listener advertises them
+        let frag_ack = FragAckConf::from_endpoint(&endpoint)?;
 
         let mut errs: Vec<ZError> = vec![];
         for da in dst_addrs {
             match self.new_link_inner(&da, iface, bind_socket, dscp).await {
                 Ok((socket, src_addr, dst_addr)) => {
                     // Create UDP link
-                    let link = Arc::new(LinkUnicastUdp::new(
-                        src_addr,
-                        dst_addr,
-                        LinkUnicastUdpVariant::Connected(LinkUnicastUdpConnected {
-                            socket: Arc::new(socket),
-                        }),
-                    ));
+                    let link = Arc::new(
+                        LinkUnicastUdp::new(
+                            src_addr,
+                            dst_addr,
+                            LinkUnicastUdpVariant::Connected(LinkUnicastUdpConnected {
+                                socket: Arc::new(socket),
+                            }),
+                        )
+                        .with_frag_ack(frag_ack.map(FragAck::new)),
+                    );
 
                     return Ok(LinkUnicast::from(link as Arc<dyn LinkUnicastTrait>));
                 }
@@ -497,6 +590,8 @@ impl LinkManagerUnicastUdp {
         let config = endpoint.config();
         let iface = config.get(BIND_INTERFACE);
         let dscp = parse_dscp(&config)?;
+        // The acknowledgments are advertised in the locator of the listener
+        let frag_ack = FragAckConf::from_endpoint(&endpoint)?;
 
         let mut errs: Vec<ZError> = vec![];
         for da in addrs {
@@ -516,7 +611,7 @@ impl LinkManagerUnicastUdp {
                         let token = token.clone();
                         let manager = self.manager.clone();
 
-                        async move { accept_read_task(socket, token, manager).await }
+                        async move { accept_read_task(socket, token, manager, frag_ack).await }
                     };
 
                     let locator = endpoint.to_locator();
@@ -615,6 +710,7 @@ async fn accept_read_task(
     socket: UdpSocket,
     token: CancellationToken,
     manager: NewLinkChannelSender,
+    frag_ack: Option<FragAckConf>,
 ) -> ZResult<()> {
     let socket = Arc::new(socket);
     let links: LinkHashMap = Arc::new(Mutex::new(HashMap::new()));
@@ -683,11 +779,18 @@ async fn accept_read_task(
                                     });
                                     zaddlink!(src_addr, dst_addr, Arc::downgrade(&unconnected));
                                     // Create the new link object
-                                    let link = Arc::new(LinkUnicastUdp::new(
-                                        src_addr,
-                                        dst_addr,
-                                        LinkUnicastUdpVariant::Unconnected(unconnected),
-                                    ));
+                                    // Plain connectors are accepted by a `frag_ack` listener
+                                    let frag_ack = frag_ack
+                                        .filter(|_| frag_ack::is_data(&buff[..n]))
+                                        .map(FragAck::new);
+                                    let link = Arc::new(
+                                        LinkUnicastUdp::new(
+                                            src_addr,
+                                            dst_addr,
+                                            LinkUnicastUdpVariant::Unconnected(unconnected),
+                                        )
+                                        .with_frag_ack(frag_ack),
+                                    );
                                     // Add the new link to the set of connected peers
                                     if let Err(e) = manager
                                         .send_async(LinkUnicast::from(link as Arc<dyn LinkUnicastTrait>))
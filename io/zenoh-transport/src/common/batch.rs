@@ -688,7 +688,7 @@ mod tests {
         };
         let mut batch = WBatch::new(config);
 
-        let tmsg: TransportMessage = KeepAlive.into();
+        let tmsg: TransportMessage = KeepAlive::new().into();
         let mut nmsg = NetworkMessage::from(Push {
             wire_expr: WireExpr::empty(),
             ext_qos: ext::QoSType::new(Priority::DEFAULT, CongestionControl::Block, false),
@@ -0,0 +1,86 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{
+    sync::atomic::{AtomicBool, AtomicI64, Ordering},
+    time::Duration,
+};
+
+use zenoh_protocol::core::{ZenohIdProto, NTP64};
+
+/// Weight of a new measurement in the moving average of the skew.
+const SKEW_EWMA_WEIGHT: f64 = 0.125;
+/// Value of the skew before the first measurement.
+const NO_ESTIMATE: i64 = i64::MIN;
+
+/// Estimates the skew between the clock of the remote peer of a transport and the local clock,
+/// from the HLC times carried by the keep-alives sent by the peer.
+///
+/// This is monitoring only: the local HLC is never adjusted.
+pub(crate) struct ClockSkewEstimator {
+    skew_nanos: AtomicI64,
+    warned: AtomicBool,
+}
+
+impl ClockSkewEstimator {
+    pub(crate) fn new() -> Self {
+        Self {
+            skew_nanos: AtomicI64::new(NO_ESTIMATE),
+            warned: AtomicBool::new(false),
+        }
+    }
+
+    /// Updates the estimate with the `time` of the remote HLC carried by a keep-alive.
+    ///
+    /// Keep-alives of different links may update the estimate concurrently, in which case one of
+    /// the measurements is lost.
+    pub(crate) fn observe(
+        &self,
+        time: NTP64,
+        zid: &ZenohIdProto,
+        warning_threshold: Option<Duration>,
+    ) {
+        let measure = diff_nanos(time, uhlc::system_time_clock());
+        let skew = match self.skew_nanos.load(Ordering::Relaxed) {
+            NO_ESTIMATE => measure,
+            skew => skew + (SKEW_EWMA_WEIGHT * measure.saturating_sub(skew) as f64) as i64,
+        };
+        self.skew_nanos.store(skew, Ordering::Relaxed);
+
+        if let Some(threshold) = warning_threshold {
+            let exceeded = skew.unsigned_abs() > threshold.as_nanos() as u64;
+            if exceeded && !self.warned.load(Ordering::Relaxed) {
+                tracing::warn!(
+                    "Estimated clock skew with {} ({}ms) exceeds the configured threshold ({}ms)",
+                    zid,
+                    skew / 1_000_000,
+                    threshold.as_millis()
+                );
+            }
+            self.warned.store(exceeded, Ordering::Relaxed);
+        }
+    }
+
+    /// Returns the estimated skew in nanoseconds, if any keep-alive carried a timestamp yet.
+    ///
+    /// A positive skew means the remote clock is ahead of the local one.
+    pub(crate) fn get(&self) -> Option<i64> {
+        let skew = self.skew_nanos.load(Ordering::Relaxed);
+        (skew != NO_ESTIMATE).then_some(skew)
+    }
+}
+
+fn diff_nanos(a: NTP64, b: NTP64) -> i64 {
+    let diff = a.to_duration().as_nanos() as i128 - b.to_duration().as_nanos() as i128;
+    diff.clamp(i64::MIN as i128 + 1, i64::MAX as i128) as i64
+}
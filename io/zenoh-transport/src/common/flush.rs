@@ -0,0 +1,100 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use tokio_util::sync::{CancellationToken, WaitForCancellationFuture};
+
+/// Counts the batches written or dropped while flushing the transmission pipelines of the
+/// closing links.
+///
+/// [`FlushTracker::abort`] stops the ongoing and future flushes at once: the batches which are
+/// not written yet are dropped.
+#[derive(Clone, Default)]
+pub(crate) struct FlushTracker {
+    flushed: Arc<AtomicUsize>,
+    dropped: Arc<AtomicUsize>,
+    abort: CancellationToken,
+}
+
+impl FlushTracker {
+    pub(crate) fn flushed(&self) -> usize {
+        self.flushed.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn dropped(&self) -> usize {
+        self.dropped.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn abort(&self) {
+        self.abort.cancel();
+    }
+
+    pub(crate) fn aborted(&self) -> WaitForCancellationFuture<'_> {
+        self.abort.cancelled()
+    }
+
+    pub(crate) fn add_flushed(&self, batches: usize) {
+        self.flushed.fetch_add(batches, Ordering::SeqCst);
+    }
+
+    pub(crate) fn add_dropped(&self, batches: usize) {
+        self.dropped.fetch_add(batches, Ordering::SeqCst);
+    }
+}
+
+/// A request to flush a transmission pipeline.
+///
+/// The request is done, i.e. its token is cancelled, once the consumers of all the priority
+/// queues of the pipeline have found them empty after the request, meaning that all the batches
+/// pushed before the request have been written on the link.
+pub(crate) struct PipelineFlush {
+    generation: usize,
+    // Bitflags of the consumers which have not acknowledged the request yet
+    pending: u8,
+    done: CancellationToken,
+}
+
+impl PipelineFlush {
+    pub(crate) fn new(generation: usize, consumers: u8, done: CancellationToken) -> Self {
+        Self {
+            generation,
+            pending: consumers,
+            done,
+        }
+    }
+
+    pub(crate) fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Acknowledges the request on behalf of the consumer `flag`. Returns `true` if the request
+    /// is done.
+    pub(crate) fn ack(&mut self, flag: u8) -> bool {
+        self.pending &= !flag;
+        if self.pending == 0 {
+            self.done.cancel();
+            return true;
+        }
+        false
+    }
+
+    /// Completes the request without waiting for the consumers, e.g. when the pipeline is
+    /// disabled and its remaining batches are drained by the link.
+    pub(crate) fn abort(self) {
+        self.done.cancel();
+    }
+}
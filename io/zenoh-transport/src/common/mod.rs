@@ -12,7 +12,10 @@
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
 pub mod batch;
+pub(crate) mod clock_skew;
 pub(crate) mod defragmentation;
+pub(crate) mod flush;
 pub(crate) mod pipeline;
 pub(crate) mod priority;
 pub(crate) mod seq_num;
+pub(crate) mod stall;
@@ -15,7 +15,7 @@ use std::{
     fmt,
     ops::Add,
     sync::{
-        atomic::{AtomicBool, AtomicU32, AtomicU8, Ordering},
+        atomic::{AtomicBool, AtomicU32, AtomicU8, AtomicUsize, Ordering},
         Arc, Mutex, MutexGuard,
     },
     time::{Duration, Instant},
@@ -23,6 +23,7 @@ use std::{
 
 use crossbeam_utils::CachePadded;
 use ringbuffer_spsc::{RingBuffer, RingBufferReader, RingBufferWriter};
+use tokio_util::sync::CancellationToken;
 use zenoh_buffers::{
     reader::{HasReader, Reader},
     writer::HasWriter,
@@ -47,7 +48,7 @@ use super::{
     batch::{Encode, WBatch},
     priority::{TransportChannelTx, TransportPriorityTx},
 };
-use crate::common::batch::BatchConfig;
+use crate::common::{batch::BatchConfig, flush::PipelineFlush};
 
 // Batches are moved all over the pipeline and are quite big (56B+), so they are boxed to optimize
 // the moves. They are always reused, so there is no allocation performance penalty.
@@ -692,10 +693,23 @@ impl TransmissionPipeline {
         priority: &[TransportPriorityTx],
         link_supports_priority: bool,
     ) -> (TransmissionPipelineProducer, TransmissionPipelineConsumer) {
+        // If the link supports priorities, each priority queue has its own consumer
+        let queues = if priority.len() == 1 {
+            1
+        } else {
+            Priority::NUM
+        };
         let status = Arc::new(TransmissionPipelineStatus {
             disabled: AtomicBool::new(false),
             congested: AtomicU8::new(0),
             pending: AtomicU8::new(0),
+            flush_generation: AtomicUsize::new(0),
+            flushes: Mutex::new(vec![]),
+            consumers: if link_supports_priority {
+                u8::MAX >> (u8::BITS as usize - queues)
+            } else {
+                1
+            },
             waits: Waits {
                 wait_before_drop: config.wait_before_drop,
                 max_wait_before_drop_fragments: config.max_wait_before_drop_fragments,
@@ -799,6 +813,7 @@ impl TransmissionPipeline {
             stage_out: stage_out.into_boxed_slice(),
             n_out_r: (!link_supports_priority).then_some(n_out_r),
             status,
+            flush_acked: 0,
         };
 
         (producer, consumer)
@@ -812,6 +827,12 @@ struct TransmissionPipelineStatus {
     congested: AtomicU8,
     // Bitflags to indicate the given priority queue has messages waiting to be sent
     pending: AtomicU8,
+    // Generation of the latest flush request
+    flush_generation: AtomicUsize,
+    // Pending flush requests
+    flushes: Mutex<Vec<PipelineFlush>>,
+    // Bitflags of the consumers which acknowledge the flush requests
+    consumers: u8,
     // wait parameters
     // Note: this is placed here to optimize TransmissionPipelineProducer memory layout and improve performance
     waits: Waits,
@@ -840,6 +861,34 @@ impl TransmissionPipelineStatus {
         self.congested.load(Ordering::Relaxed) & prioflag != 0
     }
 
+    fn congested_priorities(&self) -> Vec<Priority> {
+        let congested = self.congested.load(Ordering::Relaxed);
+        (0..Priority::NUM as u8)
+            .filter(|prio| congested & (1 << prio) != 0)
+            .map(|prio| Priority::try_from(prio).unwrap())
+            .collect()
+    }
+
+    fn flush_generation(&self) -> usize {
+        self.flush_generation.load(Ordering::Acquire)
+    }
+
+    /// Acknowledges the flush requests up to `generation` on behalf of the consumer `flag`,
+    /// which found its queues empty after loading `generation`. Returns the latest
+    /// acknowledged generation.
+    fn ack_flushes(&self, generation: usize, acked: usize, flag: u8) -> usize {
+        if generation != acked {
+            zlock!(self.flushes).retain_mut(|f| f.generation() > generation || !f.ack(flag));
+        }
+        generation
+    }
+
+    fn abort_flushes(&self) {
+        for flush in zlock!(self.flushes).drain(..) {
+            flush.abort();
+        }
+    }
+
     fn get_pending(&self) -> Option<Priority> {
         let pending = self.pending.load(Ordering::Relaxed);
         let prio = pending.trailing_zeros();
@@ -867,10 +916,15 @@ pub(crate) struct TransmissionPipelineProducer {
 }
 
 impl TransmissionPipelineProducer {
+    /// Pushes a network message in the pipeline.
+    ///
+    /// A message that is not droppable waits at most `block_timeout` for room in the pipeline,
+    /// or `wait_before_close` if `None`. Returns `false` if the message has not been pushed.
     #[inline]
     pub(crate) fn push_network_message(
         &self,
         msg: NetworkMessageRef,
+        block_timeout: Option<Duration>,
     ) -> Result<bool, TransportClosed> {
         // If the queue is not QoS, it means that we only have one priority with index 0.
         let (idx, priority) = if self.stage_in.len() > 1 {
@@ -891,7 +945,10 @@ impl TransmissionPipelineProducer {
                 Some(self.status.waits.max_wait_before_drop_fragments),
             )
         } else {
-            (self.status.waits.wait_before_close, None)
+            (
+                block_timeout.unwrap_or(self.status.waits.wait_before_close),
+                None,
+            )
         };
         let mut deadline = Deadline::new(wait_time, max_wait_time);
         // Lock the channel. We are the only one that will be writing on it.
@@ -938,8 +995,40 @@ impl TransmissionPipelineProducer {
         queue.push_transport_message(msg)
     }
 
+    /// Requests the consumers to flush the pipeline.
+    ///
+    /// The returned token is cancelled once all the messages pushed so far have been written on
+    /// the link, or once the pipeline is disabled.
+    pub(crate) fn flush(&self) -> CancellationToken {
+        let done = CancellationToken::new();
+        {
+            // The disabled flag is checked with the flushes locked, so that a request is
+            // either aborted by `disable` or done immediately.
+            let mut flushes = zlock!(self.status.flushes);
+            if self.status.is_disabled() {
+                done.cancel();
+                return done;
+            }
+            let generation = self.status.flush_generation.load(Ordering::Relaxed) + 1;
+            flushes.push(PipelineFlush::new(
+                generation,
+                self.status.consumers,
+                done.clone(),
+            ));
+            self.status
+                .flush_generation
+                .store(generation, Ordering::Release);
+        }
+        // Wake up the consumers waiting for new batches, so that they acknowledge the request
+        for stage_in in self.stage_in.iter() {
+            let _ = zlock!(stage_in).s_out.n_out_w.notify();
+        }
+        done
+    }
+
     pub(crate) fn disable(&self) {
         self.status.set_disabled(true);
+        self.status.abort_flushes();
 
         // Acquire all the locks, in_guard first, out_guard later
         // Use the same locking order as in drain to avoid deadlocks
@@ -958,6 +1047,8 @@ pub(crate) struct TransmissionPipelineConsumer {
     stage_out: Box<[StageOut]>,
     n_out_r: Option<Waiter>,
     status: Arc<TransmissionPipelineStatus>,
+    // Latest acknowledged flush generation
+    flush_acked: usize,
 }
 
 pub(crate) trait PipelineConsumer {
@@ -1006,6 +1097,10 @@ pub(crate) trait PipelineConsumer {
     }
     fn refill(&mut self, batch: BoxedWBatch, priority: Priority);
     fn drain(&mut self) -> Vec<(BoxedWBatch, Priority)>;
+    /// Returns the priority queues which currently have no batch available.
+    fn congested(&self) -> Vec<Priority> {
+        self.status().congested_priorities()
+    }
 }
 
 impl PipelineConsumer for TransmissionPipelineConsumer {
@@ -1015,6 +1110,9 @@ impl PipelineConsumer for TransmissionPipelineConsumer {
     }
 
     fn stage_pull(&mut self) -> Result<(BoxedWBatch, Priority), Option<MicroSeconds>> {
+        // The flush generation is loaded before pulling, so that the requests it covers are
+        // only acknowledged once the batches pushed before them have been pulled and refilled.
+        let flush_generation = self.status.flush_generation();
         while let Some(prio) = self.status.get_pending() {
             let queue = &mut self.stage_out[prio as usize];
             match queue.try_pull() {
@@ -1023,6 +1121,9 @@ impl PipelineConsumer for TransmissionPipelineConsumer {
                 Pull::None => {}
             }
         }
+        self.flush_acked = self
+            .status
+            .ack_flushes(flush_generation, self.flush_acked, 1);
         Err(None)
     }
 
@@ -1072,6 +1173,7 @@ impl TransmissionPipelineConsumer {
                 priority: Priority::try_from(prio as u8).unwrap(),
                 stage_out,
                 status: self.status.clone(),
+                flush_acked: self.flush_acked,
             })
             .collect()
     }
@@ -1081,6 +1183,8 @@ pub(crate) struct SplitTransmissionPipelineConsumer {
     priority: Priority,
     stage_out: StageOut,
     status: Arc<TransmissionPipelineStatus>,
+    // Latest acknowledged flush generation
+    flush_acked: usize,
 }
 
 impl SplitTransmissionPipelineConsumer {
@@ -1096,10 +1200,18 @@ impl PipelineConsumer for SplitTransmissionPipelineConsumer {
     }
 
     fn stage_pull(&mut self) -> Result<(BoxedWBatch, Priority), Option<MicroSeconds>> {
+        let flush_generation = self.status.flush_generation();
         match self.stage_out.try_pull() {
             Pull::Some(batch) => Ok((batch, self.priority)),
             Pull::Backoff(deadline) => Err(Some(deadline)),
-            Pull::None => Err(None),
+            Pull::None => {
+                self.flush_acked = self.status.ack_flushes(
+                    flush_generation,
+                    self.flush_acked,
+                    1 << self.priority as u8,
+                );
+                Err(None)
+            }
         }
     }
 
@@ -1138,7 +1250,7 @@ mod tests {
     use zenoh_codec::{RCodec, Zenoh080};
     use zenoh_config::{QueueAllocConf, QueueAllocMode};
     use zenoh_protocol::{
-        core::{Bits, CongestionControl, Priority},
+        core::{Bits, CongestionControl, Priority, Reliability},
         network::{ext, NetworkMessage, Push},
         transport::{BatchSize, Fragment, Frame, TransportBody, TransportSn},
     };
@@ -1205,7 +1317,7 @@ mod tests {
                     "Pipeline Flow [>>>]: Pushed {} msgs ({payload_size} bytes)",
                     i + 1
                 );
-                queue.push_network_message(message.as_ref()).unwrap();
+                queue.push_network_message(message.as_ref(), None).unwrap();
             }
         }
 
@@ -1334,7 +1446,7 @@ mod tests {
                 println!(
                     "Pipeline Blocking [>>>]: ({id}) Scheduling message #{i} with payload size of {payload_size} bytes"
                 );
-                queue.push_network_message(message.as_ref()).unwrap();
+                queue.push_network_message(message.as_ref(), None).unwrap();
                 let c = counter.fetch_add(1, Ordering::AcqRel);
                 println!(
                     "Pipeline Blocking [>>>]: ({}) Scheduled message #{} (tot {}) with payload size of {} bytes",
@@ -1404,6 +1516,7 @@ mod tests {
                         .into_boxed_slice(),
                     n_out_r: None,
                     status,
+                    flush_acked: 0,
                 }
             } else {
                 timeout(
@@ -1464,7 +1577,9 @@ mod tests {
                     let duration = Duration::from_millis(5_500);
                     let start = Instant::now();
                     while start.elapsed() < duration {
-                        producer.push_network_message(message.as_ref()).unwrap();
+                        producer
+                            .push_network_message(message.as_ref(), None)
+                            .unwrap();
                     }
                 }
             }
@@ -1492,6 +1607,59 @@ mod tests {
         }
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn tx_pipeline_best_effort_droppable() -> ZResult<()> {
+        // Put only one message per batch, so that the queue is full after a single message.
+        let payload_size = (CONFIG_NOT_STREAMED.batch.mtu / 2) as usize;
+
+        let tct = TransportPriorityTx::make(Bits::from(TransportSn::MAX))?;
+        let priorities = vec![tct];
+        for link_supports_priority in [false, true] {
+            for (reliability, congestion_control) in [
+                (Reliability::BestEffort, CongestionControl::Block),
+                (Reliability::BestEffort, CongestionControl::Drop),
+                (Reliability::Reliable, CongestionControl::Drop),
+            ] {
+                // The consumer is never drained, so the queue gets congested.
+                let (producer, _consumer) = TransmissionPipeline::make(
+                    CONFIG_NOT_STREAMED,
+                    priorities.as_slice(),
+                    link_supports_priority,
+                );
+                let message = NetworkMessage {
+                    reliability,
+                    ..NetworkMessage::from(Push {
+                        wire_expr: "test".into(),
+                        ext_qos: ext::QoSType::new(Priority::Control, congestion_control, false),
+                        ..Push::from(vec![0_u8; payload_size])
+                    })
+                };
+
+                // Congestion must result in the message being dropped instead of blocking.
+                let dropped = timeout(
+                    TIMEOUT,
+                    task::spawn_blocking(move || {
+                        (0..=CONFIG_NOT_STREAMED.queue_size[0] + 1)
+                            .map(|_| {
+                                producer
+                                    .push_network_message(message.as_ref(), None)
+                                    .unwrap()
+                            })
+                            .filter(|sent| !sent)
+                            .count()
+                    }),
+                )
+                .await??;
+                assert!(
+                    dropped > 0,
+                    "{reliability:?} message with {congestion_control:?} has not been dropped"
+                );
+            }
+        }
+
+        Ok(())
+    }
+
     #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
     async fn tx_pipeline_closed() -> ZResult<()> {
         // Pipeline
@@ -1518,9 +1686,73 @@ mod tests {
                 ..Push::from(vec![42u8])
             });
             // First message should not be rejected as the is one batch available in the queue
-            assert!(producer.push_network_message(message.as_ref()).is_ok());
+            assert!(producer
+                .push_network_message(message.as_ref(), None)
+                .is_ok());
             // Second message should be rejected
-            assert!(producer.push_network_message(message.as_ref()).is_err());
+            assert!(producer
+                .push_network_message(message.as_ref(), None)
+                .is_err());
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn tx_pipeline_flush() -> ZResult<()> {
+        async fn flush(
+            producer: TransmissionPipelineProducer,
+            mut consumer: impl PipelineConsumer,
+        ) {
+            let message = NetworkMessage::from(Push {
+                wire_expr: "test".into(),
+                ext_qos: ext::QoSType::new(Priority::Control, CongestionControl::Block, true),
+                ..Push::from(vec![42u8])
+            });
+            assert!(producer
+                .push_network_message(message.as_ref(), None)
+                .unwrap());
+
+            // The flush is done once the pushed batch has been pulled and refilled, and the
+            // consumer found the pipeline empty
+            let flushed = producer.flush();
+            let (batch, priority) = timeout(TIMEOUT, consumer.pull()).await.unwrap().unwrap();
+            assert!(!flushed.is_cancelled());
+            consumer.refill(batch, priority);
+            assert!(!flushed.is_cancelled());
+            assert!(timeout(SLEEP, consumer.pull()).await.is_err());
+            assert!(flushed.is_cancelled());
+
+            // A flush of an empty pipeline is done once the consumer is woken up
+            let flushed = producer.flush();
+            timeout(TIMEOUT, async {
+                tokio::select! {
+                    _ = consumer.pull() => panic!("Unexpected batch"),
+                    _ = flushed.cancelled() => {}
+                }
+            })
+            .await
+            .unwrap();
+
+            // A flush of a disabled pipeline is done immediately
+            producer.disable();
+            assert!(producer.flush().is_cancelled());
+        }
+
+        let tct = TransportPriorityTx::make(Bits::from(TransportSn::MAX))?;
+        let priorities = vec![tct];
+        for link_supports_priority in [false, true] {
+            let (producer, consumer) = TransmissionPipeline::make(
+                CONFIG_NOT_STREAMED,
+                priorities.as_slice(),
+                link_supports_priority,
+            );
+            if link_supports_priority {
+                let consumer = consumer.split().pop().unwrap();
+                flush(producer, consumer).await;
+            } else {
+                flush(producer, consumer).await;
+            }
         }
 
         Ok(())
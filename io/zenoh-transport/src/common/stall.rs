@@ -0,0 +1,109 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use zenoh_link::{LinkMulticast, LinkUnicast};
+use zenoh_protocol::core::Priority;
+use zenoh_result::{zerror, ZError, ZResult};
+
+/// Declares a link failed when a write makes no progress within the TX stall timeout.
+///
+/// Only writes are watched: an idle link waits for data to send, not on the link, and is
+/// therefore never considered stalled. A slow link is not stalled either as long as each
+/// write on it keeps accepting bytes.
+#[derive(Clone)]
+pub(crate) struct TxStallWatchdog {
+    timeout: Option<Duration>,
+    stalls: Arc<AtomicUsize>,
+}
+
+impl TxStallWatchdog {
+    pub(crate) fn new(timeout: Option<Duration>, stalls: Arc<AtomicUsize>) -> Self {
+        Self { timeout, stalls }
+    }
+
+    /// Writes `bytes` on a unicast link, returning `None` if the link stalled.
+    ///
+    /// Streamed links are written chunk by chunk so that the timeout only applies to the
+    /// progress of each write, while a datagram is written at once and must be accepted
+    /// within the timeout.
+    pub(crate) async fn write_unicast(
+        &self,
+        link: &LinkUnicast,
+        bytes: &[u8],
+        priority: Option<Priority>,
+    ) -> Option<ZResult<()>> {
+        let Some(timeout) = self.timeout else {
+            return Some(link.write_all(bytes, priority).await);
+        };
+        if !link.is_streamed() {
+            return tokio::time::timeout(timeout, link.write_all(bytes, priority))
+                .await
+                .ok();
+        }
+
+        let mut written = 0;
+        while written < bytes.len() {
+            match tokio::time::timeout(timeout, link.write(&bytes[written..], priority))
+                .await
+                .ok()?
+            {
+                Ok(0) => return Some(Err(zerror!("{link}: failed to write whole buffer").into())),
+                Ok(n) => written += n,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        Some(Ok(()))
+    }
+
+    /// Writes `bytes` on a multicast link, returning `None` if the link stalled.
+    pub(crate) async fn write_multicast(
+        &self,
+        link: &LinkMulticast,
+        bytes: &[u8],
+    ) -> Option<ZResult<()>> {
+        match self.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, link.write_all(bytes))
+                .await
+                .ok(),
+            None => Some(link.write_all(bytes).await),
+        }
+    }
+
+    /// Records a stall on `link` and returns the error closing it.
+    pub(crate) fn stalled(
+        &self,
+        link: &impl fmt::Display,
+        pending_bytes: usize,
+        congested: &[Priority],
+    ) -> ZError {
+        self.stalls.fetch_add(1, Ordering::SeqCst);
+        let timeout = self.timeout.unwrap_or_default();
+        tracing::warn!(
+            "{link}: transmission stalled for {} ms with {pending_bytes} bytes pending and congested priorities {congested:?}, closing the link",
+            timeout.as_millis(),
+        );
+        zerror!(
+            "{link}: transmission stalled for {} ms",
+            timeout.as_millis()
+        )
+    }
+}
@@ -15,6 +15,7 @@ use std::{collections::HashMap, fmt, sync::Arc, time::Duration};
 
 use rand::{RngCore, SeedableRng};
 use tokio::sync::Mutex as AsyncMutex;
+use uhlc::HLC;
 use zenoh_config::{ExpandedConfig, LinkRxConf, QueueAllocConf, QueueConf, QueueSizeConf};
 use zenoh_crypto::{BlockCipher, PseudoRng};
 use zenoh_link::{LinkKind, NewLinkChannelSender};
@@ -28,7 +29,7 @@ use zenoh_protocol::{
 use zenoh_result::{bail, ZResult};
 #[cfg(feature = "shared-memory")]
 use zenoh_shm::reader::ShmReader;
-use zenoh_task::TaskController;
+use zenoh_task::{TaskController, TaskRegistry};
 
 use super::{
     unicast::manager::{
@@ -143,6 +144,8 @@ pub struct TransportManagerConfig {
     pub supported_links: Vec<LinkKind>,
     pub bound_callback: Option<RemoteBoundCallback>,
     pub region_name: Option<RegionName>,
+    pub hlc: Option<Arc<HLC>>,
+    pub clock_skew_warning_threshold: Option<Duration>,
 }
 
 impl fmt::Debug for TransportManagerConfig {
@@ -176,6 +179,11 @@ impl fmt::Debug for TransportManagerConfig {
                 &self.bound_callback.as_ref().map(|_| ".."),
             )
             .field("region_name", &self.region_name)
+            .field("hlc", &self.hlc.as_ref().map(|_| ".."))
+            .field(
+                "clock_skew_warning_threshold",
+                &self.clock_skew_warning_threshold,
+            )
             .finish()
     }
 }
@@ -246,6 +254,8 @@ pub struct TransportManagerBuilder {
     supported_links: Option<Vec<LinkKind>>,
     region_name: Option<RegionName>,
     bound_callback: Option<RemoteBoundCallback>,
+    hlc: Option<Arc<HLC>>,
+    clock_skew_warning_threshold: Option<Duration>,
     #[cfg(feature = "shared-memory")]
     shm: zenoh_config::ShmConf,
     #[cfg(feature = "shared-memory")]
@@ -282,6 +292,11 @@ impl fmt::Debug for TransportManagerBuilder {
             .field(
                 "bound_callback",
                 &self.bound_callback.as_ref().map(|_| ".."),
+            )
+            .field("hlc", &self.hlc.as_ref().map(|_| ".."))
+            .field(
+                "clock_skew_warning_threshold",
+                &self.clock_skew_warning_threshold,
             );
         #[cfg(feature = "shared-memory")]
         debug.field("shm", &self.shm);
@@ -411,6 +426,18 @@ impl TransportManagerBuilder {
         self
     }
 
+    /// Sets the HLC whose time is sent in the keep-alives, enabling the estimation of the clock
+    /// skew with the remote peers.
+    pub fn hlc(mut self, hlc: Option<Arc<HLC>>) -> Self {
+        self.hlc = hlc;
+        self
+    }
+
+    pub fn clock_skew_warning_threshold(mut self, threshold: Option<Duration>) -> Self {
+        self.clock_skew_warning_threshold = threshold;
+        self
+    }
+
     pub async fn from_config(
         mut self,
         config: &ExpandedConfig,
@@ -441,6 +468,12 @@ impl TransportManagerBuilder {
         self = self.tx_threads(*link.tx().threads());
         self = self.protocols(link.protocols().clone());
         self = self.region_name(config.region_name().clone());
+        self = self.clock_skew_warning_threshold(
+            config
+                .timestamping()
+                .clock_skew_warning_threshold()
+                .map(Duration::from_millis),
+        );
 
         #[cfg(feature = "shared-memory")]
         {
@@ -516,6 +549,8 @@ impl TransportManagerBuilder {
                 .unwrap_or_else(|| zenoh_link::ALL_SUPPORTED_LINKS.to_vec()),
             bound_callback: self.bound_callback,
             region_name: self.region_name,
+            hlc: self.hlc,
+            clock_skew_warning_threshold: self.clock_skew_warning_threshold,
         };
 
         if cfg!(feature = "uring")
@@ -613,6 +648,8 @@ impl Default for TransportManagerBuilder {
             supported_links: None,
             region_name: None,
             bound_callback: None,
+            hlc: None,
+            clock_skew_warning_threshold: None,
             #[cfg(feature = "shared-memory")]
             shm: zenoh_config::ShmConf::default(),
             #[cfg(feature = "shared-memory")]
@@ -681,7 +718,7 @@ impl TransportManager {
         // @TODO: this should be moved into the unicast module
         let cancellation_token = this.task_controller.get_cancellation_token();
         this.task_controller
-            .spawn_with_rt(zenoh_runtime::ZRuntime::Net, {
+            .spawn_named_with_rt("zenoh:new_link", zenoh_runtime::ZRuntime::Net, {
                 let this = this.clone();
                 async move {
                     loop {
@@ -713,11 +750,30 @@ impl TransportManager {
         &self.stats
     }
 
+    /// The registry of the live tasks of this manager and of its transports.
+    pub fn task_registry(&self) -> &TaskRegistry {
+        self.task_controller.registry()
+    }
+
     #[cfg(feature = "shared-memory")]
     pub fn get_shm_context(&self) -> &Option<ShmContext> {
         &self.state.shm_context
     }
 
+    /// Waits until all the messages scheduled so far on the unicast and multicast transports have
+    /// been written on their links, or for at most `timeout`. Returns `false` if the timeout
+    /// expired.
+    pub async fn flush(&self, timeout: Duration) -> bool {
+        let mut flushes = self.flush_unicast().await;
+        flushes.extend(self.flush_multicast().await);
+        tokio::time::timeout(
+            timeout,
+            futures::future::join_all(flushes.iter().map(|f| f.cancelled())),
+        )
+        .await
+        .is_ok()
+    }
+
     pub async fn close(&self) {
         self.close_unicast().await;
         self.close_multicast().await;
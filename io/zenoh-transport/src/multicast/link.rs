@@ -39,6 +39,7 @@ use crate::{
             TransmissionPipelineConsumer, TransmissionPipelineProducer,
         },
         priority::TransportPriorityTx,
+        stall::TxStallWatchdog,
     },
     multicast::transport::TransportMulticastInner,
 };
@@ -133,36 +134,78 @@ pub(crate) struct TransportLinkMulticastTx {
 
 impl TransportLinkMulticastTx {
     pub(crate) async fn send_batch(&mut self, batch: &mut WBatch) -> ZResult<()> {
+        let bytes = Self::finalize(&self.inner, &mut self.buffer, batch)?;
+
+        // Send the message on the link
+        self.inner.link.write_all(bytes).await?;
+
+        Ok(())
+    }
+
+    /// Sends a batch on the link, returning `None` if the `watchdog` detected a stall.
+    pub(crate) async fn send_batch_watched(
+        &mut self,
+        batch: &mut WBatch,
+        watchdog: &TxStallWatchdog,
+    ) -> Option<ZResult<()>> {
+        let bytes = match Self::finalize(&self.inner, &mut self.buffer, batch) {
+            Ok(bytes) => bytes,
+            Err(e) => return Some(Err(e)),
+        };
+        watchdog.write_multicast(&self.inner.link, bytes).await
+    }
+
+    fn finalize<'a>(
+        link: &TransportLinkMulticast,
+        buffer: &'a mut Option<BBuf>,
+        batch: &'a mut WBatch,
+    ) -> ZResult<&'a [u8]> {
         const ERR: &str = "Write error on link: ";
 
         let res = batch
-            .finalize(self.buffer.as_mut())
-            .map_err(|_| zerror!("{ERR}{self}"))?;
+            .finalize(buffer.as_mut())
+            .map_err(|_| zerror!("{ERR}{link}"))?;
 
         let bytes = match res {
             Finalize::Batch => batch.as_slice(),
-            Finalize::Buffer => self
-                .buffer
+            Finalize::Buffer => buffer
                 .as_ref()
                 .ok_or_else(|| zerror!("Invalid buffer finalization"))?
                 .as_slice(),
         };
+        Ok(bytes)
+    }
 
-        // Send the message on the link
-        self.inner.link.write_all(bytes).await?;
+    pub(crate) async fn send(&mut self, msg: &TransportMessage) -> ZResult<usize> {
+        let mut batch = self.encode(msg)?;
+        self.send_batch(&mut batch).await?;
+        Ok(batch.len() as usize)
+    }
 
-        Ok(())
+    /// Sends a message on the link, returning `None` if the `watchdog` detected a stall.
+    pub(crate) async fn send_watched(
+        &mut self,
+        msg: &TransportMessage,
+        watchdog: &TxStallWatchdog,
+    ) -> Option<ZResult<usize>> {
+        let mut batch = match self.encode(msg) {
+            Ok(batch) => batch,
+            Err(e) => return Some(Err(e)),
+        };
+        Some(
+            self.send_batch_watched(&mut batch, watchdog)
+                .await?
+                .map(|_| batch.len() as usize),
+        )
     }
 
-    pub(crate) async fn send(&mut self, msg: &TransportMessage) -> ZResult<usize> {
+    fn encode(&self, msg: &TransportMessage) -> ZResult<WBatch> {
         const ERR: &str = "Write error on link: ";
 
         // Create the batch for serializing the message
         let mut batch = WBatch::new(self.inner.config.batch);
         batch.encode(msg).map_err(|_| zerror!("{ERR}{self}"))?;
-        let len = batch.len() as usize;
-        self.send_batch(&mut batch).await?;
-        Ok(len)
+        Ok(batch)
     }
 }
 
@@ -326,6 +369,10 @@ impl TransportLinkMulticastUniversal {
             // Spawn the TX task
             let c_link = self.link.clone();
             let c_transport = self.transport.clone();
+            let watchdog = TxStallWatchdog::new(
+                self.transport.manager.config.multicast.tx_stall_timeout,
+                self.transport.manager.state.multicast.tx_stalls.clone(),
+            );
 
             let handle = zenoh_runtime::ZRuntime::TX.spawn(async move {
                 let res = tx_task(
@@ -333,6 +380,7 @@ impl TransportLinkMulticastUniversal {
                     c_link.tx(),
                     config,
                     initial_sns,
+                    watchdog,
                     #[cfg(feature = "stats")]
                     c_transport.link_stats.clone(),
                 )
@@ -416,6 +464,7 @@ async fn tx_task(
     mut link: TransportLinkMulticastTx,
     config: TransportLinkMulticastConfigUniversal,
     mut last_sns: Vec<PrioritySn>,
+    watchdog: TxStallWatchdog,
     #[cfg(feature = "stats")] stats: zenoh_stats::LinkStats,
 ) -> ZResult<()> {
     async fn join(last_join: Instant, join_interval: Duration) {
@@ -434,7 +483,10 @@ async fn tx_task(
                 match res {
                     Some((mut batch, priority)) => {
                         // Send the buffer on the link
-                        link.send_batch(&mut batch).await?;
+                        let Some(res) = link.send_batch_watched(&mut batch, &watchdog).await else {
+                            return Err(watchdog.stalled(&link, batch.len().into(), &pipeline.congested()).into());
+                        };
+                        res?;
                         // Keep track of next SNs
                         if let Some(sn) = batch.codec.latest_sn.reliable {
                             last_sns[priority as usize].reliable = sn;
@@ -506,7 +558,10 @@ async fn tx_task(
                 .into();
 
                 #[allow(unused_variables)] // Used when stats feature is enabled
-                let n = link.send(&message).await?;
+                let n = match link.send_watched(&message, &watchdog).await {
+                    Some(res) => res?,
+                    None => return Err(watchdog.stalled(&link, 0, &pipeline.congested()).into()),
+                };
                 #[cfg(feature = "stats")]
                 {
                     stats.inc_bytes(zenoh_stats::Tx, n as u64);
@@ -11,9 +11,15 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
-use std::{collections::HashMap, fmt, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{atomic::AtomicUsize, Arc},
+    time::Duration,
+};
 
 use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
 #[cfg(feature = "transport_compression")]
 use zenoh_config::CompressionMulticastConf;
 use zenoh_config::{Config, LinkTxConf};
@@ -34,6 +40,7 @@ use crate::{
 pub struct TransportManagerConfigMulticast {
     pub lease: Duration,
     pub keep_alive: usize,
+    pub tx_stall_timeout: Option<Duration>,
     pub join_interval: Duration,
     pub max_sessions: usize,
     pub is_qos: bool,
@@ -44,6 +51,7 @@ pub struct TransportManagerConfigMulticast {
 pub struct TransportManagerBuilderMulticast {
     lease: Duration,
     keep_alive: usize,
+    tx_stall_timeout: Option<Duration>,
     join_interval: Duration,
     max_sessions: usize,
     is_qos: bool,
@@ -57,6 +65,7 @@ impl fmt::Debug for TransportManagerBuilderMulticast {
         debug
             .field("lease", &self.lease)
             .field("keep_alive", &self.keep_alive)
+            .field("tx_stall_timeout", &self.tx_stall_timeout)
             .field("join_interval", &self.join_interval)
             .field("max_sessions", &self.max_sessions)
             .field("is_qos", &self.is_qos);
@@ -67,6 +76,8 @@ impl fmt::Debug for TransportManagerBuilderMulticast {
 }
 
 pub struct TransportManagerStateMulticast {
+    // Links closed because of a stalled transmission
+    pub(crate) tx_stalls: Arc<AtomicUsize>,
     // Established listeners
     pub(crate) link_managers: Arc<Mutex<HashMap<LinkKind, LinkManagerMulticast>>>,
     // Established transports
@@ -76,6 +87,7 @@ pub struct TransportManagerStateMulticast {
 impl fmt::Debug for TransportManagerStateMulticast {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("TransportManagerStateMulticast")
+            .field("tx_stalls", &self.tx_stalls)
             .field("link_managers", &"..")
             .field("transports", &"..")
             .finish()
@@ -107,6 +119,11 @@ impl TransportManagerBuilderMulticast {
         self
     }
 
+    pub fn tx_stall_timeout(mut self, tx_stall_timeout: Option<Duration>) -> Self {
+        self.tx_stall_timeout = tx_stall_timeout;
+        self
+    }
+
     pub fn join_interval(mut self, join_interval: Duration) -> Self {
         self.join_interval = join_interval;
         self
@@ -133,6 +150,14 @@ impl TransportManagerBuilderMulticast {
             *config.transport().link().tx().lease(),
         ));
         self = self.keep_alive(*config.transport().link().tx().keep_alive());
+        self = self.tx_stall_timeout(
+            config
+                .transport()
+                .link()
+                .tx()
+                .stall_timeout()
+                .map(Duration::from_millis),
+        );
         self = self.join_interval(Duration::from_millis(
             config.transport().multicast().join_interval().unwrap(),
         ));
@@ -146,6 +171,7 @@ impl TransportManagerBuilderMulticast {
         let config = TransportManagerConfigMulticast {
             lease: self.lease,
             keep_alive: self.keep_alive,
+            tx_stall_timeout: self.tx_stall_timeout,
             join_interval: self.join_interval,
             max_sessions: self.max_sessions,
             is_qos: self.is_qos,
@@ -154,6 +180,7 @@ impl TransportManagerBuilderMulticast {
         };
 
         let state = TransportManagerStateMulticast {
+            tx_stalls: Arc::new(AtomicUsize::new(0)),
             link_managers: Arc::new(Mutex::new(HashMap::new())),
             transports: Arc::new(Mutex::new(HashMap::new())),
         };
@@ -173,6 +200,7 @@ impl Default for TransportManagerBuilderMulticast {
         let tmb = TransportManagerBuilderMulticast {
             lease: Duration::from_millis(*link_tx.lease()),
             keep_alive: *link_tx.keep_alive(),
+            tx_stall_timeout: link_tx.stall_timeout().map(Duration::from_millis),
             join_interval: Duration::from_millis(0),
             max_sessions: 0,
             is_qos: false,
@@ -297,6 +325,13 @@ impl TransportManager {
         None
     }
 
+    pub(crate) async fn flush_multicast(&self) -> Vec<CancellationToken> {
+        zasynclock!(self.state.multicast.transports)
+            .values()
+            .filter_map(|t| t.flush())
+            .collect()
+    }
+
     pub async fn get_transports_multicast(&self) -> Vec<TransportMulticast> {
         zasynclock!(self.state.multicast.transports)
             .values()
@@ -20,6 +20,7 @@ pub(crate) mod tx;
 use std::{
     fmt::{self, Write},
     sync::{Arc, Weak},
+    time::Duration,
 };
 
 pub use manager::{
@@ -59,7 +60,7 @@ impl TransportMulticast {
     fn get_transport(&self) -> ZResult<Arc<TransportMulticastInner>> {
         self.0
             .upgrade()
-            .ok_or_else(|| zerror!("Transport multicast closed").into())
+            .ok_or_else(|| zerror!(kind = TransportFailure, "Transport multicast closed").into())
     }
 
     #[inline(always)]
@@ -112,7 +113,21 @@ impl TransportMulticast {
     #[inline(always)]
     pub fn schedule(&self, message: NetworkMessageMut) -> ZResult<bool> {
         let transport = self.get_transport()?;
-        transport.schedule(message)
+        transport.schedule(message, None)
+    }
+
+    /// Schedules a message, waiting at most `block_timeout` for room in the transmission
+    /// queue if the message is not droppable.
+    ///
+    /// Returns if the message has successfully been queued.
+    #[inline(always)]
+    pub fn schedule_with_block_timeout(
+        &self,
+        message: NetworkMessageMut,
+        block_timeout: Duration,
+    ) -> ZResult<bool> {
+        let transport = self.get_transport()?;
+        transport.schedule(message, Some(block_timeout))
     }
 
     #[cfg(feature = "stats")]
@@ -126,6 +126,7 @@ impl TransportMulticastInner {
         #[cfg(feature = "stats")]
         let link_stats = stats.link_stats(config.link.link.get_src(), config.link.link.get_dst());
 
+        let task_controller = TaskController::with_registry(manager.task_registry().clone());
         let ti = TransportMulticastInner {
             manager,
             priority_tx: priority_tx.into_boxed_slice().into(),
@@ -133,7 +134,7 @@ impl TransportMulticastInner {
             locator: config.link.link.get_dst().to_owned(),
             link: Arc::new(RwLock::new(None)),
             callback: Arc::new(RwLock::new(None)),
-            task_controller: TaskController::default(),
+            task_controller,
             #[cfg(feature = "stats")]
             stats,
             #[cfg(feature = "stats")]
@@ -407,8 +408,11 @@ impl TransportMulticastInner {
             let _ = c_self.del_peer(&c_locator, close::reason::EXPIRED);
         };
 
-        self.task_controller
-            .spawn_with_rt(zenoh_runtime::ZRuntime::Acceptor, task);
+        self.task_controller.spawn_named_with_rt(
+            format!("zenoh:peer_lease:{}", locator),
+            zenoh_runtime::ZRuntime::Acceptor,
+            task,
+        );
 
         // TODO(yuyuan): Integrate the above async task into TransportMulticastPeer
         // Store the new peer
@@ -11,6 +11,9 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
 use zenoh_core::zread;
 use zenoh_protocol::network::{NetworkMessageExt, NetworkMessageMut, NetworkMessageRef};
 use zenoh_result::ZResult;
@@ -21,14 +24,18 @@ use crate::shm::map_zmsg_to_partner;
 
 //noinspection ALL
 impl TransportMulticastInner {
-    fn schedule_on_link(&self, msg: NetworkMessageRef) -> ZResult<bool> {
+    fn schedule_on_link(
+        &self,
+        msg: NetworkMessageRef,
+        block_timeout: Option<Duration>,
+    ) -> ZResult<bool> {
         let guard = zread!(self.link);
         match guard.as_ref() {
             Some(l) => {
                 if let Some(pl) = l.pipeline.as_ref() {
                     let pl = pl.clone();
                     drop(guard);
-                    return Ok(pl.push_network_message(msg)?);
+                    return Ok(pl.push_network_message(msg, block_timeout)?);
                 }
             }
             None => {
@@ -42,16 +49,29 @@ impl TransportMulticastInner {
         Ok(false)
     }
 
+    /// Requests the link to flush its transmission pipeline. The returned token is cancelled
+    /// once the messages scheduled so far have been written on the link.
+    pub(crate) fn flush(&self) -> Option<CancellationToken> {
+        zread!(self.link)
+            .as_ref()
+            .and_then(|l| l.pipeline.as_ref())
+            .map(|pl| pl.flush())
+    }
+
     #[allow(unused_mut)] // When feature "shared-memory" is not enabled
     #[allow(clippy::let_and_return)] // When feature "stats" is not enabled
     #[inline(always)]
-    pub(super) fn schedule(&self, mut msg: NetworkMessageMut) -> ZResult<bool> {
+    pub(super) fn schedule(
+        &self,
+        mut msg: NetworkMessageMut,
+        block_timeout: Option<Duration>,
+    ) -> ZResult<bool> {
         #[cfg(feature = "shared-memory")]
         if let Some(shm_context) = &self.shm_context {
             map_zmsg_to_partner(&mut msg, &shm_context.shm_config, &shm_context.shm_provider);
         }
 
-        let res = self.schedule_on_link(msg.as_ref())?;
+        let res = self.schedule_on_link(msg.as_ref(), block_timeout)?;
 
         #[cfg(feature = "stats")]
         if res {
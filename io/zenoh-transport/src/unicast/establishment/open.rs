@@ -262,6 +262,7 @@ impl<'a, 'b: 'a> OpenFsm for &'a mut OpenLink<'b> {
             TransportBody::InitAck(init_ack) => init_ack,
             TransportBody::Close(Close { reason, .. }) => {
                 let e = zerror!(
+                    kind = TransportFailure,
                     "Received a close message (reason {}) in response to an InitSyn on: {}",
                     reason,
                     link,
@@ -540,6 +541,7 @@ impl<'a, 'b: 'a> OpenFsm for &'a mut OpenLink<'b> {
             TransportBody::OpenAck(open_ack) => open_ack,
             TransportBody::Close(Close { reason, .. }) => {
                 let e = zerror!(
+                    kind = TransportFailure,
                     "Received a close message (reason {}) in response to an OpenSyn on: {:?}",
                     close::reason_to_str(reason),
                     link,
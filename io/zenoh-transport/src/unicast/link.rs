@@ -22,7 +22,10 @@ use zenoh_protocol::{
 };
 use zenoh_result::{zerror, ZResult};
 
-use crate::common::batch::{BatchConfig, Decode, Encode, Finalize, RBatch, WBatch};
+use crate::common::{
+    batch::{BatchConfig, Decode, Encode, Finalize, RBatch, WBatch},
+    stall::TxStallWatchdog,
+};
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub(crate) enum TransportLinkUnicastDirection {
@@ -148,29 +151,53 @@ impl TransportLinkUnicastTx {
         batch: &mut WBatch,
         priority: Option<Priority>,
     ) -> ZResult<()> {
+        let bytes = Self::finalize(&self.inner, &mut self.buffer, batch)?;
+
+        // tracing::trace!("WBytes: {:02x?}", bytes);
+
+        // Send the message on the link
+        self.inner.link.write_all(bytes, priority).await?;
+
+        Ok(())
+    }
+
+    /// Sends a batch on the link, returning `None` if the `watchdog` detected a stall.
+    pub(crate) async fn send_batch_watched(
+        &mut self,
+        batch: &mut WBatch,
+        priority: Option<Priority>,
+        watchdog: &TxStallWatchdog,
+    ) -> Option<ZResult<()>> {
+        let bytes = match Self::finalize(&self.inner, &mut self.buffer, batch) {
+            Ok(bytes) => bytes,
+            Err(e) => return Some(Err(e)),
+        };
+        watchdog
+            .write_unicast(&self.inner.link, bytes, priority)
+            .await
+    }
+
+    fn finalize<'a>(
+        link: &TransportLinkUnicast,
+        buffer: &'a mut Option<BBuf>,
+        batch: &'a mut WBatch,
+    ) -> ZResult<&'a [u8]> {
         const ERR: &str = "Write error on link: ";
 
         // tracing::trace!("WBatch: {:?}", batch);
 
         let res = batch
-            .finalize(self.buffer.as_mut())
-            .map_err(|_| zerror!("{ERR}{self}"))?;
+            .finalize(buffer.as_mut())
+            .map_err(|_| zerror!("{ERR}{link}"))?;
 
         let bytes = match res {
             Finalize::Batch => batch.as_slice(),
-            Finalize::Buffer => self
-                .buffer
+            Finalize::Buffer => buffer
                 .as_ref()
                 .ok_or_else(|| zerror!("Invalid buffer finalization"))?
                 .as_slice(),
         };
-
-        // tracing::trace!("WBytes: {:02x?}", bytes);
-
-        // Send the message on the link
-        self.inner.link.write_all(bytes, priority).await?;
-
-        Ok(())
+        Ok(bytes)
     }
 
     pub(crate) async fn send(
@@ -178,14 +205,36 @@ impl TransportLinkUnicastTx {
         msg: &TransportMessage,
         priority: Option<Priority>,
     ) -> ZResult<usize> {
+        let mut batch = self.encode(msg)?;
+        self.send_batch(&mut batch, priority).await?;
+        Ok(batch.len() as usize)
+    }
+
+    /// Sends a message on the link, returning `None` if the `watchdog` detected a stall.
+    pub(crate) async fn send_watched(
+        &mut self,
+        msg: &TransportMessage,
+        priority: Option<Priority>,
+        watchdog: &TxStallWatchdog,
+    ) -> Option<ZResult<usize>> {
+        let mut batch = match self.encode(msg) {
+            Ok(batch) => batch,
+            Err(e) => return Some(Err(e)),
+        };
+        Some(
+            self.send_batch_watched(&mut batch, priority, watchdog)
+                .await?
+                .map(|_| batch.len() as usize),
+        )
+    }
+
+    fn encode(&self, msg: &TransportMessage) -> ZResult<WBatch> {
         const ERR: &str = "Write error on link: ";
 
         // Create the batch for serializing the message
         let mut batch = WBatch::new(self.inner.config.batch);
         batch.encode(msg).map_err(|_| zerror!("{ERR}{self}"))?;
-        let len = batch.len() as usize;
-        self.send_batch(&mut batch, priority).await?;
-        Ok(len)
+        Ok(batch)
     }
 }
 
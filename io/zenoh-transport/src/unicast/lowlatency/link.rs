@@ -26,13 +26,18 @@ use zenoh_protocol::transport::{
 };
 use zenoh_result::{zerror, ZResult};
 use zenoh_runtime::ZRuntime;
+use zenoh_task::{spawn_tracked, TaskName};
 
 use super::transport::TransportUnicastLowlatency;
-use crate::unicast::link::{TransportLinkUnicast, TransportLinkUnicastRx};
+use crate::{
+    common::stall::TxStallWatchdog,
+    unicast::link::{TransportLinkUnicast, TransportLinkUnicastRx},
+};
 
 pub(crate) async fn send_with_link(
     link: &LinkUnicast,
     msg: TransportMessageLowLatencyRef<'_>,
+    watchdog: &TxStallWatchdog,
     #[cfg(feature = "stats")] stats: &zenoh_stats::LinkStats,
 ) -> ZResult<()> {
     let len;
@@ -49,7 +54,7 @@ pub(crate) async fn send_with_link(
 
         buffer[0..4].copy_from_slice(&le);
 
-        link.write_all(&buffer, None).await?;
+        write_with_link(link, &buffer, watchdog).await?;
     } else {
         let mut buffer = vec![];
         let mut writer = buffer.writer();
@@ -61,7 +66,7 @@ pub(crate) async fn send_with_link(
         {
             len = buffer.len() as u32;
         }
-        link.write_all(&buffer, None).await?;
+        write_with_link(link, &buffer, watchdog).await?;
     }
     tracing::trace!("Sent: {:?}", msg);
 
@@ -73,6 +78,22 @@ pub(crate) async fn send_with_link(
     Ok(())
 }
 
+async fn write_with_link(
+    link: &LinkUnicast,
+    buffer: &[u8],
+    watchdog: &TxStallWatchdog,
+) -> ZResult<()> {
+    match watchdog.write_unicast(link, buffer, None).await {
+        Some(res) => res,
+        None => {
+            let e = watchdog.stalled(link, buffer.len(), &[]);
+            // Closing the link fails the RX task, which in turn finalizes the transport
+            let _ = link.close().await;
+            Err(e.into())
+        }
+    }
+}
+
 pub(crate) async fn read_with_link(
     link: &TransportLinkUnicastRx,
     buffer: &mut [u8],
@@ -95,29 +116,62 @@ pub(crate) async fn read_with_link(
 }
 
 impl TransportUnicastLowlatency {
-    pub(super) fn send(&self, msg: TransportMessageLowLatencyRef) -> ZResult<()> {
-        zenoh_runtime::ZRuntime::TX.block_in_place(self.send_async(msg))
+    pub(super) fn send(
+        &self,
+        msg: TransportMessageLowLatencyRef,
+        block_timeout: Option<Duration>,
+    ) -> ZResult<bool> {
+        zenoh_runtime::ZRuntime::TX.block_in_place(self.send_async(msg, block_timeout))
     }
 
-    pub(super) async fn send_async(&self, msg: TransportMessageLowLatencyRef<'_>) -> ZResult<()> {
-        let guard = zasyncwrite!(self.link);
+    /// Sends a message on the link, returning `false` if the link could not be acquired
+    /// within `block_timeout`.
+    ///
+    /// Only the wait for the link is bounded: interrupting an ongoing write would corrupt
+    /// the framing of streamed links. A write making no progress within the TX stall
+    /// timeout instead fails and closes the link.
+    pub(super) async fn send_async(
+        &self,
+        msg: TransportMessageLowLatencyRef<'_>,
+        block_timeout: Option<Duration>,
+    ) -> ZResult<bool> {
+        let guard = match block_timeout {
+            Some(timeout) => match tokio::time::timeout(timeout, self.link.write()).await {
+                Ok(guard) => guard,
+                Err(_) => return Ok(false),
+            },
+            None => zasyncwrite!(self.link),
+        };
         let link = &guard.as_ref().ok_or_else(|| zerror!("No link"))?.link;
         send_with_link(
             link,
             msg,
+            &self.tx_stall_watchdog(),
             #[cfg(feature = "stats")]
             self.link_stats.get().unwrap(),
         )
-        .await
+        .await?;
+        Ok(true)
+    }
+
+    fn tx_stall_watchdog(&self) -> TxStallWatchdog {
+        TxStallWatchdog::new(
+            self.manager.config.unicast.tx_stall_timeout,
+            self.manager.state.unicast.tx_stalls.clone(),
+        )
     }
 
     pub(super) fn start_keepalive(&self, keep_alive: Duration) {
         let c_transport = self.clone();
         let token = self.token.child_token();
+        let name = TaskName::from(format!("zenoh:keepalive:{}", self.config.zid));
+        let registration = self.manager.task_registry().register(name.clone());
         let task = async move {
+            let _registration = registration;
             let res = keepalive_task(
                 c_transport.link.clone(),
                 keep_alive,
+                c_transport.tx_stall_watchdog(),
                 token,
                 #[cfg(feature = "stats")]
                 c_transport.link_stats.clone(),
@@ -141,7 +195,7 @@ impl TransportUnicastLowlatency {
                 zenoh_runtime::ZRuntime::RX.spawn(async move { c_transport.finalize(0).await });
             }
         };
-        self.tracker.spawn_on(task, &ZRuntime::TX);
+        spawn_tracked(&self.tracker, &name, Some(ZRuntime::TX), task);
     }
 
     pub(super) fn internal_start_rx(&self, lease: Duration) {
@@ -176,7 +230,7 @@ impl TransportUnicastLowlatency {
                 tokio::select! {
                     // Async read from the underlying link
                     res = tokio::time::timeout(lease, read_with_link(&link_rx, &mut buffer, is_streamed)) => {
-                        let bytes = res.map_err(|_| zerror!("{}: expired after {} milliseconds", link_rx, lease.as_millis()))??;
+                        let bytes = res.map_err(|_| zerror!(kind = Timeout, "{}: expired after {} milliseconds", link_rx, lease.as_millis()))??;
 
                         #[cfg(feature = "stats")] {
                             let header_bytes = if is_streamed { 2 } else { 0 };
@@ -196,29 +250,29 @@ impl TransportUnicastLowlatency {
         };
 
         let c_transport = self.clone();
-        self.tracker.spawn_on(
-            async move {
-                let res = rx_task.await;
+        let name = TaskName::from(format!("zenoh:rx:{}", self.config.zid));
+        let registration = self.manager.task_registry().register(name.clone());
+        spawn_tracked(&self.tracker, &name, Some(ZRuntime::RX), async move {
+            let _registration = registration;
+            let res = rx_task.await;
+            tracing::debug!(
+                "[{}] Rx task finished with result {:?}",
+                c_transport.manager.config.zid,
+                res
+            );
+            if res.is_err() {
                 tracing::debug!(
-                    "[{}] Rx task finished with result {:?}",
+                    "[{}] <on rx exit> finalizing transport with peer: {}",
                     c_transport.manager.config.zid,
-                    res
+                    c_transport.config.zid
                 );
-                if res.is_err() {
-                    tracing::debug!(
-                        "[{}] <on rx exit> finalizing transport with peer: {}",
-                        c_transport.manager.config.zid,
-                        c_transport.config.zid
-                    );
 
-                    // Spawn a task to avoid a deadlock waiting for this same task
-                    // to finish in the close() joining its handle
-                    // WARN: Must be spawned on RX
-                    zenoh_runtime::ZRuntime::RX.spawn(async move { c_transport.finalize(0).await });
-                }
-            },
-            &ZRuntime::RX,
-        );
+                // Spawn a task to avoid a deadlock waiting for this same task
+                // to finish in the close() joining its handle
+                // WARN: Must be spawned on RX
+                zenoh_runtime::ZRuntime::RX.spawn(async move { c_transport.finalize(0).await });
+            }
+        });
     }
 }
 
@@ -228,6 +282,7 @@ impl TransportUnicastLowlatency {
 async fn keepalive_task(
     link: Arc<RwLock<Option<TransportLinkUnicast>>>,
     keep_alive: Duration,
+    watchdog: TxStallWatchdog,
     token: CancellationToken,
     #[cfg(feature = "stats")] stats: Arc<OnceLock<zenoh_stats::LinkStats>>,
 ) -> ZResult<()> {
@@ -238,7 +293,7 @@ async fn keepalive_task(
         tokio::select! {
             _ = interval.tick() => {
                 let keepailve = TransportMessageLowLatencyRef {
-                    body: TransportBodyLowLatencyRef::KeepAlive(KeepAlive),
+                    body: TransportBodyLowLatencyRef::KeepAlive(KeepAlive::new()),
                 };
 
                 let guard = zasyncwrite!(link);
@@ -246,6 +301,7 @@ async fn keepalive_task(
                 let _ = send_with_link(
                     link,
                     keepailve,
+                    &watchdog,
                     #[cfg(feature = "stats")]
                     stats.get().unwrap(),
                 )
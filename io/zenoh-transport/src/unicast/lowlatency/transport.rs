@@ -114,7 +114,7 @@ impl TransportUnicastLowlatency {
                 session: false,
             }),
         };
-        let _ = self.send_async(close).await;
+        let _ = self.send_async(close, None).await;
 
         // Terminate and clean up the transport
         self.delete().await
@@ -165,7 +165,11 @@ impl TransportUnicastLowlatency {
             }
             TransportStatus::Alive => Ok(status_guard),
             TransportStatus::Closed => {
-                let e = zerror!("Transport with peer {} is closed", self.config.zid);
+                let e = zerror!(
+                    kind = TransportFailure,
+                    "Transport with peer {} is closed",
+                    self.config.zid
+                );
                 tracing::trace!("{}", e);
                 Err(e.into())
             }
@@ -229,6 +233,10 @@ impl TransportUnicastTrait for TransportUnicastLowlatency {
         self.config.region_name.clone()
     }
 
+    fn get_clock_skew(&self) -> Option<i64> {
+        None
+    }
+
     fn get_bound(&self) -> Option<Bound> {
         self.config.bound
     }
@@ -249,9 +257,13 @@ impl TransportUnicastTrait for TransportUnicastLowlatency {
     /*************************************/
     /*                TX                 */
     /*************************************/
-    fn schedule(&self, msg: NetworkMessageMut) -> ZResult<bool> {
-        self.internal_schedule(msg)?;
-        Ok(true)
+    fn schedule(&self, msg: NetworkMessageMut, block_timeout: Option<Duration>) -> ZResult<bool> {
+        self.internal_schedule(msg, block_timeout)
+    }
+
+    fn flush(&self) -> Vec<CancellationToken> {
+        // Messages are written on the link when scheduled
+        vec![]
     }
 
     /*************************************/
@@ -11,6 +11,8 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
+use std::time::Duration;
+
 use zenoh_protocol::{
     network::{NetworkMessageExt, NetworkMessageMut},
     transport::{TransportBodyLowLatencyRef, TransportMessageLowLatencyRef},
@@ -25,7 +27,11 @@ impl TransportUnicastLowlatency {
     #[allow(unused_mut)] // When feature "shared-memory" is not enabled
     #[allow(clippy::let_and_return)] // When feature "stats" is not enabled
     #[inline(always)]
-    pub(crate) fn internal_schedule(&self, mut msg: NetworkMessageMut) -> ZResult<()> {
+    pub(crate) fn internal_schedule(
+        &self,
+        mut msg: NetworkMessageMut,
+        block_timeout: Option<Duration>,
+    ) -> ZResult<bool> {
         #[cfg(feature = "shared-memory")]
         if let Some(shm_context) = &self.shm_context {
             map_zmsg_to_partner(&mut msg, &shm_context.shm_config, &shm_context.shm_provider);
@@ -35,10 +41,10 @@ impl TransportUnicastLowlatency {
         let tmsg = TransportMessageLowLatencyRef {
             body: TransportBodyLowLatencyRef::Network(msg),
         };
-        let res = self.send(tmsg);
+        let res = self.send(tmsg, block_timeout);
 
         #[cfg(feature = "stats")]
-        if res.is_ok() {
+        if let Ok(true) = res {
             self.link_stats
                 .get()
                 .unwrap()
@@ -22,6 +22,7 @@ use std::{
 };
 
 use tokio::sync::{Mutex as AsyncMutex, MutexGuard as AsyncMutexGuard};
+use tokio_util::sync::CancellationToken;
 #[cfg(feature = "transport_compression")]
 use zenoh_config::CompressionUnicastConf;
 use zenoh_config::{Config, LinkTxConf, QoSUnicastConf, TransportUnicastConf};
@@ -40,6 +41,7 @@ use crate::unicast::establishment::ext::auth::Auth;
 #[cfg(feature = "transport_multilink")]
 use crate::unicast::establishment::ext::multilink::MultiLink;
 use crate::{
+    common::flush::FlushTracker,
     unicast::{
         lowlatency::transport::TransportUnicastLowlatency,
         transport_unicast_inner::{InitTransportError, TransportUnicastTrait},
@@ -56,6 +58,7 @@ use crate::{
 pub struct TransportManagerConfigUnicast {
     pub lease: Duration,
     pub keep_alive: usize,
+    pub tx_stall_timeout: Option<Duration>,
     pub open_timeout: Duration,
     pub accept_timeout: Duration,
     pub accept_pending: usize,
@@ -102,6 +105,10 @@ impl From<&EndPoint> for LinkKey {
 pub struct TransportManagerStateUnicast {
     // Incoming uninitialized transports
     pub(super) incoming: Arc<AtomicUsize>,
+    // Links closed because of a stalled transmission
+    pub(super) tx_stalls: Arc<AtomicUsize>,
+    // Batches flushed or dropped by the closing links
+    pub(crate) flush: FlushTracker,
     // Established listeners
     pub(super) link_managers: Arc<AsyncMutex<HashMap<LinkKey, LinkManagerUnicast>>>,
     // Established transports
@@ -119,6 +126,8 @@ impl fmt::Debug for TransportManagerStateUnicast {
         let mut debug = f.debug_struct("TransportManagerStateUnicast");
         debug
             .field("incoming", &self.incoming)
+            .field("tx_stalls", &self.tx_stalls)
+            .field("flush", &(self.flush.flushed(), self.flush.dropped()))
             .field("link_managers", &"..")
             .field("transports", &"..");
         #[cfg(feature = "transport_multilink")]
@@ -151,6 +160,7 @@ pub struct TransportManagerBuilderUnicast {
     //       target interval.
     pub(super) lease: Duration,
     pub(super) keep_alive: usize,
+    pub(super) tx_stall_timeout: Option<Duration>,
     pub(super) open_timeout: Duration,
     pub(super) accept_timeout: Duration,
     pub(super) accept_pending: usize,
@@ -171,6 +181,7 @@ impl fmt::Debug for TransportManagerBuilderUnicast {
         debug
             .field("lease", &self.lease)
             .field("keep_alive", &self.keep_alive)
+            .field("tx_stall_timeout", &self.tx_stall_timeout)
             .field("open_timeout", &self.open_timeout)
             .field("accept_timeout", &self.accept_timeout)
             .field("accept_pending", &self.accept_pending)
@@ -198,6 +209,11 @@ impl TransportManagerBuilderUnicast {
         self
     }
 
+    pub fn tx_stall_timeout(mut self, tx_stall_timeout: Option<Duration>) -> Self {
+        self.tx_stall_timeout = tx_stall_timeout;
+        self
+    }
+
     pub fn open_timeout(mut self, open_timeout: Duration) -> Self {
         self.open_timeout = open_timeout;
         self
@@ -251,6 +267,14 @@ impl TransportManagerBuilderUnicast {
             *config.transport().link().tx().lease(),
         ));
         self = self.keep_alive(*config.transport().link().tx().keep_alive());
+        self = self.tx_stall_timeout(
+            config
+                .transport()
+                .link()
+                .tx()
+                .stall_timeout()
+                .map(Duration::from_millis),
+        );
         self = self.open_timeout(Duration::from_millis(
             *config.transport().unicast().open_timeout(),
         ));
@@ -289,6 +313,7 @@ impl TransportManagerBuilderUnicast {
         let config = TransportManagerConfigUnicast {
             lease: self.lease,
             keep_alive: self.keep_alive,
+            tx_stall_timeout: self.tx_stall_timeout,
             open_timeout: self.open_timeout,
             accept_timeout: self.accept_timeout,
             accept_pending: self.accept_pending,
@@ -303,6 +328,8 @@ impl TransportManagerBuilderUnicast {
 
         let state = TransportManagerStateUnicast {
             incoming: Arc::new(AtomicUsize::new(0)),
+            tx_stalls: Arc::new(AtomicUsize::new(0)),
+            flush: FlushTracker::default(),
             link_managers: Arc::new(AsyncMutex::new(HashMap::new())),
             transports: Arc::new(AsyncMutex::new(HashMap::new())),
             #[cfg(feature = "transport_multilink")]
@@ -328,6 +355,7 @@ impl Default for TransportManagerBuilderUnicast {
         Self {
             lease: Duration::from_millis(*link_tx.lease()),
             keep_alive: *link_tx.keep_alive(),
+            tx_stall_timeout: link_tx.stall_timeout().map(Duration::from_millis),
             open_timeout: Duration::from_millis(*transport.open_timeout()),
             accept_timeout: Duration::from_millis(*transport.accept_timeout()),
             accept_pending: *transport.accept_pending(),
@@ -352,6 +380,37 @@ impl TransportManager {
         TransportManagerBuilderUnicast::default()
     }
 
+    /// Returns the number of links closed because their transmission stalled for longer than
+    /// the configured TX stall timeout.
+    pub fn tx_stall_count(&self) -> usize {
+        self.state.unicast.tx_stalls.load(SeqCst) + self.state.multicast.tx_stalls.load(SeqCst)
+    }
+
+    /// Returns the number of batches written while flushing the transmission pipelines of the
+    /// closing links.
+    pub fn flushed_batch_count(&self) -> usize {
+        self.state.unicast.flush.flushed()
+    }
+
+    /// Returns the number of batches dropped while flushing the transmission pipelines of the
+    /// closing links, because the flush failed, timed out or was aborted.
+    pub fn dropped_batch_count(&self) -> usize {
+        self.state.unicast.flush.dropped()
+    }
+
+    /// Aborts the ongoing and future flushes of the transmission pipelines of the closing links,
+    /// so that they are closed without waiting for their pending batches to be written.
+    pub fn abort_flush(&self) {
+        self.state.unicast.flush.abort();
+    }
+
+    pub(crate) async fn flush_unicast(&self) -> Vec<CancellationToken> {
+        zasynclock!(self.state.unicast.transports)
+            .values()
+            .flat_map(|t| t.flush())
+            .collect()
+    }
+
     pub async fn close_unicast(&self) {
         tracing::trace!("TransportManagerUnicast::clear()");
 
@@ -605,7 +664,11 @@ impl TransportManager {
 
         // Verify that the node does not try to connect to itself
         if config.zid == self.zid() {
-            let e = zerror!("{} Attempt to establish transport to itself", self.zid());
+            let e = zerror!(
+                kind = TransportFailure,
+                "{} Attempt to establish transport to itself",
+                self.zid()
+            );
             tracing::warn!("{e}");
             let (l, asl) = link.fail();
             return Err(InitTransportError::Link((
@@ -619,6 +682,7 @@ impl TransportManager {
         // Verify that we haven't reached the transport number limit
         if guard.len() >= self.config.unicast.max_sessions {
             let e = zerror!(
+                kind = TransportFailure,
                 "Max transports reached ({}). Denying new transport with peer: {}",
                 self.config.unicast.max_sessions,
                 config.zid
@@ -886,7 +950,7 @@ impl TransportManager {
             }
         })
         .await
-        .map_err(|e| zerror!("{e}"))?
+        .map_err(|e| zerror!(kind = Timeout, "{e}"))?
     }
 
     pub async fn get_transport_unicast(&self, peer: &ZenohIdProto) -> Option<TransportUnicast> {
@@ -942,8 +1006,11 @@ impl TransportManager {
 
         // Spawn a task to accept the link
         let c_manager = self.clone();
-        self.task_controller
-            .spawn_with_rt(zenoh_runtime::ZRuntime::Acceptor, async move {
+        let name = format!("zenoh:accept:{}", link.get_dst());
+        self.task_controller.spawn_named_with_rt(
+            name,
+            zenoh_runtime::ZRuntime::Acceptor,
+            async move {
                 if tokio::time::timeout(
                     c_manager.config.unicast.accept_timeout,
                     super::establishment::accept::accept_link(link, &c_manager),
@@ -957,7 +1024,8 @@ impl TransportManager {
                     );
                 }
                 incoming_counter.fetch_sub(1, SeqCst);
-            });
+            },
+        );
     }
 }
 
@@ -24,6 +24,7 @@ pub(crate) mod universal;
 use std::{
     fmt,
     sync::{Arc, Weak},
+    time::Duration,
 };
 
 #[cfg(feature = "transport_multilink")]
@@ -78,7 +79,7 @@ impl TransportUnicast {
     pub(super) fn get_inner(&self) -> ZResult<Arc<dyn TransportUnicastTrait>> {
         self.0
             .upgrade()
-            .ok_or_else(|| zerror!("Transport unicast closed").into())
+            .ok_or_else(|| zerror!(kind = TransportFailure, "Transport unicast closed").into())
     }
 
     #[inline(always)]
@@ -99,6 +100,14 @@ impl TransportUnicast {
         Ok(transport.get_bound())
     }
 
+    /// Returns the estimated skew in nanoseconds between the clock of the remote peer and the
+    /// local one, if the peer sends timestamped keep-alives and a local HLC is configured.
+    #[inline(always)]
+    pub fn get_clock_skew(&self) -> ZResult<Option<i64>> {
+        let transport = self.get_inner()?;
+        Ok(transport.get_clock_skew())
+    }
+
     #[cfg(feature = "shared-memory")]
     #[inline(always)]
     pub fn is_shm(&self) -> ZResult<bool> {
@@ -140,7 +149,23 @@ impl TransportUnicast {
     #[inline(always)]
     pub fn schedule(&self, message: NetworkMessageMut) -> ZResult<bool> {
         let transport = self.get_inner()?;
-        transport.schedule(message)
+        transport.schedule(message, None)
+    }
+
+    /// Schedules a message, waiting at most `block_timeout` for room in the transmission
+    /// queues if the message is not droppable.
+    ///
+    /// Unlike with [`schedule`](Self::schedule), a message that could not be queued within
+    /// `block_timeout` is dropped without closing the transport. Returns if the message has
+    /// successfully been queued.
+    #[inline(always)]
+    pub fn schedule_with_block_timeout(
+        &self,
+        message: NetworkMessageMut,
+        block_timeout: Duration,
+    ) -> ZResult<bool> {
+        let transport = self.get_inner()?;
+        transport.schedule(message, Some(block_timeout))
     }
 
     #[inline(always)]
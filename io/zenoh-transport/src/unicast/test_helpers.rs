@@ -19,6 +19,7 @@ use std::{
 
 use async_trait::async_trait;
 use tokio::sync::MutexGuard as AsyncMutexGuard;
+use tokio_util::sync::CancellationToken;
 use zenoh_core::zcondfeat;
 use zenoh_link::Link;
 use zenoh_protocol::{
@@ -105,6 +106,10 @@ impl TransportUnicastTrait for MockTransportUnicastInner {
         None
     }
 
+    fn get_clock_skew(&self) -> Option<i64> {
+        None
+    }
+
     fn get_config(&self) -> &TransportConfigUnicast {
         unimplemented!("MockTransportUnicastInner::get_config")
     }
@@ -123,7 +128,7 @@ impl TransportUnicastTrait for MockTransportUnicastInner {
         unimplemented!("MockTransportUnicastInner::add_link")
     }
 
-    fn schedule(&self, msg: NetworkMessageMut) -> ZResult<bool> {
+    fn schedule(&self, msg: NetworkMessageMut, _block_timeout: Option<Duration>) -> ZResult<bool> {
         let body = match msg.body {
             NetworkBodyMut::Push(p) => NetworkBody::Push(p.clone()),
             NetworkBodyMut::Request(r) => NetworkBody::Request(r.clone()),
@@ -140,6 +145,10 @@ impl TransportUnicastTrait for MockTransportUnicastInner {
         Ok(true)
     }
 
+    fn flush(&self) -> Vec<CancellationToken> {
+        vec![]
+    }
+
     async fn close(&self, _reason: u8) -> ZResult<()> {
         Ok(())
     }
@@ -16,6 +16,7 @@ use std::{fmt::DebugStruct, sync::Arc, time::Duration};
 
 use async_trait::async_trait;
 use tokio::sync::MutexGuard as AsyncMutexGuard;
+use tokio_util::sync::CancellationToken;
 use zenoh_link::Link;
 use zenoh_protocol::{
     core::{Bound, RegionName, WhatAmI, ZenohIdProto},
@@ -80,6 +81,7 @@ pub(crate) trait TransportUnicastTrait: Send + Sync {
     fn is_qos(&self) -> bool;
     fn region_name(&self) -> Option<RegionName>;
     fn get_bound(&self) -> Option<Bound>;
+    fn get_clock_skew(&self) -> Option<i64>;
     fn get_config(&self) -> &TransportConfigUnicast;
     #[cfg(feature = "stats")]
     fn stats(&self) -> zenoh_stats::TransportStats;
@@ -98,7 +100,10 @@ pub(crate) trait TransportUnicastTrait: Send + Sync {
     /*                TX                 */
     /*************************************/
     /// Returns if the message has successfully been sent.
-    fn schedule(&self, msg: NetworkMessageMut) -> ZResult<bool>;
+    fn schedule(&self, msg: NetworkMessageMut, block_timeout: Option<Duration>) -> ZResult<bool>;
+    /// Requests the links to flush their transmission pipelines. The returned tokens are
+    /// cancelled once the messages scheduled so far have been written on the links.
+    fn flush(&self) -> Vec<CancellationToken>;
 
     /*************************************/
     /*            TERMINATION            */
@@ -36,6 +36,7 @@ use std::{
 use futures::{future::select_all, task::AtomicWaker};
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
+use uhlc::HLC;
 use zenoh_buffers::ZSlice;
 #[cfg(all(
     feature = "uring",
@@ -82,11 +83,13 @@ use super::transport::TransportUnicastUniversal;
 use crate::{
     common::{
         batch::{BatchConfig, RBatch},
+        flush::FlushTracker,
         pipeline::{
             PipelineConsumer, TransmissionPipeline, TransmissionPipelineConf,
             TransmissionPipelineConsumer, TransmissionPipelineProducer,
         },
         priority::TransportPriorityTx,
+        stall::TxStallWatchdog,
     },
     unicast::link::{TransportLinkUnicast, TransportLinkUnicastRx, TransportLinkUnicastTx},
 };
@@ -162,7 +165,9 @@ impl TransportLinkUnicastUniversal {
         let result = Self {
             link,
             pipeline: producer,
-            task_controller: TaskController::default(),
+            task_controller: TaskController::with_registry(
+                transport.manager.task_registry().clone(),
+            ),
             #[cfg(feature = "unstable")]
             block_first_notifiers: block_first_notifiers.try_into().ok().unwrap(),
             #[cfg(feature = "unstable")]
@@ -185,11 +190,20 @@ impl TransportLinkUnicastUniversal {
         #[cfg(feature = "stats")]
         let stats = self.stats.clone();
         let ct = self.task_controller.get_cancellation_token();
+        let watchdog = TxStallWatchdog::new(
+            transport.manager.config.unicast.tx_stall_timeout,
+            transport.manager.state.unicast.tx_stalls.clone(),
+        );
+        let hlc = transport.manager.config.hlc.clone();
+        let flush = transport.manager.state.unicast.flush.clone();
         let task = async move {
             let res = tx_task(
                 consumer,
                 &mut tx,
                 keep_alive,
+                hlc,
+                watchdog,
+                flush,
                 ct,
                 #[cfg(feature = "stats")]
                 stats,
@@ -206,8 +220,9 @@ impl TransportLinkUnicastUniversal {
                     .spawn(async move { transport.del_link(tx.inner.link()).await });
             }
         };
+        let name = format!("zenoh:tx:{}", self.link.link.get_dst());
         self.task_controller
-            .spawn_with_rt(zenoh_runtime::ZRuntime::TX, task);
+            .spawn_named_with_rt(name, zenoh_runtime::ZRuntime::TX, task);
     }
 
     pub(super) fn start_rx(&mut self, transport: TransportUnicastUniversal, lease: Duration) {
@@ -254,8 +269,9 @@ impl TransportLinkUnicastUniversal {
             }
         };
         // WARN: If this is on ZRuntime::TX, a deadlock would occur.
+        let name = format!("zenoh:rx:{}", self.link.link.get_dst());
         self.task_controller
-            .spawn_with_rt(zenoh_runtime::ZRuntime::RX, task);
+            .spawn_named_with_rt(name, zenoh_runtime::ZRuntime::RX, task);
     }
 
     pub(super) async fn close(self) -> ZResult<()> {
@@ -270,10 +286,14 @@ impl TransportLinkUnicastUniversal {
 /*************************************/
 /*              TASKS                */
 /*************************************/
+#[allow(clippy::too_many_arguments)]
 async fn tx_task(
     pipeline: TransmissionPipelineConsumer,
     link: &mut TransportLinkUnicastTx,
     keep_alive: Duration,
+    hlc: Option<Arc<HLC>>,
+    watchdog: TxStallWatchdog,
+    flush: FlushTracker,
     cancellation_token: CancellationToken,
     #[cfg(feature = "stats")] stats: zenoh_stats::LinkStats,
 ) -> ZResult<()> {
@@ -283,6 +303,9 @@ async fn tx_task(
             let mut link = link.clone();
             let cancellation_token = cancellation_token.clone();
             let keep_alive_tracker = keep_alive_tracker.clone();
+            let hlc = hlc.clone();
+            let watchdog = watchdog.clone();
+            let flush = flush.clone();
             #[cfg(feature = "stats")]
             let stats = stats.clone();
             zenoh_runtime::ZRuntime::TX.spawn(async move {
@@ -291,6 +314,9 @@ async fn tx_task(
                     pipeline,
                     &mut link,
                     keep_alive_tracker,
+                    hlc,
+                    watchdog,
+                    flush,
                     cancellation_token,
                     #[cfg(feature = "stats")]
                     stats,
@@ -306,6 +332,9 @@ async fn tx_task(
             pipeline,
             link,
             keep_alive_tracker,
+            hlc,
+            watchdog,
+            flush,
             cancellation_token,
             #[cfg(feature = "stats")]
             stats,
@@ -315,11 +344,15 @@ async fn tx_task(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn write_loop(
     write_priority: Option<Priority>,
     mut pipeline: impl PipelineConsumer,
     link: &mut TransportLinkUnicastTx,
     keep_alive_tracker: TimeoutTracker,
+    hlc: Option<Arc<HLC>>,
+    watchdog: TxStallWatchdog,
+    flush: FlushTracker,
     cancellation_token: CancellationToken,
     #[cfg(feature = "stats")] stats: zenoh_stats::LinkStats,
 ) -> ZResult<()> {
@@ -332,7 +365,10 @@ async fn write_loop(
                         break
                     };
                     debug_assert!(write_priority.is_none() || write_priority == Some(priority));
-                    link.send_batch(&mut batch, write_priority).await?;
+                    let Some(res) = link.send_batch_watched(&mut batch, write_priority, &watchdog).await else {
+                        return Err(watchdog.stalled(link, batch.len().into(), &pipeline.congested()).into());
+                    };
+                    res?;
                     // inform the latest message tracker that a message has been sent
                     keep_alive_tracker.reset();
 
@@ -348,10 +384,16 @@ async fn write_loop(
                 _ = keep_alive_tracker.wait_if(write_priority.unwrap_or(Priority::Control) == Priority::Control) => {
                     // A timeout occurred, no control/data messages have been sent during
                     // the keep_alive period, we need to send a KeepAlive message
-                    let message: TransportMessage = KeepAlive.into();
+                    let keep_alive = hlc
+                        .as_ref()
+                        .map_or(KeepAlive::new(), |hlc| KeepAlive::with_timestamp(hlc.new_timestamp().get_time().as_u64()));
+                    let message: TransportMessage = keep_alive.into();
 
                     #[allow(unused_variables)] // Used when stats feature is enabled
-                    let n = link.send(&message, Some(Priority::Control)).await?;
+                    let n = match link.send_watched(&message, Some(Priority::Control), &watchdog).await {
+                        Some(res) => res?,
+                        None => return Err(watchdog.stalled(link, 0, &pipeline.congested()).into()),
+                    };
 
                     #[cfg(feature = "stats")]
                     {
@@ -369,18 +411,27 @@ async fn write_loop(
 
     // Drain the transmission pipeline and write remaining bytes on the wire
     let mut batches = pipeline.drain();
+    let mut remaining = batches.len();
     for (mut b, _) in batches.drain(..) {
-        tokio::time::timeout(
-            keep_alive_tracker.timeout(),
-            link.send_batch(&mut b, write_priority),
-        )
-        .await
-        .map_err(|_| {
-            zerror!(
-                "{link}: flush failed after {} ms",
-                keep_alive_tracker.timeout().as_millis()
-            )
-        })??;
+        let res = tokio::select! {
+            res = tokio::time::timeout(
+                keep_alive_tracker.timeout(),
+                link.send_batch(&mut b, write_priority),
+            ) => res.map_err(|_| {
+                zerror!(
+                    "{link}: flush failed after {} ms",
+                    keep_alive_tracker.timeout().as_millis()
+                )
+                .into()
+            }).and_then(|res| res),
+            _ = flush.aborted() => Err(zerror!("{link}: flush aborted").into()),
+        };
+        if let Err(e) = res {
+            flush.add_dropped(remaining);
+            return Err(e);
+        }
+        flush.add_flushed(1);
+        remaining -= 1;
 
         #[cfg(feature = "stats")]
         {
@@ -533,7 +584,7 @@ async fn read_loop<F: Fn() -> Box<[u8]>>(
                 transport.read_messages(batch, &l, #[cfg(feature = "stats")] &stats)?;
             }
             _ = lease_tracker.wait_if(priority.unwrap_or(Priority::Control) == Priority::Control) => {
-                bail!("{link}: expired after {} milliseconds", lease_tracker.timeout().as_millis());
+                bail!(kind = Timeout, "{link}: expired after {} milliseconds", lease_tracker.timeout().as_millis());
             }
         }
     }
@@ -758,7 +809,7 @@ async fn rx_task_uring(
         }
         _ = lease_tracker.wait_if(true) => {
             tracing::debug!("Uring RX task stopped by lease timeout");
-            bail!("{link}: expired after {} milliseconds", lease.as_millis());
+            bail!(kind = Timeout, "{link}: expired after {} milliseconds", lease.as_millis());
         }
         _ = cancellation_token.cancelled() => {
             tracing::debug!("Uring RX task stopped by cancellation event");
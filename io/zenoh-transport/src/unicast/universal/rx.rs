@@ -18,7 +18,7 @@ use zenoh_codec::transport::frame::FrameReader;
 use zenoh_core::zlock;
 use zenoh_link::Link;
 use zenoh_protocol::{
-    core::{Priority, Reliability},
+    core::{Priority, Reliability, NTP64},
     network::NetworkMessageMut,
     transport::{Close, Fragment, KeepAlive, TransportBody, TransportMessage, TransportSn},
 };
@@ -273,7 +273,15 @@ impl TransportUnicastUniversal {
                 TransportBody::Close(Close { reason, session }) => {
                     self.handle_close(link, reason, session)?
                 }
-                TransportBody::KeepAlive(KeepAlive { .. }) => {}
+                TransportBody::KeepAlive(KeepAlive { ext_timestamp }) => {
+                    if let (Some(timestamp), Some(_)) = (ext_timestamp, &self.manager.config.hlc) {
+                        self.clock_skew.observe(
+                            NTP64(timestamp.value),
+                            &self.config.zid,
+                            self.manager.config.clock_skew_warning_threshold,
+                        );
+                    }
+                }
                 _ => {
                     tracing::debug!(
                         "Transport: {}. Message handling not implemented: {:?}",
@@ -23,6 +23,7 @@ use std::{
 
 use async_trait::async_trait;
 use tokio::sync::{Mutex as AsyncMutex, MutexGuard as AsyncMutexGuard};
+use tokio_util::sync::CancellationToken;
 use zenoh_core::{zasynclock, zcondfeat, zread, zwrite};
 use zenoh_link::Link;
 use zenoh_protocol::{
@@ -35,7 +36,10 @@ use zenoh_result::{bail, zerror, ZResult};
 #[cfg(feature = "shared-memory")]
 use crate::shm_context::UnicastTransportShmContext;
 use crate::{
-    common::priority::{TransportPriorityRx, TransportPriorityTx},
+    common::{
+        clock_skew::ClockSkewEstimator,
+        priority::{TransportPriorityRx, TransportPriorityTx},
+    },
     unicast::{
         authentication::TransportAuthId,
         link::{LinkUnicastWithOpenAck, TransportLinkUnicastDirection},
@@ -94,6 +98,8 @@ pub(crate) struct TransportUnicastUniversal {
     pub(super) links: Arc<RwLock<TransportLinks>>,
     // The callback
     pub(super) callback: Arc<ClosableCallback>,
+    // The clock skew with the remote peer
+    pub(super) clock_skew: Arc<ClockSkewEstimator>,
     // Mutex for notification
     pub(super) status: Arc<AsyncMutex<TransportStatus>>,
     // Transport statistics
@@ -138,6 +144,7 @@ impl TransportUnicastUniversal {
             priority_rx: priority_rx.into_boxed_slice().into(),
             links: Arc::new(RwLock::new(TransportLinks::default())),
             callback: Arc::new(ClosableCallback::new()),
+            clock_skew: Arc::new(ClockSkewEstimator::new()),
             status: Arc::new(AsyncMutex::new(TransportStatus::Uninitialized)),
             #[cfg(feature = "stats")]
             stats,
@@ -245,7 +252,11 @@ impl TransportUnicastUniversal {
             }
             TransportStatus::Alive => Ok(status_guard),
             TransportStatus::Closed => {
-                let e = zerror!("Transport with peer {} is closed", self.config.zid);
+                let e = zerror!(
+                    kind = TransportFailure,
+                    "Transport with peer {} is closed",
+                    self.config.zid
+                );
                 tracing::trace!("{}", e);
                 Err(e.into())
             }
@@ -378,6 +389,10 @@ impl TransportUnicastTrait for TransportUnicastUniversal {
         self.config.region_name.clone()
     }
 
+    fn get_clock_skew(&self) -> Option<i64> {
+        self.clock_skew.get()
+    }
+
     fn get_bound(&self) -> Option<Bound> {
         self.config.bound
     }
@@ -448,8 +463,16 @@ impl TransportUnicastTrait for TransportUnicastUniversal {
     /*************************************/
     /*                TX                 */
     /*************************************/
-    fn schedule(&self, msg: NetworkMessageMut) -> ZResult<bool> {
-        self.internal_schedule(msg)
+    fn schedule(&self, msg: NetworkMessageMut, block_timeout: Option<Duration>) -> ZResult<bool> {
+        self.internal_schedule(msg, block_timeout)
+    }
+
+    fn flush(&self) -> Vec<CancellationToken> {
+        zread!(self.links)
+            .get_links()
+            .iter()
+            .map(|l| l.pipeline.flush())
+            .collect()
     }
 
     fn add_debug_fields<'a, 'b: 'a, 'c>(
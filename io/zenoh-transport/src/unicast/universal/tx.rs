@@ -11,6 +11,7 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
+use std::time::Duration;
 
 #[cfg(feature = "unstable")]
 use zenoh_protocol::core::CongestionControl;
@@ -76,9 +77,12 @@ impl TransportUnicastUniversal {
         &self,
         msg: NetworkMessageRef,
         pushed: bool,
+        block_timeout: Option<Duration>,
         #[cfg(feature = "stats")] stats: zenoh_stats::LinkStats,
     ) {
-        if !pushed && !msg.is_droppable() {
+        // A message with a block timeout is dropped when the timeout expires, without
+        // considering the peer unresponsive
+        if !pushed && !msg.is_droppable() && block_timeout.is_none() {
             tracing::error!(
                 "Unable to push non droppable network message to {}. Closing transport!",
                 self.config.zid
@@ -107,7 +111,11 @@ impl TransportUnicastUniversal {
     #[allow(unused_mut)] // When feature "shared-memory" is not enabled
     #[allow(clippy::let_and_return)] // When feature "stats" is not enabled
     #[inline(always)]
-    pub(crate) fn internal_schedule(&self, mut msg: NetworkMessageMut) -> ZResult<bool> {
+    pub(crate) fn internal_schedule(
+        &self,
+        mut msg: NetworkMessageMut,
+        block_timeout: Option<Duration>,
+    ) -> ZResult<bool> {
         #[cfg(feature = "shared-memory")]
         if let Some(shm_context) = &self.shm_context {
             map_zmsg_to_partner(&mut msg, &shm_context.shm_config, &shm_context.shm_provider);
@@ -174,10 +182,11 @@ impl TransportUnicastUniversal {
             let msg = NetworkMessageExt::to_owned(&msg);
             zenoh_runtime::ZRuntime::Net.spawn_blocking(move || {
                 let msg = msg.as_ref();
-                if let Ok(pushed) = pipeline.push_network_message(msg) {
+                if let Ok(pushed) = pipeline.push_network_message(msg, block_timeout) {
                     transport.handle_push_result(
                         msg,
                         pushed,
+                        block_timeout,
                         #[cfg(feature = "stats")]
                         stats,
                     );
@@ -193,10 +202,11 @@ impl TransportUnicastUniversal {
         // block for fairly long time
         drop(transport_links);
 
-        let pushed = pipeline.push_network_message(msg)?;
+        let pushed = pipeline.push_network_message(msg, block_timeout)?;
         self.handle_push_result(
             msg,
             pushed,
+            block_timeout,
             #[cfg(feature = "stats")]
             stats,
         );
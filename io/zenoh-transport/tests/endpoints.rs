@@ -209,6 +209,76 @@ async fn endpoint_tcp_udp() {
     run(&endpoints).await;
 }
 
+#[cfg(all(
+    feature = "transport_tcp",
+    feature = "transport_udp",
+    target_os = "linux"
+))]
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn endpoint_tcp_udp_ipv6_zone_id() {
+    zenoh_util::init_log_from_env_or("error");
+    // Define the locators, with both interface names and interface indexes as zone ids
+    let lo = zenoh_util::net::get_index_of_interface_name("lo").unwrap();
+    let addresses = [
+        format!("tcp/[::1%lo]:{}", get_free_tcp_port()),
+        format!("udp/[::1%lo]:{}", get_free_udp_port()),
+        format!("tcp/[::1%{lo}]:{}", get_free_tcp_port()),
+        format!("udp/[::1%{lo}]:{}", get_free_udp_port()),
+    ];
+    let endpoints: Vec<EndPoint> = addresses.iter().map(|a| a.parse().unwrap()).collect();
+    // The zone ids are preserved
+    for (address, endpoint) in addresses.iter().zip(endpoints.iter()) {
+        assert_eq!(&endpoint.to_string(), address);
+        assert_eq!(&endpoint.to_locator().to_string(), address);
+    }
+    run(&endpoints).await;
+}
+
+#[cfg(all(
+    feature = "transport_tcp",
+    feature = "transport_udp",
+    target_os = "linux"
+))]
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn endpoint_tcp_udp_unknown_interface() {
+    zenoh_util::init_log_from_env_or("error");
+    const IFACE: &str = "zenohbogus0";
+
+    let sm = TransportManager::builder()
+        .whatami(WhatAmI::Peer)
+        .zid(ZenohIdProto::try_from([1]).unwrap())
+        .build_test(Arc::new(SH))
+        .unwrap();
+
+    let endpoints: Vec<EndPoint> = vec![
+        format!("tcp/[::1%{IFACE}]:{}", get_free_tcp_port())
+            .parse()
+            .unwrap(),
+        format!("udp/[::1%{IFACE}]:{}", get_free_udp_port())
+            .parse()
+            .unwrap(),
+        format!("tcp/127.0.0.1:{}#iface={IFACE}", get_free_tcp_port())
+            .parse()
+            .unwrap(),
+        format!("udp/127.0.0.1:{}#iface={IFACE}", get_free_udp_port())
+            .parse()
+            .unwrap(),
+        format!("tcp/[::1]:{}#iface={IFACE}", get_free_tcp_port())
+            .parse()
+            .unwrap(),
+    ];
+    for e in endpoints {
+        println!("Add {e}");
+        let error = ztimeout!(sm.add_listener(e.clone())).unwrap_err();
+        assert!(
+            error.to_string().contains(IFACE),
+            "unexpected error for {e}: {error}"
+        );
+    }
+
+    ztimeout!(sm.close());
+}
+
 #[cfg(all(
     feature = "transport_tcp",
     feature = "transport_udp",
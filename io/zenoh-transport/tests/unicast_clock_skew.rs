@@ -0,0 +1,119 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#![cfg(feature = "transport_tcp")]
+use std::{convert::TryFrom, sync::Arc, time::Duration};
+
+use uhlc::{HLCBuilder, HLC, NTP64};
+use zenoh_core::ztimeout;
+use zenoh_link::EndPoint;
+use zenoh_protocol::core::{WhatAmI, ZenohIdProto};
+use zenoh_result::ZResult;
+use zenoh_test::get_free_tcp_port;
+use zenoh_transport::{
+    multicast::TransportMulticast, unicast::TransportUnicast, DummyTransportPeerEventHandler,
+    TransportEventHandler, TransportManager, TransportMulticastEventHandler, TransportPeer,
+    TransportPeerEventHandler,
+};
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+const SLEEP: Duration = Duration::from_millis(100);
+const LEASE: Duration = Duration::from_millis(1_000);
+
+/// Clock skew injected in the HLC of the client.
+const SKEW: Duration = Duration::from_secs(2);
+/// Tolerance on the estimated skew, accounting for the transmission delays.
+const TOLERANCE: Duration = Duration::from_millis(500);
+
+#[derive(Default)]
+struct SHDummy;
+
+impl TransportEventHandler for SHDummy {
+    fn new_unicast(
+        &self,
+        _peer: TransportPeer,
+        _transport: TransportUnicast,
+    ) -> ZResult<Arc<dyn TransportPeerEventHandler>> {
+        Ok(Arc::new(DummyTransportPeerEventHandler))
+    }
+
+    fn new_multicast(
+        &self,
+        _transport: TransportMulticast,
+    ) -> ZResult<Arc<dyn TransportMulticastEventHandler>> {
+        panic!();
+    }
+}
+
+fn lagging_clock() -> NTP64 {
+    NTP64::from(uhlc::system_time_clock().to_duration() - SKEW)
+}
+
+fn transport_manager(zid: ZenohIdProto, whatami: WhatAmI, hlc: HLC) -> TransportManager {
+    TransportManager::builder()
+        .whatami(whatami)
+        .zid(zid)
+        .hlc(Some(Arc::new(hlc)))
+        .unicast(TransportManager::config_unicast().lease(LEASE))
+        .build_test(Arc::new(SHDummy))
+        .unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn transport_unicast_clock_skew() {
+    zenoh_util::init_log_from_env_or("error");
+    let client_id = ZenohIdProto::try_from([1]).unwrap();
+    let router_id = ZenohIdProto::try_from([2]).unwrap();
+
+    let router_manager = transport_manager(router_id, WhatAmI::Router, HLC::default());
+    let client_manager = transport_manager(
+        client_id,
+        WhatAmI::Client,
+        HLCBuilder::new().with_clock(lagging_clock).build(),
+    );
+
+    let endpoint: EndPoint = format!("tcp/127.0.0.1:{}", get_free_tcp_port())
+        .parse()
+        .unwrap();
+    let _ = ztimeout!(router_manager.add_listener(endpoint.clone())).unwrap();
+    let client_transport = ztimeout!(client_manager.open_transport_unicast(endpoint)).unwrap();
+
+    // The skew is estimated on the router from the keep-alives of the client
+    let router_transport = ztimeout!(router_manager.get_transport_unicast(&client_id)).unwrap();
+    let skew = ztimeout!(async {
+        loop {
+            if let Some(skew) = router_transport.get_clock_skew().unwrap() {
+                break skew;
+            }
+            tokio::time::sleep(SLEEP).await;
+        }
+    });
+    let error = skew + SKEW.as_nanos() as i64;
+    assert!(
+        error.unsigned_abs() < TOLERANCE.as_nanos() as u64,
+        "estimated skew {skew}ns, injected -{}ns",
+        SKEW.as_nanos()
+    );
+
+    // Once closed, the transport and its estimate are gone
+    ztimeout!(client_transport.close()).unwrap();
+    ztimeout!(async {
+        while !router_manager.get_transports_unicast().await.is_empty() {
+            tokio::time::sleep(SLEEP).await;
+        }
+    });
+    assert!(router_transport.get_clock_skew().is_err());
+
+    ztimeout!(router_manager.close());
+    ztimeout!(client_manager.close());
+}
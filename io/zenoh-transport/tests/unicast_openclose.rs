@@ -533,14 +533,25 @@ async fn openclose_lowlatency_transport(endpoint: &EndPoint) {
 
 #[cfg(any(feature = "transport_tls", feature = "transport_quic"))]
 async fn openclose_universal_transport_tls(
-    mut endpoint: EndPoint,
+    endpoint: EndPoint,
     with_certificate_common_name: bool,
     with_mtls: bool,
 ) {
-    use zenoh_link_commons::tls::config::*;
-
     zenoh_util::init_log_from_env_or("error");
 
+    let (listen_endpoint, connect_endpoint) =
+        get_tls_endpoints(endpoint, with_certificate_common_name, with_mtls);
+    openclose_transport(&listen_endpoint, &connect_endpoint, false).await;
+}
+
+#[cfg(any(feature = "transport_tls", feature = "transport_quic"))]
+fn get_tls_endpoints(
+    mut endpoint: EndPoint,
+    with_certificate_common_name: bool,
+    with_mtls: bool,
+) -> (EndPoint, EndPoint) {
+    use zenoh_link_commons::tls::config::*;
+
     let (ca, cert, key) = match with_certificate_common_name {
         false => get_tls_certs(),
         true => get_tls_certs_without_common_name(),
@@ -581,7 +592,80 @@ async fn openclose_universal_transport_tls(
             .unwrap();
     }
 
-    openclose_transport(&listen_endpoint, &connect_endpoint, false).await;
+    (listen_endpoint, connect_endpoint)
+}
+
+#[cfg(any(feature = "transport_tls", feature = "transport_quic"))]
+async fn auth_id_transport_tls(endpoint: EndPoint, with_certificate_common_name: bool) {
+    zenoh_util::init_log_from_env_or("error");
+
+    let (listen_endpoint, connect_endpoint) =
+        get_tls_endpoints(endpoint, with_certificate_common_name, true);
+
+    let router_id = ZenohIdProto::try_from([1]).unwrap();
+    let router_manager = TransportManager::builder()
+        .whatami(WhatAmI::Router)
+        .zid(router_id)
+        .unicast(make_transport_manager_builder(
+            #[cfg(feature = "transport_multilink")]
+            1,
+            false,
+        ))
+        .build_test(Arc::new(SHRouterOpenClose))
+        .unwrap();
+
+    let client_id = ZenohIdProto::try_from([2]).unwrap();
+    let client_manager = TransportManager::builder()
+        .whatami(WhatAmI::Client)
+        .zid(client_id)
+        .unicast(make_transport_manager_builder(
+            #[cfg(feature = "transport_multilink")]
+            1,
+            false,
+        ))
+        .build_test(Arc::new(SHClientOpenClose::new()))
+        .unwrap();
+
+    ztimeout!(router_manager.add_listener(listen_endpoint.clone())).unwrap();
+    let c_ses = ztimeout!(client_manager.open_transport_unicast(connect_endpoint)).unwrap();
+
+    // The client authenticates the router with the listen certificate
+    let links = c_ses.get_links().unwrap();
+    assert_eq!(links.len(), 1);
+    assert_eq!(
+        links[0].auth_identifier.get_cert_common_name(),
+        Some("localhost")
+    );
+
+    // The router authenticates the client with the connect certificate
+    ztimeout!(async {
+        loop {
+            let transports = ztimeout!(router_manager.get_transports_unicast());
+            match transports
+                .iter()
+                .find(|s| s.get_zid().unwrap() == client_id)
+            {
+                Some(s) => {
+                    let links = s.get_links().unwrap();
+                    assert_eq!(links.len(), 1);
+                    assert_eq!(
+                        links[0].auth_identifier.get_cert_common_name(),
+                        Some("localhost")
+                    );
+                    break;
+                }
+                None => tokio::time::sleep(SLEEP).await,
+            }
+        }
+    });
+
+    ztimeout!(c_ses.close()).unwrap();
+    ztimeout!(router_manager.del_listener(&listen_endpoint)).unwrap();
+    ztimeout!(router_manager.close());
+    ztimeout!(client_manager.close());
+
+    // Wait a little bit
+    tokio::time::sleep(SLEEP).await;
 }
 
 #[cfg(feature = "transport_tcp")]
@@ -715,6 +799,24 @@ async fn openclose_tls_only_with_mtls_and_no_common_name() {
     openclose_universal_transport_tls(endpoint, true, true).await;
 }
 
+#[cfg(feature = "transport_tls")]
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn auth_id_tls_with_common_name() {
+    let endpoint: EndPoint = format!("tls/localhost:{}", get_free_tcp_port())
+        .parse()
+        .unwrap();
+    auth_id_transport_tls(endpoint, false).await;
+}
+
+#[cfg(feature = "transport_tls")]
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn auth_id_tls_falls_back_to_dns_san() {
+    let endpoint: EndPoint = format!("tls/localhost:{}", get_free_tcp_port())
+        .parse()
+        .unwrap();
+    auth_id_transport_tls(endpoint, true).await;
+}
+
 #[cfg(feature = "transport_quic")]
 #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
 async fn openclose_quic_only() {
@@ -751,6 +853,15 @@ async fn openclose_quic_only_with_mtls_and_no_common_name() {
     openclose_universal_transport_tls(endpoint, true, true).await;
 }
 
+#[cfg(feature = "transport_quic")]
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn auth_id_quic_falls_back_to_dns_san() {
+    let endpoint: EndPoint = format!("quic/localhost:{}", get_free_udp_port())
+        .parse()
+        .unwrap();
+    auth_id_transport_tls(endpoint, true).await;
+}
+
 #[cfg(feature = "transport_tcp")]
 #[cfg(target_os = "linux")]
 #[should_panic(expected = "Elapsed")]
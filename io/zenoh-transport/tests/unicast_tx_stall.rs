@@ -0,0 +1,268 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#![cfg(feature = "transport_tcp")]
+use std::{
+    convert::TryFrom,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpSocket, TcpStream},
+};
+use zenoh_core::ztimeout;
+use zenoh_link::EndPoint;
+use zenoh_protocol::{
+    core::{CongestionControl, Priority, WhatAmI, ZenohIdProto},
+    network::{
+        push::{ext::QoSType, Push},
+        NetworkMessage,
+    },
+};
+use zenoh_result::ZResult;
+use zenoh_test::get_free_tcp_port;
+use zenoh_transport::{
+    multicast::TransportMulticast, unicast::TransportUnicast, DummyTransportPeerEventHandler,
+    TransportEventHandler, TransportManager, TransportMulticastEventHandler, TransportPeer,
+    TransportPeerEventHandler,
+};
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+const TIMEOUT_EXPECTED: Duration = Duration::from_secs(5);
+const STALL_TIMEOUT: Duration = Duration::from_secs(1);
+const SLEEP: Duration = Duration::from_millis(100);
+
+const MSG_COUNT: usize = 1_000;
+const MSG_SIZE: usize = 65_536;
+// Forwards about 40 KiB/s, i.e. a batch in more than the stall timeout
+const SLOW_THROTTLE: Duration = Duration::from_millis(100);
+const SLOW_MSG_COUNT: usize = 4;
+
+macro_rules! ztimeout_expected {
+    ($f:expr) => {
+        tokio::time::timeout(TIMEOUT_EXPECTED, $f).await.unwrap()
+    };
+}
+
+#[derive(Default)]
+struct SHDummy;
+
+impl TransportEventHandler for SHDummy {
+    fn new_unicast(
+        &self,
+        _peer: TransportPeer,
+        _transport: TransportUnicast,
+    ) -> ZResult<Arc<dyn TransportPeerEventHandler>> {
+        Ok(Arc::new(DummyTransportPeerEventHandler))
+    }
+
+    fn new_multicast(
+        &self,
+        _transport: TransportMulticast,
+    ) -> ZResult<Arc<dyn TransportMulticastEventHandler>> {
+        panic!();
+    }
+}
+
+/// Spawns a TCP proxy forwarding a single connection to `target`.
+///
+/// While `blocked` is set, the proxy stops reading what the connecting side writes,
+/// simulating a peer whose socket is never drained. Otherwise, it waits for `throttle`
+/// after each read, simulating a slow peer.
+async fn spawn_proxy(target: String, blocked: Arc<AtomicBool>, throttle: Duration) -> EndPoint {
+    let port = get_free_tcp_port();
+    let socket = TcpSocket::new_v4().unwrap();
+    // Keep the receive buffer small so that the connecting side gets stuck quickly
+    socket.set_recv_buffer_size(4_096).unwrap();
+    socket
+        .bind(format!("127.0.0.1:{port}").parse().unwrap())
+        .unwrap();
+    let listener = socket.listen(1).unwrap();
+
+    tokio::spawn(async move {
+        let (client, _) = listener.accept().await.unwrap();
+        let router = TcpStream::connect(target).await.unwrap();
+        let (mut client_r, mut client_w) = client.into_split();
+        let (mut router_r, mut router_w) = router.into_split();
+
+        tokio::spawn(async move {
+            let _ = tokio::io::copy(&mut router_r, &mut client_w).await;
+        });
+
+        let mut buffer = vec![0u8; 4_096];
+        loop {
+            if blocked.load(Ordering::SeqCst) {
+                tokio::time::sleep(SLEEP).await;
+                continue;
+            }
+            match client_r.read(&mut buffer).await {
+                Ok(0) | Err(_) => break,
+                Ok(n) => {
+                    if router_w.write_all(&buffer[..n]).await.is_err() {
+                        break;
+                    }
+                }
+            }
+            if !throttle.is_zero() {
+                tokio::time::sleep(throttle).await;
+            }
+        }
+    });
+
+    // Keep the send buffer of the connecting side small as well so that its writes only
+    // progress as fast as the proxy reads
+    format!("tcp/127.0.0.1:{port}#so_sndbuf=4096")
+        .parse()
+        .unwrap()
+}
+
+async fn open_transport_unicast(
+    blocked: Arc<AtomicBool>,
+    throttle: Duration,
+    lowlatency: bool,
+) -> (TransportManager, TransportManager, TransportUnicast) {
+    // Define client and router IDs
+    let client_id = ZenohIdProto::try_from([1]).unwrap();
+    let router_id = ZenohIdProto::try_from([2]).unwrap();
+
+    // Create the router transport manager
+    let router_manager = TransportManager::builder()
+        .whatami(WhatAmI::Router)
+        .zid(router_id)
+        .unicast(
+            TransportManager::config_unicast()
+                .lowlatency(lowlatency)
+                .qos(!lowlatency),
+        )
+        .build_test(Arc::new(SHDummy))
+        .unwrap();
+
+    // Create the client transport manager with a TX stall timeout
+    let unicast = TransportManager::config_unicast()
+        .lowlatency(lowlatency)
+        .qos(!lowlatency)
+        .tx_stall_timeout(Some(STALL_TIMEOUT));
+    let client_manager = TransportManager::builder()
+        .whatami(WhatAmI::Client)
+        .zid(client_id)
+        .unicast(unicast)
+        .build_test(Arc::new(SHDummy))
+        .unwrap();
+
+    // Create the listener on the router
+    let port = get_free_tcp_port();
+    let endpoint: EndPoint = format!("tcp/127.0.0.1:{port}").parse().unwrap();
+    let _ = ztimeout!(router_manager.add_listener(endpoint)).unwrap();
+
+    // Open the transport through the proxy
+    let proxy = spawn_proxy(format!("127.0.0.1:{port}"), blocked, throttle).await;
+    let client_transport = ztimeout!(client_manager.open_transport_unicast(proxy)).unwrap();
+
+    (router_manager, client_manager, client_transport)
+}
+
+fn message() -> NetworkMessage {
+    NetworkMessage::from(Push {
+        wire_expr: "test".into(),
+        ext_qos: QoSType::new(Priority::Data, CongestionControl::Drop, false),
+        ..Push::from(vec![0u8; MSG_SIZE])
+    })
+}
+
+async fn tx_stall_closes_link(lowlatency: bool) {
+    let blocked = Arc::new(AtomicBool::new(false));
+    let (router_manager, client_manager, client_transport) =
+        open_transport_unicast(blocked.clone(), Duration::ZERO, lowlatency).await;
+
+    // Stop draining the client socket and keep queueing data
+    blocked.store(true, Ordering::SeqCst);
+    let message = message();
+    let schedule = tokio::task::spawn_blocking(move || {
+        for _ in 0..MSG_COUNT {
+            if client_transport.schedule(message.clone().as_mut()).is_err() {
+                break;
+            }
+        }
+    });
+
+    // The stalled link is closed, taking down the transport
+    ztimeout_expected!(async {
+        while !client_manager.get_transports_unicast().await.is_empty() {
+            tokio::time::sleep(SLEEP).await;
+        }
+    });
+    assert_eq!(client_manager.tx_stall_count(), 1);
+    ztimeout!(schedule).unwrap();
+
+    ztimeout!(router_manager.close());
+    ztimeout!(client_manager.close());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn transport_unicast_tx_stall_closes_link() {
+    zenoh_util::init_log_from_env_or("error");
+    tx_stall_closes_link(false).await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn transport_unicast_lowlatency_tx_stall_closes_link() {
+    zenoh_util::init_log_from_env_or("error");
+    tx_stall_closes_link(true).await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn transport_unicast_tx_stall_ignores_slow_link() {
+    zenoh_util::init_log_from_env_or("error");
+    let blocked = Arc::new(AtomicBool::new(false));
+    let (router_manager, client_manager, client_transport) =
+        open_transport_unicast(blocked.clone(), SLOW_THROTTLE, false).await;
+
+    // Writing a batch takes longer than the stall timeout, but each write makes progress
+    let message = message();
+    for _ in 0..SLOW_MSG_COUNT {
+        client_transport.schedule(message.clone().as_mut()).unwrap();
+    }
+    tokio::time::sleep(3 * STALL_TIMEOUT).await;
+
+    assert_eq!(client_manager.tx_stall_count(), 0);
+    assert_eq!(client_manager.get_transports_unicast().await.len(), 1);
+
+    ztimeout!(client_transport.close()).unwrap();
+    ztimeout!(router_manager.close());
+    ztimeout!(client_manager.close());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn transport_unicast_tx_stall_ignores_idle_link() {
+    zenoh_util::init_log_from_env_or("error");
+    let blocked = Arc::new(AtomicBool::new(false));
+    let (router_manager, client_manager, client_transport) =
+        open_transport_unicast(blocked.clone(), Duration::ZERO, false).await;
+
+    // Nothing is queued: a peer not reading is not a stall
+    blocked.store(true, Ordering::SeqCst);
+    tokio::time::sleep(3 * STALL_TIMEOUT).await;
+
+    assert_eq!(client_manager.tx_stall_count(), 0);
+    assert_eq!(client_manager.get_transports_unicast().await.len(), 1);
+
+    blocked.store(false, Ordering::SeqCst);
+    ztimeout!(client_transport.close()).unwrap();
+    ztimeout!(router_manager.close());
+    ztimeout!(client_manager.close());
+}
@@ -65,6 +65,8 @@ pub struct StorageConfig {
     pub key_expr: OwnedKeyExpr,
     pub complete: bool,
     pub strip_prefix: Option<OwnedKeyExpr>,
+    /// Prefix prepended to the (stripped) keys before handing them to the backend.
+    pub add_prefix: Option<OwnedKeyExpr>,
     pub volume_id: String,
     pub volume_cfg: JsonValue,
     pub garbage_collection_config: GarbageCollectionConfig,
@@ -410,8 +412,20 @@ impl StorageConfig {
     pub fn to_json_value(&self) -> Value {
         let mut result = serde_json::Map::new();
         result.insert("key_expr".into(), Value::String(self.key_expr.to_string()));
-        if let Some(s) = &self.strip_prefix {
-            result.insert("strip_prefix".into(), Value::String(s.to_string()));
+        match &self.add_prefix {
+            Some(add_prefix) => {
+                let mut key_mapping = serde_json::Map::new();
+                if let Some(s) = &self.strip_prefix {
+                    key_mapping.insert("strip_prefix".into(), Value::String(s.to_string()));
+                }
+                key_mapping.insert("add_prefix".into(), Value::String(add_prefix.to_string()));
+                result.insert("key_mapping".into(), Value::Object(key_mapping));
+            }
+            None => {
+                if let Some(s) = &self.strip_prefix {
+                    result.insert("strip_prefix".into(), Value::String(s.to_string()));
+                }
+            }
         }
 
         result.insert(
@@ -466,33 +480,49 @@ impl StorageConfig {
                 storage_name
             ),
         };
-        let strip_prefix: Option<OwnedKeyExpr> = match config.get("strip_prefix") {
-            Some(Value::String(s)) => {
-                if !key_expr.starts_with(s) {
-                    bail!(
-                        r#"The specified "strip_prefix={}" is not a prefix of "key_expr={}""#,
-                        s,
-                        key_expr
-                    )
-                }
-                match keyexpr::new(s.as_str()) {
-                    Ok(ke) => {
-                        if ke.is_wild() {
-                            bail!(
-                                r#"The specified "strip_prefix={}" contains wildcard characters (it shouldn't)"#,
-                                ke
-                            )
-                        }
-                        Some(ke.to_owned())
+        let (key_mapping_strip_prefix, add_prefix) = match config.get("key_mapping") {
+            Some(Value::Object(key_mapping)) => {
+                for field in key_mapping.keys() {
+                    if field != "strip_prefix" && field != "add_prefix" {
+                        bail!(
+                            "Unknown field `key_mapping.{}` in storage `{}`. Only `strip_prefix` \
+                             and `add_prefix` are accepted.",
+                            field,
+                            storage_name
+                        )
                     }
-                    Err(e) => bail!("strip_prefix='{}' is not a valid key-expression: {}", s, e),
                 }
+                (
+                    parse_strip_prefix(
+                        storage_name,
+                        &key_expr,
+                        "key_mapping.strip_prefix",
+                        key_mapping.get("strip_prefix"),
+                    )?,
+                    parse_add_prefix(storage_name, key_mapping.get("add_prefix"))?,
+                )
             }
-            None => None,
+            None => (None, None),
             _ => bail!(
-                "Invalid type for field `strip_prefix` of storage `{}`. Only strings are accepted.",
+                "Invalid type for field `key_mapping` of storage `{}`. Only objects are accepted.",
+                storage_name
+            ),
+        };
+        let strip_prefix = match (
+            parse_strip_prefix(
+                storage_name,
+                &key_expr,
+                "strip_prefix",
+                config.get("strip_prefix"),
+            )?,
+            key_mapping_strip_prefix,
+        ) {
+            (Some(_), Some(_)) => bail!(
+                "Storage `{}` configures both `strip_prefix` and `key_mapping.strip_prefix`: \
+                 only one of them can be set.",
                 storage_name
             ),
+            (strip_prefix, None) | (None, strip_prefix) => strip_prefix,
         };
         let (volume_id, volume_cfg) = match config.get("volume") {
             Some(Value::String(volume_id)) => (volume_id.clone(), Value::Null),
@@ -643,6 +673,7 @@ impl StorageConfig {
             key_expr,
             complete,
             strip_prefix,
+            add_prefix,
             volume_id,
             volume_cfg: volume_cfg.into(),
             garbage_collection_config,
@@ -650,6 +681,84 @@ impl StorageConfig {
         })
     }
 }
+/// Parses the `strip_prefix` of a storage, checking that it is a non-wildcard key expression
+/// made of leading chunks of the storage `key_expr`.
+fn parse_strip_prefix(
+    storage_name: &str,
+    key_expr: &keyexpr,
+    field: &str,
+    value: Option<&Value>,
+) -> ZResult<Option<OwnedKeyExpr>> {
+    let s = match value {
+        Some(Value::String(s)) => s,
+        None => return Ok(None),
+        _ => bail!(
+            "Invalid type for field `{}` of storage `{}`. Only strings are accepted.",
+            field,
+            storage_name
+        ),
+    };
+    let prefix = match keyexpr::new(s.as_str()) {
+        Ok(ke) => ke,
+        Err(e) => bail!(
+            "{}='{}' of storage `{}` is not a valid key-expression: {}",
+            field,
+            s,
+            storage_name,
+            e
+        ),
+    };
+    if prefix.is_wild() {
+        bail!(
+            r#"The specified "{}={}" of storage `{}` contains wildcard characters (it shouldn't)"#,
+            field,
+            prefix,
+            storage_name
+        )
+    }
+    let is_chunk_prefix = key_expr
+        .as_str()
+        .strip_prefix(prefix.as_str())
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'));
+    if !is_chunk_prefix {
+        bail!(
+            "The specified \"{}={}\" of storage `{}` is not a prefix of \"key_expr={}\": it \
+             must be made of leading chunks of the key expression",
+            field,
+            prefix,
+            storage_name,
+            key_expr
+        )
+    }
+    Ok(Some(prefix.to_owned()))
+}
+
+/// Parses the `add_prefix` of a storage, checking that it is a non-wildcard key expression.
+fn parse_add_prefix(storage_name: &str, value: Option<&Value>) -> ZResult<Option<OwnedKeyExpr>> {
+    match value {
+        Some(Value::String(s)) => match keyexpr::new(s.as_str()) {
+            Ok(ke) if ke.is_wild() => bail!(
+                r#"The specified "key_mapping.add_prefix={}" of storage `{}` contains wildcard characters (it shouldn't)"#,
+                ke,
+                storage_name
+            ),
+            Ok(ke) => Ok(Some(ke.to_owned())),
+            Err(e) => bail!(
+                "key_mapping.add_prefix='{}' of storage `{}` is not a valid key-expression: {}",
+                s,
+                storage_name,
+                e
+            ),
+        },
+        None => Ok(None),
+        _ => bail!(
+            "Invalid type for field `key_mapping.add_prefix` of storage `{}`. Only strings are \
+             accepted.",
+            storage_name
+        ),
+    }
+}
+
 impl PartialEq for VolumeConfig {
     fn eq(&self, other: &Self) -> bool {
         self.name == other.name && self.paths == other.paths && self.rest == other.rest
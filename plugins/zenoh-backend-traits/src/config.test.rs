@@ -12,9 +12,10 @@
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
 
-use std::time::Duration;
+use std::{str::FromStr, time::Duration};
 
 use serde_json::json;
+use zenoh::{key_expr::OwnedKeyExpr, Result as ZResult};
 
 use super::StorageConfig;
 use crate::config::ReplicaConfig;
@@ -76,3 +77,156 @@ Actual message: {err}",
         })
     );
 }
+
+#[test]
+fn test_key_mapping_config() {
+    fn parse(config: serde_json::Value) -> ZResult<StorageConfig> {
+        StorageConfig::try_from("test-plugin", "test-storage", &config)
+    }
+    fn ke(s: &str) -> Option<OwnedKeyExpr> {
+        Some(OwnedKeyExpr::from_str(s).unwrap())
+    }
+    fn assert_err_contains(result: ZResult<StorageConfig>, expected: &[&str]) {
+        let err = result.unwrap_err().to_string();
+        for expected in expected {
+            assert!(
+                err.contains(expected),
+                "\nExpected to contain: {expected}\nActual message: {err}",
+            );
+        }
+    }
+
+    // Legacy `strip_prefix` field
+    let config = parse(json!({
+        "key_expr": "demo/memory/**",
+        "volume": "memory",
+        "strip_prefix": "demo/memory",
+    }))
+    .unwrap();
+    assert_eq!(config.strip_prefix, ke("demo/memory"));
+    assert_eq!(config.add_prefix, None);
+
+    // `key_mapping` with both prefixes
+    let config = parse(json!({
+        "key_expr": "demo/memory/**",
+        "volume": "memory",
+        "key_mapping": {
+            "strip_prefix": "demo/memory",
+            "add_prefix": "backend/space",
+        },
+    }))
+    .unwrap();
+    assert_eq!(config.strip_prefix, ke("demo/memory"));
+    assert_eq!(config.add_prefix, ke("backend/space"));
+    // the configuration is reported as it was given
+    let round_trip = parse(config.to_json_value()).unwrap();
+    assert_eq!(round_trip.strip_prefix, config.strip_prefix);
+    assert_eq!(round_trip.add_prefix, config.add_prefix);
+
+    // `add_prefix` only: the whole key expression is prefixed
+    let config = parse(json!({
+        "key_expr": "demo/memory/**",
+        "volume": "memory",
+        "key_mapping": { "add_prefix": "backend/space" },
+    }))
+    .unwrap();
+    assert_eq!(config.strip_prefix, None);
+    assert_eq!(config.add_prefix, ke("backend/space"));
+
+    // The `strip_prefix` may be equal to the full (non-wildcard) key expression
+    let config = parse(json!({
+        "key_expr": "demo/memory/a",
+        "volume": "memory",
+        "key_mapping": { "strip_prefix": "demo/memory/a" },
+    }))
+    .unwrap();
+    assert_eq!(config.strip_prefix, ke("demo/memory/a"));
+
+    // The `strip_prefix` must be made of whole chunks of the key expression
+    assert_err_contains(
+        parse(json!({
+            "key_expr": "demo/memory/**",
+            "volume": "memory",
+            "key_mapping": { "strip_prefix": "demo/mem" },
+        })),
+        &[
+            "key_mapping.strip_prefix=demo/mem",
+            "test-storage",
+            "key_expr=demo/memory/**",
+        ],
+    );
+    assert_err_contains(
+        parse(json!({
+            "key_expr": "demo/memory/**",
+            "volume": "memory",
+            "strip_prefix": "other/memory",
+        })),
+        &[
+            "strip_prefix=other/memory",
+            "test-storage",
+            "key_expr=demo/memory/**",
+        ],
+    );
+    // ... and must not contain wildcards
+    assert_err_contains(
+        parse(json!({
+            "key_expr": "demo/*/**",
+            "volume": "memory",
+            "key_mapping": { "strip_prefix": "demo/*" },
+        })),
+        &[
+            "key_mapping.strip_prefix=demo/*",
+            "test-storage",
+            "wildcard",
+        ],
+    );
+    assert_err_contains(
+        parse(json!({
+            "key_expr": "demo/memory/**",
+            "volume": "memory",
+            "key_mapping": { "add_prefix": "backend/**" },
+        })),
+        &[
+            "key_mapping.add_prefix=backend/**",
+            "test-storage",
+            "wildcard",
+        ],
+    );
+    assert_err_contains(
+        parse(json!({
+            "key_expr": "demo/memory/**",
+            "volume": "memory",
+            "key_mapping": { "add_prefix": "backend//space" },
+        })),
+        &["key_mapping.add_prefix='backend//space'", "test-storage"],
+    );
+
+    // Only one `strip_prefix` can be configured
+    assert_err_contains(
+        parse(json!({
+            "key_expr": "demo/memory/**",
+            "volume": "memory",
+            "strip_prefix": "demo/memory",
+            "key_mapping": { "strip_prefix": "demo/memory" },
+        })),
+        &["test-storage", "only one of them"],
+    );
+
+    // Unknown fields and invalid types are rejected
+    assert_err_contains(
+        parse(json!({
+            "key_expr": "demo/memory/**",
+            "volume": "memory",
+            "key_mapping": { "strip": "demo/memory" },
+        })),
+        &["key_mapping.strip", "test-storage"],
+    );
+    assert_err_contains(
+        parse(json!({
+            "key_expr": "demo/memory/**",
+            "volume": "memory",
+            "key_mapping": "demo/memory",
+        })),
+        &["key_mapping", "test-storage", "objects"],
+    );
+}
@@ -263,4 +263,11 @@ pub trait Storage: Send + Sync {
     /// The latest Timestamp corresponding to each key is either the timestamp of the delete or put whichever is the latest.
     /// Remember to fetch the entry corresponding to the `None` key
     async fn get_all_entries(&self) -> ZResult<Vec<(Option<OwnedKeyExpr>, Timestamp)>>;
+
+    /// Function called on a query on the `__compact` key of this storage in the administration space.
+    /// Backends relying on a compacting technology (e.g. RocksDB) should trigger a compaction.
+    /// The default implementation does nothing.
+    async fn compact(&mut self) -> ZResult<()> {
+        Ok(())
+    }
 }
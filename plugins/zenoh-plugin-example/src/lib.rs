@@ -28,13 +28,13 @@ use tracing::{debug, info};
 use zenoh::{
     internal::{
         bail,
-        plugins::{RunningPluginTrait, ZenohPlugin},
+        plugins::{PluginEnv, RunningPluginTrait, ZenohPlugin},
         runtime::DynamicRuntime,
         zlock,
     },
     key_expr::{keyexpr, KeyExpr},
     sample::Sample,
-    Result as ZResult,
+    Result as ZResult, Wait,
 };
 use zenoh_plugin_trait::{plugin_long_version, plugin_version, Plugin, PluginControl};
 use zenoh_util::ffi::JsonKeyValueMap;
@@ -102,6 +102,16 @@ impl Plugin for ExamplePlugin {
         .clone()
         .into_owned();
 
+        // The entities declared through a PluginEnv are undeclared by zenohd when the plugin stops,
+        // before the plugin's library is unloaded. Here a queryable replying with the plugin's version.
+        let env = PluginEnv::new(name, runtime)?;
+        env.declare_queryable(format!("@/{}/{name}/status", runtime.zid()), |query| {
+            let reply = query.reply(query.key_expr().clone(), ExamplePlugin::PLUGIN_LONG_VERSION);
+            if let Err(e) = reply.wait() {
+                tracing::warn!("Failed to reply to the status query: {e}");
+            }
+        })?;
+
         // a flag to end the plugin's loop when the plugin is removed from the config
         let flag = Arc::new(AtomicBool::new(true));
         spawn_runtime(run(runtime.clone(), selector, flag.clone()));
@@ -208,7 +218,7 @@ async fn run(runtime: DynamicRuntime, selector: KeyExpr<'_>, flag: Arc<AtomicBoo
                 info!("Handling query '{}'", query.selector());
                 for (key_expr, sample) in stored.iter() {
                     if query.key_expr().intersects(unsafe{keyexpr::from_str_unchecked(key_expr)}) {
-                        query.reply_sample(sample.clone()).await.unwrap();
+                        query.reply_sample(sample).await.unwrap();
                     }
                 }
             }
@@ -0,0 +1,103 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+//! A `zenohd` plugin rejecting the messages with invalid JSON payloads on a key expression.
+//!
+//! The plugin registers an [`IngressValidator`] into the router: the puts, replies and query
+//! payloads received from remote nodes on the configured `key_expr` must be valid JSON, or they
+//! are dropped before being routed.
+//!
+//! ```json5
+//! plugins: {
+//!   "json-validator": {
+//!     key_expr: "demo/json/**",
+//!   },
+//! },
+//! ```
+use std::sync::Arc;
+
+use zenoh::{
+    internal::{
+        bail,
+        plugins::{
+            IngressMessage, IngressSample, IngressValidator, IngressVerdict, RunningPlugin,
+            RunningPluginTrait, ZenohPlugin,
+        },
+        runtime::DynamicRuntime,
+    },
+    key_expr::OwnedKeyExpr,
+    sample::SampleKind,
+    Result as ZResult,
+};
+use zenoh_plugin_trait::{plugin_long_version, plugin_version, Plugin, PluginControl};
+
+// The struct implementing the ZenohPlugin and ZenohPlugin traits
+pub struct JsonValidatorPlugin;
+
+// declaration of the plugin's VTable for zenohd to find the plugin's functions to be called
+#[cfg(feature = "dynamic_plugin")]
+zenoh_plugin_trait::declare_plugin!(JsonValidatorPlugin);
+
+impl ZenohPlugin for JsonValidatorPlugin {}
+impl Plugin for JsonValidatorPlugin {
+    type StartArgs = DynamicRuntime;
+    type Instance = RunningPlugin;
+
+    const DEFAULT_NAME: &'static str = "json-validator";
+    const PLUGIN_VERSION: &'static str = plugin_version!();
+    const PLUGIN_LONG_VERSION: &'static str = plugin_long_version!();
+
+    fn start(name: &str, runtime: &Self::StartArgs) -> ZResult<Self::Instance> {
+        let config = runtime.get_config().get_plugin_config(name)?;
+        let key_expr = match config.get("key_expr") {
+            Some(serde_json::Value::String(key_expr)) => key_expr.parse()?,
+            _ => bail!("key_expr is a mandatory option for {}", name),
+        };
+        Ok(Box::new(JsonValidatorInstance(Arc::new(JsonValidator {
+            key_expr,
+        }))))
+    }
+}
+
+struct JsonValidatorInstance(Arc<JsonValidator>);
+
+impl PluginControl for JsonValidatorInstance {}
+
+impl RunningPluginTrait for JsonValidatorInstance {
+    fn ingress_validator(&self) -> Option<Arc<dyn IngressValidator>> {
+        Some(self.0.clone())
+    }
+}
+
+struct JsonValidator {
+    key_expr: OwnedKeyExpr,
+}
+
+impl IngressValidator for JsonValidator {
+    fn validate(&self, sample: &IngressSample<'_>) -> IngressVerdict {
+        if !self.key_expr.intersects(sample.key_expr()) {
+            return IngressVerdict::Accept;
+        }
+        match sample.kind() {
+            IngressMessage::Put | IngressMessage::Reply(SampleKind::Put) => {}
+            // The payload of a query is optional
+            IngressMessage::Query if !sample.payload().is_empty() => {}
+            _ => return IngressVerdict::Accept,
+        }
+        match serde_json::from_slice::<serde_json::Value>(&sample.payload().to_bytes()) {
+            Ok(_) => IngressVerdict::Accept,
+            Err(e) => IngressVerdict::Reject(Some(format!("invalid JSON: {e}"))),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::time::Duration;
+
+use zenoh::{
+    config::WhatAmI,
+    internal::{plugins::PluginsManager, runtime::RuntimeBuilder, ztimeout},
+    sample::SampleKind,
+    Config,
+};
+use zenoh_plugin_json_validator::JsonValidatorPlugin;
+use zenoh_test::get_tcp_locator;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+const SLEEP: Duration = Duration::from_secs(1);
+
+fn client_config(router: &zenoh::config::EndPoint) -> Config {
+    let mut config = Config::default();
+    config.set_mode(Some(WhatAmI::Client)).unwrap();
+    config
+        .insert_json5("connect/endpoints", &format!(r#"["{router}"]"#))
+        .unwrap();
+    config
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_ingress_validator_drops_invalid_json() {
+    zenoh_util::init_log_from_env_or("error");
+
+    let mut config = Config::default();
+    config.set_mode(Some(WhatAmI::Router)).unwrap();
+    config
+        .insert_json5("listen/endpoints", r#"["tcp/127.0.0.1:0"]"#)
+        .unwrap();
+    config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    config.insert_json5("adminspace/enabled", "true").unwrap();
+    config
+        .insert_json5("plugins/json-validator", r#"{ key_expr: "validated/**" }"#)
+        .unwrap();
+    let mut plugins_manager = PluginsManager::static_plugins_only();
+    plugins_manager.declare_static_plugin::<JsonValidatorPlugin, &str>("json-validator", true);
+    let mut runtime = ztimeout!(RuntimeBuilder::new(config)
+        .plugins_manager(plugins_manager)
+        .build())
+    .unwrap();
+    let router = ztimeout!(zenoh::session::init(runtime.clone().into())).unwrap();
+    ztimeout!(runtime.start()).unwrap();
+    let router_endpoint = get_tcp_locator(&router).await;
+
+    let subscriber_session = ztimeout!(zenoh::open(client_config(&router_endpoint))).unwrap();
+    let subscriber = ztimeout!(subscriber_session.declare_subscriber("**")).unwrap();
+    let queryable = ztimeout!(subscriber_session.declare_queryable("validated/q/**")).unwrap();
+    let publisher_session = ztimeout!(zenoh::open(client_config(&router_endpoint))).unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    for (key_expr, payload) in [
+        ("validated/a", r#"{"value": 1}"#),
+        ("validated/b", "not json"),
+        ("other/c", "not json"),
+        ("validated/d", "[1, 2]"),
+    ] {
+        ztimeout!(publisher_session.put(key_expr, payload)).unwrap();
+    }
+    // Deletes have no payload to validate.
+    ztimeout!(publisher_session.delete("validated/b")).unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    let received: Vec<(String, SampleKind)> = subscriber
+        .drain()
+        .map(|sample| (sample.key_expr().to_string(), sample.kind()))
+        .collect();
+    assert_eq!(
+        received,
+        [
+            ("validated/a".to_string(), SampleKind::Put),
+            ("other/c".to_string(), SampleKind::Put),
+            ("validated/d".to_string(), SampleKind::Put),
+            ("validated/b".to_string(), SampleKind::Delete),
+        ]
+    );
+
+    // Queries with an invalid JSON payload are dropped.
+    let replies = ztimeout!(publisher_session
+        .get("validated/q/a")
+        .payload("not json")
+        .timeout(SLEEP))
+    .unwrap();
+    assert!(ztimeout!(replies.recv_async()).is_err());
+    assert!(queryable.try_recv().unwrap().is_none());
+
+    // Replies with an invalid JSON payload are dropped.
+    let replies = ztimeout!(publisher_session.get("validated/q/*").timeout(SLEEP)).unwrap();
+    let query = ztimeout!(queryable.recv_async()).unwrap();
+    ztimeout!(query.reply("validated/q/a", "not json")).unwrap();
+    ztimeout!(query.reply("validated/q/b", "true")).unwrap();
+    drop(query);
+    let reply = ztimeout!(replies.recv_async()).unwrap();
+    assert_eq!(reply.result().unwrap().key_expr().as_str(), "validated/q/b");
+    assert!(ztimeout!(replies.recv_async()).is_err());
+
+    let reply = ztimeout!(router.get(format!("@/{}/router/ingress_validators", router.zid())))
+        .unwrap()
+        .recv_async()
+        .await
+        .unwrap();
+    let validators: serde_json::Value =
+        serde_json::from_slice(&reply.result().unwrap().payload().to_bytes()).unwrap();
+    assert_eq!(validators["json-validator"]["rejected"], 3);
+
+    ztimeout!(publisher_session.close()).unwrap();
+    ztimeout!(subscriber_session.close()).unwrap();
+    ztimeout!(router.close()).unwrap();
+}
@@ -102,6 +102,8 @@ struct StorageRuntimeInner {
     session: Arc<Session>,
     storages: HashMap<String, HashMap<String, Sender<StorageMessage>>>,
     plugins_manager: PluginsManager,
+    /// The replies of the latest operations performed on the storages, by admin space key.
+    operations: HashMap<String, serde_json::Value>,
 }
 impl StorageRuntimeInner {
     fn status_key(&self) -> String {
@@ -156,6 +158,7 @@ impl StorageRuntimeInner {
             session,
             storages: Default::default(),
             plugins_manager,
+            operations: Default::default(),
         };
         new_self
             .spawn_volume(&VolumeConfig {
@@ -377,16 +380,65 @@ impl RunningPluginTrait for StorageRuntime {
                 for (storage, handle) in storages {
                     with_extended_string(key, &[storage], |key| {
                         if keyexpr::new(key.as_str()).unwrap().intersects(key_expr) {
-                            if let Some(value) = tokio::task::block_in_place(|| {
-                                TOKIO_RUNTIME.block_on(async {
-                                    let (tx, mut rx) = tokio::sync::mpsc::channel(1);
-                                    let _ = handle.send(StorageMessage::GetStatus(tx));
-                                    rx.recv().await
-                                })
-                            }) {
+                            if let Some(value) = request_storage(handle, StorageMessage::GetStatus)
+                            {
                                 responses.push(Response::new(key.clone(), value))
                             }
                         }
+                        // The statistics are only computed when their key is queried explicitly.
+                        with_extended_string(key, &["/__stats"], |key| {
+                            if key.as_str() == key_expr.as_str() {
+                                if let Some(value) =
+                                    request_storage(handle, StorageMessage::GetStats)
+                                {
+                                    responses.push(Response::new(key.clone(), value))
+                                }
+                            }
+                        });
+                        // The operations are performed by PUTs, queries only get their latest reply.
+                        for operation in ["/__align", "/__compact"] {
+                            with_extended_string(key, &[operation], |key| {
+                                if keyexpr::new(key.as_str()).unwrap().intersects(key_expr) {
+                                    if let Some(value) = guard.operations.get(key.as_str()) {
+                                        responses.push(Response::new(key.clone(), value.clone()))
+                                    }
+                                }
+                            });
+                        }
+                    })
+                }
+            }
+        });
+        Ok(responses)
+    }
+
+    fn adminspace_putter<'a>(
+        &'a self,
+        key_expr: &'a KeyExpr<'a>,
+        plugin_status_key: &str,
+    ) -> ZResult<Vec<Response>> {
+        let mut responses = Vec::new();
+        let mut key = String::from(plugin_status_key);
+        let mut guard = self.0.lock().unwrap();
+        let guard = &mut *guard;
+        with_extended_string(&mut key, &["/storages/"], |key| {
+            for storages in guard.storages.values() {
+                for (storage, handle) in storages {
+                    with_extended_string(key, &[storage], |key| {
+                        // The operations are only performed when their key is put explicitly.
+                        for (operation, message) in [
+                            ("/__align", StorageMessage::Align as fn(_) -> _),
+                            ("/__compact", StorageMessage::Compact),
+                        ] {
+                            with_extended_string(key, &[operation], |key| {
+                                if key.as_str() == key_expr.as_str() {
+                                    if let Some(value) = request_storage(handle, message) {
+                                        guard.operations.insert(key.clone(), value.clone());
+                                        responses.push(Response::new(key.clone(), value))
+                                    }
+                                }
+                            });
+                        }
                     })
                 }
             }
@@ -398,6 +450,20 @@ impl RunningPluginTrait for StorageRuntime {
 const BACKEND_LIB_PREFIX: &str = "zenoh_backend_";
 const MEMORY_BACKEND_NAME: &str = "memory";
 
+/// Sends the message built by `message` to a Storage and waits for its reply.
+fn request_storage(
+    handle: &Sender<StorageMessage>,
+    message: fn(tokio::sync::mpsc::Sender<serde_json::Value>) -> StorageMessage,
+) -> Option<serde_json::Value> {
+    tokio::task::block_in_place(|| {
+        TOKIO_RUNTIME.block_on(async {
+            let (tx, mut rx) = tokio::sync::mpsc::channel(1);
+            let _ = handle.send(message(tx));
+            rx.recv().await
+        })
+    })
+}
+
 fn with_extended_string<R, F: FnMut(&mut String) -> R>(
     prefix: &mut String,
     suffixes: &[&str],
@@ -22,7 +22,10 @@ use std::{
 };
 
 use rand::Rng;
-use tokio::{sync::RwLock, task::JoinHandle};
+use tokio::{
+    sync::{Notify, RwLock},
+    task::JoinHandle,
+};
 use tracing::{debug_span, Instrument};
 use zenoh::{
     key_expr::{
@@ -54,6 +57,7 @@ pub(crate) struct Replication {
     pub(crate) storage_key_expr: OwnedKeyExpr,
     pub(crate) latest_updates: Arc<RwLock<LatestUpdates>>,
     pub(crate) storage_service: Arc<StorageService>,
+    pub(crate) align_now: Arc<Notify>,
 }
 
 impl Replication {
@@ -119,6 +123,9 @@ impl Replication {
     /// 4. It will publish the [Digest]. The periodicity of this publication is dictated by the
     ///    `interval` configuration option.
     ///
+    /// A notification of `align_now` wakes the task up to immediately publish the [Digest],
+    /// triggering an alignment round with the Replicas that differ.
+    ///
     /// [Log]: crate::replication::log::LogLatest
     pub(crate) fn spawn_digest_publisher(&self) -> JoinHandle<()> {
         let replication = self.clone();
@@ -187,7 +194,10 @@ impl Replication {
                         as u64,
                 )
             };
-            tokio::time::sleep(duration_until_next_interval).await;
+            tokio::select! {
+                _ = tokio::time::sleep(duration_until_next_interval) => {}
+                _ = replication.align_now.notified() => {}
+            }
 
             let mut serialization_buffer = Vec::default();
             let mut events = HashMap::default();
@@ -265,7 +275,10 @@ impl Replication {
                         publication_delay + configuration.propagation_delay.as_millis() as u64
                     );
                 } else {
-                    tokio::time::sleep(configuration.interval - digest_update_duration).await;
+                    tokio::select! {
+                        _ = tokio::time::sleep(configuration.interval - digest_update_duration) => {}
+                        _ = replication.align_now.notified() => {}
+                    }
                 }
             }
         })
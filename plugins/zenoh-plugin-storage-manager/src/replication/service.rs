@@ -15,13 +15,13 @@
 use std::sync::Arc;
 
 use tokio::{
-    sync::{broadcast::Receiver, RwLock},
+    sync::{broadcast::Receiver, Notify, RwLock},
     task::JoinHandle,
 };
 use zenoh::{key_expr::OwnedKeyExpr, session::Session};
 
 use super::{core::Replication, LogLatest};
-use crate::storages_mgt::{LatestUpdates, StorageMessage, StorageService};
+use crate::storages_mgt::{operation_reply, LatestUpdates, StorageMessage, StorageService};
 
 pub(crate) struct ReplicationService {
     digest_publisher_handle: JoinHandle<()>,
@@ -46,7 +46,7 @@ impl ReplicationService {
     /// 3. One to receive alignment queries of other Replica.
     /// 4. One to wait on the provided [Receiver] in order to stop the Replication Service,
     ///    attempting to abort all the tasks that were spawned, once a Stop message has been
-    ///    received.
+    ///    received. An Align message triggers an immediate publication of the [Digest].
     pub async fn spawn_start(
         zenoh_session: Arc<Session>,
         storage_service: Arc<StorageService>,
@@ -61,6 +61,7 @@ impl ReplicationService {
             storage_key_expr,
             latest_updates,
             storage_service,
+            align_now: Arc::new(Notify::new()),
         };
 
         if replication
@@ -81,9 +82,16 @@ impl ReplicationService {
             };
 
             while let Ok(storage_message) = rx.recv().await {
-                if matches!(storage_message, StorageMessage::Stop) {
-                    replication_service.stop();
-                    return;
+                match storage_message {
+                    StorageMessage::Stop => {
+                        replication_service.stop();
+                        return;
+                    }
+                    StorageMessage::Align(tx) => {
+                        replication.align_now.notify_one();
+                        std::mem::drop(tx.send(operation_reply(Ok(Default::default()))).await);
+                    }
+                    _ => {}
                 }
             }
         });
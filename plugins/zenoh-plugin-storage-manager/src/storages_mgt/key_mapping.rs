@@ -0,0 +1,139 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+use async_trait::async_trait;
+use zenoh::{
+    bytes::{Encoding, ZBytes},
+    key_expr::OwnedKeyExpr,
+    time::Timestamp,
+    Result as ZResult,
+};
+use zenoh_backend_traits::{Storage, StorageInsertionResult, StoredData};
+use zenoh_util::ffi::JsonValue;
+
+/// A [`Storage`] wrapper applying the `key_mapping.add_prefix` of a Storage.
+///
+/// The Storage manager only manipulates keys stripped of the `strip_prefix`. This wrapper
+/// prepends the `add_prefix` to these keys before handing them to the backend and removes it from
+/// the keys returned by the backend. Hence, the rest of the Storage manager (including the
+/// Replication) is unaware of the `add_prefix` and clients always see the original key space.
+///
+/// As the `add_prefix` is never empty, the backend never receives a `None` key: a key equal to the
+/// `strip_prefix` is stored under the `add_prefix` itself.
+pub(crate) struct PrefixedStorage {
+    inner: Box<dyn Storage>,
+    add_prefix: OwnedKeyExpr,
+}
+
+impl PrefixedStorage {
+    pub(crate) fn new(inner: Box<dyn Storage>, add_prefix: OwnedKeyExpr) -> Self {
+        Self { inner, add_prefix }
+    }
+}
+
+/// Returns the key under which the provided stripped key is stored in the backend.
+pub(crate) fn to_backend_key(
+    add_prefix: &OwnedKeyExpr,
+    stripped_key: Option<&OwnedKeyExpr>,
+) -> OwnedKeyExpr {
+    match stripped_key {
+        Some(stripped_key) => add_prefix / stripped_key,
+        None => add_prefix.clone(),
+    }
+}
+
+/// Returns the stripped key corresponding to a key stored in the backend.
+///
+/// `Ok(None)` is returned if the backend key is equal to the `add_prefix`.
+///
+/// # Errors
+///
+/// This function will return an error if the backend key is not prefixed by the `add_prefix`,
+/// which can happen if the backend is shared with another Storage.
+pub(crate) fn from_backend_key(
+    add_prefix: &OwnedKeyExpr,
+    backend_key: Option<&OwnedKeyExpr>,
+) -> ZResult<Option<OwnedKeyExpr>> {
+    match backend_key {
+        Some(backend_key) => crate::strip_prefix(Some(add_prefix), &backend_key.into()),
+        None => zenoh::internal::bail!(
+            "Found an entry without key, not prefixed by < {} >",
+            add_prefix
+        ),
+    }
+}
+
+#[async_trait]
+impl Storage for PrefixedStorage {
+    fn get_admin_status(&self) -> JsonValue {
+        self.inner.get_admin_status()
+    }
+
+    async fn put(
+        &mut self,
+        key: Option<OwnedKeyExpr>,
+        payload: ZBytes,
+        encoding: Encoding,
+        timestamp: Timestamp,
+    ) -> ZResult<StorageInsertionResult> {
+        let key = to_backend_key(&self.add_prefix, key.as_ref());
+        self.inner
+            .put(Some(key), payload, encoding, timestamp)
+            .await
+    }
+
+    async fn delete(
+        &mut self,
+        key: Option<OwnedKeyExpr>,
+        timestamp: Timestamp,
+    ) -> ZResult<StorageInsertionResult> {
+        let key = to_backend_key(&self.add_prefix, key.as_ref());
+        self.inner.delete(Some(key), timestamp).await
+    }
+
+    async fn get(
+        &mut self,
+        key: Option<OwnedKeyExpr>,
+        parameters: &str,
+    ) -> ZResult<Vec<StoredData>> {
+        let key = to_backend_key(&self.add_prefix, key.as_ref());
+        self.inner.get(Some(key), parameters).await
+    }
+
+    async fn get_all_entries(&self) -> ZResult<Vec<(Option<OwnedKeyExpr>, Timestamp)>> {
+        Ok(self
+            .inner
+            .get_all_entries()
+            .await?
+            .into_iter()
+            .filter_map(|(backend_key, timestamp)| {
+                match from_backend_key(&self.add_prefix, backend_key.as_ref()) {
+                    Ok(stripped_key) => Some((stripped_key, timestamp)),
+                    Err(e) => {
+                        tracing::warn!("Ignoring entry of the backend: {e}");
+                        None
+                    }
+                }
+            })
+            .collect())
+    }
+
+    async fn compact(&mut self) -> ZResult<()> {
+        self.inner.compact().await
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/key_mapping.test.rs"]
+mod tests;
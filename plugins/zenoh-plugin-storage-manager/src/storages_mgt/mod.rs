@@ -20,6 +20,7 @@ use zenoh_backend_traits::{config::StorageConfig, History, VolumeInstance};
 
 use crate::replication::{Action, Event, LogLatest, LogLatestKey, ReplicationService};
 
+mod key_mapping;
 pub(crate) mod service;
 pub(crate) use service::StorageService;
 
@@ -27,6 +28,25 @@ pub(crate) use service::StorageService;
 pub enum StorageMessage {
     Stop,
     GetStatus(tokio::sync::mpsc::Sender<serde_json::Value>),
+    GetStats(tokio::sync::mpsc::Sender<serde_json::Value>),
+    Align(tokio::sync::mpsc::Sender<serde_json::Value>),
+    Compact(tokio::sync::mpsc::Sender<serde_json::Value>),
+}
+
+/// Returns the JSON reply to an administration operation on a Storage.
+///
+/// The `details` of a successful operation are completed with `"success": true`, a failed
+/// operation is described by `"success": false` and its `"error"`.
+pub(crate) fn operation_reply(
+    result: ZResult<serde_json::Map<String, serde_json::Value>>,
+) -> serde_json::Value {
+    match result {
+        Ok(mut details) => {
+            details.insert("success".into(), true.into());
+            details.into()
+        }
+        Err(e) => serde_json::json!({ "success": false, "error": e.to_string() }),
+    }
 }
 
 pub(crate) type LatestUpdates = HashMap<LogLatestKey, Event>;
@@ -57,7 +77,13 @@ pub(crate) async fn create_and_start_storage(
 ) -> ZResult<Sender<StorageMessage>> {
     tracing::trace!("Create storage '{}'", &admin_key);
     let capability = backend.get_capability();
-    let storage = backend.create_storage(config.clone()).await?;
+    let mut storage = backend.create_storage(config.clone()).await?;
+    if let Some(add_prefix) = &config.add_prefix {
+        storage = Box::new(key_mapping::PrefixedStorage::new(
+            storage,
+            add_prefix.clone(),
+        ));
+    }
 
     // Ex: @/390CEC11A1E34977A1C609A35BC015E6/router/status/plugins/storage_manager/storages/demo1
     // -> 390CEC11A1E34977A1C609A35BC015E6/demo1 (/<type> needed????)
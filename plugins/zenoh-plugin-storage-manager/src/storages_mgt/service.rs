@@ -31,6 +31,7 @@ use zenoh::{
         },
         OwnedKeyExpr,
     },
+    query::ZenohParameters,
     sample::{Sample, SampleBuilder, SampleFields, SampleKind},
     session::Session,
     time::{Timestamp, NTP64},
@@ -44,7 +45,7 @@ use zenoh_backend_traits::{
 use super::LatestUpdates;
 use crate::{
     replication::{Action, Event},
-    storages_mgt::{CacheLatest, StorageMessage},
+    storages_mgt::{operation_reply, CacheLatest, StorageMessage},
 };
 
 #[derive(Clone)]
@@ -77,6 +78,13 @@ impl From<Update> for StoredData {
     }
 }
 
+/// The timestamps of the latest put and delete applied to a Storage by its subscriber.
+#[derive(Default)]
+pub(crate) struct LastUpdates {
+    put: Option<Timestamp>,
+    delete: Option<Timestamp>,
+}
+
 #[derive(Clone)]
 pub struct StorageService {
     session: Arc<Session>,
@@ -87,6 +95,7 @@ pub struct StorageService {
     pub(crate) wildcard_deletes: Arc<RwLock<KeBoxTree<Update, UnknownWildness, KeyedSetProvider>>>,
     pub(crate) wildcard_puts: Arc<RwLock<KeBoxTree<Update, UnknownWildness, KeyedSetProvider>>>,
     cache_latest: CacheLatest,
+    last_updates: Arc<RwLock<LastUpdates>>,
 }
 
 impl StorageService {
@@ -107,6 +116,7 @@ impl StorageService {
             wildcard_deletes: Arc::new(RwLock::new(KeBoxTree::default())),
             wildcard_puts: Arc::new(RwLock::new(KeBoxTree::default())),
             cache_latest,
+            last_updates: Arc::new(RwLock::new(LastUpdates::default())),
         }
     }
 
@@ -200,6 +210,24 @@ impl StorageService {
                                 let storage = self.storage.lock().await;
                                 std::mem::drop(tx.send(storage.get_admin_status().into()).await);
                             }
+                            StorageMessage::GetStats(tx) => {
+                                std::mem::drop(tx.send(operation_reply(self.stats().await)).await);
+                            }
+                            // The Replication Service replies if the replication is enabled.
+                            StorageMessage::Align(tx) => {
+                                if self.cache_latest.replication_log.is_none() {
+                                    std::mem::drop(tx.send(operation_reply(Err(
+                                        "the replication is not enabled for this storage".into(),
+                                    ))).await);
+                                }
+                            }
+                            StorageMessage::Compact(tx) => {
+                                let result = self.storage.lock().await.compact().await;
+                                std::mem::drop(
+                                    tx.send(operation_reply(result.map(|_| Default::default())))
+                                        .await,
+                                );
+                            }
                         };
                     },
                 );
@@ -356,6 +384,14 @@ impl StorageService {
                     if let Some(mut cache_guard) = cache_guard {
                         cache_guard.insert(new_event.log_key(), new_event);
                     }
+                    let mut last_updates = self.last_updates.write().await;
+                    let last_update = match sample.kind() {
+                        SampleKind::Put => &mut last_updates.put,
+                        SampleKind::Delete => &mut last_updates.delete,
+                    };
+                    if last_update.map_or(true, |last| last < sample_to_store_timestamp) {
+                        *last_update = Some(sample_to_store_timestamp);
+                    }
                 }
                 Err(e) => {
                     // TODO In case of a wildcard update, multiple keys can be updated. What should
@@ -368,6 +404,49 @@ impl StorageService {
         Ok(())
     }
 
+    /// Returns the statistics of the Storage: the number of keys it stores, the approximate
+    /// number of bytes of their payloads and the timestamps of the latest put and delete it
+    /// received.
+    ///
+    /// The payloads are retrieved from the backend to compute their size: this can be costly for
+    /// large Storages.
+    async fn stats(&self) -> ZResult<serde_json::Map<String, serde_json::Value>> {
+        let mut storage = self.storage.lock().await;
+        let entries = match storage.get_all_entries().await {
+            Ok(entries) => entries,
+            Err(e) => bail!("`get_all_entries` failed with: {e:?}"),
+        };
+        let mut keys = 0usize;
+        let mut bytes = 0usize;
+        for (stripped_key, _) in entries {
+            // Some backends keep the deleted keys, these do not store any payload.
+            if let Ok(stored_data) = storage.get(stripped_key, "").await {
+                if !stored_data.is_empty() {
+                    keys += 1;
+                    bytes += stored_data
+                        .iter()
+                        .map(|data| data.payload.len())
+                        .sum::<usize>();
+                }
+            }
+        }
+        drop(storage);
+
+        let last_updates = self.last_updates.read().await;
+        let mut stats = serde_json::Map::new();
+        stats.insert("keys".into(), keys.into());
+        stats.insert("bytes".into(), bytes.into());
+        stats.insert(
+            "last_put".into(),
+            last_updates.put.map(|ts| ts.to_string()).into(),
+        );
+        stats.insert(
+            "last_delete".into(),
+            last_updates.delete.map(|ts| ts.to_string()).into(),
+        );
+        Ok(stats)
+    }
+
     /// Registers a Wildcard Update, storing it in a dedicated in-memory structure and on disk if
     /// the Storage persistence capability is set to `Durable`.
     ///
@@ -553,6 +632,7 @@ impl StorageService {
         tracing::trace!("[STORAGE] Processing query on key_expr: {}", q.key_expr());
 
         let prefix = self.configuration.strip_prefix.as_ref();
+        let encoding_filter = q.parameters().encoding_filter();
 
         if q.key_expr().is_wild() {
             // resolve key expr into individual keys
@@ -570,6 +650,12 @@ impl StorageService {
                 match storage.get(stripped_key, q.parameters().as_str()).await {
                     Ok(stored_data) => {
                         for entry in stored_data {
+                            if encoding_filter
+                                .as_ref()
+                                .is_some_and(|filter| !filter.matches(&entry.encoding))
+                            {
+                                continue;
+                            }
                             if let Err(e) = q
                                 .reply(key.clone(), entry.payload.clone())
                                 .encoding(entry.encoding.clone())
@@ -603,6 +689,12 @@ impl StorageService {
             match storage.get(stripped_key, q.parameters().as_str()).await {
                 Ok(stored_data) => {
                     for entry in stored_data {
+                        if encoding_filter
+                            .as_ref()
+                            .is_some_and(|filter| !filter.matches(&entry.encoding))
+                        {
+                            continue;
+                        }
                         if let Err(e) = q
                             .reply(q.key_expr().clone(), entry.payload.clone())
                             .encoding(entry.encoding.clone())
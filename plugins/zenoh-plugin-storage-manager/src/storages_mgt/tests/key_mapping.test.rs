@@ -0,0 +1,58 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+use std::str::FromStr;
+
+use super::*;
+
+fn ke(s: &str) -> OwnedKeyExpr {
+    OwnedKeyExpr::from_str(s).unwrap()
+}
+
+#[test]
+fn test_backend_key_roundtrip() {
+    let add_prefix = ke("backend/space");
+
+    // (stripped key, backend key)
+    let cases = [
+        (Some(ke("a")), ke("backend/space/a")),
+        (Some(ke("a/b/c")), ke("backend/space/a/b/c")),
+        // A key equal to the `strip_prefix` is stored under the `add_prefix` itself.
+        (None, ke("backend/space")),
+        // The `add_prefix` may reappear in the stripped key.
+        (Some(ke("backend/space")), ke("backend/space/backend/space")),
+    ];
+
+    for (stripped_key, backend_key) in cases {
+        assert_eq!(
+            to_backend_key(&add_prefix, stripped_key.as_ref()),
+            backend_key
+        );
+        assert_eq!(
+            from_backend_key(&add_prefix, Some(&backend_key)).unwrap(),
+            stripped_key
+        );
+    }
+}
+
+#[test]
+fn test_from_backend_key_outside_of_add_prefix() {
+    let add_prefix = ke("backend/space");
+
+    // Entries that do not belong to the key space of this Storage are rejected.
+    assert!(from_backend_key(&add_prefix, Some(&ke("other/a"))).is_err());
+    assert!(from_backend_key(&add_prefix, Some(&ke("backend"))).is_err());
+    assert!(from_backend_key(&add_prefix, Some(&ke("backend/spaces/a"))).is_err());
+    assert!(from_backend_key(&add_prefix, None).is_err());
+}
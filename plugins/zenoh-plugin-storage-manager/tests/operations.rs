@@ -19,7 +19,15 @@
 use std::{str::FromStr, thread::sleep};
 
 use tokio::runtime::Runtime;
-use zenoh::{query::Reply, sample::Sample, time::Timestamp, Config, Session};
+use zenoh::{
+    bytes::Encoding,
+    internal::plugins::RunningPlugin,
+    key_expr::KeyExpr,
+    query::{EncodingFilter, Parameters, Reply, Selector, ZenohParameters},
+    sample::Sample,
+    time::Timestamp,
+    Config, Session,
+};
 use zenoh_plugin_trait::Plugin;
 
 async fn put_data(session: &Session, key_expr: &str, value: &str, _timestamp: Timestamp) {
@@ -141,6 +149,345 @@ async fn test_updates_in_order() {
     drop(storage);
 }
 
+async fn test_key_mapping() {
+    let mut config = Config::default();
+    config
+        .insert_json5(
+            "plugins/storage-manager",
+            r#"{
+                    storages: {
+                        key_mapping_test: {
+                            key_expr: "operation/mapped/**",
+                            key_mapping: {
+                                strip_prefix: "operation/mapped",
+                                add_prefix: "backend/space",
+                            },
+                            volume: {
+                                id: "memory"
+                            }
+                        }
+                    }
+                }"#,
+        )
+        .unwrap();
+    config
+        .insert_json5(
+            "timestamping",
+            r#"{
+                    enabled: {
+                        router: true,
+                        peer: true,
+                        client: true
+                    }
+                }"#,
+        )
+        .unwrap();
+
+    let runtime = zenoh::internal::runtime::RuntimeBuilder::new(config)
+        .build()
+        .await
+        .unwrap()
+        .into();
+    let storage =
+        zenoh_plugin_storage_manager::StoragesPlugin::start("storage-manager", &runtime).unwrap();
+
+    let session = zenoh::session::init(runtime).await.unwrap();
+
+    sleep(std::time::Duration::from_secs(1));
+
+    let timestamp =
+        Timestamp::from_str("7054123566570568799/BC779A06D7E049BD88C3FF3DB0C17FCC").unwrap();
+    put_data(&session, "operation/mapped/a", "1", timestamp).await;
+    // stripping the prefix from this key produces an empty key
+    put_data(&session, "operation/mapped", "2", timestamp).await;
+
+    sleep(std::time::Duration::from_millis(10));
+
+    // replies are in the original key space
+    let data = get_data(&session, "operation/mapped/a").await;
+    assert_eq!(data.len(), 1);
+    assert_eq!(data[0].key_expr().as_str(), "operation/mapped/a");
+    assert_eq!(data[0].payload().try_to_string().unwrap(), "1");
+
+    let data = get_data(&session, "operation/mapped").await;
+    assert_eq!(data.len(), 1);
+    assert_eq!(data[0].key_expr().as_str(), "operation/mapped");
+    assert_eq!(data[0].payload().try_to_string().unwrap(), "2");
+
+    let mut data = get_data(&session, "operation/mapped/**").await;
+    data.sort_by(|a, b| a.key_expr().as_str().cmp(b.key_expr().as_str()));
+    assert_eq!(data.len(), 2);
+    assert_eq!(data[0].key_expr().as_str(), "operation/mapped");
+    assert_eq!(data[1].key_expr().as_str(), "operation/mapped/a");
+
+    // the backend key space is not exposed
+    assert!(get_data(&session, "backend/space/**").await.is_empty());
+
+    delete_data(&session, "operation/mapped/a", timestamp).await;
+
+    sleep(std::time::Duration::from_millis(10));
+
+    assert!(get_data(&session, "operation/mapped/a").await.is_empty());
+    let data = get_data(&session, "operation/mapped/**").await;
+    assert_eq!(data.len(), 1);
+    assert_eq!(data[0].key_expr().as_str(), "operation/mapped");
+
+    drop(storage);
+}
+
+async fn test_encoding_filter() {
+    let mut config = Config::default();
+    config
+        .insert_json5(
+            "plugins/storage-manager",
+            r#"{
+                    storages: {
+                        encoding_test: {
+                            key_expr: "operation/encoding/**",
+                            volume: {
+                                id: "memory"
+                            }
+                        }
+                    }
+                }"#,
+        )
+        .unwrap();
+    config
+        .insert_json5(
+            "timestamping",
+            r#"{
+                    enabled: {
+                        router: true,
+                        peer: true,
+                        client: true
+                    }
+                }"#,
+        )
+        .unwrap();
+
+    let runtime = zenoh::internal::runtime::RuntimeBuilder::new(config)
+        .build()
+        .await
+        .unwrap()
+        .into();
+    let storage =
+        zenoh_plugin_storage_manager::StoragesPlugin::start("storage-manager", &runtime).unwrap();
+
+    let session = zenoh::session::init(runtime).await.unwrap();
+
+    sleep(std::time::Duration::from_secs(1));
+
+    session
+        .put("operation/encoding/json", r#"{"a": 1}"#)
+        .encoding(Encoding::APPLICATION_JSON)
+        .await
+        .unwrap();
+    session
+        .put("operation/encoding/bytes", "1")
+        .encoding(Encoding::APPLICATION_OCTET_STREAM)
+        .await
+        .unwrap();
+
+    sleep(std::time::Duration::from_millis(10));
+
+    let get_encoded = |key_expr: &'static str, filter: Option<&'static str>| {
+        let session = session.clone();
+        async move {
+            let mut parameters = Parameters::empty();
+            parameters.set_encoding_filter(filter.map(EncodingFilter::new));
+            let selector = Selector::owned(KeyExpr::try_from(key_expr).unwrap(), parameters);
+            let replies = session.get(selector).await.unwrap();
+            let mut keys = Vec::new();
+            while let Ok(reply) = replies.recv_async().await {
+                keys.push(reply.into_result().unwrap().key_expr().to_string());
+            }
+            keys.sort();
+            keys
+        }
+    };
+
+    assert_eq!(
+        get_encoded("operation/encoding/**", None).await,
+        ["operation/encoding/bytes", "operation/encoding/json"]
+    );
+    assert_eq!(
+        get_encoded("operation/encoding/**", Some("application/json")).await,
+        ["operation/encoding/json"]
+    );
+    assert_eq!(
+        get_encoded("operation/encoding/**", Some("application/octet-stream")).await,
+        ["operation/encoding/bytes"]
+    );
+    assert_eq!(
+        get_encoded("operation/encoding/**", Some("application/*")).await,
+        ["operation/encoding/bytes", "operation/encoding/json"]
+    );
+    assert!(get_encoded("operation/encoding/**", Some("text/*"))
+        .await
+        .is_empty());
+    // non-wildcard queries are filtered as well
+    assert!(
+        get_encoded("operation/encoding/json", Some("application/octet-stream"))
+            .await
+            .is_empty()
+    );
+    assert_eq!(
+        get_encoded("operation/encoding/json", Some("application/json")).await,
+        ["operation/encoding/json"]
+    );
+
+    drop(storage);
+}
+
+/// Queries the `operation` key of the storage `storage` through the admin space getter of the
+/// plugin.
+fn query_operation(plugin: &RunningPlugin, storage: &str, operation: &str) -> serde_json::Value {
+    let plugin_status_key = "@/zid/router/status/plugins/storage-manager";
+    let key_expr = KeyExpr::try_from(format!(
+        "{plugin_status_key}/storages/{storage}/{operation}"
+    ))
+    .unwrap();
+    let mut responses = plugin
+        .adminspace_getter(&key_expr, plugin_status_key)
+        .unwrap();
+    assert_eq!(responses.len(), 1);
+    let response = responses.pop().unwrap();
+    assert_eq!(response.key, key_expr.as_str());
+    response.value.into()
+}
+
+/// Puts on the `operation` key of the storage `storage` through the admin space putter of the
+/// plugin.
+fn put_operation(plugin: &RunningPlugin, storage: &str, operation: &str) -> serde_json::Value {
+    let plugin_status_key = "@/zid/router/status/plugins/storage-manager";
+    let key_expr = KeyExpr::try_from(format!(
+        "{plugin_status_key}/storages/{storage}/{operation}"
+    ))
+    .unwrap();
+    let mut responses = plugin
+        .adminspace_putter(&key_expr, plugin_status_key)
+        .unwrap();
+    assert_eq!(responses.len(), 1);
+    let response = responses.pop().unwrap();
+    assert_eq!(response.key, key_expr.as_str());
+    response.value.into()
+}
+
+async fn test_admin_operations() {
+    let mut config = Config::default();
+    config
+        .insert_json5(
+            "plugins/storage-manager",
+            r#"{
+                    storages: {
+                        admin_test: {
+                            key_expr: "operation/admin/**",
+                            volume: {
+                                id: "memory"
+                            }
+                        }
+                    }
+                }"#,
+        )
+        .unwrap();
+    config
+        .insert_json5(
+            "timestamping",
+            r#"{
+                    enabled: {
+                        router: true,
+                        peer: true,
+                        client: true
+                    }
+                }"#,
+        )
+        .unwrap();
+
+    let runtime = zenoh::internal::runtime::RuntimeBuilder::new(config)
+        .build()
+        .await
+        .unwrap()
+        .into();
+    let storage =
+        zenoh_plugin_storage_manager::StoragesPlugin::start("storage-manager", &runtime).unwrap();
+
+    let session = zenoh::session::init(runtime).await.unwrap();
+
+    sleep(std::time::Duration::from_secs(1));
+
+    let stats = query_operation(&storage, "admin_test", "__stats");
+    assert_eq!(stats["success"], true);
+    assert_eq!(stats["keys"], 0);
+    assert_eq!(stats["bytes"], 0);
+    assert!(stats["last_put"].is_null());
+    assert!(stats["last_delete"].is_null());
+
+    let timestamp =
+        Timestamp::from_str("7054123566570568799/BC779A06D7E049BD88C3FF3DB0C17FCC").unwrap();
+    put_data(&session, "operation/admin/a", "1", timestamp).await;
+    put_data(&session, "operation/admin/b", "234", timestamp).await;
+    put_data(&session, "operation/admin/c", "56", timestamp).await;
+    delete_data(&session, "operation/admin/c", timestamp).await;
+
+    sleep(std::time::Duration::from_millis(10));
+
+    let stats = query_operation(&storage, "admin_test", "__stats");
+    assert_eq!(stats["success"], true);
+    assert_eq!(stats["keys"], 2);
+    assert_eq!(stats["bytes"], 4);
+    let last_put: Timestamp = stats["last_put"].as_str().unwrap().parse().unwrap();
+    let last_delete: Timestamp = stats["last_delete"].as_str().unwrap().parse().unwrap();
+    assert!(last_put < last_delete);
+
+    // The operations are not performed by queries, nor by puts on wildcards.
+    let status_key = "@/zid/router/status/plugins/storage-manager";
+    let operations_key = KeyExpr::try_from(format!("{status_key}/storages/admin_test/*")).unwrap();
+    assert!(storage
+        .adminspace_getter(&operations_key, status_key)
+        .unwrap()
+        .is_empty());
+    assert!(storage
+        .adminspace_putter(&operations_key, status_key)
+        .unwrap()
+        .is_empty());
+
+    // The memory backend does not compact its data.
+    let compact = put_operation(&storage, "admin_test", "__compact");
+    assert_eq!(compact, serde_json::json!({ "success": true }));
+    assert_eq!(get_data(&session, "operation/admin/**").await.len(), 2);
+
+    let align = put_operation(&storage, "admin_test", "__align");
+    assert_eq!(align["success"], false);
+    assert!(align["error"].is_string());
+
+    // The queries get the reply of the latest operation.
+    assert_eq!(
+        query_operation(&storage, "admin_test", "__compact"),
+        compact
+    );
+    assert_eq!(query_operation(&storage, "admin_test", "__align"), align);
+
+    drop(storage);
+}
+
+#[test]
+fn admin_operations_test() {
+    let rt = Runtime::new().unwrap();
+    rt.block_on(async { test_admin_operations().await });
+}
+
+#[test]
+fn encoding_filter_test() {
+    let rt = Runtime::new().unwrap();
+    rt.block_on(async { test_encoding_filter().await });
+}
+
+#[test]
+fn key_mapping_test() {
+    let rt = Runtime::new().unwrap();
+    rt.block_on(async { test_key_mapping().await });
+}
+
 #[test]
 fn updates_test() {
     let rt = Runtime::new().unwrap();
@@ -14,7 +14,7 @@
 
 use std::fmt::Display;
 
-use zenoh_result::{bail, ZResult};
+use zenoh_result::{bail, zerror, ZResult};
 
 pub trait StructVersion {
     /// The version of the structure which implements this trait.
@@ -34,70 +34,170 @@ pub struct Compatibility {
 }
 
 impl Compatibility {
+    /// Builds the [`Compatibility`] record of the current build.
+    ///
+    /// An unparseable rustc version is replaced by a placeholder that no host is compatible with,
+    /// see [`Compatibility::try_new`] to get an error instead.
     pub fn new(zenoh_version: &'static str, zenoh_features: &'static str) -> Self {
-        let rust_version = RustVersion::new();
         Self {
-            rust_version,
+            rust_version: RustVersion::new(),
             zenoh_version: zenoh_version.into(),
             zenoh_features: zenoh_features.into(),
         }
     }
 
-    pub fn check(&self, other: &Self) -> ZResult<()> {
-        fn get_version_and_commit(version: &str) -> (&str, &str) {
-            let parts = version.split('-').collect::<Vec<_>>();
-            (
-                parts.first().cloned().unwrap_or("undefined"),
-                parts.get(1).cloned().unwrap_or("undefined"),
-            )
+    /// Builds the [`Compatibility`] record of the current build.
+    ///
+    /// Returns an error if the rustc version the crate was compiled with can't be parsed.
+    pub fn try_new(zenoh_version: &'static str, zenoh_features: &'static str) -> ZResult<Self> {
+        Ok(Self {
+            rust_version: RustVersion::try_new()?,
+            zenoh_version: zenoh_version.into(),
+            zenoh_features: zenoh_features.into(),
+        })
+    }
+
+    #[cfg(test)]
+    fn from_rustc_release(
+        release: &str,
+        commit: &'static str,
+        zenoh_version: &'static str,
+        zenoh_features: &'static str,
+    ) -> ZResult<Self> {
+        Ok(Self {
+            rust_version: RustVersion::parse(release, commit)?,
+            zenoh_version: zenoh_version.into(),
+            zenoh_features: zenoh_features.into(),
+        })
+    }
+
+    /// Builds a [`Compatibility`] from explicit values, to test the compatibility checks against
+    /// arbitrary rustc versions and structure versions or features.
+    #[doc(hidden)]
+    pub fn for_test(
+        major: u32,
+        minor: u32,
+        patch: u32,
+        stable: bool,
+        commit: &'static str,
+        zenoh_version: &'static str,
+        zenoh_features: &'static str,
+    ) -> Self {
+        Self {
+            rust_version: RustVersion {
+                major,
+                minor,
+                patch,
+                stable,
+                commit: commit.into(),
+            },
+            zenoh_version: zenoh_version.into(),
+            zenoh_features: zenoh_features.into(),
         }
+    }
 
-        fn version_equals(left: &str, right: &str) -> bool {
-            const RELEASE_COMMIT: &str = "release"; // fallback from `zenoh::GIT_COMMIT`
-            let (left_version, left_commit) = get_version_and_commit(left);
-            let (right_version, right_commit) = get_version_and_commit(right);
-            if left_version != right_version {
-                return false;
-            }
-            // We check equality of git hashes of zenoh crate used by plugin and host.
-            // This is mostly done for development purposes, to avoid crashes in case of
-            // internal API change during releases.
-            // If we receive "release" instead of commit hash, it means that
-            // zenoh crate is taken from crates.io (or is built in the environment without git ?).
-            // In this case we ignore the commit hash check.
-            if left_commit != right_commit
-                && left_commit != RELEASE_COMMIT
-                && right_commit != RELEASE_COMMIT
-            {
-                return false;
-            }
-            true
+    /// Compares the [`Compatibility`] records of a `host` and of a `plugin` it tries to load.
+    ///
+    /// Unlike [`Compatibility::check`], the outcome tells which side should be rebuilt: the rustc
+    /// versions are compared first, then the Zenoh versions. Builds with the same versions whose
+    /// structures may still differ (unstable rustc commits, Zenoh commits or features) are
+    /// reported as [`CompatOutcome::LayoutMismatch`].
+    pub fn compare(host: &Self, plugin: &Self) -> CompatOutcome {
+        match host.rust_version.compare(&plugin.rust_version) {
+            CompatOutcome::Compatible => {}
+            outcome => return outcome,
         }
+        match compare_zenoh_versions(&host.zenoh_version, &plugin.zenoh_version) {
+            CompatOutcome::Compatible => {}
+            outcome => return outcome,
+        }
+        if host.zenoh_features != plugin.zenoh_features {
+            return CompatOutcome::LayoutMismatch;
+        }
+        CompatOutcome::Compatible
+    }
 
-        if self.rust_version != other.rust_version {
-            bail!(
-                "Incompatible rustc versions:\n host: {}\n plugin: {}",
-                self.rust_version,
-                other.rust_version
-            )
-        } else if !version_equals(&self.zenoh_version, &other.zenoh_version) {
-            bail!(
-                "Incompatible Zenoh versions:\n host: {}\n plugin: {}",
-                self.zenoh_version,
-                other.zenoh_version
-            )
-        } else if self.zenoh_features != other.zenoh_features {
-            bail!(
-                "Incompatible Zenoh feature sets:\n host: {}\n plugin: {}",
-                self.zenoh_features,
-                other.zenoh_features
-            )
-        } else {
-            Ok(())
+    /// Checks that the plugin described by `other` can be loaded by the host described by `self`.
+    ///
+    /// The error tells the [`CompatOutcome`] of [`Compatibility::compare`], followed by the
+    /// versions of both sides.
+    pub fn check(&self, other: &Self) -> ZResult<()> {
+        match Self::compare(self, other) {
+            CompatOutcome::Compatible => Ok(()),
+            outcome => bail!(
+                kind = PluginIncompatible,
+                "Incompatible plugin ({outcome}):\nhost:\n{self}plugin:\n{other}"
+            ),
         }
     }
 }
 
+/// Compares Zenoh versions of the form `<version>-<commit>`, such as `1.9.0-abcdef`.
+fn compare_zenoh_versions(host: &str, plugin: &str) -> CompatOutcome {
+    // Fallback from `zenoh::GIT_COMMIT`
+    const RELEASE_COMMIT: &str = "release";
+
+    fn get_version_and_commit(version: &str) -> (&str, &str) {
+        let parts = version.split('-').collect::<Vec<_>>();
+        (
+            parts.first().cloned().unwrap_or("undefined"),
+            parts.get(1).cloned().unwrap_or("undefined"),
+        )
+    }
+
+    fn parse_version(version: &str) -> Option<Vec<u64>> {
+        version.split('.').map(|n| n.parse().ok()).collect()
+    }
+
+    let (host_version, host_commit) = get_version_and_commit(host);
+    let (plugin_version, plugin_commit) = get_version_and_commit(plugin);
+    if host_version != plugin_version {
+        return match (parse_version(host_version), parse_version(plugin_version)) {
+            (Some(h), Some(p)) if p < h => CompatOutcome::PluginTooOld,
+            (Some(h), Some(p)) if p > h => CompatOutcome::PluginTooNew,
+            _ => CompatOutcome::LayoutMismatch,
+        };
+    }
+    // We check equality of git hashes of zenoh crate used by plugin and host.
+    // This is mostly done for development purposes, to avoid crashes in case of
+    // internal API change during releases.
+    // If we receive "release" instead of commit hash, it means that
+    // zenoh crate is taken from crates.io (or is built in the environment without git ?).
+    // In this case we ignore the commit hash check.
+    if host_commit != plugin_commit
+        && host_commit != RELEASE_COMMIT
+        && plugin_commit != RELEASE_COMMIT
+    {
+        return CompatOutcome::LayoutMismatch;
+    }
+    CompatOutcome::Compatible
+}
+
+/// The outcome of [`Compatibility::compare`] between a host and a plugin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompatOutcome {
+    /// The plugin can be loaded by the host.
+    Compatible,
+    /// The plugin was built with an older rustc or Zenoh version than the host.
+    PluginTooOld,
+    /// The plugin was built with a newer rustc or Zenoh version than the host.
+    PluginTooNew,
+    /// The plugin was built with the same versions as the host, but from different unstable
+    /// rustc or Zenoh commits, or with different Zenoh features.
+    LayoutMismatch,
+}
+
+impl Display for CompatOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            CompatOutcome::Compatible => "compatible",
+            CompatOutcome::PluginTooOld => "the plugin is older than the host",
+            CompatOutcome::PluginTooNew => "the plugin is newer than the host",
+            CompatOutcome::LayoutMismatch => "the plugin and the host layouts differ",
+        })
+    }
+}
+
 impl Display for Compatibility {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "Rust version:\n{}", self.rust_version)?;
@@ -133,20 +233,57 @@ impl Display for RustVersion {
 
 const RELEASE_AND_COMMIT: (&str, &str) = zenoh_macros::rustc_version_release!();
 impl RustVersion {
+    /// Gets the version of rustc the crate was compiled with.
+    ///
+    /// An unparseable version is replaced by a placeholder that no other version is compatible
+    /// with, see [`RustVersion::try_new`] to get an error instead.
     pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|e| {
+            tracing::error!("Unable to get the rustc version: {e}");
+            Self::unknown()
+        })
+    }
+
+    /// Gets the version of rustc the crate was compiled with.
+    ///
+    /// Returns an error if the version can't be parsed.
+    pub fn try_new() -> ZResult<Self> {
         let (release, commit) = RELEASE_AND_COMMIT;
-        let (release, stable) = if let Some(p) = release.chars().position(|c| c == '-') {
+        Self::parse(release, commit)
+    }
+
+    fn parse(release: &str, commit: &'static str) -> ZResult<Self> {
+        let (version, stable) = if let Some(p) = release.chars().position(|c| c == '-') {
             (&release[..p], false)
         } else {
             (release, true)
         };
-        let mut split = release.split('.').map(|s| s.trim());
-        RustVersion {
-            major: split.next().unwrap().parse().unwrap(),
-            minor: split.next().unwrap().parse().unwrap(),
-            patch: split.next().unwrap().parse().unwrap(),
+        let mut split = version.split('.').map(|s| s.trim());
+        let mut next = |component: &str| -> ZResult<u32> {
+            let Some(value) = split.next() else {
+                bail!("Missing {component} version in rustc release '{release}'")
+            };
+            value.parse().map_err(|e| {
+                zerror!("Invalid {component} version '{value}' in rustc release '{release}': {e}")
+                    .into()
+            })
+        };
+        Ok(RustVersion {
+            major: next("major")?,
+            minor: next("minor")?,
+            patch: next("patch")?,
             stable,
             commit: commit.into(),
+        })
+    }
+
+    fn unknown() -> Self {
+        RustVersion {
+            major: 0,
+            minor: 0,
+            patch: 0,
+            stable: false,
+            commit: "unknown".into(),
         }
     }
 }
@@ -156,3 +293,272 @@ impl Default for RustVersion {
         Self::new()
     }
 }
+
+impl RustVersion {
+    /// Returns `true` if the structures compiled by the two rustc versions have the same layout.
+    ///
+    /// Stable releases of rustc are identified by their version only: the commit hash is
+    /// intentionally ignored, as two builds of the same stable release produce the same layouts.
+    /// Unstable (e.g. nightly) builds may change layouts from one commit to another, so the commit
+    /// hash must match as well.
+    pub fn are_compatible(&self, other: &Self) -> bool {
+        if self.stable && other.stable {
+            self.major == other.major && self.minor == other.minor && self.patch == other.patch
+        } else {
+            self == other
+        }
+    }
+
+    /// Compares the rustc version of a host (`self`) with the one of a plugin (`other`).
+    fn compare(&self, other: &Self) -> CompatOutcome {
+        if self.are_compatible(other) {
+            return CompatOutcome::Compatible;
+        }
+        let release = |v: &Self| (v.major, v.minor, v.patch);
+        match release(other).cmp(&release(self)) {
+            std::cmp::Ordering::Less => CompatOutcome::PluginTooOld,
+            std::cmp::Ordering::Greater => CompatOutcome::PluginTooNew,
+            std::cmp::Ordering::Equal => CompatOutcome::LayoutMismatch,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rust_version(stable: bool, commit: &'static str) -> RustVersion {
+        RustVersion {
+            major: 1,
+            minor: 75,
+            patch: 0,
+            stable,
+            commit: commit.into(),
+        }
+    }
+
+    #[test]
+    fn stable_rust_versions_ignore_commit() {
+        let host = rust_version(true, "82e1608df");
+        let plugin = rust_version(true, "0e7a185d3");
+        assert!(host.are_compatible(&plugin));
+
+        let plugin = RustVersion {
+            patch: 1,
+            ..rust_version(true, "82e1608df")
+        };
+        assert!(!host.are_compatible(&plugin));
+    }
+
+    #[test]
+    fn unstable_rust_versions_check_commit() {
+        let host = rust_version(false, "82e1608df");
+        let plugin = rust_version(false, "0e7a185d3");
+        assert!(!host.are_compatible(&plugin));
+        assert!(host.are_compatible(&rust_version(false, "82e1608df")));
+
+        let plugin = rust_version(true, "82e1608df");
+        assert!(!host.are_compatible(&plugin));
+    }
+
+    #[test]
+    fn rust_version_parsing() {
+        let version = RustVersion::parse("1.75.0", "82e1608df").unwrap();
+        assert_eq!(version, rust_version(true, "82e1608df"));
+        let version = RustVersion::parse("1.75.0-nightly", "82e1608df").unwrap();
+        assert_eq!(version, rust_version(false, "82e1608df"));
+        assert!(RustVersion::try_new().is_ok());
+        assert_eq!(RustVersion::new(), RustVersion::try_new().unwrap());
+    }
+
+    #[test]
+    fn malformed_rustc_release_is_an_error() {
+        for release in ["", "1.75", "1.x.0", "1..0", "-nightly"] {
+            assert!(
+                Compatibility::from_rustc_release(release, "82e1608df", "1.9.0-release", "")
+                    .is_err(),
+                "{release:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_rust_version_is_incompatible() {
+        let host = Compatibility::new("1.9.0-release", "");
+        let plugin = Compatibility {
+            rust_version: RustVersion::unknown(),
+            ..host.clone()
+        };
+        assert!(host.check(&plugin).is_err());
+    }
+
+    #[test]
+    fn compatibility_check_uses_rust_version_compatibility() {
+        let host = Compatibility {
+            rust_version: rust_version(true, "82e1608df"),
+            ..Compatibility::new("1.9.0-release", "")
+        };
+        let plugin = Compatibility {
+            rust_version: rust_version(true, "0e7a185d3"),
+            ..Compatibility::new("1.9.0-release", "")
+        };
+        assert!(host.check(&plugin).is_ok());
+
+        let host = Compatibility {
+            rust_version: rust_version(false, "82e1608df"),
+            ..host
+        };
+        let plugin = Compatibility {
+            rust_version: rust_version(false, "0e7a185d3"),
+            ..plugin
+        };
+        assert!(host.check(&plugin).is_err());
+    }
+
+    #[test]
+    fn compatibility_check_scenarios() {
+        let host = Compatibility::for_test(1, 75, 0, true, "82e1608df", "1.9.0-abcdef", "shm");
+        // (plugin, expected to be compatible)
+        let scenarios = [
+            // identical
+            (
+                Compatibility::for_test(1, 75, 0, true, "82e1608df", "1.9.0-abcdef", "shm"),
+                true,
+            ),
+            // stable rustc builds of the same release
+            (
+                Compatibility::for_test(1, 75, 0, true, "0e7a185d3", "1.9.0-abcdef", "shm"),
+                true,
+            ),
+            // rustc version skew
+            (
+                Compatibility::for_test(1, 75, 1, true, "82e1608df", "1.9.0-abcdef", "shm"),
+                false,
+            ),
+            (
+                Compatibility::for_test(1, 76, 0, true, "82e1608df", "1.9.0-abcdef", "shm"),
+                false,
+            ),
+            // stable host, unstable plugin
+            (
+                Compatibility::for_test(1, 75, 0, false, "82e1608df", "1.9.0-abcdef", "shm"),
+                false,
+            ),
+            // zenoh version skew
+            (
+                Compatibility::for_test(1, 75, 0, true, "82e1608df", "1.9.1-abcdef", "shm"),
+                false,
+            ),
+            // zenoh commit mismatch
+            (
+                Compatibility::for_test(1, 75, 0, true, "82e1608df", "1.9.0-123456", "shm"),
+                false,
+            ),
+            // zenoh release build matches any commit
+            (
+                Compatibility::for_test(1, 75, 0, true, "82e1608df", "1.9.0-release", "shm"),
+                true,
+            ),
+            // structure feature mismatch
+            (
+                Compatibility::for_test(1, 75, 0, true, "82e1608df", "1.9.0-abcdef", ""),
+                false,
+            ),
+            (
+                Compatibility::for_test(
+                    1,
+                    75,
+                    0,
+                    true,
+                    "82e1608df",
+                    "1.9.0-abcdef",
+                    "shm unstable",
+                ),
+                false,
+            ),
+        ];
+        for (plugin, compatible) in scenarios {
+            assert_eq!(
+                host.check(&plugin).is_ok(),
+                compatible,
+                "host:\n{host}plugin:\n{plugin}"
+            );
+            assert_eq!(plugin.check(&host).is_ok(), compatible);
+        }
+
+        // unstable rustc builds must match exactly
+        let host = Compatibility::for_test(1, 76, 0, false, "82e1608df", "1.9.0-release", "");
+        let plugin = Compatibility::for_test(1, 76, 0, false, "0e7a185d3", "1.9.0-release", "");
+        assert!(host.check(&plugin).is_err());
+        let plugin = Compatibility::for_test(1, 76, 0, false, "82e1608df", "1.9.0-release", "");
+        assert!(host.check(&plugin).is_ok());
+    }
+
+    #[test]
+    fn compatibility_compare_outcomes() {
+        let host = Compatibility::for_test(1, 75, 0, true, "82e1608df", "1.9.0-abcdef", "shm");
+        let scenarios = [
+            (
+                Compatibility::for_test(1, 75, 0, true, "0e7a185d3", "1.9.0-release", "shm"),
+                CompatOutcome::Compatible,
+            ),
+            // older rustc, newer Zenoh: rustc is compared first
+            (
+                Compatibility::for_test(1, 74, 1, true, "82e1608df", "1.10.0-abcdef", "shm"),
+                CompatOutcome::PluginTooOld,
+            ),
+            (
+                Compatibility::for_test(1, 75, 0, true, "82e1608df", "1.8.2-abcdef", "shm"),
+                CompatOutcome::PluginTooOld,
+            ),
+            (
+                Compatibility::for_test(1, 76, 0, true, "82e1608df", "1.8.2-abcdef", "shm"),
+                CompatOutcome::PluginTooNew,
+            ),
+            // versions are compared numerically
+            (
+                Compatibility::for_test(1, 75, 0, true, "82e1608df", "1.10.0-abcdef", "shm"),
+                CompatOutcome::PluginTooNew,
+            ),
+            (
+                Compatibility::for_test(1, 75, 0, false, "82e1608df", "1.9.0-abcdef", "shm"),
+                CompatOutcome::LayoutMismatch,
+            ),
+            (
+                Compatibility::for_test(1, 75, 0, true, "82e1608df", "1.9.0-123456", "shm"),
+                CompatOutcome::LayoutMismatch,
+            ),
+            (
+                Compatibility::for_test(1, 75, 0, true, "82e1608df", "1.9.0-abcdef", ""),
+                CompatOutcome::LayoutMismatch,
+            ),
+            (
+                Compatibility::for_test(1, 75, 0, true, "82e1608df", "dev-abcdef", "shm"),
+                CompatOutcome::LayoutMismatch,
+            ),
+        ];
+        for (plugin, outcome) in scenarios {
+            assert_eq!(
+                Compatibility::compare(&host, &plugin),
+                outcome,
+                "host:\n{host}plugin:\n{plugin}"
+            );
+            assert_eq!(
+                host.check(&plugin).is_ok(),
+                outcome == CompatOutcome::Compatible
+            );
+        }
+
+        let plugin = Compatibility::for_test(1, 75, 0, true, "82e1608df", "1.8.2-abcdef", "shm");
+        let error = host.check(&plugin).unwrap_err().to_string();
+        assert!(
+            error.contains("the plugin is older than the host"),
+            "{error}"
+        );
+        let error = plugin.check(&host).unwrap_err().to_string();
+        assert!(
+            error.contains("the plugin is newer than the host"),
+            "{error}"
+        );
+    }
+}
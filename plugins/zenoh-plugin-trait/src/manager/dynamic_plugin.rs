@@ -63,6 +63,7 @@ impl<StartArgs: PluginStartArgs, Instance: PluginInstance>
         tracing::debug!("Plugin loader version: {}", &plugin_loader_version);
         if plugin_loader_version != PLUGIN_LOADER_VERSION {
             bail!(
+                kind = PluginIncompatible,
                 "Plugin loader version mismatch: host = {}, plugin = {}",
                 PLUGIN_LOADER_VERSION,
                 plugin_loader_version
@@ -71,13 +72,13 @@ impl<StartArgs: PluginStartArgs, Instance: PluginInstance>
         let get_compatibility = unsafe { lib.get::<fn() -> Compatibility>(b"get_compatibility")? };
         let plugin_compatibility_record = get_compatibility();
         let host_compatibility_record =
-            Compatibility::new(StartArgs::struct_version(), StartArgs::struct_features());
+            Compatibility::try_new(StartArgs::struct_version(), StartArgs::struct_features())?;
         tracing::debug!(
             "Plugin compatibility record: {:?}",
             &plugin_compatibility_record
         );
         if let Err(e) = host_compatibility_record.check(&plugin_compatibility_record) {
-            bail!("Plugin compatibility mismatch:\n {}", e);
+            bail!(e => "Plugin compatibility mismatch");
         }
         let load_plugin =
             unsafe { lib.get::<fn() -> PluginVTable<StartArgs, Instance>>(b"load_plugin")? };
@@ -86,7 +87,7 @@ impl<StartArgs: PluginStartArgs, Instance: PluginInstance>
     }
     fn new(lib: Library, path: PathBuf) -> ZResult<Self> {
         let vtable = Self::get_vtable(&lib, &path)
-            .map_err(|e| format!("Error loading {}: {}", path.to_str().unwrap(), e))?;
+            .map_err(|e| zerror!(e => "Error loading {}", path.to_str().unwrap()))?;
         Ok(Self {
             _lib: lib,
             path,
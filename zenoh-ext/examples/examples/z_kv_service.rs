@@ -0,0 +1,102 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{collections::HashMap, time::Duration};
+
+use clap::Parser;
+use zenoh::{
+    config::Config,
+    key_expr::OwnedKeyExpr,
+    query::Query,
+    sample::{Sample, SampleKind},
+    Wait,
+};
+use zenoh_ext::{HistoryConfig, ServiceHandler, SessionExt};
+use zenoh_ext_examples::CommonArgs;
+
+/// A key-value cache storing the latest sample published on each key.
+#[derive(Clone, Default)]
+struct KvCache(HashMap<OwnedKeyExpr, Sample>);
+
+impl ServiceHandler for KvCache {
+    fn on_sample(&mut self, sample: Sample) {
+        println!(
+            ">> [Service] Received {} ('{}')",
+            sample.kind(),
+            sample.key_expr().as_str(),
+        );
+        match sample.kind() {
+            SampleKind::Put => {
+                self.0.insert(sample.key_expr().clone().into(), sample);
+            }
+            SampleKind::Delete => {
+                self.0.remove(sample.key_expr().as_keyexpr());
+            }
+        }
+    }
+
+    fn on_query(&self, query: Query) {
+        println!(">> [Service] Received Query '{}'", query.selector());
+        for (key_expr, sample) in &self.0 {
+            if query.key_expr().intersects(key_expr) {
+                if let Err(e) = query.reply_sample(sample).wait() {
+                    println!(">> [Service] Error replying to query: {e}");
+                }
+            }
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    // Initiate logging
+    zenoh::init_log_from_env_or("error");
+
+    let (config, key_expr, history) = parse_args();
+
+    println!("Opening session...");
+    let session = zenoh::open(config).await.unwrap();
+
+    println!("Declaring Service on {key_expr}...");
+    let builder = session.declare_service(&key_expr, KvCache::default());
+    let _service = if history {
+        builder
+            .history(HistoryConfig::default().detect_late_publishers())
+            .await
+            .unwrap()
+    } else {
+        builder.await.unwrap()
+    };
+
+    println!("Press CTRL-C to quit...");
+    loop {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+}
+
+#[derive(clap::Parser, Clone, PartialEq, Eq, Hash, Debug)]
+struct Args {
+    #[arg(short, long, default_value = "demo/example/**")]
+    /// The key expression of the cache.
+    key: String,
+    #[arg(long)]
+    /// Replay the history of the key expression from the advanced publishers' caches at startup.
+    history: bool,
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+fn parse_args() -> (Config, String, bool) {
+    let args = Args::parse();
+    (args.common.into(), args.key, args.history)
+}
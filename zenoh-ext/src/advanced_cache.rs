@@ -150,7 +150,7 @@ impl<'a, 'b, 'c> AdvancedCacheBuilder<'a, 'b, 'c> {
             session,
             pub_key_expr,
             queryable_suffix: Some(Ok((KE_ADV_PREFIX / KE_STARSTAR).into())),
-            queryable_origin: Locality::default(),
+            queryable_origin: Locality::Any,
             history: CacheConfig::default(),
             liveliness: false,
         }
@@ -292,8 +292,7 @@ impl AdvancedCache {
                                                 conf.history.replies_config.congestion_control,
                                             )
                                             .priority(conf.history.replies_config.priority)
-                                            .express(conf.history.replies_config.is_express)
-                                            .into(),
+                                            .express(conf.history.replies_config.is_express),
                                     )
                                     .wait()
                                 {
@@ -330,8 +329,7 @@ impl AdvancedCache {
                                                     conf.history.replies_config.congestion_control,
                                                 )
                                                 .priority(conf.history.replies_config.priority)
-                                                .express(conf.history.replies_config.is_express)
-                                                .into(),
+                                                .express(conf.history.replies_config.is_express),
                                         )
                                         .wait()
                                     {
@@ -401,11 +401,19 @@ impl Group {
         let task_controller = TaskController::default();
         // If the liveliness is manual it is the user who has to assert it.
         if is_auto_liveliness {
-            task_controller.spawn_abortable(keep_alive_task(state.clone()));
+            task_controller
+                .spawn_abortable_named("zenoh:group:keepalive", keep_alive_task(state.clone()));
         }
-        task_controller.spawn_abortable(net_event_handler(z.clone(), state.clone()));
-        task_controller.spawn_abortable(query_handler(z.clone(), state.clone()));
-        task_controller.spawn_abortable(watchdog_task(state.clone(), Duration::from_secs(1)));
+        task_controller.spawn_abortable_named(
+            "zenoh:group:net_event",
+            net_event_handler(z.clone(), state.clone()),
+        );
+        task_controller
+            .spawn_abortable_named("zenoh:group:query", query_handler(z.clone(), state.clone()));
+        task_controller.spawn_abortable_named(
+            "zenoh:group:watchdog",
+            watchdog_task(state.clone(), Duration::from_secs(1)),
+        );
         Ok(Group {
             state,
             task_controller,
@@ -36,6 +36,14 @@
 //!
 //! The [`AdvancedPublisher`] and [`AdvancedSubscriber`] provide advanced pub/sub
 //! functionalities, including support for message history, recovery, and more.
+//!
+//! The [`RateLimitedPublisher`] limits the rate of its publications, coalescing the ones made
+//! faster than the configured rate.
+//!
+//! # Services
+//!
+//! A [`Service`] pairs a subscriber maintaining a state with a queryable answering queries about
+//! it, the state being implemented by a [`ServiceHandler`].
 #[cfg(feature = "unstable")]
 mod advanced_cache;
 #[cfg(feature = "unstable")]
@@ -50,8 +58,12 @@ mod publication_cache;
 mod publisher_ext;
 #[cfg(feature = "unstable")]
 mod querying_subscriber;
+#[cfg(feature = "unstable")]
+mod rate_limited_publisher;
 mod serialization;
 #[cfg(feature = "unstable")]
+mod service;
+#[cfg(feature = "unstable")]
 mod session_ext;
 #[cfg(feature = "unstable")]
 mod subscriber_ext;
@@ -77,11 +89,16 @@ pub use crate::{
         SampleMissHandlerUndeclaration, SampleMissListener, SampleMissListenerBuilder,
     },
     publication_cache::{PublicationCache, PublicationCacheBuilder},
-    publisher_ext::AdvancedPublisherBuilderExt,
+    publisher_ext::{AdvancedPublisherBuilderExt, PublisherBuilderExt},
     querying_subscriber::{
         ExtractSample, FetchingSubscriber, FetchingSubscriberBuilder, KeySpace, LivelinessSpace,
         QueryingSubscriberBuilder, UserSpace,
     },
+    rate_limited_publisher::{
+        CoalescingStrategy, RateLimitedPublicationBuilder, RateLimitedPublisher,
+        RateLimitedPublisherBuilder,
+    },
+    service::{Service, ServiceBuilder, ServiceHandler},
     session_ext::SessionExt,
     subscriber_ext::{AdvancedSubscriberBuilderExt, SubscriberBuilderExt, SubscriberForward},
 };
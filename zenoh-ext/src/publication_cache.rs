@@ -315,6 +315,7 @@ impl PublicationCache {
                         // on query, reply with cached content
                         query = quer_recv.recv_async() => {
                             if let Ok(query) = query {
+                                let encoding_filter = query.parameters().encoding_filter();
                                 if !query.key_expr().as_str().contains('*') {
                                     if let Some(queue) = cache.get(query.key_expr().as_keyexpr()) {
                                         for sample in queue {
@@ -323,7 +324,10 @@ impl PublicationCache {
                                                     continue;
                                                 }
                                             }
-                                            if let Err(e) = query.reply_sample(sample.clone()).await {
+                                            if encoding_filter.as_ref().is_some_and(|filter| !filter.matches(sample.encoding())) {
+                                                continue;
+                                            }
+                                            if let Err(e) = query.reply_sample(sample).await {
                                                 tracing::warn!("Error replying to query: {}", e);
                                             }
                                         }
@@ -337,7 +341,10 @@ impl PublicationCache {
                                                         continue;
                                                     }
                                                 }
-                                                if let Err(e) = query.reply_sample(sample.clone()).await {
+                                                if encoding_filter.as_ref().is_some_and(|filter| !filter.matches(sample.encoding())) {
+                                                    continue;
+                                                }
+                                                if let Err(e) = query.reply_sample(sample).await {
                                                     tracing::warn!("Error replying to query: {}", e);
                                                 }
                                             }
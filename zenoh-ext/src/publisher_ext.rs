@@ -13,7 +13,10 @@
 //
 use zenoh::pubsub::PublisherBuilder;
 
-use crate::{advanced_cache::CacheConfig, AdvancedPublisherBuilder, MissDetectionConfig};
+use crate::{
+    advanced_cache::CacheConfig, AdvancedPublisherBuilder, MissDetectionConfig,
+    RateLimitedPublisherBuilder,
+};
 
 /// Some extensions to the [`zenoh::publication::PublisherBuilder`](zenoh::publication::PublisherBuilder)
 #[zenoh_macros::unstable]
@@ -75,3 +78,20 @@ impl<'a, 'b, 'c> AdvancedPublisherBuilderExt<'a, 'b, 'c> for PublisherBuilder<'a
         AdvancedPublisherBuilder::new(self)
     }
 }
+
+/// Some extensions to the [`zenoh::pubsub::PublisherBuilder`](zenoh::pubsub::PublisherBuilder)
+#[zenoh_macros::unstable]
+pub trait PublisherBuilderExt<'a, 'b> {
+    /// Turn this [`Publisher`](zenoh::pubsub::Publisher) into a [`RateLimitedPublisher`](crate::RateLimitedPublisher).
+    #[zenoh_macros::unstable]
+    fn rate_limited(self) -> RateLimitedPublisherBuilder<'a, 'b>;
+}
+
+#[zenoh_macros::unstable]
+impl<'a, 'b> PublisherBuilderExt<'a, 'b> for PublisherBuilder<'a, 'b> {
+    /// Turn this [`Publisher`](zenoh::pubsub::Publisher) into a [`RateLimitedPublisher`](crate::RateLimitedPublisher).
+    #[zenoh_macros::unstable]
+    fn rate_limited(self) -> RateLimitedPublisherBuilder<'a, 'b> {
+        RateLimitedPublisherBuilder::new(self)
+    }
+}
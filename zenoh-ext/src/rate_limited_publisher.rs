@@ -0,0 +1,526 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{
+    collections::HashMap,
+    fmt,
+    future::{IntoFuture, Ready},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use tokio::sync::Notify;
+use zenoh::{
+    bytes::{Encoding, OptionZBytes, ZBytes},
+    internal::{
+        bail, runtime::ZRuntime, traits::EncodingBuilderTrait, zlock, ResolveFuture,
+        TerminatableTask,
+    },
+    key_expr::KeyExpr,
+    pubsub::{Publisher, PublisherBuilder, PublisherPutBuilder},
+    Resolvable, Resolve, Result as ZResult, Wait,
+};
+
+use crate::z_deserialize;
+
+/// The publication retained by a [`RateLimitedPublisher`] when several are made within an interval.
+#[zenoh_macros::unstable]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CoalescingStrategy {
+    /// The last publication is retained, replacing the pending one.
+    #[default]
+    LatestWins,
+    /// The first publication is retained, the following ones are dropped.
+    FirstWins,
+}
+
+/// The builder of a [`RateLimitedPublisher`], allowing to configure it.
+#[zenoh_macros::unstable]
+#[must_use = "Resolvables do nothing unless you resolve them using `.await` or `zenoh::Wait::wait`"]
+pub struct RateLimitedPublisherBuilder<'a, 'b> {
+    builder: PublisherBuilder<'a, 'b>,
+    min_interval: Option<Duration>,
+    max_rate: Option<f64>,
+    strategy: CoalescingStrategy,
+    flush_key: Option<String>,
+}
+
+#[zenoh_macros::unstable]
+impl fmt::Debug for RateLimitedPublisherBuilder<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RateLimitedPublisherBuilder")
+            .field("key_expr", &self.builder.key_expr)
+            .field("min_interval", &self.min_interval)
+            .field("max_rate", &self.max_rate)
+            .field("strategy", &self.strategy)
+            .field("flush_key", &self.flush_key)
+            .finish()
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<'a, 'b> RateLimitedPublisherBuilder<'a, 'b> {
+    pub(crate) fn new(builder: PublisherBuilder<'a, 'b>) -> Self {
+        RateLimitedPublisherBuilder {
+            builder,
+            min_interval: None,
+            max_rate: None,
+            strategy: CoalescingStrategy::default(),
+            flush_key: None,
+        }
+    }
+
+    /// Publish at most `max_rate` samples per second.
+    ///
+    /// [`max_rate`](RateLimitedPublisherBuilder::max_rate) and
+    /// [`min_interval`](RateLimitedPublisherBuilder::min_interval) are mutually exclusive.
+    /// Setting one will unset the other.
+    #[zenoh_macros::unstable]
+    pub fn max_rate(mut self, hz: f64) -> Self {
+        self.max_rate = Some(hz);
+        self.min_interval = None;
+        self
+    }
+
+    /// Publish at most one sample per `interval`.
+    ///
+    /// [`max_rate`](RateLimitedPublisherBuilder::max_rate) and
+    /// [`min_interval`](RateLimitedPublisherBuilder::min_interval) are mutually exclusive.
+    /// Setting one will unset the other.
+    #[zenoh_macros::unstable]
+    pub fn min_interval(mut self, interval: Duration) -> Self {
+        self.min_interval = Some(interval);
+        self.max_rate = None;
+        self
+    }
+
+    /// Change the [`CoalescingStrategy`] applied to the publications made within an interval.
+    ///
+    /// The default strategy is [`CoalescingStrategy::LatestWins`].
+    #[zenoh_macros::unstable]
+    pub fn coalescing(mut self, strategy: CoalescingStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Publish immediately the publications changing the value of `key` in their attachment.
+    ///
+    /// Such publications bypass the rate limit.
+    /// The attachment is expected to be a [`z_serialize`](crate::z_serialize)d
+    /// `HashMap<String, String>`, and the value of `key` is compared with the one of the previous
+    /// publication. A missing attachment or key is a value of its own. The pending publication,
+    /// if any, is discarded.
+    #[zenoh_macros::unstable]
+    pub fn flush_on_change_of<K: Into<String>>(mut self, key: K) -> Self {
+        self.flush_key = Some(key.into());
+        self
+    }
+
+    fn interval(&self) -> ZResult<Duration> {
+        match (self.min_interval, self.max_rate) {
+            (Some(interval), _) if interval.is_zero() => {
+                bail!("The minimum interval of a RateLimitedPublisher must not be zero")
+            }
+            (Some(interval), _) => Ok(interval),
+            (None, Some(hz)) if hz.is_finite() && hz > 0.0 => Ok(Duration::from_secs_f64(1.0 / hz)),
+            (None, Some(hz)) => {
+                bail!("The maximum rate of a RateLimitedPublisher must be positive, got {hz}")
+            }
+            (None, None) => {
+                bail!("A RateLimitedPublisher requires either a maximum rate or a minimum interval")
+            }
+        }
+    }
+}
+
+#[zenoh_macros::unstable]
+impl Resolvable for RateLimitedPublisherBuilder<'_, '_> {
+    type To = ZResult<RateLimitedPublisher>;
+}
+
+#[zenoh_macros::unstable]
+impl Wait for RateLimitedPublisherBuilder<'_, '_> {
+    #[zenoh_macros::unstable]
+    fn wait(self) -> <Self as Resolvable>::To {
+        RateLimitedPublisher::new(self)
+    }
+}
+
+#[zenoh_macros::unstable]
+impl IntoFuture for RateLimitedPublisherBuilder<'_, '_> {
+    type Output = <Self as Resolvable>::To;
+    type IntoFuture = Ready<<Self as Resolvable>::To>;
+
+    #[zenoh_macros::unstable]
+    fn into_future(self) -> Self::IntoFuture {
+        std::future::ready(self.wait())
+    }
+}
+
+struct Publication {
+    payload: ZBytes,
+    encoding: Option<Encoding>,
+    attachment: Option<ZBytes>,
+}
+
+#[derive(Default)]
+struct State {
+    last_sent: Option<Instant>,
+    pending: Option<Publication>,
+    flush_value: Option<String>,
+    coalesced: u64,
+    dropped: u64,
+}
+
+struct Inner {
+    publisher: Publisher<'static>,
+    interval: Duration,
+    strategy: CoalescingStrategy,
+    flush_key: Option<String>,
+    state: Mutex<State>,
+    /// Held while taking a publication from the state and publishing it, so that the
+    /// publications are published in the order they are taken.
+    emission: Mutex<()>,
+    notify: Notify,
+}
+
+impl Inner {
+    fn flush_value(&self, attachment: Option<&ZBytes>) -> Option<String> {
+        let key = self.flush_key.as_ref()?;
+        let mut map = z_deserialize::<HashMap<String, String>>(attachment?).ok()?;
+        map.remove(key)
+    }
+
+    /// Records `publication` as sent and returns the builder publishing it, to be resolved
+    /// once the lock on the state is released, but before the emission lock is.
+    fn send(&self, state: &mut State, publication: Publication) -> PublisherPutBuilder<'_> {
+        state.last_sent = Some(Instant::now());
+        let mut builder = self
+            .publisher
+            .put(publication.payload)
+            .attachment(publication.attachment);
+        if let Some(encoding) = publication.encoding {
+            builder = builder.encoding(encoding);
+        }
+        builder
+    }
+
+    fn publish(&self, publication: Publication) -> ZResult<()> {
+        let _emission = zlock!(self.emission);
+        let mut state = zlock!(self.state);
+        let flush_value = self.flush_value(publication.attachment.as_ref());
+        let changed = self.flush_key.is_some() && flush_value != state.flush_value;
+        state.flush_value = flush_value;
+        let elapsed =
+            !matches!(state.last_sent, Some(last_sent) if last_sent.elapsed() < self.interval);
+        let publication = if changed {
+            if state.pending.take().is_some() {
+                state.coalesced += 1;
+            }
+            Some(publication)
+        } else {
+            match &mut state.pending {
+                None if elapsed => Some(publication),
+                None => {
+                    state.pending = Some(publication);
+                    None
+                }
+                Some(pending) => {
+                    match self.strategy {
+                        CoalescingStrategy::LatestWins => {
+                            *pending = publication;
+                            state.coalesced += 1;
+                        }
+                        CoalescingStrategy::FirstWins => state.dropped += 1,
+                    }
+                    None
+                }
+            }
+        };
+        match publication {
+            Some(publication) => {
+                let builder = self.send(&mut state, publication);
+                drop(state);
+                builder.wait()
+            }
+            None => {
+                drop(state);
+                self.notify.notify_one();
+                Ok(())
+            }
+        }
+    }
+
+    /// Publishes the pending publication, if any, as soon as the interval since the last one
+    /// has elapsed.
+    async fn flush(&self) {
+        while let Err(deadline) = self.try_flush() {
+            tokio::time::sleep_until(deadline.into()).await;
+        }
+    }
+
+    /// Publishes the pending publication, if any, unless the interval since the last one has
+    /// not elapsed yet, in which case the instant it elapses is returned.
+    fn try_flush(&self) -> Result<(), Instant> {
+        let _emission = zlock!(self.emission);
+        let mut state = zlock!(self.state);
+        let deadline = match (&state.pending, state.last_sent) {
+            (None, _) => return Ok(()),
+            (Some(_), Some(last_sent)) => last_sent + self.interval,
+            (Some(_), None) => Instant::now(),
+        };
+        if deadline > Instant::now() {
+            return Err(deadline);
+        }
+        let publication = state.pending.take().unwrap();
+        let builder = self.send(&mut state, publication);
+        drop(state);
+        if let Err(e) = builder.wait() {
+            tracing::warn!(
+                "RateLimitedPublisher{{key_expr: {}}}: Unable to publish: {}",
+                self.publisher.key_expr(),
+                e
+            );
+        }
+        Ok(())
+    }
+}
+
+/// A [`Publisher`] limiting the rate of its publications.
+///
+/// The publications made less than the configured interval after the previous one are not
+/// published immediately: they are coalesced locally according to the [`CoalescingStrategy`],
+/// and the retained one is published once the interval has elapsed. The number of coalesced
+/// and dropped publications can be retrieved with [`coalesced_count`](Self::coalesced_count)
+/// and [`dropped_count`](Self::dropped_count).
+///
+/// The pending publication, if any, is published by [`undeclare`](Self::undeclare), and lost
+/// if the publisher is dropped.
+///
+/// # Examples
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() {
+/// use zenoh_ext::PublisherBuilderExt;
+///
+/// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+/// let publisher = session
+///     .declare_publisher("key/expression")
+///     .rate_limited()
+///     .max_rate(10.0)
+///     .await
+///     .unwrap();
+/// for i in 0..1000 {
+///     publisher.put(i.to_string()).await.unwrap();
+///     tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+/// }
+/// publisher.undeclare().await.unwrap();
+/// # }
+/// ```
+#[zenoh_macros::unstable]
+pub struct RateLimitedPublisher {
+    inner: Arc<Inner>,
+    task: TerminatableTask,
+}
+
+#[zenoh_macros::unstable]
+impl fmt::Debug for RateLimitedPublisher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RateLimitedPublisher")
+            .field("publisher", &self.inner.publisher)
+            .field("interval", &self.inner.interval)
+            .field("strategy", &self.inner.strategy)
+            .field("flush_key", &self.inner.flush_key)
+            .finish()
+    }
+}
+
+#[zenoh_macros::unstable]
+impl RateLimitedPublisher {
+    fn new(conf: RateLimitedPublisherBuilder<'_, '_>) -> ZResult<Self> {
+        let interval = conf.interval()?;
+        let builder = conf.builder;
+        let key_expr = builder.key_expr?.into_owned();
+        tracing::debug!(
+            "Create RateLimitedPublisher{{key_expr: {}}} with interval {:?}",
+            &key_expr,
+            interval
+        );
+        let publisher = builder
+            .session
+            .declare_publisher(key_expr)
+            .encoding(builder.encoding)
+            .allowed_destination(builder.destination)
+            .reliability(builder.reliability)
+            .congestion_control(builder.congestion_control)
+            .priority(builder.priority)
+            .express(builder.is_express)
+            .wait()?;
+        let inner = Arc::new(Inner {
+            publisher,
+            interval,
+            strategy: conf.strategy,
+            flush_key: conf.flush_key,
+            state: Mutex::new(State::default()),
+            emission: Mutex::new(()),
+            notify: Notify::new(),
+        });
+        let task = TerminatableTask::spawn_abortable(ZRuntime::Net, {
+            let inner = inner.clone();
+            async move {
+                loop {
+                    inner.notify.notified().await;
+                    inner.flush().await;
+                }
+            }
+        });
+        Ok(RateLimitedPublisher { inner, task })
+    }
+
+    /// Returns the [`KeyExpr`] of this publisher.
+    #[zenoh_macros::unstable]
+    pub fn key_expr(&self) -> &KeyExpr<'static> {
+        self.inner.publisher.key_expr()
+    }
+
+    /// Returns the interval between two publications.
+    #[zenoh_macros::unstable]
+    pub fn interval(&self) -> Duration {
+        self.inner.interval
+    }
+
+    /// Put data, immediately or once the interval since the previous publication has elapsed.
+    #[zenoh_macros::unstable]
+    pub fn put<IntoZBytes>(&self, payload: IntoZBytes) -> RateLimitedPublicationBuilder<'_>
+    where
+        IntoZBytes: Into<ZBytes>,
+    {
+        RateLimitedPublicationBuilder {
+            inner: &self.inner,
+            publication: Publication {
+                payload: payload.into(),
+                encoding: None,
+                attachment: None,
+            },
+        }
+    }
+
+    /// Returns the number of publications replaced by a later one before being published.
+    #[zenoh_macros::unstable]
+    pub fn coalesced_count(&self) -> u64 {
+        zlock!(self.inner.state).coalesced
+    }
+
+    /// Returns the number of publications dropped because an earlier one was pending.
+    ///
+    /// Publications are only dropped with [`CoalescingStrategy::FirstWins`].
+    #[zenoh_macros::unstable]
+    pub fn dropped_count(&self) -> u64 {
+        zlock!(self.inner.state).dropped
+    }
+
+    /// Publish the pending publication, if any, and undeclare this publisher.
+    #[zenoh_macros::unstable]
+    pub fn undeclare(self) -> impl Resolve<ZResult<()>> {
+        let RateLimitedPublisher { inner, mut task } = self;
+        ResolveFuture::new(async move {
+            task.terminate_async().await;
+            let builder = {
+                let mut state = zlock!(inner.state);
+                let publication = state.pending.take();
+                publication.map(|publication| inner.send(&mut state, publication))
+            };
+            if let Some(builder) = builder {
+                builder.await?;
+            }
+            match Arc::try_unwrap(inner) {
+                Ok(inner) => inner.publisher.undeclare().await,
+                Err(_) => Ok(()),
+            }
+        })
+    }
+}
+
+/// A builder for a publication of a [`RateLimitedPublisher`].
+#[zenoh_macros::unstable]
+#[must_use = "Resolvables do nothing unless you resolve them using `.await` or `zenoh::Wait::wait`"]
+pub struct RateLimitedPublicationBuilder<'a> {
+    inner: &'a Inner,
+    publication: Publication,
+}
+
+#[zenoh_macros::unstable]
+impl fmt::Debug for RateLimitedPublicationBuilder<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RateLimitedPublicationBuilder")
+            .field("key_expr", self.inner.publisher.key_expr())
+            .field("payload", &self.publication.payload)
+            .field("encoding", &self.publication.encoding)
+            .field("attachment", &self.publication.attachment)
+            .finish()
+    }
+}
+
+#[zenoh_macros::internal_trait]
+#[zenoh_macros::unstable]
+impl EncodingBuilderTrait for RateLimitedPublicationBuilder<'_> {
+    /// Set the [`Encoding`]
+    #[zenoh_macros::unstable]
+    fn encoding<T: Into<Encoding>>(self, encoding: T) -> Self {
+        Self {
+            publication: Publication {
+                encoding: Some(encoding.into()),
+                ..self.publication
+            },
+            ..self
+        }
+    }
+}
+
+#[zenoh_macros::unstable]
+impl RateLimitedPublicationBuilder<'_> {
+    /// Sets an optional attachment to be sent along with the publication.
+    ///
+    /// The argument is converted via [`OptionZBytes`], which supports both `T: Into<ZBytes>`
+    /// and `Option<T>` where `T: Into<ZBytes>`.
+    pub fn attachment<TA: Into<OptionZBytes>>(mut self, attachment: TA) -> Self {
+        let attachment: OptionZBytes = attachment.into();
+        self.publication.attachment = attachment.into();
+        self
+    }
+}
+
+#[zenoh_macros::unstable]
+impl Resolvable for RateLimitedPublicationBuilder<'_> {
+    type To = ZResult<()>;
+}
+
+#[zenoh_macros::unstable]
+impl Wait for RateLimitedPublicationBuilder<'_> {
+    #[inline]
+    #[zenoh_macros::unstable]
+    fn wait(self) -> <Self as Resolvable>::To {
+        self.inner.publish(self.publication)
+    }
+}
+
+#[zenoh_macros::unstable]
+impl IntoFuture for RateLimitedPublicationBuilder<'_> {
+    type Output = <Self as Resolvable>::To;
+    type IntoFuture = Ready<<Self as Resolvable>::To>;
+
+    #[zenoh_macros::unstable]
+    fn into_future(self) -> Self::IntoFuture {
+        std::future::ready(self.wait())
+    }
+}
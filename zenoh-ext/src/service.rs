@@ -0,0 +1,329 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{
+    fmt,
+    future::{IntoFuture, Ready},
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+
+use zenoh::{
+    internal::ResolveFuture,
+    key_expr::KeyExpr,
+    pubsub::Subscriber,
+    query::{Query, Queryable},
+    sample::Sample,
+    Error, Resolvable, Resolve, Result as ZResult, Session, Wait,
+};
+
+use crate::{AdvancedSubscriber, AdvancedSubscriberBuilderExt, HistoryConfig};
+
+/// The state of a [`Service`], updated by its subscriber and queried through its queryable.
+///
+/// The state is copied on write: [`on_query`](ServiceHandler::on_query) is called on a snapshot
+/// of the state, taken without holding any lock, while [`on_sample`](ServiceHandler::on_sample)
+/// is called with exclusive access, cloning the state if a snapshot of it is still in use. A
+/// query hence always observes a consistent state, in which every received sample has either
+/// been fully applied or not at all, and `on_query` may publish on the key expression of the
+/// service without deadlocking.
+#[zenoh_macros::unstable]
+pub trait ServiceHandler: Clone + Send + Sync + 'static {
+    /// Applies a sample received by the service's subscriber to the state.
+    ///
+    /// This function is called while holding the lock on the state, it must not publish on the
+    /// key expression of the service.
+    fn on_sample(&mut self, sample: Sample);
+
+    /// Replies to a query received by the service's queryable, from a snapshot of the state.
+    fn on_query(&self, query: Query);
+}
+
+/// The builder of a [`Service`], allowing to configure it.
+#[zenoh_macros::unstable]
+#[must_use = "Resolvables do nothing unless you resolve them using `.await` or `zenoh::Wait::wait`"]
+pub struct ServiceBuilder<'a, 'b, 'c, Handler> {
+    session: &'a Session,
+    key_expr: ZResult<KeyExpr<'b>>,
+    queryable_key_expr: Option<ZResult<KeyExpr<'c>>>,
+    complete: bool,
+    history: Option<HistoryConfig>,
+    query_timeout: Duration,
+    handler: Handler,
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> fmt::Debug for ServiceBuilder<'_, '_, '_, Handler> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ServiceBuilder")
+            .field("session", &"..")
+            .field("key_expr", &self.key_expr)
+            .field("queryable_key_expr", &self.queryable_key_expr)
+            .field("complete", &self.complete)
+            .field("history", &self.history)
+            .field("query_timeout", &self.query_timeout)
+            .field("handler", &"..")
+            .finish()
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<'a, 'b, 'c, Handler> ServiceBuilder<'a, 'b, 'c, Handler>
+where
+    Handler: ServiceHandler,
+{
+    pub(crate) fn new(
+        session: &'a Session,
+        key_expr: ZResult<KeyExpr<'b>>,
+        handler: Handler,
+    ) -> Self {
+        ServiceBuilder {
+            session,
+            key_expr,
+            queryable_key_expr: None,
+            complete: false,
+            history: None,
+            query_timeout: Duration::from_secs(10),
+            handler,
+        }
+    }
+
+    /// Declare the queryable on the given key expression instead of the subscriber's one.
+    #[zenoh_macros::unstable]
+    pub fn queryable_key_expr<TryIntoKeyExpr>(mut self, key_expr: TryIntoKeyExpr) -> Self
+    where
+        TryIntoKeyExpr: TryInto<KeyExpr<'c>>,
+        <TryIntoKeyExpr as TryInto<KeyExpr<'c>>>::Error: Into<Error>,
+    {
+        self.queryable_key_expr = Some(key_expr.try_into().map_err(Into::into));
+        self
+    }
+
+    /// Set completeness option for the queryable.
+    #[zenoh_macros::unstable]
+    pub fn complete(mut self, complete: bool) -> Self {
+        self.complete = complete;
+        self
+    }
+
+    /// Replay the history of the subscriber's key expression at startup.
+    ///
+    /// The subscriber is an [`AdvancedSubscriber`] querying the caches of the matching
+    /// [`AdvancedPublishers`](crate::AdvancedPublisher) according to `config`, and the replies
+    /// are applied to the state, merged with the live samples. The replay is asynchronous: the
+    /// queries received before it completes observe the samples applied so far.
+    #[zenoh_macros::unstable]
+    pub fn history(mut self, config: HistoryConfig) -> Self {
+        self.history = Some(config);
+        self
+    }
+
+    /// Change the timeout of the history query.
+    #[zenoh_macros::unstable]
+    pub fn query_timeout(mut self, query_timeout: Duration) -> Self {
+        self.query_timeout = query_timeout;
+        self
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> Resolvable for ServiceBuilder<'_, '_, '_, Handler>
+where
+    Handler: ServiceHandler,
+{
+    type To = ZResult<Service<Handler>>;
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> Wait for ServiceBuilder<'_, '_, '_, Handler>
+where
+    Handler: ServiceHandler,
+{
+    #[zenoh_macros::unstable]
+    fn wait(self) -> <Self as Resolvable>::To {
+        Service::new(self)
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> IntoFuture for ServiceBuilder<'_, '_, '_, Handler>
+where
+    Handler: ServiceHandler,
+{
+    type Output = <Self as Resolvable>::To;
+    type IntoFuture = Ready<<Self as Resolvable>::To>;
+
+    #[zenoh_macros::unstable]
+    fn into_future(self) -> Self::IntoFuture {
+        std::future::ready(self.wait())
+    }
+}
+
+enum ServiceSubscriber {
+    Live(Subscriber<()>),
+    Advanced(AdvancedSubscriber<()>),
+}
+
+/// A subscriber and a queryable sharing a [`ServiceHandler`] state.
+///
+/// A service is declared with [`SessionExt::declare_service`](crate::SessionExt::declare_service).
+/// The service is undeclared when dropped.
+///
+/// # Examples
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() {
+/// use std::collections::HashMap;
+///
+/// use zenoh::{query::Query, sample::Sample, Wait};
+/// use zenoh_ext::{ServiceHandler, SessionExt};
+///
+/// #[derive(Clone, Default)]
+/// struct Cache(HashMap<String, Sample>);
+///
+/// impl ServiceHandler for Cache {
+///     fn on_sample(&mut self, sample: Sample) {
+///         self.0.insert(sample.key_expr().to_string(), sample);
+///     }
+///
+///     fn on_query(&self, query: Query) {
+///         for sample in self.0.values() {
+///             if query.key_expr().intersects(sample.key_expr()) {
+///                 query.reply_sample(sample).wait().unwrap();
+///             }
+///         }
+///     }
+/// }
+///
+/// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+/// let service = session
+///     .declare_service("key/expression/**", Cache::default())
+///     .await
+///     .unwrap();
+/// # }
+/// ```
+#[zenoh_macros::unstable]
+pub struct Service<Handler> {
+    state: Arc<RwLock<Arc<Handler>>>,
+    subscriber: ServiceSubscriber,
+    queryable: Queryable<()>,
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> fmt::Debug for Service<Handler> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Service")
+            .field("key_expr", self.key_expr())
+            .field("queryable_key_expr", self.queryable.key_expr())
+            .finish()
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> Service<Handler>
+where
+    Handler: ServiceHandler,
+{
+    fn new(conf: ServiceBuilder<'_, '_, '_, Handler>) -> ZResult<Self> {
+        let key_expr = conf.key_expr?;
+        let queryable_key_expr = match conf.queryable_key_expr {
+            Some(queryable_key_expr) => queryable_key_expr?,
+            None => key_expr.clone(),
+        };
+        tracing::debug!(
+            "Create Service on {} with queryable on {} and history={:?}",
+            &key_expr,
+            &queryable_key_expr,
+            conf.history
+        );
+        let state = Arc::new(RwLock::new(Arc::new(conf.handler)));
+
+        let on_sample = {
+            let state = state.clone();
+            move |sample| {
+                let mut state = state.write().unwrap_or_else(|e| e.into_inner());
+                Arc::make_mut(&mut state).on_sample(sample)
+            }
+        };
+        let subscriber = conf.session.declare_subscriber(&key_expr);
+        let subscriber = if let Some(history) = conf.history {
+            ServiceSubscriber::Advanced(
+                subscriber
+                    .history(history)
+                    .query_timeout(conf.query_timeout)
+                    .callback(on_sample)
+                    .wait()?,
+            )
+        } else {
+            ServiceSubscriber::Live(subscriber.callback(on_sample).wait()?)
+        };
+
+        let queryable = {
+            let state = state.clone();
+            conf.session
+                .declare_queryable(queryable_key_expr)
+                .complete(conf.complete)
+                .callback(move |query| {
+                    // Release the lock before replying, so that the samples are not blocked by
+                    // the query and the handler can publish on the service's key expression
+                    let snapshot = state.read().unwrap_or_else(|e| e.into_inner()).clone();
+                    snapshot.on_query(query)
+                })
+                .wait()?
+        };
+
+        Ok(Service {
+            state,
+            subscriber,
+            queryable,
+        })
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> Service<Handler> {
+    /// Returns a snapshot of the state of this service.
+    ///
+    /// The samples received afterwards are not applied to the returned snapshot.
+    #[zenoh_macros::unstable]
+    pub fn state(&self) -> Arc<Handler> {
+        self.state.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Returns the key expression of the subscriber of this service.
+    #[zenoh_macros::unstable]
+    pub fn key_expr(&self) -> &KeyExpr<'static> {
+        match &self.subscriber {
+            ServiceSubscriber::Live(subscriber) => subscriber.key_expr(),
+            ServiceSubscriber::Advanced(subscriber) => subscriber.key_expr(),
+        }
+    }
+
+    /// Returns the key expression of the queryable of this service.
+    #[zenoh_macros::unstable]
+    pub fn queryable_key_expr(&self) -> &KeyExpr<'static> {
+        self.queryable.key_expr()
+    }
+
+    /// Undeclare this [`Service`].
+    #[zenoh_macros::unstable]
+    pub fn undeclare(self) -> impl Resolve<ZResult<()>> {
+        ResolveFuture::new(async move {
+            self.queryable.undeclare().await?;
+            match self.subscriber {
+                ServiceSubscriber::Live(subscriber) => subscriber.undeclare().await,
+                ServiceSubscriber::Advanced(subscriber) => subscriber.undeclare().await,
+            }
+        })
+    }
+}
@@ -16,6 +16,7 @@ use zenoh::{key_expr::KeyExpr, session::Session, Error};
 
 #[allow(deprecated)]
 use super::PublicationCacheBuilder;
+use super::{ServiceBuilder, ServiceHandler};
 
 /// Some extensions to the [`zenoh::Session`](zenoh::Session)
 #[zenoh_macros::unstable]
@@ -50,6 +51,21 @@ pub trait SessionExt {
     where
         TryIntoKeyExpr: TryInto<KeyExpr<'b>>,
         <TryIntoKeyExpr as TryInto<KeyExpr<'b>>>::Error: Into<Error>;
+
+    /// Declare a [`Service`](crate::Service) sharing the state of the given [`ServiceHandler`].
+    ///
+    /// The service is made of a subscriber and a queryable on `key_expr`.
+    /// See [`Service`](crate::Service) for an example.
+    #[zenoh_macros::unstable]
+    fn declare_service<'a, 'b, 'c, TryIntoKeyExpr, Handler>(
+        &'a self,
+        key_expr: TryIntoKeyExpr,
+        handler: Handler,
+    ) -> ServiceBuilder<'a, 'b, 'c, Handler>
+    where
+        TryIntoKeyExpr: TryInto<KeyExpr<'b>>,
+        <TryIntoKeyExpr as TryInto<KeyExpr<'b>>>::Error: Into<Error>,
+        Handler: ServiceHandler;
 }
 
 #[allow(deprecated)]
@@ -65,4 +81,18 @@ impl SessionExt for Session {
     {
         PublicationCacheBuilder::new(self, pub_key_expr.try_into().map_err(Into::into))
     }
+
+    #[zenoh_macros::unstable]
+    fn declare_service<'a, 'b, 'c, TryIntoKeyExpr, Handler>(
+        &'a self,
+        key_expr: TryIntoKeyExpr,
+        handler: Handler,
+    ) -> ServiceBuilder<'a, 'b, 'c, Handler>
+    where
+        TryIntoKeyExpr: TryInto<KeyExpr<'b>>,
+        <TryIntoKeyExpr as TryInto<KeyExpr<'b>>>::Error: Into<Error>,
+        Handler: ServiceHandler,
+    {
+        ServiceBuilder::new(self, key_expr.try_into().map_err(Into::into), handler)
+    }
 }
@@ -357,7 +357,7 @@ impl<'a, 'b, Handler> SubscriberBuilderExt<'a, 'b, Handler>
             session: self.session,
             key_expr: self.key_expr,
             key_space: crate::LivelinessSpace,
-            origin: Locality::default(),
+            origin: Locality::Any,
             fetch,
             handler: self.handler,
             phantom: std::marker::PhantomData,
@@ -399,7 +399,7 @@ impl<'a, 'b, Handler> SubscriberBuilderExt<'a, 'b, Handler>
             session: self.session,
             key_expr: self.key_expr,
             key_space: crate::LivelinessSpace,
-            origin: Locality::default(),
+            origin: Locality::Any,
             query_selector: None,
             query_target: QueryTarget::DEFAULT,
             query_consolidation: QueryConsolidation::DEFAULT,
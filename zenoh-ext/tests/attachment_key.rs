@@ -0,0 +1,112 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#![cfg(feature = "unstable")]
+use std::{collections::HashMap, time::Duration};
+
+use zenoh::{internal::ztimeout, Wait};
+use zenoh_ext::{z_deserialize, z_serialize};
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_sample_has_attachment_key() {
+    zenoh_util::init_log_from_env_or("error");
+    let session = ztimeout!(zenoh::open(zenoh::Config::default())).unwrap();
+    let subscriber = ztimeout!(session.declare_subscriber("test/attachment_key/**")).unwrap();
+
+    let attachment = z_serialize(&HashMap::from([
+        ("trace".to_string(), "1".to_string()),
+        ("source".to_string(), "arm".to_string()),
+    ]));
+    session
+        .put("test/attachment_key/a", "a")
+        .attachment(attachment)
+        .wait()
+        .unwrap();
+    let sample = ztimeout!(subscriber.recv_async()).unwrap();
+    assert!(sample.has_attachment_key("trace"));
+    assert!(sample.has_attachment_key("source"));
+    assert!(sample.has_attachment_key(b"trace"));
+    assert!(!sample.has_attachment_key("arm"));
+    assert!(!sample.has_attachment_key("missing"));
+
+    session.put("test/attachment_key/b", "b").wait().unwrap();
+    let sample = ztimeout!(subscriber.recv_async()).unwrap();
+    assert!(!sample.has_attachment_key("trace"));
+
+    session
+        .put("test/attachment_key/c", "c")
+        .attachment("not a map")
+        .wait()
+        .unwrap();
+    let sample = ztimeout!(subscriber.recv_async()).unwrap();
+    assert!(!sample.has_attachment_key("trace"));
+
+    let mut truncated = z_serialize(&HashMap::from([("trace".to_string(), "1".to_string())]))
+        .to_bytes()
+        .into_owned();
+    truncated.pop();
+    session
+        .put("test/attachment_key/d", "d")
+        .attachment(truncated)
+        .wait()
+        .unwrap();
+    let sample = ztimeout!(subscriber.recv_async()).unwrap();
+    assert!(!sample.has_attachment_key("trace"));
+
+    ztimeout!(session.close()).unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_put_to_attachment_map() {
+    zenoh_util::init_log_from_env_or("error");
+    let session = ztimeout!(zenoh::open(zenoh::Config::default())).unwrap();
+    let subscriber = ztimeout!(session.declare_subscriber("test/put_to_map/**")).unwrap();
+
+    session
+        .put_to("test/put_to_map/a?unit=celsius", "a")
+        .wait()
+        .unwrap();
+    let sample = ztimeout!(subscriber.recv_async()).unwrap();
+    assert!(sample.has_attachment_key("__params"));
+    let map: HashMap<String, String> = z_deserialize(sample.attachment().unwrap()).unwrap();
+    assert_eq!(map["__params"], "unit=celsius");
+
+    // The parameters are merged into a user key-value attachment.
+    let attachment = z_serialize(&HashMap::from([("trace".to_string(), "1".to_string())]));
+    session
+        .put_to("test/put_to_map/b?unit=celsius", "b")
+        .attachment(attachment)
+        .wait()
+        .unwrap();
+    let sample = ztimeout!(subscriber.recv_async()).unwrap();
+    assert!(sample.has_attachment_key("trace"));
+    assert_eq!(sample.parameters().unwrap().get("unit"), Some("celsius"));
+    let map: HashMap<String, String> = z_deserialize(sample.attachment().unwrap()).unwrap();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map["trace"], "1");
+
+    // A user map already carrying parameters is rejected.
+    let attachment = z_serialize(&HashMap::from([(
+        "__params".to_string(),
+        "unit=kelvin".to_string(),
+    )]));
+    assert!(session
+        .put_to("test/put_to_map/c?unit=celsius", "c")
+        .attachment(attachment)
+        .wait()
+        .is_err());
+
+    ztimeout!(session.close()).unwrap();
+}
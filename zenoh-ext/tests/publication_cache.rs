@@ -0,0 +1,78 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#![cfg(feature = "unstable")]
+#![allow(deprecated)]
+use std::time::Duration;
+
+use zenoh::{bytes::Encoding, internal::ztimeout, Session};
+use zenoh_ext::SessionExt;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+const SLEEP: Duration = Duration::from_secs(1);
+
+async fn get_keys(session: &Session, selector: &str) -> Vec<String> {
+    let replies = ztimeout!(session.get(selector)).unwrap();
+    let mut keys = Vec::new();
+    while let Ok(reply) = ztimeout!(replies.recv_async()) {
+        keys.push(reply.into_result().unwrap().key_expr().to_string());
+    }
+    keys.sort();
+    keys
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_publication_cache_encoding_filter() {
+    zenoh_util::init_log_from_env_or("error");
+    let mut config = zenoh::Config::default();
+    config.insert_json5("timestamping/enabled", "true").unwrap();
+    let session = ztimeout!(zenoh::open(config)).unwrap();
+    let _cache = ztimeout!(session.declare_publication_cache("test/cache/**")).unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    ztimeout!(session
+        .put("test/cache/json", r#"{"a": 1}"#)
+        .encoding(Encoding::APPLICATION_JSON))
+    .unwrap();
+    ztimeout!(session
+        .put("test/cache/bytes", "1")
+        .encoding(Encoding::APPLICATION_OCTET_STREAM))
+    .unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    assert_eq!(
+        get_keys(&session, "test/cache/**").await,
+        ["test/cache/bytes", "test/cache/json"]
+    );
+    assert_eq!(
+        get_keys(&session, "test/cache/**?_encoding=application/json").await,
+        ["test/cache/json"]
+    );
+    assert_eq!(
+        get_keys(&session, "test/cache/**?_encoding=application/*").await,
+        ["test/cache/bytes", "test/cache/json"]
+    );
+    assert!(get_keys(
+        &session,
+        "test/cache/json?_encoding=application/octet-stream"
+    )
+    .await
+    .is_empty());
+    // an empty filter doesn't filter anything
+    assert_eq!(
+        get_keys(&session, "test/cache/bytes?_encoding").await,
+        ["test/cache/bytes"]
+    );
+
+    ztimeout!(session.close()).unwrap();
+}
@@ -0,0 +1,241 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#![cfg(feature = "unstable")]
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use zenoh::{
+    handlers::FifoChannelHandler, internal::ztimeout, pubsub::Subscriber, sample::Sample, Session,
+    Wait,
+};
+use zenoh_ext::{z_serialize, CoalescingStrategy, PublisherBuilderExt};
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+const PUT_PERIOD: Duration = Duration::from_millis(1);
+// Long enough for the pending publications to only be published when undeclaring
+const LONG_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Declares a subscriber on `subscriber_session` and waits until `publisher_session` sees it.
+async fn subscribe(
+    publisher_session: &Session,
+    subscriber_session: &Session,
+    key_expr: &'static str,
+) -> Subscriber<FifoChannelHandler<Sample>> {
+    let probe = ztimeout!(publisher_session.declare_publisher(key_expr)).unwrap();
+    let listener = ztimeout!(probe.matching_listener()).unwrap();
+    let subscriber = ztimeout!(subscriber_session.declare_subscriber(key_expr)).unwrap();
+    if !ztimeout!(probe.matching_status()).unwrap().matching() {
+        while !ztimeout!(listener.recv_async()).unwrap().matching() {}
+    }
+    ztimeout!(listener.undeclare()).unwrap();
+    ztimeout!(probe.undeclare()).unwrap();
+    subscriber
+}
+
+async fn recv(subscriber: &Subscriber<FifoChannelHandler<Sample>>) -> u32 {
+    let sample = ztimeout!(subscriber.recv_async()).unwrap();
+    sample.payload().try_to_string().unwrap().parse().unwrap()
+}
+
+fn flag(value: &str) -> zenoh::bytes::ZBytes {
+    z_serialize(&HashMap::from([("state".to_string(), value.to_string())]))
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_rate_limited_publisher_latest_wins() {
+    zenoh_util::init_log_from_env_or("error");
+    let mut test_sessions = zenoh_test::TestSessions::new();
+    let (peer1, peer2) = test_sessions.open_pairs().await;
+    let subscriber = subscribe(&peer1, &peer2, "test/rate_limited/latest").await;
+
+    let publisher = ztimeout!(peer1
+        .declare_publisher("test/rate_limited/latest")
+        .rate_limited()
+        .max_rate(10.0))
+    .unwrap();
+    assert_eq!(publisher.interval(), Duration::from_millis(100));
+
+    // Publish at up to 1 kHz for 1 second
+    let start = Instant::now();
+    let mut count = 0;
+    while start.elapsed() < Duration::from_secs(1) {
+        publisher.put(count.to_string()).wait().unwrap();
+        count += 1;
+        tokio::time::sleep(PUT_PERIOD).await;
+    }
+    let coalesced = publisher.coalesced_count();
+    assert_eq!(publisher.dropped_count(), 0);
+    // The pending publication, if any, is published when undeclaring
+    ztimeout!(publisher.undeclare()).unwrap();
+    let elapsed = start.elapsed();
+
+    let mut received = vec![recv(&subscriber).await];
+    while received.last() != Some(&(count - 1)) {
+        received.push(recv(&subscriber).await);
+    }
+    // The first put is published immediately and every coalesced one is accounted for
+    assert_eq!(received.first(), Some(&0));
+    assert!(received.windows(2).all(|w| w[0] < w[1]), "{received:?}");
+    assert_eq!(coalesced, u64::from(count) - received.len() as u64);
+    // At most one publication per interval, plus the first one and the one flushed on undeclare
+    let max = elapsed.as_secs_f64() * 10.0 + 2.0;
+    assert!(
+        received.len() as f64 <= max,
+        "received {} samples in {elapsed:?}",
+        received.len()
+    );
+
+    test_sessions.close().await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_rate_limited_publisher_first_wins() {
+    zenoh_util::init_log_from_env_or("error");
+    let mut test_sessions = zenoh_test::TestSessions::new();
+    let (peer1, peer2) = test_sessions.open_pairs().await;
+    let subscriber = subscribe(&peer1, &peer2, "test/rate_limited/first").await;
+
+    let publisher = ztimeout!(peer1
+        .declare_publisher("test/rate_limited/first")
+        .rate_limited()
+        .min_interval(LONG_INTERVAL)
+        .coalescing(CoalescingStrategy::FirstWins))
+    .unwrap();
+
+    for i in 0..10u32 {
+        publisher.put(i.to_string()).wait().unwrap();
+    }
+    // The first put is published immediately, the second one is kept pending
+    assert_eq!(recv(&subscriber).await, 0);
+    assert_eq!(publisher.dropped_count(), 8);
+    assert_eq!(publisher.coalesced_count(), 0);
+
+    ztimeout!(publisher.undeclare()).unwrap();
+    assert_eq!(recv(&subscriber).await, 1);
+    assert!(subscriber.try_recv().unwrap().is_none());
+
+    test_sessions.close().await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_rate_limited_publisher_flush_on_change() {
+    zenoh_util::init_log_from_env_or("error");
+    let mut test_sessions = zenoh_test::TestSessions::new();
+    let (peer1, peer2) = test_sessions.open_pairs().await;
+    let subscriber = subscribe(&peer1, &peer2, "test/rate_limited/flush").await;
+
+    let publisher = ztimeout!(peer1
+        .declare_publisher("test/rate_limited/flush")
+        .rate_limited()
+        .min_interval(LONG_INTERVAL)
+        .flush_on_change_of("state"))
+    .unwrap();
+
+    publisher.put("0").attachment(flag("idle")).wait().unwrap();
+    publisher.put("1").attachment(flag("idle")).wait().unwrap();
+    publisher.put("2").attachment(flag("alarm")).wait().unwrap();
+    publisher.put("3").attachment(flag("alarm")).wait().unwrap();
+
+    // The transition is published at once, discarding the pending publication
+    assert_eq!(recv(&subscriber).await, 0);
+    assert_eq!(recv(&subscriber).await, 2);
+    assert_eq!(publisher.coalesced_count(), 1);
+
+    // The pending publication is published when undeclaring
+    ztimeout!(publisher.undeclare()).unwrap();
+    assert_eq!(recv(&subscriber).await, 3);
+    assert!(subscriber.try_recv().unwrap().is_none());
+
+    test_sessions.close().await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_rate_limited_publisher_flush_race() {
+    zenoh_util::init_log_from_env_or("error");
+    let mut test_sessions = zenoh_test::TestSessions::new();
+    let (peer1, peer2) = test_sessions.open_pairs().await;
+    let subscriber = subscribe(&peer1, &peer2, "test/rate_limited/race").await;
+
+    let interval = Duration::from_millis(1);
+    let publisher = ztimeout!(peer1
+        .declare_publisher("test/rate_limited/race")
+        .rate_limited()
+        .min_interval(interval)
+        .flush_on_change_of("state"))
+    .unwrap();
+
+    // Each round leaves a publication pending, and publishes a transition at about the time
+    // the pending one is flushed.
+    let rounds = 200u32;
+    for i in 0..rounds {
+        let state = if i % 2 == 0 { "idle" } else { "alarm" };
+        publisher
+            .put((3 * i).to_string())
+            .attachment(flag(state))
+            .wait()
+            .unwrap();
+        publisher
+            .put((3 * i + 1).to_string())
+            .attachment(flag(state))
+            .wait()
+            .unwrap();
+        tokio::time::sleep(interval).await;
+        let state = if i % 2 == 0 { "alarm" } else { "idle" };
+        publisher
+            .put((3 * i + 2).to_string())
+            .attachment(flag(state))
+            .wait()
+            .unwrap();
+    }
+    let last = 3 * rounds - 1;
+
+    // A flushed publication never lands after a later transition
+    let mut received = vec![recv(&subscriber).await];
+    while received.last() != Some(&last) {
+        received.push(recv(&subscriber).await);
+    }
+    assert!(received.windows(2).all(|w| w[0] < w[1]), "{received:?}");
+    ztimeout!(publisher.undeclare()).unwrap();
+    assert!(subscriber.try_recv().unwrap().is_none());
+
+    test_sessions.close().await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_rate_limited_publisher_invalid_config() {
+    zenoh_util::init_log_from_env_or("error");
+    let session = ztimeout!(zenoh::open(zenoh::Config::default())).unwrap();
+
+    let key_expr = "test/rate_limited/invalid";
+    assert!(ztimeout!(session.declare_publisher(key_expr).rate_limited()).is_err());
+    for hz in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+        assert!(
+            ztimeout!(session
+                .declare_publisher(key_expr)
+                .rate_limited()
+                .max_rate(hz))
+            .is_err(),
+            "{hz}"
+        );
+    }
+    assert!(ztimeout!(session
+        .declare_publisher(key_expr)
+        .rate_limited()
+        .min_interval(Duration::ZERO))
+    .is_err());
+
+    ztimeout!(session.close()).unwrap();
+}
@@ -0,0 +1,243 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#![cfg(feature = "unstable")]
+use std::{collections::HashMap, time::Duration};
+
+use zenoh::{
+    internal::ztimeout,
+    key_expr::OwnedKeyExpr,
+    query::Query,
+    sample::{Sample, SampleKind},
+    Session, Wait,
+};
+use zenoh_ext::{
+    AdvancedPublisherBuilderExt, CacheConfig, HistoryConfig, ServiceHandler, SessionExt,
+};
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+const SLEEP: Duration = Duration::from_secs(1);
+
+/// A key-value cache storing the latest sample published on each key.
+#[derive(Clone, Default)]
+struct KvCache(HashMap<OwnedKeyExpr, Sample>);
+
+impl ServiceHandler for KvCache {
+    fn on_sample(&mut self, sample: Sample) {
+        match sample.kind() {
+            SampleKind::Put => {
+                self.0.insert(sample.key_expr().clone().into(), sample);
+            }
+            SampleKind::Delete => {
+                self.0.remove(sample.key_expr().as_keyexpr());
+            }
+        }
+    }
+
+    fn on_query(&self, query: Query) {
+        for (key_expr, sample) in &self.0 {
+            if query.key_expr().intersects(key_expr) {
+                query.reply_sample(sample).wait().unwrap();
+            }
+        }
+    }
+}
+
+/// A counter of the received samples, publishing on its own key expression when queried.
+#[derive(Clone)]
+struct Echo {
+    session: Session,
+    count: usize,
+}
+
+impl ServiceHandler for Echo {
+    fn on_sample(&mut self, _sample: Sample) {
+        self.count += 1;
+    }
+
+    fn on_query(&self, query: Query) {
+        self.session
+            .put("test/service/echo/queried", "")
+            .wait()
+            .unwrap();
+        query
+            .reply(query.key_expr().clone(), self.count.to_string())
+            .wait()
+            .unwrap();
+    }
+}
+
+/// Returns the sorted `(key, payload)` pairs replied to a query on `selector`.
+async fn get(session: &Session, selector: &str) -> Vec<(String, String)> {
+    let replies = ztimeout!(session.get(selector)).unwrap();
+    let mut results = Vec::new();
+    while let Ok(reply) = ztimeout!(replies.recv_async()) {
+        let sample = reply.result().unwrap();
+        results.push((
+            sample.key_expr().to_string(),
+            sample.payload().try_to_string().unwrap().into_owned(),
+        ));
+    }
+    results.sort();
+    results
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_service_kv_cache() {
+    zenoh_util::init_log_from_env_or("error");
+    let mut test_sessions = zenoh_test::TestSessions::new();
+    let (peer1, peer2) = test_sessions.open_pairs().await;
+
+    let service =
+        ztimeout!(peer1.declare_service("test/service/kv/**", KvCache::default())).unwrap();
+    assert_eq!(service.key_expr().as_str(), "test/service/kv/**");
+    assert_eq!(service.queryable_key_expr().as_str(), "test/service/kv/**");
+    tokio::time::sleep(SLEEP).await;
+
+    ztimeout!(peer2.put("test/service/kv/a", "1")).unwrap();
+    ztimeout!(peer2.put("test/service/kv/b", "2")).unwrap();
+    ztimeout!(peer2.put("test/service/kv/a", "3")).unwrap();
+    ztimeout!(peer2.put("test/service/other", "4")).unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    assert_eq!(service.state().0.len(), 2);
+    assert_eq!(
+        get(&peer2, "test/service/kv/**").await,
+        [
+            ("test/service/kv/a".to_string(), "3".to_string()),
+            ("test/service/kv/b".to_string(), "2".to_string()),
+        ]
+    );
+    assert_eq!(
+        get(&peer2, "test/service/kv/b").await,
+        [("test/service/kv/b".to_string(), "2".to_string())]
+    );
+
+    ztimeout!(peer2.delete("test/service/kv/a")).unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    assert_eq!(
+        get(&peer2, "test/service/kv/**").await,
+        [("test/service/kv/b".to_string(), "2".to_string())]
+    );
+
+    ztimeout!(service.undeclare()).unwrap();
+    tokio::time::sleep(SLEEP).await;
+    assert!(get(&peer2, "test/service/kv/**").await.is_empty());
+
+    test_sessions.close().await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_service_queryable_key_expr() {
+    zenoh_util::init_log_from_env_or("error");
+    let mut test_sessions = zenoh_test::TestSessions::new();
+    let (peer1, peer2) = test_sessions.open_pairs().await;
+
+    let service = ztimeout!(peer1
+        .declare_service("test/service/qke/**", KvCache::default())
+        .queryable_key_expr("test/service/qke/a"))
+    .unwrap();
+    assert_eq!(service.key_expr().as_str(), "test/service/qke/**");
+    assert_eq!(service.queryable_key_expr().as_str(), "test/service/qke/a");
+    tokio::time::sleep(SLEEP).await;
+
+    ztimeout!(peer2.put("test/service/qke/a", "1")).unwrap();
+    ztimeout!(peer2.put("test/service/qke/b", "2")).unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    assert_eq!(service.state().0.len(), 2);
+    assert_eq!(
+        get(&peer2, "test/service/qke/a").await,
+        [("test/service/qke/a".to_string(), "1".to_string())]
+    );
+    assert!(get(&peer2, "test/service/qke/b").await.is_empty());
+
+    test_sessions.close().await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_service_history() {
+    zenoh_util::init_log_from_env_or("error");
+    let mut test_sessions = zenoh_test::TestSessions::new();
+    let peer1 = {
+        let mut config = test_sessions.get_listener_config("tcp/127.0.0.1:0", 1);
+        config.insert_json5("timestamping/enabled", "true").unwrap();
+        test_sessions.open_listener_with_cfg(config).await
+    };
+    let peer2 = test_sessions.open_connector().await;
+
+    // The caches of the advanced publishers hold the history replayed by the service.
+    let publ_a = ztimeout!(peer1
+        .declare_publisher("test/service/history/a")
+        .cache(CacheConfig::default().max_samples(1)))
+    .unwrap();
+    let publ_b = ztimeout!(peer1
+        .declare_publisher("test/service/history/b")
+        .cache(CacheConfig::default().max_samples(1)))
+    .unwrap();
+    ztimeout!(publ_a.put("1")).unwrap();
+    ztimeout!(publ_b.put("2")).unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    let service = ztimeout!(peer2
+        .declare_service("test/service/history/**", KvCache::default())
+        .history(HistoryConfig::default()))
+    .unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    ztimeout!(peer1.put("test/service/history/c", "3")).unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    let mut keys: Vec<String> = service
+        .state()
+        .0
+        .keys()
+        .map(|key_expr| key_expr.to_string())
+        .collect();
+    keys.sort();
+    assert_eq!(
+        keys,
+        [
+            "test/service/history/a",
+            "test/service/history/b",
+            "test/service/history/c"
+        ]
+    );
+
+    test_sessions.close().await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_service_reentrant_query() {
+    zenoh_util::init_log_from_env_or("error");
+    let session = ztimeout!(zenoh::open(zenoh::Config::default())).unwrap();
+
+    let handler = Echo {
+        session: session.clone(),
+        count: 0,
+    };
+    let service = ztimeout!(session.declare_service("test/service/echo/**", handler)).unwrap();
+
+    // Each query is replied to from the state it observed, while its own sample is applied
+    for count in 0..3 {
+        assert_eq!(
+            get(&session, "test/service/echo/count").await,
+            [("test/service/echo/count".to_string(), count.to_string())]
+        );
+        assert_eq!(service.state().count, count + 1);
+    }
+
+    ztimeout!(service.undeclare()).unwrap();
+    ztimeout!(session.close()).unwrap();
+}
@@ -0,0 +1,120 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#![cfg(feature = "unstable")]
+use std::time::Duration;
+
+use zenoh::{
+    internal::ztimeout,
+    query::Queryable,
+    sample::{Sample, SampleKind},
+    Session, Wait,
+};
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+const SLEEP: Duration = Duration::from_secs(1);
+
+const STORAGE_KEYEXPR: &str = "test/tombstone/**";
+const LIVE_KEYEXPR: &str = "test/tombstone/live";
+const DELETED_KEYEXPR: &str = "test/tombstone/deleted";
+
+// A storage holding one live value and the tombstone of a deleted one
+async fn declare_storage(session: &Session) -> Queryable<()> {
+    let put_ts = session.new_timestamp();
+    let del_ts = session.new_timestamp();
+    let queryable = ztimeout!(session
+        .declare_queryable(STORAGE_KEYEXPR)
+        .callback(move |query| {
+            query
+                .reply(LIVE_KEYEXPR, "value")
+                .timestamp(put_ts)
+                .wait()
+                .unwrap();
+            query
+                .reply_del(DELETED_KEYEXPR)
+                .timestamp(del_ts)
+                .attachment("tombstone")
+                .wait()
+                .unwrap();
+        }))
+    .unwrap();
+    tokio::time::sleep(SLEEP).await;
+    queryable
+}
+
+fn sorted(mut samples: Vec<Sample>) -> Vec<Sample> {
+    samples.sort_by(|a, b| a.key_expr().as_str().cmp(b.key_expr().as_str()));
+    samples
+}
+
+fn check_tombstone(samples: &[Sample]) {
+    assert_eq!(samples.len(), 2);
+    let deleted = &samples[0];
+    assert_eq!(deleted.key_expr().as_str(), DELETED_KEYEXPR);
+    assert_eq!(deleted.kind(), SampleKind::Delete);
+    assert!(deleted.payload().is_empty());
+    assert!(deleted.timestamp().is_some());
+    assert_eq!(
+        deleted.attachment().unwrap().try_to_string().unwrap(),
+        "tombstone"
+    );
+    let live = &samples[1];
+    assert_eq!(live.key_expr().as_str(), LIVE_KEYEXPR);
+    assert_eq!(live.kind(), SampleKind::Put);
+    assert_eq!(live.payload().try_to_string().unwrap(), "value");
+    assert!(live.timestamp().unwrap() < deleted.timestamp().unwrap());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_get_reply_tombstone() {
+    zenoh_util::init_log_from_env_or("error");
+    let mut test_sessions = zenoh_test::TestSessions::new();
+    let (peer1, peer2) = test_sessions.open_pairs().await;
+    let _storage = declare_storage(&peer1).await;
+
+    let replies = ztimeout!(peer2.get(STORAGE_KEYEXPR)).unwrap();
+    let mut samples = Vec::new();
+    while let Ok(reply) = ztimeout!(replies.recv_async()) {
+        samples.push(reply.into_result().unwrap());
+    }
+    check_tombstone(&sorted(samples));
+
+    test_sessions.close().await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+#[allow(deprecated)]
+async fn test_querying_subscriber_replays_tombstone() {
+    use zenoh_ext::SubscriberBuilderExt;
+
+    zenoh_util::init_log_from_env_or("error");
+    let mut test_sessions = zenoh_test::TestSessions::new();
+    let (peer1, peer2) = test_sessions.open_pairs().await;
+    let _storage = declare_storage(&peer1).await;
+
+    let subscriber = ztimeout!(peer2.declare_subscriber(STORAGE_KEYEXPR).querying()).unwrap();
+
+    let samples = vec![
+        ztimeout!(subscriber.recv_async()).unwrap(),
+        ztimeout!(subscriber.recv_async()).unwrap(),
+    ];
+    check_tombstone(&sorted(samples));
+
+    // Live deletions keep going through after the history replay
+    ztimeout!(peer1.delete(LIVE_KEYEXPR)).unwrap();
+    let sample = ztimeout!(subscriber.recv_async()).unwrap();
+    assert_eq!(sample.key_expr().as_str(), LIVE_KEYEXPR);
+    assert_eq!(sample.kind(), SampleKind::Delete);
+
+    test_sessions.close().await;
+}
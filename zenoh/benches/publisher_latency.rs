@@ -0,0 +1,99 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#[macro_use]
+extern crate criterion;
+
+use std::time::Duration;
+
+use criterion::{Criterion, Throughput};
+use zenoh::{config::WhatAmI, Config, Session, Wait};
+
+const KEY_EXPR: &str = "bench/publisher_latency";
+const PAYLOAD_SIZE: usize = 64;
+
+fn peer_config() -> Config {
+    let mut config = Config::default();
+    config.set_mode(Some(WhatAmI::Peer)).unwrap();
+    config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    config
+}
+
+/// Opens a publishing peer connected over TCP to a subscribing peer, so that
+/// every put goes through the transport.
+fn open_sessions() -> (Session, Session) {
+    let mut config = peer_config();
+    config
+        .insert_json5("listen/endpoints", r#"["tcp/127.0.0.1:0"]"#)
+        .unwrap();
+    let subscriber_session = zenoh::open(config).wait().unwrap();
+    let locator = subscriber_session
+        .info()
+        .locators()
+        .wait()
+        .into_iter()
+        .find(|locator| locator.protocol().as_str() == "tcp")
+        .unwrap();
+
+    let mut config = peer_config();
+    config
+        .insert_json5("connect/endpoints", &format!(r#"["{locator}"]"#))
+        .unwrap();
+    let publisher_session = zenoh::open(config).wait().unwrap();
+    (publisher_session, subscriber_session)
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let (publisher_session, subscriber_session) = open_sessions();
+    let _subscriber = subscriber_session
+        .declare_subscriber(KEY_EXPR)
+        .callback(|_| {})
+        .wait()
+        .unwrap();
+    std::thread::sleep(Duration::from_secs(1));
+
+    let payload = vec![0u8; PAYLOAD_SIZE];
+    let mut group = c.benchmark_group("publisher put");
+    group.throughput(Throughput::Elements(1));
+    for enabled in [false, true] {
+        let publisher = publisher_session
+            .declare_publisher(KEY_EXPR)
+            .wait()
+            .unwrap()
+            .with_latency_tracking(enabled);
+        let name = if enabled {
+            "latency tracking enabled"
+        } else {
+            "latency tracking disabled"
+        };
+        group.bench_function(name, |b| {
+            b.iter(|| publisher.put(payload.as_slice()).wait().unwrap())
+        });
+        if let Some(snapshot) = publisher.latency_snapshot() {
+            println!(
+                "put latency: p50 {:?}, p90 {:?}, p99 {:?}, max {:?} over {} puts",
+                snapshot.p50(),
+                snapshot.p90(),
+                snapshot.p99(),
+                snapshot.max(),
+                snapshot.count()
+            );
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,94 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#[macro_use]
+extern crate criterion;
+
+use std::time::Duration;
+
+use criterion::{Criterion, Throughput};
+use zenoh::{config::WhatAmI, handlers::FifoChannelHandler, query::Reply, Config, Session, Wait};
+
+const KEY_EXPR: &str = "bench/querier_get";
+const QUERIES: u64 = 10_000;
+
+fn peer_config() -> Config {
+    let mut config = Config::default();
+    config.set_mode(Some(WhatAmI::Peer)).unwrap();
+    config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    config
+}
+
+/// Opens a querying peer connected over TCP to a replying peer, so that every
+/// query and reply goes through the transport.
+fn open_sessions() -> (Session, Session) {
+    let mut config = peer_config();
+    config
+        .insert_json5("listen/endpoints", r#"["tcp/127.0.0.1:0"]"#)
+        .unwrap();
+    let replier_session = zenoh::open(config).wait().unwrap();
+    let locator = replier_session
+        .info()
+        .locators()
+        .wait()
+        .into_iter()
+        .find(|locator| locator.protocol().as_str() == "tcp")
+        .unwrap();
+
+    let mut config = peer_config();
+    config
+        .insert_json5("connect/endpoints", &format!(r#"["{locator}"]"#))
+        .unwrap();
+    let querier_session = zenoh::open(config).wait().unwrap();
+    (querier_session, replier_session)
+}
+
+/// Waits for the replies of a query, until the final one closes the channel.
+fn drain(replies: FifoChannelHandler<Reply>) {
+    while replies.recv().is_ok() {}
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let (querier_session, replier_session) = open_sessions();
+    let _queryable = replier_session
+        .declare_queryable(KEY_EXPR)
+        .callback(|query| query.reply(KEY_EXPR, "reply").wait().unwrap())
+        .wait()
+        .unwrap();
+    std::thread::sleep(Duration::from_secs(1));
+
+    let mut group = c.benchmark_group(format!("{QUERIES} gets"));
+    group.throughput(Throughput::Elements(QUERIES));
+    group.sample_size(10);
+    group.bench_function("session get", |b| {
+        b.iter(|| {
+            for _ in 0..QUERIES {
+                drain(querier_session.get(KEY_EXPR).wait().unwrap());
+            }
+        })
+    });
+    let querier = querier_session.declare_querier(KEY_EXPR).wait().unwrap();
+    group.bench_function("querier get", |b| {
+        b.iter(|| {
+            for _ in 0..QUERIES {
+                drain(querier.get().wait().unwrap());
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
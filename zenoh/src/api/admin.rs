@@ -23,6 +23,8 @@ use zenoh_link::Locator;
 use zenoh_macros::ke;
 use zenoh_protocol::core::{CongestionControl, Reliability};
 
+#[cfg(feature = "unstable")]
+use crate::api::publisher::DEFAULT_BLOCK_TIMEOUT;
 use crate::{
     self as zenoh,
     api::{
@@ -76,6 +78,8 @@ struct TransportJson {
     is_qos: bool,
     #[cfg(feature = "shared-memory")]
     is_shm: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cert_common_name: Option<String>,
 }
 
 impl From<Transport> for TransportJson {
@@ -86,6 +90,7 @@ impl From<Transport> for TransportJson {
             is_qos: transport.is_qos,
             #[cfg(feature = "shared-memory")]
             is_shm: transport.is_shm,
+            cert_common_name: transport.cert_common_name,
         }
     }
 }
@@ -222,6 +227,8 @@ pub(crate) fn init(session: WeakSession) {
                 Locality::SessionLocal,
                 #[cfg(feature = "unstable")]
                 Reliability::default(),
+                #[cfg(feature = "unstable")]
+                DEFAULT_BLOCK_TIMEOUT,
                 None,
                 #[cfg(feature = "unstable")]
                 None,
@@ -280,6 +287,8 @@ pub(crate) fn init(session: WeakSession) {
                     Locality::SessionLocal,
                     #[cfg(feature = "unstable")]
                     Reliability::default(),
+                    #[cfg(feature = "unstable")]
+                    DEFAULT_BLOCK_TIMEOUT,
                     None,
                     #[cfg(feature = "unstable")]
                     None,
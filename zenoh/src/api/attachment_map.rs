@@ -0,0 +1,83 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+//! Key-value attachments in the format of a map serialized with `zenoh_ext::z_serialize`.
+//!
+//! A map is encoded as its number of entries followed by each key and value. The number of
+//! entries and the byte lengths of the keys and values are unsigned LEB128 integers. Decoding is
+//! strict: a truncated map or trailing bytes make the whole attachment invalid.
+
+use crate::api::bytes::ZBytes;
+
+/// The entries of a key-value attachment, in their encoded order.
+pub(crate) type AttachmentMap = Vec<(Vec<u8>, Vec<u8>)>;
+
+/// Decodes `attachment` as a key-value map, or returns `None` if it is not a valid map.
+pub(crate) fn decode(attachment: &ZBytes) -> Option<AttachmentMap> {
+    let bytes = attachment.to_bytes();
+    let mut reader: &[u8] = &bytes;
+    let len = read_varint(&mut reader)?;
+    // Each entry takes at least two bytes, this bounds the allocation on garbage input.
+    let mut map = Vec::with_capacity(len.min(reader.len() / 2));
+    for _ in 0..len {
+        let key = read_slice(&mut reader)?;
+        let value = read_slice(&mut reader)?;
+        map.push((key.to_vec(), value.to_vec()));
+    }
+    reader.is_empty().then_some(map)
+}
+
+/// Encodes `map` as a key-value attachment.
+pub(crate) fn encode(map: &[(Vec<u8>, Vec<u8>)]) -> ZBytes {
+    let mut bytes = Vec::new();
+    write_varint(&mut bytes, map.len());
+    for (key, value) in map {
+        write_varint(&mut bytes, key.len());
+        bytes.extend_from_slice(key);
+        write_varint(&mut bytes, value.len());
+        bytes.extend_from_slice(value);
+    }
+    bytes.into()
+}
+
+fn read_varint(reader: &mut &[u8]) -> Option<usize> {
+    let mut value: u64 = 0;
+    for shift in (0..u64::BITS).step_by(7) {
+        let (&byte, rest) = reader.split_first()?;
+        *reader = rest;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return usize::try_from(value).ok();
+        }
+    }
+    None
+}
+
+fn read_slice<'a>(reader: &mut &'a [u8]) -> Option<&'a [u8]> {
+    let len = read_varint(reader)?;
+    if len > reader.len() {
+        return None;
+    }
+    let (slice, rest) = reader.split_at(len);
+    *reader = rest;
+    Some(slice)
+}
+
+fn write_varint(bytes: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        bytes.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
@@ -60,14 +60,15 @@ impl<TCloseable: Closeable> CloseBuilder<TCloseable> {
         }
     }
 
-    #[cfg(all(feature = "unstable", feature = "internal"))]
+    #[zenoh_macros::unstable]
     /// Set the timeout for the close operation
     ///
+    /// The close operation fails if it does not complete within the timeout.
+    ///
     /// # Arguments
     ///
     /// * `timeout` - The timeout value for the close operation (10s by default)
     ///
-    #[doc(hidden)]
     pub fn timeout(mut self, timeout: Duration) -> Self {
         self.timeout = timeout;
         self
@@ -89,33 +90,39 @@ impl<TCloseable: Closeable> Resolvable for CloseBuilder<TCloseable> {
 
 impl<TCloseable: Closeable> Wait for CloseBuilder<TCloseable> {
     fn wait(self) -> Self::To {
-        let future = self.into_future();
-        match tokio::runtime::Handle::try_current() {
-            Ok(_) => {
-                tracing::trace!("tokio TLS available, closing closeable directly");
-                ZRuntime::Net.block_in_place(future)
-            }
-            Err(e) if e.is_missing_context() => {
-                tracing::trace!("tokio TLS is just missing, closing closeable directly");
-                ZRuntime::Net.block_in_place(future)
-            }
-            Err(_) => {
-                #[cfg(nolocal_thread_not_available)]
-                panic!("Close when thread-local storage is unavailable (typically in atexit()) does not work for this Rust 1.85..1.85.1, see https://github.com/rust-lang/rust/issues/138696");
+        wait_close(self.into_future())
+    }
+}
 
-                #[cfg(not(nolocal_thread_not_available))]
-                {
-                    let evaluate = move || {
-                        // NOTE: tracing logger also panics if used inside atexit() handler!!!
-                        tracing::trace!(
-                            "tokio TLS NOT available, closing closeable in separate thread"
-                        );
-                        ZRuntime::Net.block_in_place(future)
-                    };
-                    std::thread::spawn(evaluate)
-                        .join()
-                        .expect("Error spawning atexit-safe thread")
-                }
+// Close operations must also be resolvable when thread-local storage is unavailable, e.g. in atexit().
+fn wait_close<TOutput: Send + 'static>(
+    future: Pin<Box<dyn Future<Output = TOutput> + Send>>,
+) -> TOutput {
+    match tokio::runtime::Handle::try_current() {
+        Ok(_) => {
+            tracing::trace!("tokio TLS available, closing closeable directly");
+            ZRuntime::Net.block_in_place(future)
+        }
+        Err(e) if e.is_missing_context() => {
+            tracing::trace!("tokio TLS is just missing, closing closeable directly");
+            ZRuntime::Net.block_in_place(future)
+        }
+        Err(_) => {
+            #[cfg(nolocal_thread_not_available)]
+            panic!("Close when thread-local storage is unavailable (typically in atexit()) does not work for this Rust 1.85..1.85.1, see https://github.com/rust-lang/rust/issues/138696");
+
+            #[cfg(not(nolocal_thread_not_available))]
+            {
+                let evaluate = move || {
+                    // NOTE: tracing logger also panics if used inside atexit() handler!!!
+                    tracing::trace!(
+                        "tokio TLS NOT available, closing closeable in separate thread"
+                    );
+                    ZRuntime::Net.block_in_place(future)
+                };
+                std::thread::spawn(evaluate)
+                    .join()
+                    .expect("Error spawning atexit-safe thread")
             }
         }
     }
@@ -132,7 +139,7 @@ impl<TCloseable: Closeable> IntoFuture for CloseBuilder<TCloseable> {
                     .await
                     .is_err()
                 {
-                    bail!("close operation timed out!")
+                    bail!(kind = Timeout, "close operation timed out!")
                 }
                 Ok(())
             }
@@ -265,4 +272,163 @@ impl CloseBuilder<crate::Session> {
         self.close_args.wait_callbacks = true;
         self
     }
+
+    #[zenoh_macros::unstable]
+    /// Undeclare all the entities declared through the session before closing it.
+    ///
+    /// The undeclarations of the publishers, queriers, subscribers and queryables of the session
+    /// are sent to the network, before the transmission pipelines are flushed and the links are
+    /// closed. Without this option, the session entities are not undeclared on the network, and
+    /// the remote nodes only forget them when the session transports are closed.
+    ///
+    /// Liveliness tokens are not concerned, as they are always undeclared when their session is closed.
+    pub fn undeclare_all(mut self) -> Self {
+        self.close_args.undeclare_all = true;
+        self
+    }
+
+    #[zenoh_macros::unstable]
+    /// Resolve the close operation into a [`SessionCloseSummary`].
+    ///
+    /// Instead of failing when the [`timeout`](CloseBuilder::timeout) expires, the close operation
+    /// then aborts the flush of the transmission pipelines, dropping their pending messages, and
+    /// force-closes the remaining links. The summary reports it, together with the number of
+    /// batches of messages flushed and dropped while closing the links.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use std::time::Duration;
+    ///
+    /// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+    /// let subscriber = session.declare_subscriber("key/expression").await.unwrap();
+    /// let summary = session
+    ///     .close()
+    ///     .undeclare_all()
+    ///     .timeout(Duration::from_secs(1))
+    ///     .with_summary()
+    ///     .await
+    ///     .unwrap();
+    /// assert_eq!(summary.undeclared_entities(), 1);
+    /// assert_eq!(summary.dropped_batches(), 0);
+    /// assert!(!summary.timed_out());
+    /// # }
+    /// ```
+    pub fn with_summary(self) -> SessionCloseSummaryBuilder {
+        SessionCloseSummaryBuilder { inner: self }
+    }
+}
+
+/// The outcome of a session close operation.
+///
+/// It is returned by [`CloseBuilder::with_summary`](CloseBuilder::with_summary).
+#[zenoh_macros::unstable]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SessionCloseSummary {
+    undeclared_entities: usize,
+    flushed_batches: usize,
+    dropped_batches: usize,
+    timed_out: bool,
+}
+
+#[zenoh_macros::unstable]
+impl SessionCloseSummary {
+    /// The number of entities undeclared by [`CloseBuilder::undeclare_all`](CloseBuilder::undeclare_all).
+    pub fn undeclared_entities(&self) -> usize {
+        self.undeclared_entities
+    }
+
+    /// The number of batches of messages written on the links while flushing their transmission
+    /// pipelines.
+    pub fn flushed_batches(&self) -> usize {
+        self.flushed_batches
+    }
+
+    /// The number of batches of messages dropped from the transmission pipelines of the links,
+    /// because their flush failed or was aborted at the timeout.
+    pub fn dropped_batches(&self) -> usize {
+        self.dropped_batches
+    }
+
+    /// Whether the close operation did not complete within the timeout.
+    ///
+    /// In that case, the flush of the transmission pipelines was aborted and the remaining links
+    /// were force-closed.
+    pub fn timed_out(&self) -> bool {
+        self.timed_out
+    }
+}
+
+/// A builder for session close operations returning a [`SessionCloseSummary`].
+///
+/// It is returned by the [`CloseBuilder::with_summary`](CloseBuilder::with_summary) method.
+#[zenoh_macros::unstable]
+#[must_use = "Resolvables do nothing unless you resolve them using `.await` or `zenoh::Wait::wait`"]
+#[derive(Debug)]
+pub struct SessionCloseSummaryBuilder {
+    inner: CloseBuilder<crate::Session>,
+}
+
+#[zenoh_macros::unstable]
+impl Resolvable for SessionCloseSummaryBuilder {
+    type To = ZResult<SessionCloseSummary>;
+}
+
+#[zenoh_macros::unstable]
+impl Wait for SessionCloseSummaryBuilder {
+    fn wait(self) -> Self::To {
+        wait_close(self.into_future())
+    }
+}
+
+#[zenoh_macros::unstable]
+impl IntoFuture for SessionCloseSummaryBuilder {
+    type Output = <Self as Resolvable>::To;
+    type IntoFuture = Pin<Box<dyn Future<Output = <Self as IntoFuture>::Output> + Send>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let CloseBuilder {
+            closee,
+            timeout,
+            mut close_args,
+        } = self.inner;
+        Box::pin(async move {
+            let deadline = tokio::time::Instant::now() + timeout;
+            let (flushed, dropped) = closee.flush_counts();
+            // Undeclare the entities here rather than in `close_inner` to count them even if
+            // the rest of the close operation times out.
+            let undeclared_entities = if std::mem::take(&mut close_args.undeclare_all) {
+                closee.undeclare_all_inner()
+            } else {
+                0
+            };
+            // Spawn the close operation so that it is not interrupted at the timeout.
+            let c_closee = closee.clone();
+            let mut close =
+                ZRuntime::Net.spawn(async move { c_closee.close_inner(close_args).await });
+            let timed_out = match tokio::time::timeout_at(deadline, &mut close).await {
+                Ok(result) => {
+                    result.map_err(|e| zerror!("close operation failed: {e}"))?;
+                    false
+                }
+                Err(_) => {
+                    // Nothing is left to wait for once the flushes are aborted: the links are
+                    // closed right away.
+                    closee.abort_flush();
+                    close
+                        .await
+                        .map_err(|e| zerror!("close operation failed: {e}"))?;
+                    true
+                }
+            };
+            let (c_flushed, c_dropped) = closee.flush_counts();
+            Ok(SessionCloseSummary {
+                undeclared_entities,
+                flushed_batches: c_flushed - flushed,
+                dropped_batches: c_dropped - dropped,
+                timed_out,
+            })
+        })
+    }
 }
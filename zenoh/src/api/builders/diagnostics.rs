@@ -0,0 +1,76 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{
+    future::{Future, IntoFuture},
+    pin::Pin,
+    time::Duration,
+};
+
+use zenoh_core::{Resolvable, Wait};
+use zenoh_result::ZResult;
+use zenoh_runtime::ZRuntime;
+
+use crate::api::{
+    diagnostics::{_ping, PingReport, PingTarget},
+    session::Session,
+};
+
+/// A builder for a ping, returned by [`ping`](crate::diagnostics::ping).
+#[zenoh_macros::unstable]
+#[must_use = "Resolvables do nothing unless you resolve them using `.await` or `zenoh::Wait::wait`"]
+#[derive(Debug)]
+pub struct PingBuilder<'a> {
+    pub(crate) session: &'a Session,
+    pub(crate) target: PingTarget,
+    pub(crate) payload_size: usize,
+    pub(crate) count: usize,
+    pub(crate) timeout: Duration,
+}
+
+#[zenoh_macros::unstable]
+impl PingBuilder<'_> {
+    /// Sets the time after which an unanswered probe is considered lost (1 second by default).
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+#[zenoh_macros::unstable]
+impl Resolvable for PingBuilder<'_> {
+    type To = ZResult<PingReport>;
+}
+
+#[zenoh_macros::unstable]
+impl Wait for PingBuilder<'_> {
+    fn wait(self) -> <Self as Resolvable>::To {
+        ZRuntime::Application.block_in_place(self.into_future())
+    }
+}
+
+#[zenoh_macros::unstable]
+impl IntoFuture for PingBuilder<'_> {
+    type Output = <Self as Resolvable>::To;
+    type IntoFuture = Pin<Box<dyn Future<Output = <Self as IntoFuture>::Output> + Send>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(_ping(
+            self.session.clone(),
+            self.target.key_expr(),
+            self.payload_size,
+            self.count,
+            self.timeout,
+        ))
+    }
+}
@@ -1,12 +1,25 @@
+#[cfg(feature = "unstable")]
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+};
 use std::{
     future::{IntoFuture, Ready},
     time::Duration,
 };
 
+#[cfg(feature = "unstable")]
+use zenoh_core::zlock;
 use zenoh_core::{Resolvable, Result as ZResult, Wait};
+#[cfg(feature = "unstable")]
+use zenoh_keyexpr::OwnedKeyExpr;
 
 #[cfg(feature = "unstable")]
-use crate::api::cancellation::CancellationTokenBuilderTrait;
+use crate::api::{
+    cancellation::CancellationTokenBuilderTrait,
+    liveliness::{LivelinessGroupChange, LivelinessGroupView},
+    sample::SampleKind,
+};
 use crate::api::{
     handlers::{locked, Callback, DefaultHandler, IntoHandler},
     key_expr::KeyExpr,
@@ -273,6 +286,8 @@ where
                 },
                 handler,
                 callback_sync_group,
+                #[cfg(feature = "unstable")]
+                suppressed_duplicates: None,
             })
     }
 }
@@ -536,3 +551,140 @@ where
         std::future::ready(self.wait())
     }
 }
+
+/// A builder for initializing a [`LivelinessGroupView`].
+///
+/// The builder is returned by the [`Liveliness::declare_group_view`](crate::liveliness::Liveliness::declare_group_view) method and
+/// resolves to the [`LivelinessGroupView`] receiving the [`LivelinessGroupChange`]s.
+///
+/// # Examples
+/// ```
+/// # #[tokio::main]
+/// # async fn main() {
+///
+/// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+/// let view = session
+///     .liveliness()
+///     .declare_group_view("key/expression/*")
+///     .await
+///     .unwrap();
+/// # }
+/// ```
+#[zenoh_macros::unstable]
+#[must_use = "Resolvables do nothing unless you resolve them using `.await` or `zenoh::Wait::wait`"]
+#[derive(Debug)]
+pub struct LivelinessGroupViewBuilder<'a, 'b, Handler> {
+    pub(crate) session: &'a Session,
+    pub(crate) key_expr: ZResult<KeyExpr<'b>>,
+    pub(crate) handler: Handler,
+}
+
+#[zenoh_macros::unstable]
+impl<'a, 'b> LivelinessGroupViewBuilder<'a, 'b, DefaultHandler> {
+    /// Receive the changes of this group view with a callback.
+    #[inline]
+    pub fn callback<F>(
+        self,
+        callback: F,
+    ) -> LivelinessGroupViewBuilder<'a, 'b, Callback<LivelinessGroupChange>>
+    where
+        F: Fn(LivelinessGroupChange) + Send + Sync + 'static,
+    {
+        self.with(Callback::from(callback))
+    }
+
+    /// Receive the changes of this group view with a mutable callback.
+    ///
+    /// Using this guarantees that your callback will never be called concurrently.
+    /// If your callback is also accepted by the [`callback`](LivelinessGroupViewBuilder::callback) method, we suggest you use it instead of `callback_mut`.
+    #[inline]
+    pub fn callback_mut<F>(
+        self,
+        callback: F,
+    ) -> LivelinessGroupViewBuilder<'a, 'b, Callback<LivelinessGroupChange>>
+    where
+        F: FnMut(LivelinessGroupChange) + Send + Sync + 'static,
+    {
+        self.callback(locked(callback))
+    }
+
+    /// Receive the changes of this group view with a [`Handler`](crate::handlers::IntoHandler).
+    #[inline]
+    pub fn with<Handler>(self, handler: Handler) -> LivelinessGroupViewBuilder<'a, 'b, Handler>
+    where
+        Handler: IntoHandler<LivelinessGroupChange>,
+    {
+        let LivelinessGroupViewBuilder {
+            session,
+            key_expr,
+            handler: _,
+        } = self;
+        LivelinessGroupViewBuilder {
+            session,
+            key_expr,
+            handler,
+        }
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> Resolvable for LivelinessGroupViewBuilder<'_, '_, Handler>
+where
+    Handler: IntoHandler<LivelinessGroupChange> + Send,
+    Handler::Handler: Send,
+{
+    type To = ZResult<LivelinessGroupView<Handler::Handler>>;
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> Wait for LivelinessGroupViewBuilder<'_, '_, Handler>
+where
+    Handler: IntoHandler<LivelinessGroupChange> + Send,
+    Handler::Handler: Send,
+{
+    fn wait(self) -> <Self as Resolvable>::To {
+        let (callback, handler) = self.handler.into_handler();
+        let alive = Arc::new(Mutex::new(HashSet::new()));
+        let state = alive.clone();
+        let subscriber = LivelinessSubscriberBuilder::<_, false> {
+            session: self.session,
+            key_expr: self.key_expr,
+            handler: Callback::from(move |sample: Sample| {
+                let key_expr = sample.key_expr().clone();
+                let mut alive = zlock!(state);
+                let changed = match sample.kind() {
+                    SampleKind::Put => alive.insert(OwnedKeyExpr::from(key_expr.clone())),
+                    SampleKind::Delete => alive.remove(key_expr.as_keyexpr()),
+                };
+                if changed {
+                    callback.call(LivelinessGroupChange {
+                        key_expr,
+                        kind: sample.kind(),
+                        alive: alive.clone(),
+                    });
+                }
+            }),
+            history: true,
+        }
+        .wait()?;
+        Ok(LivelinessGroupView {
+            subscriber,
+            alive,
+            handler,
+        })
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> IntoFuture for LivelinessGroupViewBuilder<'_, '_, Handler>
+where
+    Handler: IntoHandler<LivelinessGroupChange> + Send,
+    Handler::Handler: Send,
+{
+    type Output = <Self as Resolvable>::To;
+    type IntoFuture = Ready<<Self as Resolvable>::To>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        std::future::ready(self.wait())
+    }
+}
@@ -12,14 +12,21 @@
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
 use std::future::{IntoFuture, Ready};
+#[cfg(feature = "unstable")]
+use std::time::Duration;
 
 use zenoh_core::{Resolvable, Result as ZResult, Wait};
+#[cfg(feature = "unstable")]
+use zenoh_keyexpr::keyexpr;
 use zenoh_protocol::core::CongestionControl;
 #[cfg(feature = "unstable")]
-use zenoh_protocol::core::Reliability;
+use zenoh_protocol::core::{Parameters, Reliability};
+use zenoh_result::ErrKind;
 
 #[cfg(feature = "unstable")]
-use crate::api::sample::SourceInfo;
+use crate::api::sample::{QoS, SourceInfo};
+#[cfg(feature = "unstable")]
+use crate::api::selector::parameters_into_attachment;
 #[cfg(feature = "unstable")]
 use crate::api::timestamp_stack::TimestampInstrumentation;
 use crate::{
@@ -57,6 +64,27 @@ pub type PublisherPutBuilder<'a> = PublicationBuilder<&'a Publisher<'a>, Publica
 pub type PublisherDeleteBuilder<'a> =
     PublicationBuilder<&'a Publisher<'a>, PublicationBuilderDelete>;
 
+/// The alias for [`PublicationBuilder`] returned by [`Publisher::put_suffixed`](crate::pubsub::Publisher::put_suffixed).
+#[zenoh_macros::unstable]
+pub type PublisherSuffixedPutBuilder<'a> =
+    PublicationBuilder<PublisherSuffix<'a>, PublicationBuilderPut>;
+
+/// The alias for [`PublicationBuilder`] returned by [`Publisher::delete_suffixed`](crate::pubsub::Publisher::delete_suffixed).
+#[zenoh_macros::unstable]
+pub type PublisherSuffixedDeleteBuilder<'a> =
+    PublicationBuilder<PublisherSuffix<'a>, PublicationBuilderDelete>;
+
+/// A [`Publisher`] publishing to one of its sub-keys.
+///
+/// The publisher of the builders returned by [`Publisher::put_suffixed`] and
+/// [`Publisher::delete_suffixed`].
+#[zenoh_macros::unstable]
+#[derive(Debug, Clone, Copy)]
+pub struct PublisherSuffix<'a> {
+    pub(crate) publisher: &'a Publisher<'a>,
+    pub(crate) suffix: &'a keyexpr,
+}
+
 /// The type-modifier for a [`PublicationBuilder`] for a `Put` operation.
 ///
 /// Makes the publication builder make a sample of a [`kind`](crate::sample::Sample::kind) [`SampleKind::Put`].
@@ -64,6 +92,8 @@ pub type PublisherDeleteBuilder<'a> =
 pub struct PublicationBuilderPut {
     pub(crate) payload: ZBytes,
     pub(crate) encoding: Encoding,
+    #[cfg(feature = "unstable")]
+    pub(crate) parameters: Option<Parameters<'static>>,
 }
 
 /// The type-modifier for a [`PublicationBuilder`] for a `Delete` operation.
@@ -79,6 +109,8 @@ pub struct PublicationBuilderDelete;
 /// - [`Session::delete`](crate::session::Session::delete)
 /// - [`Publisher::put`](crate::pubsub::Publisher::put)
 /// - [`Publisher::delete`](crate::pubsub::Publisher::delete)
+/// - [`Publisher::put_suffixed`](crate::pubsub::Publisher::put_suffixed)
+/// - [`Publisher::delete_suffixed`](crate::pubsub::Publisher::delete_suffixed)
 ///
 /// It resolves to `ZResult<()>` when awaited or when calling `.wait()`.
 ///
@@ -156,9 +188,7 @@ impl<T> PublicationBuilder<PublisherBuilder<'_, '_>, T> {
 
     /// Changes the [`Reliability`](crate::qos::Reliability) to apply when routing the data.
     ///
-    /// **NOTE**: Currently `reliability` does not trigger any data retransmission on the wire. It
-    ///   is rather used as a marker on the wire and it may be used to select the best link
-    ///   available (e.g. TCP for reliable data and UDP for best effort data).
+    /// See [`PublisherBuilder::reliability`].
     #[zenoh_macros::unstable]
     #[inline]
     pub fn reliability(self, reliability: Reliability) -> Self {
@@ -167,6 +197,32 @@ impl<T> PublicationBuilder<PublisherBuilder<'_, '_>, T> {
             ..self
         }
     }
+
+    /// Changes the timeout applied with [`CongestionControl::BlockWithTimeout`](crate::qos::CongestionControl::BlockWithTimeout).
+    ///
+    /// See [`PublisherBuilder::block_timeout`].
+    #[zenoh_macros::unstable]
+    #[inline]
+    pub fn block_timeout(self, timeout: Duration) -> Self {
+        Self {
+            publisher: self.publisher.block_timeout(timeout),
+            ..self
+        }
+    }
+
+    /// Changes the priority, congestion control and express policy at once from a [`QoS`] preset.
+    ///
+    /// The reliability is changed as well if the [`QoS`] sets one.
+    ///
+    /// See [`PublisherBuilder::qos`].
+    #[zenoh_macros::unstable]
+    #[inline]
+    pub fn qos(self, qos: QoS) -> Self {
+        Self {
+            publisher: self.publisher.qos(qos),
+            ..self
+        }
+    }
 }
 
 #[zenoh_macros::internal_trait]
@@ -248,6 +304,13 @@ impl Wait for PublicationBuilder<PublisherBuilder<'_, '_>, PublicationBuilderPut
     #[inline]
     fn wait(mut self) -> <Self as Resolvable>::To {
         self.publisher = self.publisher.apply_qos_overwrites();
+        #[cfg(feature = "unstable")]
+        if let Some(parameters) = &self.kind.parameters {
+            self.attachment = Some(parameters_into_attachment(
+                parameters,
+                self.attachment.as_ref(),
+            )?);
+        }
         self.publisher.session.resolve_put(
             &self.publisher.key_expr?,
             self.kind.payload,
@@ -259,6 +322,8 @@ impl Wait for PublicationBuilder<PublisherBuilder<'_, '_>, PublicationBuilderPut
             self.publisher.destination,
             #[cfg(feature = "unstable")]
             self.publisher.reliability,
+            #[cfg(feature = "unstable")]
+            self.publisher.block_timeout,
             self.timestamp,
             #[cfg(feature = "unstable")]
             self.source_info,
@@ -284,6 +349,8 @@ impl Wait for PublicationBuilder<PublisherBuilder<'_, '_>, PublicationBuilderDel
             self.publisher.destination,
             #[cfg(feature = "unstable")]
             self.publisher.reliability,
+            #[cfg(feature = "unstable")]
+            self.publisher.block_timeout,
             self.timestamp,
             #[cfg(feature = "unstable")]
             self.source_info,
@@ -366,6 +433,12 @@ pub struct PublisherBuilder<'a, 'b> {
     #[cfg(feature = "unstable")]
     pub(crate) reliability: Reliability,
     #[cfg(feature = "internal")]
+    #[cfg(feature = "unstable")]
+    pub block_timeout: Duration,
+    #[cfg(not(feature = "internal"))]
+    #[cfg(feature = "unstable")]
+    pub(crate) block_timeout: Duration,
+    #[cfg(feature = "internal")]
     pub destination: Locality,
     #[cfg(not(feature = "internal"))]
     pub(crate) destination: Locality,
@@ -377,7 +450,7 @@ impl Clone for PublisherBuilder<'_, '_> {
             session: self.session,
             key_expr: match &self.key_expr {
                 Ok(k) => Ok(k.clone()),
-                Err(e) => Err(zerror!("Cloned KE Error: {}", e).into()),
+                Err(e) => Err(zerror!("Cloned KE Error: {}", e).with_kind(e.kind()).into()),
             },
             encoding: self.encoding.clone(),
             congestion_control: self.congestion_control,
@@ -385,6 +458,8 @@ impl Clone for PublisherBuilder<'_, '_> {
             is_express: self.is_express,
             #[cfg(feature = "unstable")]
             reliability: self.reliability,
+            #[cfg(feature = "unstable")]
+            block_timeout: self.block_timeout,
             destination: self.destination,
         }
     }
@@ -463,6 +538,21 @@ impl PublisherBuilder<'_, '_> {
     /// **NOTE**: Currently `reliability` does not trigger any data retransmission on the wire. It
     ///   is rather used as a marker on the wire and it may be used to select the best link
     ///   available (e.g. TCP for reliable data and UDP for best effort data).
+    ///
+    /// The reliability is kept by the routers forwarding the data and is reported to the
+    /// subscribers by [`Sample::reliability`](crate::sample::Sample::reliability). Subscribers do
+    /// not declare any reliability: the one chosen by the publisher applies along the whole route.
+    ///
+    /// The reliability also affects how the [`CongestionControl`] is applied when the transmission
+    /// queues toward a destination are full:
+    ///
+    /// | Reliability  | `Drop`  | `Block` (and its variants) |
+    /// |--------------|---------|----------------------------|
+    /// | `Reliable`   | dropped | waits for the queues       |
+    /// | `BestEffort` | dropped | dropped                    |
+    ///
+    /// In other words, best effort data is always droppable: a blocking congestion control only
+    /// applies to reliable data.
     #[zenoh_macros::unstable]
     #[inline]
     pub fn reliability(self, reliability: Reliability) -> Self {
@@ -471,6 +561,51 @@ impl PublisherBuilder<'_, '_> {
             ..self
         }
     }
+
+    /// Changes the timeout applied with [`CongestionControl::BlockWithTimeout`](crate::qos::CongestionControl::BlockWithTimeout).
+    ///
+    /// A publication waits at most `timeout` for space in the transmission queues. When the
+    /// timeout expires, the message is dropped and the publication fails with a
+    /// [`BlockTimeoutError`](crate::qos::BlockTimeoutError). Defaults to 1 second.
+    /// The timeout is ignored with other congestion control strategies.
+    #[zenoh_macros::unstable]
+    #[inline]
+    pub fn block_timeout(self, timeout: Duration) -> Self {
+        Self {
+            block_timeout: timeout,
+            ..self
+        }
+    }
+
+    /// Changes the priority, congestion control and express policy at once from a [`QoS`] preset.
+    ///
+    /// The reliability is changed as well if the [`QoS`] sets one.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use zenoh::qos::QoS;
+    ///
+    /// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+    /// let publisher = session
+    ///     .declare_publisher("key/expression")
+    ///     .qos(QoS::realtime())
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    #[zenoh_macros::unstable]
+    #[inline]
+    pub fn qos(self, qos: QoS) -> Self {
+        Self {
+            congestion_control: qos.congestion_control(),
+            priority: qos.priority(),
+            is_express: qos.express(),
+            reliability: qos.reliability().unwrap_or(self.reliability),
+            ..self
+        }
+    }
 }
 
 impl<'b> Resolvable for PublisherBuilder<'_, 'b> {
@@ -496,6 +631,12 @@ impl Wait for PublisherBuilder<'_, '_> {
             destination: self.destination,
             #[cfg(feature = "unstable")]
             reliability: self.reliability,
+            #[cfg(feature = "unstable")]
+            block_timeout: self.block_timeout,
+            #[cfg(feature = "unstable")]
+            latency: None,
+            #[cfg(feature = "unstable")]
+            suffix_base: Default::default(),
             matching_listeners: Default::default(),
             undeclare_on_drop: true,
             sync_group: SyncGroup::default(),
@@ -514,47 +655,55 @@ impl IntoFuture for PublisherBuilder<'_, '_> {
 
 impl Wait for PublicationBuilder<&Publisher<'_>, PublicationBuilderPut> {
     fn wait(self) -> <Self as Resolvable>::To {
-        self.publisher.session.resolve_put(
-            &self.publisher.key_expr,
-            self.kind.payload,
-            SampleKind::Put,
-            self.kind.encoding,
-            self.publisher.congestion_control,
-            self.publisher.priority,
-            self.publisher.is_express,
-            self.publisher.destination,
-            #[cfg(feature = "unstable")]
-            self.publisher.reliability,
-            self.timestamp,
-            #[cfg(feature = "unstable")]
-            self.source_info,
-            self.attachment,
-            #[cfg(feature = "unstable")]
-            self.timestamp_instrumentation,
-        )
+        self.publisher.track_enqueue_latency(|| {
+            self.publisher.session.resolve_put(
+                &self.publisher.key_expr,
+                self.kind.payload,
+                SampleKind::Put,
+                self.kind.encoding,
+                self.publisher.congestion_control,
+                self.publisher.priority,
+                self.publisher.is_express,
+                self.publisher.destination,
+                #[cfg(feature = "unstable")]
+                self.publisher.reliability,
+                #[cfg(feature = "unstable")]
+                self.publisher.block_timeout,
+                self.timestamp,
+                #[cfg(feature = "unstable")]
+                self.source_info,
+                self.attachment,
+                #[cfg(feature = "unstable")]
+                self.timestamp_instrumentation,
+            )
+        })
     }
 }
 
 impl Wait for PublicationBuilder<&Publisher<'_>, PublicationBuilderDelete> {
     fn wait(self) -> <Self as Resolvable>::To {
-        self.publisher.session.resolve_put(
-            &self.publisher.key_expr,
-            ZBytes::new(),
-            SampleKind::Delete,
-            Encoding::ZENOH_BYTES,
-            self.publisher.congestion_control,
-            self.publisher.priority,
-            self.publisher.is_express,
-            self.publisher.destination,
-            #[cfg(feature = "unstable")]
-            self.publisher.reliability,
-            self.timestamp,
-            #[cfg(feature = "unstable")]
-            self.source_info,
-            self.attachment,
-            #[cfg(feature = "unstable")]
-            self.timestamp_instrumentation,
-        )
+        self.publisher.track_enqueue_latency(|| {
+            self.publisher.session.resolve_put(
+                &self.publisher.key_expr,
+                ZBytes::new(),
+                SampleKind::Delete,
+                Encoding::ZENOH_BYTES,
+                self.publisher.congestion_control,
+                self.publisher.priority,
+                self.publisher.is_express,
+                self.publisher.destination,
+                #[cfg(feature = "unstable")]
+                self.publisher.reliability,
+                #[cfg(feature = "unstable")]
+                self.publisher.block_timeout,
+                self.timestamp,
+                #[cfg(feature = "unstable")]
+                self.source_info,
+                self.attachment,
+                #[cfg(feature = "unstable")]
+                self.timestamp_instrumentation,
+            )
+        })
     }
 }
 
@@ -575,3 +724,75 @@ impl IntoFuture for PublicationBuilder<&Publisher<'_>, PublicationBuilderDelete>
         std::future::ready(self.wait())
     }
 }
+
+#[cfg(feature = "unstable")]
+impl Wait for PublicationBuilder<PublisherSuffix<'_>, PublicationBuilderPut> {
+    fn wait(self) -> <Self as Resolvable>::To {
+        let publisher = self.publisher.publisher;
+        let key_expr = publisher.suffixed_key_expr(self.publisher.suffix)?;
+        publisher.track_enqueue_latency(|| {
+            publisher.session.resolve_put(
+                &key_expr,
+                self.kind.payload,
+                SampleKind::Put,
+                self.kind.encoding,
+                publisher.congestion_control,
+                publisher.priority,
+                publisher.is_express,
+                publisher.destination,
+                publisher.reliability,
+                publisher.block_timeout,
+                self.timestamp,
+                self.source_info,
+                self.attachment,
+                self.timestamp_instrumentation,
+            )
+        })
+    }
+}
+
+#[cfg(feature = "unstable")]
+impl Wait for PublicationBuilder<PublisherSuffix<'_>, PublicationBuilderDelete> {
+    fn wait(self) -> <Self as Resolvable>::To {
+        let publisher = self.publisher.publisher;
+        let key_expr = publisher.suffixed_key_expr(self.publisher.suffix)?;
+        publisher.track_enqueue_latency(|| {
+            publisher.session.resolve_put(
+                &key_expr,
+                ZBytes::new(),
+                SampleKind::Delete,
+                Encoding::ZENOH_BYTES,
+                publisher.congestion_control,
+                publisher.priority,
+                publisher.is_express,
+                publisher.destination,
+                publisher.reliability,
+                publisher.block_timeout,
+                self.timestamp,
+                self.source_info,
+                self.attachment,
+                self.timestamp_instrumentation,
+            )
+        })
+    }
+}
+
+#[cfg(feature = "unstable")]
+impl IntoFuture for PublicationBuilder<PublisherSuffix<'_>, PublicationBuilderPut> {
+    type Output = <Self as Resolvable>::To;
+    type IntoFuture = Ready<<Self as Resolvable>::To>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        std::future::ready(self.wait())
+    }
+}
+
+#[cfg(feature = "unstable")]
+impl IntoFuture for PublicationBuilder<PublisherSuffix<'_>, PublicationBuilderDelete> {
+    type Output = <Self as Resolvable>::To;
+    type IntoFuture = Ready<<Self as Resolvable>::To>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        std::future::ready(self.wait())
+    }
+}
@@ -12,10 +12,14 @@
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
 use std::future::{IntoFuture, Ready};
+#[cfg(feature = "unstable")]
+use std::sync::Arc;
 
 use zenoh_core::{Resolvable, Wait};
 use zenoh_result::ZResult;
 
+#[cfg(feature = "unstable")]
+use crate::api::queryable::QueryableLoad;
 use crate::{
     api::{
         cancellation::SyncGroup,
@@ -47,6 +51,8 @@ pub struct QueryableBuilder<'a, 'b, Handler, const BACKGROUND: bool = false> {
     pub(crate) key_expr: ZResult<KeyExpr<'b>>,
     pub(crate) complete: bool,
     pub(crate) origin: Locality,
+    #[cfg(feature = "unstable")]
+    pub(crate) max_concurrent_queries: Option<usize>,
     pub(crate) handler: Handler,
 }
 
@@ -129,6 +135,8 @@ impl<'a, 'b> QueryableBuilder<'a, 'b, DefaultHandler> {
             key_expr,
             complete,
             origin,
+            #[cfg(feature = "unstable")]
+            max_concurrent_queries,
             handler: _,
         } = self;
         QueryableBuilder {
@@ -136,6 +144,8 @@ impl<'a, 'b> QueryableBuilder<'a, 'b, DefaultHandler> {
             key_expr,
             complete,
             origin,
+            #[cfg(feature = "unstable")]
+            max_concurrent_queries,
             handler,
         }
     }
@@ -167,6 +177,8 @@ impl<'a, 'b> QueryableBuilder<'a, 'b, Callback<Query>> {
             key_expr: self.key_expr,
             complete: self.complete,
             origin: self.origin,
+            #[cfg(feature = "unstable")]
+            max_concurrent_queries: self.max_concurrent_queries,
             handler: self.handler,
         }
     }
@@ -208,6 +220,35 @@ impl<Handler, const BACKGROUND: bool> QueryableBuilder<'_, '_, Handler, BACKGROU
         self.origin = origin;
         self
     }
+
+    /// Limit the number of queries this [`Queryable`] handles concurrently.
+    ///
+    /// A query is counted from the moment it is received until the [`Query`] and all its clones
+    /// are dropped, i.e. until the query is finalized by this queryable. While `max` queries are
+    /// counted, any new query is rejected before being handed to the callback or the handler:
+    /// it receives an error reply with the payload `"busy"` and the `"application/error"`
+    /// encoding, then is finalized. The number of rejected queries is returned by
+    /// [`Queryable::shed_queries`].
+    ///
+    /// # Examples
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    ///
+    /// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+    /// let queryable = session
+    ///     .declare_queryable("key/expression")
+    ///     .max_concurrent_queries(8)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    #[zenoh_macros::unstable]
+    #[inline]
+    pub fn max_concurrent_queries(mut self, max: usize) -> Self {
+        self.max_concurrent_queries = Some(max);
+        self
+    }
 }
 
 impl<Handler> Resolvable for QueryableBuilder<'_, '_, Handler>
@@ -227,6 +268,14 @@ where
         let callback_sync_group = SyncGroup::default();
         let session = self.session;
         let (callback, receiver) = self.handler.into_handler();
+        #[cfg(feature = "unstable")]
+        let (callback, load) = match self.max_concurrent_queries {
+            Some(max) => {
+                let load = Arc::new(QueryableLoad::default());
+                (load.limit(callback, max), Some(load))
+            }
+            None => (callback, None),
+        };
         let mut ke = self.key_expr?;
         ke = self.session.declare_nonwild_prefix(ke)?;
         session
@@ -246,6 +295,8 @@ where
                 },
                 handler: receiver,
                 callback_sync_group,
+                #[cfg(feature = "unstable")]
+                load,
             })
     }
 }
@@ -269,15 +320,17 @@ impl Resolvable for QueryableBuilder<'_, '_, Callback<Query>, true> {
 
 impl Wait for QueryableBuilder<'_, '_, Callback<Query>, true> {
     fn wait(self) -> <Self as Resolvable>::To {
+        #[cfg(feature = "unstable")]
+        let callback = match self.max_concurrent_queries {
+            Some(max) => Arc::new(QueryableLoad::default()).limit(self.handler, max),
+            None => self.handler,
+        };
+        #[cfg(not(feature = "unstable"))]
+        let callback = self.handler;
         let mut ke = self.key_expr?;
         ke = self.session.declare_nonwild_prefix(ke)?;
-        self.session.declare_queryable_inner(
-            &ke,
-            self.complete,
-            self.origin,
-            self.handler,
-            None,
-        )?;
+        self.session
+            .declare_queryable_inner(&ke, self.complete, self.origin, callback, None)?;
         Ok(())
     }
 }
@@ -23,7 +23,7 @@ use zenoh_protocol::{
 use zenoh_result::ZResult;
 
 #[zenoh_macros::unstable]
-use crate::api::sample::SourceInfo;
+use crate::api::sample::{QoS, SourceInfo};
 use crate::api::{
     builders::sample::{
         EncodingBuilderTrait, QoSBuilderTrait, SampleBuilder, SampleBuilderTrait,
@@ -58,6 +58,7 @@ pub struct ReplyBuilder<'a, 'b, T> {
     kind: T,
     timestamp: Option<Timestamp>,
     qos: QoSBuilder,
+    replier_qos: bool,
     #[cfg(feature = "unstable")]
     source_info: Option<SourceInfo>,
     attachment: Option<ZBytes>,
@@ -78,6 +79,7 @@ impl<'a, 'b> ReplyBuilder<'a, 'b, ReplyBuilderPut> {
             query,
             key_expr: key_expr.try_into().map_err(Into::into),
             qos: query.inner.qos.into(),
+            replier_qos: false,
             kind: ReplyBuilderPut {
                 payload: payload.into(),
                 encoding: Encoding::default(),
@@ -100,6 +102,7 @@ impl<'a, 'b> ReplyBuilder<'a, 'b, ReplyBuilderDelete> {
             query,
             key_expr: key_expr.try_into().map_err(Into::into),
             qos: query.inner.qos.into(),
+            replier_qos: false,
             kind: ReplyBuilderDelete,
             timestamp: None,
             #[cfg(feature = "unstable")]
@@ -149,9 +152,18 @@ impl<T> QoSBuilderTrait for ReplyBuilder<'_, '_, T> {
         self
     }
 
-    #[deprecated = "calling this function has no impact, replies will use the query priority"]
-    fn priority(self, _priority: Priority) -> Self {
-        self
+    /// Changes the [`Priority`](crate::qos::Priority) of the reply.
+    ///
+    /// Unless the priority or the express policy of the reply is changed, both are the ones the
+    /// querier issued the query with. Once changed, they are kept on every hop back to the
+    /// querier.
+    fn priority(self, priority: Priority) -> Self {
+        let qos = self.qos.priority(priority);
+        Self {
+            qos,
+            replier_qos: true,
+            ..self
+        }
     }
 
     /// Changes the Express policy to apply when routing the reply.
@@ -160,7 +172,45 @@ impl<T> QoSBuilderTrait for ReplyBuilder<'_, '_, T> {
     /// This usually has a positive impact on latency but a negative impact on throughput.
     fn express(self, is_express: bool) -> Self {
         let qos = self.qos.express(is_express);
-        Self { qos, ..self }
+        Self {
+            qos,
+            replier_qos: true,
+            ..self
+        }
+    }
+}
+
+impl<T> ReplyBuilder<'_, '_, T> {
+    /// Changes the priority and express policy at once from a [`QoS`] preset.
+    ///
+    /// The congestion control of a reply is always the one of the query, so the congestion
+    /// control of `qos` is ignored.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use zenoh::qos::QoS;
+    ///
+    /// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+    /// let queryable = session.declare_queryable("key/expression").await.unwrap();
+    /// while let Ok(query) = queryable.recv_async().await {
+    ///     query
+    ///         .reply("key/expression", "value")
+    ///         .qos(QoS::realtime())
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// # }
+    /// ```
+    #[zenoh_macros::unstable]
+    pub fn qos(self, qos: QoS) -> Self {
+        let qos = self.qos.priority(qos.priority()).express(qos.express());
+        Self {
+            qos,
+            replier_qos: true,
+            ..self
+        }
     }
 }
 
@@ -192,7 +242,7 @@ impl Wait for ReplyBuilder<'_, '_, ReplyBuilderPut> {
         #[cfg(feature = "unstable")]
         let sample = sample.source_info(self.source_info);
         let sample = sample.attachment(self.attachment);
-        self.query._reply_sample(sample.into())
+        self.query._reply_sample(sample.into(), self.replier_qos)
     }
 }
 
@@ -205,7 +255,7 @@ impl Wait for ReplyBuilder<'_, '_, ReplyBuilderDelete> {
         #[cfg(feature = "unstable")]
         let sample = sample.source_info(self.source_info);
         let sample = sample.attachment(self.attachment);
-        self.query._reply_sample(sample.into())
+        self.query._reply_sample(sample.into(), self.replier_qos)
     }
 }
 
@@ -289,6 +339,7 @@ impl Wait for ReplyErrBuilder<'_> {
                 eid: self.query.eid,
             }),
             ext_ts_stack: None,
+            ext_replier_qos: None,
         };
         #[cfg(feature = "unstable")]
         {
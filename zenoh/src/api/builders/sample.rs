@@ -18,16 +18,20 @@ use zenoh_core::zresult;
 use zenoh_protocol::core::CongestionControl;
 #[cfg(feature = "unstable")]
 use zenoh_protocol::core::Reliability;
-
 #[cfg(feature = "unstable")]
-use crate::api::timestamp_stack::TimestampInstrumentation;
+use zenoh_result::ZResult;
+
 use crate::api::{
     bytes::{OptionZBytes, ZBytes},
     encoding::Encoding,
     key_expr::KeyExpr,
     publisher::Priority,
-    sample::{QoS, QoSBuilder, Sample, SampleKind},
+    sample::{Locality, QoS, QoSBuilder, Sample, SampleKind},
 };
+#[cfg(feature = "unstable")]
+use crate::api::{config::Config, timestamp_stack::TimestampInstrumentation};
+#[cfg(feature = "unstable")]
+use crate::net::routing::interceptor::payload_limit::PayloadSizeLimits;
 #[zenoh_macros::internal]
 use crate::pubsub::{
     PublicationBuilder, PublicationBuilderDelete, PublicationBuilderPut, Publisher,
@@ -127,6 +131,7 @@ impl SampleBuilder<SampleBuilderPut> {
                 attachment: None,
                 #[cfg(feature = "unstable")]
                 timestamp_stack: None,
+                locality: Locality::SessionLocal,
             },
             _t: PhantomData::<SampleBuilderPut>,
         }
@@ -161,6 +166,7 @@ impl SampleBuilder<SampleBuilderDelete> {
                 attachment: None,
                 #[cfg(feature = "unstable")]
                 timestamp_stack: None,
+                locality: Locality::SessionLocal,
             },
             _t: PhantomData::<SampleBuilderDelete>,
         }
@@ -346,6 +352,131 @@ impl TryFrom<Sample> for SampleBuilder<SampleBuilderDelete> {
     }
 }
 
+/// The limits checked by [`SampleBuilder::try_build`].
+///
+/// The payload size is checked like the publications of a session, against the `limits` of
+/// its configuration, see [`SampleLimits::from_config`]. The attachment size and the
+/// wildcards can be limited on top of it. No limit is set by default.
+///
+/// # Examples
+/// ```
+/// use zenoh::{
+///     key_expr::KeyExpr,
+///     sample::{SampleBuilder, SampleLimits},
+/// };
+///
+/// let limits = SampleLimits::default()
+///     .max_payload_size(1024)
+///     .max_attachment_size(64)
+///     .forbid_wildcards(true);
+/// let key_expr = KeyExpr::try_from("key/expression").unwrap();
+/// assert!(SampleBuilder::put(key_expr, "value").try_build(&limits).is_ok());
+/// let key_expr = KeyExpr::try_from("key/**").unwrap();
+/// assert!(SampleBuilder::put(key_expr, "value").try_build(&limits).is_err());
+/// ```
+#[zenoh_macros::unstable]
+#[derive(Default)]
+pub struct SampleLimits {
+    payload: PayloadSizeLimits,
+    max_attachment_size: Option<usize>,
+    forbid_wildcards: bool,
+}
+
+#[zenoh_macros::unstable]
+impl std::fmt::Debug for SampleLimits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SampleLimits")
+            .field("max_attachment_size", &self.max_attachment_size)
+            .field("forbid_wildcards", &self.forbid_wildcards)
+            .finish_non_exhaustive()
+    }
+}
+
+#[zenoh_macros::unstable]
+impl SampleLimits {
+    /// Returns the payload size limits configured in `limits`, including their overrides
+    /// per key expression.
+    ///
+    /// # Examples
+    /// ```
+    /// use zenoh::{
+    ///     key_expr::KeyExpr,
+    ///     sample::{SampleBuilder, SampleLimits},
+    ///     Config,
+    /// };
+    ///
+    /// let mut config = Config::default();
+    /// config
+    ///     .insert_json5("limits", r#"{ max_payload_size: 4 }"#)
+    ///     .unwrap();
+    /// let limits = SampleLimits::from_config(&config);
+    /// let key_expr = KeyExpr::try_from("key/expression").unwrap();
+    /// assert!(SampleBuilder::put(key_expr, "value").try_build(&limits).is_err());
+    /// ```
+    pub fn from_config(config: &Config) -> Self {
+        SampleLimits {
+            payload: PayloadSizeLimits::new(config.0.limits()).unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the maximum size of the payload, in bytes, on the key expressions matching no
+    /// override of the configuration.
+    pub fn max_payload_size(mut self, max_payload_size: usize) -> Self {
+        self.payload.set_max_payload_size(max_payload_size);
+        self
+    }
+
+    /// Sets the maximum size of the attachment, in bytes.
+    pub fn max_attachment_size(mut self, max_attachment_size: usize) -> Self {
+        self.max_attachment_size = Some(max_attachment_size);
+        self
+    }
+
+    /// Rejects the key expressions containing wildcards (`*`, `**` or `$*`) if `forbid` is `true`.
+    pub fn forbid_wildcards(mut self, forbid: bool) -> Self {
+        self.forbid_wildcards = forbid;
+        self
+    }
+}
+
+impl<T> SampleBuilder<T> {
+    /// Builds the [`Sample`], checking it against `limits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error listing all the limits exceeded by the sample.
+    #[zenoh_macros::unstable]
+    pub fn try_build(self, limits: &SampleLimits) -> ZResult<Sample> {
+        let sample = self.sample;
+        let mut errors = Vec::new();
+        let attachment_size = sample.attachment.as_ref().map_or(0, ZBytes::len);
+        if let Some(max) = limits.payload.max_payload_size(&sample.key_expr) {
+            let len = limits.payload.size(sample.payload.len(), attachment_size);
+            if len > max {
+                errors.push(format!("payload of {len} bytes exceeds {max} bytes"));
+            }
+        }
+        if let Some(max) = limits.max_attachment_size {
+            if attachment_size > max {
+                errors.push(format!(
+                    "attachment of {attachment_size} bytes exceeds {max} bytes"
+                ));
+            }
+        }
+        if limits.forbid_wildcards && sample.key_expr.is_wild() {
+            errors.push(format!(
+                "key expression `{}` contains wildcards",
+                sample.key_expr
+            ));
+        }
+        if !errors.is_empty() {
+            bail!("Invalid sample: {}", errors.join(", "));
+        }
+        Ok(sample)
+    }
+}
+
 impl<T> From<SampleBuilder<T>> for Sample {
     fn from(sample_builder: SampleBuilder<T>) -> Self {
         sample_builder.sample
@@ -373,6 +504,7 @@ impl From<&PublicationBuilder<&Publisher<'_>, PublicationBuilderPut>> for Sample
             attachment: builder.attachment.clone(),
             #[cfg(feature = "unstable")]
             timestamp_stack: None,
+            locality: Locality::SessionLocal,
         }
     }
 }
@@ -398,6 +530,120 @@ impl From<&PublicationBuilder<&Publisher<'_>, PublicationBuilderDelete>> for Sam
             attachment: builder.attachment.clone(),
             #[cfg(feature = "unstable")]
             timestamp_stack: None,
+            locality: Locality::SessionLocal,
         }
     }
 }
+
+#[cfg(all(test, feature = "unstable"))]
+mod tests {
+    use super::*;
+
+    fn ke(key_expr: &'static str) -> KeyExpr<'static> {
+        KeyExpr::try_from(key_expr).unwrap()
+    }
+
+    #[test]
+    fn sample_try_build_limits() {
+        let limits = SampleLimits::default()
+            .max_payload_size(4)
+            .max_attachment_size(2)
+            .forbid_wildcards(true);
+
+        let sample = SampleBuilder::put(ke("a/b"), "1234")
+            .attachment("12")
+            .try_build(&limits)
+            .unwrap();
+        assert_eq!(sample.key_expr().as_str(), "a/b");
+        assert_eq!(sample.payload().to_bytes().as_ref(), b"1234");
+        let sample = SampleBuilder::delete(ke("a/b")).try_build(&limits).unwrap();
+        assert_eq!(sample.kind(), SampleKind::Delete);
+
+        let error = SampleBuilder::put(ke("a/b"), "12345")
+            .try_build(&limits)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.contains("payload of 5 bytes exceeds 4 bytes"),
+            "{error}"
+        );
+
+        let error = SampleBuilder::delete(ke("a/b"))
+            .attachment("123")
+            .try_build(&limits)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.contains("attachment of 3 bytes exceeds 2 bytes"),
+            "{error}"
+        );
+
+        for key_expr in ["a/*", "a/**", "a/b$*"] {
+            let error = SampleBuilder::put(ke(key_expr), "1")
+                .try_build(&limits)
+                .unwrap_err()
+                .to_string();
+            assert!(error.contains("contains wildcards"), "{error}");
+        }
+
+        // All the exceeded limits are reported together
+        let error = SampleBuilder::put(ke("a/**"), "12345")
+            .attachment("123")
+            .try_build(&limits)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("payload"), "{error}");
+        assert!(error.contains("attachment"), "{error}");
+        assert!(error.contains("wildcards"), "{error}");
+
+        // No limit is set by default
+        assert!(SampleBuilder::put(ke("a/**"), vec![0u8; 1024])
+            .attachment(vec![0u8; 1024])
+            .try_build(&SampleLimits::default())
+            .is_ok());
+    }
+
+    #[test]
+    fn sample_try_build_config_limits() {
+        let mut config = Config::default();
+        config
+            .insert_json5(
+                "limits",
+                r#"{
+                    max_payload_size: 8,
+                    include_attachment: true,
+                    overrides: [{ key_exprs: ["a/small/**"], max_payload_size: 2 }],
+                }"#,
+            )
+            .unwrap();
+        let limits = SampleLimits::from_config(&config);
+
+        assert!(SampleBuilder::put(ke("a/b"), "1234")
+            .attachment("1234")
+            .try_build(&limits)
+            .is_ok());
+        // The attachment is included in the payload size
+        let error = SampleBuilder::put(ke("a/b"), "1234")
+            .attachment("12345")
+            .try_build(&limits)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.contains("payload of 9 bytes exceeds 8 bytes"),
+            "{error}"
+        );
+        // The overrides take precedence over max_payload_size
+        let error = SampleBuilder::put(ke("a/small/b"), "123")
+            .try_build(&limits)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.contains("payload of 3 bytes exceeds 2 bytes"),
+            "{error}"
+        );
+        // The admin space is not limited
+        assert!(SampleBuilder::put(ke("@/a/b"), vec![0u8; 1024])
+            .try_build(&limits)
+            .is_ok());
+    }
+}
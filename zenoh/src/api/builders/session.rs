@@ -141,7 +141,7 @@ where
         let config: crate::config::Config = self
             .config
             .try_into()
-            .map_err(|e| zerror!("Invalid Zenoh configuration {:?}", &e))?;
+            .map_err(|e| zerror!(kind = ConfigInvalid, "Invalid Zenoh configuration {:?}", &e))?;
         Session::new(
             config,
             #[cfg(feature = "shared-memory")]
@@ -12,10 +12,18 @@
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
 use std::future::{IntoFuture, Ready};
+#[cfg(feature = "unstable")]
+use std::time::Duration;
 
 use zenoh_core::{Resolvable, Wait};
 use zenoh_result::ZResult;
 
+#[cfg(feature = "unstable")]
+use crate::api::{
+    deduplication::{deduplicate, DeduplicationConfig},
+    sample::SampleKind,
+    subscriber::MultiSubscriber,
+};
 use crate::{
     api::{
         handlers::{locked, Callback, DefaultHandler, IntoHandler},
@@ -64,6 +72,12 @@ pub struct SubscriberBuilder<'a, 'b, Handler, const BACKGROUND: bool = false> {
     pub handler: Handler,
     #[cfg(not(feature = "internal"))]
     pub(crate) handler: Handler,
+
+    #[cfg(feature = "unstable")]
+    pub(crate) allowed_kinds: Option<Vec<SampleKind>>,
+
+    #[cfg(feature = "unstable")]
+    pub(crate) deduplication: DeduplicationConfig,
 }
 
 impl<'a, 'b> SubscriberBuilder<'a, 'b, DefaultHandler> {
@@ -117,6 +131,36 @@ impl<'a, 'b> SubscriberBuilder<'a, 'b, DefaultHandler> {
         self.callback(locked(callback))
     }
 
+    /// Buffer the samples for this subscription until they are pulled.
+    ///
+    /// The samples are still pushed by the network, but they are buffered in a default
+    /// [`PullChannel`](crate::handlers::PullChannel) and only delivered when
+    /// [`pull`](crate::handlers::PullChannelHandler::pull) is called. Use
+    /// [`with`](SubscriberBuilder::with) and a configured [`PullChannel`](crate::handlers::PullChannel)
+    /// to change the buffer capacity or overflow policy.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    ///
+    /// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+    /// let subscriber = session
+    ///     .declare_subscriber("key/expression")
+    ///     .pull_mode()
+    ///     .await
+    ///     .unwrap();
+    /// for sample in subscriber.pull(10).unwrap() {
+    ///     println!("Received: {} {:?}", sample.key_expr(), sample.payload());
+    /// }
+    /// # }
+    /// ```
+    #[zenoh_macros::unstable]
+    #[inline]
+    pub fn pull_mode(self) -> SubscriberBuilder<'a, 'b, crate::api::handlers::PullChannel> {
+        self.with(crate::api::handlers::PullChannel::default())
+    }
+
     /// Receive the samples for this subscription with a [`Handler`](crate::handlers::IntoHandler).
     ///
     /// # Examples
@@ -145,12 +189,20 @@ impl<'a, 'b> SubscriberBuilder<'a, 'b, DefaultHandler> {
             key_expr,
             origin,
             handler: _,
+            #[cfg(feature = "unstable")]
+            allowed_kinds,
+            #[cfg(feature = "unstable")]
+            deduplication,
         } = self;
         SubscriberBuilder {
             session,
             key_expr,
             origin,
             handler,
+            #[cfg(feature = "unstable")]
+            allowed_kinds,
+            #[cfg(feature = "unstable")]
+            deduplication,
         }
     }
 }
@@ -181,6 +233,10 @@ impl<'a, 'b> SubscriberBuilder<'a, 'b, Callback<Sample>> {
             key_expr: self.key_expr,
             origin: self.origin,
             handler: self.handler,
+            #[cfg(feature = "unstable")]
+            allowed_kinds: self.allowed_kinds,
+            #[cfg(feature = "unstable")]
+            deduplication: self.deduplication,
         }
     }
 }
@@ -195,6 +251,95 @@ impl<Handler, const BACKGROUND: bool> SubscriberBuilder<'_, '_, Handler, BACKGRO
         self.origin = origin;
         self
     }
+
+    /// Restricts the received samples to the ones of the given [`SampleKind`]s.
+    ///
+    /// The samples of the other kinds are dropped by the session before reaching the callback or
+    /// the handler. They are still routed to the session, as the subscriber declaration carries
+    /// no sample kinds for the routers to filter on.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use zenoh::sample::SampleKind;
+    ///
+    /// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+    /// let subscriber = session
+    ///     .declare_subscriber("key/expression")
+    ///     .allowed_kinds(&[SampleKind::Put])
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    #[zenoh_macros::unstable]
+    #[inline]
+    pub fn allowed_kinds(mut self, kinds: &[SampleKind]) -> Self {
+        self.allowed_kinds = Some(kinds.to_vec());
+        self
+    }
+
+    /// Suppresses the duplicated samples received within the given window.
+    ///
+    /// In meshes with redundant links, the same publication can occasionally be delivered twice.
+    /// With this option, the subscriber records for each key expression the samples it recently
+    /// received, identified by their timestamp and [`SourceInfo`](crate::sample::SourceInfo), and
+    /// drops the exact duplicates of a sample received on the same key expression within `window`.
+    /// The samples without timestamp are never suppressed, unless
+    /// [`dedup_untimestamped`](SubscriberBuilder::dedup_untimestamped) is enabled.
+    ///
+    /// The memory is bounded: a limited number of samples is recorded per key expression, and only
+    /// the most recently used key expressions are tracked. The number of suppressed samples is
+    /// returned by [`Subscriber::suppressed_duplicates`].
+    ///
+    /// # Examples
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use std::time::Duration;
+    ///
+    /// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+    /// let subscriber = session
+    ///     .declare_subscriber("key/expression")
+    ///     .deduplicate(Duration::from_secs(1))
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    #[zenoh_macros::unstable]
+    #[inline]
+    pub fn deduplicate(mut self, window: Duration) -> Self {
+        self.deduplication.window = Some(window);
+        self
+    }
+
+    /// Also suppresses the duplicated samples without timestamp, comparing the hash of their payload.
+    ///
+    /// Two distinct publications of the same payload on the same key expression within the window
+    /// are then considered duplicates. This option only applies if
+    /// [`deduplicate`](SubscriberBuilder::deduplicate) is enabled.
+    #[zenoh_macros::unstable]
+    #[inline]
+    pub fn dedup_untimestamped(mut self, dedup_untimestamped: bool) -> Self {
+        self.deduplication.untimestamped = dedup_untimestamped;
+        self
+    }
+}
+
+/// Wraps `callback` to drop the samples whose kind is not in `allowed_kinds`.
+#[cfg(feature = "unstable")]
+fn filter_kinds(
+    callback: Callback<Sample>,
+    allowed_kinds: Option<Vec<SampleKind>>,
+) -> Callback<Sample> {
+    match allowed_kinds {
+        Some(kinds) => Callback::from(move |sample: Sample| {
+            if kinds.contains(&sample.kind()) {
+                callback.call(sample)
+            }
+        }),
+        None => callback,
+    }
 }
 
 impl<Handler> Resolvable for SubscriberBuilder<'_, '_, Handler>
@@ -215,6 +360,11 @@ where
         key_expr = self.session.declare_nonwild_prefix(key_expr)?;
         let session = self.session;
         let (callback, receiver) = self.handler.into_handler();
+        #[cfg(feature = "unstable")]
+        let (callback, suppressed_duplicates) = deduplicate(
+            filter_kinds(callback, self.allowed_kinds),
+            self.deduplication,
+        );
         let callback_sync_group = crate::api::cancellation::SyncGroup::default();
         session
             .declare_subscriber_inner(
@@ -233,6 +383,8 @@ where
                 },
                 handler: receiver,
                 callback_sync_group,
+                #[cfg(feature = "unstable")]
+                suppressed_duplicates,
             })
     }
 }
@@ -258,8 +410,15 @@ impl Wait for SubscriberBuilder<'_, '_, Callback<Sample>, true> {
     fn wait(self) -> <Self as Resolvable>::To {
         let mut key_expr = self.key_expr?;
         key_expr = self.session.declare_nonwild_prefix(key_expr)?;
+        #[cfg(feature = "unstable")]
+        let (callback, _) = deduplicate(
+            filter_kinds(self.handler, self.allowed_kinds),
+            self.deduplication,
+        );
+        #[cfg(not(feature = "unstable"))]
+        let callback = self.handler;
         self.session
-            .declare_subscriber_inner(&key_expr, self.origin, self.handler, None)?;
+            .declare_subscriber_inner(&key_expr, self.origin, callback, None)?;
         Ok(())
     }
 }
@@ -272,3 +431,194 @@ impl IntoFuture for SubscriberBuilder<'_, '_, Callback<Sample>, true> {
         std::future::ready(self.wait())
     }
 }
+
+/// A builder for initializing a [`MultiSubscriber`](crate::pubsub::MultiSubscriber).
+///
+/// Returned by the
+/// [`Session::declare_subscriber_multi`](crate::Session::declare_subscriber_multi) method.
+///
+/// # Examples
+/// ```
+/// # #[tokio::main]
+/// # async fn main() {
+///
+/// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+/// let subscriber = session
+///     .declare_subscriber_multi(["key/expression/a", "key/expression/b"])
+///     .await
+///     .unwrap();
+/// # }
+/// ```
+#[zenoh_macros::unstable]
+#[must_use = "Resolvables do nothing unless you resolve them using `.await` or `zenoh::Wait::wait`"]
+#[derive(Debug)]
+pub struct MultiSubscriberBuilder<'a, 'b, Handler> {
+    pub(crate) session: &'a Session,
+    pub(crate) key_exprs: ZResult<Vec<KeyExpr<'b>>>,
+    pub(crate) origin: Locality,
+    pub(crate) handler: Handler,
+}
+
+#[zenoh_macros::unstable]
+impl<'a, 'b> MultiSubscriberBuilder<'a, 'b, DefaultHandler> {
+    /// Receive the samples for this subscription with a callback.
+    #[inline]
+    pub fn callback<F>(self, callback: F) -> MultiSubscriberBuilder<'a, 'b, Callback<Sample>>
+    where
+        F: Fn(Sample) + Send + Sync + 'static,
+    {
+        self.with(Callback::from(callback))
+    }
+
+    /// Receive the samples for this subscription with a mutable callback.
+    ///
+    /// Using this guarantees that your callback will never be called concurrently.
+    /// If your callback is also accepted by the [`callback`](MultiSubscriberBuilder::callback) method, we suggest you use it instead of `callback_mut`.
+    #[inline]
+    pub fn callback_mut<F>(self, callback: F) -> MultiSubscriberBuilder<'a, 'b, Callback<Sample>>
+    where
+        F: FnMut(Sample) + Send + Sync + 'static,
+    {
+        self.callback(locked(callback))
+    }
+
+    /// Receive the samples for this subscription with a [`Handler`](crate::handlers::IntoHandler).
+    #[inline]
+    pub fn with<Handler>(self, handler: Handler) -> MultiSubscriberBuilder<'a, 'b, Handler>
+    where
+        Handler: IntoHandler<Sample>,
+    {
+        let MultiSubscriberBuilder {
+            session,
+            key_exprs,
+            origin,
+            handler: _,
+        } = self;
+        MultiSubscriberBuilder {
+            session,
+            key_exprs,
+            origin,
+            handler,
+        }
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> MultiSubscriberBuilder<'_, '_, Handler> {
+    /// Changes the [`Locality`](crate::sample::Locality) of received publications.
+    ///
+    /// Restricts the matching publications that will be received by this
+    /// [`MultiSubscriber`](crate::pubsub::MultiSubscriber) to the ones
+    /// that have the given [`Locality`](crate::sample::Locality).
+    #[inline]
+    pub fn allowed_origin(mut self, origin: Locality) -> Self {
+        self.origin = origin;
+        self
+    }
+}
+
+/// Removes the key expressions already covered by another one of the set.
+///
+/// Exact duplicates keep their first occurrence.
+#[cfg(feature = "unstable")]
+fn dedup_key_exprs(key_exprs: Vec<KeyExpr<'_>>) -> Vec<KeyExpr<'_>> {
+    let covered = |i: usize| {
+        key_exprs.iter().enumerate().any(|(j, other)| {
+            j != i && other.includes(&key_exprs[i]) && (j < i || !key_exprs[i].includes(other))
+        })
+    };
+    let kept: Vec<bool> = (0..key_exprs.len()).map(|i| !covered(i)).collect();
+    key_exprs
+        .into_iter()
+        .zip(kept)
+        .filter_map(|(key_expr, kept)| {
+            if !kept {
+                tracing::warn!(
+                    "declare_subscriber_multi: ignoring '{}' which is included in another key expression of the set",
+                    key_expr
+                );
+            }
+            kept.then_some(key_expr)
+        })
+        .collect()
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> Resolvable for MultiSubscriberBuilder<'_, '_, Handler>
+where
+    Handler: IntoHandler<Sample> + Send,
+    Handler::Handler: Send,
+{
+    type To = ZResult<MultiSubscriber<Handler::Handler>>;
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> Wait for MultiSubscriberBuilder<'_, '_, Handler>
+where
+    Handler: IntoHandler<Sample> + Send,
+    Handler::Handler: Send,
+{
+    fn wait(self) -> <Self as Resolvable>::To {
+        let session = self.session;
+        let key_exprs = dedup_key_exprs(self.key_exprs?)
+            .into_iter()
+            .map(|key_expr| session.declare_nonwild_prefix(key_expr))
+            .collect::<ZResult<Vec<_>>>()?;
+        let (callback, receiver) = self.handler.into_handler();
+        let callback_sync_group = crate::api::cancellation::SyncGroup::default();
+        let mut multi = MultiSubscriber {
+            inners: Vec::with_capacity(key_exprs.len()),
+            key_exprs: Vec::with_capacity(key_exprs.len()),
+            handler: receiver,
+            callback_sync_group,
+        };
+        for (i, key_expr) in key_exprs.iter().enumerate() {
+            // A sample matching several key expressions of the set is only delivered
+            // by the subscription of the first one.
+            let previous: Vec<KeyExpr<'static>> = key_exprs[..i]
+                .iter()
+                .map(|k| k.clone().into_owned())
+                .collect();
+            let callback = callback.clone();
+            let callback = if previous.is_empty() {
+                callback
+            } else {
+                Callback::from(move |sample: Sample| {
+                    if !previous.iter().any(|k| k.intersects(sample.key_expr())) {
+                        callback.call(sample);
+                    }
+                })
+            };
+            // on error, `multi` is dropped and undeclares the subscriptions declared so far
+            let sub_state = session.declare_subscriber_inner(
+                key_expr,
+                self.origin,
+                callback,
+                multi.callback_sync_group.notifier(),
+            )?;
+            multi.key_exprs.push(sub_state.key_expr.clone());
+            multi.inners.push(SubscriberInner {
+                session: session.downgrade(),
+                id: sub_state.id,
+                key_expr: sub_state.key_expr.clone(),
+                kind: SubscriberKind::Subscriber,
+                undeclare_on_drop: true,
+            });
+        }
+        Ok(multi)
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> IntoFuture for MultiSubscriberBuilder<'_, '_, Handler>
+where
+    Handler: IntoHandler<Sample> + Send,
+    Handler::Handler: Send,
+{
+    type Output = <Self as Resolvable>::To;
+    type IntoFuture = Ready<<Self as Resolvable>::To>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        std::future::ready(self.wait())
+    }
+}
@@ -18,7 +18,7 @@ use std::{borrow::Cow, fmt::Debug, mem, str::Utf8Error};
 use zenoh_buffers::{
     buffer::{Buffer, SplitBuffer},
     reader::{HasReader, Reader},
-    ZBuf, ZBufReader, ZSlice, ZSliceBuffer,
+    ZBuf, ZBufReader, ZSlice,
 };
 use zenoh_protocol::zenoh::ext::AttachmentType;
 
@@ -516,28 +516,32 @@ impl From<&Cow<'_, str>> for ZBytes {
     }
 }
 
-// Define a transparent wrapper type to get around Rust's orphan rule.
-// This allows using bytes::Bytes directly as the supporting buffer of a
-// ZSlice resulting in zero-copy and zero-alloc bytes::Bytes serialization.
-#[repr(transparent)]
-#[derive(Debug)]
-struct BytesWrap(bytes::Bytes);
-impl ZSliceBuffer for BytesWrap {
-    fn as_slice(&self) -> &[u8] {
-        &self.0
-    }
-
-    fn as_any(&self) -> &dyn std::any::Any {
-        self
-    }
-
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
-        self
-    }
-}
+/// Converts [`bytes::Bytes`] into [`ZBytes`] without copying: the [`bytes::Bytes`] buffer is
+/// shared by the [`ZBytes`].
 impl From<bytes::Bytes> for ZBytes {
     fn from(value: bytes::Bytes) -> Self {
-        Self(BytesWrap(value).into())
+        Self(value.into())
+    }
+}
+
+/// Converts [`ZBytes`] into [`bytes::Bytes`].
+///
+/// Contiguous [`ZBytes`] are converted without copying: the returned [`bytes::Bytes`] shares
+/// their buffer. Fragmented [`ZBytes`], e.g. received in several network fragments or built with
+/// a [`ZBytesWriter`], are copied into a new contiguous buffer.
+///
+/// # Examples
+/// ```
+/// use zenoh::bytes::ZBytes;
+///
+/// let bytes = bytes::Bytes::from(vec![0u8, 1, 2, 3]);
+/// let ptr = bytes.as_ptr();
+/// let zbytes = ZBytes::from(bytes);
+/// assert_eq!(bytes::Bytes::from(zbytes).as_ptr(), ptr);
+/// ```
+impl From<ZBytes> for bytes::Bytes {
+    fn from(value: ZBytes) -> Self {
+        value.0.into()
     }
 }
 
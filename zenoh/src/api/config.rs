@@ -61,12 +61,16 @@ impl Config {
 
     /// Load configuration from the JSON5 string `input`.
     pub fn from_json5(input: &str) -> ZResult<Config> {
-        match zenoh_config::Config::from_deserializer(&mut json5::Deserializer::from_str(input)?) {
+        let mut deserializer = json5::Deserializer::from_str(input)
+            .map_err(|err| zerror!(kind = ConfigInvalid, err))?;
+        match zenoh_config::Config::from_deserializer(&mut deserializer) {
             Ok(config) => Ok(Config(config)),
-            Err(Ok(_)) => {
-                Err(zerror!("The config was correctly deserialized, but it is invalid").into())
-            }
-            Err(Err(err)) => Err(err.into()),
+            Err(Ok(_)) => Err(zerror!(
+                kind = ConfigInvalid,
+                "The config was correctly deserialized, but it is invalid"
+            )
+            .into()),
+            Err(Err(err)) => Err(zerror!(kind = ConfigInvalid, err).into()),
         }
     }
 
@@ -83,14 +87,14 @@ impl Config {
     pub fn insert_json5(&mut self, key: &str, value: &str) -> ZResult<()> {
         self.0
             .insert_json5(key, value)
-            .map_err(|err| zerror!("{err}").into())
+            .map_err(|err| zerror!(kind = ConfigInvalid, "{err}").into())
     }
 
     /// See [`zenoh_config::Config::try_insert_json5_array_item`].
     pub fn try_insert_json5_array_item(&mut self, key: &str, value: &str) -> ZResult<bool> {
         self.0
             .try_insert_json5_array_item(key, value)
-            .map_err(|err| zerror!("{err}").into())
+            .map_err(|err| zerror!(kind = ConfigInvalid, "{err}").into())
     }
 
     /// Returns a JSON string containing the configuration at `key`.
@@ -176,11 +180,19 @@ impl<T> Clone for Notifier<T> {
     }
 }
 
+/// The prefixes of the configuration keys which are applied when updated at runtime.
+const DYNAMICALLY_WRITABLE_PREFIXES: [&str; 3] =
+    ["plugins/", "scouting/multicast/", "scouting/gossip/"];
+
 fn ensure_config_key_is_dynamically_writable(key: &str) -> ZResult<()> {
-    if !key.starts_with("plugins/") {
+    if !DYNAMICALLY_WRITABLE_PREFIXES
+        .iter()
+        .any(|prefix| key.starts_with(prefix))
+    {
         bail!(
+            kind = ConfigInvalid,
             "Error inserting conf value {} : updating config is only \
-                supported for keys starting with `plugins/`",
+                supported for keys starting with `plugins/`, `scouting/multicast/` or `scouting/gossip/`",
             key
         );
     }
@@ -197,7 +209,6 @@ impl Notifier<ExpandedConfig> {
         }
     }
 
-    #[cfg(feature = "plugins")]
     pub fn subscribe(&self) -> flume::Receiver<Notification> {
         let (tx, rx) = flume::unbounded();
         self.lock_subscribers().push(tx);
@@ -302,6 +313,29 @@ mod tests {
         assert_eq!(config.lock().get_json("qos/network").unwrap(), before);
     }
 
+    #[test]
+    fn runtime_insert_json5_rejects_scouting_keys_not_applied() {
+        let config = super::Notifier::new(zenoh_config::Config::default().expanded());
+
+        for key in [
+            "scouting/timeout",
+            "scouting/delay",
+            "scouting/multicast",
+            "scouting/multicastx/enabled",
+        ] {
+            let err = config.insert_json5(key, "1000").unwrap_err();
+            assert!(err
+                .to_string()
+                .contains("supported for keys starting with `plugins/`"));
+        }
+        config
+            .insert_json5("scouting/multicast/enabled", "false")
+            .unwrap();
+        config
+            .insert_json5("scouting/gossip/enabled", "false")
+            .unwrap();
+    }
+
     #[test]
     fn insert_remove_json5_array_item() {
         let mut config = Config::default();
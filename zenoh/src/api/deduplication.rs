@@ -0,0 +1,145 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{
+    collections::{hash_map::DefaultHasher, VecDeque},
+    hash::{Hash, Hasher},
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use lru::LruCache;
+use uhlc::Timestamp;
+use zenoh_config::wrappers::EntityGlobalId;
+use zenoh_core::zlock;
+
+use crate::api::{
+    handlers::Callback,
+    sample::{Sample, SourceSn},
+};
+
+/// The maximum number of key expressions for which the recently seen samples are recorded.
+const MAX_DEDUPLICATION_KEYS: usize = 1024;
+/// The maximum number of recently seen samples recorded per key expression.
+const MAX_DEDUPLICATION_RECORDS_PER_KEY: usize = 64;
+
+/// The sample deduplication options of a subscriber.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DeduplicationConfig {
+    pub(crate) window: Option<Duration>,
+    pub(crate) untimestamped: bool,
+}
+
+/// The identity of a sample used to recognize its duplicates.
+#[derive(PartialEq, Eq)]
+enum SampleId {
+    Timestamped {
+        timestamp: Timestamp,
+        source: Option<(EntityGlobalId, SourceSn)>,
+    },
+    Untimestamped(u64),
+}
+
+impl SampleId {
+    fn new(sample: &Sample, untimestamped: bool) -> Option<Self> {
+        let source = sample
+            .source_info()
+            .map(|info| (*info.source_id(), info.source_sn()));
+        match sample.timestamp() {
+            Some(timestamp) => Some(SampleId::Timestamped {
+                timestamp: *timestamp,
+                source,
+            }),
+            None if untimestamped => {
+                let mut hasher = DefaultHasher::new();
+                (sample.kind() as u8).hash(&mut hasher);
+                source.hash(&mut hasher);
+                for slice in sample.payload().slices() {
+                    hasher.write(slice);
+                }
+                Some(SampleId::Untimestamped(hasher.finish()))
+            }
+            None => None,
+        }
+    }
+}
+
+/// Records the samples recently received on each key expression to suppress their duplicates.
+struct Deduplicator {
+    window: Duration,
+    untimestamped: bool,
+    records: LruCache<String, VecDeque<(Instant, SampleId)>>,
+}
+
+impl Deduplicator {
+    /// Returns `true` if a sample identical to `sample` was received on the same key expression
+    /// within the window, recording `sample` otherwise.
+    fn is_duplicate(&mut self, sample: &Sample) -> bool {
+        let Some(id) = SampleId::new(sample, self.untimestamped) else {
+            return false;
+        };
+        let now = Instant::now();
+        let records = self
+            .records
+            .get_or_insert_mut_ref(sample.key_expr().as_str(), VecDeque::new);
+        while records
+            .front()
+            .is_some_and(|(seen, _)| now.duration_since(*seen) > self.window)
+        {
+            records.pop_front();
+        }
+        if records.iter().any(|(_, seen)| *seen == id) {
+            return true;
+        }
+        if records.len() == MAX_DEDUPLICATION_RECORDS_PER_KEY {
+            records.pop_front();
+        }
+        records.push_back((now, id));
+        false
+    }
+}
+
+/// Wraps `callback` to suppress the duplicated samples, according to `config`.
+///
+/// Returns the wrapped callback, along with the counter of the suppressed samples if the
+/// deduplication is enabled.
+pub(crate) fn deduplicate(
+    callback: Callback<Sample>,
+    config: DeduplicationConfig,
+) -> (Callback<Sample>, Option<Arc<AtomicU64>>) {
+    let Some(window) = config.window else {
+        return (callback, None);
+    };
+    let deduplicator = Mutex::new(Deduplicator {
+        window,
+        untimestamped: config.untimestamped,
+        records: LruCache::new(NonZeroUsize::new(MAX_DEDUPLICATION_KEYS).unwrap()),
+    });
+    let suppressed = Arc::new(AtomicU64::new(0));
+    let callback = Callback::from({
+        let suppressed = suppressed.clone();
+        move |sample: Sample| {
+            if zlock!(deduplicator).is_duplicate(&sample) {
+                suppressed.fetch_add(1, Ordering::Relaxed);
+                tracing::trace!("Suppressing duplicated sample on {}", sample.key_expr());
+            } else {
+                callback.call(sample)
+            }
+        }
+    });
+    (callback, Some(suppressed))
+}
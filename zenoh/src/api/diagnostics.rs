@@ -0,0 +1,247 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use zenoh_config::{PingConf, ZenohId};
+use zenoh_core::{zlock, Wait};
+use zenoh_keyexpr::keyexpr;
+use zenoh_macros::ke;
+#[cfg(feature = "unstable")]
+use zenoh_result::ZResult;
+
+#[cfg(feature = "unstable")]
+use crate::api::{builders::diagnostics::PingBuilder, query::ConsolidationMode, session::Session};
+use crate::{
+    self as zenoh,
+    api::{key_expr::KeyExpr, queryable::Query, sample::Locality, session::WeakSession, Id},
+    handlers::Callback,
+};
+
+static KE_PING: &keyexpr = ke!("@/ping");
+
+/// The key expression on which the ping responder of the session `zid` is declared.
+fn ke_ping(zid: ZenohId) -> KeyExpr<'static> {
+    KeyExpr::from(KE_PING / &zid.into_keyexpr())
+}
+
+/// Limits the number of probes answered per second.
+struct RateLimiter {
+    limit: u32,
+    window: Mutex<(Instant, u32)>,
+}
+
+impl RateLimiter {
+    fn new(limit: u32) -> Self {
+        Self {
+            limit,
+            window: Mutex::new((Instant::now(), 0)),
+        }
+    }
+
+    fn allow(&self) -> bool {
+        let mut window = zlock!(self.window);
+        let now = Instant::now();
+        if now.duration_since(window.0) >= Duration::from_secs(1) {
+            *window = (now, 0);
+        }
+        if window.1 < self.limit {
+            window.1 += 1;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Declares the responder echoing the ping probes sent to `@/ping/<zid>`, unless disabled
+/// in the `diagnostics/ping` configuration, and returns its id.
+pub(crate) fn init(session: WeakSession) -> Option<Id> {
+    let config = session
+        .runtime()
+        .get_config()
+        .get_typed::<PingConf>("diagnostics/ping")
+        .unwrap_or_default();
+    if !*config.enabled() {
+        return None;
+    }
+    let key_expr = ke_ping(session.zid());
+    let limiter = RateLimiter::new(*config.rate_limit());
+    let ping_qabl = session.declare_queryable_inner(
+        &key_expr,
+        false,
+        Locality::Any,
+        Callback::from({
+            let key_expr = key_expr.clone();
+            move |query: Query| {
+                if !limiter.allow() {
+                    tracing::trace!("Dropping ping probe from {:?}: rate limit", query);
+                    return;
+                }
+                let payload = query.payload().cloned().unwrap_or_default();
+                if let Err(e) = query.reply(key_expr.clone(), payload).express(true).wait() {
+                    tracing::debug!("Ping reply error: {}", e);
+                }
+            }
+        }),
+        None,
+    );
+    match ping_qabl {
+        Ok(ping_qabl) => Some(ping_qabl.id),
+        Err(e) => {
+            tracing::error!("Unable to declare the ping responder: {}", e);
+            None
+        }
+    }
+}
+
+/// The target of a [`ping`].
+///
+/// Either a peer identified by its [`ZenohId`], or any key expression matching the
+/// `@/ping/<zid>` responders, such as `@/ping/*`.
+#[zenoh_macros::unstable]
+#[derive(Clone, Debug)]
+pub enum PingTarget {
+    Peer(ZenohId),
+    KeyExpr(KeyExpr<'static>),
+}
+
+#[zenoh_macros::unstable]
+impl From<ZenohId> for PingTarget {
+    fn from(zid: ZenohId) -> Self {
+        PingTarget::Peer(zid)
+    }
+}
+
+#[zenoh_macros::unstable]
+impl From<KeyExpr<'_>> for PingTarget {
+    fn from(key_expr: KeyExpr<'_>) -> Self {
+        PingTarget::KeyExpr(key_expr.into_owned())
+    }
+}
+
+#[zenoh_macros::unstable]
+impl PingTarget {
+    pub(crate) fn key_expr(&self) -> KeyExpr<'static> {
+        match self {
+            PingTarget::Peer(zid) => ke_ping(*zid),
+            PingTarget::KeyExpr(key_expr) => key_expr.clone(),
+        }
+    }
+}
+
+/// The result of a [`ping`].
+///
+/// Each probe is either answered, with its round-trip time, or lost.
+#[zenoh_macros::unstable]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PingReport {
+    rtts: Vec<Option<Duration>>,
+}
+
+#[zenoh_macros::unstable]
+impl PingReport {
+    /// The round-trip time of each probe, in sending order, `None` for the lost probes.
+    pub fn rtts(&self) -> &[Option<Duration>] {
+        &self.rtts
+    }
+
+    /// The number of probes sent.
+    pub fn sent(&self) -> usize {
+        self.rtts.len()
+    }
+
+    /// The number of probes left unanswered before the timeout.
+    pub fn lost(&self) -> usize {
+        self.rtts.iter().filter(|rtt| rtt.is_none()).count()
+    }
+
+    /// The ratio of lost probes, between 0 and 1.
+    pub fn loss(&self) -> f64 {
+        if self.rtts.is_empty() {
+            return 0.0;
+        }
+        self.lost() as f64 / self.sent() as f64
+    }
+}
+
+/// Measures the round-trip time to a session.
+///
+/// Sends `count` probes of `payload_size` bytes to the target, one after the other, and waits
+/// for each of them to be answered.
+///
+/// Every session answers the probes sent to `@/ping/<zid>` by echoing their payload, unless
+/// the responder is disabled with the `diagnostics/ping/enabled` configuration. The responder
+/// answers at most `diagnostics/ping/rate_limit` probes per second, the extra probes are lost.
+///
+/// # Examples
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() {
+/// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+/// let peer = session.info().peers_zid().await.next().unwrap();
+/// let report = zenoh::diagnostics::ping(&session, peer, 64, 10).await.unwrap();
+/// for rtt in report.rtts() {
+///     println!("rtt={rtt:?}");
+/// }
+/// println!("loss={:.0}%", report.loss() * 100.0);
+/// # }
+/// ```
+#[zenoh_macros::unstable]
+pub fn ping(
+    session: &Session,
+    target: impl Into<PingTarget>,
+    payload_size: usize,
+    count: usize,
+) -> PingBuilder<'_> {
+    PingBuilder {
+        session,
+        target: target.into(),
+        payload_size,
+        count,
+        timeout: Duration::from_secs(1),
+    }
+}
+
+#[cfg(feature = "unstable")]
+pub(crate) async fn _ping(
+    session: Session,
+    key_expr: KeyExpr<'static>,
+    payload_size: usize,
+    count: usize,
+    timeout: Duration,
+) -> ZResult<PingReport> {
+    let payload = vec![0u8; payload_size];
+    let mut rtts = Vec::with_capacity(count);
+    for _ in 0..count {
+        let start = Instant::now();
+        let replies = session
+            .get(&key_expr)
+            .payload(payload.clone())
+            .consolidation(ConsolidationMode::None)
+            .express(true)
+            .timeout(timeout)
+            .await?;
+        let rtt = match replies.recv_async().await {
+            Ok(reply) if reply.result().is_ok() => Some(start.elapsed()),
+            _ => None,
+        };
+        rtts.push(rtt);
+        // A key expression target may match several responders
+        while replies.recv_async().await.is_ok() {}
+    }
+    Ok(PingReport { rtts })
+}
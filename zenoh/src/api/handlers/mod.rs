@@ -15,10 +15,14 @@
 //! Callback handler trait.
 mod callback;
 mod fifo;
+#[cfg(feature = "unstable")]
+mod pull;
 mod ring;
 
 pub use callback::*;
 pub use fifo::*;
+#[cfg(feature = "unstable")]
+pub use pull::*;
 pub use ring::*;
 
 use crate::api::session::API_DATA_RECEPTION_CHANNEL_SIZE;
@@ -0,0 +1,142 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+//! Pull handler.
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex, Weak},
+};
+
+use zenoh_result::ZResult;
+
+use crate::api::{
+    handlers::{callback::Callback, IntoHandler},
+    session::API_DATA_RECEPTION_CHANNEL_SIZE,
+};
+
+/// The data dropped by a [`PullChannel`] when a new item arrives while it is full.
+#[zenoh_macros::unstable]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PullOverflow {
+    /// Drop the oldest buffered item to make room for the new one.
+    #[default]
+    DropOldest,
+    /// Drop the new item, keeping the buffered ones.
+    DropNewest,
+}
+
+/// A bounded buffer whose data items are only delivered when explicitly pulled.
+///
+/// Unlike the [`FifoChannel`](crate::handlers::FifoChannel) and the
+/// [`RingChannel`](crate::handlers::RingChannel), [`PullChannelHandler`] has no blocking receive:
+/// the items are buffered until [`PullChannelHandler::pull`] is called, letting consumers poll at
+/// their own cadence. When the buffer is full, the [`PullOverflow`] policy decides which item is
+/// dropped.
+#[zenoh_macros::unstable]
+#[derive(Debug)]
+pub struct PullChannel {
+    capacity: usize,
+    overflow: PullOverflow,
+}
+
+#[zenoh_macros::unstable]
+impl PullChannel {
+    /// Initialize the PullChannel with the given capacity, dropping the oldest items on overflow.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            overflow: PullOverflow::default(),
+        }
+    }
+
+    /// Changes the [`PullOverflow`] policy applied when the buffer is full.
+    pub fn overflow(mut self, overflow: PullOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+}
+
+#[zenoh_macros::unstable]
+impl Default for PullChannel {
+    fn default() -> Self {
+        Self::new(*API_DATA_RECEPTION_CHANNEL_SIZE)
+    }
+}
+
+#[derive(Debug)]
+struct PullChannelInner<T> {
+    buffer: Mutex<VecDeque<T>>,
+    capacity: usize,
+    overflow: PullOverflow,
+}
+
+/// The handler of a [`PullChannel`].
+#[zenoh_macros::unstable]
+#[derive(Debug)]
+pub struct PullChannelHandler<T> {
+    inner: Weak<PullChannelInner<T>>,
+}
+
+#[zenoh_macros::unstable]
+impl<T> PullChannelHandler<T> {
+    /// Pull up to `max` buffered items, oldest first.
+    ///
+    /// This call never blocks: it returns an empty vector if no item is buffered.
+    pub fn pull(&self, max: usize) -> ZResult<Vec<T>> {
+        let Some(inner) = self.inner.upgrade() else {
+            bail!("The pull channel has been deleted.");
+        };
+        let mut buffer = inner.buffer.lock().map_err(|e| zerror!("{}", e))?;
+        let n = max.min(buffer.len());
+        Ok(buffer.drain(..n).collect())
+    }
+
+    /// Gets the number of buffered items.
+    pub fn buffered(&self) -> usize {
+        self.inner
+            .upgrade()
+            .and_then(|inner| inner.buffer.lock().ok().map(|buffer| buffer.len()))
+            .unwrap_or(0)
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<T: Send + 'static> IntoHandler<T> for PullChannel {
+    type Handler = PullChannelHandler<T>;
+
+    fn into_handler(self) -> (Callback<T>, Self::Handler) {
+        let inner = Arc::new(PullChannelInner {
+            buffer: Mutex::new(VecDeque::with_capacity(self.capacity)),
+            capacity: self.capacity,
+            overflow: self.overflow,
+        });
+        let handler = PullChannelHandler {
+            inner: Arc::downgrade(&inner),
+        };
+        (
+            Callback::from(move |t| match inner.buffer.lock() {
+                Ok(mut buffer) => {
+                    if buffer.len() < inner.capacity {
+                        buffer.push_back(t);
+                    } else if inner.overflow == PullOverflow::DropOldest && inner.capacity > 0 {
+                        buffer.pop_front();
+                        buffer.push_back(t);
+                    }
+                }
+                Err(e) => tracing::error!("{}", e),
+            }),
+            handler,
+        )
+    }
+}
@@ -14,10 +14,15 @@
 
 //! Tools to access information about the current zenoh [`Session`](crate::Session).
 
+#[cfg(all(feature = "unstable", feature = "stats"))]
+use std::sync::atomic::{AtomicU64, Ordering};
+
 use zenoh_config::{wrappers::ZenohId, WhatAmI};
 #[cfg(feature = "unstable")]
 use zenoh_core::{Resolve, ResolveClosure};
 use zenoh_link::LinkAuthId;
+#[cfg(all(feature = "unstable", feature = "stats"))]
+use zenoh_protocol::core::Priority as ProtocolPriority;
 use zenoh_protocol::core::{Locator, Reliability};
 use zenoh_transport::TransportPeer;
 
@@ -27,6 +32,8 @@ use crate::api::builders::info_links::{LinkEventsListenerBuilder, LinksBuilder};
 use crate::api::builders::info_transport::{TransportEventsListenerBuilder, TransportsBuilder};
 #[cfg(feature = "unstable")]
 use crate::api::handlers::DefaultHandler;
+#[cfg(all(feature = "unstable", feature = "stats"))]
+use crate::api::publisher::Priority;
 use crate::api::{
     builders::info::{PeersZenohIdBuilder, RoutersZenohIdBuilder, ZenohIdBuilder},
     handlers::CallbackParameter,
@@ -124,6 +131,35 @@ impl SessionInfo {
         ResolveClosure::new(|| self.session.runtime().get_locators())
     }
 
+    /// Return the estimated clock skew with the zenoh nodes this session has a unicast transport with.
+    ///
+    /// The skew is estimated from the HLC times carried by the keep-alives of the remote nodes, and
+    /// only when timestamping is enabled on both ends. A node whose transport is closed is no longer
+    /// reported. Zenoh doesn't adjust its clock based on these estimates.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    ///
+    /// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+    /// for skew in session.info().peers_skew().await {
+    ///     println!("{}: {}ns", skew.zid(), skew.skew_nanos());
+    /// }
+    /// # }
+    /// ```
+    #[zenoh_macros::unstable]
+    pub fn peers_skew(&self) -> impl Resolve<Vec<ClockSkew>> + '_ {
+        ResolveClosure::new(|| {
+            self.session
+                .runtime()
+                .get_clock_skews()
+                .into_iter()
+                .map(|(zid, skew_nanos)| ClockSkew { zid, skew_nanos })
+                .collect()
+        })
+    }
+
     /// Return information about currently opened transport sessions. Transport session is a connection to another zenoh node.
     ///
     /// # Examples
@@ -137,6 +173,26 @@ impl SessionInfo {
     /// }
     /// # }
     /// ```
+    /// Return the number of messages routed by this session, per [`Priority`].
+    ///
+    /// See [`RoutedMessages`] for the messages that are counted.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+    /// for (priority, count) in session.info().routed_messages().await.iter() {
+    ///     println!("{priority}: {count}");
+    /// }
+    /// # }
+    /// ```
+    #[zenoh_macros::unstable]
+    #[cfg(feature = "stats")]
+    pub fn routed_messages(&self) -> impl Resolve<RoutedMessages> + '_ {
+        ResolveClosure::new(|| self.session.routed_counters().snapshot())
+    }
+
     #[zenoh_macros::unstable]
     pub fn transports(&self) -> TransportsBuilder<'_> {
         TransportsBuilder::new(self.session.runtime())
@@ -234,6 +290,7 @@ pub struct Transport {
     pub(crate) is_multicast: bool,
     #[cfg(feature = "shared-memory")]
     pub(crate) is_shm: bool,
+    pub(crate) cert_common_name: Option<String>,
 }
 
 impl Transport {
@@ -245,6 +302,11 @@ impl Transport {
             is_multicast,
             #[cfg(feature = "shared-memory")]
             is_shm: peer.is_shm,
+            cert_common_name: peer
+                .links
+                .iter()
+                .find_map(|link| link.auth_identifier.get_cert_common_name())
+                .map(str::to_owned),
         }
     }
 
@@ -258,6 +320,7 @@ impl Transport {
             is_multicast: false,
             #[cfg(feature = "shared-memory")]
             is_shm: false,
+            cert_common_name: None,
         }
     }
 
@@ -277,6 +340,7 @@ impl Transport {
             is_multicast,
             #[cfg(feature = "shared-memory")]
             is_shm,
+            cert_common_name: None,
         }
     }
 }
@@ -313,6 +377,97 @@ impl Transport {
     pub fn is_multicast(&self) -> bool {
         self.is_multicast
     }
+
+    /// Gets the identity of the certificate the remote zenoh node authenticated with over
+    /// TLS or QUIC, if any: its subject Common Name, or its first DNS Subject Alternative Name
+    /// if it has no Common Name.
+    #[inline]
+    pub fn cert_common_name(&self) -> Option<&str> {
+        self.cert_common_name.as_deref()
+    }
+}
+
+/// The estimated clock skew with a remote zenoh node.
+///
+/// Returned by [`SessionInfo::peers_skew`](crate::session::SessionInfo::peers_skew).
+#[zenoh_macros::unstable]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockSkew {
+    pub(crate) zid: ZenohId,
+    pub(crate) skew_nanos: i64,
+}
+
+#[zenoh_macros::unstable]
+impl ClockSkew {
+    /// Gets the ZenohId of the remote zenoh node.
+    #[inline]
+    pub fn zid(&self) -> &ZenohId {
+        &self.zid
+    }
+
+    /// Gets the estimated skew in nanoseconds.
+    ///
+    /// A positive skew means the clock of the remote zenoh node is ahead of the local one.
+    #[inline]
+    pub fn skew_nanos(&self) -> i64 {
+        self.skew_nanos
+    }
+}
+
+/// Per-[`Priority`] counters of the messages a session hands to the router.
+#[cfg(all(feature = "unstable", feature = "stats"))]
+#[derive(Debug, Default)]
+pub(crate) struct RoutedCounters([AtomicU64; Priority::ALL.len()]);
+
+#[cfg(all(feature = "unstable", feature = "stats"))]
+impl RoutedCounters {
+    #[inline]
+    pub(crate) fn increment(&self, priority: ProtocolPriority) {
+        // `Control` messages are not emitted by the session API, so they are not counted.
+        if let Some(counter) = (priority as usize)
+            .checked_sub(1)
+            .and_then(|i| self.0.get(i))
+        {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> RoutedMessages {
+        RoutedMessages(std::array::from_fn(|i| self.0[i].load(Ordering::Relaxed)))
+    }
+}
+
+/// The number of messages routed by a session, per [`Priority`].
+///
+/// Publications, deletions, queries and replies are counted when the session hands them to the
+/// router, whatever their destination. Messages delivered to the session only through
+/// [`Locality::SessionLocal`](crate::sample::Locality::SessionLocal) are not counted.
+///
+/// Returned by [`SessionInfo::routed_messages`](crate::session::SessionInfo::routed_messages).
+#[zenoh_macros::unstable]
+#[cfg(feature = "stats")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RoutedMessages([u64; Priority::ALL.len()]);
+
+#[zenoh_macros::unstable]
+#[cfg(feature = "stats")]
+impl RoutedMessages {
+    /// Gets the number of messages routed with the given [`Priority`].
+    #[inline]
+    pub fn get(&self, priority: Priority) -> u64 {
+        self.0[priority as usize - 1]
+    }
+
+    /// Iterates over the number of messages routed with each [`Priority`], from the highest to
+    /// the lowest.
+    pub fn iter(&self) -> impl Iterator<Item = (Priority, u64)> + '_ {
+        Priority::ALL.into_iter().map(|p| (p, self.get(p)))
+    }
+
+    /// Gets the number of messages routed with any [`Priority`].
+    pub fn total(&self) -> u64 {
+        self.0.iter().sum()
+    }
 }
 
 /// Describes a concrete link within a [`Transport`](crate::session::Transport).
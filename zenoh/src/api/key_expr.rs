@@ -130,7 +130,7 @@ impl KeyExpr<'static> {
     }
 }
 
-#[zenoh_macros::internal]
+#[cfg(any(feature = "internal", feature = "unstable"))]
 static KEYEXPR_DUMMY: &keyexpr = unsafe { keyexpr::from_str_unchecked("dummy") };
 
 impl<'a> KeyExpr<'a> {
@@ -152,6 +152,12 @@ impl<'a> KeyExpr<'a> {
     /// but may be used in language bindings (zenoh-c)
     #[zenoh_macros::internal]
     pub fn dummy() -> Self {
+        Self::placeholder()
+    }
+
+    /// Constructs the dummy key expression, for builders whose key expression is set afterwards.
+    #[cfg(any(feature = "internal", feature = "unstable"))]
+    pub(crate) fn placeholder() -> Self {
         Self(KeyExprInner::Borrowed {
             key_expr: KEYEXPR_DUMMY,
             declaration: None,
@@ -266,7 +272,7 @@ impl<'a> KeyExpr<'a> {
     pub fn concat<S: AsRef<str> + ?Sized>(&self, s: &S) -> ZResult<KeyExpr<'static>> {
         let s = s.as_ref();
         if self.ends_with('*') && s.starts_with('*') {
-            bail!("Tried to concatenate {} (ends with *) and {} (starts with *), which would likely have caused bugs. If you're sure you want to do this, concatenate these into a string and then try to convert.", self, s)
+            bail!(kind = KeyExprInvalid, "Tried to concatenate {} (ends with *) and {} (starts with *), which would likely have caused bugs. If you're sure you want to do this, concatenate these into a string and then try to convert.", self, s)
         }
         Ok(KeyExpr(KeyExprInner::Owned {
             key_expr: OwnedKeyExpr::try_from(format!("{self}{s}"))?,
@@ -565,13 +571,14 @@ impl<'a> KeyExpr<'a> {
                 } else {
                     let expr_id = self.declaration_mut().insert(d).expr_id;
                         Err(zerror!(
+                            kind = DeclarationRefused,
                             "Failed to undeclare expr with id {}, as it was declared by another Session",
                             expr_id
                         )
                         .into())
                 }
             },
-            _ => Err(zerror!("Failed to undeclare {}, make sure you use the result of `Session::declare_keyexpr` to call `Session::undeclare`, and that key_expression was not undeclared previously", self).into()),
+            _ => Err(zerror!(kind = DeclarationRefused, "Failed to undeclare {}, make sure you use the result of `Session::declare_keyexpr` to call `Session::undeclare`, and that key_expression was not undeclared previously", self).into()),
         }
     }
 }
@@ -0,0 +1,307 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{
+    fmt,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crossbeam_queue::ArrayQueue;
+
+/// A histogram recording the publication latencies of a [`Publisher`](crate::pubsub::Publisher).
+///
+/// The latency of a publication is measured from the call to `put` or `delete` until the message
+/// is enqueued into the transmission pipelines of the transports, and delivered to the local
+/// subscribers. It does not include the time spent in the transmission pipelines, nor the write
+/// of the message to the sockets.
+///
+/// A default implementation based on the `hdrhistogram` crate, `HdrLatencyHistogram`,
+/// is provided when the `latency_histogram` feature is enabled.
+/// See [`Publisher::with_latency_histogram`](crate::pubsub::Publisher::with_latency_histogram).
+#[zenoh_macros::unstable]
+pub trait LatencyHistogram: Send {
+    /// Records the latency of a single publication.
+    fn record(&mut self, latency: Duration);
+    /// Returns the percentiles of the latencies recorded since creation or the last [`reset`](Self::reset).
+    fn snapshot(&self) -> LatencySnapshot;
+    /// Discards all the recorded latencies.
+    fn reset(&mut self);
+}
+
+/// The percentiles of the publication latencies recorded by a [`LatencyHistogram`].
+#[zenoh_macros::unstable]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LatencySnapshot {
+    pub(crate) count: u64,
+    pub(crate) p50: Duration,
+    pub(crate) p90: Duration,
+    pub(crate) p99: Duration,
+    pub(crate) max: Duration,
+}
+
+#[zenoh_macros::unstable]
+impl LatencySnapshot {
+    /// Creates a snapshot, to be returned by custom [`LatencyHistogram`] implementations.
+    pub fn new(count: u64, p50: Duration, p90: Duration, p99: Duration, max: Duration) -> Self {
+        Self {
+            count,
+            p50,
+            p90,
+            p99,
+            max,
+        }
+    }
+
+    /// The number of recorded publications.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// The median latency.
+    pub fn p50(&self) -> Duration {
+        self.p50
+    }
+
+    /// The 90th percentile latency.
+    pub fn p90(&self) -> Duration {
+        self.p90
+    }
+
+    /// The 99th percentile latency.
+    pub fn p99(&self) -> Duration {
+        self.p99
+    }
+
+    /// The highest recorded latency.
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+}
+
+/// A [`LatencyHistogram`] backed by an HDR histogram.
+///
+/// It has a nanosecond resolution and 3 significant digits, and tracks latencies up to one hour.
+#[zenoh_macros::unstable]
+#[cfg(feature = "latency_histogram")]
+#[derive(Debug, Clone)]
+pub struct HdrLatencyHistogram(hdrhistogram::Histogram<u64>);
+
+#[zenoh_macros::unstable]
+#[cfg(feature = "latency_histogram")]
+impl HdrLatencyHistogram {
+    const HIGHEST_TRACKABLE_NANOS: u64 = 3_600_000_000_000;
+    const SIGNIFICANT_DIGITS: u8 = 3;
+
+    /// Creates an empty histogram.
+    pub fn new() -> Self {
+        Self(
+            hdrhistogram::Histogram::new_with_bounds(
+                1,
+                Self::HIGHEST_TRACKABLE_NANOS,
+                Self::SIGNIFICANT_DIGITS,
+            )
+            .expect("valid histogram bounds"),
+        )
+    }
+
+    fn percentile(&self, quantile: f64) -> Duration {
+        Duration::from_nanos(self.0.value_at_quantile(quantile))
+    }
+}
+
+#[zenoh_macros::unstable]
+#[cfg(feature = "latency_histogram")]
+impl Default for HdrLatencyHistogram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[zenoh_macros::unstable]
+#[cfg(feature = "latency_histogram")]
+impl LatencyHistogram for HdrLatencyHistogram {
+    fn record(&mut self, latency: Duration) {
+        let nanos = u64::try_from(latency.as_nanos()).unwrap_or(u64::MAX);
+        self.0.saturating_record(nanos);
+    }
+
+    fn snapshot(&self) -> LatencySnapshot {
+        if self.0.is_empty() {
+            return LatencySnapshot::default();
+        }
+        LatencySnapshot {
+            count: self.0.len(),
+            p50: self.percentile(0.5),
+            p90: self.percentile(0.9),
+            p99: self.percentile(0.99),
+            max: Duration::from_nanos(self.0.max()),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+/// The enqueue latency tracker of a [`Publisher`](crate::pubsub::Publisher).
+///
+/// The publishing threads push the latencies into a lock-free queue. They are moved into the
+/// histogram when the queue is full or when the histogram is read, so that the histogram lock is
+/// taken once every [`PENDING_LATENCIES`](Self::PENDING_LATENCIES) publications at most.
+pub(crate) struct EnqueueLatencyTracker {
+    pending: ArrayQueue<Duration>,
+    histogram: Mutex<Box<dyn LatencyHistogram>>,
+}
+
+impl EnqueueLatencyTracker {
+    const PENDING_LATENCIES: usize = 1024;
+
+    pub(crate) fn new<H: LatencyHistogram + 'static>(histogram: H) -> Self {
+        Self {
+            pending: ArrayQueue::new(Self::PENDING_LATENCIES),
+            histogram: Mutex::new(Box::new(histogram)),
+        }
+    }
+
+    /// Runs `enqueue` and records its duration.
+    #[inline]
+    pub(crate) fn track<T>(&self, enqueue: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = enqueue();
+        if let Err(latency) = self.pending.push(start.elapsed()) {
+            let mut histogram = zlock!(self.histogram);
+            self.flush(histogram.as_mut());
+            histogram.record(latency);
+        }
+        result
+    }
+
+    pub(crate) fn snapshot(&self) -> LatencySnapshot {
+        let mut histogram = zlock!(self.histogram);
+        self.flush(histogram.as_mut());
+        histogram.snapshot()
+    }
+
+    pub(crate) fn reset(&self) {
+        let mut histogram = zlock!(self.histogram);
+        while self.pending.pop().is_some() {}
+        histogram.reset()
+    }
+
+    fn flush(&self, histogram: &mut dyn LatencyHistogram) {
+        while let Some(latency) = self.pending.pop() {
+            histogram.record(latency);
+        }
+    }
+}
+
+impl fmt::Debug for EnqueueLatencyTracker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("EnqueueLatencyTracker")
+            .field(&self.snapshot())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct CountHistogram(u64);
+
+    impl LatencyHistogram for CountHistogram {
+        fn record(&mut self, _latency: Duration) {
+            self.0 += 1;
+        }
+
+        fn snapshot(&self) -> LatencySnapshot {
+            LatencySnapshot::new(
+                self.0,
+                Duration::ZERO,
+                Duration::ZERO,
+                Duration::ZERO,
+                Duration::ZERO,
+            )
+        }
+
+        fn reset(&mut self) {
+            self.0 = 0;
+        }
+    }
+
+    #[test]
+    fn tracker_flushes_pending_latencies() {
+        let tracker = EnqueueLatencyTracker::new(CountHistogram::default());
+        let publications = 3 * EnqueueLatencyTracker::PENDING_LATENCIES as u64 + 1;
+        for _ in 0..publications {
+            tracker.track(|| ());
+        }
+        assert!(tracker.pending.len() < EnqueueLatencyTracker::PENDING_LATENCIES);
+        assert_eq!(tracker.snapshot().count(), publications);
+        assert!(tracker.pending.is_empty());
+
+        tracker.track(|| ());
+        tracker.reset();
+        assert!(tracker.pending.is_empty());
+        assert_eq!(tracker.snapshot().count(), 0);
+    }
+
+    #[cfg(feature = "latency_histogram")]
+    #[test]
+    fn hdr_snapshot_percentiles() {
+        let mut histogram = HdrLatencyHistogram::new();
+        assert_eq!(histogram.snapshot(), LatencySnapshot::default());
+
+        for micros in 1..=100 {
+            histogram.record(Duration::from_micros(micros));
+        }
+        let snapshot = histogram.snapshot();
+        assert_eq!(snapshot.count(), 100);
+        // 3 significant digits: percentiles are exact to within 0.1%
+        let close = |actual: Duration, expected: u64| {
+            let expected = Duration::from_micros(expected);
+            actual.abs_diff(expected) <= expected / 1000
+        };
+        assert!(close(snapshot.p50(), 50));
+        assert!(close(snapshot.p90(), 90));
+        assert!(close(snapshot.p99(), 99));
+        assert!(close(snapshot.max(), 100));
+    }
+
+    #[cfg(feature = "latency_histogram")]
+    #[test]
+    fn hdr_reset() {
+        let mut histogram = HdrLatencyHistogram::new();
+        histogram.record(Duration::from_millis(3));
+        histogram.reset();
+        assert_eq!(histogram.snapshot(), LatencySnapshot::default());
+
+        histogram.record(Duration::from_micros(7));
+        let snapshot = histogram.snapshot();
+        assert_eq!(snapshot.count(), 1);
+        assert!(snapshot.max() < Duration::from_millis(1));
+    }
+
+    #[cfg(feature = "latency_histogram")]
+    #[test]
+    fn hdr_saturates_out_of_range_latencies() {
+        let mut histogram = HdrLatencyHistogram::new();
+        histogram.record(Duration::ZERO);
+        histogram.record(Duration::from_secs(2 * 3600));
+        let snapshot = histogram.snapshot();
+        assert_eq!(snapshot.count(), 2);
+        assert!(snapshot.max() >= Duration::from_secs(3599));
+    }
+}
@@ -12,6 +12,11 @@
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
 
+#[cfg(feature = "unstable")]
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+};
 use std::{
     convert::TryInto,
     fmt,
@@ -19,8 +24,17 @@ use std::{
 };
 
 use tracing::error;
+#[cfg(feature = "unstable")]
+use zenoh_core::{zlock, ResolveFuture};
 use zenoh_core::{Resolvable, Resolve, Result as ZResult, Wait};
+#[cfg(feature = "unstable")]
+use zenoh_keyexpr::OwnedKeyExpr;
 
+#[cfg(feature = "unstable")]
+use crate::api::{
+    builders::liveliness::LivelinessGroupViewBuilder, handlers::CallbackParameter,
+    sample::SampleKind, subscriber::Subscriber,
+};
 use crate::api::{
     builders::liveliness::{
         LivelinessGetBuilder, LivelinessSubscriberBuilder, LivelinessTokenBuilder,
@@ -210,6 +224,223 @@ impl<'a> Liveliness<'a> {
     }
 }
 
+#[zenoh_macros::unstable]
+impl<'a> Liveliness<'a> {
+    /// Count the liveliness tokens with matching key expressions.
+    ///
+    /// The matching tokens are queried with [`get`](Liveliness::get) and the distinct key
+    /// expressions of the replies are counted, so this resolves once the query is complete.
+    ///
+    /// # Arguments
+    ///
+    /// * `key_expr` - The key expression matching liveliness tokens to count
+    ///
+    /// # Examples
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    ///
+    /// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+    /// let count = session.liveliness().count("service/x/*").await.unwrap();
+    /// println!("{count} instances of service x are alive");
+    /// # }
+    /// ```
+    pub fn count<'b, TryIntoKeyExpr>(
+        &self,
+        key_expr: TryIntoKeyExpr,
+    ) -> impl Resolve<ZResult<usize>> + 'a
+    where
+        TryIntoKeyExpr: TryInto<KeyExpr<'b>>,
+        <TryIntoKeyExpr as TryInto<KeyExpr<'b>>>::Error: Into<zenoh_result::Error>,
+    {
+        let session = self.session;
+        let key_expr = key_expr
+            .try_into()
+            .map(KeyExpr::into_owned)
+            .map_err(Into::into);
+        ResolveFuture::new(async move {
+            let replies = session.liveliness().get(key_expr?).await?;
+            let mut alive = HashSet::new();
+            while let Ok(reply) = replies.recv_async().await {
+                if let Ok(sample) = reply.into_result() {
+                    alive.insert(OwnedKeyExpr::from(sample.key_expr().clone()));
+                }
+            }
+            Ok(alive.len())
+        })
+    }
+
+    /// Create a [`LivelinessGroupView`] maintaining the set of alive liveliness tokens matching the given key expression.
+    ///
+    /// The view is notified with a [`LivelinessGroupChange`] each time a token appears or disappears.
+    ///
+    /// # Arguments
+    ///
+    /// * `key_expr` - The key expression matching the liveliness tokens of the group
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() {
+    ///
+    /// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+    /// let view = session.liveliness().declare_group_view("service/x/*").await.unwrap();
+    /// while let Ok(change) = view.recv_async().await {
+    ///     println!("{} instances of service x are alive", change.alive().len());
+    /// }
+    /// # }
+    /// ```
+    pub fn declare_group_view<'b, TryIntoKeyExpr>(
+        &self,
+        key_expr: TryIntoKeyExpr,
+    ) -> LivelinessGroupViewBuilder<'a, 'b, DefaultHandler>
+    where
+        TryIntoKeyExpr: TryInto<KeyExpr<'b>>,
+        <TryIntoKeyExpr as TryInto<KeyExpr<'b>>>::Error: Into<zenoh_result::Error>,
+    {
+        LivelinessGroupViewBuilder {
+            session: self.session,
+            key_expr: TryIntoKeyExpr::try_into(key_expr).map_err(Into::into),
+            handler: DefaultHandler::default(),
+        }
+    }
+}
+
+/// A change of the set of alive tokens of a [`LivelinessGroupView`].
+///
+/// It carries both the token that appeared or disappeared and the resulting set of alive tokens.
+#[zenoh_macros::unstable]
+#[derive(Debug, Clone)]
+pub struct LivelinessGroupChange {
+    pub(crate) key_expr: KeyExpr<'static>,
+    pub(crate) kind: SampleKind,
+    pub(crate) alive: HashSet<OwnedKeyExpr>,
+}
+
+#[zenoh_macros::unstable]
+impl LivelinessGroupChange {
+    /// The key expression of the token that appeared or disappeared.
+    pub fn key_expr(&self) -> &KeyExpr<'static> {
+        &self.key_expr
+    }
+
+    /// [`SampleKind::Put`] if the token appeared, [`SampleKind::Delete`] if it disappeared.
+    pub fn kind(&self) -> SampleKind {
+        self.kind
+    }
+
+    /// The key expressions of the tokens alive after this change.
+    pub fn alive(&self) -> &HashSet<OwnedKeyExpr> {
+        &self.alive
+    }
+}
+
+#[zenoh_macros::unstable]
+impl CallbackParameter for LivelinessGroupChange {
+    type Message<'a> = Self;
+
+    fn from_message(msg: Self::Message<'_>) -> Self {
+        msg
+    }
+}
+
+/// A view of the liveliness tokens alive on a key expression.
+///
+/// The view is built on a liveliness subscriber with [`history`](LivelinessSubscriberBuilder::history)
+/// enabled: the tokens alive when the view is declared and the later changes are delivered by the
+/// same subscription, so the initial state is reconciled with live updates without gaps. A change is
+/// only notified when it modifies the set of alive tokens, so a token reported twice is notified once.
+///
+/// Notifications are delivered while the set of alive tokens is locked: a callback must not call
+/// [`alive`](LivelinessGroupView::alive) or [`count`](LivelinessGroupView::count) on its own view.
+///
+/// # Examples
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() {
+/// use zenoh::sample::SampleKind;
+///
+/// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+/// let view = session.liveliness().declare_group_view("service/x/*").await.unwrap();
+/// while let Ok(change) = view.recv_async().await {
+///     match change.kind() {
+///         SampleKind::Put => println!("New instance: {}", change.key_expr()),
+///         SampleKind::Delete => println!("Lost instance: {}", change.key_expr()),
+///     }
+/// }
+/// # }
+/// ```
+#[zenoh_macros::unstable]
+pub struct LivelinessGroupView<Handler> {
+    pub(crate) subscriber: Subscriber<()>,
+    pub(crate) alive: Arc<Mutex<HashSet<OwnedKeyExpr>>>,
+    pub(crate) handler: Handler,
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> fmt::Debug for LivelinessGroupView<Handler> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LivelinessGroupView")
+            .field("key_expr", self.subscriber.key_expr())
+            .field("alive", &self.alive)
+            .finish()
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> LivelinessGroupView<Handler> {
+    /// Returns the key expression of the group.
+    pub fn key_expr(&self) -> &KeyExpr<'static> {
+        self.subscriber.key_expr()
+    }
+
+    /// Returns the key expressions of the currently alive tokens.
+    pub fn alive(&self) -> HashSet<OwnedKeyExpr> {
+        zlock!(self.alive).clone()
+    }
+
+    /// Returns the number of currently alive tokens.
+    pub fn count(&self) -> usize {
+        zlock!(self.alive).len()
+    }
+
+    /// Returns a reference to this view's handler.
+    /// A handler is anything that implements [`IntoHandler`](crate::handlers::IntoHandler).
+    /// The default handler is [`DefaultHandler`](crate::handlers::DefaultHandler).
+    pub fn handler(&self) -> &Handler {
+        &self.handler
+    }
+
+    /// Returns a mutable reference to this view's handler.
+    /// A handler is anything that implements [`IntoHandler`](crate::handlers::IntoHandler).
+    /// The default handler is [`DefaultHandler`](crate::handlers::DefaultHandler).
+    pub fn handler_mut(&mut self) -> &mut Handler {
+        &mut self.handler
+    }
+
+    /// Undeclare the view and its underlying liveliness subscriber.
+    #[inline]
+    pub fn undeclare(self) -> impl Resolve<ZResult<()>> {
+        self.subscriber.undeclare()
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> std::ops::Deref for LivelinessGroupView<Handler> {
+    type Target = Handler;
+
+    fn deref(&self) -> &Self::Target {
+        &self.handler
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> std::ops::DerefMut for LivelinessGroupView<Handler> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.handler
+    }
+}
+
 /// A token whose liveliness is tied to the Zenoh [`Session`](Session).
 ///
 /// A declared liveliness token will be seen as alive by any other Zenoh
@@ -120,4 +120,17 @@ pub(crate) fn start_plugins(runtime: &Runtime) {
         }
         tracing::info!("Finished loading plugins");
     }
+    update_ingress_validators(runtime, &manager);
+}
+
+/// Registers the ingress validators of the started plugins into the routing tables.
+pub(crate) fn update_ingress_validators(runtime: &Runtime, manager: &PluginsManager) {
+    let validators = manager
+        .started_plugins_iter()
+        .filter_map(|plugin| {
+            let validator = plugin.instance().ingress_validator()?;
+            Some((plugin.id().to_string(), validator))
+        })
+        .collect();
+    runtime.router().tables.set_ingress_validators(validators);
 }
@@ -15,15 +15,22 @@
 pub(crate) type Id = u32;
 
 pub(crate) mod admin;
+#[cfg(feature = "unstable")]
+pub(crate) mod attachment_map;
 pub(crate) mod builders;
 pub(crate) mod bytes;
 pub(crate) mod cancellation;
 pub(crate) mod config;
 pub(crate) mod connectivity;
+#[cfg(feature = "unstable")]
+pub(crate) mod deduplication;
+pub(crate) mod diagnostics;
 pub(crate) mod encoding;
 pub(crate) mod handlers;
 pub(crate) mod info;
 pub(crate) mod key_expr;
+#[cfg(feature = "unstable")]
+pub(crate) mod latency;
 pub(crate) mod liveliness;
 #[cfg(feature = "plugins")]
 pub(crate) mod loader;
@@ -14,14 +14,32 @@
 
 //! `zenohd`'s plugin system. For more details, consult the [detailed documentation](https://github.com/eclipse-zenoh/roadmap/blob/main/rfcs/ALL/Plugins/Zenoh%20Plugins.md).
 
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
 use serde_json::Value;
-use zenoh_core::zconfigurable;
+use zenoh_config::ZenohId;
+use zenoh_core::{zconfigurable, zlock, Wait};
 use zenoh_plugin_trait::{Plugin, PluginControl, PluginInstance, PluginReport, PluginStatusRec};
 use zenoh_protocol::core::key_expr::keyexpr;
 use zenoh_result::ZResult;
+use zenoh_transport::TransportManager;
 use zenoh_util::ffi::{JsonKeyValueMap, JsonValue};
 
-use crate::{api::key_expr::KeyExpr, net::runtime::DynamicRuntime};
+use crate::{
+    api::{
+        bytes::ZBytes,
+        encoding::Encoding,
+        key_expr::KeyExpr,
+        queryable::{Query, Queryable},
+        sample::{Sample, SampleKind},
+        session::Session,
+        subscriber::Subscriber,
+    },
+    net::runtime::DynamicRuntime,
+};
 
 zconfigurable! {
     pub static ref PLUGIN_PREFIX: String = "zenoh_plugin_".to_string();
@@ -61,6 +79,106 @@ impl Response {
     }
 }
 
+/// The kind of message submitted to the [`IngressValidator`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IngressMessage {
+    /// A publication of a value.
+    Put,
+    /// A publication of a deletion, whose payload is empty.
+    Delete,
+    /// A query, whose payload is the optional query payload.
+    Query,
+    /// A reply to a query, of the given kind. The payload of a [`SampleKind::Delete`] reply is empty.
+    Reply(SampleKind),
+}
+
+/// A message received from a remote node, submitted to the [`IngressValidator`]s before being routed.
+#[derive(Debug)]
+pub struct IngressSample<'a> {
+    kind: IngressMessage,
+    key_expr: &'a KeyExpr<'a>,
+    payload: &'a ZBytes,
+    encoding: &'a Encoding,
+    attachment: Option<&'a ZBytes>,
+    source: ZenohId,
+}
+
+impl<'a> IngressSample<'a> {
+    pub(crate) fn new(
+        kind: IngressMessage,
+        key_expr: &'a KeyExpr<'a>,
+        payload: &'a ZBytes,
+        encoding: &'a Encoding,
+        attachment: Option<&'a ZBytes>,
+        source: ZenohId,
+    ) -> Self {
+        Self {
+            kind,
+            key_expr,
+            payload,
+            encoding,
+            attachment,
+            source,
+        }
+    }
+
+    /// Gets the kind of the message.
+    pub fn kind(&self) -> IngressMessage {
+        self.kind
+    }
+
+    /// Gets the key expression of the message.
+    pub fn key_expr(&self) -> &KeyExpr<'a> {
+        self.key_expr
+    }
+
+    /// Gets the payload of the message.
+    pub fn payload(&self) -> &ZBytes {
+        self.payload
+    }
+
+    /// Gets the encoding of the payload.
+    pub fn encoding(&self) -> &Encoding {
+        self.encoding
+    }
+
+    /// Gets the attachment of the message.
+    pub fn attachment(&self) -> Option<&ZBytes> {
+        self.attachment
+    }
+
+    /// Gets the [`ZenohId`] of the node the message was received from.
+    pub fn source(&self) -> ZenohId {
+        self.source
+    }
+}
+
+/// The verdict of an [`IngressValidator`] on an [`IngressSample`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IngressVerdict {
+    /// Route the message.
+    Accept,
+    /// Drop the message.
+    ///
+    /// If a reason is given, the rejection is logged as a negative acknowledgment: the first
+    /// rejection of a validator is logged as a warning, and the following ones at debug level.
+    Reject(Option<String>),
+}
+
+/// A validator of the messages received from remote nodes, registered by a plugin through
+/// [`RunningPluginTrait::ingress_validator`].
+///
+/// The puts, deletes, queries and replies received over unicast and multicast transports are
+/// submitted to the validators. The messages rejected by any validator are dropped before being
+/// routed and counted in the admin space under `@/<zid>/<whatami>/ingress_validators`.
+pub trait IngressValidator: Send + Sync {
+    /// Decides whether the message should be routed.
+    ///
+    /// This function is called on the routing path of every message received from a remote
+    /// node: it should not block.
+    fn validate(&self, sample: &IngressSample<'_>) -> IngressVerdict;
+}
+
 pub trait RunningPluginTrait: Send + Sync + PluginControl {
     /// Function that will be called when the configuration relevant to the plugin is about to change.
     ///
@@ -111,7 +229,200 @@ pub trait RunningPluginTrait: Send + Sync + PluginControl {
     ) -> ZResult<Vec<Response>> {
         Ok(Vec::new())
     }
+    /// Used to request an operation from the plugin through the administration space.
+    /// Function called on any PUT on the admin space that matches this plugin's sub-part of the admin space,
+    /// provided that `adminspace.permissions.write` is enabled.
+    /// Parameters:
+    /// * `key_expr`: the key_expr of the PUT, for example "@/ROUTER_ID/router/status/plugins/PLUGIN_NAME/some/operation".
+    /// * `plugin_status_key`: the actual path to the plugin's status in the admin space. For example "@/ROUTER_ID/router/plugins/PLUGIN_NAME"
+    ///   Return value:
+    /// * `Ok(Vec<Response>)`: the results of the operations performed, if any. As a PUT can't be replied to,
+    ///   the admin space only logs them.
+    /// * `Err(ZError)`: A problem occurred when processing the PUT.
+    ///
+    /// Contrary to [RunningPluginTrait::adminspace_getter], this function may have side effects.
+    fn adminspace_putter<'a>(
+        &'a self,
+        _key_expr: &'a KeyExpr<'a>,
+        _plugin_status_key: &str,
+    ) -> ZResult<Vec<Response>> {
+        Ok(Vec::new())
+    }
+    /// Returns the [`IngressValidator`] this plugin registers into the runtime, if any.
+    ///
+    /// This function is called once the plugin is started, and its validator applies to the
+    /// messages received from remote nodes until the plugin is stopped.
+    fn ingress_validator(&self) -> Option<Arc<dyn IngressValidator>> {
+        None
+    }
 }
 
 /// The zenoh plugins manager. It handles the full lifetime of plugins, from loading to destruction.
 pub type PluginsManager = zenoh_plugin_trait::PluginsManager<DynamicRuntime, RunningPlugin>;
+
+/// The environment of a running plugin, declaring entities on the plugin's behalf.
+///
+/// The subscribers and queryables declared through a `PluginEnv` are tracked per plugin by the
+/// runtime, and undeclared when the plugin is stopped with
+/// [`Runtime::stop_plugin`](crate::net::runtime::Runtime::stop_plugin) or when the runtime is
+/// closed. Their callbacks, which may be code of a dynamically loaded plugin, are thus dropped
+/// before the plugin library is released. They are listed in the admin space under
+/// `@/<zid>/<whatami>/plugin_declarations/<plugin>`.
+pub struct PluginEnv {
+    plugin: String,
+    session: Session,
+    declarations: Arc<PluginDeclarations>,
+}
+
+impl PluginEnv {
+    /// Creates the environment of the plugin `name`, with a session sharing the given runtime.
+    ///
+    /// The session is closed when the plugin stops.
+    pub fn new(name: &str, runtime: &DynamicRuntime) -> ZResult<Self> {
+        let session = crate::api::builders::session::init(runtime.clone()).wait()?;
+        let declarations = runtime.plugin_declarations();
+        declarations.insert(name, PluginEntity::Session(session.clone()));
+        Ok(Self {
+            plugin: name.to_string(),
+            session,
+            declarations,
+        })
+    }
+
+    /// Gets the session this environment declares entities with.
+    ///
+    /// The entities declared directly on this session are not tracked.
+    pub fn session(&self) -> &Session {
+        &self.session
+    }
+
+    /// Declares a subscriber calling `callback` on each received sample, until the plugin stops.
+    pub fn declare_subscriber<'a, TryIntoKeyExpr, F>(
+        &self,
+        key_expr: TryIntoKeyExpr,
+        callback: F,
+    ) -> ZResult<()>
+    where
+        TryIntoKeyExpr: TryInto<KeyExpr<'a>>,
+        <TryIntoKeyExpr as TryInto<KeyExpr<'a>>>::Error: Into<zenoh_result::Error>,
+        F: Fn(Sample) + Send + Sync + 'static,
+    {
+        let subscriber = self
+            .session
+            .declare_subscriber(key_expr)
+            .callback(callback)
+            .wait()?;
+        self.declarations
+            .insert(&self.plugin, PluginEntity::Subscriber(subscriber));
+        Ok(())
+    }
+
+    /// Declares a queryable calling `callback` on each received query, until the plugin stops.
+    pub fn declare_queryable<'a, TryIntoKeyExpr, F>(
+        &self,
+        key_expr: TryIntoKeyExpr,
+        callback: F,
+    ) -> ZResult<()>
+    where
+        TryIntoKeyExpr: TryInto<KeyExpr<'a>>,
+        <TryIntoKeyExpr as TryInto<KeyExpr<'a>>>::Error: Into<zenoh_result::Error>,
+        F: Fn(Query) + Send + Sync + 'static,
+    {
+        let queryable = self
+            .session
+            .declare_queryable(key_expr)
+            .callback(callback)
+            .wait()?;
+        self.declarations
+            .insert(&self.plugin, PluginEntity::Queryable(queryable));
+        Ok(())
+    }
+}
+
+enum PluginEntity {
+    Session(Session),
+    Subscriber(Subscriber<()>),
+    Queryable(Queryable<()>),
+}
+
+impl PluginEntity {
+    async fn undeclare(self) -> ZResult<()> {
+        match self {
+            PluginEntity::Session(session) => session.close().await,
+            PluginEntity::Subscriber(subscriber) => subscriber.undeclare().await,
+            PluginEntity::Queryable(queryable) => queryable.undeclare().await,
+        }
+    }
+}
+
+/// The entities declared by each plugin through its [`PluginEnv`].
+#[derive(Default)]
+pub(crate) struct PluginDeclarations(Mutex<HashMap<String, Vec<PluginEntity>>>);
+
+impl PluginDeclarations {
+    fn insert(&self, plugin: &str, entity: PluginEntity) {
+        zlock!(self.0)
+            .entry(plugin.to_string())
+            .or_default()
+            .push(entity);
+    }
+
+    /// Undeclares the entities declared by `plugin`.
+    ///
+    /// Each undeclaration waits for the running callbacks of the entity to return, so none of
+    /// the plugin's callbacks is running or called anymore once this function returns. The
+    /// transmission pipelines of `manager` are then flushed, so that the undeclarations are
+    /// written on the links before the plugin is stopped.
+    pub(crate) async fn undeclare(&self, plugin: &str, manager: &TransportManager) {
+        let entities = zlock!(self.0).remove(plugin).unwrap_or_default();
+        if entities.is_empty() {
+            return;
+        }
+        Self::undeclare_entities(plugin, entities).await;
+        if !manager.flush(manager.config.wait_before_close).await {
+            tracing::warn!("Timed out flushing the undeclarations of plugin `{plugin}`");
+        }
+    }
+
+    /// Undeclares the entities declared by all plugins.
+    pub(crate) async fn undeclare_all(&self) {
+        let declarations = std::mem::take(&mut *zlock!(self.0));
+        for (plugin, entities) in declarations {
+            Self::undeclare_entities(&plugin, entities).await;
+        }
+    }
+
+    async fn undeclare_entities(plugin: &str, mut entities: Vec<PluginEntity>) {
+        tracing::debug!("Undeclaring the entities of plugin `{plugin}`");
+        // The sessions are closed last, once their entities are undeclared
+        entities.sort_by_key(|entity| matches!(entity, PluginEntity::Session(_)));
+        for entity in entities {
+            if let Err(e) = entity.undeclare().await {
+                tracing::warn!("Failed to undeclare an entity of plugin `{plugin}`: {e}");
+            }
+        }
+    }
+
+    /// Returns the key expressions of the subscribers and queryables declared by each plugin.
+    pub(crate) fn snapshot(&self) -> Vec<(String, Vec<KeyExpr<'static>>, Vec<KeyExpr<'static>>)> {
+        zlock!(self.0)
+            .iter()
+            .map(|(plugin, entities)| {
+                let mut subscribers = Vec::new();
+                let mut queryables = Vec::new();
+                for entity in entities {
+                    match entity {
+                        PluginEntity::Session(_) => {}
+                        PluginEntity::Subscriber(subscriber) => {
+                            subscribers.push(subscriber.key_expr().clone())
+                        }
+                        PluginEntity::Queryable(queryable) => {
+                            queryables.push(queryable.inner.key_expr.clone())
+                        }
+                    }
+                }
+                (plugin.clone(), subscribers, queryables)
+            })
+            .collect()
+    }
+}
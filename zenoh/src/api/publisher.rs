@@ -12,6 +12,8 @@
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
 
+#[cfg(feature = "unstable")]
+use std::time::Duration;
 use std::{
     collections::HashSet,
     convert::TryFrom,
@@ -23,7 +25,7 @@ use std::{
 };
 
 use futures::Sink;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use tracing::error;
 use zenoh_config::qos::PriorityConf;
 use zenoh_core::{Resolvable, Resolve, Wait};
@@ -31,8 +33,16 @@ use zenoh_protocol::core::CongestionControl;
 use zenoh_result::{Error, ZResult};
 #[cfg(feature = "unstable")]
 use {
-    zenoh_config::wrappers::EntityGlobalId, zenoh_protocol::core::EntityGlobalIdProto,
+    crate::api::builders::publisher::{
+        PublisherSuffix, PublisherSuffixedDeleteBuilder, PublisherSuffixedPutBuilder,
+    },
+    crate::api::latency::{EnqueueLatencyTracker, LatencyHistogram, LatencySnapshot},
+    zenoh_config::wrappers::EntityGlobalId,
+    zenoh_core::zlock,
+    zenoh_keyexpr::keyexpr,
+    zenoh_protocol::core::EntityGlobalIdProto,
     zenoh_protocol::core::Reliability,
+    zenoh_result::bail,
 };
 
 use crate::api::{
@@ -119,6 +129,12 @@ pub struct Publisher<'a> {
     pub(crate) destination: Locality,
     #[cfg(feature = "unstable")]
     pub(crate) reliability: Reliability,
+    #[cfg(feature = "unstable")]
+    pub(crate) block_timeout: Duration,
+    #[cfg(feature = "unstable")]
+    pub(crate) latency: Option<EnqueueLatencyTracker>,
+    #[cfg(feature = "unstable")]
+    pub(crate) suffix_base: Mutex<Option<KeyExpr<'static>>>,
     pub(crate) matching_listeners: Arc<Mutex<HashSet<Id>>>,
     pub(crate) undeclare_on_drop: bool,
     pub(crate) sync_group: SyncGroup,
@@ -238,6 +254,73 @@ impl<'a> Publisher<'a> {
         self.reliability
     }
 
+    /// Enable or disable the tracking of the publication latencies of this Publisher.
+    ///
+    /// When enabled, the enqueue latency of each publication is recorded into an
+    /// [`HdrLatencyHistogram`](crate::pubsub::HdrLatencyHistogram): the duration from the call to
+    /// `put` or `delete` until the message is enqueued into the transmission pipelines of the
+    /// transports. The time spent in the transmission pipelines and the write of the message to
+    /// the sockets are not included. Disabling the tracking discards the recorded latencies.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+    /// let publisher = session.declare_publisher("key/expression")
+    ///     .await
+    ///     .unwrap()
+    ///     .with_latency_tracking(true);
+    /// publisher.put("value").await.unwrap();
+    /// let snapshot = publisher.latency_snapshot().unwrap();
+    /// assert_eq!(snapshot.count(), 1);
+    /// # }
+    /// ```
+    #[zenoh_macros::unstable]
+    #[cfg(feature = "latency_histogram")]
+    pub fn with_latency_tracking(mut self, enabled: bool) -> Self {
+        self.latency = enabled
+            .then(|| EnqueueLatencyTracker::new(crate::api::latency::HdrLatencyHistogram::new()));
+        self
+    }
+
+    /// Track the publication latencies of this Publisher with a custom [`LatencyHistogram`].
+    ///
+    /// See `Publisher::with_latency_tracking`, available with the `latency_histogram` feature,
+    /// for the default histogram.
+    #[zenoh_macros::unstable]
+    pub fn with_latency_histogram<H: LatencyHistogram + 'static>(mut self, histogram: H) -> Self {
+        self.latency = Some(EnqueueLatencyTracker::new(histogram));
+        self
+    }
+
+    /// Returns the percentiles of the recorded publication latencies.
+    ///
+    /// The snapshot covers the publications since latency tracking was enabled or since the last
+    /// [`reset_latency`](Publisher::reset_latency). Returns `None` if latency tracking is disabled.
+    #[zenoh_macros::unstable]
+    pub fn latency_snapshot(&self) -> Option<LatencySnapshot> {
+        self.latency.as_ref().map(EnqueueLatencyTracker::snapshot)
+    }
+
+    /// Discards the publication latencies recorded so far. Does nothing if latency tracking is disabled.
+    #[zenoh_macros::unstable]
+    pub fn reset_latency(&self) {
+        if let Some(latency) = &self.latency {
+            latency.reset();
+        }
+    }
+
+    /// Runs `enqueue`, recording its duration if latency tracking is enabled.
+    #[inline]
+    pub(crate) fn track_enqueue_latency<T>(&self, enqueue: impl FnOnce() -> T) -> T {
+        #[cfg(feature = "unstable")]
+        if let Some(latency) = &self.latency {
+            return latency.track(enqueue);
+        }
+        enqueue()
+    }
+
     /// Publish the data. The subscribers matching the Publisher's key expression will receive the
     /// [`Sample`] with [`kind`](crate::sample::Sample::kind) [SampleKind::Put](crate::sample::SampleKind::Put).
     ///
@@ -264,6 +347,8 @@ impl<'a> Publisher<'a> {
             kind: PublicationBuilderPut {
                 payload: payload.into(),
                 encoding: self.encoding.clone(),
+                #[cfg(feature = "unstable")]
+                parameters: None,
             },
             timestamp: None,
             #[cfg(feature = "unstable")]
@@ -303,6 +388,135 @@ impl<'a> Publisher<'a> {
         }
     }
 
+    /// Publish the data to a sub-key of the Publisher's key expression.
+    ///
+    /// The data is published on the Publisher's key expression followed by `suffix`, or, for a
+    /// Publisher declared on `prefix/**`, on `prefix` followed by `suffix`. The key expression is
+    /// sent as the resource declared for the Publisher plus `suffix`, which saves the cost of
+    /// a declaration per sub-key. The publication uses the Publisher's configuration, as
+    /// [`Publisher::put`] does.
+    ///
+    /// Resolving the builder fails if `suffix` contains wildcards, or if the Publisher's key
+    /// expression contains wildcards other than a trailing `/**`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use zenoh::key_expr::keyexpr;
+    ///
+    /// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+    /// let publisher = session.declare_publisher("robot/1/**").await.unwrap();
+    /// // Published on "robot/1/arm/pose"
+    /// publisher
+    ///     .put_suffixed(keyexpr::new("arm/pose").unwrap(), "value")
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    #[zenoh_macros::unstable]
+    pub fn put_suffixed<'b, IntoZBytes>(
+        &'b self,
+        suffix: &'b keyexpr,
+        payload: IntoZBytes,
+    ) -> PublisherSuffixedPutBuilder<'b>
+    where
+        IntoZBytes: Into<ZBytes>,
+    {
+        PublicationBuilder {
+            publisher: PublisherSuffix {
+                publisher: self,
+                suffix,
+            },
+            kind: PublicationBuilderPut {
+                payload: payload.into(),
+                encoding: self.encoding.clone(),
+                #[cfg(feature = "unstable")]
+                parameters: None,
+            },
+            timestamp: None,
+            source_info: None,
+            attachment: None,
+            timestamp_instrumentation: None,
+        }
+    }
+
+    /// Declare that the data associated with a sub-key of the Publisher's key expression is deleted.
+    ///
+    /// The sub-key is built from `suffix` as in [`Publisher::put_suffixed`].
+    ///
+    /// # Examples
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use zenoh::key_expr::keyexpr;
+    ///
+    /// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+    /// let publisher = session.declare_publisher("robot/1/**").await.unwrap();
+    /// publisher
+    ///     .delete_suffixed(keyexpr::new("arm/pose").unwrap())
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    #[zenoh_macros::unstable]
+    pub fn delete_suffixed<'b>(
+        &'b self,
+        suffix: &'b keyexpr,
+    ) -> PublisherSuffixedDeleteBuilder<'b> {
+        PublicationBuilder {
+            publisher: PublisherSuffix {
+                publisher: self,
+                suffix,
+            },
+            kind: PublicationBuilderDelete,
+            timestamp: None,
+            source_info: None,
+            attachment: None,
+            timestamp_instrumentation: None,
+        }
+    }
+
+    /// Returns the key expression to publish `suffix` on, addressed from the Publisher's
+    /// declared resource, or from its non-wild prefix declared on first use.
+    #[cfg(feature = "unstable")]
+    pub(crate) fn suffixed_key_expr(&self, suffix: &keyexpr) -> ZResult<KeyExpr<'static>> {
+        if suffix.is_wild() {
+            bail!(
+                kind = KeyExprInvalid,
+                "Suffix '{}' of publisher '{}' contains wildcards",
+                suffix,
+                self.key_expr
+            );
+        }
+        if !self.key_expr.is_wild() {
+            return self.key_expr.join(suffix);
+        }
+        let Some(prefix) = self
+            .key_expr
+            .as_str()
+            .strip_suffix("/**")
+            .filter(|prefix| !prefix.contains('*'))
+        else {
+            bail!(
+                kind = KeyExprInvalid,
+                "Publisher '{}' cannot be suffixed: only a trailing '/**' wildcard is allowed",
+                self.key_expr
+            );
+        };
+        if let Some(base) = &*zlock!(self.suffix_base) {
+            return base.join(suffix);
+        }
+        // The prefix is declared without holding the lock. If another publication declared it
+        // in the meantime, that declaration is kept and this one is undeclared when dropped.
+        let declared = KeyExpr::try_from(prefix.to_owned())?.declare(&self.session, true)?;
+        let res = zlock!(self.suffix_base)
+            .get_or_insert_with(|| declared.clone())
+            .join(suffix);
+        drop(declared);
+        res
+    }
+
     /// Return the [`MatchingStatus`] of the publisher.
     ///
     /// [`MatchingStatus::matching`] will return true if there exist Subscribers
@@ -389,6 +603,12 @@ impl<'a> Publisher<'a> {
         for id in ids {
             self.session.undeclare_matches_listener_inner(id)?
         }
+        // The prefix is undeclared once the publications still using it are done
+        #[cfg(feature = "unstable")]
+        {
+            let suffix_base = zlock!(self.suffix_base).take();
+            drop(suffix_base);
+        }
         self.session.undeclare_publisher_inner(self.id)
     }
 
@@ -495,24 +715,28 @@ impl Sink<Sample> for Publisher<'_> {
             attachment,
             ..
         } = item.into();
-        self.session.resolve_put(
-            &self.key_expr,
-            payload,
-            kind,
-            encoding,
-            self.congestion_control,
-            self.priority,
-            self.is_express,
-            self.destination,
-            #[cfg(feature = "unstable")]
-            self.reliability,
-            None,
-            #[cfg(feature = "unstable")]
-            None,
-            attachment,
-            #[cfg(feature = "unstable")]
-            None,
-        )
+        self.track_enqueue_latency(|| {
+            self.session.resolve_put(
+                &self.key_expr,
+                payload,
+                kind,
+                encoding,
+                self.congestion_control,
+                self.priority,
+                self.is_express,
+                self.destination,
+                #[cfg(feature = "unstable")]
+                self.reliability,
+                #[cfg(feature = "unstable")]
+                self.block_timeout,
+                None,
+                #[cfg(feature = "unstable")]
+                None,
+                attachment,
+                #[cfg(feature = "unstable")]
+                None,
+            )
+        })
     }
 
     #[inline]
@@ -526,6 +750,13 @@ impl Sink<Sample> for Publisher<'_> {
     }
 }
 
+/// The default timeout applied with [`CongestionControl::BlockWithTimeout`].
+#[cfg(feature = "unstable")]
+pub(crate) const DEFAULT_BLOCK_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[zenoh_macros::unstable]
+pub use zenoh_result::BlockTimeoutError;
+
 /// Message priority.
 ///
 /// If QoS is enabled in the [`Config`](crate::config::Config) (see the boolean
@@ -533,16 +764,26 @@ impl Sink<Sample> for Publisher<'_> {
 /// Zenoh keeps one transmission queue per [`Priority`], where all messages in
 /// the queue have the same [`Priority`]. These queues are serviced in the order of their assigned
 /// [`Priority`] (i.e. from [`Priority::RealTime`] to [`Priority::Background`]).
-#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, Deserialize)]
+///
+/// Priorities are serialized with their variant names, e.g. `"DataHigh"`, and are also
+/// deserialized from their configuration names, e.g. `"data_high"`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[repr(u8)]
 pub enum Priority {
+    #[serde(alias = "real_time")]
     RealTime = 1,
+    #[serde(alias = "interactive_high")]
     InteractiveHigh = 2,
+    #[serde(alias = "interactive_low")]
     InteractiveLow = 3,
+    #[serde(alias = "data_high")]
     DataHigh = 4,
     #[default]
+    #[serde(alias = "data")]
     Data = 5,
+    #[serde(alias = "data_low")]
     DataLow = 6,
+    #[serde(alias = "background")]
     Background = 7,
 }
 
@@ -560,8 +801,74 @@ impl Priority {
     /// The number of available priorities
     #[zenoh_macros::internal]
     pub const NUM: usize = 1 + Self::MIN_ as usize - Self::MAX_ as usize;
+    /// All the priorities, from the highest to the lowest
+    #[zenoh_macros::unstable]
+    pub const ALL: [Self; 7] = [
+        Self::RealTime,
+        Self::InteractiveHigh,
+        Self::InteractiveLow,
+        Self::DataHigh,
+        Self::Data,
+        Self::DataLow,
+        Self::Background,
+    ];
+
+    /// Parse a priority from its name.
+    ///
+    /// The accepted names are the ones used in the configuration and by the [`Display`](fmt::Display)
+    /// implementation: `real_time`, `interactive_high`, `interactive_low`, `data_high`, `data`,
+    /// `data_low` and `background`.
+    #[zenoh_macros::unstable]
+    pub fn try_from_str(s: &str) -> Result<Self, InvalidPriority> {
+        Self::ALL
+            .into_iter()
+            .find(|p| p.as_str() == s)
+            .ok_or_else(|| InvalidPriority(s.to_string()))
+    }
+
+    #[cfg(feature = "unstable")]
+    const fn as_str(&self) -> &'static str {
+        match self {
+            Self::RealTime => "real_time",
+            Self::InteractiveHigh => "interactive_high",
+            Self::InteractiveLow => "interactive_low",
+            Self::DataHigh => "data_high",
+            Self::Data => "data",
+            Self::DataLow => "data_low",
+            Self::Background => "background",
+        }
+    }
+}
+
+/// Formats the priority with its configuration name, e.g. `data_high`.
+///
+/// The name can be parsed back with [`Priority::try_from_str`]. Note that this differs from the
+/// serde representation, which uses the variant name, e.g. `"DataHigh"`, to stay compatible with
+/// the already serialized values. Deserialization accepts both forms.
+#[zenoh_macros::unstable]
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The error returned when a value doesn't designate a valid [`Priority`].
+///
+/// See [`Priority::try_from_str`] and [`QoS::try_priority`](crate::qos::QoS::try_priority).
+#[zenoh_macros::unstable]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidPriority(pub(crate) String);
+
+#[zenoh_macros::unstable]
+impl fmt::Display for InvalidPriority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}' is not a valid priority", self.0)
+    }
 }
 
+#[zenoh_macros::unstable]
+impl std::error::Error for InvalidPriority {}
+
 impl TryFrom<u8> for Priority {
     type Error = zenoh_result::Error;
 
@@ -736,4 +1043,103 @@ mod tests {
         sample_kind_integrity_in_put_builder_with(SampleKind::Put);
         sample_kind_integrity_in_put_builder_with(SampleKind::Delete);
     }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn suffixed_key_expr_reuses_declaration() {
+        use zenoh_keyexpr::keyexpr;
+
+        use crate::api::session::open;
+
+        let session = open(Config::default()).wait().unwrap();
+        let suffix = keyexpr::new("arm/pose").unwrap();
+
+        let pub_ = session.declare_publisher("test/suffixed").wait().unwrap();
+        let key_expr = pub_.suffixed_key_expr(suffix).unwrap();
+        assert_eq!(key_expr.as_str(), "test/suffixed/arm/pose");
+        let wire_expr = key_expr.to_wire(&session);
+        assert_ne!(wire_expr.scope, 0);
+        assert_eq!(wire_expr.scope, pub_.key_expr.to_wire(&session).scope);
+        assert_eq!(wire_expr.suffix, "/arm/pose");
+
+        let pub_ = session
+            .declare_publisher("test/suffixed/**")
+            .wait()
+            .unwrap();
+        let key_expr = pub_.suffixed_key_expr(suffix).unwrap();
+        assert_eq!(key_expr.as_str(), "test/suffixed/arm/pose");
+        let wire_expr = key_expr.to_wire(&session);
+        assert_ne!(wire_expr.scope, 0);
+        assert_eq!(wire_expr.suffix, "/arm/pose");
+        // The prefix is declared once
+        let key_expr = pub_
+            .suffixed_key_expr(keyexpr::new("leg").unwrap())
+            .unwrap();
+        assert_eq!(key_expr.to_wire(&session).scope, wire_expr.scope);
+
+        assert!(pub_
+            .suffixed_key_expr(keyexpr::new("arm/*").unwrap())
+            .is_err());
+        let pub_ = session
+            .declare_publisher("test/*/suffixed/**")
+            .wait()
+            .unwrap();
+        assert!(pub_.suffixed_key_expr(suffix).is_err());
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn priority_str_roundtrip() {
+        use super::Priority;
+
+        let names = [
+            "real_time",
+            "interactive_high",
+            "interactive_low",
+            "data_high",
+            "data",
+            "data_low",
+            "background",
+        ];
+        assert_eq!(Priority::ALL.len(), names.len());
+        for (priority, name) in Priority::ALL.into_iter().zip(names) {
+            assert_eq!(Priority::try_from_str(name), Ok(priority));
+            assert_eq!(priority.to_string(), name);
+        }
+        // ALL goes from the highest to the lowest priority
+        assert!(Priority::ALL
+            .windows(2)
+            .all(|w| (w[0] as u8) < (w[1] as u8)));
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn priority_invalid_str() {
+        use super::Priority;
+
+        for name in ["", "control", "RealTime", "real-time", "Data"] {
+            let err = Priority::try_from_str(name).unwrap_err();
+            assert!(err.to_string().contains(name));
+        }
+    }
+
+    #[test]
+    fn priority_serde_roundtrip() {
+        use super::Priority;
+
+        let json = serde_json::to_string(&Priority::DataHigh).unwrap();
+        assert_eq!(json, "\"DataHigh\"");
+        assert_eq!(
+            serde_json::from_str::<Priority>(&json).unwrap(),
+            Priority::DataHigh
+        );
+        assert_eq!(
+            serde_json::from_str::<Priority>("\"RealTime\"").unwrap(),
+            Priority::RealTime
+        );
+        assert_eq!(
+            serde_json::from_str::<Priority>("\"real_time\"").unwrap(),
+            Priority::RealTime
+        );
+    }
 }
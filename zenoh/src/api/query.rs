@@ -17,10 +17,9 @@ use std::{collections::HashMap, error::Error, fmt::Display};
 use serde::Deserialize;
 #[cfg(feature = "unstable")]
 use zenoh_config::wrappers::EntityGlobalId;
+use zenoh_config::wrappers::ZenohId;
 use zenoh_keyexpr::OwnedKeyExpr;
-#[cfg(feature = "unstable")]
-use zenoh_protocol::core::EntityGlobalIdProto;
-use zenoh_protocol::core::Parameters;
+use zenoh_protocol::core::{EntityGlobalIdProto, Parameters};
 /// The [`Queryable`](crate::query::Queryable)s to which a query from
 /// a [`Session::get`](crate::Session::get) or a [`Querier::get`](crate::query::Querier::get)
 /// is delivered.
@@ -179,7 +178,6 @@ impl Error for ReplyError {}
 #[derive(Clone, Debug)]
 pub struct Reply {
     pub(crate) result: Result<Sample, ReplyError>,
-    #[cfg(feature = "unstable")]
     pub(crate) replier_id: Option<EntityGlobalIdProto>,
 }
 
@@ -205,12 +203,20 @@ impl Reply {
         self.replier_id.map(Into::into)
     }
 
+    /// Gets the [`ZenohId`] of the zenoh instance that answered this reply.
+    ///
+    /// The timeout errors generated by a router carry the id of the next hop that did not
+    /// answer in time. Returns `None` when the replier is unknown, e.g. for the timeout errors
+    /// generated by the querying session itself.
+    pub fn replier_zid(&self) -> Option<ZenohId> {
+        self.replier_id.map(|id| id.zid.into())
+    }
+
     /// Constructs an uninitialized empty Reply.
     #[zenoh_macros::internal]
     pub fn empty() -> Self {
         Reply {
             result: Ok(Sample::empty()),
-            #[cfg(feature = "unstable")]
             replier_id: None,
         }
     }
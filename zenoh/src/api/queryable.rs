@@ -11,6 +11,8 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
+#[cfg(feature = "unstable")]
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::{
     fmt,
     future::{IntoFuture, Ready},
@@ -91,8 +93,18 @@ impl ReplyPrimitives {
 
     pub(crate) fn send_response(&self, msg: &mut Response) {
         match self {
-            ReplyPrimitives::Local(local) => local.session.send_response(msg),
-            ReplyPrimitives::Remote(remote) => remote.primitives.send_response(msg),
+            ReplyPrimitives::Local(local) => {
+                local.session.handle_response(msg, Locality::SessionLocal)
+            }
+            ReplyPrimitives::Remote(remote) => {
+                #[cfg(all(feature = "unstable", feature = "stats"))]
+                if let Some(session) = &remote.session {
+                    session
+                        .routed_counters()
+                        .increment(msg.ext_qos.get_priority());
+                }
+                remote.primitives.send_response(msg)
+            }
         }
     }
 
@@ -182,6 +194,8 @@ pub struct Query {
     pub(crate) eid: EntityId,
     pub(crate) value: Option<(ZBytes, Encoding)>,
     pub(crate) attachment: Option<ZBytes>,
+    #[cfg(feature = "unstable")]
+    pub(crate) permit: Option<Arc<QueryPermit>>,
 }
 
 impl Query {
@@ -368,6 +382,12 @@ impl Query {
     ///
     /// This api is for internal use only.
     ///
+    /// The sample can be passed by value, by reference or as an `Arc<Sample>`, so that a cached
+    /// sample can answer many queries. Replying with a borrowed or shared sample never copies its
+    /// payload or attachment: the [`ZBytes`] of the reply alias the buffers of the cached sample,
+    /// which are reference-counted and immutable while shared. Only the key expression and the
+    /// sample metadata are cloned.
+    ///
     /// # Examples
     /// ```
     /// # use zenoh::sample::Sample;
@@ -383,10 +403,13 @@ impl Query {
     /// # }
     #[inline(always)]
     #[zenoh_macros::internal]
-    pub fn reply_sample(&self, sample: Sample) -> ReplySample<'_> {
+    pub fn reply_sample<IntoSample>(&self, sample: IntoSample) -> ReplySample<'_>
+    where
+        IntoSample: Into<Sample>,
+    {
         ReplySample {
             query: self,
-            sample,
+            sample: sample.into(),
         }
     }
 
@@ -426,6 +449,10 @@ impl Query {
     /// Sends a [`Sample`](crate::sample::Sample) of kind [`Delete`](crate::sample::SampleKind::Delete)
     /// as a reply to this Query.
     ///
+    /// This is typically used by storages to return tombstones: the querier receives a
+    /// [`Reply`](crate::query::Reply) whose sample has kind [`Delete`](crate::sample::SampleKind::Delete),
+    /// carrying the timestamp and attachment set on the returned builder.
+    ///
     /// By default, queries only accept replies whose key expression intersects with the query's.
     /// Unless the query has enabled disjoint replies (you can check this through [`Query::accepts_replies`]),
     /// replying on a disjoint key expression will result in an error when resolving the reply.
@@ -487,6 +514,8 @@ impl Query {
             eid: 0,
             value: None,
             attachment: None,
+            #[cfg(feature = "unstable")]
+            permit: None,
         }
     }
 
@@ -564,7 +593,7 @@ impl Resolvable for ReplySample<'_> {
 #[zenoh_macros::internal]
 impl Wait for ReplySample<'_> {
     fn wait(self) -> <Self as Resolvable>::To {
-        self.query._reply_sample(self.sample)
+        self.query._reply_sample(self.sample, false)
     }
 }
 
@@ -579,7 +608,10 @@ impl IntoFuture for ReplySample<'_> {
 }
 
 impl Query {
-    pub(crate) fn _reply_sample(&self, sample: Sample) -> ZResult<()> {
+    /// Sends `sample` as a reply. The priority and express policy of the sample are kept on
+    /// every hop back to the querier if `replier_qos` is set, otherwise the ones the querier
+    /// issued the query with apply.
+    pub(crate) fn _reply_sample(&self, sample: Sample, replier_qos: bool) -> ZResult<()> {
         if !self._accepts_any_replies() && !self.key_expr().intersects(&sample.key_expr) {
             bail!("Attempted to reply on `{}`, which does not intersect with query `{}`, despite query only allowing replies on matching key expressions", sample.key_expr, self.key_expr())
         }
@@ -619,6 +651,7 @@ impl Query {
                 eid: self.eid,
             }),
             ext_ts_stack: None,
+            ext_replier_qos: replier_qos.then_some(response::ext::ReplierQoS::new()),
         };
         #[cfg(feature = "unstable")]
         {
@@ -641,6 +674,73 @@ impl Query {
         Ok(())
     }
 }
+/// The payload of the error reply sent to the queries rejected by a [`Queryable`] declared with
+/// [`max_concurrent_queries`](crate::query::QueryableBuilder::max_concurrent_queries).
+#[cfg(feature = "unstable")]
+pub(crate) const QUERYABLE_BUSY: &str = "busy";
+
+/// The encoding of the [`QUERYABLE_BUSY`] error reply.
+#[cfg(feature = "unstable")]
+pub(crate) const QUERYABLE_BUSY_ENCODING: &str = "application/error";
+
+/// The load of a [`Queryable`] declared with a
+/// [`max_concurrent_queries`](crate::query::QueryableBuilder::max_concurrent_queries) limit.
+#[cfg(feature = "unstable")]
+#[derive(Debug, Default)]
+pub(crate) struct QueryableLoad {
+    in_flight: AtomicUsize,
+    shed: AtomicUsize,
+}
+
+#[cfg(feature = "unstable")]
+impl QueryableLoad {
+    pub(crate) fn shed(&self) -> usize {
+        self.shed.load(Ordering::Relaxed)
+    }
+
+    /// Wraps `callback` so that it is only called while less than `max` of the queries it received
+    /// are alive; the other queries are rejected with a [`QUERYABLE_BUSY`] error reply.
+    pub(crate) fn limit(
+        self: &Arc<Self>,
+        callback: Callback<Query>,
+        max: usize,
+    ) -> Callback<Query> {
+        let load = self.clone();
+        Callback::from(move |mut query: Query| {
+            let accepted = load
+                .in_flight
+                .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| {
+                    (n < max).then_some(n + 1)
+                })
+                .is_ok();
+            if !accepted {
+                load.shed.fetch_add(1, Ordering::Relaxed);
+                if let Err(error) = query
+                    .reply_err(QUERYABLE_BUSY)
+                    .encoding(Encoding::from(QUERYABLE_BUSY_ENCODING))
+                    .wait()
+                {
+                    tracing::debug!("Unable to reject query {}: {}", query, error);
+                }
+                return;
+            }
+            query.permit = Some(Arc::new(QueryPermit(load.clone())));
+            callback.call(query);
+        })
+    }
+}
+
+/// Counts a [`Query`] in the load of its queryable until the query and all its clones are dropped.
+#[cfg(feature = "unstable")]
+pub(crate) struct QueryPermit(Arc<QueryableLoad>);
+
+#[cfg(feature = "unstable")]
+impl Drop for QueryPermit {
+    fn drop(&mut self) {
+        self.0.in_flight.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
 pub(crate) struct QueryableState {
     pub(crate) id: Id,
     pub(crate) key_expr: KeyExpr<'static>,
@@ -784,6 +884,8 @@ pub struct Queryable<Handler> {
     pub(crate) inner: QueryableInner,
     pub(crate) handler: Handler,
     pub(crate) callback_sync_group: SyncGroup,
+    #[cfg(feature = "unstable")]
+    pub(crate) load: Option<Arc<QueryableLoad>>,
 }
 
 impl<Handler> fmt::Debug for Queryable<Handler> {
@@ -915,6 +1017,30 @@ impl<Handler> Queryable<Handler> {
     pub fn key_expr(&self) -> &KeyExpr<'static> {
         &self.inner.key_expr
     }
+
+    /// Returns the number of queries rejected by this queryable.
+    ///
+    /// Queries are rejected when the
+    /// [`max_concurrent_queries`](crate::query::QueryableBuilder::max_concurrent_queries) limit is
+    /// reached.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    ///
+    /// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+    /// let queryable = session.declare_queryable("key/expression")
+    ///     .max_concurrent_queries(8)
+    ///     .await
+    ///     .unwrap();
+    /// assert_eq!(queryable.shed_queries(), 0);
+    /// # }
+    /// ```
+    #[zenoh_macros::unstable]
+    pub fn shed_queries(&self) -> usize {
+        self.load.as_ref().map_or(0, |load| load.shed())
+    }
 }
 
 impl<Handler> Drop for Queryable<Handler> {
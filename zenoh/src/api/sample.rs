@@ -13,20 +13,40 @@
 //
 
 //! Sample primitives
-use std::{convert::TryFrom, fmt, mem};
+#[cfg(feature = "unstable")]
+use std::collections::HashMap;
+use std::{
+    convert::TryFrom,
+    fmt, mem,
+    sync::atomic::{AtomicU8, Ordering},
+};
 
 use serde::{Deserialize, Serialize};
 use zenoh_config::qos::PublisherLocalityConf;
 #[cfg(feature = "unstable")]
-use zenoh_config::wrappers::EntityGlobalId;
+use zenoh_config::{
+    qos::{CongestionControlConf, PriorityConf, ReliabilityConf},
+    wrappers::{EntityGlobalId, ZenohId},
+};
 #[cfg(feature = "unstable")]
-use zenoh_protocol::core::Reliability;
+use zenoh_protocol::core::{Parameters, Reliability};
 use zenoh_protocol::{
     core::{CongestionControl, Timestamp},
     network::{declare::ext::QoSType, push},
     zenoh::PushBody,
 };
+#[cfg(feature = "unstable")]
+use {
+    std::{
+        borrow::Cow,
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    },
+    zenoh_result::{bail, zerror, ZResult},
+};
 
+#[cfg(feature = "unstable")]
+use crate::api::publisher::InvalidPriority;
 use crate::api::{
     builders::sample::QoSBuilderTrait, bytes::ZBytes, encoding::Encoding,
     handlers::CallbackParameter, key_expr::KeyExpr, publisher::Priority,
@@ -43,15 +63,72 @@ pub type SourceSn = u32;
 /// publishers's [`allowed_destination`](crate::pubsub::PublisherBuilder::allowed_destination) and
 /// querier's [`allowed_destination`](crate::query::QuerierBuilder::allowed_destination) settings
 /// which allows to restrict the connection to only local or only remote entities.
-#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+///
+/// These settings default to [`Locality::default()`], which is [`Locality::Any`] unless overridden
+/// process-wide.
+///
+/// A received [`Sample`] also reports its origin with [`Sample::locality`], which is either
+/// [`Locality::SessionLocal`] or [`Locality::Remote`].
+///
+/// With the `clap` feature, it can be parsed as a command line argument value, e.g.
+/// `--locality session-local`.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 pub enum Locality {
     /// Request / serve data only to entities in the same session
-    SessionLocal,
+    SessionLocal = 0,
     /// Request / serve data only to remote entities (not in the same session)
-    Remote,
-    #[default]
+    Remote = 1,
     /// Request / serve data to both local and remote entities
-    Any,
+    Any = 2,
+}
+
+static DEFAULT_LOCALITY: AtomicU8 = AtomicU8::new(Locality::Any.as_u8());
+
+impl Default for Locality {
+    fn default() -> Self {
+        Locality::try_from(DEFAULT_LOCALITY.load(Ordering::Relaxed)).unwrap_or(Locality::Any)
+    }
+}
+
+/// Overrides the process-wide value returned by [`Locality::default()`].
+///
+/// The publishers, subscribers, liveliness subscribers, queryables, queriers, puts and gets
+/// declared afterwards without an explicit locality use this default. This is global state shared by every
+/// session of the process, including the ones opened by libraries: prefer setting the locality
+/// explicitly on each builder when possible. Entities declared before the call are not affected.
+#[zenoh_macros::unstable]
+pub fn set_default_locality(locality: Locality) {
+    DEFAULT_LOCALITY.store(locality.as_u8(), Ordering::Relaxed);
+}
+
+/// Gets the process-wide value returned by [`Locality::default()`].
+///
+/// It is [`Locality::Any`] unless overridden with [`set_default_locality`].
+#[zenoh_macros::unstable]
+pub fn default_locality() -> Locality {
+    Locality::default()
+}
+
+impl Locality {
+    /// Returns the stable numeric representation of this locality, suitable for persistence
+    /// or for bridging to other transports. The inverse conversion is provided by [`TryFrom<u8>`].
+    pub const fn as_u8(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl TryFrom<u8> for Locality {
+    type Error = u8;
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Locality::SessionLocal),
+            1 => Ok(Locality::Remote),
+            2 => Ok(Locality::Any),
+            _ => Err(value),
+        }
+    }
 }
 
 impl From<PublisherLocalityConf> for Locality {
@@ -91,7 +168,7 @@ impl From<Locality> for PublisherLocalityConf {
 /// The [`Advanced pub/sub`](https://docs.rs/zenoh-ext/latest/zenoh_ext/#advanced-pubsub)
 /// uses it for missing sample detection, reordering, and duplicate detection.
 #[zenoh_macros::unstable]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SourceInfo {
     pub(crate) source_id: EntityGlobalId,
     pub(crate) source_sn: SourceSn,
@@ -141,6 +218,139 @@ impl<const ID: u8> From<SourceInfo> for zenoh_protocol::zenoh::ext::SourceInfoTy
     }
 }
 
+/// Serializes as `{"source_id": "<zid>/<eid>", "source_sn": <sn>}`.
+#[zenoh_macros::unstable]
+impl Serialize for SourceInfo {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut s = serializer.serialize_struct("SourceInfo", 2)?;
+        s.serialize_field(
+            "source_id",
+            &format!("{}/{}", self.source_id.zid(), self.source_id.eid()),
+        )?;
+        s.serialize_field("source_sn", &self.source_sn)?;
+        s.end()
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<'de> Deserialize<'de> for SourceInfo {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct SourceInfoConf {
+            source_id: String,
+            source_sn: SourceSn,
+        }
+
+        let conf = SourceInfoConf::deserialize(deserializer)?;
+        let invalid = || D::Error::custom(format!("Invalid source_id: {}", conf.source_id));
+        let (zid, eid) = conf.source_id.split_once('/').ok_or_else(invalid)?;
+        let source_id = zenoh_protocol::core::EntityGlobalIdProto {
+            zid: zid.parse().map_err(|_| invalid())?,
+            eid: eid.parse().map_err(|_| invalid())?,
+        };
+        Ok(SourceInfo {
+            source_id: source_id.into(),
+            source_sn: conf.source_sn,
+        })
+    }
+}
+
+/// The status of a [`SourceInfo`] observed by a [`SourceSeqTracker`].
+#[zenoh_macros::unstable]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeqStatus {
+    /// The sequence number directly follows the last one observed for the source,
+    /// or it is the first one observed for the source.
+    InOrder,
+    /// Some sequence numbers are missing between the last one observed
+    /// for the source and this one.
+    Gap {
+        /// The number of missing sequence numbers.
+        missing: u64,
+    },
+    /// The sequence number is not newer than the last one observed for the source,
+    /// i.e. the sample is duplicated or arrived out of order.
+    Duplicate,
+    /// No source info was provided.
+    Unknown,
+}
+
+/// Detects dropped, duplicated or reordered samples from their [`SourceInfo`].
+///
+/// The tracker keeps the last sequence number observed for each source, keyed by
+/// [`SourceInfo::source_id`]. Sequence numbers are compared with wrapping arithmetic,
+/// so the tracker keeps working when a source sequence number wraps around.
+///
+/// # Examples
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() {
+/// use zenoh::sample::{SeqStatus, SourceSeqTracker};
+///
+/// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+/// let subscriber = session.declare_subscriber("key/expression").await.unwrap();
+/// let mut tracker = SourceSeqTracker::new();
+/// while let Ok(sample) = subscriber.recv_async().await {
+///     if let SeqStatus::Gap { missing } = tracker.observe(sample.source_info()) {
+///         println!("{missing} samples were lost");
+///     }
+/// }
+/// # }
+/// ```
+#[zenoh_macros::unstable]
+#[derive(Debug, Clone, Default)]
+pub struct SourceSeqTracker {
+    last_sn: HashMap<EntityGlobalId, SourceSn>,
+}
+
+#[zenoh_macros::unstable]
+impl SourceSeqTracker {
+    /// Creates a tracker without any observed source.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Observes the source info of a sample, returning its [`SeqStatus`].
+    ///
+    /// A [`SeqStatus::Duplicate`] source info leaves the tracked sequence number unchanged.
+    pub fn observe(&mut self, info: Option<&SourceInfo>) -> SeqStatus {
+        let Some(info) = info else {
+            return SeqStatus::Unknown;
+        };
+        let Some(last_sn) = self.last_sn.get_mut(&info.source_id) else {
+            self.last_sn.insert(info.source_id, info.source_sn);
+            return SeqStatus::InOrder;
+        };
+        let delta = info.source_sn.wrapping_sub(*last_sn);
+        if delta == 0 || delta > SourceSn::MAX / 2 {
+            return SeqStatus::Duplicate;
+        }
+        *last_sn = info.source_sn;
+        match delta - 1 {
+            0 => SeqStatus::InOrder,
+            missing => SeqStatus::Gap {
+                missing: missing.into(),
+            },
+        }
+    }
+
+    /// Forgets the sequence number tracked for the given source.
+    pub fn remove(&mut self, source_id: &EntityGlobalId) {
+        self.last_sn.remove(source_id);
+    }
+}
+
 /// The kind of a `Sample`.
 #[repr(u8)]
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
@@ -232,10 +442,52 @@ impl From<Sample> for SampleFields {
     }
 }
 
+/// The metadata of a [`Sample`], i.e. everything but its key expression, payload and attachment.
+///
+/// It gives storage backends a persistence contract: a sample can be stored as its key
+/// expression, its payload and its [`Sample::metadata`], and later rebuilt with
+/// [`Sample::from_payload_and_metadata`]. Like in [`SampleFields`], the QoS is flattened into
+/// its stable parts.
+///
+/// The fields depend on the enabled features, so the struct can't be built with a literal: start
+/// from [`SampleMetadata::default()`] and set the persisted fields instead.
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SampleMetadata {
+    /// The kind of the sample, see [`Sample::kind`].
+    pub kind: SampleKind,
+    /// The encoding of the sample payload, see [`Sample::encoding`].
+    pub encoding: Encoding,
+    /// The timestamp of the sample, see [`Sample::timestamp`].
+    pub timestamp: Option<Timestamp>,
+    /// The express flag of the sample QoS, see [`Sample::express`].
+    pub express: bool,
+    /// The priority of the sample QoS, see [`Sample::priority`].
+    pub priority: Priority,
+    /// The congestion control of the sample QoS, see [`Sample::congestion_control`].
+    pub congestion_control: CongestionControl,
+    /// The reliability of the sample, see [`Sample::reliability`].
+    #[cfg(feature = "unstable")]
+    pub reliability: Reliability,
+    /// The source info of the sample, see [`Sample::source_info`].
+    #[cfg(feature = "unstable")]
+    pub source_info: Option<SourceInfo>,
+}
+
 /// The `Sample` structure is the data unit received
 /// by [`Subscriber`](crate::pubsub::Subscriber) or [`Querier`](crate::query::Querier)
 /// or [`Session::get`](crate::session::Session::get).
 /// It contains the payload and all metadata associated with the data.
+///
+/// Cloning a `Sample` does not copy its payload and attachment: the clones share the same
+/// reference-counted buffers, so fanning a sample out to many consumers is cheap. Use
+/// [`Sample::deep_clone`] or [`Sample::into_owned`] to get a sample owning an independent copy
+/// of the bytes.
+///
+/// Two Samples are equal if their key expressions, payloads, kinds, encodings, timestamps, QoS,
+/// attachments and, with the `unstable` feature, reliabilities and source infos are equal.
+/// The [`locality`](Sample::locality) the sample was received from and its timestamp stack are
+/// ignored, so a Sample built locally can be compared with a received one.
 #[non_exhaustive]
 #[derive(Clone, Debug)]
 pub struct Sample {
@@ -252,8 +504,38 @@ pub struct Sample {
     pub(crate) attachment: Option<ZBytes>,
     #[cfg(feature = "unstable")]
     pub(crate) timestamp_stack: Option<crate::api::timestamp_stack::TimestampStack>,
+    pub(crate) locality: Locality,
+}
+
+impl PartialEq for Sample {
+    fn eq(&self, other: &Self) -> bool {
+        self.key_expr == other.key_expr
+            && self.payload == other.payload
+            && self.kind == other.kind
+            && self.encoding == other.encoding
+            && self.timestamp == other.timestamp
+            && self.qos == other.qos
+            && self.attachment == other.attachment
+            && self.unstable_eq(other)
+    }
+}
+
+impl Sample {
+    #[cfg(feature = "unstable")]
+    #[inline]
+    fn unstable_eq(&self, other: &Self) -> bool {
+        self.reliability == other.reliability && self.source_info == other.source_info
+    }
+
+    #[cfg(not(feature = "unstable"))]
+    #[inline]
+    fn unstable_eq(&self, _other: &Self) -> bool {
+        true
+    }
 }
 
+impl Eq for Sample {}
+
 impl Sample {
     /// Gets the key expression on which this Sample was published.
     #[inline]
@@ -273,6 +555,25 @@ impl Sample {
         &mut self.payload
     }
 
+    /// Gets the payload of this Sample as a UTF-8 string.
+    ///
+    /// Fails if the payload is not valid UTF-8. The string is borrowed from the payload
+    /// when it is contiguous, see [`ZBytes::try_to_string`].
+    #[zenoh_macros::unstable]
+    #[inline]
+    pub fn payload_as_str(&self) -> ZResult<Cow<'_, str>> {
+        Ok(self.payload.try_to_string()?)
+    }
+
+    /// Gets the payload of this Sample as bytes.
+    ///
+    /// The bytes are borrowed from the payload when it is contiguous, see [`ZBytes::to_bytes`].
+    #[zenoh_macros::unstable]
+    #[inline]
+    pub fn payload_as_bytes(&self) -> Cow<'_, [u8]> {
+        self.payload.to_bytes()
+    }
+
     /// Gets the kind of this Sample.
     #[inline]
     pub fn kind(&self) -> SampleKind {
@@ -285,12 +586,34 @@ impl Sample {
         &self.encoding
     }
 
+    /// Sets the declared encoding of this Sample, leaving its payload bytes untouched.
+    ///
+    /// The payload is not converted: this only changes how the receivers are told to interpret
+    /// it, e.g. when bridging between formats sharing the same byte representation.
+    #[zenoh_macros::unstable]
+    #[inline]
+    pub fn with_encoding(mut self, encoding: Encoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
     /// Gets the timestamp of this Sample.
     #[inline]
     pub fn timestamp(&self) -> Option<&Timestamp> {
         self.timestamp.as_ref()
     }
 
+    /// Returns whether this Sample's timestamp precedes the `other` Sample's one.
+    ///
+    /// The timestamps are compared with the total order of the Hybrid Logical Clock: by time,
+    /// then by the [`ID`](uhlc::ID) of the clock which generated them. This order is consistent
+    /// with causality between timestamps issued by HLCs, but not necessarily with wall-clock time.
+    /// Returns `Some(false)` for equal timestamps and `None` if either Sample has no timestamp.
+    #[zenoh_macros::unstable]
+    pub fn happens_before(&self, other: &Sample) -> Option<bool> {
+        Some(self.timestamp.as_ref()? < other.timestamp.as_ref()?)
+    }
+
     /// Gets the [`CongestionControl`] applied when routing the data.
     pub fn congestion_control(&self) -> CongestionControl {
         self.qos.congestion_control()
@@ -319,6 +642,32 @@ impl Sample {
         self.source_info.as_ref()
     }
 
+    /// Returns `true` if this Sample was published by the session with the given [`ZenohId`].
+    ///
+    /// The [`ZenohId`] of the [`SourceInfo::source_id`] is compared to `id`: passing the id of
+    /// the local session detects the loopback samples. Returns `false` if this Sample has no
+    /// [`SourceInfo`].
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+    /// let subscriber = session.declare_subscriber("key/expression").await.unwrap();
+    /// while let Ok(sample) = subscriber.recv_async().await {
+    ///     if !sample.is_from(&session.zid()) {
+    ///         println!("Received: {}", sample.key_expr());
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    #[zenoh_macros::unstable]
+    pub fn is_from(&self, id: &ZenohId) -> bool {
+        self.source_info
+            .as_ref()
+            .is_some_and(|info| info.source_id.zid() == *id)
+    }
+
     /// Gets the optional timestamp stack attached to this sample.
     ///
     /// The timestamp stack carries interception records (Send, Route, Receive)
@@ -335,12 +684,210 @@ impl Sample {
         self.attachment.as_ref()
     }
 
+    /// Returns `true` if the attachment of this Sample is a key-value map containing `key`.
+    ///
+    /// The attachment is expected to be a map serialized with `zenoh_ext::z_serialize`, e.g. a
+    /// `HashMap<String, String>`. Only the keys are compared, as raw bytes. Returns `false` if
+    /// there is no attachment or if it is not such a map, including a truncated map or one
+    /// followed by trailing bytes.
+    #[zenoh_macros::unstable]
+    pub fn has_attachment_key<K: AsRef<[u8]> + ?Sized>(&self, key: &K) -> bool {
+        self.attachment
+            .as_ref()
+            .and_then(crate::api::attachment_map::decode)
+            .is_some_and(|map| map.iter().any(|(k, _)| k == key.as_ref()))
+    }
+
     /// Gets a mutable reference to the optional sample attachment bytes.
     #[inline]
     pub fn attachment_mut(&mut self) -> Option<&mut ZBytes> {
         self.attachment.as_mut()
     }
 
+    /// Gets whether this Sample originates from the session which delivered it.
+    ///
+    /// Returns [`Locality::SessionLocal`] for a Sample published by the receiving session itself
+    /// and [`Locality::Remote`] for a Sample received from another session, even one running in
+    /// the same process. This is never [`Locality::Any`]. The locality is set by the session on
+    /// delivery and is not transmitted: Samples built locally report [`Locality::SessionLocal`].
+    #[inline]
+    pub fn locality(&self) -> Locality {
+        self.locality
+    }
+
+    /// Gets the selector parameters carried by this Sample.
+    ///
+    /// The parameters are set by [`Session::put_to`](crate::Session::put_to). Returns `None`
+    /// if the attachment doesn't carry parameters, e.g. for samples published with a plain
+    /// [`put`](crate::Session::put).
+    #[zenoh_macros::unstable]
+    pub fn parameters(&self) -> Option<Parameters<'static>> {
+        self.attachment
+            .as_ref()
+            .and_then(crate::api::selector::parameters_from_attachment)
+    }
+
+    /// Gets the metadata of this Sample.
+    pub fn metadata(&self) -> SampleMetadata {
+        SampleMetadata {
+            kind: self.kind,
+            encoding: self.encoding.clone(),
+            timestamp: self.timestamp,
+            express: self.qos.express(),
+            priority: self.qos.priority(),
+            congestion_control: self.qos.congestion_control(),
+            #[cfg(feature = "unstable")]
+            reliability: self.reliability,
+            #[cfg(feature = "unstable")]
+            source_info: self.source_info.clone(),
+        }
+    }
+
+    /// Constructs a Sample from its key expression, its payload and its [`SampleMetadata`].
+    ///
+    /// This is the inverse of [`Sample::metadata`]. The returned Sample has no attachment.
+    pub fn from_payload_and_metadata<IntoKeyExpr, IntoZBytes>(
+        key_expr: IntoKeyExpr,
+        payload: IntoZBytes,
+        metadata: SampleMetadata,
+    ) -> Sample
+    where
+        IntoKeyExpr: Into<KeyExpr<'static>>,
+        IntoZBytes: Into<ZBytes>,
+    {
+        Sample {
+            key_expr: key_expr.into(),
+            payload: payload.into(),
+            kind: metadata.kind,
+            encoding: metadata.encoding,
+            timestamp: metadata.timestamp,
+            qos: QoSType::new(
+                metadata.priority.into(),
+                metadata.congestion_control,
+                metadata.express,
+            )
+            .into(),
+            #[cfg(feature = "unstable")]
+            reliability: metadata.reliability,
+            #[cfg(feature = "unstable")]
+            source_info: metadata.source_info,
+            attachment: None,
+            #[cfg(feature = "unstable")]
+            timestamp_stack: None,
+            locality: Locality::SessionLocal,
+        }
+    }
+
+    /// Clones this Sample, copying its payload and attachment into new buffers.
+    ///
+    /// Unlike [`Clone::clone`], which shares the underlying buffers, the returned Sample does not
+    /// keep the buffers of this Sample alive. The copies are contiguous and always reside in
+    /// regular memory, even if the original bytes are fragmented or in shared memory. The key
+    /// expression is converted to its owned form.
+    #[zenoh_macros::unstable]
+    pub fn deep_clone(&self) -> Sample {
+        fn deep_copy(bytes: &ZBytes) -> ZBytes {
+            bytes.to_bytes().into_owned().into()
+        }
+        Sample {
+            key_expr: self.key_expr.clone().into_owned(),
+            payload: deep_copy(&self.payload),
+            attachment: self.attachment.as_ref().map(deep_copy),
+            ..self.clone()
+        }
+    }
+
+    /// Converts this Sample into one owning all its data, see [`Sample::deep_clone`].
+    ///
+    /// The returned Sample no longer keeps alive the buffers this one was received in, such as
+    /// the network buffers or a shared memory segment.
+    ///
+    /// # Examples
+    /// ```
+    /// use zenoh::{
+    ///     key_expr::KeyExpr,
+    ///     sample::{Sample, SampleBuilder},
+    /// };
+    ///
+    /// let key_expr = KeyExpr::try_from("key/expression").unwrap();
+    /// let sample: Sample = SampleBuilder::put(key_expr, "value").into();
+    /// let owned = sample.clone().into_owned();
+    /// std::thread::spawn(move || assert_eq!(owned, sample)).join().unwrap();
+    /// ```
+    #[zenoh_macros::unstable]
+    pub fn into_owned(self) -> Sample {
+        self.deep_clone()
+    }
+
+    /// Compares the content of two Samples, ignoring their metadata.
+    ///
+    /// The key expression, kind, payload, encoding and attachment are compared, while the
+    /// timestamp, QoS and source info are ignored. Payloads and attachments are compared
+    /// byte by byte, regardless of how they are split into slices. Unlike `==`, this allows
+    /// comparing an expected sample against a received one.
+    pub fn content_eq(&self, other: &Sample) -> bool {
+        self.key_expr == other.key_expr
+            && self.kind == other.kind
+            && self.payload == other.payload
+            && self.encoding == other.encoding
+            && self.attachment == other.attachment
+    }
+
+    /// Returns a hash of the content of this Sample, to be used as key by deduplication caches.
+    ///
+    /// The key expression, kind and payload are hashed, while the timestamp, source info, QoS,
+    /// encoding and attachment are ignored. Samples with equal key expressions, kinds and
+    /// payloads produce equal hashes, regardless of how their payloads are split into slices;
+    /// in particular, samples that are [`content_eq`](Sample::content_eq) hash equally.
+    ///
+    /// The hash is not guaranteed to be stable across Zenoh or Rust versions, and thus should
+    /// not be persisted nor exchanged between processes.
+    #[zenoh_macros::unstable]
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.key_expr.as_str().hash(&mut hasher);
+        (self.kind as u8).hash(&mut hasher);
+        // The slices are streamed so that the hash does not depend on the fragmentation
+        self.payload.len().hash(&mut hasher);
+        for slice in self.payload.slices() {
+            hasher.write(slice);
+        }
+        hasher.finish()
+    }
+
+    /// Constructs a put Sample, rejecting wildcard key expressions.
+    ///
+    /// Publishing on a key expression containing `*` or `**` is almost always a mistake,
+    /// so this returns an error in that case. Use [`SampleBuilder::put`](crate::sample::SampleBuilder::put)
+    /// to build a sample without this check.
+    ///
+    /// # Examples
+    /// ```
+    /// use zenoh::sample::Sample;
+    ///
+    /// assert!(Sample::try_new_concrete("demo/example", "value").is_ok());
+    /// assert!(Sample::try_new_concrete("demo/*", "value").is_err());
+    /// ```
+    #[zenoh_macros::unstable]
+    pub fn try_new_concrete<TryIntoKeyExpr, IntoZBytes>(
+        key_expr: TryIntoKeyExpr,
+        payload: IntoZBytes,
+    ) -> ZResult<Sample>
+    where
+        TryIntoKeyExpr: TryInto<KeyExpr<'static>>,
+        <TryIntoKeyExpr as TryInto<KeyExpr<'static>>>::Error: Into<zenoh_result::Error>,
+        IntoZBytes: Into<ZBytes>,
+    {
+        let key_expr: KeyExpr<'static> = key_expr.try_into().map_err(Into::into)?;
+        if key_expr.is_wild() {
+            zenoh_result::bail!(
+                kind = KeyExprInvalid,
+                "Cannot build a sample on wildcard key expression '{key_expr}'"
+            );
+        }
+        Ok(crate::api::builders::sample::SampleBuilder::put(key_expr, payload).into())
+    }
+
     /// Constructs an uninitialized empty Sample.
     #[zenoh_macros::internal]
     pub fn empty() -> Self {
@@ -358,6 +905,7 @@ impl Sample {
             attachment: None,
             #[cfg(feature = "unstable")]
             timestamp_stack: None,
+            locality: Locality::SessionLocal,
         }
     }
 
@@ -369,6 +917,7 @@ impl Sample {
         #[cfg(feature = "unstable")] timestamp_stack: Option<
             zenoh_protocol::network::timestamp_stack::TimestampStack,
         >,
+        locality: Locality,
     ) -> Self {
         #[cfg(feature = "unstable")]
         let timestamp_stack = timestamp_stack
@@ -388,6 +937,7 @@ impl Sample {
                 attachment: mem::take(&mut put.ext_attachment).map(Into::into),
                 #[cfg(feature = "unstable")]
                 timestamp_stack,
+                locality,
             },
             PushBody::Del(del) => Self {
                 key_expr,
@@ -403,11 +953,29 @@ impl Sample {
                 attachment: mem::take(&mut del.ext_attachment).map(Into::into),
                 #[cfg(feature = "unstable")]
                 timestamp_stack,
+                locality,
             },
         }
     }
 }
 
+/// Clones the sample, sharing its payload and attachment buffers instead of copying them.
+#[zenoh_macros::internal]
+impl From<&Sample> for Sample {
+    fn from(sample: &Sample) -> Self {
+        sample.clone()
+    }
+}
+
+/// Unwraps the sample if it is not shared, otherwise clones it, sharing its payload and
+/// attachment buffers instead of copying them.
+#[zenoh_macros::internal]
+impl From<std::sync::Arc<Sample>> for Sample {
+    fn from(sample: std::sync::Arc<Sample>) -> Self {
+        std::sync::Arc::try_unwrap(sample).unwrap_or_else(|sample| (*sample).clone())
+    }
+}
+
 impl CallbackParameter for Sample {
     #[cfg(feature = "unstable")]
     type Message<'a> = (
@@ -416,9 +984,15 @@ impl CallbackParameter for Sample {
         &'a mut PushBody,
         Reliability,
         Option<zenoh_protocol::network::timestamp_stack::TimestampStack>,
+        Locality,
     );
     #[cfg(not(feature = "unstable"))]
-    type Message<'a> = (KeyExpr<'static>, push::ext::QoSType, &'a mut PushBody);
+    type Message<'a> = (
+        KeyExpr<'static>,
+        push::ext::QoSType,
+        &'a mut PushBody,
+        Locality,
+    );
 
     fn from_message(msg: Self::Message<'_>) -> Self {
         Self::from_push(
@@ -429,14 +1003,25 @@ impl CallbackParameter for Sample {
             msg.3,
             #[cfg(feature = "unstable")]
             msg.4,
+            #[cfg(feature = "unstable")]
+            msg.5,
+            #[cfg(not(feature = "unstable"))]
+            msg.3,
         )
     }
 }
 
 /// Structure containing quality of service data
+///
+/// It combines the [`Priority`], the [`CongestionControl`] and the express policy applied when
+/// routing the data, and optionally the reliability to publish with. Preset constructors are
+/// provided for common combinations.
+#[zenoh_macros::unstable_doc]
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
-pub(crate) struct QoS {
+pub struct QoS {
     inner: QoSType,
+    #[cfg(feature = "unstable")]
+    reliability: Option<Reliability>,
 }
 
 #[derive(Debug)]
@@ -450,7 +1035,7 @@ impl From<QoS> for QoSBuilder {
 
 impl From<QoSType> for QoSBuilder {
     fn from(qos: QoSType) -> Self {
-        QoSBuilder(QoS { inner: qos })
+        QoSBuilder(QoS::from(qos))
     }
 }
 
@@ -464,16 +1049,16 @@ impl From<QoSBuilder> for QoS {
 impl QoSBuilderTrait for QoSBuilder {
     /// Changes the [`CongestionControl`](crate::qos::CongestionControl) to apply when routing the data.
     fn congestion_control(self, congestion_control: CongestionControl) -> Self {
-        let mut inner = self.0.inner;
-        inner.set_congestion_control(congestion_control);
-        Self(QoS { inner })
+        let mut qos = self.0;
+        qos.inner.set_congestion_control(congestion_control);
+        Self(qos)
     }
 
     /// Changes the [`Priority`](crate::qos::Priority) to apply when routing the data.
     fn priority(self, priority: Priority) -> Self {
-        let mut inner = self.0.inner;
-        inner.set_priority(priority.into());
-        Self(QoS { inner })
+        let mut qos = self.0;
+        qos.inner.set_priority(priority.into());
+        Self(qos)
     }
 
     /// Changes the Express policy to apply when routing the data.
@@ -481,13 +1066,46 @@ impl QoSBuilderTrait for QoSBuilder {
     /// When express is set to `true`, then the message will not be batched.
     /// This usually has a positive impact on latency but a negative impact on throughput.
     fn express(self, is_express: bool) -> Self {
-        let mut inner = self.0.inner;
-        inner.set_is_express(is_express);
-        Self(QoS { inner })
+        let mut qos = self.0;
+        qos.inner.set_is_express(is_express);
+        Self(qos)
     }
 }
 
 impl QoS {
+    /// Build a QoS for latency-critical data.
+    ///
+    /// Sets [`Priority::RealTime`], [`CongestionControl::Drop`] and the express policy.
+    #[zenoh_macros::unstable]
+    pub fn realtime() -> Self {
+        QoS {
+            inner: QoSType::new(Priority::RealTime.into(), CongestionControl::Drop, true),
+            reliability: None,
+        }
+    }
+
+    /// Build a QoS for data that must not be dropped.
+    ///
+    /// Sets [`Priority::Data`], [`CongestionControl::Block`] and no express policy.
+    #[zenoh_macros::unstable]
+    pub fn reliable_data() -> Self {
+        QoS {
+            inner: QoSType::new(Priority::Data.into(), CongestionControl::Block, false),
+            reliability: None,
+        }
+    }
+
+    /// Build a QoS for bulk data that gives way to any other traffic.
+    ///
+    /// Sets [`Priority::Background`], [`CongestionControl::Drop`] and no express policy.
+    #[zenoh_macros::unstable]
+    pub fn background() -> Self {
+        QoS {
+            inner: QoSType::new(Priority::Background.into(), CongestionControl::Drop, false),
+            reliability: None,
+        }
+    }
+
     /// Get the [`Priority`] of the message.
     pub fn priority(&self) -> Priority {
         match Priority::try_from(self.inner.get_priority()) {
@@ -502,6 +1120,42 @@ impl QoS {
         }
     }
 
+    /// Get the [`Priority`] of the message, failing if it is not a valid [`Priority`].
+    ///
+    /// Unlike [`QoS::priority`], which falls back to the default priority, this reports
+    /// the priorities reserved for zenoh's internal use as an error.
+    #[zenoh_macros::unstable]
+    pub fn try_priority(&self) -> Result<Priority, InvalidPriority> {
+        let priority = self.inner.get_priority();
+        Priority::try_from(priority).map_err(|_| InvalidPriority(priority.to_string()))
+    }
+
+    /// Checks that this QoS can be used to publish data.
+    ///
+    /// A QoS is rejected if its priority is reserved for zenoh's internal use, which may happen
+    /// when reusing the QoS of a received [`Sample`]. Such a priority would otherwise be silently
+    /// replaced by the default one, see [`QoS::priority`].
+    ///
+    /// The reliability, congestion control and express policy can't contradict each other, so
+    /// all their combinations are valid: the reliability selects the link the message is sent on,
+    /// the congestion control applies when the message is queued for transmission, and the express
+    /// policy only disables batching. In particular, [`CongestionControl::Block`] with express set
+    /// still blocks on full queues, and then sends the message without waiting for a batch.
+    ///
+    /// # Examples
+    /// ```
+    /// use zenoh::qos::QoS;
+    ///
+    /// assert!(QoS::realtime().validate().is_ok());
+    /// ```
+    #[zenoh_macros::unstable]
+    pub fn validate(&self) -> ZResult<()> {
+        if let Err(e) = self.try_priority() {
+            bail!(kind = QoSInvalid, "Invalid QoS: {e}");
+        }
+        Ok(())
+    }
+
     /// Get the [`CongestionControl`] applied when routing the data.
     pub fn congestion_control(&self) -> CongestionControl {
         self.inner.get_congestion_control()
@@ -511,11 +1165,82 @@ impl QoS {
     pub fn express(&self) -> bool {
         self.inner.is_express()
     }
+
+    /// Get the [`Reliability`] to publish with, if any.
+    ///
+    /// When `None`, applying this QoS to a publisher keeps the reliability of the publisher.
+    #[zenoh_macros::unstable]
+    pub fn reliability(&self) -> Option<Reliability> {
+        self.reliability
+    }
+
+    /// Sets the [`Reliability`] to publish with.
+    #[zenoh_macros::unstable]
+    pub fn with_reliability(mut self, reliability: Reliability) -> Self {
+        self.reliability = Some(reliability);
+        self
+    }
+}
+
+/// Parses a QoS from a JSON object, using the names of the `qos/publication` configuration.
+///
+/// The object may have `priority`, `congestion_control`, `express` and `reliability` fields,
+/// the missing ones taking their default value, or no value for the reliability. Unknown fields
+/// and invalid values are rejected.
+///
+/// # Examples
+/// ```
+/// use zenoh::qos::{CongestionControl, Priority, QoS, Reliability};
+///
+/// let value = serde_json::json!({
+///     "priority": "real_time",
+///     "congestion_control": "block",
+///     "reliability": "best_effort",
+/// });
+/// let qos = QoS::try_from(&value).unwrap();
+/// assert_eq!(qos.priority(), Priority::RealTime);
+/// assert_eq!(qos.congestion_control(), CongestionControl::Block);
+/// assert!(!qos.express());
+/// assert_eq!(qos.reliability(), Some(Reliability::BestEffort));
+/// ```
+#[zenoh_macros::unstable]
+impl TryFrom<&serde_json::Value> for QoS {
+    type Error = zenoh_result::Error;
+
+    fn try_from(value: &serde_json::Value) -> ZResult<Self> {
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct QoSConf {
+            priority: Option<PriorityConf>,
+            congestion_control: Option<CongestionControlConf>,
+            express: Option<bool>,
+            reliability: Option<ReliabilityConf>,
+        }
+
+        let conf = QoSConf::deserialize(value).map_err(|e| zerror!("Invalid QoS: {e}"))?;
+        let default = QoS::default();
+        Ok(QoS {
+            inner: QoSType::new(
+                conf.priority
+                    .map(Into::into)
+                    .unwrap_or_else(|| default.priority().into()),
+                conf.congestion_control
+                    .map(Into::into)
+                    .unwrap_or_else(|| default.congestion_control()),
+                conf.express.unwrap_or_else(|| default.express()),
+            ),
+            reliability: conf.reliability.map(Into::into),
+        })
+    }
 }
 
 impl From<QoSType> for QoS {
     fn from(qos: QoSType) -> Self {
-        QoS { inner: qos }
+        QoS {
+            inner: qos,
+            #[cfg(feature = "unstable")]
+            reliability: None,
+        }
     }
 }
 
@@ -524,3 +1249,636 @@ impl From<QoS> for QoSType {
         qos.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locality_u8_roundtrip() {
+        for locality in [Locality::SessionLocal, Locality::Remote, Locality::Any] {
+            assert_eq!(Locality::try_from(locality.as_u8()), Ok(locality));
+        }
+        assert_eq!(Locality::SessionLocal.as_u8(), 0);
+        assert_eq!(Locality::Remote.as_u8(), 1);
+        assert_eq!(Locality::Any.as_u8(), 2);
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn source_info_serde() {
+        use zenoh_protocol::core::{EntityGlobalIdProto, ZenohIdProto};
+
+        let zid: ZenohIdProto = "a1b2c3d4e5f6".parse().unwrap();
+        let source_info = SourceInfo::new(EntityGlobalIdProto { zid, eid: 42 }.into(), 7);
+        let json = serde_json::to_value(&source_info).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "source_id": "a1b2c3d4e5f6/42", "source_sn": 7 })
+        );
+        assert_eq!(
+            serde_json::from_value::<SourceInfo>(json).unwrap(),
+            source_info
+        );
+
+        // A missing source info serializes as null.
+        let json = serde_json::to_value(None::<SourceInfo>).unwrap();
+        assert!(json.is_null());
+        assert_eq!(
+            serde_json::from_value::<Option<SourceInfo>>(json).unwrap(),
+            None
+        );
+
+        for json in [
+            serde_json::json!({ "source_id": "a1b2c3d4e5f6", "source_sn": 7 }),
+            serde_json::json!({ "source_id": "a1b2c3d4e5f6/x", "source_sn": 7 }),
+            serde_json::json!({ "source_id": "xyz/42", "source_sn": 7 }),
+            serde_json::json!({ "source_id": "a1b2c3d4e5f6/42" }),
+        ] {
+            assert!(serde_json::from_value::<SourceInfo>(json).is_err());
+        }
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn qos_presets() {
+        let qos = QoS::realtime();
+        assert_eq!(qos.priority(), Priority::RealTime);
+        assert_eq!(qos.congestion_control(), CongestionControl::Drop);
+        assert!(qos.express());
+
+        let qos = QoS::reliable_data();
+        assert_eq!(qos.priority(), Priority::Data);
+        assert_eq!(qos.congestion_control(), CongestionControl::Block);
+        assert!(!qos.express());
+
+        let qos = QoS::background();
+        assert_eq!(qos.priority(), Priority::Background);
+        assert_eq!(qos.congestion_control(), CongestionControl::Drop);
+        assert!(!qos.express());
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn qos_try_from_json() {
+        let qos = QoS::try_from(&serde_json::json!({
+            "priority": "data_high",
+            "congestion_control": "block",
+            "express": true,
+            "reliability": "reliable",
+        }))
+        .unwrap();
+        assert_eq!(qos.priority(), Priority::DataHigh);
+        assert_eq!(qos.congestion_control(), CongestionControl::Block);
+        assert!(qos.express());
+        assert_eq!(qos.reliability(), Some(Reliability::Reliable));
+
+        assert_eq!(
+            QoS::try_from(&serde_json::json!({})).unwrap(),
+            QoS::default()
+        );
+
+        for invalid in [
+            serde_json::json!({ "priority": "urgent" }),
+            serde_json::json!({ "congestion_control": "drop", "unknown": 1 }),
+            serde_json::json!({ "express": "yes" }),
+            serde_json::json!({ "reliability": "unreliable" }),
+            serde_json::json!("real_time"),
+        ] {
+            assert!(QoS::try_from(&invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn qos_try_priority() {
+        for priority in Priority::ALL {
+            let qos = QoS::from(QoSType::new(
+                priority.into(),
+                CongestionControl::DEFAULT,
+                false,
+            ));
+            assert_eq!(qos.try_priority(), Ok(priority));
+            assert_eq!(qos.priority(), priority);
+        }
+
+        let qos = QoS::from(QoSType::new(
+            zenoh_protocol::core::Priority::Control,
+            CongestionControl::DEFAULT,
+            false,
+        ));
+        assert!(qos.try_priority().is_err());
+        // the lossy accessor falls back to the default priority
+        assert_eq!(qos.priority(), Priority::DEFAULT);
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn qos_validate() {
+        assert!(QoS::default().validate().is_ok());
+        for priority in Priority::ALL {
+            for congestion_control in [CongestionControl::Drop, CongestionControl::Block] {
+                for express in [false, true] {
+                    let qos = QoS::from(QoSType::new(priority.into(), congestion_control, express));
+                    assert!(qos.validate().is_ok(), "{qos:?}");
+                    for reliability in [Reliability::BestEffort, Reliability::Reliable] {
+                        let qos = qos.with_reliability(reliability);
+                        assert!(qos.validate().is_ok(), "{qos:?}");
+                    }
+                }
+            }
+        }
+
+        let qos = QoS::from(QoSType::new(
+            zenoh_protocol::core::Priority::Control,
+            CongestionControl::Block,
+            true,
+        ));
+        let error = qos.validate().unwrap_err();
+        assert_eq!(
+            zenoh_result::ErrKind::kind(&*error),
+            zenoh_result::ZErrorKind::QoSInvalid
+        );
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn try_new_concrete_accepts_concrete_key() {
+        let sample = Sample::try_new_concrete("test/concrete", "value").unwrap();
+        assert_eq!(sample.key_expr().as_str(), "test/concrete");
+        assert_eq!(sample.kind(), SampleKind::Put);
+        assert_eq!(sample.payload_as_str().unwrap(), "value");
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn try_new_concrete_rejects_wildcard_key() {
+        assert!(Sample::try_new_concrete("test/*", "value").is_err());
+        assert!(Sample::try_new_concrete("test/**", "value").is_err());
+        assert!(Sample::try_new_concrete("test/a$*", "value").is_err());
+        // invalid key expressions are rejected as well
+        assert!(Sample::try_new_concrete("test//invalid", "value").is_err());
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn with_encoding_keeps_payload() {
+        let sample = Sample::try_new_concrete("test/encoding", r#"{"a": 1}"#)
+            .unwrap()
+            .with_encoding(Encoding::TEXT_PLAIN);
+        let payload = sample.payload().clone();
+
+        let sample = sample.with_encoding(Encoding::APPLICATION_JSON);
+        assert_eq!(sample.encoding(), &Encoding::APPLICATION_JSON);
+        assert_eq!(sample.payload_as_bytes(), payload.to_bytes());
+        assert_eq!(sample.key_expr().as_str(), "test/encoding");
+        assert_eq!(sample.kind(), SampleKind::Put);
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn happens_before() {
+        use crate::api::builders::sample::SampleBuilder;
+
+        let sample = |timestamp: Option<(u64, u8)>| -> Sample {
+            let builder = SampleBuilder::put(KeyExpr::try_from("test/order").unwrap(), "value");
+            match timestamp {
+                Some((time, id)) => builder
+                    .timestamp(Timestamp::new(
+                        uhlc::NTP64(time),
+                        uhlc::ID::try_from([id]).unwrap(),
+                    ))
+                    .into(),
+                None => builder.into(),
+            }
+        };
+
+        let first = sample(Some((1, 2)));
+        let second = sample(Some((2, 1)));
+        assert_eq!(first.happens_before(&second), Some(true));
+        assert_eq!(second.happens_before(&first), Some(false));
+        assert_eq!(first.happens_before(&first.clone()), Some(false));
+
+        // equal times are ordered by HLC id
+        let concurrent = sample(Some((1, 3)));
+        assert_eq!(first.happens_before(&concurrent), Some(true));
+        assert_eq!(concurrent.happens_before(&first), Some(false));
+
+        let untimed = sample(None);
+        assert_eq!(untimed.happens_before(&first), None);
+        assert_eq!(first.happens_before(&untimed), None);
+        assert_eq!(untimed.happens_before(&untimed.clone()), None);
+    }
+
+    fn fragmented(chunks: &[&[u8]]) -> ZBytes {
+        let mut writer = ZBytes::writer();
+        for chunk in chunks {
+            writer.append(ZBytes::from(chunk.to_vec()));
+        }
+        writer.finish()
+    }
+
+    #[test]
+    fn sample_clone_shares_buffers() {
+        use crate::api::builders::sample::SampleBuilder;
+
+        let sample: Sample = SampleBuilder::put(
+            KeyExpr::try_from("test/clone").unwrap(),
+            fragmented(&[b"hel", b"lo"]),
+        )
+        .attachment("attachment")
+        .into();
+        let clone = sample.clone();
+        assert!(sample
+            .payload()
+            .slices()
+            .zip(clone.payload().slices())
+            .all(|(a, b)| std::ptr::eq(a, b)));
+        assert!(std::ptr::eq(
+            sample.attachment().unwrap().slices().next().unwrap(),
+            clone.attachment().unwrap().slices().next().unwrap(),
+        ));
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn sample_deep_clone_copies_buffers() {
+        use crate::api::builders::sample::SampleBuilder;
+
+        let sample: Sample = SampleBuilder::put(
+            KeyExpr::try_from("test/clone").unwrap(),
+            fragmented(&[b"hel", b"lo"]),
+        )
+        .attachment("attachment")
+        .into();
+        let copy = sample.deep_clone();
+        assert_eq!(copy, sample);
+        assert_eq!(copy.payload().slices().count(), 1);
+        let copied = copy.payload().slices().next().unwrap().as_ptr_range();
+        assert!(sample
+            .payload()
+            .slices()
+            .all(|slice| !copied.contains(&slice.as_ptr())));
+        assert!(!std::ptr::eq(
+            sample.attachment().unwrap().slices().next().unwrap(),
+            copy.attachment().unwrap().slices().next().unwrap(),
+        ));
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn sample_into_owned() {
+        use zenoh_keyexpr::keyexpr;
+
+        use crate::api::{builders::sample::SampleBuilder, key_expr::KeyExprInner};
+
+        fn assert_send_static<T: Send + 'static>(value: T) -> T {
+            value
+        }
+
+        let sample: Sample = SampleBuilder::put(
+            KeyExpr::from(keyexpr::new("test/owned").unwrap()),
+            fragmented(&[b"hel", b"lo"]),
+        )
+        .attachment(fragmented(&[b"atta", b"chment"]))
+        .into();
+        let owned = assert_send_static(sample.clone().into_owned());
+        assert_eq!(owned, sample);
+        assert!(matches!(owned.key_expr.0, KeyExprInner::Owned { .. }));
+        assert_eq!(owned.payload().slices().count(), 1);
+        assert_eq!(owned.attachment().unwrap().slices().count(), 1);
+        let copied = owned.payload().slices().next().unwrap().as_ptr_range();
+        assert!(sample
+            .payload()
+            .slices()
+            .all(|slice| !copied.contains(&slice.as_ptr())));
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn sample_metadata_roundtrip() {
+        use zenoh_protocol::core::{EntityGlobalIdProto, ZenohIdProto};
+
+        use crate::api::builders::sample::SampleBuilder;
+
+        let source_id = EntityGlobalIdProto {
+            zid: ZenohIdProto::try_from([1]).unwrap(),
+            eid: 7,
+        };
+        let sample: Sample = SampleBuilder::put(KeyExpr::try_from("test/meta").unwrap(), "value")
+            .encoding(Encoding::TEXT_PLAIN)
+            .timestamp(Timestamp::new(
+                uhlc::NTP64(42),
+                uhlc::ID::try_from([1]).unwrap(),
+            ))
+            .source_info(SourceInfo::new(source_id.into(), 3))
+            .priority(Priority::Background)
+            .congestion_control(CongestionControl::Block)
+            .express(true)
+            .reliability(Reliability::BestEffort)
+            .into();
+        let metadata = sample.metadata();
+        assert_eq!(metadata.kind, SampleKind::Put);
+        assert_eq!(metadata.priority, Priority::Background);
+        assert!(metadata.express);
+
+        let rebuilt = Sample::from_payload_and_metadata(
+            sample.key_expr().clone(),
+            sample.payload().clone(),
+            metadata,
+        );
+        assert_eq!(rebuilt, sample);
+
+        let delete: Sample = SampleBuilder::delete(KeyExpr::try_from("test/meta").unwrap()).into();
+        let rebuilt = Sample::from_payload_and_metadata(
+            delete.key_expr().clone(),
+            ZBytes::new(),
+            delete.metadata(),
+        );
+        assert_eq!(rebuilt, delete);
+    }
+
+    #[test]
+    fn sample_eq_ignores_slice_layout() {
+        use crate::api::builders::sample::SampleBuilder;
+
+        let contiguous = fragmented(&[b"hello world"]);
+        let split = fragmented(&[b"hel", b"lo w", b"orld"]);
+        assert_eq!(contiguous.slices().count(), 1);
+        assert_eq!(split.slices().count(), 3);
+
+        let key_expr = KeyExpr::try_from("test/eq").unwrap();
+        let a: Sample = SampleBuilder::put(key_expr.clone(), contiguous.clone())
+            .attachment(split.clone())
+            .into();
+        let b: Sample = SampleBuilder::put(key_expr, split)
+            .attachment(contiguous)
+            .into();
+        assert_eq!(a, b);
+        assert!(a.content_eq(&b));
+    }
+
+    #[test]
+    fn sample_eq_ignores_delivery_fields() {
+        use crate::api::builders::sample::SampleBuilder;
+
+        let key_expr = KeyExpr::try_from("test/eq").unwrap();
+        let built: Sample = SampleBuilder::put(key_expr, "value").into();
+        let mut received = built.clone();
+        received.locality = Locality::Remote;
+        assert_eq!(built, received);
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn sample_eq_compares_reliability_and_source_info() {
+        use crate::api::builders::sample::SampleBuilder;
+
+        let key_expr = KeyExpr::try_from("test/eq").unwrap();
+        let built: Sample = SampleBuilder::put(key_expr, "value").into();
+
+        let mut other = built.clone();
+        other.reliability = Reliability::BestEffort;
+        assert_ne!(built.reliability, other.reliability);
+        assert_ne!(built, other);
+
+        let mut other = built.clone();
+        other.source_info = Some(SourceInfo::new(Default::default(), 1));
+        assert_ne!(built, other);
+    }
+
+    #[test]
+    fn sample_content_eq_ignores_metadata() {
+        use crate::api::builders::sample::SampleBuilder;
+
+        let key_expr = KeyExpr::try_from("test/eq").unwrap();
+        let a: Sample = SampleBuilder::put(key_expr.clone(), "value").into();
+        let b: Sample = SampleBuilder::put(key_expr.clone(), "value")
+            .timestamp(Timestamp::new(
+                uhlc::NTP64(42),
+                uhlc::ID::try_from([1]).unwrap(),
+            ))
+            .priority(Priority::Background)
+            .into();
+        assert_ne!(a, b);
+        assert!(a.content_eq(&b));
+
+        let c: Sample = SampleBuilder::put(key_expr.clone(), "value")
+            .encoding(Encoding::TEXT_PLAIN)
+            .into();
+        assert!(!a.content_eq(&c));
+
+        let d: Sample = SampleBuilder::delete(key_expr.clone()).into();
+        assert!(!a.content_eq(&d));
+    }
+
+    #[test]
+    fn sample_content_eq_attachment_order_sensitive() {
+        use crate::api::builders::sample::SampleBuilder;
+
+        let key_expr = KeyExpr::try_from("test/eq").unwrap();
+        let a: Sample = SampleBuilder::put(key_expr.clone(), "value")
+            .attachment(fragmented(&[b"a", b"b"]))
+            .into();
+        let b: Sample = SampleBuilder::put(key_expr.clone(), "value")
+            .attachment(fragmented(&[b"b", b"a"]))
+            .into();
+        let c: Sample = SampleBuilder::put(key_expr, "value").into();
+        assert!(!a.content_eq(&b));
+        assert!(!a.content_eq(&c));
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn sample_content_hash_ignores_metadata() {
+        use crate::api::builders::sample::SampleBuilder;
+
+        let key_expr = KeyExpr::try_from("test/hash").unwrap();
+        let a: Sample = SampleBuilder::put(key_expr.clone(), "value").into();
+        let b: Sample = SampleBuilder::put(key_expr.clone(), fragmented(&[b"va", b"lue"]))
+            .timestamp(Timestamp::new(
+                uhlc::NTP64(42),
+                uhlc::ID::try_from([1]).unwrap(),
+            ))
+            .into();
+        assert_ne!(a.timestamp(), b.timestamp());
+        assert_eq!(a.content_hash(), b.content_hash());
+
+        let c: Sample = SampleBuilder::put(key_expr.clone(), "other").into();
+        assert_ne!(a.content_hash(), c.content_hash());
+
+        let d: Sample =
+            SampleBuilder::put(KeyExpr::try_from("test/other").unwrap(), "value").into();
+        assert_ne!(a.content_hash(), d.content_hash());
+
+        let e: Sample = SampleBuilder::delete(key_expr).into();
+        assert_ne!(a.content_hash(), e.content_hash());
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn source_seq_tracker() {
+        use zenoh_protocol::core::{EntityGlobalIdProto, ZenohIdProto};
+
+        let source = |eid: u32, sn: SourceSn| {
+            let id = EntityGlobalIdProto {
+                zid: ZenohIdProto::try_from([1]).unwrap(),
+                eid,
+            };
+            SourceInfo::new(id.into(), sn)
+        };
+        let mut tracker = SourceSeqTracker::new();
+
+        assert_eq!(tracker.observe(None), SeqStatus::Unknown);
+
+        // in order
+        assert_eq!(tracker.observe(Some(&source(1, 10))), SeqStatus::InOrder);
+        assert_eq!(tracker.observe(Some(&source(1, 11))), SeqStatus::InOrder);
+        assert_eq!(tracker.observe(Some(&source(1, 12))), SeqStatus::InOrder);
+
+        // gap
+        assert_eq!(
+            tracker.observe(Some(&source(1, 15))),
+            SeqStatus::Gap { missing: 2 }
+        );
+        assert_eq!(tracker.observe(Some(&source(1, 16))), SeqStatus::InOrder);
+
+        // duplicate and late samples don't move the tracked sequence number
+        assert_eq!(tracker.observe(Some(&source(1, 16))), SeqStatus::Duplicate);
+        assert_eq!(tracker.observe(Some(&source(1, 13))), SeqStatus::Duplicate);
+        assert_eq!(tracker.observe(Some(&source(1, 17))), SeqStatus::InOrder);
+
+        // sources are tracked independently
+        assert_eq!(tracker.observe(Some(&source(2, 0))), SeqStatus::InOrder);
+        assert_eq!(
+            tracker.observe(Some(&source(2, 2))),
+            SeqStatus::Gap { missing: 1 }
+        );
+        assert_eq!(tracker.observe(Some(&source(1, 18))), SeqStatus::InOrder);
+
+        // wrap around
+        assert_eq!(
+            tracker.observe(Some(&source(3, SourceSn::MAX))),
+            SeqStatus::InOrder
+        );
+        assert_eq!(tracker.observe(Some(&source(3, 0))), SeqStatus::InOrder);
+        assert_eq!(
+            tracker.observe(Some(&source(3, SourceSn::MAX))),
+            SeqStatus::Duplicate
+        );
+
+        tracker.remove(source(2, 0).source_id());
+        assert_eq!(tracker.observe(Some(&source(2, 0))), SeqStatus::InOrder);
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn is_from() {
+        use zenoh_protocol::core::{EntityGlobalIdProto, ZenohIdProto};
+
+        use crate::api::builders::sample::SampleBuilder;
+
+        let zid = ZenohIdProto::try_from([1]).unwrap();
+        let other_zid: ZenohId = ZenohIdProto::try_from([2]).unwrap().into();
+        let sample = |source_info: Option<SourceInfo>| -> Sample {
+            SampleBuilder::put(KeyExpr::try_from("test/source").unwrap(), "value")
+                .source_info(source_info)
+                .into()
+        };
+
+        let source_info = SourceInfo::new(EntityGlobalIdProto { zid, eid: 7 }.into(), 3);
+        let authored = sample(Some(source_info));
+        assert!(authored.is_from(&zid.into()));
+        assert!(!authored.is_from(&other_zid));
+        assert!(!sample(None).is_from(&zid.into()));
+    }
+
+    #[test]
+    fn locality_invalid_u8() {
+        assert_eq!(Locality::try_from(3), Err(3));
+        assert_eq!(Locality::try_from(u8::MAX), Err(u8::MAX));
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn payload_as_str_valid_utf8() {
+        use crate::api::builders::sample::SampleBuilder;
+
+        let sample: Sample =
+            SampleBuilder::put(KeyExpr::try_from("test/payload").unwrap(), "héllo").into();
+        assert_eq!(sample.payload_as_str().unwrap(), "héllo");
+        assert_eq!(sample.payload_as_bytes().as_ref(), "héllo".as_bytes());
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn payload_as_str_invalid_utf8() {
+        use crate::api::builders::sample::SampleBuilder;
+
+        let sample: Sample = SampleBuilder::put(
+            KeyExpr::try_from("test/payload").unwrap(),
+            vec![0xffu8, 0xfe],
+        )
+        .into();
+        assert!(sample.payload_as_str().is_err());
+        assert_eq!(sample.payload_as_bytes().as_ref(), &[0xff, 0xfe]);
+    }
+
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn has_attachment_key() {
+        use crate::api::builders::sample::SampleBuilder;
+
+        let key_expr = KeyExpr::try_from("test/attachment").unwrap();
+        // A map of two entries: "trace" => "1" and "source" => "arm".
+        let map = [
+            &[2, 5][..],
+            b"trace",
+            &[1],
+            b"1",
+            &[6],
+            b"source",
+            &[3],
+            b"arm",
+        ]
+        .concat();
+        let sample: Sample = SampleBuilder::put(key_expr.clone(), "a")
+            .attachment(map)
+            .into();
+        assert!(sample.has_attachment_key("trace"));
+        assert!(sample.has_attachment_key(b"source"));
+        assert!(!sample.has_attachment_key("arm"));
+        assert!(!sample.has_attachment_key("missing"));
+
+        let sample: Sample = SampleBuilder::put(key_expr.clone(), "b").into();
+        assert!(!sample.has_attachment_key("trace"));
+
+        // A count larger than the number of entries actually encoded.
+        let truncated = [&[2, 5][..], b"trace", &[1], b"1"].concat();
+        let sample: Sample = SampleBuilder::put(key_expr.clone(), "c")
+            .attachment(truncated)
+            .into();
+        assert!(!sample.has_attachment_key("trace"));
+        assert!(!sample.has_attachment_key("source"));
+
+        // A value cut short.
+        let truncated = [&[1, 5][..], b"trace", &[3], b"1"].concat();
+        let sample: Sample = SampleBuilder::put(key_expr.clone(), "d")
+            .attachment(truncated)
+            .into();
+        assert!(!sample.has_attachment_key("trace"));
+
+        // Trailing bytes after a valid map.
+        let trailing = [&[1, 5][..], b"trace", &[1], b"1", b"garbage"].concat();
+        let sample: Sample = SampleBuilder::put(key_expr.clone(), "e")
+            .attachment(trailing)
+            .into();
+        assert!(!sample.has_attachment_key("trace"));
+
+        // Garbage with an unterminated length.
+        let sample: Sample = SampleBuilder::put(key_expr, "f")
+            .attachment(vec![0xffu8; 16])
+            .into();
+        assert!(!sample.has_attachment_key("trace"));
+    }
+}
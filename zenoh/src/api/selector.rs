@@ -13,6 +13,8 @@
 //
 
 //! [Selector](https://github.com/eclipse-zenoh/roadmap/tree/main/rfcs/ALL/Selectors) to issue queries
+#[cfg(feature = "unstable")]
+use std::collections::HashSet;
 use std::{borrow::Cow, convert::TryFrom, str::FromStr};
 
 use zenoh_protocol::core::{
@@ -20,8 +22,13 @@ use zenoh_protocol::core::{
     Parameters,
 };
 #[cfg(feature = "unstable")]
-use ::{zenoh_result::ZResult, zenoh_util::time_range::TimeRange};
+use ::{
+    zenoh_result::{bail, zerror, ZResult},
+    zenoh_util::time_range::TimeRange,
+};
 
+#[cfg(feature = "unstable")]
+use crate::api::{bytes::ZBytes, encoding::Encoding};
 use crate::api::{key_expr::KeyExpr, queryable::Query};
 
 /// A selector is the combination of a [`Key Expression`](crate::key_expr::KeyExpr), which defines the
@@ -58,6 +65,8 @@ use crate::api::{key_expr::KeyExpr, queryable::Query};
 ///   whose key expression matches the query's key expression are accepted. `_anyke` disables the query-reply key expression matching check.
 ///   This parameter is set by the `accept_replies` method on query builders, such as [`SessionGetBuilder::accept_replies`](crate::session::SessionGetBuilder::accept_replies)
 ///   and [`QuerierBuilder::accept_replies`](crate::query::QuerierBuilder::accept_replies).
+/// - **`[unstable]`** `_encoding`: used in queries to express interest in only values whose encoding matches the given
+///   MIME type, such as `application/json`, or MIME type prefix followed by `*`, such as `text/*`. See [`EncodingFilter`].
 #[derive(Clone, PartialEq, Eq)]
 pub struct Selector<'a> {
     /// The part of this selector identifying which keys should be part of the selection.
@@ -109,6 +118,120 @@ impl<'a> Selector<'a> {
             self.parameters.into_owned().into_owned(),
         )
     }
+
+    /// Lowercase the names of all the parameters of this selector.
+    ///
+    /// Parameter values are left untouched. If several parameter names are lowercased into the
+    /// same name, they are collapsed into a single parameter holding the value of the first one,
+    /// consistently with [`Parameters::get`] which returns the value of the first occurrence.
+    ///
+    /// # Examples
+    /// ```
+    /// # use zenoh::query::Selector;
+    /// let selector = Selector::try_from("key/expression?Foo=1;BAR=2;foo=3").unwrap();
+    /// let selector = selector.with_lowercased_param_names();
+    /// assert_eq!(selector.parameters().as_str(), "foo=1;bar=2");
+    /// ```
+    #[zenoh_macros::unstable]
+    pub fn with_lowercased_param_names(self) -> Self {
+        let (key_expr, parameters) = self.split();
+        let mut lowercased: Vec<(String, &str)> = Vec::new();
+        for (name, value) in parameters.iter() {
+            let name = name.to_lowercase();
+            if !lowercased.iter().any(|(n, _)| *n == name) {
+                lowercased.push((name, value));
+            }
+        }
+        let parameters = lowercased.iter().collect::<Parameters>().into_owned();
+        Selector::owned(key_expr, parameters)
+    }
+
+    /// Set the `_time` parameter of this selector to the given [`TimeRange`].
+    ///
+    /// Any previous `_time` parameter is replaced, see [`ZenohParameters::set_time_range`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use zenoh::query::{Selector, TimeRange, ZenohParameters};
+    /// let time_range: TimeRange = "[now(-2s)..now(2s)]".parse().unwrap();
+    /// let selector = Selector::try_from("key/expression").unwrap().with_time_range(time_range);
+    /// assert_eq!(selector.parameters().time_range().unwrap().unwrap(), time_range);
+    /// ```
+    #[zenoh_macros::unstable]
+    pub fn with_time_range(mut self, range: TimeRange) -> Self {
+        self.parameters.to_mut().set_time_range(range);
+        self
+    }
+
+    /// Get an owned copy of the [`KeyExpr`] of this selector, along with whether it has any parameters.
+    ///
+    /// This is useful for subscribers also serving queries, which need a pure key expression
+    /// while still knowing whether the query carried parameters they may not support.
+    ///
+    /// # Examples
+    /// ```
+    /// # use zenoh::query::Selector;
+    /// let (key_expr, has_parameters) = Selector::try_from("a/b?x=1").unwrap().key_expr_only();
+    /// assert_eq!(key_expr.as_str(), "a/b");
+    /// assert!(has_parameters);
+    /// ```
+    #[zenoh_macros::unstable]
+    pub fn key_expr_only(&self) -> (KeyExpr<'static>, bool) {
+        (
+            self.key_expr.as_ref().clone().into_owned(),
+            !self.parameters.is_empty(),
+        )
+    }
+
+    /// Get a canonical string identifying this selector, suitable as a query-result cache key.
+    ///
+    /// Two selectors with the same key expression and the same parameters map to the same cache
+    /// key, whatever the order and the percent-encoding of their parameters. The parameters are
+    /// normalized with [`Parameters::reencode`], left untouched if they are malformed, then sorted
+    /// by name. Only the first occurrence of a parameter name is kept, consistently with
+    /// [`Parameters::get`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use zenoh::query::Selector;
+    /// let a = Selector::try_from("key/expression?b=2;a=1").unwrap();
+    /// let b = Selector::try_from("key/expression?a=1;%62=2").unwrap();
+    /// assert_eq!(a.cache_key(), b.cache_key());
+    /// assert_eq!(a.cache_key(), "key/expression?a=1;b=2");
+    /// ```
+    #[zenoh_macros::unstable]
+    pub fn cache_key(&self) -> String {
+        let mut parameters = self
+            .parameters
+            .reencode()
+            .unwrap_or_else(|_| self.parameters.as_ref().clone().into_owned());
+        let mut names = HashSet::new();
+        parameters.retain(|name, _| names.insert(name.to_owned()));
+        let parameters = parameters.iter_sorted().collect::<Parameters>();
+        Selector::borrowed(&self.key_expr, &parameters).to_string()
+    }
+
+    /// Get an iterator on the percent-decoded parameters of this selector.
+    ///
+    /// Each parameter is decoded lazily and independently, see [`Parameters::iter_decoded`]: a
+    /// malformed parameter yields an error, while the others are still decoded. This allows
+    /// handlers to process the valid parameters and report the invalid ones.
+    ///
+    /// # Examples
+    /// ```
+    /// # use zenoh::query::Selector;
+    /// let selector = Selector::try_from("key/expression?name=J%C3%BCrgen;id=%ZZ").unwrap();
+    /// for param in selector.iter_decoded_params() {
+    ///     match param {
+    ///         Ok((name, value)) => println!("{name}: {value}"),
+    ///         Err(e) => eprintln!("Ignoring parameter: {e}"),
+    ///     }
+    /// }
+    /// ```
+    #[zenoh_macros::unstable]
+    pub fn iter_decoded_params(&self) -> impl Iterator<Item = ZResult<(String, String)>> + '_ {
+        self.parameters.iter_decoded()
+    }
 }
 
 impl<'a, K, P> From<(K, P)> for Selector<'a>
@@ -139,6 +262,48 @@ impl<'a> From<&'a Selector<'a>> for (&'a KeyExpr<'a>, &'a Parameters<'a>) {
 pub(crate) const REPLY_KEY_EXPR_ANY_SEL_PARAM: &str = "_anyke";
 #[zenoh_macros::unstable]
 pub(crate) const TIME_RANGE_KEY: &str = "_time";
+#[zenoh_macros::unstable]
+pub(crate) const ENCODING_FILTER_KEY: &str = "_encoding";
+/// The attachment key under which [`Session::put_to`](crate::Session::put_to) stores the selector parameters.
+#[zenoh_macros::unstable]
+pub(crate) const PARAMETERS_ATTACHMENT_KEY: &str = "__params";
+
+/// Adds `parameters` under [`PARAMETERS_ATTACHMENT_KEY`] to a key-value `attachment`.
+///
+/// A missing attachment is treated as an empty map. Fails if the attachment is not a key-value
+/// map or if it already carries parameters.
+#[zenoh_macros::unstable]
+pub(crate) fn parameters_into_attachment(
+    parameters: &Parameters,
+    attachment: Option<&ZBytes>,
+) -> ZResult<ZBytes> {
+    let mut map = match attachment {
+        Some(attachment) => crate::api::attachment_map::decode(attachment).ok_or_else(|| {
+            zerror!("Selector parameters can only be added to a key-value attachment")
+        })?,
+        None => Vec::new(),
+    };
+    if map
+        .iter()
+        .any(|(k, _)| k == PARAMETERS_ATTACHMENT_KEY.as_bytes())
+    {
+        bail!("The attachment already has a `{PARAMETERS_ATTACHMENT_KEY}` key");
+    }
+    map.push((
+        PARAMETERS_ATTACHMENT_KEY.as_bytes().to_vec(),
+        parameters.as_str().as_bytes().to_vec(),
+    ));
+    Ok(crate::api::attachment_map::encode(&map))
+}
+
+/// Gets the parameters stored under [`PARAMETERS_ATTACHMENT_KEY`] in a key-value `attachment`.
+#[zenoh_macros::unstable]
+pub(crate) fn parameters_from_attachment(attachment: &ZBytes) -> Option<Parameters<'static>> {
+    let (_, parameters) = crate::api::attachment_map::decode(attachment)?
+        .into_iter()
+        .find(|(k, _)| k == PARAMETERS_ATTACHMENT_KEY.as_bytes())?;
+    Some(Parameters::from(String::from_utf8(parameters).ok()?))
+}
 
 #[zenoh_macros::unstable]
 /// The trait allows setting/reading parameters processed by the Zenoh library itself.
@@ -164,6 +329,63 @@ pub trait ZenohParameters {
     fn time_range(&self) -> Option<ZResult<TimeRange>>;
     /// Returns true if the `_anyke` parameter is present in the selector parameters
     fn reply_key_expr_any(&self) -> bool;
+    /// Sets the encoding filter targeted by the selector parameters.
+    fn set_encoding_filter<T: Into<Option<EncodingFilter>>>(&mut self, encoding_filter: T);
+    /// Extracts the standardized `_encoding` argument from the selector parameters.
+    /// Returns `None` if the `_encoding` argument is absent or empty, in which case values of any encoding are targeted.
+    fn encoding_filter(&self) -> Option<EncodingFilter>;
+}
+
+/// The filter on value encodings expressed by the `_encoding` selector parameter.
+///
+/// The filter is either a MIME type, such as `application/json`, matching the encodings with this MIME type
+/// whatever their schema, or a prefix followed by `*`, such as `text/*`, matching the encodings starting with
+/// this prefix. The comparison is ASCII case-insensitive.
+///
+/// # Examples
+/// ```
+/// use zenoh::{bytes::Encoding, query::{EncodingFilter, Parameters, ZenohParameters}};
+///
+/// let filter = Parameters::from("_encoding=text/*").encoding_filter().unwrap();
+/// assert!(filter.matches(&Encoding::TEXT_PLAIN));
+/// assert!(!filter.matches(&Encoding::APPLICATION_JSON));
+/// ```
+#[zenoh_macros::unstable]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodingFilter(String);
+
+#[zenoh_macros::unstable]
+impl EncodingFilter {
+    /// Creates a filter from a MIME type or a MIME type prefix followed by `*`.
+    pub fn new<S: Into<String>>(filter: S) -> Self {
+        Self(filter.into())
+    }
+
+    /// Returns the filter as passed in the `_encoding` parameter.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns true if values with the given encoding pass this filter.
+    pub fn matches(&self, encoding: &Encoding) -> bool {
+        let encoding = Cow::from(encoding);
+        match self.0.strip_suffix('*') {
+            Some(prefix) => encoding
+                .get(..prefix.len())
+                .is_some_and(|head| head.eq_ignore_ascii_case(prefix)),
+            None => {
+                let mime = encoding.split(';').next().unwrap_or_default();
+                mime.eq_ignore_ascii_case(&self.0) || encoding.eq_ignore_ascii_case(&self.0)
+            }
+        }
+    }
+}
+
+#[zenoh_macros::unstable]
+impl std::fmt::Display for EncodingFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
 }
 
 #[cfg(feature = "unstable")]
@@ -195,6 +417,21 @@ impl ZenohParameters for Parameters<'_> {
     fn reply_key_expr_any(&self) -> bool {
         self.contains_key(REPLY_KEY_EXPR_ANY_SEL_PARAM)
     }
+
+    /// Sets the encoding filter targeted by the selector parameters.
+    fn set_encoding_filter<T: Into<Option<EncodingFilter>>>(&mut self, encoding_filter: T) {
+        match encoding_filter.into() {
+            Some(filter) => self.insert(ENCODING_FILTER_KEY, filter.0),
+            None => self.remove(ENCODING_FILTER_KEY),
+        };
+    }
+
+    /// Extracts the standardized `_encoding` argument from the selector parameters.
+    fn encoding_filter(&self) -> Option<EncodingFilter> {
+        self.get(ENCODING_FILTER_KEY)
+            .filter(|filter| !filter.is_empty())
+            .map(EncodingFilter::new)
+    }
 }
 
 impl std::fmt::Debug for Selector<'_> {
@@ -385,3 +622,118 @@ fn selector_accessors() {
         );
     }
 }
+
+#[cfg(feature = "unstable")]
+#[test]
+fn selector_lowercased_param_names() {
+    let selector = Selector::try_from("key/expression?Foo=1;BAR=2").unwrap();
+    let selector = selector.with_lowercased_param_names();
+    assert_eq!(selector.key_expr().as_str(), "key/expression");
+    assert_eq!(selector.parameters().get("foo"), Some("1"));
+    assert_eq!(selector.parameters().get("bar"), Some("2"));
+    assert_eq!(selector.parameters().get("Foo"), None);
+    assert_eq!(selector.parameters().as_str(), "foo=1;bar=2");
+}
+
+#[cfg(feature = "unstable")]
+#[test]
+fn selector_lowercased_param_names_collapse_duplicates() {
+    let selector = Selector::try_from("key/expression?Foo=1;FOO=2;foo=3;Val=AbC").unwrap();
+    let selector = selector.with_lowercased_param_names();
+    assert_eq!(selector.parameters().as_str(), "foo=1;val=AbC");
+}
+
+#[cfg(feature = "unstable")]
+#[test]
+fn selector_with_time_range() {
+    let time_range: TimeRange = "[now(-2s)..now(2s)]".parse().unwrap();
+    let selector = Selector::try_from("key/expression?_time=[..];foo=bar")
+        .unwrap()
+        .with_time_range(time_range);
+    assert_eq!(selector.key_expr().as_str(), "key/expression");
+    assert_eq!(
+        selector.parameters().time_range().unwrap().unwrap(),
+        time_range
+    );
+    assert_eq!(selector.parameters().get("foo"), Some("bar"));
+}
+
+#[cfg(feature = "unstable")]
+#[test]
+fn selector_key_expr_only() {
+    let (key_expr, has_parameters) = Selector::try_from("a/b").unwrap().key_expr_only();
+    assert_eq!(key_expr.as_str(), "a/b");
+    assert!(!has_parameters);
+
+    let (key_expr, has_parameters) = Selector::try_from("a/b?x=1").unwrap().key_expr_only();
+    assert_eq!(key_expr.as_str(), "a/b");
+    assert!(has_parameters);
+}
+
+#[cfg(feature = "unstable")]
+#[test]
+fn selector_cache_key() {
+    let a = Selector::try_from("key/expression?b=2;a=1;c").unwrap();
+    let b = Selector::try_from("key/expression?c;a=1;b=2").unwrap();
+    let c = Selector::try_from("key/expression?%63;a=%31;b=2;a=3").unwrap();
+    assert_eq!(a.cache_key(), "key/expression?a=1;b=2;c");
+    assert_eq!(a.cache_key(), b.cache_key());
+    assert_eq!(a.cache_key(), c.cache_key());
+
+    let d = Selector::try_from("key/expression?b=2;a=2;c").unwrap();
+    assert_ne!(a.cache_key(), d.cache_key());
+    let e = Selector::try_from("key/other?b=2;a=1;c").unwrap();
+    assert_ne!(a.cache_key(), e.cache_key());
+
+    assert_eq!(
+        Selector::try_from("key/expression").unwrap().cache_key(),
+        "key/expression"
+    );
+}
+
+#[cfg(feature = "unstable")]
+#[test]
+fn selector_encoding_filter() {
+    let mut parameters = Parameters::from("foo=bar");
+    assert_eq!(parameters.encoding_filter(), None);
+    parameters.insert(ENCODING_FILTER_KEY, "");
+    assert_eq!(parameters.encoding_filter(), None);
+
+    parameters.set_encoding_filter(EncodingFilter::new("application/json"));
+    assert_eq!(
+        parameters.get(ENCODING_FILTER_KEY),
+        Some("application/json")
+    );
+    let filter = parameters.encoding_filter().unwrap();
+    assert!(filter.matches(&Encoding::APPLICATION_JSON));
+    assert!(filter.matches(&Encoding::APPLICATION_JSON.with_schema("schema")));
+    assert!(!filter.matches(&Encoding::APPLICATION_OCTET_STREAM));
+    assert!(!filter.matches(&Encoding::TEXT_JSON));
+
+    parameters.set_encoding_filter(None);
+    assert_eq!(parameters.encoding_filter(), None);
+    assert_eq!(parameters.get("foo"), Some("bar"));
+
+    let filter = EncodingFilter::new("TEXT/*");
+    assert!(filter.matches(&Encoding::TEXT_PLAIN));
+    assert!(filter.matches(&Encoding::TEXT_JSON));
+    assert!(!filter.matches(&Encoding::APPLICATION_JSON));
+    assert!(EncodingFilter::new("*").matches(&Encoding::ZENOH_BYTES));
+    assert!(EncodingFilter::new("zenoh/bytes;my*")
+        .matches(&Encoding::ZENOH_BYTES.with_schema("my_schema")));
+    assert!(!EncodingFilter::new("zenoh/bytes;my*").matches(&Encoding::ZENOH_BYTES));
+}
+
+#[cfg(feature = "unstable")]
+#[test]
+fn selector_iter_decoded_params() {
+    let selector = Selector::try_from("key/expression?a=x%3By;b=%E2%82;c=%zz;d;%65=%35").unwrap();
+    let params = selector.iter_decoded_params().collect::<Vec<_>>();
+    assert_eq!(params.len(), 5);
+    assert_eq!(params[0].as_ref().unwrap(), &("a".into(), "x;y".into()));
+    // Truncated UTF-8 sequence and malformed escape
+    assert!(params[1].as_ref().unwrap_err().to_string().contains("`b`"));
+    assert!(params[2].as_ref().unwrap_err().to_string().contains("`c`"));
+    assert_eq!(params[3].as_ref().unwrap(), &("d".into(), "".into()));
+    assert_eq!(params[4].as_ref().unwrap(), &("e".into(), "5".into()));
+}
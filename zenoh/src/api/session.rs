@@ -12,9 +12,14 @@
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
 use std::{
-    collections::{hash_map::Entry, HashMap},
+    collections::{
+        hash_map::{DefaultHasher, Entry},
+        HashMap,
+    },
     convert::TryInto,
-    fmt, hint,
+    fmt,
+    hash::{Hash, Hasher},
+    hint,
     mem::{self, ManuallyDrop},
     ops::Deref,
     sync::{
@@ -35,6 +40,7 @@ use zenoh_collections::{IntHashMap, SingleOrVec};
 use zenoh_config::{
     qos::{PublisherQoSConfList, PublisherQoSConfig},
     wrappers::ZenohId,
+    LimitsConf,
 };
 #[cfg(feature = "unstable")]
 use zenoh_config::{wrappers::EntityGlobalId, GenericConfig};
@@ -66,13 +72,21 @@ use zenoh_protocol::{
 use zenoh_result::ZResult;
 #[cfg(feature = "shared-memory")]
 use zenoh_shm::api::client_storage::ShmClientStorage;
-use zenoh_task::TaskController;
+#[cfg(feature = "unstable")]
+use zenoh_task::TaskInfo;
+use zenoh_task::{TaskController, TaskName};
 
 use super::{
     builders::close::{CloseBuilder, Closeable, Closee},
     connectivity,
 };
 #[cfg(feature = "unstable")]
+use crate::api::builders::subscriber::MultiSubscriberBuilder;
+#[cfg(all(feature = "unstable", feature = "stats"))]
+use crate::api::info::RoutedCounters;
+#[cfg(feature = "unstable")]
+use crate::api::publisher::DEFAULT_BLOCK_TIMEOUT;
+#[cfg(feature = "unstable")]
 use crate::api::timestamp_stack::TimestampInstrumentation;
 #[cfg(feature = "unstable")]
 use crate::api::{
@@ -99,6 +113,7 @@ use crate::{
         },
         bytes::ZBytes,
         cancellation::{SyncGroup, SyncGroupNotifier},
+        diagnostics,
         encoding::Encoding,
         handlers::{Callback, CallbackParameter, DefaultHandler},
         info::{Link, LinkEvent, SessionInfo, Transport, TransportEvent},
@@ -118,7 +133,8 @@ use crate::{
         Id,
     },
     net::{
-        primitives::Primitives,
+        primitives::{BlockTimeout, Primitives},
+        routing::interceptor::payload_limit::PayloadSizeLimits,
         runtime::{GenericRuntime, RuntimeBuilder},
     },
     query::ReplyError,
@@ -183,6 +199,7 @@ pub(crate) struct SessionState {
     pub(crate) aggregated_subscribers: Vec<OwnedKeyExpr>,
     pub(crate) aggregated_publishers: Vec<OwnedKeyExpr>,
     pub(crate) publisher_qos_tree: KeBoxTree<PublisherQoSConfig>,
+    pub(crate) ping_responder: Option<Id>,
     span: tracing::span::Span,
 }
 
@@ -216,6 +233,7 @@ impl SessionState {
             aggregated_subscribers,
             aggregated_publishers,
             publisher_qos_tree,
+            ping_responder: None,
             span: tracing::debug_span!("sess", zid = %ZenohIdProto::from(runtime.zid()).short()), // TODO(regions): include the face id
         }
     }
@@ -560,6 +578,7 @@ impl SubscriberCallbacks {
         #[cfg(feature = "unstable")] timestamp_stack: Option<
             zenoh_protocol::network::timestamp_stack::TimestampStack,
         >,
+        locality: Locality,
     ) {
         let zenoh_collections::single_or_vec::IntoIter { drain, last } = self.0.into_iter();
         for (cb, key_expr) in drain {
@@ -570,9 +589,10 @@ impl SubscriberCallbacks {
                 &mut msg.clone(),
                 reliability,
                 timestamp_stack.clone(),
+                locality,
             ));
             #[cfg(not(feature = "unstable"))]
-            cb.call_with_message((key_expr, qos, &mut msg.clone()));
+            cb.call_with_message((key_expr, qos, &mut msg.clone(), locality));
         }
         if let Some((cb, key_expr)) = last {
             let mut msg = &mut *msg;
@@ -582,9 +602,9 @@ impl SubscriberCallbacks {
                 msg = &mut msg_clone;
             }
             #[cfg(feature = "unstable")]
-            cb.call_with_message((key_expr, qos, msg, reliability, timestamp_stack));
+            cb.call_with_message((key_expr, qos, msg, reliability, timestamp_stack, locality));
             #[cfg(not(feature = "unstable"))]
-            cb.call_with_message((key_expr, qos, msg));
+            cb.call_with_message((key_expr, qos, msg, locality));
         }
     }
 }
@@ -689,6 +709,13 @@ pub trait Undeclarable<S = ()>: UndeclarableSealed<S> {}
 
 impl<T, S> Undeclarable<S> for T where T: UndeclarableSealed<S> {}
 
+/// Returns the name of a task handling `key_expr` after `prefix`, e.g. `zenoh:get:<hash>`.
+fn task_name(prefix: &'static str, key_expr: &keyexpr) -> TaskName {
+    let mut hasher = DefaultHasher::new();
+    key_expr.hash(&mut hasher);
+    TaskName::hashed(prefix, hasher.finish())
+}
+
 #[allow(dead_code)] // to allow using `id` with `unstable` feature
 pub(crate) struct SessionInner {
     /// See [`WeakSession`] doc
@@ -698,7 +725,10 @@ pub(crate) struct SessionInner {
     id: EntityId,
     task_controller: TaskController,
     face_id: OnceCell<usize>,
+    payload_limits: Option<PayloadSizeLimits>,
     pub(crate) callbacks_drop_sync_group: SyncGroup,
+    #[cfg(all(feature = "unstable", feature = "stats"))]
+    routed: RoutedCounters,
 }
 
 impl fmt::Debug for SessionInner {
@@ -774,6 +804,11 @@ impl Session {
         }
     }
 
+    #[cfg(all(feature = "unstable", feature = "stats"))]
+    pub(crate) fn routed_counters(&self) -> &RoutedCounters {
+        &self.0.routed
+    }
+
     #[cfg(feature = "test")]
     #[allow(dead_code)]
     pub(crate) fn inner_weak(&self) -> std::sync::Weak<SessionInner> {
@@ -846,20 +881,7 @@ impl Deref for WeakSession {
     }
 }
 
-/// Error indicating the operation cannot proceed because the session is closed.
-///
-/// It may be returned by operations like [`Session::get`] or [`Publisher::put`](crate::api::publisher::Publisher::put) when
-/// [`Session::close`] has been called before.
-#[derive(Debug)]
-pub struct SessionClosedError;
-
-impl fmt::Display for SessionClosedError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "session closed")
-    }
-}
-
-impl std::error::Error for SessionClosedError {}
+pub use zenoh_result::SessionClosedError;
 
 impl Session {
     pub(crate) fn init(
@@ -872,6 +894,10 @@ impl Session {
                 .get_config()
                 .get_typed::<PublisherQoSConfList>("qos/publication")
                 .unwrap();
+            let payload_limits = runtime
+                .get_config()
+                .get_typed::<LimitsConf>("limits")
+                .unwrap();
             let state = RwLock::new(SessionState::new(
                 aggregated_subscribers,
                 aggregated_publishers,
@@ -883,9 +909,12 @@ impl Session {
                 runtime: runtime.clone(),
                 state,
                 id: runtime.next_id(),
-                task_controller: TaskController::default(),
+                task_controller: TaskController::with_registry(runtime.task_registry().clone()),
                 face_id: OnceCell::new(),
+                payload_limits: PayloadSizeLimits::new(&payload_limits),
                 callbacks_drop_sync_group: SyncGroup::default(),
+                #[cfg(all(feature = "unstable", feature = "stats"))]
+                routed: RoutedCounters::default(),
             }));
 
             // Register connectivity handler
@@ -899,6 +928,7 @@ impl Session {
             session.0.face_id.set(_face_id).unwrap(); // this is the only attempt to set value
 
             admin::init(session.downgrade());
+            zwrite!(session.0.state).ping_responder = diagnostics::init(session.downgrade());
 
             session
         })
@@ -925,6 +955,30 @@ impl Session {
         self.0.runtime.hlc()
     }
 
+    /// Returns the live background tasks of the Session's runtime, ordered by spawn time.
+    ///
+    /// Each task is listed with its spawn time and a name telling its role, e.g.
+    /// `zenoh:tx:<locator>` and `zenoh:rx:<locator>` for the tasks of a link, or
+    /// `zenoh:get:<hash>` for the timeout of a query, where `<hash>` is a hash of its key
+    /// expression. When built with `--cfg tokio_unstable`, the tokio tasks bear the same names,
+    /// e.g. in `tokio-console`. The runtime shared by several sessions lists the tasks of all
+    /// of them.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+    /// for task in session.tasks() {
+    ///     println!("{} spawned at {:?}", task.name(), task.spawned_at());
+    /// }
+    /// # }
+    /// ```
+    #[zenoh_macros::unstable]
+    pub fn tasks(&self) -> Vec<TaskInfo> {
+        self.0.runtime.tasks()
+    }
+
     /// Close the zenoh [`Session`](Session).
     ///
     /// Every subscriber and queryable declared will stop receiving data, and further attempts to
@@ -1132,6 +1186,52 @@ impl Session {
             key_expr: TryIntoKeyExpr::try_into(key_expr).map_err(Into::into),
             origin: Locality::default(),
             handler: DefaultHandler::default(),
+            #[cfg(feature = "unstable")]
+            allowed_kinds: None,
+            #[cfg(feature = "unstable")]
+            deduplication: Default::default(),
+        }
+    }
+
+    /// Create a [`MultiSubscriber`](crate::pubsub::MultiSubscriber) for the given key expressions.
+    ///
+    /// One subscription is declared per key expression, sharing a single handler.
+    /// A sample matching several of the key expressions is delivered only once, and
+    /// key expressions included in another one of the set are ignored.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() {
+    ///
+    /// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+    /// let subscriber = session
+    ///     .declare_subscriber_multi(["key/expression/a", "key/expression/b"])
+    ///     .await
+    ///     .unwrap();
+    /// while let Ok(sample) = subscriber.recv_async().await {
+    ///     println!("Received: {:?}", sample);
+    /// }
+    /// # }
+    /// ```
+    #[zenoh_macros::unstable]
+    pub fn declare_subscriber_multi<'b, I, TryIntoKeyExpr>(
+        &self,
+        key_exprs: I,
+    ) -> MultiSubscriberBuilder<'_, 'b, DefaultHandler>
+    where
+        I: IntoIterator<Item = TryIntoKeyExpr>,
+        TryIntoKeyExpr: TryInto<KeyExpr<'b>>,
+        <TryIntoKeyExpr as TryInto<KeyExpr<'b>>>::Error: Into<zenoh_result::Error>,
+    {
+        MultiSubscriberBuilder {
+            session: self,
+            key_exprs: key_exprs
+                .into_iter()
+                .map(|key_expr| TryIntoKeyExpr::try_into(key_expr).map_err(Into::into))
+                .collect(),
+            origin: Locality::default(),
+            handler: DefaultHandler::default(),
         }
     }
 
@@ -1174,6 +1274,8 @@ impl Session {
             key_expr: key_expr.try_into().map_err(Into::into),
             complete: false,
             origin: Locality::default(),
+            #[cfg(feature = "unstable")]
+            max_concurrent_queries: None,
             handler: DefaultHandler::default(),
         }
     }
@@ -1213,6 +1315,8 @@ impl Session {
             is_express: false,
             #[cfg(feature = "unstable")]
             reliability: Reliability::DEFAULT,
+            #[cfg(feature = "unstable")]
+            block_timeout: DEFAULT_BLOCK_TIMEOUT,
             destination: Locality::default(),
         }
     }
@@ -1346,6 +1450,8 @@ impl Session {
             kind: PublicationBuilderPut {
                 payload: payload.into(),
                 encoding: Encoding::default(),
+                #[cfg(feature = "unstable")]
+                parameters: None,
             },
             timestamp: None,
             attachment: None,
@@ -1356,6 +1462,59 @@ impl Session {
         }
     }
 
+    /// Publish a [`SampleKind::Put`] sample on a [`Selector`], forwarding its parameters.
+    ///
+    /// The sample is published on the key expression of the selector.
+    /// The parameters are stored in the sample attachment under the `__params` key, and can be
+    /// retrieved on the subscriber side with [`Sample::parameters`]. The attachment is a
+    /// key-value map in the format of a `HashMap<String, String>` serialized with
+    /// `zenoh_ext::z_serialize`. When the selector has no parameters, no attachment is set.
+    ///
+    /// An explicit [`attachment`](crate::pubsub::PublicationBuilder::attachment) set on the
+    /// returned builder must be such a map, the parameters are then added to it. The publication
+    /// fails if that attachment is not a key-value map or if it already has a `__params` key.
+    ///
+    /// # Arguments
+    ///
+    /// * `selector` - The selector whose key expression is published on and whose parameters are forwarded
+    /// * `payload` - The payload to put
+    ///
+    /// # Examples
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    ///
+    /// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+    /// session
+    ///     .put_to("key/expression?unit=celsius", "21.5")
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    #[zenoh_macros::unstable]
+    pub fn put_to<'a, 'b: 'a, TryIntoSelector, IntoZBytes>(
+        &'a self,
+        selector: TryIntoSelector,
+        payload: IntoZBytes,
+    ) -> SessionPutBuilder<'a, 'b>
+    where
+        TryIntoSelector: TryInto<Selector<'b>>,
+        <TryIntoSelector as TryInto<Selector<'b>>>::Error: Into<zenoh_result::Error>,
+        IntoZBytes: Into<ZBytes>,
+    {
+        let (key_expr, parameters) = match selector.try_into().map_err(Into::into) {
+            Ok(selector) => {
+                let (key_expr, parameters) = selector.split();
+                (Ok(key_expr), parameters)
+            }
+            Err(e) => (Err(e), Parameters::empty()),
+        };
+        let mut builder = self.put(KeyExpr::placeholder(), payload);
+        builder.publisher.key_expr = key_expr;
+        builder.kind.parameters = (!parameters.is_empty()).then(|| parameters.into_owned());
+        builder
+    }
+
     /// Publish a [`SampleKind::Delete`] sample directly from the session. This is a shortcut for declaring
     /// a [`Publisher`](crate::pubsub::Publisher) and calling [`delete`](crate::api::publisher::Publisher::delete) on it.
     ///
@@ -1574,7 +1733,11 @@ impl Session {
             }
             Ok(())
         } else {
-            bail!("Unknown prefix id: {expr_id} for session: {}", self.zid())
+            bail!(
+                kind = DeclarationRefused,
+                "Unknown prefix id: {expr_id} for session: {}",
+                self.zid()
+            )
         }
     }
 
@@ -1647,6 +1810,91 @@ impl Session {
         Ok(id)
     }
 
+    /// Returns the numbers of batches flushed and dropped by the closing links of the session
+    /// runtime, if the session owns it.
+    #[cfg(feature = "unstable")]
+    pub(crate) fn flush_counts(&self) -> (usize, usize) {
+        self.0.runtime.static_runtime().map_or((0, 0), |r| {
+            (
+                r.manager().flushed_batch_count(),
+                r.manager().dropped_batch_count(),
+            )
+        })
+    }
+
+    /// Aborts the flush of the transmission pipelines of the closing links of the session
+    /// runtime, if the session owns it.
+    #[cfg(feature = "unstable")]
+    pub(crate) fn abort_flush(&self) {
+        if let Some(r) = self.0.runtime.static_runtime() {
+            r.manager().abort_flush();
+        }
+    }
+
+    /// Undeclares all the publishers, queriers, subscribers, liveliness subscribers and queryables
+    /// of the session which are visible to the network, sending their undeclarations, and returns
+    /// the number of undeclared entities.
+    ///
+    /// Session-local entities, such as the adminspace queryables, and the ping responder are left
+    /// to the session cleanup.
+    #[cfg(feature = "unstable")]
+    pub(crate) fn undeclare_all_inner(&self) -> usize {
+        fn remote_ids<'a, T: 'a>(
+            entities: impl IntoIterator<Item = (&'a Id, T)>,
+            locality: impl Fn(&T) -> Locality,
+        ) -> Vec<Id> {
+            entities
+                .into_iter()
+                .filter(|(_, e)| locality(e) != Locality::SessionLocal)
+                .map(|(id, _)| *id)
+                .collect()
+        }
+
+        let state = zread!(self.0.state);
+        if state.primitives.is_none() {
+            return 0;
+        }
+        let publishers = remote_ids(&state.publishers, |p| p.destination);
+        let queriers = remote_ids(&state.queriers, |q| q.destination);
+        let subscribers = remote_ids(&state.subscribers, |s| s.origin);
+        let liveliness_subscribers = remote_ids(&state.liveliness_subscribers, |s| s.origin);
+        let queryables = remote_ids(
+            state
+                .queryables
+                .iter()
+                .filter(|(id, _)| Some(**id) != state.ping_responder),
+            |q| q.origin,
+        );
+        drop(state);
+
+        let results = publishers
+            .into_iter()
+            .map(|id| self.undeclare_publisher_inner(id))
+            .chain(
+                queriers
+                    .into_iter()
+                    .map(|id| self.undeclare_querier_inner(id)),
+            )
+            .chain(
+                subscribers
+                    .into_iter()
+                    .map(|id| self.undeclare_subscriber_inner(id, SubscriberKind::Subscriber)),
+            )
+            .chain(liveliness_subscribers.into_iter().map(|id| {
+                self.undeclare_subscriber_inner(id, SubscriberKind::LivelinessSubscriber)
+            }))
+            .chain(queryables.into_iter().map(|id| self.close_queryable(id)));
+        let mut undeclared = 0;
+        for result in results {
+            match result {
+                Ok(()) => undeclared += 1,
+                // the entity may have been concurrently undeclared
+                Err(e) => tracing::debug!("undeclare_all: {e}"),
+            }
+        }
+        undeclared
+    }
+
     pub(crate) fn undeclare_publisher_inner(&self, pid: Id) -> ZResult<()> {
         let mut state = zwrite!(self.0.state);
         let Ok(primitives) = state.primitives() else {
@@ -1676,7 +1924,7 @@ impl Session {
             }
             Ok(())
         } else {
-            Err(zerror!("Unable to find publisher").into())
+            Err(zerror!(kind = DeclarationRefused, "Unable to find publisher").into())
         }
     }
 
@@ -1750,7 +1998,7 @@ impl Session {
             }
             Ok(())
         } else {
-            Err(zerror!("Unable to find querier").into())
+            Err(zerror!(kind = DeclarationRefused, "Unable to find querier").into())
         }
     }
 
@@ -1892,7 +2140,7 @@ impl Session {
             // on `sub_state` drop, for background subscribers.
             Ok(())
         } else {
-            Err(zerror!("Unable to find subscriber").into())
+            Err(zerror!(kind = DeclarationRefused, "Unable to find subscriber").into())
         }
     }
 
@@ -1990,7 +2238,7 @@ impl Session {
             // on `qable_state` drop for background queryables.
             Ok(())
         } else {
-            Err(zerror!("Unable to find queryable").into())
+            Err(zerror!(kind = DeclarationRefused, "Unable to find queryable").into())
         }
     }
 
@@ -2076,9 +2324,10 @@ impl Session {
         drop(state);
 
         if !known_tokens.is_empty() {
-            self.0
-                .task_controller
-                .spawn_with_rt(zenoh_runtime::ZRuntime::Net, async move {
+            self.0.task_controller.spawn_named_with_rt(
+                task_name("zenoh:liveliness_sub:", key_expr),
+                zenoh_runtime::ZRuntime::Net,
+                async move {
                     for token in known_tokens {
                         callback.call(Sample {
                             key_expr: token,
@@ -2094,9 +2343,11 @@ impl Session {
                             attachment: None,
                             #[cfg(feature = "unstable")]
                             timestamp_stack: None,
+                            locality: Locality::Remote,
                         });
                     }
-                });
+                },
+            );
         }
 
         primitives.send_interest(&mut Interest {
@@ -2250,9 +2501,10 @@ impl Session {
             if msub.is_matching(key_expr, match_type) {
                 // Cannot hold session lock when calling tables (matching_status())
                 // TODO: check which ZRuntime should be used
-                self.0
-                    .task_controller
-                    .spawn_with_rt(zenoh_runtime::ZRuntime::Net, {
+                self.0.task_controller.spawn_named_with_rt(
+                    task_name("zenoh:matching:", &msub.key_expr),
+                    zenoh_runtime::ZRuntime::Net,
+                    {
                         let session = self.downgrade();
                         let msub = msub.clone();
                         async move {
@@ -2280,7 +2532,8 @@ impl Session {
                                 }
                             }
                         }
-                    });
+                    },
+                );
             }
         }
     }
@@ -2299,7 +2552,7 @@ impl Session {
             trace!("undeclare_matches_listener_inner({:?})", state);
             Ok(())
         } else {
-            Err(zerror!("Unable to find MatchingListener").into())
+            Err(zerror!(kind = DeclarationRefused, "Unable to find MatchingListener").into())
         }
     }
 
@@ -2353,7 +2606,11 @@ impl Session {
             trace!("undeclare_transport_events_listener_inner({:?})", state);
             Ok(())
         } else {
-            Err(zerror!("Unable to find TransportEventsListener").into())
+            Err(zerror!(
+                kind = DeclarationRefused,
+                "Unable to find TransportEventsListener"
+            )
+            .into())
         }
     }
 
@@ -2432,7 +2689,11 @@ impl Session {
             trace!("undeclare_transport_links_listener_inner({:?})", state);
             Ok(())
         } else {
-            Err(zerror!("Unable to find LinkEventsListener").into())
+            Err(zerror!(
+                kind = DeclarationRefused,
+                "Unable to find LinkEventsListener"
+            )
+            .into())
         }
     }
 
@@ -2496,6 +2757,11 @@ impl Session {
             // so there is no need to pass ext_ts_stack in its parameters.
             #[cfg(feature = "unstable")]
             None,
+            if local {
+                Locality::SessionLocal
+            } else {
+                Locality::Remote
+            },
         );
     }
 
@@ -2511,12 +2777,17 @@ impl Session {
         is_express: bool,
         destination: Locality,
         #[cfg(feature = "unstable")] reliability: Reliability,
+        #[cfg(feature = "unstable")] block_timeout: Duration,
         timestamp: Option<uhlc::Timestamp>,
         #[cfg(feature = "unstable")] source_info: Option<SourceInfo>,
         attachment: Option<ZBytes>,
         #[cfg(feature = "unstable")] timestamp_instrumentation: Option<TimestampInstrumentation>,
     ) -> ZResult<()> {
         trace!("write({:?}, [...])", key_expr);
+        if let Some(limits) = &self.0.payload_limits {
+            let attachment_size = attachment.as_ref().map_or(0, ZBytes::len);
+            limits.check(key_expr, payload.len(), attachment_size)?;
+        }
         let state = zread!(self.0.state);
         let primitives = state.primitives()?;
         let wire_expr = key_expr.to_wire(self);
@@ -2578,7 +2849,14 @@ impl Session {
             push.ext_ts_stack = ext_ts_stack;
         }
         let has_local_callbacks = !callbacks.is_empty();
+        #[cfg(feature = "unstable")]
+        let block_timeout = (congestion_control == CongestionControl::BlockWithTimeout)
+            .then(|| BlockTimeout::new(block_timeout));
+        #[cfg(not(feature = "unstable"))]
+        let block_timeout: Option<BlockTimeout> = None;
         if destination != Locality::SessionLocal {
+            #[cfg(all(feature = "unstable", feature = "stats"))]
+            self.0.routed.increment(push.ext_qos.get_priority());
             primitives.send_push_consume(
                 &mut push,
                 #[cfg(feature = "unstable")]
@@ -2586,6 +2864,7 @@ impl Session {
                 #[cfg(not(feature = "unstable"))]
                 Reliability::DEFAULT,
                 !has_local_callbacks,
+                block_timeout.as_ref(),
             );
         }
         if has_local_callbacks {
@@ -2606,6 +2885,7 @@ impl Session {
                     reliability,
                     #[cfg(feature = "unstable")]
                     timestamp_stack,
+                    Locality::SessionLocal,
                 );
             }
 
@@ -2637,6 +2917,10 @@ impl Session {
                 if ext_unknown.is_empty() => {}
             _ => unsafe { hint::unreachable_unchecked() },
         }
+        #[cfg(feature = "unstable")]
+        if let Some(block_timeout) = block_timeout.filter(BlockTimeout::expired) {
+            return Err(zenoh_result::block_timeout_error(block_timeout.timeout()));
+        }
         Ok(())
     }
 
@@ -2744,9 +3028,10 @@ impl Session {
             _ => 1,
         };
         let token = self.0.task_controller.get_cancellation_token();
-        self.0
-            .task_controller
-            .spawn_with_rt(zenoh_runtime::ZRuntime::Net, {
+        self.0.task_controller.spawn_named_with_rt(
+            task_name("zenoh:get:", key_expr),
+            zenoh_runtime::ZRuntime::Net,
+            {
                 let session = self.downgrade();
                 async move {
                     tokio::select! {
@@ -2762,7 +3047,6 @@ impl Session {
                                 }
                                 query.callback.call(Reply {
                                     result: Err(ReplyError::new("Timeout", Encoding::ZENOH_STRING)),
-                                    #[cfg(feature = "unstable")]
                                     replier_id: None
                                 });
                             }
@@ -2770,7 +3054,8 @@ impl Session {
                         _ = token.cancelled() => {}
                     }
                 }
-            });
+            },
+        );
 
         tracing::trace!("Register query {} (nb_final = {})", qid, nb_final);
         state.queries.insert(
@@ -2813,6 +3098,10 @@ impl Session {
         if destination != Locality::SessionLocal {
             let wexpr = key_expr.to_wire(self).to_owned();
             let ext_attachment = attachment.clone().map(Into::into);
+            #[cfg(all(feature = "unstable", feature = "stats"))]
+            self.0
+                .routed
+                .increment(request::ext::QoSType::from(qos).get_priority());
             primitives.send_request(&mut Request {
                 id: qid,
                 wire_expr: wexpr.clone(),
@@ -2916,8 +3205,10 @@ impl Session {
             &mut callback,
         )?;
         let token = self.0.task_controller.get_cancellation_token();
-        self.0.task_controller
-            .spawn_with_rt(zenoh_runtime::ZRuntime::Net, {
+        self.0.task_controller.spawn_named_with_rt(
+            task_name("zenoh:liveliness_get:", key_expr),
+            zenoh_runtime::ZRuntime::Net,
+            {
                 let session = self.downgrade();
                 async move {
                     tokio::select! {
@@ -2928,7 +3219,6 @@ impl Session {
                                 tracing::debug!("Timeout on liveliness query {}! Send error and close.", id);
                                 query.callback.call(Reply {
                                     result: Err(ReplyError::new("Timeout", Encoding::ZENOH_STRING)),
-                                    #[cfg(feature = "unstable")]
                                     replier_id: None
                                 });
                             }
@@ -3039,6 +3329,8 @@ impl Session {
                 eid: 0,
                 value: body.map(|b| (b.payload.into(), b.encoding.into())),
                 attachment,
+                #[cfg(feature = "unstable")]
+                permit: None,
             };
             for (eid, cb) in queryables {
                 query.eid = eid;
@@ -3243,8 +3535,8 @@ impl Primitives for WeakSession {
                                         attachment: None,
                                         #[cfg(feature = "unstable")]
                                         timestamp_stack: None,
+                                        locality: Locality::Remote,
                                     }),
-                                    #[cfg(feature = "unstable")]
                                     replier_id: None,
                                 };
 
@@ -3341,7 +3633,13 @@ impl Primitives for WeakSession {
     }
 
     #[inline(always)]
-    fn send_push_consume(&self, msg: &mut Push, _reliability: Reliability, consume: bool) {
+    fn send_push_consume(
+        &self,
+        msg: &mut Push,
+        _reliability: Reliability,
+        consume: bool,
+        _block_timeout: Option<&BlockTimeout>,
+    ) {
         trace!("recv Push {:?}", msg);
         let state = zread!(self.0.state);
         let callbacks =
@@ -3364,6 +3662,7 @@ impl Primitives for WeakSession {
             _reliability,
             #[cfg(feature = "unstable")]
             msg.ext_ts_stack.as_ref().map(|ts| ts.ts_stack.clone()),
+            Locality::Remote,
         );
     }
 
@@ -3412,6 +3711,48 @@ impl Primitives for WeakSession {
     }
 
     fn send_response(&self, msg: &mut Response) {
+        self.handle_response(msg, Locality::Remote);
+    }
+
+    fn send_response_final(&self, msg: &mut ResponseFinal) {
+        trace!("recv ResponseFinal {:?}", msg);
+        let mut state = zwrite!(self.0.state);
+        if state.primitives.is_none() {
+            return; // Session closing or closed
+        }
+        match state.queries.get_mut(&msg.rid) {
+            Some(query) => {
+                query.nb_final -= 1;
+                if query.nb_final == 0 {
+                    let query = state.queries.remove(&msg.rid).unwrap();
+                    std::mem::drop(state);
+                    if query.reception_mode == ConsolidationMode::Latest {
+                        for (_, reply) in query.replies.unwrap().into_iter() {
+                            query.callback.call(reply);
+                        }
+                    }
+                    trace!("Close query {}", msg.rid);
+                }
+            }
+            None => {
+                warn!("Received ResponseFinal for unknown Request: {}", msg.rid);
+            }
+        }
+    }
+
+    fn send_close(&self) {
+        trace!("recv Close");
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl WeakSession {
+    /// Handles a reply to one of this session's queries, `locality` telling whether it was
+    /// sent by a queryable of this session or received from the network.
+    pub(crate) fn handle_response(&self, msg: &mut Response, locality: Locality) {
         trace!("recv Response {:?}", msg);
         #[cfg(feature = "unstable")]
         {
@@ -3444,7 +3785,6 @@ impl Primitives for WeakSession {
                                     .ok()
                                 }),
                             }),
-                            #[cfg(feature = "unstable")]
                             replier_id: mem::take(&mut msg.ext_respid).map(|rid| {
                                 zenoh_protocol::core::EntityGlobalIdProto {
                                     zid: rid.zid,
@@ -3494,8 +3834,8 @@ impl Primitives for WeakSession {
                                 Reliability::Reliable,
                                 #[cfg(feature = "unstable")]
                                 mem::take(&mut msg.ext_ts_stack).map(|ts| ts.ts_stack),
+                                locality,
                             )),
-                            #[cfg(feature = "unstable")]
                             replier_id: mem::take(&mut msg.ext_respid).map(|rid| {
                                 zenoh_protocol::core::EntityGlobalIdProto {
                                     zid: rid.zid,
@@ -3595,40 +3935,6 @@ impl Primitives for WeakSession {
             }
         }
     }
-
-    fn send_response_final(&self, msg: &mut ResponseFinal) {
-        trace!("recv ResponseFinal {:?}", msg);
-        let mut state = zwrite!(self.0.state);
-        if state.primitives.is_none() {
-            return; // Session closing or closed
-        }
-        match state.queries.get_mut(&msg.rid) {
-            Some(query) => {
-                query.nb_final -= 1;
-                if query.nb_final == 0 {
-                    let query = state.queries.remove(&msg.rid).unwrap();
-                    std::mem::drop(state);
-                    if query.reception_mode == ConsolidationMode::Latest {
-                        for (_, reply) in query.replies.unwrap().into_iter() {
-                            query.callback.call(reply);
-                        }
-                    }
-                    trace!("Close query {}", msg.rid);
-                }
-            }
-            None => {
-                warn!("Received ResponseFinal for unknown Request: {}", msg.rid);
-            }
-        }
-    }
-
-    fn send_close(&self) {
-        trace!("recv Close");
-    }
-
-    fn as_any(&self) -> &dyn std::any::Any {
-        self
-    }
 }
 
 impl crate::net::primitives::EPrimitives for WeakSession {
@@ -3645,7 +3951,12 @@ impl crate::net::primitives::EPrimitives for WeakSession {
     }
 
     #[inline]
-    fn send_push(&self, msg: &mut Push, reliability: Reliability) -> bool {
+    fn send_push(
+        &self,
+        msg: &mut Push,
+        reliability: Reliability,
+        _block_timeout: Option<&BlockTimeout>,
+    ) -> bool {
         (self as &dyn Primitives).send_push(msg, reliability);
         false
     }
@@ -3713,6 +4024,8 @@ where
 #[derive(Default)]
 pub(crate) struct SessionCloseArgs {
     pub(crate) wait_callbacks: bool,
+    #[cfg(feature = "unstable")]
+    pub(crate) undeclare_all: bool,
 }
 
 #[async_trait]
@@ -3720,6 +4033,11 @@ impl Closee for WeakSession {
     type CloseArgs = SessionCloseArgs;
     #[allow(unused_variables)] // SessionCloseArgs are only required for wait until callback execution ends under unstable
     async fn close_inner(&self, close_args: SessionCloseArgs) {
+        #[cfg(feature = "unstable")]
+        if close_args.undeclare_all {
+            self.undeclare_all_inner();
+        }
+
         let primitives = zwrite!(self.0.state).primitives.take();
 
         // defer the cleanup of internal data structures by taking them out of the locked state
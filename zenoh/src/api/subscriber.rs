@@ -21,7 +21,14 @@ use tracing::error;
 use zenoh_core::{Resolvable, Wait};
 use zenoh_result::ZResult;
 #[cfg(feature = "unstable")]
-use {zenoh_config::wrappers::EntityGlobalId, zenoh_protocol::core::EntityGlobalIdProto};
+use {
+    std::sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    zenoh_config::wrappers::EntityGlobalId,
+    zenoh_protocol::core::EntityGlobalIdProto,
+};
 
 use crate::api::{
     cancellation::SyncGroup,
@@ -163,8 +170,16 @@ pub struct Subscriber<Handler> {
     pub(crate) inner: SubscriberInner,
     pub(crate) handler: Handler,
     pub(crate) callback_sync_group: SyncGroup,
+    #[cfg(feature = "unstable")]
+    pub(crate) suppressed_duplicates: Option<Arc<AtomicU64>>,
 }
 
+/// A [`Subscriber`] whose samples are buffered until pulled.
+///
+/// See [`SubscriberBuilder::pull_mode`](crate::pubsub::SubscriberBuilder::pull_mode).
+#[zenoh_macros::unstable]
+pub type PullSubscriber = Subscriber<crate::api::handlers::PullChannelHandler<Sample>>;
+
 impl<Handler> fmt::Debug for Subscriber<Handler> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Subscriber")
@@ -204,6 +219,17 @@ impl<Handler> Subscriber<Handler> {
         &self.inner.key_expr
     }
 
+    /// Returns the number of duplicated samples suppressed by this subscriber.
+    ///
+    /// Always returns 0 if the deduplication was not enabled with
+    /// [`SubscriberBuilder::deduplicate`](crate::pubsub::SubscriberBuilder::deduplicate).
+    #[zenoh_macros::unstable]
+    pub fn suppressed_duplicates(&self) -> u64 {
+        self.suppressed_duplicates
+            .as_ref()
+            .map_or(0, |suppressed| suppressed.load(Ordering::Relaxed))
+    }
+
     /// Returns a reference to this subscriber's handler.
     /// A handler is anything that implements [`IntoHandler`](crate::handlers::IntoHandler).
     /// The default handler is [`DefaultHandler`](crate::handlers::DefaultHandler).
@@ -297,6 +323,186 @@ impl<Handler> DerefMut for Subscriber<Handler> {
     }
 }
 
+/// A [`Resolvable`] returned by [`MultiSubscriber::undeclare`]
+#[zenoh_macros::unstable]
+#[must_use = "Resolvables do nothing unless you resolve them using `.await` or `zenoh::Wait::wait`"]
+pub struct MultiSubscriberUndeclaration<Handler> {
+    subscriber: MultiSubscriber<Handler>,
+    wait_callbacks: bool,
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> fmt::Debug for MultiSubscriberUndeclaration<Handler> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MultiSubscriberUndeclaration")
+            .field("subscriber", &self.subscriber)
+            .field("wait_callbacks", &self.wait_callbacks)
+            .finish()
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> MultiSubscriberUndeclaration<Handler> {
+    /// Block in undeclare operation until all currently running instances of subscriber callbacks (if any) return.
+    pub fn wait_callbacks(mut self) -> Self {
+        self.wait_callbacks = true;
+        self
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> Resolvable for MultiSubscriberUndeclaration<Handler> {
+    type To = ZResult<()>;
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> Wait for MultiSubscriberUndeclaration<Handler> {
+    fn wait(mut self) -> <Self as Resolvable>::To {
+        self.subscriber.undeclare_impl()?;
+        if self.wait_callbacks {
+            self.subscriber.callback_sync_group.wait();
+        }
+        Ok(())
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> IntoFuture for MultiSubscriberUndeclaration<Handler> {
+    type Output = <Self as Resolvable>::To;
+    type IntoFuture = Ready<<Self as Resolvable>::To>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        std::future::ready(self.wait())
+    }
+}
+
+/// A subscriber on several key expressions sharing a single handler.
+///
+/// The data is provided through a [`Handler`](crate::handlers::IntoHandler).
+/// Multi-subscribers can be created from a zenoh [`Session`](crate::Session)
+/// with the [`declare_subscriber_multi`](crate::Session::declare_subscriber_multi) function.
+/// One subscription is declared per key expression, but a sample matching several of them
+/// is delivered only once.
+///
+/// # Examples
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() {
+/// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+/// let subscriber = session
+///     .declare_subscriber_multi(["key/expression/a", "key/expression/b"])
+///     .await
+///     .unwrap();
+/// while let Ok(sample) = subscriber.recv_async().await {
+///     println!("Received: {} {:?}", sample.key_expr(), sample.payload());
+/// }
+/// # }
+/// ```
+#[zenoh_macros::unstable]
+#[non_exhaustive]
+pub struct MultiSubscriber<Handler> {
+    pub(crate) inners: Vec<SubscriberInner>,
+    pub(crate) key_exprs: Vec<KeyExpr<'static>>,
+    pub(crate) handler: Handler,
+    pub(crate) callback_sync_group: SyncGroup,
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> fmt::Debug for MultiSubscriber<Handler> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MultiSubscriber")
+            .field("inners", &self.inners)
+            .field("handler", &"..")
+            .field("callback_sync_group", &self.callback_sync_group)
+            .finish()
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> MultiSubscriber<Handler> {
+    /// Returns the [`KeyExpr`]s this subscriber subscribes to.
+    ///
+    /// Key expressions included in another one of the declared set are not part of it.
+    pub fn key_exprs(&self) -> &[KeyExpr<'static>] {
+        &self.key_exprs
+    }
+
+    /// Returns a reference to this subscriber's handler.
+    pub fn handler(&self) -> &Handler {
+        &self.handler
+    }
+
+    /// Returns a mutable reference to this subscriber's handler.
+    pub fn handler_mut(&mut self) -> &mut Handler {
+        &mut self.handler
+    }
+
+    /// Undeclare all the subscriptions of the [`MultiSubscriber`].
+    #[inline]
+    pub fn undeclare(self) -> MultiSubscriberUndeclaration<Handler>
+    where
+        Handler: Send,
+    {
+        self.undeclare_inner(())
+    }
+
+    fn undeclare_impl(&mut self) -> ZResult<()> {
+        let mut result = Ok(());
+        for inner in self
+            .inners
+            .iter_mut()
+            .filter(|inner| inner.undeclare_on_drop)
+        {
+            // set the flag first to avoid double panic if this function panics
+            inner.undeclare_on_drop = false;
+            if let Err(error) = inner
+                .session
+                .undeclare_subscriber_inner(inner.id, inner.kind)
+            {
+                result = Err(error);
+            }
+        }
+        result
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> Drop for MultiSubscriber<Handler> {
+    fn drop(&mut self) {
+        if let Err(error) = self.undeclare_impl() {
+            error!(error);
+        }
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<Handler: Send> UndeclarableSealed<()> for MultiSubscriber<Handler> {
+    type Undeclaration = MultiSubscriberUndeclaration<Handler>;
+
+    fn undeclare_inner(self, _: ()) -> Self::Undeclaration {
+        MultiSubscriberUndeclaration {
+            subscriber: self,
+            wait_callbacks: false,
+        }
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> Deref for MultiSubscriber<Handler> {
+    type Target = Handler;
+
+    fn deref(&self) -> &Self::Target {
+        self.handler()
+    }
+}
+
+#[zenoh_macros::unstable]
+impl<Handler> DerefMut for MultiSubscriber<Handler> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.handler_mut()
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum SubscriberKind {
     Subscriber,
@@ -165,6 +165,10 @@
 //!   Enable some internal APIs, usually necessary to expose some internal functionalities to other language bindings. These APIs are not supposed
 //!   to be called by users as they are close to implementation and can be changed at any moment
 //!
+//! * `latency_histogram`
+//!
+//!   Enable the HDR histogram used by `Publisher::with_latency_tracking` to record publication latencies. Requires `unstable`
+//!
 //! * `plugins`
 //!
 //!   Enable the APIs related to plugin support in `zenohd`. These APIs are `internal` and `unstable` for now
@@ -277,6 +281,8 @@ pub use zenoh_core::{Resolvable, Resolve, Wait};
 pub use zenoh_result::Error;
 /// A Zenoh result.
 pub use zenoh_result::ZResult as Result;
+#[zenoh_macros::unstable]
+pub use zenoh_result::{ErrKind, ZErrorKind};
 #[doc(inline)]
 pub use zenoh_util::{init_log_from_env_or, try_init_log_from_env};
 
@@ -355,8 +361,10 @@ pub mod key_expr {
     };
 
     pub use crate::api::key_expr::{KeyExpr, KeyExprUndeclaration};
-    // keyexpr format macro support
-    #[zenoh_macros::unstable]
+    /// Key expression formats, to build and parse key expressions following a template
+    /// such as `factory/${line:*}/machine/${id:*}/state`.
+    ///
+    /// See [`KeFormat`](format::KeFormat) for the format syntax.
     pub mod format {
         pub use zenoh_keyexpr::format::*;
         pub use zenoh_macros::{ke, kedefine, keformat, kewrite};
@@ -385,9 +393,16 @@ pub mod session {
     pub use zenoh_config::wrappers::ZenohId;
     #[zenoh_macros::unstable]
     pub use zenoh_protocol::core::EntityId;
+    #[zenoh_macros::unstable]
+    pub use zenoh_task::TaskInfo;
 
+    #[zenoh_macros::unstable]
+    pub use crate::api::builders::close::{SessionCloseSummary, SessionCloseSummaryBuilder};
     #[zenoh_macros::internal]
     pub use crate::api::builders::session::{init, InitBuilder};
+    #[zenoh_macros::unstable]
+    #[cfg(feature = "stats")]
+    pub use crate::api::info::RoutedMessages;
     #[zenoh_macros::internal]
     pub use crate::api::session::WeakSession;
     #[zenoh_macros::unstable]
@@ -400,7 +415,7 @@ pub mod session {
             TransportEventsListener, TransportEventsListenerBuilder,
             TransportEventsListenerUndeclaration, TransportsBuilder,
         },
-        info::{Link, LinkEvent, Transport, TransportEvent},
+        info::{ClockSkew, Link, LinkEvent, Transport, TransportEvent},
     };
     pub use crate::api::{
         builders::{
@@ -435,12 +450,16 @@ pub mod session {
 /// than using getter methods.
 pub mod sample {
     #[zenoh_macros::unstable]
-    pub use crate::api::sample::{SourceInfo, SourceSn};
+    pub use crate::api::builders::sample::SampleLimits;
+    #[zenoh_macros::unstable]
+    pub use crate::api::sample::{
+        default_locality, set_default_locality, SeqStatus, SourceInfo, SourceSeqTracker, SourceSn,
+    };
     pub use crate::api::{
         builders::sample::{
             SampleBuilder, SampleBuilderAny, SampleBuilderDelete, SampleBuilderPut,
         },
-        sample::{Locality, Sample, SampleFields, SampleKind},
+        sample::{Locality, Sample, SampleFields, SampleKind, SampleMetadata},
     };
 }
 
@@ -548,6 +567,9 @@ pub mod bytes {
 /// # }
 /// ```
 pub mod pubsub {
+    #[zenoh_macros::unstable]
+    #[cfg(feature = "latency_histogram")]
+    pub use crate::api::latency::HdrLatencyHistogram;
     pub use crate::api::{
         builders::{
             publisher::{
@@ -559,6 +581,17 @@ pub mod pubsub {
         publisher::{Publisher, PublisherUndeclaration},
         subscriber::{Subscriber, SubscriberUndeclaration},
     };
+    #[zenoh_macros::unstable]
+    pub use crate::api::{
+        builders::{
+            publisher::{
+                PublisherSuffix, PublisherSuffixedDeleteBuilder, PublisherSuffixedPutBuilder,
+            },
+            subscriber::MultiSubscriberBuilder,
+        },
+        latency::{LatencyHistogram, LatencySnapshot},
+        subscriber::{MultiSubscriber, MultiSubscriberUndeclaration, PullSubscriber},
+    };
 }
 
 /// # Query/reply primitives
@@ -643,14 +676,14 @@ pub mod pubsub {
 /// # }
 /// ```
 pub mod query {
-    pub use zenoh_protocol::core::Parameters;
+    pub use zenoh_protocol::core::{ParamSchema, Parameters};
     #[zenoh_macros::unstable]
     pub use zenoh_util::time_range::{TimeBound, TimeExpr, TimeRange};
 
     #[zenoh_macros::internal]
     pub use crate::api::queryable::ReplySample;
     #[zenoh_macros::unstable]
-    pub use crate::api::selector::ZenohParameters;
+    pub use crate::api::selector::{EncodingFilter, ZenohParameters};
     pub use crate::api::{
         builders::{
             querier::{QuerierBuilder, QuerierGetBuilder},
@@ -804,6 +837,8 @@ pub mod handlers {
         Callback, CallbackDrop, DefaultHandler, FifoChannel, FifoChannelHandler, IntoHandler,
         RingChannel, RingChannelHandler,
     };
+    #[zenoh_macros::unstable]
+    pub use crate::api::handlers::{PullChannel, PullChannelHandler, PullOverflow};
     /// The module contains helper types and traits necessary to work with FIFO channels
     pub mod fifo {
         pub use crate::api::handlers::{
@@ -840,6 +875,37 @@ pub mod qos {
     pub use zenoh_protocol::core::Reliability;
 
     pub use crate::api::publisher::Priority;
+    #[zenoh_macros::unstable]
+    pub use crate::api::publisher::{BlockTimeoutError, InvalidPriority};
+    #[zenoh_macros::unstable]
+    pub use crate::api::sample::QoS;
+}
+
+/// # Diagnostics utilities
+///
+/// Every session runs a lightweight responder on `@/ping/<zid>`, which echoes the probes it
+/// receives. The [`ping`](diagnostics::ping) function uses it to measure the round-trip time
+/// to another session, without having to deploy a dedicated pong application.
+///
+/// The responder is rate-limited and can be disabled with the `diagnostics/ping`
+/// configuration.
+///
+/// # Example
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() {
+/// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+/// let peer = session.info().peers_zid().await.next().unwrap();
+/// let report = zenoh::diagnostics::ping(&session, peer, 64, 10).await.unwrap();
+/// println!("{} probes lost out of {}", report.lost(), report.sent());
+/// # }
+/// ```
+#[zenoh_macros::unstable]
+pub mod diagnostics {
+    pub use crate::api::{
+        builders::diagnostics::PingBuilder,
+        diagnostics::{ping, PingReport, PingTarget},
+    };
 }
 
 /// # Scouting primitives
@@ -932,6 +998,11 @@ pub mod scouting {
 /// # }
 /// ```
 pub mod liveliness {
+    #[zenoh_macros::unstable]
+    pub use crate::api::{
+        builders::liveliness::LivelinessGroupViewBuilder,
+        liveliness::{LivelinessGroupChange, LivelinessGroupView},
+    };
     pub use crate::api::{
         builders::liveliness::{
             LivelinessGetBuilder, LivelinessSubscriberBuilder, LivelinessTokenBuilder,
@@ -1009,6 +1080,8 @@ pub mod time {
 /// let session = zenoh::open(config).await.unwrap();
 /// # }
 pub mod config {
+    #[zenoh_macros::unstable]
+    pub use zenoh_config::EndPointBuilder;
     pub use zenoh_config::{EndPoint, EndPoints, Locator, WhatAmI, WhatAmIMatcher, ZenohId};
 
     pub use crate::api::config::Config;
@@ -1071,7 +1144,9 @@ pub mod internal {
     #[cfg(feature = "plugins")]
     pub mod plugins {
         pub use crate::api::plugins::{
-            PluginsManager, Response, RunningPlugin, RunningPluginTrait, ZenohPlugin, PLUGIN_PREFIX,
+            IngressMessage, IngressSample, IngressValidator, IngressVerdict, PluginEnv,
+            PluginsManager, Response, RunningPlugin, RunningPluginTrait, ZenohPlugin,
+            PLUGIN_PREFIX,
         };
     }
 
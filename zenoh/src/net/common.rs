@@ -2,6 +2,7 @@ use zenoh_config::{
     unwrap_or_default, AutoConnectStrategy, Config, ModeDependent, TargetDependentValue,
 };
 use zenoh_protocol::core::{WhatAmI, WhatAmIMatcher, ZenohIdProto};
+use zenoh_result::{bail, ZResult};
 
 /// Auto-connection manager, combining autoconnect matcher and strategy from the config.
 #[derive(Clone, Copy)]
@@ -70,3 +71,33 @@ impl AutoConnect {
         self.matcher.matches(what) && strategy()
     }
 }
+
+/// Gossip scouting settings of the routing networks.
+#[derive(Clone, Copy)]
+pub(crate) struct GossipConf {
+    pub(crate) enabled: bool,
+    pub(crate) multihop: bool,
+    pub(crate) target: WhatAmIMatcher,
+    pub(crate) autoconnect: AutoConnect,
+}
+
+impl GossipConf {
+    /// Builds a `GossipConf` from the gossip config.
+    pub(crate) fn new(config: &Config, what: WhatAmI, zid: ZenohIdProto) -> ZResult<Self> {
+        let enabled = unwrap_or_default!(config.scouting().gossip().enabled());
+        let target = *unwrap_or_default!(config.scouting().gossip().target().get(what));
+        if target.matches(WhatAmI::Client) {
+            bail!("\"client\" is not allowed as gossip target")
+        }
+        Ok(Self {
+            enabled,
+            multihop: unwrap_or_default!(config.scouting().gossip().multihop()),
+            target,
+            autoconnect: if enabled {
+                AutoConnect::gossip(config, what, zid)
+            } else {
+                AutoConnect::disabled()
+            },
+        })
+    }
+}
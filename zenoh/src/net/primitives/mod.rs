@@ -14,7 +14,7 @@
 mod demux;
 mod mux;
 
-use std::any::Any;
+use std::{any::Any, cell::Cell, time::Duration};
 
 pub use demux::*;
 pub use mux::*;
@@ -25,16 +25,57 @@ use zenoh_protocol::{
 
 use super::routing::RoutingContext;
 
+/// Bounds the time a non-droppable push may block on congested transports.
+///
+/// It is handed down along with the message and records whether a transport
+/// dropped the message because the timeout expired.
+#[derive(Debug)]
+pub struct BlockTimeout {
+    timeout: Duration,
+    expired: Cell<bool>,
+}
+
+impl BlockTimeout {
+    #[cfg(feature = "unstable")]
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            expired: Cell::new(false),
+        }
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Returns whether the message has been dropped by at least one transport
+    /// because the timeout expired.
+    #[cfg(feature = "unstable")]
+    pub fn expired(&self) -> bool {
+        self.expired.get()
+    }
+
+    pub(crate) fn set_expired(&self) {
+        self.expired.set(true);
+    }
+}
+
 pub trait Primitives: Send + Sync {
     fn send_interest(&self, msg: &mut Interest);
 
     fn send_declare(&self, msg: &mut Declare);
 
-    fn send_push_consume(&self, msg: &mut Push, reliability: Reliability, consume: bool);
+    fn send_push_consume(
+        &self,
+        msg: &mut Push,
+        reliability: Reliability,
+        consume: bool,
+        block_timeout: Option<&BlockTimeout>,
+    );
 
     #[inline(always)]
     fn send_push(&self, msg: &mut Push, reliability: Reliability) {
-        self.send_push_consume(msg, reliability, true)
+        self.send_push_consume(msg, reliability, true, None)
     }
 
     fn send_request(&self, msg: &mut Request);
@@ -56,7 +97,12 @@ pub(crate) trait EPrimitives: Send + Sync {
 
     fn send_declare(&self, ctx: RoutingContext<&mut Declare>) -> bool;
 
-    fn send_push(&self, msg: &mut Push, reliability: Reliability) -> bool;
+    fn send_push(
+        &self,
+        msg: &mut Push,
+        reliability: Reliability,
+        block_timeout: Option<&BlockTimeout>,
+    ) -> bool;
 
     fn send_request(&self, msg: &mut Request) -> bool;
 
@@ -73,7 +119,14 @@ impl Primitives for DummyPrimitives {
 
     fn send_declare(&self, _msg: &mut Declare) {}
 
-    fn send_push_consume(&self, _msg: &mut Push, _reliability: Reliability, _consume: bool) {}
+    fn send_push_consume(
+        &self,
+        _msg: &mut Push,
+        _reliability: Reliability,
+        _consume: bool,
+        _block_timeout: Option<&BlockTimeout>,
+    ) {
+    }
 
     fn send_request(&self, _msg: &mut Request) {}
 
@@ -97,7 +150,12 @@ impl EPrimitives for DummyPrimitives {
         false
     }
 
-    fn send_push(&self, _msg: &mut Push, _reliability: Reliability) -> bool {
+    fn send_push(
+        &self,
+        _msg: &mut Push,
+        _reliability: Reliability,
+        _block_timeout: Option<&BlockTimeout>,
+    ) -> bool {
         false
     }
 
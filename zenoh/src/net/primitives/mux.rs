@@ -27,7 +27,7 @@ use zenoh_protocol::{
 };
 use zenoh_transport::{multicast::TransportMulticast, unicast::TransportUnicast};
 
-use super::{EPrimitives, Primitives};
+use super::{BlockTimeout, EPrimitives, Primitives};
 use crate::net::routing::{
     dispatcher::face::{Face, WeakFace},
     gateway::{InterceptorCacheValueType, Resource},
@@ -175,12 +175,33 @@ impl EPrimitives for Mux {
             && self.handler.schedule(msg).unwrap_or(false)
     }
 
-    fn send_push(&self, msg: &mut Push, reliability: Reliability) -> bool {
-        let msg = NetworkMessageMut {
+    fn send_push(
+        &self,
+        msg: &mut Push,
+        reliability: Reliability,
+        block_timeout: Option<&BlockTimeout>,
+    ) -> bool {
+        let mut msg = NetworkMessageMut {
             body: NetworkBodyMut::Push(msg),
             reliability,
         };
-        self.schedule(msg)
+        let Some(block_timeout) = block_timeout else {
+            return self.schedule(msg);
+        };
+        if !self.can_schedule(&mut msg) {
+            return false;
+        }
+        match self
+            .handler
+            .schedule_with_block_timeout(msg, block_timeout.timeout())
+        {
+            Ok(true) => true,
+            Ok(false) => {
+                block_timeout.set_expired();
+                false
+            }
+            Err(_) => false,
+        }
     }
 
     fn send_request(&self, msg: &mut Request) -> bool {
@@ -367,12 +388,33 @@ impl EPrimitives for McastMux {
         }
     }
 
-    fn send_push(&self, msg: &mut Push, reliability: Reliability) -> bool {
-        let msg = NetworkMessageMut {
+    fn send_push(
+        &self,
+        msg: &mut Push,
+        reliability: Reliability,
+        block_timeout: Option<&BlockTimeout>,
+    ) -> bool {
+        let mut msg = NetworkMessageMut {
             body: NetworkBodyMut::Push(msg),
             reliability,
         };
-        self.schedule(msg)
+        let Some(block_timeout) = block_timeout else {
+            return self.schedule(msg);
+        };
+        if !self.can_schedule(&mut msg) {
+            return false;
+        }
+        match self
+            .handler
+            .schedule_with_block_timeout(msg, block_timeout.timeout())
+        {
+            Ok(true) => true,
+            Ok(false) => {
+                block_timeout.set_expired();
+                false
+            }
+            Err(_) => false,
+        }
     }
 
     fn send_request(&self, msg: &mut Request) -> bool {
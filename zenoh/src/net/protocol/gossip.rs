@@ -13,7 +13,7 @@
 //
 use std::convert::TryInto;
 
-use petgraph::graph::NodeIndex;
+use petgraph::{graph::NodeIndex, visit::IntoNodeReferences};
 use vec_map::VecMap;
 use zenoh_buffers::{
     writer::{DidntWrite, HasWriter},
@@ -35,8 +35,8 @@ use zenoh_transport::unicast::TransportUnicast;
 
 use crate::net::{
     codec::Zenoh080Routing,
-    common::AutoConnect,
-    protocol::linkstate::{LinkState, LinkStateList},
+    common::{AutoConnect, GossipConf},
+    protocol::linkstate::{LinkState, LinkStateList, LinkstateGraph, LinkstateGraphNode},
     runtime::{Runtime, WeakRuntime},
 };
 
@@ -144,10 +144,38 @@ impl Gossip {
         }
     }
 
+    pub(crate) fn update_gossip(&mut self, gossip: GossipConf) {
+        tracing::info!("{} Update gossip (target: {})", &self.name, gossip.target);
+        self.gossip_target = gossip.target;
+        self.autoconnect = gossip.autoconnect;
+    }
+
     pub(crate) fn dot(&self) -> String {
         std::format!("{:?}", petgraph::dot::Dot::new(&self.graph))
     }
 
+    /// The gossip graph only holds nodes: the returned graph has no edges.
+    pub(crate) fn linkstate_graph(&self) -> LinkstateGraph {
+        LinkstateGraph {
+            nodes: self
+                .graph
+                .node_references()
+                .map(|(idx, node)| LinkstateGraphNode {
+                    zid: node.zid,
+                    whatami: node.whatami,
+                    locators: if idx == self.idx {
+                        self.runtime.upgrade().map(|rt| rt.get_locators())
+                    } else {
+                        node.locators.clone()
+                    },
+                    is_gateway: node.is_gateway,
+                    local: idx == self.idx,
+                })
+                .collect(),
+            edges: Vec::new(),
+        }
+    }
+
     #[inline]
     fn get_idx(&self, zid: &ZenohIdProto) -> Option<NodeIndex> {
         self.graph
@@ -387,7 +415,7 @@ impl Gossip {
                 if let Some(locators) = locators {
                     let runtime = strong_runtime.clone();
                     let wait_declares = self.wait_declares;
-                    strong_runtime.spawn(async move {
+                    strong_runtime.spawn(format!("zenoh:connect:{zid}"), async move {
                         if runtime
                             .manager()
                             .get_transport_unicast(&zid)
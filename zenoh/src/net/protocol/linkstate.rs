@@ -224,3 +224,27 @@ pub(crate) struct LinkInfo {
     pub(crate) dst_weight: Option<u16>,
     pub(crate) actual_weight: u16,
 }
+
+/// Snapshot of a linkstate graph, as exposed in the admin space.
+#[derive(Debug, Default, serde::Serialize)]
+pub(crate) struct LinkstateGraph {
+    pub(crate) nodes: Vec<LinkstateGraphNode>,
+    pub(crate) edges: Vec<LinkstateGraphEdge>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct LinkstateGraphNode {
+    pub(crate) zid: ZenohIdProto,
+    pub(crate) whatami: Option<WhatAmI>,
+    pub(crate) locators: Option<Vec<Locator>>,
+    pub(crate) is_gateway: bool,
+    /// Whether this node is the local node.
+    pub(crate) local: bool,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct LinkstateGraphEdge {
+    pub(crate) src: ZenohIdProto,
+    pub(crate) dst: ZenohIdProto,
+    pub(crate) weight: f64,
+}
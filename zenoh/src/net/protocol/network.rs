@@ -20,7 +20,7 @@ use std::{
 use itertools::Itertools;
 use petgraph::{
     graph::NodeIndex,
-    visit::{EdgeRef, IntoNodeReferences, VisitMap, Visitable},
+    visit::{EdgeRef, IntoEdgeReferences, IntoNodeReferences, VisitMap, Visitable},
 };
 use rand::Rng;
 use vec_map::VecMap;
@@ -43,8 +43,11 @@ use zenoh_transport::unicast::TransportUnicast;
 use super::linkstate::LinkInfo;
 use crate::net::{
     codec::Zenoh080Routing,
-    common::AutoConnect,
-    protocol::linkstate::{LinkEdgeWeight, LinkState, LinkStateList, LocalLinkState},
+    common::{AutoConnect, GossipConf},
+    protocol::linkstate::{
+        LinkEdgeWeight, LinkState, LinkStateList, LinkstateGraph, LinkstateGraphEdge,
+        LinkstateGraphNode, LocalLinkState,
+    },
     routing::dispatcher::tables::NodeId,
     runtime::{Runtime, WeakRuntime},
 };
@@ -252,10 +255,53 @@ impl Network {
         true
     }
 
+    pub(crate) fn update_gossip(&mut self, gossip: GossipConf) {
+        tracing::info!(
+            "{} Update gossip (enabled: {}, multihop: {}, target: {})",
+            &self.name,
+            gossip.enabled,
+            gossip.multihop,
+            gossip.target
+        );
+        self.gossip = gossip.enabled;
+        self.gossip_multihop = gossip.multihop;
+        self.gossip_target = gossip.target;
+        self.autoconnect = gossip.autoconnect;
+    }
+
     pub(crate) fn dot(&self) -> String {
         std::format!("{:?}", petgraph::dot::Dot::new(&self.graph))
     }
 
+    pub(crate) fn linkstate_graph(&self) -> LinkstateGraph {
+        LinkstateGraph {
+            nodes: self
+                .graph
+                .node_references()
+                .map(|(idx, node)| LinkstateGraphNode {
+                    zid: node.zid,
+                    whatami: node.whatami,
+                    locators: if idx == self.idx {
+                        self.runtime.upgrade().map(|rt| rt.get_locators())
+                    } else {
+                        node.locators.clone()
+                    },
+                    is_gateway: node.is_gateway,
+                    local: idx == self.idx,
+                })
+                .collect(),
+            edges: self
+                .graph
+                .edge_references()
+                .map(|e| LinkstateGraphEdge {
+                    src: self.graph[e.source()].zid,
+                    dst: self.graph[e.target()].zid,
+                    weight: *e.weight(),
+                })
+                .collect(),
+        }
+    }
+
     #[inline]
     pub(crate) fn get_idx(&self, zid: &ZenohIdProto) -> Option<NodeIndex> {
         self.graph
@@ -632,7 +678,8 @@ impl Network {
 
     fn connect_discovered_peer(&self, zid: ZenohIdProto, locators: Vec<Locator>) {
         let runtime = self.runtime.upgrade().unwrap();
-        self.runtime.upgrade().unwrap().spawn(async move {
+        let name = format!("zenoh:connect:{zid}");
+        self.runtime.upgrade().unwrap().spawn(name, async move {
             if runtime
                 .manager()
                 .get_transport_unicast(&zid)
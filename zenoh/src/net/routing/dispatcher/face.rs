@@ -40,7 +40,7 @@ use super::{
     super::gateway::*, interests::PendingCurrentInterest, resource::*, tables::TablesLock,
 };
 use crate::net::{
-    primitives::{EPrimitives, McastMux, Mux, Primitives},
+    primitives::{BlockTimeout, EPrimitives, McastMux, Mux, Primitives},
     routing::{
         dispatcher::{
             interests::{finalize_pending_interests, RemoteInterest},
@@ -668,7 +668,13 @@ impl Primitives for Face {
     }
 
     #[inline]
-    fn send_push_consume(&self, msg: &mut Push, reliability: Reliability, consume: bool) {
+    fn send_push_consume(
+        &self,
+        msg: &mut Push,
+        reliability: Reliability,
+        consume: bool,
+        block_timeout: Option<&BlockTimeout>,
+    ) {
         let _span = tracing::enabled!(tracing::Level::DEBUG).then(|| {
             tracing::debug_span!(
                 "send_push",
@@ -679,7 +685,14 @@ impl Primitives for Face {
             .entered()
         });
 
-        route_data(&self.tables, &self.state, msg, reliability, consume);
+        route_data(
+            &self.tables,
+            &self.state,
+            msg,
+            reliability,
+            consume,
+            block_timeout,
+        );
     }
 
     #[tracing::instrument(level = "debug", skip(msg), fields(id = msg.id, expr = %msg.wire_expr), ret)]
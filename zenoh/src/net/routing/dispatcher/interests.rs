@@ -143,14 +143,17 @@ impl CurrentInterestCleanup {
         if let Some(pending_interest) = face.pending_current_interests.get(&id) {
             let cancellation_token = pending_interest.cancellation_token.clone();
             let rejection_token = pending_interest.rejection_token.clone();
-            face.task_controller
-                .spawn_with_rt(zenoh_runtime::ZRuntime::Net, async move {
+            face.task_controller.spawn_named_with_rt(
+                "zenoh:interest_timeout",
+                zenoh_runtime::ZRuntime::Net,
+                async move {
                     tokio::select! {
                         _ = tokio::time::sleep(cleanup.interests_timeout) => { cleanup.run().await }
                         _ = cancellation_token.cancelled() => {}
                         _ = rejection_token.cancelled() => { cleanup.execute(false).await }
                     }
-                });
+                },
+            );
         }
     }
 
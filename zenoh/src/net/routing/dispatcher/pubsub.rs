@@ -26,14 +26,17 @@ use super::{
     resource::Resource,
     tables::{NodeId, Route, RoutingExpr, Tables, TablesLock},
 };
-use crate::net::routing::{
-    dispatcher::{
-        face::Face,
-        local_resources::{LocalResourceInfoTrait, LocalResources},
-        tables::InterRegionFilter,
+use crate::net::{
+    primitives::BlockTimeout,
+    routing::{
+        dispatcher::{
+            face::Face,
+            local_resources::{LocalResourceInfoTrait, LocalResources},
+            tables::InterRegionFilter,
+        },
+        gateway::{get_or_set_route, node_id_as_source, Direction, RouteBuilder},
+        hat::{DispatcherContext, SendDeclare},
     },
-    gateway::{get_or_set_route, node_id_as_source, Direction, RouteBuilder},
-    hat::{DispatcherContext, SendDeclare},
 };
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -235,6 +238,7 @@ pub fn route_data(
     msg: &mut Push,
     reliability: Reliability,
     consume: bool,
+    block_timeout: Option<&BlockTimeout>,
 ) {
     let rtables = zread!(tables_ref.tables);
     let tables = &*rtables;
@@ -270,7 +274,10 @@ pub fn route_data(
     }
 
     let send_push = |dst_face: &FaceState, msg: &mut Push, reliability: Reliability| {
-        if dst_face.primitives.send_push(msg, reliability) {
+        if dst_face
+            .primitives
+            .send_push(msg, reliability, block_timeout)
+        {
             #[cfg(feature = "stats")]
             payload_observer.observe_payload(zenoh_stats::Tx, dst_face, msg);
         }
@@ -24,7 +24,7 @@ use zenoh_buffers::ZBuf;
 #[allow(unused_imports)]
 use zenoh_core::polyfill::*;
 use zenoh_protocol::{
-    core::{Encoding, Region, WireExpr},
+    core::{Encoding, Priority, Region, WireExpr},
     network::{
         declare::{queryable::ext::QueryableInfoType, QueryableId},
         request::{self, ext::QueryTarget, Request, RequestId},
@@ -431,6 +431,25 @@ fn insert_pending_query(outface: &mut Arc<FaceState>, query: Arc<Query>) -> Requ
     qid
 }
 
+// Computes the QoS of a reply routed back to the querier.
+//
+// The congestion control is always the one of the query. The priority and express policy are
+// the ones of the original query, unless the replier explicitly set them, as indicated by the
+// replier QoS extension: the ones the query was received with may come from egress interceptors
+// and must not leak into the reply. Replies cannot use the priority reserved to control messages.
+fn reply_qos(
+    src_qos: response::ext::QoSType,
+    reply_qos: response::ext::QoSType,
+    replier_qos: bool,
+) -> response::ext::QoSType {
+    let mut qos = src_qos;
+    if replier_qos {
+        qos.set_priority(reply_qos.get_priority().max(Priority::RealTime));
+        qos.set_is_express(reply_qos.is_express());
+    }
+    qos
+}
+
 #[derive(Clone)]
 struct QueryCleanup {
     tables: Arc<TablesLock>,
@@ -459,13 +478,16 @@ impl QueryCleanup {
         if let Some((_, cancellation_token)) = face.pending_queries.get(&qid) {
             let c_cancellation_token = cancellation_token.clone();
             drop(queries_lock);
-            face.task_controller
-                .spawn_with_rt(zenoh_runtime::ZRuntime::Net, async move {
+            face.task_controller.spawn_named_with_rt(
+                "zenoh:query_timeout",
+                zenoh_runtime::ZRuntime::Net,
+                async move {
                     tokio::select! {
                         _ = tokio::time::sleep(timeout) => { cleanup.run().await }
                         _ = c_cancellation_token.cancelled() => {}
                     }
-                });
+                },
+            );
         }
     }
 }
@@ -497,6 +519,7 @@ impl Timed for QueryCleanup {
                     ext_respid,
                     // TODO: Maybe this should be set?
                     ext_ts_stack: None,
+                    ext_replier_qos: None,
                 },
             );
             let queries_lock = zwrite!(self.tables.queries_lock);
@@ -603,7 +626,8 @@ pub(crate) fn route_send_response(
                     drop(queries_lock);
 
                     msg.rid = query.src_qid;
-                    msg.ext_qos = query.src_qos;
+                    msg.ext_qos =
+                        reply_qos(query.src_qos, msg.ext_qos, msg.ext_replier_qos.is_some());
                     #[cfg(feature = "unstable")]
                     {
                         let weak = weak_runtime.clone();
@@ -728,3 +752,42 @@ impl LocalResourceInfoTrait<Arc<Resource>> for QueryableInfoType {
 }
 
 pub(crate) type LocalQueryables = LocalResources<QueryableId, Arc<Resource>, QueryableInfoType>;
+
+#[cfg(test)]
+mod tests {
+    use zenoh_protocol::core::CongestionControl;
+
+    use super::*;
+
+    fn qos(priority: Priority, express: bool) -> response::ext::QoSType {
+        response::ext::QoSType::new(priority, CongestionControl::Drop, express)
+    }
+
+    #[test]
+    fn reply_qos_keeps_replier_choice() {
+        let src = response::ext::QoSType::new(Priority::DataLow, CongestionControl::Block, false);
+        let reply = reply_qos(src, qos(Priority::DataHigh, true), true);
+        assert_eq!(reply.get_priority(), Priority::DataHigh);
+        assert!(reply.is_express());
+        assert_eq!(reply.get_congestion_control(), CongestionControl::Block);
+
+        // The replier may explicitly choose the QoS of the query
+        let reply = reply_qos(src, qos(Priority::DataLow, false), true);
+        assert_eq!(reply.get_priority(), Priority::DataLow);
+        assert!(!reply.is_express());
+    }
+
+    #[test]
+    fn reply_qos_ignores_received_query_qos() {
+        let src = qos(Priority::DataLow, false);
+        let reply = reply_qos(src, qos(Priority::RealTime, true), false);
+        assert_eq!(reply, src);
+    }
+
+    #[test]
+    fn reply_qos_clamps_control_priority() {
+        let src = qos(Priority::DataLow, false);
+        let reply = reply_qos(src, qos(Priority::Control, false), true);
+        assert_eq!(reply.get_priority(), Priority::RealTime);
+    }
+}
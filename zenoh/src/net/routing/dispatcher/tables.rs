@@ -17,7 +17,7 @@ use std::{
     collections::HashMap,
     fmt::Debug,
     sync::{
-        atomic::{AtomicUsize, Ordering},
+        atomic::{AtomicU64, AtomicUsize, Ordering},
         Arc, Mutex, RwLock,
     },
     time::Duration,
@@ -38,10 +38,21 @@ use crate::net::{
     routing::{
         dispatcher::{face::FaceId, region::RegionMap},
         hat::{HatTrait, Sources},
-        interceptor::{interceptor_factories, InterceptorFactory},
+        interceptor::{
+            interceptor_factories,
+            payload_limit::{payload_limit_interceptor_factory, PayloadSizeLimits},
+            InterceptorFactory,
+        },
     },
     runtime::WeakRuntime,
 };
+#[cfg(feature = "plugins")]
+use crate::{
+    api::plugins::IngressValidator,
+    net::routing::interceptor::ingress_validation::{
+        ingress_validation_interceptor_factory, IngressValidators,
+    },
+};
 
 pub(crate) struct RoutingExpr<'a> {
     prefix: &'a Arc<Resource>,
@@ -135,6 +146,10 @@ pub(crate) struct TablesData {
 
     pub(crate) next_interceptor_version: AtomicUsize,
     pub(crate) interceptors: Vec<InterceptorFactory>,
+    #[cfg(feature = "plugins")]
+    pub(crate) ingress_validators: Arc<IngressValidators>,
+    /// The number of publications dropped on ingress for exceeding the payload size limits.
+    pub(crate) payload_limit_dropped: Arc<AtomicU64>,
 
     pub(crate) faces: HashMap<FaceId, Arc<FaceState>>,
 
@@ -192,6 +207,19 @@ impl TablesData {
             config.stats.filters().iter().map(|f| &*f.key),
         );
 
+        #[cfg(feature = "plugins")]
+        let ingress_validators = Arc::<IngressValidators>::default();
+        let payload_limit_dropped = Arc::<AtomicU64>::default();
+        let mut interceptors = interceptor_factories(config)?;
+        #[cfg(feature = "plugins")]
+        interceptors.push(ingress_validation_interceptor_factory(&ingress_validators));
+        if let Some(limits) = PayloadSizeLimits::new(config.limits()) {
+            interceptors.push(payload_limit_interceptor_factory(
+                limits,
+                &payload_limit_dropped,
+            ));
+        }
+
         Ok(TablesData {
             zid,
             runtime: None,
@@ -200,7 +228,10 @@ impl TablesData {
             queries_default_timeout,
             interests_timeout,
             root_res: Resource::root(),
-            interceptors: interceptor_factories(config)?,
+            interceptors,
+            #[cfg(feature = "plugins")]
+            ingress_validators,
+            payload_limit_dropped,
             next_interceptor_version: AtomicUsize::new(0),
             hats: hat,
             face_counter: 0,
@@ -534,7 +565,32 @@ impl TablesLock {
             );
         }
         tables.data.interceptors = interceptor_factories(config)?;
+        #[cfg(feature = "plugins")]
+        {
+            let factory = ingress_validation_interceptor_factory(&tables.data.ingress_validators);
+            tables.data.interceptors.push(factory);
+        }
+        if let Some(limits) = PayloadSizeLimits::new(config.limits()) {
+            let factory =
+                payload_limit_interceptor_factory(limits, &tables.data.payload_limit_dropped);
+            tables.data.interceptors.push(factory);
+        }
         drop(tables);
+        self.refresh_interceptors();
+        Ok(())
+    }
+
+    /// Replaces the ingress validators registered by the plugins.
+    #[cfg(feature = "plugins")]
+    pub(crate) fn set_ingress_validators(
+        &self,
+        validators: Vec<(String, Arc<dyn IngressValidator>)>,
+    ) {
+        zread!(self.tables).data.ingress_validators.set(validators);
+        self.refresh_interceptors();
+    }
+
+    fn refresh_interceptors(&self) {
         let tables = zread!(self.tables);
         let version = tables
             .data
@@ -543,6 +599,5 @@ impl TablesLock {
         tables.data.faces.values().for_each(|face| {
             face.set_interceptors_from_factories(&tables.data.interceptors, version + 1);
         });
-        Ok(())
     }
 }
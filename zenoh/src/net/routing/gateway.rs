@@ -112,7 +112,11 @@ impl<'conf> GatewayBuilder<'conf> {
                     WhatAmI::Peer => {
                         regions.push(Region::default_south(WhatAmI::Client));
                     }
-                    WhatAmI::Client => {}
+                    WhatAmI::Client => {
+                        if self.config.gateway.accepts_clients(mode) {
+                            regions.push(Region::default_south(WhatAmI::Client));
+                        }
+                    }
                 },
                 GatewaySouthConf::Custom(subregions) => {
                     for (id, _) in subregions.iter().enumerate() {
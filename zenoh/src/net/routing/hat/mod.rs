@@ -48,7 +48,10 @@ use super::{
     RoutingContext,
 };
 use crate::net::{
-    protocol::{linkstate::LinkInfo, network::SuccessorEntry},
+    protocol::{
+        linkstate::{LinkInfo, LinkstateGraph},
+        network::SuccessorEntry,
+    },
     routing::dispatcher::{
         interests::{CurrentInterest, RemoteInterest},
         region::RegionMap,
@@ -218,6 +221,11 @@ pub(crate) trait HatBaseTrait: Any {
 
     fn info(&self) -> String;
 
+    /// Returns a snapshot of the linkstate graph of this hat, including the local node.
+    fn linkstate_graph(&self) -> LinkstateGraph {
+        LinkstateGraph::default()
+    }
+
     fn close_face(&mut self, ctx: DispatcherContext);
 
     fn update_from_config(
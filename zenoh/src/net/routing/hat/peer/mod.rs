@@ -26,7 +26,7 @@ use std::{
 };
 
 use petgraph::graph::NodeIndex;
-use zenoh_config::{unwrap_or_default, ModeDependent};
+use zenoh_config::unwrap_or_default;
 use zenoh_protocol::{
     common::ZExtBody,
     core::{Bound, Region, WhatAmI, ZenohIdProto},
@@ -52,7 +52,7 @@ use crate::net::{
     codec::Zenoh080Routing,
     protocol::{
         gossip::Gossip,
-        linkstate::{LinkState, LinkStateList},
+        linkstate::{LinkState, LinkStateList, LinkstateGraph},
         network::Network,
     },
     routing::{
@@ -72,7 +72,7 @@ mod pubsub;
 mod queries;
 mod token;
 
-use crate::net::common::AutoConnect;
+use crate::net::common::GossipConf;
 
 pub(crate) enum Hat {
     /// Uninitialized value.
@@ -177,18 +177,7 @@ impl HatBaseTrait for Hat {
     fn init(&mut self, tables: &mut TablesData, runtime: Runtime) -> ZResult<()> {
         let config_guard = runtime.config().lock();
         let config = &config_guard;
-        let whatami = config.mode();
-        let is_gossip_enabled = unwrap_or_default!(config.scouting().gossip().enabled());
-        let is_gossip_multihop_enabled = unwrap_or_default!(config.scouting().gossip().multihop());
-        let gossip_target = *unwrap_or_default!(config.scouting().gossip().target().get(whatami));
-        if gossip_target.matches(WhatAmI::Client) {
-            bail!("\"client\" is not allowed as gossip target")
-        }
-        let autoconnect = if is_gossip_enabled {
-            AutoConnect::gossip(config, whatami, runtime.zid().into())
-        } else {
-            AutoConnect::disabled()
-        };
+        let gossip = GossipConf::new(config, config.mode(), runtime.zid().into())?;
         let wait_declares = unwrap_or_default!(config.open().return_conditions().declares());
         drop(config_guard);
 
@@ -200,8 +189,8 @@ impl HatBaseTrait for Hat {
 
         match region.bound() {
             Bound::North => {
-                if is_gossip_enabled {
-                    if is_gossip_multihop_enabled {
+                if gossip.enabled {
+                    if gossip.multihop {
                         *self = Self::Network {
                             region,
                             network: Some(Network::new(
@@ -209,10 +198,10 @@ impl HatBaseTrait for Hat {
                                 tables.zid,
                                 runtime,
                                 false,
-                                is_gossip_enabled,
-                                is_gossip_multihop_enabled,
-                                gossip_target,
-                                autoconnect,
+                                gossip.enabled,
+                                gossip.multihop,
+                                gossip.target,
+                                gossip.autoconnect,
                                 HashMap::new(),
                                 Bound::North,
                             )),
@@ -223,8 +212,8 @@ impl HatBaseTrait for Hat {
                                 NAME.to_string(),
                                 tables.zid,
                                 runtime,
-                                gossip_target,
-                                autoconnect,
+                                gossip.target,
+                                gossip.autoconnect,
                                 wait_declares,
                             )),
                         };
@@ -244,10 +233,10 @@ impl HatBaseTrait for Hat {
                         tables.zid,
                         runtime,
                         false,
-                        is_gossip_enabled,
-                        is_gossip_multihop_enabled,
-                        gossip_target,
-                        autoconnect,
+                        gossip.enabled,
+                        gossip.multihop,
+                        gossip.target,
+                        gossip.autoconnect,
                         HashMap::new(),
                         Bound::South,
                     )),
@@ -403,6 +392,44 @@ impl HatBaseTrait for Hat {
             .unwrap_or_else(|| "graph {}".to_string())
     }
 
+    fn update_from_config(
+        &mut self,
+        _tables_ref: &Arc<TablesLock>,
+        runtime: &Runtime,
+    ) -> ZResult<()> {
+        let config = runtime.config().lock();
+        let gossip = GossipConf::new(&config, config.mode(), runtime.zid().into())?;
+        drop(config);
+
+        // Switching between the gossip and the network implementations is not supported at
+        // runtime: only the settings of the current implementation are updated.
+        match self {
+            Self::Uninit(..) => unreachable!(),
+            Self::Gossip { gossip: Some(net) } if gossip.enabled && !gossip.multihop => {
+                net.update_gossip(gossip)
+            }
+            Self::Network {
+                region,
+                network: Some(net),
+            } if region.bound().is_south() || (gossip.enabled && gossip.multihop) => {
+                net.update_gossip(gossip)
+            }
+            Self::Gossip { gossip: None } if !gossip.enabled => {}
+            _ => tracing::warn!(
+                "{:?} Enabling/disabling gossip or multihop gossip requires a restart",
+                self
+            ),
+        }
+        Ok(())
+    }
+
+    fn linkstate_graph(&self) -> LinkstateGraph {
+        self.net()
+            .as_ref()
+            .map(|net| net.linkstate_graph())
+            .unwrap_or_default()
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -539,6 +566,13 @@ impl Net<'_> {
             Self::Network(n) => n.dot(),
         }
     }
+
+    pub(crate) fn linkstate_graph(&self) -> LinkstateGraph {
+        match self {
+            Self::Gossip(n) => n.linkstate_graph(),
+            Self::Network(n) => n.linkstate_graph(),
+        }
+    }
 }
 
 pub(crate) enum NetMut<'a> {
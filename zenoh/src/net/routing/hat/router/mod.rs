@@ -26,7 +26,7 @@ use std::{
 };
 
 use itertools::Itertools;
-use zenoh_config::{unwrap_or_default, ModeDependent, WhatAmI};
+use zenoh_config::WhatAmI;
 use zenoh_protocol::{
     common::ZExtBody,
     core::{Region, ZenohIdProto},
@@ -47,7 +47,7 @@ use super::{
 use crate::net::{
     codec::Zenoh080Routing,
     protocol::{
-        linkstate::{link_weights_from_config, LinkStateList},
+        linkstate::{link_weights_from_config, LinkStateList, LinkstateGraph},
         network::{LinkId, Network},
         ROUTERS_NET_NAME,
     },
@@ -64,7 +64,7 @@ mod pubsub;
 mod queries;
 mod token;
 
-use crate::net::{common::AutoConnect, protocol::network::SuccessorEntry};
+use crate::net::{common::GossipConf, protocol::network::SuccessorEntry};
 
 struct TreesComputationWorker {
     _task: TerminatableTask,
@@ -238,18 +238,7 @@ impl HatBaseTrait for Hat {
     fn init(&mut self, tables: &mut TablesData, runtime: Runtime) -> ZResult<()> {
         let config_guard = runtime.config().lock();
         let config = &config_guard;
-        let whatami = config.mode();
-        let gossip = unwrap_or_default!(config.scouting().gossip().enabled());
-        let gossip_multihop = unwrap_or_default!(config.scouting().gossip().multihop());
-        let gossip_target = *unwrap_or_default!(config.scouting().gossip().target().get(whatami));
-        if gossip_target.matches(WhatAmI::Client) {
-            bail!("\"client\" is not allowed as gossip target")
-        }
-        let autoconnect = if gossip {
-            AutoConnect::gossip(config, whatami, runtime.zid().into())
-        } else {
-            AutoConnect::disabled()
-        };
+        let gossip = GossipConf::new(config, config.mode(), runtime.zid().into())?;
 
         let router_link_weights = config
             .routing()
@@ -264,10 +253,10 @@ impl HatBaseTrait for Hat {
             tables.zid,
             runtime.clone(),
             true,
-            gossip,
-            gossip_multihop,
-            gossip_target,
-            autoconnect,
+            gossip.enabled,
+            gossip.multihop,
+            gossip.target,
+            gossip.autoconnect,
             link_weights_from_config(router_link_weights, ROUTERS_NET_NAME)?,
             self.region().bound(),
         ));
@@ -463,6 +452,10 @@ impl HatBaseTrait for Hat {
         self.net().dot()
     }
 
+    fn linkstate_graph(&self) -> LinkstateGraph {
+        self.net().linkstate_graph()
+    }
+
     fn update_from_config(
         &mut self,
         tables_ref: &Arc<TablesLock>,
@@ -478,8 +471,10 @@ impl HatBaseTrait for Hat {
                 .clone(),
             ROUTERS_NET_NAME,
         )?;
+        let gossip = GossipConf::new(&config, config.mode(), runtime.zid().into())?;
         drop(config);
         if let Some(net) = self.routers_net.as_mut() {
+            net.update_gossip(gossip);
             if net.update_link_weights(router_link_weights) {
                 self.compute_trees_async(tables_ref.clone());
             }
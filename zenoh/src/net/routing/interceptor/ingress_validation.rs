@@ -0,0 +1,268 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+//! ⚠️ WARNING ⚠️
+//!
+//! This module is intended for Zenoh's internal use.
+//!
+//! [Click here for Zenoh's documentation](https://docs.rs/zenoh/latest/zenoh)
+use std::{
+    any::Any,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, RwLock,
+    },
+};
+
+use zenoh_buffers::ZBuf;
+use zenoh_config::ZenohId;
+use zenoh_core::{zread, zwrite};
+use zenoh_keyexpr::keyexpr;
+use zenoh_protocol::{
+    network::{NetworkBodyMut, NetworkMessageMut, Push, Request, Response},
+    zenoh::{PushBody, RequestBody, ResponseBody},
+};
+use zenoh_transport::{multicast::TransportMulticast, unicast::TransportUnicast};
+
+use super::{
+    EgressInterceptor, IngressInterceptor, InterceptorContext, InterceptorFactory,
+    InterceptorFactoryTrait, InterceptorTrait,
+};
+use crate::api::{
+    bytes::ZBytes,
+    encoding::Encoding,
+    plugins::{IngressMessage, IngressSample, IngressValidator, IngressVerdict},
+    sample::SampleKind,
+};
+
+/// An [`IngressValidator`] registered by a plugin.
+///
+/// Its first rejection is logged as a warning, and the following ones at debug level so that a
+/// flood of invalid messages does not flood the logs.
+pub(crate) struct RegisteredValidator {
+    name: String,
+    validator: Arc<dyn IngressValidator>,
+    rejected: AtomicU64,
+}
+
+/// The [`IngressValidator`]s registered by the started plugins.
+#[derive(Default)]
+pub(crate) struct IngressValidators(RwLock<Vec<Arc<RegisteredValidator>>>);
+
+impl IngressValidators {
+    /// Replaces the registered validators, keeping the rejection counters of the plugins that
+    /// still register the same validator.
+    pub(crate) fn set(&self, validators: Vec<(String, Arc<dyn IngressValidator>)>) {
+        let mut registered = zwrite!(self.0);
+        *registered = validators
+            .into_iter()
+            .map(|(name, validator)| {
+                registered
+                    .iter()
+                    .find(|r| r.name == name && Arc::ptr_eq(&r.validator, &validator))
+                    .cloned()
+                    .unwrap_or_else(|| {
+                        Arc::new(RegisteredValidator {
+                            name,
+                            validator,
+                            rejected: AtomicU64::new(0),
+                        })
+                    })
+            })
+            .collect();
+    }
+
+    /// Returns the number of publications rejected by each registered validator.
+    pub(crate) fn rejected(&self) -> Vec<(String, u64)> {
+        zread!(self.0)
+            .iter()
+            .map(|r| (r.name.clone(), r.rejected.load(Ordering::Relaxed)))
+            .collect()
+    }
+}
+
+pub(crate) fn ingress_validation_interceptor_factory(
+    validators: &Arc<IngressValidators>,
+) -> InterceptorFactory {
+    Box::new(IngressValidationInterceptorFactory {
+        validators: validators.clone(),
+    })
+}
+
+pub(crate) struct IngressValidationInterceptorFactory {
+    validators: Arc<IngressValidators>,
+}
+
+impl InterceptorFactoryTrait for IngressValidationInterceptorFactory {
+    fn new_transport_unicast(
+        &self,
+        transport: &TransportUnicast,
+    ) -> (Option<IngressInterceptor>, Option<EgressInterceptor>) {
+        let validators = zread!(self.validators.0).clone();
+        if validators.is_empty() {
+            return (None, None);
+        }
+        let source = match transport.get_zid() {
+            Ok(zid) => zid.into(),
+            Err(e) => {
+                tracing::error!("Unable to get zid from transport {:?}: {e}", transport);
+                return (None, None);
+            }
+        };
+        tracing::debug!("New ingress validation transport unicast {:?}", transport);
+        (
+            Some(Box::new(IngressValidationInterceptor {
+                validators,
+                source: Some(source),
+            })),
+            None,
+        )
+    }
+
+    fn new_transport_multicast(
+        &self,
+        _transport: &TransportMulticast,
+    ) -> Option<EgressInterceptor> {
+        None
+    }
+
+    fn new_peer_multicast(&self, transport: &TransportMulticast) -> Option<IngressInterceptor> {
+        let validators = zread!(self.validators.0).clone();
+        if validators.is_empty() {
+            return None;
+        }
+        tracing::debug!("New ingress validation peer multicast {:?}", transport);
+        // The peers of a multicast transport share it: the source is the peer of the face the
+        // message is received on.
+        Some(Box::new(IngressValidationInterceptor {
+            validators,
+            source: None,
+        }))
+    }
+}
+
+pub(crate) struct IngressValidationInterceptor {
+    validators: Vec<Arc<RegisteredValidator>>,
+    source: Option<ZenohId>,
+}
+
+impl InterceptorTrait for IngressValidationInterceptor {
+    fn compute_keyexpr_cache(&self, _key_expr: &keyexpr) -> Option<Box<dyn Any + Send + Sync>> {
+        None
+    }
+
+    fn intercept(&self, msg: &mut NetworkMessageMut, ctx: &mut dyn InterceptorContext) -> bool {
+        let (kind, payload, encoding, attachment) = match &msg.body {
+            NetworkBodyMut::Push(Push { payload, .. }) => {
+                push_body(payload, IngressMessage::Put, IngressMessage::Delete)
+            }
+            NetworkBodyMut::Response(Response {
+                payload: ResponseBody::Reply(reply),
+                ..
+            }) => push_body(
+                &reply.payload,
+                IngressMessage::Reply(SampleKind::Put),
+                IngressMessage::Reply(SampleKind::Delete),
+            ),
+            NetworkBodyMut::Request(Request {
+                payload: RequestBody::Query(query),
+                ..
+            }) => {
+                let (payload, encoding) = match &query.ext_body {
+                    Some(body) => (
+                        ZBytes::from(body.payload.clone()),
+                        Encoding::from(body.encoding.clone()),
+                    ),
+                    None => (ZBytes::new(), Encoding::default()),
+                };
+                (
+                    IngressMessage::Query,
+                    payload,
+                    encoding,
+                    query.ext_attachment.as_ref().map(|a| &a.buffer),
+                )
+            }
+            _ => return true,
+        };
+        let attachment = attachment.map(|attachment| ZBytes::from(attachment.clone()));
+        let Some(source) = self
+            .source
+            .or_else(|| ctx.face().map(|face| face.state.zid.into()))
+        else {
+            return true;
+        };
+        let Some(key_expr) = ctx.full_keyexpr(msg) else {
+            return true;
+        };
+        let sample = IngressSample::new(
+            kind,
+            &key_expr,
+            &payload,
+            &encoding,
+            attachment.as_ref(),
+            source,
+        );
+        for registered in &self.validators {
+            if let IngressVerdict::Reject(reason) = registered.validator.validate(&sample) {
+                let rejected = registered.rejected.fetch_add(1, Ordering::Relaxed) + 1;
+                if let Some(reason) = reason {
+                    if rejected == 1 {
+                        tracing::warn!(
+                            "{:?} on {} from {} rejected by {}: {}. The following rejections of {} are logged at debug level.",
+                            kind,
+                            key_expr,
+                            source,
+                            registered.name,
+                            reason,
+                            registered.name,
+                        );
+                    } else {
+                        tracing::debug!(
+                            "{:?} on {} from {} rejected by {} ({} rejections): {}",
+                            kind,
+                            key_expr,
+                            source,
+                            registered.name,
+                            rejected,
+                            reason
+                        );
+                    }
+                }
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn push_body(
+    body: &PushBody,
+    put: IngressMessage,
+    delete: IngressMessage,
+) -> (IngressMessage, ZBytes, Encoding, Option<&ZBuf>) {
+    match body {
+        PushBody::Put(p) => (
+            put,
+            ZBytes::from(p.payload.clone()),
+            Encoding::from(p.encoding.clone()),
+            p.ext_attachment.as_ref().map(|a| &a.buffer),
+        ),
+        PushBody::Del(d) => (
+            delete,
+            ZBytes::new(),
+            Encoding::default(),
+            d.ext_attachment.as_ref().map(|a| &a.buffer),
+        ),
+    }
+}
@@ -50,6 +50,9 @@ use crate::{
     },
 };
 
+#[cfg(feature = "plugins")]
+pub(crate) mod ingress_validation;
+pub(crate) mod payload_limit;
 pub mod qos_overwrite;
 use crate::net::routing::interceptor::qos_overwrite::qos_overwrite_interceptor_factories;
 
@@ -0,0 +1,225 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+//! ⚠️ WARNING ⚠️
+//!
+//! This module is intended for Zenoh's internal use.
+//!
+//! [Click here for Zenoh's documentation](https://docs.rs/zenoh/latest/zenoh)
+use std::{
+    any::Any,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use zenoh_buffers::buffer::Buffer;
+use zenoh_config::LimitsConf;
+use zenoh_keyexpr::{
+    keyexpr,
+    keyexpr_tree::{IKeyExprTree, IKeyExprTreeMut, IKeyExprTreeNode, KeBoxTree},
+};
+use zenoh_protocol::{
+    network::{NetworkBodyMut, NetworkMessageMut, Push},
+    zenoh::PushBody,
+};
+use zenoh_result::ZResult;
+use zenoh_transport::{multicast::TransportMulticast, unicast::TransportUnicast};
+
+use super::{
+    EgressInterceptor, IngressInterceptor, InterceptorContext, InterceptorFactory,
+    InterceptorFactoryTrait, InterceptorTrait,
+};
+
+/// The payload size limits of the publications, as configured in `limits`.
+#[derive(Default)]
+pub(crate) struct PayloadSizeLimits {
+    max_payload_size: Option<usize>,
+    include_attachment: bool,
+    overrides: KeBoxTree<usize>,
+}
+
+impl PayloadSizeLimits {
+    /// Returns `None` if the configuration does not limit any payload size.
+    pub(crate) fn new(config: &LimitsConf) -> Option<Self> {
+        if config.max_payload_size.is_none() && config.overrides.is_empty() {
+            return None;
+        }
+        let mut overrides = KeBoxTree::default();
+        for limit in &config.overrides {
+            for key_expr in &limit.key_exprs {
+                let max_payload_size = match overrides.weight_at(key_expr) {
+                    Some(&max_payload_size) => limit.max_payload_size.min(max_payload_size),
+                    None => limit.max_payload_size,
+                };
+                overrides.insert(key_expr, max_payload_size);
+            }
+        }
+        Some(PayloadSizeLimits {
+            max_payload_size: config.max_payload_size,
+            include_attachment: config.include_attachment,
+            overrides,
+        })
+    }
+
+    /// Sets the maximum payload size of the publications matching no override.
+    #[cfg(feature = "unstable")]
+    pub(crate) fn set_max_payload_size(&mut self, max_payload_size: usize) {
+        self.max_payload_size = Some(max_payload_size);
+    }
+
+    /// Returns the maximum payload size of the publications on `key_expr`.
+    ///
+    /// The smallest override including `key_expr` takes precedence over `max_payload_size`.
+    /// The admin space is not limited.
+    pub(crate) fn max_payload_size(&self, key_expr: &keyexpr) -> Option<usize> {
+        if key_expr.starts_with("@/") {
+            return None;
+        }
+        self.overrides
+            .nodes_including(key_expr)
+            .filter_map(|node| node.weight().copied())
+            .min()
+            .or(self.max_payload_size)
+    }
+
+    /// Returns the size compared to the limits of a publication with the given sizes.
+    pub(crate) fn size(&self, payload_size: usize, attachment_size: usize) -> usize {
+        if self.include_attachment {
+            payload_size.saturating_add(attachment_size)
+        } else {
+            payload_size
+        }
+    }
+
+    /// Returns an error if a publication with the given sizes on `key_expr` exceeds the limits.
+    pub(crate) fn check(
+        &self,
+        key_expr: &keyexpr,
+        payload_size: usize,
+        attachment_size: usize,
+    ) -> ZResult<()> {
+        let size = self.size(payload_size, attachment_size);
+        match self.max_payload_size(key_expr) {
+            Some(max_payload_size) if size > max_payload_size => bail!(
+                "Publication of {} bytes on {} exceeds the payload size limit of {} bytes",
+                size,
+                key_expr,
+                max_payload_size
+            ),
+            _ => Ok(()),
+        }
+    }
+}
+
+pub(crate) fn payload_limit_interceptor_factory(
+    limits: PayloadSizeLimits,
+    dropped: &Arc<AtomicU64>,
+) -> InterceptorFactory {
+    Box::new(PayloadLimitInterceptorFactory {
+        limits: limits.into(),
+        dropped: dropped.clone(),
+    })
+}
+
+pub(crate) struct PayloadLimitInterceptorFactory {
+    limits: Arc<PayloadSizeLimits>,
+    dropped: Arc<AtomicU64>,
+}
+
+impl InterceptorFactoryTrait for PayloadLimitInterceptorFactory {
+    fn new_transport_unicast(
+        &self,
+        transport: &TransportUnicast,
+    ) -> (Option<IngressInterceptor>, Option<EgressInterceptor>) {
+        tracing::debug!("New payload limit transport unicast {:?}", transport);
+        (
+            Some(Box::new(PayloadLimitInterceptor {
+                limits: self.limits.clone(),
+                dropped: self.dropped.clone(),
+            })),
+            None,
+        )
+    }
+
+    fn new_transport_multicast(
+        &self,
+        _transport: &TransportMulticast,
+    ) -> Option<EgressInterceptor> {
+        None
+    }
+
+    fn new_peer_multicast(&self, transport: &TransportMulticast) -> Option<IngressInterceptor> {
+        tracing::debug!("New payload limit peer multicast {:?}", transport);
+        Some(Box::new(PayloadLimitInterceptor {
+            limits: self.limits.clone(),
+            dropped: self.dropped.clone(),
+        }))
+    }
+}
+
+pub(crate) struct PayloadLimitInterceptor {
+    limits: Arc<PayloadSizeLimits>,
+    dropped: Arc<AtomicU64>,
+}
+
+struct Cache(Option<usize>);
+
+impl InterceptorTrait for PayloadLimitInterceptor {
+    fn compute_keyexpr_cache(&self, key_expr: &keyexpr) -> Option<Box<dyn Any + Send + Sync>> {
+        Some(Box::new(Cache(self.limits.max_payload_size(key_expr))))
+    }
+
+    fn intercept(&self, msg: &mut NetworkMessageMut, ctx: &mut dyn InterceptorContext) -> bool {
+        let NetworkBodyMut::Push(Push { payload, .. }) = &msg.body else {
+            return true;
+        };
+        let (payload_size, attachment_size) = match payload {
+            PushBody::Put(put) => (
+                put.payload.len(),
+                put.ext_attachment
+                    .as_ref()
+                    .map_or(0, |att| att.buffer.len()),
+            ),
+            PushBody::Del(delete) => (
+                0,
+                delete
+                    .ext_attachment
+                    .as_ref()
+                    .map_or(0, |att| att.buffer.len()),
+            ),
+        };
+        let max_payload_size = match ctx.get_cache(msg).and_then(|c| c.downcast_ref::<Cache>()) {
+            Some(Cache(max_payload_size)) => *max_payload_size,
+            None => match ctx.full_keyexpr(msg) {
+                Some(key_expr) => self.limits.max_payload_size(&key_expr),
+                None => return true,
+            },
+        };
+        let size = self.limits.size(payload_size, attachment_size);
+        if let Some(max_payload_size) = max_payload_size {
+            if size > max_payload_size {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+                tracing::debug!(
+                    "Dropping publication of {} bytes exceeding the payload size limit of {} bytes",
+                    size,
+                    max_payload_size,
+                );
+                return false;
+            }
+        }
+        true
+    }
+}
@@ -27,7 +27,7 @@ use zenoh_protocol::{
 };
 
 use super::dispatcher::face::Face;
-use crate::net::primitives::{EPrimitives, Primitives};
+use crate::net::primitives::{BlockTimeout, EPrimitives, Primitives};
 
 pub(crate) struct Namespace {
     namespace: OwnedNonWildKeyExpr,
@@ -98,9 +98,11 @@ impl Primitives for Namespace {
         msg: &mut Push,
         reliability: zenoh_protocol::core::Reliability,
         consume: bool,
+        block_timeout: Option<&BlockTimeout>,
     ) {
         self.handle_namespace_egress(&mut msg.wire_expr, false);
-        self.primitives.send_push_consume(msg, reliability, consume);
+        self.primitives
+            .send_push_consume(msg, reliability, consume, block_timeout);
     }
 
     fn send_request(&self, msg: &mut Request) {
@@ -266,9 +268,14 @@ impl EPrimitives for ENamespace {
         self.handle_declare_ingress(ctx.msg) && self.primitives.send_declare(ctx)
     }
 
-    fn send_push(&self, msg: &mut Push, reliability: zenoh_protocol::core::Reliability) -> bool {
+    fn send_push(
+        &self,
+        msg: &mut Push,
+        reliability: zenoh_protocol::core::Reliability,
+        block_timeout: Option<&BlockTimeout>,
+    ) -> bool {
         self.handle_namespace_ingress(&mut msg.wire_expr, None)
-            && self.primitives.send_push(msg, reliability)
+            && self.primitives.send_push(msg, reliability, block_timeout)
     }
 
     fn send_request(&self, msg: &mut Request) -> bool {
@@ -49,7 +49,7 @@ use zenoh_transport::{multicast::TransportMulticast, unicast::TransportUnicast,
 
 use super::{routing::dispatcher::face::Face, Runtime};
 #[cfg(all(feature = "plugins", feature = "runtime_plugins"))]
-use crate::api::plugins::PluginsManager;
+use crate::api::{loader::update_ingress_validators, plugins::PluginsManager};
 use crate::{
     api::{
         bytes::ZBytes,
@@ -58,7 +58,7 @@ use crate::{
     },
     bytes::Encoding,
     net::{
-        primitives::Primitives,
+        primitives::{BlockTimeout, Primitives},
         routing::{dispatcher::tables::Tables, gateway::Resource, hat::Sources},
         runtime::{region, DynamicRuntime},
     },
@@ -209,17 +209,26 @@ impl AdminSpace {
         add_handler!(local_data);
         add_handler!("metrics", metrics);
         add_handler!("linkstate", "*", linkstate_data);
+        add_handler!("linkstate", "*/graph", linkstate_graph_data);
         add_handler!("subscriber", "**", subscribers_data);
         add_handler!("publisher", "**", publishers_data);
         add_handler!("queryable", "**", queryables_data);
         add_handler!("querier", "**", queriers_data);
         add_handler!("token", "**", tokens_data);
         add_handler!("route/successor", "**", route_successor);
+        add_handler!("connect/retry", connect_retry_data);
+        add_handler!("limits", limits_data);
+        #[cfg(feature = "unstable")]
+        add_handler!("clock_skew", "*", clock_skew_data);
 
         #[cfg(feature = "plugins")]
         add_handler!("plugins", "**", plugins_data);
         #[cfg(feature = "plugins")]
         add_handler!("status/plugins", "**", plugins_status);
+        #[cfg(feature = "plugins")]
+        add_handler!("ingress_validators", ingress_validators_data);
+        #[cfg(feature = "plugins")]
+        add_handler!("plugin_declarations", "*", plugin_declarations_data);
 
         #[cfg(all(feature = "plugins", feature = "runtime_plugins"))]
         let mut active_plugins = runtime
@@ -281,14 +290,23 @@ impl AdminSpace {
                             }
                             diffs.push(PluginDiff::Start(request))
                         }
+                        // The entities of the deleted plugins are undeclared before locking the
+                        // plugins manager, as the undeclarations wait for their callbacks
+                        let state = &admin.context.runtime.state;
+                        for diff in &diffs {
+                            if let PluginDiff::Delete(id) = diff {
+                                state
+                                    .plugin_declarations
+                                    .undeclare(id, state.manager())
+                                    .await;
+                            }
+                        }
                         let mut plugins_mgr = admin.context.runtime.plugins_manager();
                         for diff in diffs {
                             match diff {
                                 PluginDiff::Delete(id) => {
                                     active_plugins.remove(id.as_str());
-                                    if let Some(running) = plugins_mgr.started_plugin_mut(&id) {
-                                        running.stop()
-                                    }
+                                    Runtime::stop_started_plugin(&mut plugins_mgr, &id);
                                 }
                                 PluginDiff::Start(plugin) => {
                                     let dynamic_runtime = admin.context.runtime.clone().into();
@@ -311,6 +329,7 @@ impl AdminSpace {
                                 }
                             }
                         }
+                        update_ingress_validators(&admin.context.runtime, &plugins_mgr);
                     }
                     tracing::info!("Running plugins: {:?}", &active_plugins)
                 }
@@ -382,7 +401,13 @@ impl Primitives for AdminSpace {
         }
     }
 
-    fn send_push_consume(&self, msg: &mut Push, _reliability: Reliability, _consume: bool) {
+    fn send_push_consume(
+        &self,
+        msg: &mut Push,
+        _reliability: Reliability,
+        _consume: bool,
+        _block_timeout: Option<&BlockTimeout>,
+    ) {
         trace!("recv Push {:?}", msg);
         #[cfg(feature = "unstable")]
         {
@@ -412,6 +437,20 @@ impl Primitives for AdminSpace {
             }
         };
 
+        #[cfg(feature = "plugins")]
+        {
+            let plugins_status_key = format!(
+                "@/{}/{}/status/plugins",
+                self.zid, self.context.runtime.state.whatami,
+            );
+            if key_expr.starts_with(&plugins_status_key) {
+                if let PushBody::Put(_) = &msg.payload {
+                    plugins_put(&plugins_status_key, &self.context, &key_expr);
+                }
+                return;
+            }
+        }
+
         static CONFIG_FORMAT: OnceLock<KeFormat<'static, [Segment<'static>; 3]>> = OnceLock::new();
         let config_format = CONFIG_FORMAT.get_or_init(|| {
             KeFormat::noalloc_new("@/${zid:*}/${whatami:*}/config/${key:**}").unwrap()
@@ -549,6 +588,8 @@ impl Primitives for AdminSpace {
                     value: mem::take(&mut query.ext_body)
                         .map(|b| (b.payload.into(), b.encoding.into())),
                     attachment: query.ext_attachment.take().map(Into::into),
+                    #[cfg(feature = "unstable")]
+                    permit: None,
                 };
 
                 for (full_key, (handler, prefix)) in &self.handlers {
@@ -591,7 +632,12 @@ impl crate::net::primitives::EPrimitives for AdminSpace {
     }
 
     #[inline]
-    fn send_push(&self, msg: &mut Push, reliability: Reliability) -> bool {
+    fn send_push(
+        &self,
+        msg: &mut Push,
+        reliability: Reliability,
+        _block_timeout: Option<&BlockTimeout>,
+    ) -> bool {
         (self as &dyn Primitives).send_push(msg, reliability);
         false
     }
@@ -645,6 +691,7 @@ fn local_data(prefix: &keyexpr, context: &AdminContext, query: Query) {
     let links_info = context.runtime.get_links_info();
 
     let config = context.runtime.config().lock().clone();
+    let accept_clients = config.gateway.accepts_clients(config.mode());
     // FIXME(regions): this should not be re-computed (and the config need not be cloned).
     let transport_unicast_to_region = move |transport: &TransportUnicast| -> Option<Region> {
         let peer = transport.get_peer().ok()?;
@@ -658,15 +705,15 @@ fn local_data(prefix: &keyexpr, context: &AdminContext, query: Query) {
         let link_to_json = |link: &Link| {
             json!({
                 "src": link.src.to_string(),
-                "dst": link.dst.to_string()
+                "dst": link.dst.to_string(),
+                "cert_common_name": link.auth_identifier.get_cert_common_name()
             })
         };
-        let links = transport
-            .get_links()
-            .unwrap_or_default()
+        let links = transport.get_links().unwrap_or_default();
+        let cert_common_name = links
             .iter()
-            .map(link_to_json)
-            .collect_vec();
+            .find_map(|link| link.auth_identifier.get_cert_common_name());
+        let links = links.iter().map(link_to_json).collect_vec();
         #[cfg(feature = "shared-memory")]
         let shm = transport.is_shm().unwrap_or_default();
         #[cfg(not(feature = "shared-memory"))]
@@ -675,6 +722,7 @@ fn local_data(prefix: &keyexpr, context: &AdminContext, query: Query) {
             "peer": transport.get_zid().map_or_else(|_| "unknown".to_string(), |p| p.to_string()),
             "whatami": transport.get_whatami().map_or_else(|_| "unknown".to_string(), |p| p.to_string()),
             "links": links,
+            "cert_common_name": cert_common_name,
             "weight": transport.get_zid().ok().and_then(|zid| links_info.get(&zid)),
             "shm": shm,
             "region": transport_unicast_to_region(transport).map_or_else(|| "unknown".to_string(), |r| r.to_string())
@@ -725,6 +773,7 @@ fn local_data(prefix: &keyexpr, context: &AdminContext, query: Query) {
         "version": &*LONG_VERSION,
         "metadata": context.runtime.config().lock().metadata(),
         "locators": locators,
+        "accept_clients": accept_clients,
         "sessions": transports,
         "plugins": plugins,
     });
@@ -876,6 +925,137 @@ fn linkstate_data(prefix: &keyexpr, context: &AdminContext, query: Query) {
     }
 }
 
+/// Replies with a snapshot of each linkstate graph, as JSON or as GraphViz if `format=dot`.
+#[tracing::instrument(level = "trace", skip_all)]
+fn linkstate_graph_data(prefix: &keyexpr, context: &AdminContext, query: Query) {
+    let dot = query.parameters().get("format") == Some("dot");
+    let tables = &context.runtime.state.router.tables;
+    let rtables = zread!(tables.tables);
+
+    let replies = rtables
+        .hats
+        .iter()
+        .filter(|(_, hat)| hat.mode().is_peer() || hat.mode().is_router())
+        .filter_map(|(region, hat)| {
+            let reply_key = prefix / &KeyExpr::try_from(format!("{region}/graph")).unwrap();
+            if !query.key_expr().intersects(&reply_key) {
+                return None;
+            }
+            let payload = if dot {
+                hat.info().into_bytes()
+            } else {
+                serde_json::to_vec(&hat.linkstate_graph()).unwrap()
+            };
+            Some((reply_key, payload))
+        })
+        .collect_vec();
+    drop(rtables);
+
+    let encoding = if dot {
+        Encoding::TEXT_PLAIN
+    } else {
+        Encoding::APPLICATION_JSON
+    };
+    for (reply_key, payload) in replies {
+        if let Err(e) = query
+            .reply(reply_key, payload)
+            .encoding(encoding.clone())
+            .wait()
+        {
+            tracing::error!("Error sending AdminSpace reply: {:?}", e);
+        }
+    }
+}
+
+#[cfg(feature = "unstable")]
+#[tracing::instrument(level = "trace", skip_all)]
+fn clock_skew_data(prefix: &keyexpr, context: &AdminContext, query: Query) {
+    for (zid, skew_nanos) in context.runtime.get_clock_skews() {
+        let reply_key = prefix / &OwnedKeyExpr::from(zid);
+        if query.key_expr().intersects(&reply_key) {
+            if let Err(e) = query
+                .reply(
+                    reply_key,
+                    serde_json::to_vec(&json!({ "skew_ns": skew_nanos })).unwrap(),
+                )
+                .encoding(Encoding::APPLICATION_JSON)
+                .wait()
+            {
+                tracing::error!("Error sending AdminSpace reply: {:?}", e);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "plugins")]
+fn ingress_validators_data(prefix: &keyexpr, context: &AdminContext, query: Query) {
+    let validators: serde_json::Map<String, serde_json::Value> =
+        zread!(context.runtime.state.router.tables.tables)
+            .data
+            .ingress_validators
+            .rejected()
+            .into_iter()
+            .map(|(name, rejected)| (name, json!({ "rejected": rejected })))
+            .collect();
+    if let Err(e) = query
+        .reply(prefix, serde_json::to_vec(&validators).unwrap())
+        .encoding(Encoding::APPLICATION_JSON)
+        .wait()
+    {
+        tracing::error!("Error sending AdminSpace reply: {:?}", e);
+    }
+}
+
+#[cfg(feature = "plugins")]
+fn plugin_declarations_data(prefix: &keyexpr, context: &AdminContext, query: Query) {
+    for (plugin, subscribers, queryables) in context.runtime.state.plugin_declarations.snapshot() {
+        let Ok(plugin) = keyexpr::new(&plugin) else {
+            continue;
+        };
+        let reply_key = prefix / plugin;
+        if query.key_expr().intersects(&reply_key) {
+            let declarations = json!({ "subscribers": subscribers, "queryables": queryables });
+            if let Err(e) = query
+                .reply(reply_key, serde_json::to_vec(&declarations).unwrap())
+                .encoding(Encoding::APPLICATION_JSON)
+                .wait()
+            {
+                tracing::error!("Error sending AdminSpace reply: {:?}", e);
+            }
+        }
+    }
+}
+
+fn limits_data(prefix: &keyexpr, context: &AdminContext, query: Query) {
+    let dropped = zread!(context.runtime.state.router.tables.tables)
+        .data
+        .payload_limit_dropped
+        .load(std::sync::atomic::Ordering::Relaxed);
+    if let Err(e) = query
+        .reply(prefix, json!({ "dropped": dropped }).to_string())
+        .encoding(Encoding::APPLICATION_JSON)
+        .wait()
+    {
+        tracing::error!("Error sending AdminSpace reply: {:?}", e);
+    }
+}
+
+fn connect_retry_data(prefix: &keyexpr, context: &AdminContext, query: Query) {
+    let retries: serde_json::Map<String, serde_json::Value> = context
+        .runtime
+        .get_connect_retries()
+        .into_iter()
+        .map(|(endpoint, state)| (endpoint.to_string(), json!(state)))
+        .collect();
+    if let Err(e) = query
+        .reply(prefix, serde_json::to_vec(&retries).unwrap())
+        .encoding(Encoding::APPLICATION_JSON)
+        .wait()
+    {
+        tracing::error!("Error sending AdminSpace reply: {:?}", e);
+    }
+}
+
 #[tracing::instrument(level = "trace", skip_all)]
 fn route_successor(prefix: &keyexpr, context: &AdminContext, query: Query) {
     let reply = |ke: &keyexpr, successor: ZenohIdProto| {
@@ -950,6 +1130,51 @@ fn plugins_data(prefix: &keyexpr, context: &AdminContext, query: Query) {
     }
 }
 
+#[cfg(feature = "plugins")]
+fn plugins_put(prefix: &str, context: &AdminContext, key_expr: &str) {
+    let Ok(key_expr) = KeyExpr::try_from(key_expr) else {
+        tracing::error!("Error: invalid admin space key {}", key_expr);
+        return;
+    };
+    let guard = context.runtime.plugins_manager();
+    let mut root_key = prefix.to_string();
+    for plugin in guard.started_plugins_iter() {
+        with_extended_string(&mut root_key, &["/", plugin.id()], |plugin_key| {
+            let matches_plugin = |plugin_status_space: &mut String| {
+                key_expr.intersects(plugin_status_space.as_str().try_into().unwrap())
+            };
+            if !with_extended_string(plugin_key, &["/**"], matches_plugin) {
+                return;
+            }
+            match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                plugin.instance().adminspace_putter(&key_expr, plugin_key)
+            })) {
+                Ok(Ok(responses)) => {
+                    for response in responses {
+                        tracing::info!(
+                            "Plugin {} performed {}: {}",
+                            plugin.id(),
+                            response.key,
+                            serde_json::Value::from(response.value)
+                        );
+                    }
+                }
+                Ok(Err(e)) => {
+                    tracing::error!("Plugin {} bailed from performing {}: {}", plugin.id(), key_expr, e)
+                }
+                Err(e) => match e
+                    .downcast_ref::<String>()
+                    .map(|s| s.as_str())
+                    .or_else(|| e.downcast_ref::<&str>().copied())
+                {
+                    Some(e) => tracing::error!("Plugin {} panicked while performing {}: {}", plugin.id(), key_expr, e),
+                    None => tracing::error!("Plugin {} panicked while performing {}. The panic message couldn't be recovered.", plugin.id(), key_expr),
+                },
+            }
+        });
+    }
+}
+
 #[cfg(feature = "plugins")]
 fn plugins_status(prefix: &keyexpr, context: &AdminContext, query: Query) {
     let key_expr = query.key_expr();
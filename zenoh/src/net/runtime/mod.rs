@@ -30,7 +30,7 @@ use std::sync::OnceLock;
 use std::sync::{Mutex, MutexGuard};
 use std::{
     any::Any,
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     fmt,
     ops::Deref,
     sync::{
@@ -70,13 +70,15 @@ use zenoh_shm::api::{
 #[cfg(feature = "shared-memory")]
 use zenoh_shm::reader::ShmReader;
 use zenoh_sync::get_mut_unchecked;
-use zenoh_task::TaskController;
+#[cfg(feature = "unstable")]
+use zenoh_task::TaskInfo;
+use zenoh_task::{TaskController, TaskName, TaskRegistry};
 use zenoh_transport::{
     multicast::TransportMulticast, unicast::TransportUnicast, TransportEventHandler,
     TransportManager, TransportMulticastEventHandler, TransportPeer, TransportPeerEventHandler,
 };
 
-use self::orchestrator::StartConditions;
+use self::orchestrator::{ConnectRetryState, MulticastScouting, StartConditions};
 use super::{
     primitives::{DeMux, EPrimitives, Primitives},
     routing::{
@@ -88,7 +90,7 @@ use super::{
 #[cfg(feature = "plugins")]
 use crate::api::loader::{load_plugins, start_plugins};
 #[cfg(feature = "plugins")]
-use crate::api::plugins::PluginsManager;
+use crate::api::plugins::{PluginDeclarations, PluginsManager};
 #[cfg(feature = "unstable")]
 use crate::api::timestamp_stack::{GetTimestampCallback, TimestampContext};
 #[cfg(feature = "internal")]
@@ -185,8 +187,12 @@ pub(crate) struct RuntimeState {
     task_controller: TaskController,
     #[cfg(feature = "plugins")]
     plugins_manager: Mutex<PluginsManager>,
+    #[cfg(feature = "plugins")]
+    plugin_declarations: Arc<PluginDeclarations>,
     start_conditions: Arc<StartConditions>,
     pending_connections: tokio::sync::Mutex<HashSet<ZenohIdProto>>,
+    multicast_scouting: tokio::sync::Mutex<MulticastScouting>,
+    connect_retries: std::sync::Mutex<HashMap<EndPoint, ConnectRetryState>>,
     namespace: Option<OwnedNonWildKeyExpr>,
     #[cfg(feature = "stats")]
     stats: zenoh_stats::StatsRegistry,
@@ -207,6 +213,9 @@ pub trait IRuntime: Send + Sync {
     fn get_ts_stack_timestamp(&self, context: TimestampContext) -> (Vec<u8>, bool);
     fn get_locators(&self) -> Vec<Locator>;
     fn get_zids(&self, whatami: WhatAmI) -> Box<dyn Iterator<Item = ZenohId> + Send + Sync>;
+    /// Returns the estimated clock skew (in nanoseconds) with each unicast peer.
+    #[cfg(feature = "unstable")]
+    fn get_clock_skews(&self) -> Vec<(ZenohId, i64)>;
     fn new_handler(&self, handler: Arc<dyn TransportEventHandler>);
 
     #[cfg(feature = "shared-memory")]
@@ -236,6 +245,16 @@ pub trait IRuntime: Send + Sync {
     ) -> crate::matching::MatchingStatus;
 
     fn get_config(&self) -> GenericConfig;
+
+    /// Returns the live tasks of the runtime, ordered by spawn time.
+    #[cfg(feature = "unstable")]
+    fn tasks(&self) -> Vec<TaskInfo>;
+
+    /// The registry of the live tasks of the runtime.
+    fn task_registry(&self) -> &TaskRegistry;
+
+    #[cfg(feature = "plugins")]
+    fn plugin_declarations(&self) -> Arc<PluginDeclarations>;
 }
 
 impl IConfig for Notifier<ExpandedConfig> {
@@ -325,6 +344,15 @@ impl IRuntime for RuntimeState {
         )
     }
 
+    #[cfg(feature = "unstable")]
+    fn get_clock_skews(&self) -> Vec<(ZenohId, i64)> {
+        zenoh_runtime::ZRuntime::Net
+            .block_in_place(self.manager.get_transports_unicast())
+            .into_iter()
+            .filter_map(|t| Some((t.get_zid().ok()?.into(), t.get_clock_skew().ok()??)))
+            .collect()
+    }
+
     fn new_handler(&self, handler: Arc<dyn TransportEventHandler>) {
         zwrite!(self.transport_handlers).push(handler);
     }
@@ -451,10 +479,24 @@ impl IRuntime for RuntimeState {
         }
     }
 
+    #[cfg(feature = "unstable")]
+    fn tasks(&self) -> Vec<TaskInfo> {
+        self.task_controller.registry().tasks()
+    }
+
+    fn task_registry(&self) -> &TaskRegistry {
+        self.task_controller.registry()
+    }
+
     fn get_config(&self) -> GenericConfig {
         GenericConfig::new(Arc::new(self.config.clone()))
     }
 
+    #[cfg(feature = "plugins")]
+    fn plugin_declarations(&self) -> Arc<PluginDeclarations> {
+        self.plugin_declarations.clone()
+    }
+
     #[cfg(feature = "shared-memory")]
     #[zenoh_macros::unstable]
     fn get_shm_provider(&self) -> ShmProviderState {
@@ -486,26 +528,29 @@ impl RuntimeState {
         zlock!(self.plugins_manager)
     }
 
-    /// Spawns a task within runtime.
+    /// Spawns a task named `name` within runtime.
     /// Upon close runtime will block until this task completes
-    fn spawn<F, T>(&self, future: F) -> JoinHandle<T>
+    fn spawn<F, T>(&self, name: impl Into<TaskName>, future: F) -> JoinHandle<T>
     where
         F: Future<Output = T> + Send + 'static,
         T: Send + 'static,
     {
         self.task_controller
-            .spawn_with_rt(zenoh_runtime::ZRuntime::Net, future)
+            .spawn_named_with_rt(name, zenoh_runtime::ZRuntime::Net, future)
     }
 
-    /// Spawns a task within runtime.
+    /// Spawns a task named `name` within runtime.
     /// Upon runtime close the task will be automatically aborted.
-    fn spawn_abortable<F, T>(&self, future: F) -> JoinHandle<Option<T>>
+    fn spawn_abortable<F, T>(&self, name: impl Into<TaskName>, future: F) -> JoinHandle<Option<T>>
     where
         F: Future<Output = T> + Send + 'static,
         T: Send + 'static,
     {
-        self.task_controller
-            .spawn_abortable_with_rt(zenoh_runtime::ZRuntime::Net, future)
+        self.task_controller.spawn_abortable_named_with_rt(
+            name,
+            zenoh_runtime::ZRuntime::Net,
+            future,
+        )
     }
 
     fn router(&self) -> Arc<Gateway> {
@@ -777,6 +822,7 @@ impl RuntimeBuilder {
             .from_config(&config)
             .await?
             .whatami(whatami)
+            .hlc(hlc.clone())
             .bound_callback({
                 let config = config.clone();
                 move |p| region::compute_transient_bound_of(&p, &config)
@@ -803,6 +849,8 @@ impl RuntimeBuilder {
         #[cfg(feature = "shared-memory")]
         let shm_init_mode = *config.transport.shared_memory.mode();
 
+        let task_controller =
+            TaskController::with_registry(transport_manager.task_registry().clone());
         let namespace = config.namespace().clone();
         let config = Notifier::new(config);
         let span = tracing::debug_span!("rt", zid = %zid.short());
@@ -821,11 +869,15 @@ impl RuntimeBuilder {
                 lazy_hlc: OnceLock::new(),
                 #[cfg(feature = "unstable")]
                 timestamp_callback,
-                task_controller: TaskController::default(),
+                task_controller,
                 #[cfg(feature = "plugins")]
                 plugins_manager: Mutex::new(plugins_manager),
+                #[cfg(feature = "plugins")]
+                plugin_declarations: Arc::default(),
                 start_conditions: Arc::new(StartConditions::default()),
                 pending_connections: tokio::sync::Mutex::new(HashSet::new()),
+                multicast_scouting: tokio::sync::Mutex::new(MulticastScouting::default()),
+                connect_retries: std::sync::Mutex::new(HashMap::new()),
                 namespace,
                 #[cfg(feature = "stats")]
                 stats,
@@ -928,6 +980,29 @@ impl Runtime {
         self.state.plugins_manager()
     }
 
+    /// Stops the started plugin `id`, returning `false` if there is no such plugin.
+    ///
+    /// The entities declared by the plugin through its
+    /// [`PluginEnv`](crate::api::plugins::PluginEnv) are undeclared before the plugin is stopped.
+    #[cfg(feature = "plugins")]
+    pub fn stop_plugin(&self, id: &str) -> bool {
+        zenoh_runtime::ZRuntime::Application
+            .block_in_place(self.state.plugin_declarations.undeclare(id, self.manager()));
+        Self::stop_started_plugin(&mut self.plugins_manager(), id)
+    }
+
+    /// Stops the started plugin `id`, whose entities must have been undeclared beforehand.
+    #[cfg(feature = "plugins")]
+    pub(crate) fn stop_started_plugin(plugins_manager: &mut PluginsManager, id: &str) -> bool {
+        match plugins_manager.started_plugin_mut(id) {
+            Some(running) => {
+                running.stop();
+                true
+            }
+            None => false,
+        }
+    }
+
     #[inline]
     pub fn next_id(&self) -> u32 {
         self.state.next_id()
@@ -951,24 +1026,39 @@ impl Runtime {
         self.state.get_locators()
     }
 
-    /// Spawns a task within runtime.
+    #[cfg(feature = "unstable")]
+    pub(crate) fn get_clock_skews(&self) -> Vec<(ZenohId, i64)> {
+        self.state.get_clock_skews()
+    }
+
+    /// Spawns a task named `name` within runtime.
     /// Upon close runtime will block until this task completes
-    pub(crate) fn spawn<F, T>(&self, future: F) -> JoinHandle<T>
+    pub(crate) fn spawn<F, T>(&self, name: impl Into<TaskName>, future: F) -> JoinHandle<T>
     where
         F: Future<Output = T> + Send + 'static,
         T: Send + 'static,
     {
-        self.state.spawn(future)
+        self.state.spawn(name, future)
     }
 
-    /// Spawns a task within runtime.
+    /// Spawns a task named `name` within runtime.
     /// Upon runtime close the task will be automatically aborted.
-    pub(crate) fn spawn_abortable<F, T>(&self, future: F) -> JoinHandle<Option<T>>
+    pub(crate) fn spawn_abortable<F, T>(
+        &self,
+        name: impl Into<TaskName>,
+        future: F,
+    ) -> JoinHandle<Option<T>>
     where
         F: Future<Output = T> + Send + 'static,
         T: Send + 'static,
     {
-        self.state.spawn_abortable(future)
+        self.state.spawn_abortable(name, future)
+    }
+
+    /// Returns the live tasks of the runtime and of its sessions, ordered by spawn time.
+    #[cfg(feature = "unstable")]
+    pub fn tasks(&self) -> Vec<TaskInfo> {
+        self.state.tasks()
     }
 
     pub(crate) fn router(&self) -> Arc<Gateway> {
@@ -1298,6 +1388,8 @@ impl Closee for Arc<RuntimeState> {
     async fn close_inner(&self, _: ()) {
         tracing::trace!("Runtime::close()");
         // TODO: Plugins should be stopped
+        #[cfg(feature = "plugins")]
+        self.plugin_declarations.undeclare_all().await;
         // TODO: Check this whether is able to terminate all spawned task by Runtime::spawn
         self.task_controller.terminate_all_async().await;
         self.manager.close().await;
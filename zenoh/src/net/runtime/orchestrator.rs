@@ -24,6 +24,7 @@ use socket2::{Domain, Socket, Type};
 use tokio::{
     net::UdpSocket,
     sync::{futures::Notified, Mutex, Notify},
+    task::JoinHandle,
 };
 use tokio_util::sync::CancellationToken;
 use zenoh_buffers::{
@@ -32,8 +33,8 @@ use zenoh_buffers::{
 };
 use zenoh_codec::{RCodec, WCodec, Zenoh080};
 use zenoh_config::{
-    get_global_connect_timeout, get_global_listener_timeout, unwrap_or_default,
-    ConnectionRetryPeriod, ModeDependent,
+    get_connect_exit_on_failure_policy, get_global_connect_timeout, get_global_listener_timeout,
+    unwrap_or_default, ConnectionRetryPeriod, ExitOnFailurePolicy, ModeDependent,
 };
 use zenoh_link::{Locator, LocatorInspector};
 use zenoh_protocol::{
@@ -65,6 +66,27 @@ pub(crate) struct PeerConnector {
     terminated: bool,
 }
 
+/// The multicast scouting task of a peer or router.
+///
+/// The task is restarted whenever the `scouting/multicast` configuration it was started from
+/// changes.
+#[derive(Default, Debug)]
+pub(crate) struct MulticastScouting {
+    conf: Option<String>,
+    task: Option<(CancellationToken, JoinHandle<Option<()>>)>,
+}
+
+/// The retry state of a configured endpoint the runtime failed to connect to.
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+pub(crate) struct ConnectRetryState {
+    /// The number of failed connection attempts.
+    pub(crate) attempts: u64,
+    /// The time of the next connection attempt, in milliseconds since the UNIX epoch.
+    pub(crate) next_attempt_ms: u64,
+    /// The wait time before the next connection attempt, in milliseconds, jitter included.
+    pub(crate) wait_ms: u64,
+}
+
 #[derive(Default, Debug)]
 pub(crate) struct StartConditions {
     notify: Notify,
@@ -142,7 +164,39 @@ impl Runtime {
             WhatAmI::Client => self.start_client().await,
             WhatAmI::Peer => self.start_peer().await,
             WhatAmI::Router => self.start_router().await,
+        }?;
+        if self.whatami() != WhatAmI::Client {
+            self.spawn_scouting_updater();
+        }
+        Ok(())
+    }
+
+    /// Applies the changes of the scouting configuration to the running peer or router.
+    ///
+    /// Changes of `scouting/multicast` restart the multicast scouting task, while changes of
+    /// `scouting/gossip` are applied to the routing networks.
+    fn spawn_scouting_updater(&self) {
+        fn affects(change: &str, key: &str) -> bool {
+            change.starts_with(key) || key.starts_with(change)
         }
+
+        let config_rx = self.state.config.subscribe();
+        let this = self.clone();
+        self.spawn_abortable("zenoh:scouting_updater", async move {
+            while let Ok(change) = config_rx.recv_async().await {
+                let change = change.strip_prefix('/').unwrap_or(&change);
+                if affects(change, "scouting/multicast") {
+                    if let Err(e) = this.update_scout().await {
+                        tracing::error!("Unable to update multicast scouting: {}", e);
+                    }
+                }
+                if affects(change, "scouting/gossip") {
+                    if let Err(e) = this.update_network() {
+                        tracing::error!("Unable to update gossip scouting: {}", e);
+                    }
+                }
+            }
+        });
     }
 
     async fn start_client(&self) -> ZResult<()> {
@@ -197,7 +251,7 @@ impl Runtime {
                         }
                         if let Some(mcast_socket) = mcast_socket {
                             let this = self.clone();
-                            self.spawn_abortable(async move {
+                            self.spawn_abortable("zenoh:scout_responder", async move {
                                 this.responder(&mcast_socket, &sockets).await;
                             });
                         }
@@ -217,7 +271,7 @@ impl Runtime {
     }
 
     async fn start_peer(&self) -> ZResult<()> {
-        let (listeners, peers, scouting, wait_scouting, listen, autoconnect, addr, ifaces, delay) = {
+        let (listeners, peers, scouting, wait_scouting, delay) = {
             let guard = &self.state.config.lock();
             (
                 guard.listen().endpoints().peer().unwrap_or(&vec![]).clone(),
@@ -229,10 +283,6 @@ impl Runtime {
                     .clone(),
                 unwrap_or_default!(guard.scouting().multicast().enabled()),
                 unwrap_or_default!(guard.open().return_conditions().connect_scouted()),
-                *unwrap_or_default!(guard.scouting().multicast().listen().peer()),
-                AutoConnect::multicast(guard, WhatAmI::Peer, self.zid().into()),
-                unwrap_or_default!(guard.scouting().multicast().address()),
-                unwrap_or_default!(guard.scouting().multicast().interface()),
                 Duration::from_millis(unwrap_or_default!(guard.scouting().delay())),
             )
         };
@@ -241,9 +291,7 @@ impl Runtime {
 
         self.connect_peers(&peers, false).await?;
 
-        if scouting {
-            self.start_scout(listen, autoconnect, addr, ifaces).await?;
-        }
+        self.update_scout().await?;
 
         if wait_scouting
             && (scouting || !peers.is_empty())
@@ -258,7 +306,7 @@ impl Runtime {
     }
 
     async fn start_router(&self) -> ZResult<()> {
-        let (listeners, peers, scouting, listen, autoconnect, addr, ifaces, delay) = {
+        let (listeners, peers, delay) = {
             let guard = &self.state.config.lock();
             (
                 guard
@@ -273,11 +321,6 @@ impl Runtime {
                     .router()
                     .unwrap_or(&vec![])
                     .clone(),
-                unwrap_or_default!(guard.scouting().multicast().enabled()),
-                *unwrap_or_default!(guard.scouting().multicast().listen().router()),
-                AutoConnect::multicast(guard, WhatAmI::Router, self.zid().into()),
-                unwrap_or_default!(guard.scouting().multicast().address()),
-                unwrap_or_default!(guard.scouting().multicast().interface()),
                 Duration::from_millis(unwrap_or_default!(guard.scouting().delay())),
             )
         };
@@ -286,63 +329,104 @@ impl Runtime {
 
         self.connect_peers(&peers, false).await?;
 
-        if scouting {
-            self.start_scout(listen, autoconnect, addr, ifaces).await?;
-        }
+        self.update_scout().await?;
 
         tokio::time::sleep(delay).await;
         Ok(())
     }
 
+    /// Starts, restarts or stops the multicast scouting of a peer or router according to the
+    /// current `scouting/multicast` configuration.
+    ///
+    /// The running scouting task is left untouched if its configuration didn't change.
+    async fn update_scout(&self) -> ZResult<()> {
+        let whatami = self.whatami();
+        let (conf, scouting, listen, autoconnect, explicit_autoconnect, addr, ifaces, ttl) = {
+            let guard = &self.state.config.lock();
+            (
+                guard.get_json("scouting/multicast").ok(),
+                unwrap_or_default!(guard.scouting().multicast().enabled()),
+                *unwrap_or_default!(guard.scouting().multicast().listen().get(whatami)),
+                AutoConnect::multicast(guard, whatami, self.zid().into()),
+                guard.scouting().multicast().autoconnect().is_some(),
+                unwrap_or_default!(guard.scouting().multicast().address()),
+                unwrap_or_default!(guard.scouting().multicast().interface()),
+                unwrap_or_default!(guard.scouting().multicast().ttl()),
+            )
+        };
+
+        let mut state = self.state.multicast_scouting.lock().await;
+        if state.conf.is_some() && state.conf == conf {
+            return Ok(());
+        }
+        if let Some((token, handle)) = state.task.take() {
+            tracing::debug!("Stop multicast scouting");
+            token.cancel();
+            let _ = handle.await;
+        }
+        state.conf = conf;
+
+        if !scouting {
+            if explicit_autoconnect && autoconnect.is_enabled() {
+                tracing::warn!(
+                    "scouting/multicast/autoconnect has no effect while multicast scouting is disabled"
+                );
+            }
+            return Ok(());
+        }
+        state.task = self
+            .start_scout(listen, autoconnect, addr, ifaces, ttl)
+            .await?;
+        Ok(())
+    }
+
     async fn start_scout(
         &self,
         listen: bool,
         autoconnect: AutoConnect,
         addr: SocketAddr,
         ifaces: String,
-    ) -> ZResult<()> {
-        let multicast_ttl = {
-            let config_guard = self.config().lock();
-            let config = &config_guard;
-            unwrap_or_default!(config.scouting().multicast().ttl())
-        };
+        multicast_ttl: u32,
+    ) -> ZResult<Option<(CancellationToken, JoinHandle<Option<()>>)>> {
         let ifaces = Runtime::get_interfaces(&ifaces);
         let mcast_socket = Runtime::bind_mcast_port(&addr, &ifaces, multicast_ttl).await?;
-        if !ifaces.is_empty() {
-            let sockets: Vec<UdpSocket> = ifaces
-                .into_iter()
-                .filter_map(|iface| Runtime::bind_ucast_port(iface, multicast_ttl).ok())
-                .collect();
-            if !sockets.is_empty() {
-                let this = self.clone();
-                match (listen, autoconnect.is_enabled()) {
-                    (true, true) => {
-                        self.spawn_abortable(async move {
-                            tokio::select! {
-                                _ = this.responder(&mcast_socket, &sockets) => {},
-                                _ = this.autoconnect_all(
-                                    &sockets,
-                                    autoconnect,
-                                    &addr
-                                ) => {},
-                            }
-                        });
-                    }
-                    (true, false) => {
-                        self.spawn_abortable(async move {
-                            this.responder(&mcast_socket, &sockets).await;
-                        });
+        if ifaces.is_empty() {
+            return Ok(None);
+        }
+        let sockets: Vec<UdpSocket> = ifaces
+            .into_iter()
+            .filter_map(|iface| Runtime::bind_ucast_port(iface, multicast_ttl).ok())
+            .collect();
+        if sockets.is_empty() || !(listen || autoconnect.is_enabled()) {
+            return Ok(None);
+        }
+        let token = CancellationToken::new();
+        let this = self.clone();
+        let handle = self.spawn_abortable("zenoh:scout", {
+            let token = token.clone();
+            async move {
+                let responder = async {
+                    if listen {
+                        this.responder(&mcast_socket, &sockets).await
+                    } else {
+                        std::future::pending().await
                     }
-                    (false, true) => {
-                        self.spawn_abortable(async move {
-                            this.autoconnect_all(&sockets, autoconnect, &addr).await
-                        });
+                };
+                let autoconnect = async {
+                    if autoconnect.is_enabled() {
+                        this.autoconnect_all(&sockets, autoconnect, &addr).await
+                    } else {
+                        std::future::pending().await
                     }
-                    _ => {}
+                };
+                tokio::select! {
+                    _ = token.cancelled() => {},
+                    _ = responder => {},
+                    _ = autoconnect => {},
                 }
             }
-        }
-        Ok(())
+        });
+        Ok(Some((token, handle)))
     }
 
     async fn connect_peers(&self, peers: &[EndPoints], single_link: bool) -> ZResult<()> {
@@ -357,7 +441,11 @@ impl Runtime {
             match res {
                 Ok(r) => r,
                 Err(_) => {
-                    let e = zerror!("Unable to connect to any of {:?}. Timeout!", peers);
+                    let e = zerror!(
+                        kind = Timeout,
+                        "Unable to connect to any of {:?}. Timeout!",
+                        peers
+                    );
                     tracing::warn!("{}", &e);
                     Err(e.into())
                 }
@@ -416,7 +504,11 @@ impl Runtime {
         if success_flag {
             Ok(())
         } else {
-            let e = zerror!("Unable to connect to any of {:?}! ", peers);
+            let e = zerror!(
+                kind = TransportFailure,
+                "Unable to connect to any of {:?}! ",
+                peers
+            );
             tracing::warn!("{}", &e);
             Err(e.into())
         }
@@ -425,9 +517,15 @@ impl Runtime {
     async fn connect_peers_multiply_links(&self, peers: &[EndPoints]) -> ZResult<()> {
         for peer_group in peers {
             Self::warn_if_oneof(peer_group);
+            // the endpoints with the `all` policy only make the group fail if none of its
+            // endpoints connected
+            let mut group_connected = false;
+            let mut group_error = None;
+            let mut peers_to_retry = Vec::new();
             for peer in peer_group.as_vec() {
                 let endpoint = peer.clone();
                 let retry_config = self.get_connect_retry_config(&endpoint);
+                let policy = self.get_connect_exit_on_failure_policy(&endpoint);
                 tracing::debug!(
                     "Try to connect: {:?}: global timeout: {:?}, retry: {:?}",
                     endpoint,
@@ -436,14 +534,22 @@ impl Runtime {
                 );
                 if retry_config.timeout().is_zero() || self.get_global_connect_timeout().is_zero() {
                     // try to connect and exit immediately without retry
-                    if let Err(e) = self.peer_connector(endpoint).await {
-                        if retry_config.exit_on_failure {
-                            return Err(e);
-                        }
+                    match self.peer_connector(endpoint).await {
+                        Ok(()) => group_connected = true,
+                        Err(e) if retry_config.exit_on_failure => match policy {
+                            ExitOnFailurePolicy::Any => return Err(e),
+                            ExitOnFailurePolicy::All => group_error = Some(e),
+                        },
+                        Err(_) => {}
                     }
                 } else if retry_config.exit_on_failure {
-                    // try to connect with retry waiting
-                    let _ = self.peer_connector_retry(endpoint).await;
+                    match policy {
+                        // try to connect with retry waiting
+                        ExitOnFailurePolicy::Any => {
+                            let _ = self.peer_connector_retry(endpoint).await;
+                        }
+                        ExitOnFailurePolicy::All => peers_to_retry.push(endpoint),
+                    }
                 } else {
                     // try to connect in background
                     if let Err(e) = self.spawn_peer_connector(endpoint.clone()).await {
@@ -452,6 +558,29 @@ impl Runtime {
                     }
                 }
             }
+            if !group_connected && !peers_to_retry.is_empty() {
+                // try to connect with retry waiting to the first available endpoint of the group
+                match self
+                    .peers_connector_retry(peers_to_retry.clone(), true)
+                    .await
+                {
+                    Ok(connected) => {
+                        group_connected = true;
+                        peers_to_retry.retain(|peer| connected.iter().all(|(p, _)| p != peer));
+                    }
+                    Err(e) => group_error = Some(e),
+                }
+            }
+            if let Some(e) = group_error.filter(|_| !group_connected) {
+                return Err(e);
+            }
+            // try to connect in background to the remaining endpoints of the group
+            for endpoint in peers_to_retry {
+                if let Err(e) = self.spawn_peer_connector(endpoint.clone()).await {
+                    tracing::warn!("Error connecting to {}: {}", endpoint, e);
+                    return Err(e);
+                }
+            }
         }
         Ok(())
     }
@@ -489,6 +618,11 @@ impl Runtime {
         zenoh_config::get_retry_config(guard, Some(endpoint), false)
     }
 
+    fn get_connect_exit_on_failure_policy(&self, endpoint: &EndPoint) -> ExitOnFailurePolicy {
+        let guard = &self.state.config.lock();
+        get_connect_exit_on_failure_policy(guard, endpoint)
+    }
+
     fn get_global_listener_timeout(&self) -> std::time::Duration {
         let guard = &self.state.config.lock();
         get_global_listener_timeout(guard)
@@ -552,7 +686,7 @@ impl Runtime {
         retry_config: zenoh_config::ConnectionRetryConf,
     ) {
         let this = self.clone();
-        self.spawn(async move {
+        self.spawn(format!("zenoh:listen:{listener}"), async move {
             this.add_listener_retry(listener, retry_config).await;
             this.print_locators();
         });
@@ -768,7 +902,7 @@ impl Runtime {
             let gossip = unwrap_or_default!(config.scouting().gossip().enabled());
             let wait_declares = unwrap_or_default!(config.open().return_conditions().declares());
             drop(config_guard);
-            self.spawn(async move {
+            self.spawn(format!("zenoh:connect:{peer}"), async move {
                 if let Ok(zid) = this.peer_connector_retry(peer).await {
                     this.state
                         .start_conditions
@@ -792,7 +926,7 @@ impl Runtime {
         &self,
         peers: Vec<EndPoint>,
         stop_after_first_connection: bool,
-    ) -> ZResult<Vec<ZenohIdProto>> {
+    ) -> ZResult<Vec<(EndPoint, ZenohIdProto)>> {
         async fn wait_next_peer_retry(
             peer: EndPoint,
             period: ConnectionRetryPeriod,
@@ -814,7 +948,7 @@ impl Runtime {
         let mut tasks = FuturesUnordered::new();
         let cancellation_token = self.get_cancellation_token();
 
-        for peer in peers {
+        for peer in peers.iter().cloned() {
             let retry_config = self.get_connect_retry_config(&peer);
             let period = retry_config.period();
             tasks.push(wait_next_peer_retry(
@@ -853,7 +987,8 @@ impl Runtime {
                 match result {
                     Ok(zid) => {
                         tracing::debug!("Successfully connected to configured peer {}", peer);
-                        connected_peers.push(zid);
+                        zlock!(self.state.connect_retries).remove(&peer);
+                        connected_peers.push((peer, zid));
                         if stop_after_first_connection {
                             break;
                         }
@@ -866,6 +1001,7 @@ impl Runtime {
                             period.duration()
                         );
                         let wait_time = period.next_duration();
+                        self.record_connect_retry(&peer, wait_time);
                         tasks.push(wait_next_peer_retry(
                             peer,
                             period,
@@ -876,6 +1012,13 @@ impl Runtime {
                 }
             }
         }
+        // the endpoints remaining in the tasks are not retried anymore
+        {
+            let mut connect_retries = zlock!(self.state.connect_retries);
+            for peer in &peers {
+                connect_retries.remove(peer);
+            }
+        }
         if connected_peers.is_empty() {
             bail!("Peer connector terminated without connecting to any endpoint")
         } else {
@@ -886,7 +1029,35 @@ impl Runtime {
     async fn peer_connector_retry(&self, peer: EndPoint) -> ZResult<ZenohIdProto> {
         self.peers_connector_retry(vec![peer], true)
             .await
-            .map(|peers| peers[0])
+            .map(|peers| peers[0].1)
+    }
+
+    fn record_connect_retry(&self, peer: &EndPoint, wait_time: Duration) {
+        let next_attempt_ms = std::time::SystemTime::now()
+            .checked_add(wait_time)
+            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+            .map_or(u64::MAX, |d| d.as_millis() as u64);
+        let wait_ms = u64::try_from(wait_time.as_millis()).unwrap_or(u64::MAX);
+        zlock!(self.state.connect_retries)
+            .entry(peer.clone())
+            .and_modify(|state| {
+                state.attempts += 1;
+                state.next_attempt_ms = next_attempt_ms;
+                state.wait_ms = wait_ms;
+            })
+            .or_insert(ConnectRetryState {
+                attempts: 1,
+                next_attempt_ms,
+                wait_ms,
+            });
+    }
+
+    /// Returns the retry state of the configured endpoints the runtime is trying to connect to.
+    pub(crate) fn get_connect_retries(&self) -> Vec<(EndPoint, ConnectRetryState)> {
+        zlock!(self.state.connect_retries)
+            .iter()
+            .map(|(endpoint, state)| (endpoint.clone(), *state))
+            .collect()
     }
 
     pub async fn scout<Fut, F>(
@@ -1148,7 +1319,7 @@ impl Runtime {
         };
         let timeout = async {
             tokio::time::sleep(timeout).await;
-            bail!("timeout")
+            bail!(kind = Timeout, "timeout")
         };
         tokio::select! {
             res = scout => { res },
@@ -1290,7 +1461,7 @@ impl Runtime {
 
         if !peers.is_empty() {
             let runtime = session.runtime.clone();
-            session.runtime.spawn(async move {
+            session.runtime.spawn("zenoh:connect", async move {
                 runtime
                     .peers_connector_retry(peers, runtime.whatami() == WhatAmI::Client)
                     .await
@@ -1324,13 +1495,14 @@ impl Runtime {
 
         if peers.contains(&endpoint) && zwrite!(session.endpoints).remove(&endpoint) {
             let runtime = session.runtime.clone();
-            session.runtime.spawn(async move {
-                let _ = runtime.peer_connector_retry(endpoint).await;
-            });
+            session
+                .runtime
+                .spawn(format!("zenoh:connect:{endpoint}"), async move {
+                    let _ = runtime.peer_connector_retry(endpoint).await;
+                });
         }
     }
 
-    #[allow(dead_code)]
     pub(crate) fn update_network(&self) -> ZResult<()> {
         let router = self.router();
         let _ctrl_lock = zlock!(router.tables.ctrl_lock);
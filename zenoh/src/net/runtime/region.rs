@@ -120,7 +120,13 @@ fn compute_transient_region_of(
     config: &ExpandedConfig,
 ) -> ZResult<Option<Region>> {
     match config.gateway.south.clone().unwrap_or_default() {
-        GatewaySouthConf::Preset(GatewayPresetConf::Auto) => Ok(None),
+        GatewaySouthConf::Preset(GatewayPresetConf::Auto) => {
+            if peer.whatami.is_client() && config.gateway.accepts_clients(config.mode()) {
+                Ok(Some(Region::default_south(WhatAmI::Client)))
+            } else {
+                Ok(None)
+            }
+        }
         GatewaySouthConf::Custom(subregions) => {
             if let Some(id) = subregions
                 .iter()
@@ -2024,7 +2024,7 @@ fn test_push_message_consumption() {
 
     // Here the wire expr is non-scoped
     s1.face
-        .send_push_consume(&mut push, Reliability::default(), false);
+        .send_push_consume(&mut push, Reliability::default(), false, None);
 
     assert_eq!(
         s2.recorder().pushes().len(),
@@ -63,7 +63,7 @@ use zenoh_transport::{
 
 use crate::net::{
     codec::Zenoh080Routing,
-    primitives::{DeMux, EPrimitives, Primitives},
+    primitives::{BlockTimeout, DeMux, EPrimitives, Primitives},
     protocol::linkstate::{LinkState, LinkStateList},
     routing::{
         dispatcher::face::Face,
@@ -399,7 +399,13 @@ impl Primitives for RecordingPrimitives {
             .push(Message::Declare(msg.clone()));
     }
 
-    fn send_push_consume(&self, msg: &mut Push, _reliability: Reliability, _consume: bool) {
+    fn send_push_consume(
+        &self,
+        msg: &mut Push,
+        _reliability: Reliability,
+        _consume: bool,
+        _block_timeout: Option<&BlockTimeout>,
+    ) {
         self.messages
             .lock()
             .unwrap()
@@ -445,8 +451,13 @@ impl EPrimitives for RecordingPrimitives {
         false
     }
 
-    fn send_push(&self, msg: &mut Push, reliability: Reliability) -> bool {
-        self.send_push_consume(msg, reliability, true);
+    fn send_push(
+        &self,
+        msg: &mut Push,
+        reliability: Reliability,
+        block_timeout: Option<&BlockTimeout>,
+    ) -> bool {
+        self.send_push_consume(msg, reliability, true, block_timeout);
         false
     }
 
@@ -908,7 +919,7 @@ impl Harness {
             cfg.mode,
             Arc::new(move |msg| match msg.body {
                 NetworkBody::Push(mut p) => {
-                    Primitives::send_push_consume(&*rec, &mut p, msg.reliability, true)
+                    Primitives::send_push_consume(&*rec, &mut p, msg.reliability, true, None)
                 }
                 NetworkBody::Declare(mut d) => Primitives::send_declare(&*rec, &mut d),
                 NetworkBody::Interest(mut i) => Primitives::send_interest(&*rec, &mut i),
@@ -29,7 +29,7 @@ use zenoh_protocol::{
 use crate::{
     key_expr::KeyExpr,
     net::{
-        primitives::{DummyPrimitives, EPrimitives, Primitives},
+        primitives::{BlockTimeout, DummyPrimitives, EPrimitives, Primitives},
         routing::{
             dispatcher::{
                 face::{Face, FaceState},
@@ -537,6 +537,7 @@ impl Primitives for ClientPrimitives {
         msg: &mut zenoh_protocol::network::Push,
         _reliability: Reliability,
         _consume: bool,
+        _block_timeout: Option<&BlockTimeout>,
     ) {
         *zlock!(self.data) = Some(msg.wire_expr.to_owned());
     }
@@ -581,6 +582,7 @@ impl EPrimitives for ClientPrimitives {
         &self,
         msg: &mut zenoh_protocol::network::Push,
         _reliability: Reliability,
+        _block_timeout: Option<&BlockTimeout>,
     ) -> bool {
         *zlock!(self.data) = Some(msg.wire_expr.to_owned());
         false
@@ -705,6 +707,7 @@ fn test_response_wireexpr() {
             ext_tstamp: None,
             ext_respid: None,
             ext_ts_stack: None,
+            ext_replier_qos: None,
         },
     );
     assert_eq!(
@@ -752,6 +755,7 @@ fn test_response_wireexpr() {
             ext_tstamp: None,
             ext_respid: None,
             ext_ts_stack: None,
+            ext_replier_qos: None,
         },
     );
     assert_eq!(
@@ -906,6 +910,7 @@ fn client_test() {
             },
             Reliability::Reliable,
             true,
+            None,
         );
     };
 
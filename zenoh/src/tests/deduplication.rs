@@ -0,0 +1,141 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#![cfg(feature = "unstable")]
+
+use std::{sync::atomic::Ordering, time::Duration};
+
+use zenoh_core::{ztimeout, Wait};
+
+use crate::api::{
+    builders::sample::SampleBuilder,
+    config::Config,
+    deduplication::{deduplicate, DeduplicationConfig},
+    handlers::Callback,
+    key_expr::KeyExpr,
+    sample::{Locality, Sample},
+    session::open,
+    subscriber::SubscriberKind,
+};
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+fn channel_callback() -> (Callback<Sample>, flume::Receiver<Sample>) {
+    let (tx, rx) = flume::unbounded();
+    let callback = Callback::from(move |sample| {
+        let _ = tx.send(sample);
+    });
+    (callback, rx)
+}
+
+/// Delivers every publication twice to a deduplicating callback, through two overlapping
+/// subscriptions.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn deduplicate_overlapping_subscriptions() {
+    zenoh_util::init_log_from_env_or("error");
+    let mut config = Config::default();
+    config.scouting.multicast.set_enabled(Some(false)).unwrap();
+    let session = ztimeout!(open(config)).unwrap();
+
+    for untimestamped in [false, true] {
+        let (callback, receiver) = channel_callback();
+        let (callback, suppressed) = deduplicate(
+            callback,
+            DeduplicationConfig {
+                window: Some(Duration::from_secs(10)),
+                untimestamped,
+            },
+        );
+        let suppressed = suppressed.unwrap();
+        let mut subscriptions = Vec::new();
+        for key_expr in ["test/dedup/**", "test/dedup/a"] {
+            subscriptions.push(
+                session
+                    .declare_subscriber_inner(
+                        &KeyExpr::try_from(key_expr).unwrap(),
+                        Locality::Any,
+                        callback.clone(),
+                        None,
+                    )
+                    .unwrap(),
+            );
+        }
+
+        for payload in ["1", "2"] {
+            session
+                .put("test/dedup/a", payload)
+                .timestamp(session.new_timestamp())
+                .wait()
+                .unwrap();
+        }
+        assert_eq!(receiver.drain().count(), 2);
+        assert_eq!(suppressed.load(Ordering::Relaxed), 2);
+
+        session.put("test/dedup/a", "3").wait().unwrap();
+        session.put("test/dedup/a", "4").wait().unwrap();
+        if untimestamped {
+            assert_eq!(receiver.drain().count(), 2);
+            assert_eq!(suppressed.load(Ordering::Relaxed), 4);
+        } else {
+            assert_eq!(receiver.drain().count(), 4);
+            assert_eq!(suppressed.load(Ordering::Relaxed), 2);
+        }
+
+        for subscription in subscriptions {
+            session
+                .undeclare_subscriber_inner(subscription.id, SubscriberKind::Subscriber)
+                .unwrap();
+        }
+    }
+
+    ztimeout!(session.close()).unwrap();
+}
+
+#[test]
+fn deduplicate_within_window() {
+    let (callback, receiver) = channel_callback();
+    let (callback, suppressed) = deduplicate(
+        callback,
+        DeduplicationConfig {
+            window: Some(Duration::from_millis(100)),
+            untimestamped: false,
+        },
+    );
+    let suppressed = suppressed.unwrap();
+    let timestamp = uhlc::HLC::default().new_timestamp();
+    let sample = |key_expr: &'static str| -> Sample {
+        SampleBuilder::put(KeyExpr::try_from(key_expr).unwrap(), "payload")
+            .timestamp(timestamp)
+            .into()
+    };
+
+    callback.call(sample("test/dedup/a"));
+    callback.call(sample("test/dedup/a"));
+    // The same publication on another key expression is not a duplicate.
+    callback.call(sample("test/dedup/b"));
+    assert_eq!(receiver.drain().count(), 2);
+    assert_eq!(suppressed.load(Ordering::Relaxed), 1);
+
+    std::thread::sleep(Duration::from_millis(200));
+    callback.call(sample("test/dedup/a"));
+    assert_eq!(receiver.drain().count(), 1);
+    assert_eq!(suppressed.load(Ordering::Relaxed), 1);
+
+    // The deduplication is disabled without window.
+    let (callback, receiver) = channel_callback();
+    let (callback, suppressed) = deduplicate(callback, DeduplicationConfig::default());
+    assert!(suppressed.is_none());
+    callback.call(sample("test/dedup/a"));
+    callback.call(sample("test/dedup/a"));
+    assert_eq!(receiver.drain().count(), 2);
+}
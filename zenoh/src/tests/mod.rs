@@ -12,6 +12,7 @@
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
 
+mod deduplication;
 mod interceptor_cache;
 mod link_weights;
 
@@ -15,7 +15,7 @@ use crate::{
         queryable::{Query, QueryInner, ReplyPrimitives},
         sample::QoS,
     },
-    net::primitives::Primitives,
+    net::primitives::{BlockTimeout, Primitives},
 };
 
 const TIMEOUT: Duration = Duration::from_secs(60);
@@ -43,7 +43,14 @@ impl Primitives for ReplyTestPrimitives {
 
     fn send_push(&self, _msg: &mut Push, _reliability: Reliability) {}
 
-    fn send_push_consume(&self, _msg: &mut Push, _reliability: Reliability, _consume: bool) {}
+    fn send_push_consume(
+        &self,
+        _msg: &mut Push,
+        _reliability: Reliability,
+        _consume: bool,
+        _block_timeout: Option<&BlockTimeout>,
+    ) {
+    }
 
     fn send_request(&self, _msg: &mut Request) {}
 
@@ -87,6 +94,8 @@ async fn test_reply_preserves_optimized_ke() {
         eid: 1,
         value: None,
         attachment: None,
+        #[cfg(feature = "unstable")]
+        permit: None,
     };
 
     let ke = "test/reply_declared_ke";
@@ -18,7 +18,7 @@ use std::time::Duration;
 use zenoh_config::WhatAmI;
 use zenoh_core::ztimeout;
 use zenoh_link::EndPoint;
-use zenoh_test::get_locators_from_session;
+use zenoh_test::{get_locators_from_session, get_tcp_locator};
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
 async fn test_adminspace_wonly() {
@@ -863,6 +863,87 @@ async fn test_adminspace_transports_and_links() {
     ztimeout!(router1.close()).unwrap();
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_adminspace_linkstate_graph() {
+    const TIMEOUT: Duration = Duration::from_secs(60);
+
+    zenoh_util::init_log_from_env_or("error");
+
+    let router = |connect: Vec<EndPoint>| {
+        let mut c = zenoh_config::Config::default();
+        c.set_mode(Some(WhatAmI::Router)).unwrap();
+        c.listen
+            .endpoints
+            .set(vec!["tcp/127.0.0.1:0".parse::<EndPoint>().unwrap()])
+            .unwrap();
+        c.connect
+            .endpoints
+            .set(connect.into_iter().map(Into::into).collect())
+            .unwrap();
+        c.scouting.multicast.set_enabled(Some(false)).unwrap();
+        c.adminspace.set_enabled(true).unwrap();
+        c.adminspace.permissions.set_read(true).unwrap();
+        zenoh::open(c)
+    };
+
+    // Full mesh of 3 routers
+    let router1 = ztimeout!(router(vec![])).unwrap();
+    let endpoint1 = get_tcp_locator(&router1).await;
+    let router2 = ztimeout!(router(vec![endpoint1.clone()])).unwrap();
+    let endpoint2 = get_tcp_locator(&router2).await;
+    let router3 = ztimeout!(router(vec![endpoint1, endpoint2])).unwrap();
+    let zid1 = router1.zid();
+
+    let graph_key = format!("@/{zid1}/router/linkstate/north/graph");
+    let json = ztimeout!(async {
+        loop {
+            let reply = router1.get(&graph_key).await.unwrap().recv_async().await;
+            let sample = reply.unwrap().into_result().unwrap();
+            assert_eq!(sample.encoding(), &zenoh::bytes::Encoding::APPLICATION_JSON);
+            let json: serde_json::Value =
+                serde_json::from_slice(&sample.payload().to_bytes()).unwrap();
+            if json["nodes"].as_array().unwrap().len() == 3
+                && json["edges"].as_array().unwrap().len() == 3
+            {
+                break json;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    });
+
+    let nodes = json["nodes"].as_array().unwrap();
+    let local = nodes
+        .iter()
+        .find(|n| n["local"].as_bool().unwrap())
+        .unwrap();
+    assert_eq!(local["zid"].as_str().unwrap(), zid1.to_string());
+    assert_eq!(local["whatami"].as_str().unwrap(), "router");
+    assert!(!local["locators"].as_array().unwrap().is_empty());
+    for zid in [router2.zid(), router3.zid()] {
+        assert!(nodes
+            .iter()
+            .any(|n| n["zid"].as_str().unwrap() == zid.to_string()));
+    }
+    for edge in json["edges"].as_array().unwrap() {
+        assert!(edge["weight"].as_f64().unwrap() > 0.0);
+    }
+
+    let reply = ztimeout!(router1.get(format!("{graph_key}?format=dot")))
+        .unwrap()
+        .recv_async()
+        .await
+        .unwrap();
+    let sample = reply.into_result().unwrap();
+    assert_eq!(sample.encoding(), &zenoh::bytes::Encoding::TEXT_PLAIN);
+    let dot = sample.payload().try_to_string().unwrap().into_owned();
+    assert!(dot.starts_with("graph {"));
+    assert_eq!(dot.matches(" -- ").count(), 3);
+
+    router3.close().await.unwrap();
+    router2.close().await.unwrap();
+    router1.close().await.unwrap();
+}
+
 #[cfg(feature = "stats")]
 #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
 async fn test_adminspace_regression_1() {
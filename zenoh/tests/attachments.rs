@@ -71,3 +71,40 @@ fn attachment_queries() {
         }
     }
 }
+
+#[cfg(feature = "unstable")]
+#[test]
+fn attachment_put_to_parameters() {
+    let zenoh = zenoh::open(Config::default()).wait().unwrap();
+    let subscriber = zenoh
+        .declare_subscriber("test/attachment/put_to")
+        .wait()
+        .unwrap();
+
+    zenoh
+        .put_to("test/attachment/put_to?unit=celsius;precision=2", "21.50")
+        .wait()
+        .unwrap();
+    let sample = subscriber
+        .recv_timeout(std::time::Duration::from_secs(5))
+        .unwrap()
+        .unwrap();
+    assert_eq!(sample.key_expr().as_str(), "test/attachment/put_to");
+    let parameters = sample.parameters().unwrap();
+    assert_eq!(parameters.get("unit"), Some("celsius"));
+    assert_eq!(parameters.get("precision"), Some("2"));
+
+    zenoh.put("test/attachment/put_to", "21.50").wait().unwrap();
+    let sample = subscriber
+        .recv_timeout(std::time::Duration::from_secs(5))
+        .unwrap()
+        .unwrap();
+    assert!(sample.parameters().is_none());
+
+    // The parameters can't be added to an attachment which is not a key-value map.
+    assert!(zenoh
+        .put_to("test/attachment/put_to?unit=celsius", "21.50")
+        .attachment("not a map")
+        .wait()
+        .is_err());
+}
@@ -86,6 +86,15 @@ mod test {
         test_pub_sub_deny_then_allow_tls(true).await;
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_authentication_cert_common_name() {
+        zenoh_util::init_log_from_env_or("error");
+        create_new_files(TESTFILES_PATH.to_path_buf())
+            .await
+            .unwrap();
+        test_cert_common_name_session_info().await;
+    }
+
     #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
     async fn test_authentication_subject_combinations() {
         zenoh_util::init_log_from_env_or("error");
@@ -807,6 +816,43 @@ client2name:client2passwd";
         ztimeout!(s02.close()).unwrap();
     }
 
+    async fn test_cert_common_name_session_info() {
+        println!("test_cert_common_name_session_info");
+        let mut test_context = TestSessions::new();
+
+        let mut config_router = get_basic_router_config_tls(false).await;
+        config_router.adminspace.set_enabled(true).unwrap();
+        config_router.adminspace.permissions.set_read(true).unwrap();
+        println!("Opening router session");
+        let router = test_context.open_listener_with_cfg(config_router).await;
+        let (s01, s02) = get_client_sessions_tls(&mut test_context, false).await;
+        tokio::time::sleep(SLEEP).await;
+
+        // The router authenticated both clients with their certificate
+        let transports: Vec<_> = ztimeout!(router.info().transports()).collect();
+        assert_eq!(transports.len(), 2);
+        for transport in &transports {
+            assert_eq!(transport.cert_common_name(), Some("localhost"));
+        }
+
+        for zid in [s01.zid(), s02.zid()] {
+            let replies = ztimeout!(router.get(format!(
+                "@/{}/session/transport/unicast/{zid}",
+                router.zid()
+            )))
+            .unwrap();
+            let sample = ztimeout!(replies.recv_async())
+                .unwrap()
+                .into_result()
+                .unwrap();
+            let json: serde_json::Value =
+                serde_json::from_slice(&sample.payload().to_bytes()).unwrap();
+            assert_eq!(json["cert_common_name"], "localhost", "{json}");
+        }
+
+        test_context.close().await;
+    }
+
     async fn test_pub_sub_deny_then_allow_tls(lowlatency: bool) {
         println!("test_pub_sub_deny_then_allow_tls");
         let mut test_context = TestSessions::new();
@@ -0,0 +1,87 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#![cfg(feature = "unstable")]
+
+use std::time::Duration;
+
+use zenoh_core::ztimeout;
+use zenoh_test::TestSessions;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+const SLEEP: Duration = Duration::from_millis(100);
+
+/// Tolerance on the estimated skew between two sessions sharing the same clock, accounting for
+/// the transmission delays.
+const TOLERANCE: Duration = Duration::from_millis(500);
+
+fn with_timestamping(mut config: zenoh_config::Config) -> zenoh_config::Config {
+    config.insert_json5("timestamping/enabled", "true").unwrap();
+    // Send keep-alives every 250ms
+    config
+        .insert_json5("transport/link/tx/lease", "1000")
+        .unwrap();
+    config
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn clock_skew_estimation() {
+    zenoh_util::init_log_from_env_or("error");
+    let mut test_sessions = TestSessions::new();
+    let mut config = with_timestamping(test_sessions.get_listener_config("tcp/127.0.0.1:0", 1));
+    config.adminspace.set_enabled(true).unwrap();
+    config.adminspace.permissions.set_read(true).unwrap();
+    let listener_session = test_sessions.open_listener_with_cfg(config).await;
+    let config = with_timestamping(test_sessions.get_connector_config());
+    let connector_session = test_sessions.open_connector_with_cfg(config).await;
+
+    // The skew is estimated from the keep-alives of the connector
+    let skews = ztimeout!(async {
+        loop {
+            let skews = listener_session.info().peers_skew().await;
+            if !skews.is_empty() {
+                break skews;
+            }
+            tokio::time::sleep(SLEEP).await;
+        }
+    });
+    assert_eq!(skews.len(), 1);
+    let skew = skews[0];
+    assert_eq!(*skew.zid(), connector_session.zid());
+    assert!(
+        skew.skew_nanos().unsigned_abs() < TOLERANCE.as_nanos() as u64,
+        "estimated skew {}ns between sessions sharing the same clock",
+        skew.skew_nanos()
+    );
+
+    let replies = ztimeout!(listener_session.get(format!(
+        "@/{}/peer/clock_skew/{}",
+        listener_session.zid(),
+        connector_session.zid()
+    )))
+    .unwrap();
+    let reply = ztimeout!(replies.recv_async()).unwrap();
+    let value: serde_json::Value =
+        serde_json::from_slice(&reply.result().unwrap().payload().to_bytes()).unwrap();
+    assert!(value["skew_ns"].as_i64().is_some());
+
+    // The estimate goes away with the transport of the connector
+    ztimeout!(connector_session.close()).unwrap();
+    ztimeout!(async {
+        while !listener_session.info().peers_skew().await.is_empty() {
+            tokio::time::sleep(SLEEP).await;
+        }
+    });
+
+    test_sessions.close().await;
+}
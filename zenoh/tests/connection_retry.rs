@@ -13,8 +13,14 @@
 //
 #![cfg(feature = "unstable")]
 
+use std::time::Duration;
+
 use zenoh::{Config, Wait};
 use zenoh_config::{ConnectionRetryConf, EndPoint, ModeDependent};
+use zenoh_core::ztimeout;
+use zenoh_test::{get_free_tcp_port, get_tcp_locator};
+
+const TIMEOUT: Duration = Duration::from_secs(60);
 
 #[test]
 fn retry_config_overriding() {
@@ -26,7 +32,7 @@ fn retry_config_overriding() {
             [
                 "tcp/1.2.3.4:0",
                 "tcp/1.2.3.4:0#retry_period_init_ms=30000",
-                "tcp/1.2.3.4:0#retry_period_init_ms=30000;retry_period_max_ms=60000;retry_period_increase_factor=15;exit_on_failure=true",
+                "tcp/1.2.3.4:0#retry_period_init_ms=30000;retry_period_max_ms=60000;retry_period_increase_factor=15;retry_jitter=0.5;exit_on_failure=true",
             ]
             "#,
         )
@@ -55,6 +61,7 @@ fn retry_config_overriding() {
             period_init_ms: 3000,
             period_max_ms: 6000,
             period_increase_factor: 1.5,
+            jitter: 0.,
             exit_on_failure: false,
         },
         // override one key
@@ -62,6 +69,7 @@ fn retry_config_overriding() {
             period_init_ms: 30000,
             period_max_ms: 6000,
             period_increase_factor: 1.5,
+            jitter: 0.,
             exit_on_failure: false,
         },
         // override all keys
@@ -69,6 +77,7 @@ fn retry_config_overriding() {
             period_init_ms: 30000,
             period_max_ms: 60000,
             period_increase_factor: 15.,
+            jitter: 0.5,
             exit_on_failure: true,
         },
     ];
@@ -114,6 +123,50 @@ fn retry_config_parsing() {
     }
 }
 
+#[test]
+fn retry_config_jitter() {
+    let mut config = Config::default();
+    config
+        .insert_json5(
+            "listen/retry",
+            r#"
+            {
+                period_init_ms: 1000,
+                period_max_ms: 8000,
+                period_increase_factor: 2,
+                jitter: 0.25,
+            }
+            "#,
+        )
+        .unwrap();
+
+    let endpoint: EndPoint = "tcp/[::]:0".parse().unwrap();
+    let retry_config = zenoh_config::get_retry_config(&config, Some(&endpoint), true);
+
+    let mut period = retry_config.period();
+    let expected = vec![1000, 2000, 4000, 8000, 8000, 8000, 8000];
+    let shifts = [-1., 1., 0., -0.5, 0.5, 2., -2.];
+
+    for (v, shift) in expected.into_iter().zip(shifts) {
+        let base = std::time::Duration::from_millis(v);
+        assert_eq!(period.duration(), base);
+        let shift: f64 = shift;
+        assert_eq!(
+            period.next_duration_shifted(shift),
+            base.mul_f64(1. + shift.clamp(-1., 1.) * 0.25)
+        );
+    }
+
+    // the random shift stays within the jitter bounds
+    let mut period = retry_config.period();
+    let base = period.duration();
+    let wait = period.next_duration();
+    assert!(
+        wait >= base.mul_f64(0.75) && wait <= base.mul_f64(1.25),
+        "{wait:?} is not within 25% of {base:?}"
+    );
+}
+
 #[test]
 fn retry_config_const_period() {
     let mut config = Config::default();
@@ -189,3 +242,144 @@ fn listen_with_retry() {
 
     zenoh::open(config).wait().unwrap();
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn connect_retry_backoff_with_jitter() {
+    zenoh_util::init_log_from_env_or("error");
+    const INIT_MS: u64 = 200;
+    const MAX_MS: u64 = 1600;
+    const JITTER: f64 = 0.1;
+    const ATTEMPTS: u64 = 6;
+
+    let port = get_free_tcp_port();
+    let mut config = Config::default();
+    config.insert_json5("mode", r#""peer""#).unwrap();
+    config.insert_json5("listen/endpoints", "[]").unwrap();
+    config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    config.insert_json5("adminspace/enabled", "true").unwrap();
+    config
+        .insert_json5(
+            "connect/endpoints",
+            &format!(
+                r#"["tcp/127.0.0.1:{port}#retry_period_init_ms={INIT_MS};retry_period_max_ms={MAX_MS};retry_period_increase_factor=2;retry_jitter={JITTER}"]"#
+            ),
+        )
+        .unwrap();
+    let session = ztimeout!(zenoh::open(config)).unwrap();
+
+    // the wait computed after each failed attempt is within the jitter bounds of the backoff
+    ztimeout!(async {
+        loop {
+            let reply = session
+                .get(format!("@/{}/peer/connect/retry", session.zid()))
+                .await
+                .unwrap()
+                .recv_async()
+                .await
+                .unwrap();
+            let retries: serde_json::Value =
+                serde_json::from_slice(&reply.result().unwrap().payload().to_bytes()).unwrap();
+            if let Some((endpoint, state)) = retries.as_object().unwrap().iter().next() {
+                assert!(endpoint.starts_with(&format!("tcp/127.0.0.1:{port}")));
+                assert!(state["next_attempt_ms"].as_u64().is_some());
+                let attempts = state["attempts"].as_u64().unwrap();
+                let wait = Duration::from_millis(state["wait_ms"].as_u64().unwrap());
+                let base = Duration::from_millis((INIT_MS << (attempts - 1).min(8)).min(MAX_MS));
+                // the wait is truncated to milliseconds
+                assert!(
+                    wait >= base.mul_f64(1. - JITTER) - Duration::from_millis(1)
+                        && wait <= base.mul_f64(1. + JITTER),
+                    "wait {wait:?} of attempt {attempts} is not within the jitter bounds of {base:?}"
+                );
+                if attempts >= ATTEMPTS {
+                    break;
+                }
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    });
+
+    ztimeout!(session.close()).unwrap();
+}
+
+fn exit_on_failure_group_config(policy: &str, locators: [EndPoint; 2]) -> Config {
+    let mut config = Config::default();
+    config.insert_json5("mode", r#""peer""#).unwrap();
+    config.insert_json5("listen/endpoints", "[]").unwrap();
+    config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    config.insert_json5("connect/timeout_ms", "0").unwrap();
+    config
+        .insert_json5("connect/exit_on_failure", "true")
+        .unwrap();
+    config
+        .insert_json5("connect/exit_on_failure_policy", &format!(r#""{policy}""#))
+        .unwrap();
+    config
+        .insert_json5(
+            "connect/endpoints",
+            &format!(
+                r#"[{{ strategy: "allOf", locators: ["{}", "{}"] }}]"#,
+                locators[0], locators[1]
+            ),
+        )
+        .unwrap();
+    config
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn connect_exit_on_failure_policy() {
+    zenoh_util::init_log_from_env_or("error");
+
+    let mut config = Config::default();
+    config
+        .insert_json5("listen/endpoints", r#"["tcp/127.0.0.1:0"]"#)
+        .unwrap();
+    config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    let router = ztimeout!(zenoh::open(config)).unwrap();
+    let refused: EndPoint = format!("tcp/127.0.0.1:{}", get_free_tcp_port())
+        .parse()
+        .unwrap();
+    let locators = [refused, get_tcp_locator(&router).await];
+
+    // any endpoint failing makes the group fail
+    let config = exit_on_failure_group_config("any", locators.clone());
+    assert!(ztimeout!(zenoh::open(config)).is_err());
+
+    // the group only fails if all its endpoints fail
+    let config = exit_on_failure_group_config("all", locators.clone());
+    let peer = ztimeout!(zenoh::open(config)).unwrap();
+    ztimeout!(peer.close()).unwrap();
+
+    // the policy of an endpoint overrides the global one
+    let locators = locators.map(|mut locator| {
+        locator
+            .config_mut()
+            .insert("exit_on_failure_policy", "all")
+            .unwrap();
+        locator
+    });
+    let config = exit_on_failure_group_config("any", locators);
+    let peer = ztimeout!(zenoh::open(config)).unwrap();
+    ztimeout!(peer.close()).unwrap();
+
+    // the group fails if all its endpoints fail while retrying
+    let refused = || -> EndPoint {
+        format!("tcp/127.0.0.1:{}", get_free_tcp_port())
+            .parse()
+            .unwrap()
+    };
+    let mut config = exit_on_failure_group_config("all", [refused(), refused()]);
+    config.insert_json5("connect/timeout_ms", "1000").unwrap();
+    config
+        .insert_json5("connect/retry", "{ period_init_ms: 100 }")
+        .unwrap();
+    assert!(ztimeout!(zenoh::open(config)).is_err());
+
+    ztimeout!(router.close()).unwrap();
+}
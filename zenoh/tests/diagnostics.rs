@@ -0,0 +1,85 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#![cfg(feature = "unstable")]
+use std::time::Duration;
+
+use zenoh::{diagnostics::ping, key_expr::KeyExpr};
+use zenoh_core::ztimeout;
+use zenoh_test::TestSessions;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+const SLEEP: Duration = Duration::from_secs(1);
+const PROBE_TIMEOUT: Duration = Duration::from_millis(100);
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn diagnostics_ping() {
+    zenoh_util::init_log_from_env_or("error");
+    let mut test_sessions = TestSessions::new();
+    let (peer01, peer02) = test_sessions.open_pairs().await;
+    tokio::time::sleep(SLEEP).await;
+
+    let report = ztimeout!(ping(&peer01, peer02.zid(), 64, 10)).unwrap();
+    assert_eq!(report.sent(), 10);
+    assert_eq!(report.rtts().iter().flatten().count(), 10);
+    assert_eq!(report.lost(), 0);
+    assert_eq!(report.loss(), 0.0);
+
+    let key_expr = KeyExpr::try_from("@/ping/*").unwrap();
+    let report = ztimeout!(ping(&peer02, key_expr, 1024, 5)).unwrap();
+    assert_eq!(report.sent(), 5);
+    assert_eq!(report.lost(), 0);
+
+    test_sessions.close().await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn diagnostics_ping_disabled() {
+    zenoh_util::init_log_from_env_or("error");
+    let mut test_sessions = TestSessions::new();
+    let mut config = test_sessions.get_listener_config("tcp/127.0.0.1:0", 1);
+    config
+        .insert_json5("diagnostics/ping/enabled", "false")
+        .unwrap();
+    let peer01 = test_sessions.open_listener_with_cfg(config).await;
+    let peer02 = test_sessions.open_connector().await;
+    tokio::time::sleep(SLEEP).await;
+
+    let report = ztimeout!(ping(&peer02, peer01.zid(), 64, 3).timeout(PROBE_TIMEOUT)).unwrap();
+    assert_eq!(report.sent(), 3);
+    assert_eq!(report.lost(), 3);
+    assert_eq!(report.loss(), 1.0);
+
+    test_sessions.close().await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn diagnostics_ping_rate_limit() {
+    zenoh_util::init_log_from_env_or("error");
+    let mut test_sessions = TestSessions::new();
+    let mut config = test_sessions.get_listener_config("tcp/127.0.0.1:0", 1);
+    config
+        .insert_json5("diagnostics/ping/rate_limit", "5")
+        .unwrap();
+    let peer01 = test_sessions.open_listener_with_cfg(config).await;
+    let peer02 = test_sessions.open_connector().await;
+    tokio::time::sleep(SLEEP).await;
+
+    // The probes are sent within a second, only the first ones are answered
+    let report = ztimeout!(ping(&peer02, peer01.zid(), 64, 8).timeout(PROBE_TIMEOUT)).unwrap();
+    assert_eq!(report.sent(), 8);
+    assert!(report.rtts()[..5].iter().all(Option::is_some));
+    assert_eq!(report.lost(), 3);
+
+    test_sessions.close().await;
+}
@@ -0,0 +1,122 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#![cfg(feature = "unstable")]
+use std::time::Duration;
+
+use zenoh::{config::WhatAmI, session::SessionClosedError, Config, ErrKind, Session, ZErrorKind};
+use zenoh_core::{zerror, ztimeout};
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+async fn open_peer() -> Session {
+    let mut config = Config::default();
+    config.set_mode(Some(WhatAmI::Peer)).unwrap();
+    config.insert_json5("listen/endpoints", "[]").unwrap();
+    config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    ztimeout!(zenoh::open(config)).unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_error_kind_session_closed() {
+    zenoh_util::init_log_from_env_or("error");
+    let session = open_peer().await;
+    ztimeout!(session.close()).unwrap();
+
+    let err = ztimeout!(session.put("test/error_kind/closed", "")).unwrap_err();
+    assert_eq!(err.kind(), ZErrorKind::SessionClosed);
+    // The typed error is still returned
+    assert!(err.downcast_ref::<SessionClosedError>().is_some());
+
+    let err = ztimeout!(session.get("test/error_kind/closed")).unwrap_err();
+    assert_eq!(err.kind(), ZErrorKind::SessionClosed);
+
+    let err = ztimeout!(session.declare_subscriber("test/error_kind/closed")).unwrap_err();
+    assert_eq!(err.kind(), ZErrorKind::SessionClosed);
+
+    let err = ztimeout!(session.declare_queryable("test/error_kind/closed")).unwrap_err();
+    assert_eq!(err.kind(), ZErrorKind::SessionClosed);
+
+    let err = ztimeout!(session.declare_publisher("test/error_kind/closed")).unwrap_err();
+    assert_eq!(err.kind(), ZErrorKind::SessionClosed);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_error_kind_keyexpr_invalid() {
+    zenoh_util::init_log_from_env_or("error");
+    let session = open_peer().await;
+
+    let err = ztimeout!(session.put("test//error_kind", "")).unwrap_err();
+    assert_eq!(err.kind(), ZErrorKind::KeyExprInvalid);
+
+    let err = ztimeout!(session.get("test/error_kind/**/**")).unwrap_err();
+    assert_eq!(err.kind(), ZErrorKind::KeyExprInvalid);
+
+    let err = ztimeout!(session.declare_subscriber("test/error_kind/")).unwrap_err();
+    assert_eq!(err.kind(), ZErrorKind::KeyExprInvalid);
+
+    let err = ztimeout!(session.declare_publisher("test/error_kind/#")).unwrap_err();
+    assert_eq!(err.kind(), ZErrorKind::KeyExprInvalid);
+
+    ztimeout!(session.close()).unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_error_kind_declaration_refused() {
+    zenoh_util::init_log_from_env_or("error");
+    let session1 = open_peer().await;
+    let session2 = open_peer().await;
+
+    let key_expr = ztimeout!(session1.declare_keyexpr("test/error_kind/declared")).unwrap();
+    let err = ztimeout!(session2.undeclare(key_expr.clone())).unwrap_err();
+    assert_eq!(err.kind(), ZErrorKind::DeclarationRefused);
+    ztimeout!(session1.undeclare(key_expr)).unwrap();
+
+    ztimeout!(session1.close()).unwrap();
+    ztimeout!(session2.close()).unwrap();
+}
+
+#[test]
+fn test_error_kind_config_invalid() {
+    let mut config = Config::default();
+    let err = config
+        .insert_json5("scouting/multicast/enabled", "42")
+        .unwrap_err();
+    assert_eq!(err.kind(), ZErrorKind::ConfigInvalid);
+
+    let err = Config::from_json5("{mode: 42}").unwrap_err();
+    assert_eq!(err.kind(), ZErrorKind::ConfigInvalid);
+
+    let err = Config::from_json5("{mode:").unwrap_err();
+    assert_eq!(err.kind(), ZErrorKind::ConfigInvalid);
+}
+
+#[test]
+fn test_error_kind_wrapping() {
+    let source: zenoh::Error = zerror!(kind = Timeout, "deadline exceeded").into();
+    let wrapped = zerror!(source => "Unable to do it");
+    assert_eq!(wrapped.kind(), ZErrorKind::Timeout);
+
+    // The kind of the wrapper prevails
+    let wrapped = zerror!(kind = TransportFailure, wrapped => "Unable to do it again");
+    assert_eq!(wrapped.kind(), ZErrorKind::TransportFailure);
+
+    // Through typed errors
+    let wrapped: zenoh::Error = zerror!(SessionClosedError => "Unable to put").into();
+    assert_eq!(wrapped.kind(), ZErrorKind::SessionClosed);
+
+    let plain: zenoh::Error = "plain error".into();
+    assert_eq!(plain.kind(), ZErrorKind::Other);
+}
@@ -11,8 +11,10 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
-#![cfg(feature = "unstable")]
-use zenoh::key_expr::format::{kedefine, keformat};
+use zenoh::key_expr::{
+    format::{kedefine, keformat, KeFormat},
+    keyexpr,
+};
 
 #[test]
 fn kedefine_reuse() {
@@ -37,3 +39,83 @@ fn kedefine_reuse() {
 
     keformat!(formatter, group = "**", member = "**").unwrap_err();
 }
+
+#[test]
+fn keformat_parse() {
+    let format = KeFormat::new("factory/${line:*}/machine/${id:*}/state").unwrap();
+    let ke = keyexpr::new("factory/3/machine/m12/state").unwrap();
+    let parsed = format.parse(ke).unwrap();
+    assert_eq!(parsed.get("line").unwrap(), "3");
+    assert_eq!(parsed.get("id").unwrap(), "m12");
+    assert!(parsed.get("other").is_err());
+    assert_eq!(
+        parsed.iter().collect::<Vec<_>>(),
+        [
+            ("line", keyexpr::new("3").ok()),
+            ("id", keyexpr::new("m12").ok())
+        ]
+    );
+
+    assert!(format
+        .parse(keyexpr::new("factory/3/robot/m12/state").unwrap())
+        .is_err());
+}
+
+#[test]
+fn keformat_multi_chunk_captures() {
+    let format = KeFormat::new("factory/${path:**}/@state/${field:**}").unwrap();
+    let ke = format
+        .format([("path", "hall/3/machine/m12"), ("field", "temp/max")])
+        .unwrap();
+    assert_eq!(ke.as_str(), "factory/hall/3/machine/m12/@state/temp/max");
+
+    let parsed = format.parse(&ke).unwrap();
+    assert_eq!(parsed.get("path").unwrap(), "hall/3/machine/m12");
+    assert_eq!(parsed.get("field").unwrap(), "temp/max");
+
+    // `**` captures may be empty
+    let ke = format.format([("path", "m12"), ("field", "")]).unwrap();
+    assert_eq!(ke.as_str(), "factory/m12/@state");
+    assert_eq!(format.parse(&ke).unwrap().get("field").unwrap(), "");
+}
+
+#[test]
+fn keformat_format_errors() {
+    let format = KeFormat::new("factory/${line:*}/machine/${id:*}/state").unwrap();
+    assert_eq!(
+        format
+            .format([("line", "3"), ("id", "m12")])
+            .unwrap()
+            .as_str(),
+        "factory/3/machine/m12/state"
+    );
+    // Missing field
+    let err = format.format([("line", "3")]).unwrap_err();
+    assert!(err.to_string().contains("Missing field `id`"));
+    // Unknown field
+    assert!(format
+        .format([("line", "3"), ("id", "m12"), ("zone", "a")])
+        .is_err());
+    // A single chunk spec doesn't accept several chunks
+    assert!(format.format([("line", "3/4"), ("id", "m12")]).is_err());
+    // Specs must be valid key expression patterns
+    assert!(KeFormat::new("factory/${line:}/state").is_err());
+    assert!(KeFormat::new("factory/${line:a//b}/state").is_err());
+}
+
+#[test]
+fn keformat_defaults() {
+    let format = KeFormat::new("factory/${line:*#main}/${id:*}").unwrap();
+    assert_eq!(
+        format.format([("id", "m12")]).unwrap().as_str(),
+        "factory/main/m12"
+    );
+    kedefine!(
+        pub machine: "factory/${line:*#main}/${id:*}",
+    );
+    let mut formatter = machine::formatter();
+    assert_eq!(
+        keformat!(formatter, id = "m12").unwrap().as_str(),
+        "factory/main/m12"
+    );
+}
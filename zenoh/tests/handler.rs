@@ -64,3 +64,62 @@ fn query_with_ringbuffer() {
     // Only receive the latest query
     assert_eq!(query.payload().unwrap().try_to_string().unwrap(), "query2");
 }
+
+#[cfg(feature = "unstable")]
+#[test]
+fn pubsub_with_pull_mode() {
+    let zenoh = zenoh::open(Config::default()).wait().unwrap();
+    let sub = zenoh
+        .declare_subscriber("test/pull_mode")
+        .pull_mode()
+        .wait()
+        .unwrap();
+    for i in 0..100 {
+        zenoh
+            .put("test/pull_mode", format!("put{i}"))
+            .wait()
+            .unwrap();
+    }
+    assert_eq!(sub.buffered(), 100);
+    for start in [0, 10] {
+        let samples = sub.pull(10).unwrap();
+        assert_eq!(samples.len(), 10);
+        for (i, sample) in (start..start + 10).zip(samples) {
+            assert_eq!(sample.payload().try_to_string().unwrap(), format!("put{i}"));
+        }
+    }
+    assert_eq!(sub.buffered(), 80);
+    assert_eq!(sub.pull(100).unwrap().len(), 80);
+    assert!(sub.pull(10).unwrap().is_empty());
+}
+
+#[cfg(feature = "unstable")]
+#[test]
+fn pubsub_with_pull_channel_overflow() {
+    use zenoh::handlers::{PullChannel, PullOverflow};
+
+    let zenoh = zenoh::open(Config::default()).wait().unwrap();
+    for (overflow, first) in [
+        (PullOverflow::DropOldest, 50),
+        (PullOverflow::DropNewest, 0),
+    ] {
+        let sub = zenoh
+            .declare_subscriber("test/pull_overflow")
+            .with(PullChannel::new(50).overflow(overflow))
+            .wait()
+            .unwrap();
+        for i in 0..100 {
+            zenoh
+                .put("test/pull_overflow", format!("put{i}"))
+                .wait()
+                .unwrap();
+        }
+        assert_eq!(sub.buffered(), 50);
+        let samples = sub.pull(10).unwrap();
+        for (i, sample) in (first..first + 10).zip(samples) {
+            assert_eq!(sample.payload().try_to_string().unwrap(), format!("put{i}"));
+        }
+        assert_eq!(sub.buffered(), 40);
+        sub.undeclare().wait().unwrap();
+    }
+}
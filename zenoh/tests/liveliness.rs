@@ -4216,3 +4216,84 @@ async fn test_liveliness_sub_history_conflict() {
 
     test_context.close().await;
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_liveliness_count_and_group_view() {
+    use std::time::Duration;
+
+    use zenoh::sample::SampleKind;
+    use zenoh_config::WhatAmI;
+    const TIMEOUT: Duration = Duration::from_secs(60);
+    const SLEEP: Duration = Duration::from_secs(1);
+    const LIVELINESS_KEYEXPR_1: &str = "test/liveliness/group/1";
+    const LIVELINESS_KEYEXPR_2: &str = "test/liveliness/group/2";
+    const LIVELINESS_KEYEXPR_GROUP: &str = "test/liveliness/group/*";
+
+    zenoh_util::init_log_from_env_or("error");
+
+    let mut test_context = TestSessions::new();
+    let mut config_peer1 = test_context.get_listener_config("tcp/127.0.0.1:0", 1);
+    config_peer1.set_mode(Some(WhatAmI::Peer)).unwrap();
+    let peer1 = test_context.open_listener_with_cfg(config_peer1).await;
+    tracing::info!("Peer (1) ZID: {}", peer1.zid());
+
+    let mut config_peer2 = test_context.get_connector_config();
+    config_peer2.set_mode(Some(WhatAmI::Peer)).unwrap();
+    let peer2 = test_context.open_connector_with_cfg(config_peer2).await;
+    tracing::info!("Peer (2) ZID: {}", peer2.zid());
+
+    let token1 = ztimeout!(peer2.liveliness().declare_token(LIVELINESS_KEYEXPR_1)).unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    let view = ztimeout!(peer1
+        .liveliness()
+        .declare_group_view(LIVELINESS_KEYEXPR_GROUP))
+    .unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    // The tokens alive before the view is declared are part of its initial state
+    let change = ztimeout!(view.recv_async()).unwrap();
+    assert!(change.kind() == SampleKind::Put);
+    assert!(change.key_expr().as_str() == LIVELINESS_KEYEXPR_1);
+    assert!(change.alive().len() == 1);
+    assert!(view.count() == 1);
+    assert!(ztimeout!(peer1.liveliness().count(LIVELINESS_KEYEXPR_GROUP)).unwrap() == 1);
+
+    let token2 = ztimeout!(peer2.liveliness().declare_token(LIVELINESS_KEYEXPR_2)).unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    let change = ztimeout!(view.recv_async()).unwrap();
+    assert!(change.kind() == SampleKind::Put);
+    assert!(change.key_expr().as_str() == LIVELINESS_KEYEXPR_2);
+    assert!(change.alive().len() == 2);
+    assert!(view.count() == 2);
+    assert!(ztimeout!(peer1.liveliness().count(LIVELINESS_KEYEXPR_GROUP)).unwrap() == 2);
+
+    token1.undeclare().await.unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    let change = ztimeout!(view.recv_async()).unwrap();
+    assert!(change.kind() == SampleKind::Delete);
+    assert!(change.key_expr().as_str() == LIVELINESS_KEYEXPR_1);
+    assert!(change
+        .alive()
+        .iter()
+        .map(|ke| ke.as_str())
+        .eq([LIVELINESS_KEYEXPR_2]));
+    assert!(view.count() == 1);
+    assert!(ztimeout!(peer1.liveliness().count(LIVELINESS_KEYEXPR_GROUP)).unwrap() == 1);
+    assert!(view.try_recv().unwrap().is_none());
+
+    token2.undeclare().await.unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    let change = ztimeout!(view.recv_async()).unwrap();
+    assert!(change.kind() == SampleKind::Delete);
+    assert!(change.alive().is_empty());
+    assert!(view.count() == 0);
+    assert!(ztimeout!(peer1.liveliness().count(LIVELINESS_KEYEXPR_GROUP)).unwrap() == 0);
+
+    view.undeclare().await.unwrap();
+
+    test_context.close().await;
+}
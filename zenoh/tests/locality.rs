@@ -0,0 +1,95 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#![cfg(feature = "unstable")]
+
+// The default locality is process-wide: this file holds a single test so that no other test
+// observes the override, and the test restores it even when failing.
+
+use std::time::Duration;
+
+use zenoh::sample::{default_locality, set_default_locality, Locality};
+use zenoh_config::WhatAmI;
+use zenoh_core::ztimeout;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+const RECV_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Restores the default locality it was created with when dropped.
+struct DefaultLocalityGuard(Locality);
+
+impl Drop for DefaultLocalityGuard {
+    fn drop(&mut self) {
+        set_default_locality(self.0);
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_default_locality_override() {
+    zenoh_util::init_log_from_env_or("error");
+
+    let mut config = zenoh_config::Config::default();
+    config.set_mode(Some(WhatAmI::Peer)).unwrap();
+    config.listen.endpoints.set(vec![]).unwrap();
+    config.scouting.multicast.set_enabled(Some(false)).unwrap();
+    let session = ztimeout!(zenoh::open(config)).unwrap();
+
+    assert_eq!(default_locality(), Locality::Any);
+    let _guard = DefaultLocalityGuard(default_locality());
+    set_default_locality(Locality::Remote);
+    assert_eq!(default_locality(), Locality::Remote);
+    assert_eq!(Locality::default(), Locality::Remote);
+
+    // Both the subscriber and the put exclude the local session
+    let subscriber = ztimeout!(session.declare_subscriber("test/locality/remote")).unwrap();
+    ztimeout!(session.put("test/locality/remote", "value")).unwrap();
+    assert!(tokio::time::timeout(RECV_TIMEOUT, subscriber.recv_async())
+        .await
+        .is_err());
+
+    // An explicit locality still takes precedence over the default
+    let subscriber = ztimeout!(session
+        .declare_subscriber("test/locality/any")
+        .allowed_origin(Locality::Any))
+    .unwrap();
+    ztimeout!(session
+        .put("test/locality/any", "value")
+        .allowed_destination(Locality::Any))
+    .unwrap();
+    let sample = ztimeout!(subscriber.recv_async()).unwrap();
+    assert_eq!(sample.key_expr().as_str(), "test/locality/any");
+
+    // Liveliness subscribers follow the default too. Tokens are always delivered through the
+    // router, so a session-local liveliness subscriber doesn't see any, even the session ones.
+    set_default_locality(Locality::SessionLocal);
+    let liveliness_subscriber = ztimeout!(session
+        .liveliness()
+        .declare_subscriber("test/locality/token"))
+    .unwrap();
+    let _token = ztimeout!(session.liveliness().declare_token("test/locality/token")).unwrap();
+    assert!(
+        tokio::time::timeout(RECV_TIMEOUT, liveliness_subscriber.recv_async())
+            .await
+            .is_err()
+    );
+
+    set_default_locality(Locality::Any);
+    assert_eq!(Locality::default(), Locality::Any);
+
+    let subscriber = ztimeout!(session.declare_subscriber("test/locality/local")).unwrap();
+    ztimeout!(session.put("test/locality/local", "value")).unwrap();
+    let sample = ztimeout!(subscriber.recv_async()).unwrap();
+    assert_eq!(sample.key_expr().as_str(), "test/locality/local");
+
+    ztimeout!(session.close()).unwrap();
+}
@@ -0,0 +1,36 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#![cfg(feature = "clap")]
+use clap::Parser;
+use zenoh::sample::Locality;
+
+#[derive(Parser)]
+struct Args {
+    #[arg(long, value_enum, default_value_t = Locality::Any)]
+    locality: Locality,
+}
+
+#[test]
+fn locality_clap_value_enum() {
+    for (arg, locality) in [
+        ("session-local", Locality::SessionLocal),
+        ("remote", Locality::Remote),
+        ("any", Locality::Any),
+    ] {
+        let args = Args::try_parse_from(["test", "--locality", arg]).unwrap();
+        assert_eq!(args.locality, locality);
+    }
+    assert_eq!(Args::parse_from(["test"]).locality, Locality::Any);
+    assert!(Args::try_parse_from(["test", "--locality", "local"]).is_err());
+}
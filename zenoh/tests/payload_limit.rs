@@ -0,0 +1,144 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#![cfg(feature = "unstable")]
+
+use std::time::Duration;
+
+use zenoh::{config::WhatAmI, Config, Wait};
+use zenoh_core::ztimeout;
+use zenoh_test::get_tcp_locator;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+const SLEEP: Duration = Duration::from_secs(1);
+
+/// Limits payloads to 16 bytes, except on `cmd/**` where they are limited to 64 bytes.
+fn limits_config(include_attachment: bool) -> Config {
+    let mut config = Config::default();
+    config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    config
+        .insert_json5(
+            "limits",
+            &format!(
+                r#"{{
+                    max_payload_size: 16,
+                    include_attachment: {include_attachment},
+                    overrides: [{{ key_exprs: ["cmd/**"], max_payload_size: 64 }}],
+                }}"#
+            ),
+        )
+        .unwrap();
+    config
+}
+
+fn client_config(router: &zenoh::config::EndPoint) -> Config {
+    let mut config = Config::default();
+    config.set_mode(Some(WhatAmI::Client)).unwrap();
+    config
+        .insert_json5("connect/endpoints", &format!(r#"["{router}"]"#))
+        .unwrap();
+    config
+}
+
+#[test]
+fn test_payload_limit_session_put() {
+    zenoh_util::init_log_from_env_or("error");
+    let session = zenoh::open(limits_config(false)).wait().unwrap();
+
+    session.put("data/a", [0u8; 16]).wait().unwrap();
+    assert!(session.put("data/a", [0u8; 17]).wait().is_err());
+    session.put("cmd/a", [0u8; 64]).wait().unwrap();
+    assert!(session.put("cmd/a", [0u8; 65]).wait().is_err());
+
+    let publisher = session.declare_publisher("data/b").wait().unwrap();
+    publisher.put([0u8; 16]).wait().unwrap();
+    assert!(publisher.put([0u8; 17]).wait().is_err());
+
+    // The attachment is not accounted unless `include_attachment` is set.
+    session
+        .put("data/a", [0u8; 16])
+        .attachment([0u8; 16])
+        .wait()
+        .unwrap();
+
+    session.close().wait().unwrap();
+}
+
+#[test]
+fn test_payload_limit_session_include_attachment() {
+    zenoh_util::init_log_from_env_or("error");
+    let session = zenoh::open(limits_config(true)).wait().unwrap();
+
+    session
+        .put("data/a", [0u8; 8])
+        .attachment([0u8; 8])
+        .wait()
+        .unwrap();
+    assert!(session
+        .put("data/a", [0u8; 8])
+        .attachment([0u8; 9])
+        .wait()
+        .is_err());
+    assert!(session
+        .delete("data/a")
+        .attachment([0u8; 17])
+        .wait()
+        .is_err());
+
+    session.close().wait().unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_payload_limit_router_ingress() {
+    zenoh_util::init_log_from_env_or("error");
+
+    let mut config = limits_config(false);
+    config.set_mode(Some(WhatAmI::Router)).unwrap();
+    config
+        .insert_json5("listen/endpoints", r#"["tcp/127.0.0.1:0"]"#)
+        .unwrap();
+    config.insert_json5("adminspace/enabled", "true").unwrap();
+    let router = ztimeout!(zenoh::open(config)).unwrap();
+    let router_endpoint = get_tcp_locator(&router).await;
+
+    let subscriber_session = ztimeout!(zenoh::open(client_config(&router_endpoint))).unwrap();
+    let subscriber = ztimeout!(subscriber_session.declare_subscriber("**")).unwrap();
+    let publisher_session = ztimeout!(zenoh::open(client_config(&router_endpoint))).unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    for (key_expr, size) in [("data/a", 16), ("data/b", 17), ("cmd/a", 64), ("cmd/b", 65)] {
+        ztimeout!(publisher_session.put(key_expr, vec![0u8; size])).unwrap();
+    }
+    tokio::time::sleep(SLEEP).await;
+
+    let received: Vec<String> = subscriber
+        .drain()
+        .map(|sample| sample.key_expr().to_string())
+        .collect();
+    assert_eq!(received, ["data/a", "cmd/a"]);
+
+    let reply = ztimeout!(router.get(format!("@/{}/router/limits", router.zid())))
+        .unwrap()
+        .recv_async()
+        .await
+        .unwrap();
+    let limits: serde_json::Value =
+        serde_json::from_slice(&reply.result().unwrap().payload().to_bytes()).unwrap();
+    assert_eq!(limits["dropped"], 2);
+
+    ztimeout!(publisher_session.close()).unwrap();
+    ztimeout!(subscriber_session.close()).unwrap();
+    ztimeout!(router.close()).unwrap();
+}
@@ -0,0 +1,227 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#![cfg(all(feature = "plugins", feature = "internal", feature = "unstable"))]
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use zenoh::{
+    config::WhatAmI,
+    internal::{
+        plugins::{PluginEnv, PluginsManager, RunningPlugin, RunningPluginTrait, ZenohPlugin},
+        runtime::{DynamicRuntime, RuntimeBuilder},
+    },
+    Config, Result as ZResult, Session, Wait,
+};
+use zenoh_core::ztimeout;
+use zenoh_plugin_trait::{plugin_long_version, plugin_version, Plugin, PluginControl};
+use zenoh_test::get_tcp_locator;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+const SLEEP: Duration = Duration::from_secs(1);
+
+static RECEIVED: AtomicUsize = AtomicUsize::new(0);
+
+/// A plugin counting the samples published on `stub/**` and replying to queries on `stub/query`.
+struct StubPlugin;
+
+impl ZenohPlugin for StubPlugin {}
+
+impl Plugin for StubPlugin {
+    type StartArgs = DynamicRuntime;
+    type Instance = RunningPlugin;
+
+    const DEFAULT_NAME: &'static str = "stub";
+    const PLUGIN_VERSION: &'static str = plugin_version!();
+    const PLUGIN_LONG_VERSION: &'static str = plugin_long_version!();
+
+    fn start(name: &str, runtime: &Self::StartArgs) -> ZResult<Self::Instance> {
+        let env = PluginEnv::new(name, runtime)?;
+        env.declare_subscriber("stub/**", |_| {
+            RECEIVED.fetch_add(1, Ordering::SeqCst);
+        })?;
+        env.declare_queryable("stub/query", |query| {
+            query.reply("stub/query", "reply").wait().unwrap();
+        })?;
+        Ok(Box::new(StubInstance))
+    }
+}
+
+/// A plugin replying to queries on `status/query`.
+struct StatusPlugin;
+
+impl ZenohPlugin for StatusPlugin {}
+
+impl Plugin for StatusPlugin {
+    type StartArgs = DynamicRuntime;
+    type Instance = RunningPlugin;
+
+    const DEFAULT_NAME: &'static str = "status";
+    const PLUGIN_VERSION: &'static str = plugin_version!();
+    const PLUGIN_LONG_VERSION: &'static str = plugin_long_version!();
+
+    fn start(name: &str, runtime: &Self::StartArgs) -> ZResult<Self::Instance> {
+        let env = PluginEnv::new(name, runtime)?;
+        env.declare_queryable("status/query", |query| {
+            query.reply("status/query", "ok").wait().unwrap();
+        })?;
+        Ok(Box::new(StubInstance))
+    }
+}
+
+struct StubInstance;
+
+impl PluginControl for StubInstance {}
+
+impl RunningPluginTrait for StubInstance {}
+
+async fn plugin_declarations(router: &Session) -> Vec<serde_json::Value> {
+    let replies =
+        ztimeout!(router.get(format!("@/{}/router/plugin_declarations/*", router.zid()))).unwrap();
+    let mut declarations = Vec::new();
+    while let Ok(reply) = replies.recv_async().await {
+        let payload = reply.result().unwrap().payload().to_bytes();
+        declarations.push(serde_json::from_slice(&payload).unwrap());
+    }
+    declarations
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_plugin_env_undeclares_on_stop() {
+    zenoh_util::init_log_from_env_or("error");
+
+    let mut config = Config::default();
+    config.set_mode(Some(WhatAmI::Router)).unwrap();
+    config
+        .insert_json5("listen/endpoints", r#"["tcp/127.0.0.1:0"]"#)
+        .unwrap();
+    config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    config.insert_json5("adminspace/enabled", "true").unwrap();
+    let mut plugins_manager = PluginsManager::static_plugins_only();
+    plugins_manager.declare_static_plugin::<StubPlugin, &str>("stub", true);
+    let mut runtime = ztimeout!(RuntimeBuilder::new(config)
+        .plugins_manager(plugins_manager)
+        .build())
+    .unwrap();
+    let router = ztimeout!(zenoh::session::init(runtime.clone().into())).unwrap();
+    ztimeout!(runtime.start()).unwrap();
+    let router_endpoint = get_tcp_locator(&router).await;
+
+    let mut client_config = Config::default();
+    client_config.set_mode(Some(WhatAmI::Client)).unwrap();
+    client_config
+        .insert_json5("connect/endpoints", &format!(r#"["{router_endpoint}"]"#))
+        .unwrap();
+    let client = ztimeout!(zenoh::open(client_config)).unwrap();
+    let publisher = ztimeout!(client.declare_publisher("stub/a")).unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    assert!(ztimeout!(publisher.matching_status()).unwrap().matching());
+    ztimeout!(publisher.put("a")).unwrap();
+    let replies = ztimeout!(client.get("stub/query")).unwrap();
+    assert!(ztimeout!(replies.recv_async()).is_ok());
+    tokio::time::sleep(SLEEP).await;
+    assert_eq!(RECEIVED.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        plugin_declarations(&router).await,
+        [serde_json::json!({ "subscribers": ["stub/**"], "queryables": ["stub/query"] })]
+    );
+
+    // Keep publishing while the plugin is stopped: no callback may run once `stop_plugin`
+    // returned, as the library of a dynamic plugin is released right after.
+    let publishing = Arc::new(AtomicBool::new(true));
+    let publish_task = tokio::spawn({
+        let publishing = publishing.clone();
+        async move {
+            while publishing.load(Ordering::SeqCst) {
+                publisher.put("a").await.unwrap();
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+            publisher
+        }
+    });
+    tokio::time::sleep(SLEEP).await;
+    assert!(runtime.stop_plugin("stub"));
+    let received = RECEIVED.load(Ordering::SeqCst);
+    assert!(received > 1);
+    assert!(!runtime.stop_plugin("stub"));
+    tokio::time::sleep(SLEEP).await;
+    publishing.store(false, Ordering::SeqCst);
+    let publisher = ztimeout!(publish_task).unwrap();
+    assert_eq!(RECEIVED.load(Ordering::SeqCst), received);
+
+    // The undeclarations reached the client
+    assert!(!ztimeout!(publisher.matching_status()).unwrap().matching());
+    let replies = ztimeout!(client.get("stub/query")).unwrap();
+    assert!(ztimeout!(replies.recv_async()).is_err());
+    assert!(plugin_declarations(&router).await.is_empty());
+
+    ztimeout!(client.close()).unwrap();
+    ztimeout!(router.close()).unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_plugin_env_undeclares_on_close() {
+    zenoh_util::init_log_from_env_or("error");
+
+    let mut config = Config::default();
+    config.set_mode(Some(WhatAmI::Router)).unwrap();
+    config
+        .insert_json5("listen/endpoints", r#"["tcp/127.0.0.1:0"]"#)
+        .unwrap();
+    config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    config.insert_json5("adminspace/enabled", "true").unwrap();
+    let mut plugins_manager = PluginsManager::static_plugins_only();
+    plugins_manager.declare_static_plugin::<StatusPlugin, &str>("status", true);
+    let mut runtime = ztimeout!(RuntimeBuilder::new(config)
+        .plugins_manager(plugins_manager)
+        .build())
+    .unwrap();
+    let router = ztimeout!(zenoh::session::init(runtime.clone().into())).unwrap();
+    ztimeout!(runtime.start()).unwrap();
+    let router_endpoint = get_tcp_locator(&router).await;
+
+    let mut client_config = Config::default();
+    client_config.set_mode(Some(WhatAmI::Client)).unwrap();
+    client_config
+        .insert_json5("connect/endpoints", &format!(r#"["{router_endpoint}"]"#))
+        .unwrap();
+    let client = ztimeout!(zenoh::open(client_config)).unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    let replies = ztimeout!(client.get("status/query")).unwrap();
+    assert!(ztimeout!(replies.recv_async()).unwrap().result().is_ok());
+    assert_eq!(
+        plugin_declarations(&router).await,
+        [serde_json::json!({ "subscribers": [], "queryables": ["status/query"] })]
+    );
+
+    // Closing the runtime undeclares the entities of the plugin
+    ztimeout!(router.close()).unwrap();
+    ztimeout!(runtime.close()).unwrap();
+    tokio::time::sleep(SLEEP).await;
+    let replies = ztimeout!(client.get("status/query")).unwrap();
+    assert!(ztimeout!(replies.recv_async()).is_err());
+
+    ztimeout!(client.close()).unwrap();
+}
@@ -0,0 +1,108 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#![cfg(feature = "unstable")]
+
+use std::time::Duration;
+
+use zenoh::{
+    pubsub::{LatencyHistogram, LatencySnapshot},
+    Config, Wait,
+};
+
+/// A histogram keeping every recorded latency.
+#[derive(Default)]
+struct VecHistogram(Vec<Duration>);
+
+impl LatencyHistogram for VecHistogram {
+    fn record(&mut self, latency: Duration) {
+        self.0.push(latency);
+    }
+
+    fn snapshot(&self) -> LatencySnapshot {
+        let max = self.0.iter().max().copied().unwrap_or_default();
+        LatencySnapshot::new(self.0.len() as u64, max, max, max, max)
+    }
+
+    fn reset(&mut self) {
+        self.0.clear();
+    }
+}
+
+#[test]
+fn test_publisher_latency_disabled_by_default() {
+    let session = zenoh::open(Config::default()).wait().unwrap();
+    let publisher = session.declare_publisher("test/latency").wait().unwrap();
+    publisher.put("value").wait().unwrap();
+    assert_eq!(publisher.latency_snapshot(), None);
+    publisher.reset_latency();
+    assert_eq!(publisher.latency_snapshot(), None);
+    session.close().wait().unwrap();
+}
+
+#[test]
+fn test_publisher_latency_custom_histogram() {
+    let session = zenoh::open(Config::default()).wait().unwrap();
+    let publisher = session
+        .declare_publisher("test/latency")
+        .wait()
+        .unwrap()
+        .with_latency_histogram(VecHistogram::default());
+    assert_eq!(
+        publisher.latency_snapshot(),
+        Some(LatencySnapshot::default())
+    );
+
+    publisher.put("value").wait().unwrap();
+    publisher.delete().wait().unwrap();
+    let snapshot = publisher.latency_snapshot().unwrap();
+    assert_eq!(snapshot.count(), 2);
+    assert!(snapshot.max() > Duration::ZERO);
+
+    publisher.reset_latency();
+    assert_eq!(
+        publisher.latency_snapshot(),
+        Some(LatencySnapshot::default())
+    );
+    publisher.put("value").wait().unwrap();
+    assert_eq!(publisher.latency_snapshot().unwrap().count(), 1);
+    session.close().wait().unwrap();
+}
+
+#[cfg(feature = "latency_histogram")]
+#[test]
+fn test_publisher_latency_tracking() {
+    let session = zenoh::open(Config::default()).wait().unwrap();
+    let publisher = session
+        .declare_publisher("test/latency")
+        .wait()
+        .unwrap()
+        .with_latency_tracking(true);
+    for _ in 0..100 {
+        publisher.put("value").wait().unwrap();
+    }
+    let snapshot = publisher.latency_snapshot().unwrap();
+    assert_eq!(snapshot.count(), 100);
+    assert!(snapshot.p50() <= snapshot.p90());
+    assert!(snapshot.p90() <= snapshot.p99());
+    assert!(snapshot.p99() <= snapshot.max());
+    assert!(snapshot.max() > Duration::ZERO);
+
+    publisher.reset_latency();
+    assert_eq!(publisher.latency_snapshot().unwrap().count(), 0);
+
+    let publisher = publisher.with_latency_tracking(false);
+    publisher.put("value").wait().unwrap();
+    assert_eq!(publisher.latency_snapshot(), None);
+    session.close().wait().unwrap();
+}
@@ -0,0 +1,121 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#![cfg(feature = "unstable")]
+use std::time::Duration;
+
+use zenoh::{
+    config::WhatAmI, key_expr::keyexpr, qos::Priority, sample::SampleKind, Config, ErrKind,
+    Session, ZErrorKind,
+};
+use zenoh_core::ztimeout;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+const SLEEP: Duration = Duration::from_secs(1);
+
+async fn open_pair(port: u16) -> (Session, Session) {
+    let mut config = Config::default();
+    config.set_mode(Some(WhatAmI::Peer)).unwrap();
+    config
+        .insert_json5("listen/endpoints", &format!("[\"tcp/127.0.0.1:{port}\"]"))
+        .unwrap();
+    config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    let session1 = ztimeout!(zenoh::open(config)).unwrap();
+
+    let mut config = Config::default();
+    config.set_mode(Some(WhatAmI::Peer)).unwrap();
+    config
+        .insert_json5("connect/endpoints", &format!("[\"tcp/127.0.0.1:{port}\"]"))
+        .unwrap();
+    config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    let session2 = ztimeout!(zenoh::open(config)).unwrap();
+    (session1, session2)
+}
+
+async fn check_delivery(publisher_key_expr: &str, sub_key_expr: &str, port: u16) {
+    let (session1, session2) = open_pair(port).await;
+
+    let subscriber = ztimeout!(session2.declare_subscriber(sub_key_expr)).unwrap();
+    let other_subscriber =
+        ztimeout!(session2.declare_subscriber(format!("{sub_key_expr}/x"))).unwrap();
+    let publisher = ztimeout!(session1
+        .declare_publisher(publisher_key_expr)
+        .priority(Priority::DataHigh))
+    .unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    let suffix = keyexpr::new("arm/pose").unwrap();
+    ztimeout!(publisher.put_suffixed(suffix, "pose")).unwrap();
+    let sample = ztimeout!(subscriber.recv_async()).unwrap();
+    assert_eq!(sample.key_expr().as_str(), sub_key_expr);
+    assert_eq!(sample.kind(), SampleKind::Put);
+    assert_eq!(sample.payload().try_to_string().unwrap(), "pose");
+    assert_eq!(sample.priority(), Priority::DataHigh);
+
+    ztimeout!(publisher.delete_suffixed(suffix)).unwrap();
+    let sample = ztimeout!(subscriber.recv_async()).unwrap();
+    assert_eq!(sample.key_expr().as_str(), sub_key_expr);
+    assert_eq!(sample.kind(), SampleKind::Delete);
+
+    // Only the concrete sub-key receives the samples
+    tokio::time::sleep(SLEEP).await;
+    assert!(other_subscriber.try_recv().unwrap().is_none());
+
+    ztimeout!(session1.close()).unwrap();
+    ztimeout!(session2.close()).unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn publisher_suffix_delivery() {
+    zenoh_util::init_log_from_env_or("error");
+    check_delivery(
+        "test/publisher_suffix/1",
+        "test/publisher_suffix/1/arm/pose",
+        27461,
+    )
+    .await;
+    check_delivery(
+        "test/publisher_suffix/2/**",
+        "test/publisher_suffix/2/arm/pose",
+        27462,
+    )
+    .await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn publisher_suffix_invalid() {
+    zenoh_util::init_log_from_env_or("error");
+    let mut config = Config::default();
+    config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    let session = ztimeout!(zenoh::open(config)).unwrap();
+
+    let publisher = ztimeout!(session.declare_publisher("test/publisher_suffix/**")).unwrap();
+    for suffix in ["arm/*", "**", "arm/a$*"] {
+        let err = ztimeout!(publisher.put_suffixed(keyexpr::new(suffix).unwrap(), "")).unwrap_err();
+        assert_eq!(err.kind(), ZErrorKind::KeyExprInvalid);
+        let err = ztimeout!(publisher.delete_suffixed(keyexpr::new(suffix).unwrap())).unwrap_err();
+        assert_eq!(err.kind(), ZErrorKind::KeyExprInvalid);
+    }
+
+    let publisher = ztimeout!(session.declare_publisher("test/*/publisher_suffix")).unwrap();
+    let err = ztimeout!(publisher.put_suffixed(keyexpr::new("arm").unwrap(), "")).unwrap_err();
+    assert_eq!(err.kind(), ZErrorKind::KeyExprInvalid);
+
+    ztimeout!(session.close()).unwrap();
+}
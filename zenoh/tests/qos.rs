@@ -16,6 +16,7 @@ use std::time::Duration;
 use zenoh::{
     bytes::Encoding,
     qos::{CongestionControl, Priority},
+    Wait,
 };
 use zenoh_core::ztimeout;
 
@@ -63,6 +64,37 @@ async fn qos_pubsub() {
     assert!(!sample.express());
 }
 
+#[cfg(feature = "unstable")]
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn qos_pubsub_from_json() {
+    use zenoh::qos::{QoS, Reliability};
+
+    let session1 = ztimeout!(zenoh::open(zenoh::Config::default())).unwrap();
+    let session2 = ztimeout!(zenoh::open(zenoh::Config::default())).unwrap();
+
+    let qos = QoS::try_from(&serde_json::json!({
+        "priority": "data_high",
+        "congestion_control": "drop",
+        "reliability": "best_effort",
+    }))
+    .unwrap();
+    let publisher = ztimeout!(session1
+        .declare_publisher("test/qos/json")
+        .reliability(Reliability::Reliable)
+        .qos(qos))
+    .unwrap();
+
+    let subscriber = ztimeout!(session2.declare_subscriber("test/qos/json")).unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    ztimeout!(publisher.put("qos")).unwrap();
+    let sample = ztimeout!(subscriber.recv_async()).unwrap();
+
+    assert_eq!(sample.priority(), Priority::DataHigh);
+    assert_eq!(sample.congestion_control(), CongestionControl::Drop);
+    assert_eq!(sample.reliability(), Reliability::BestEffort);
+}
+
 #[cfg(feature = "unstable")]
 #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
 async fn qos_pubsub_overwrite_config() {
@@ -222,3 +254,272 @@ async fn qos_pubsub_overwrite_config() {
     assert!(sample.express());
     assert_eq!(sample.reliability(), Reliability::Reliable);
 }
+
+#[cfg(feature = "unstable")]
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn qos_block_with_timeout() {
+    use std::{
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+        time::Instant,
+    };
+
+    use zenoh::{qos::BlockTimeoutError, ErrKind, Wait, ZErrorKind};
+    use zenoh_test::TestSessions;
+
+    const KEY_EXPR: &str = "test/qos/block_with_timeout";
+    const BLOCK_TIMEOUT: Duration = Duration::from_millis(500);
+    const TOLERANCE: Duration = Duration::from_secs(2);
+    const MAX_PUTS: usize = 10_000;
+
+    let mut test_sessions = TestSessions::new();
+    let (session1, session2) = test_sessions.open_pairs().await;
+
+    // Stall the reception on session2 so that the transmission pipeline of session1 saturates
+    let blocked = Arc::new(AtomicBool::new(true));
+    let c_blocked = blocked.clone();
+    let _subscriber = ztimeout!(session2.declare_subscriber(KEY_EXPR).callback(move |_| {
+        while c_blocked.load(Ordering::Relaxed) {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }))
+    .unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    let publisher = ztimeout!(session1
+        .declare_publisher(KEY_EXPR)
+        .congestion_control(CongestionControl::BlockWithTimeout)
+        .block_timeout(BLOCK_TIMEOUT))
+    .unwrap();
+
+    let payload = vec![0u8; 1_000_000];
+    let mut result = None;
+    for _ in 0..MAX_PUTS {
+        let start = Instant::now();
+        if let Err(e) = publisher.put(payload.clone()).wait() {
+            result = Some((e, start.elapsed()));
+            break;
+        }
+    }
+    blocked.store(false, Ordering::Relaxed);
+
+    let (error, elapsed) = result.expect("the pipeline should have saturated");
+    assert_eq!(error.kind(), ZErrorKind::CongestionDrop);
+    let error = error
+        .downcast_ref::<BlockTimeoutError>()
+        .expect("publication should fail with a BlockTimeoutError");
+    assert_eq!(error.timeout(), BLOCK_TIMEOUT);
+    assert!(elapsed >= BLOCK_TIMEOUT, "returned too early: {elapsed:?}");
+    assert!(
+        elapsed < BLOCK_TIMEOUT + TOLERANCE,
+        "returned too late: {elapsed:?}"
+    );
+
+    // The transport is not closed on timeout
+    tokio::time::sleep(SLEEP).await;
+    assert!(ztimeout!(publisher.put("qos")).is_ok());
+    assert_eq!(ztimeout!(session1.info().peers_zid()).count(), 1);
+
+    test_sessions.close().await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn qos_reply() {
+    use zenoh::config::WhatAmI;
+    use zenoh_test::TestSessions;
+
+    const KEY_EXPR: &str = "test/qos/reply";
+
+    // Route the replies through a router
+    let mut test_sessions = TestSessions::new();
+    let mut config = test_sessions.get_listener_config("tcp/127.0.0.1:0", 1);
+    config.set_mode(Some(WhatAmI::Router)).unwrap();
+    let _router = test_sessions.open_listener_with_cfg(config).await;
+    let mut config = test_sessions.get_connector_config();
+    config.set_mode(Some(WhatAmI::Client)).unwrap();
+    let replier = test_sessions.open_connector_with_cfg(config.clone()).await;
+    let querier = test_sessions.open_connector_with_cfg(config).await;
+
+    let _queryable = ztimeout!(replier
+        .declare_queryable(format!("{KEY_EXPR}/**"))
+        .callback(|query| {
+            let reply = query.reply(query.key_expr().clone(), "reply");
+            let reply = if query.key_expr().ends_with("prioritized") {
+                reply.priority(Priority::DataHigh).express(true)
+            } else {
+                reply
+            };
+            reply.wait().unwrap();
+        }))
+    .unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    let replies = ztimeout!(querier.get(format!("{KEY_EXPR}/prioritized"))).unwrap();
+    let sample = ztimeout!(replies.recv_async())
+        .unwrap()
+        .into_result()
+        .unwrap();
+    assert_eq!(sample.priority(), Priority::DataHigh);
+    assert!(sample.express());
+    // The congestion control is the one of the query
+    assert_eq!(sample.congestion_control(), CongestionControl::Block);
+
+    let replies = ztimeout!(querier
+        .get(format!("{KEY_EXPR}/default"))
+        .priority(Priority::InteractiveLow))
+    .unwrap();
+    let sample = ztimeout!(replies.recv_async())
+        .unwrap()
+        .into_result()
+        .unwrap();
+    assert_eq!(sample.priority(), Priority::InteractiveLow);
+    assert!(!sample.express());
+
+    test_sessions.close().await;
+}
+
+#[cfg(feature = "unstable")]
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn qos_reply_priority_order() {
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    };
+
+    use zenoh::{qos::Reliability, query::ConsolidationMode};
+    use zenoh_test::TestSessions;
+
+    const KEY_EXPR: &str = "test/qos/reply_order";
+    const FLOOD_KEY_EXPR: &str = "test/qos/reply_order/flood";
+
+    // Small TCP buffers and a small data queue keep the flood queued in the transmission
+    // pipeline of the replier rather than in the sockets
+    let configure = |config: &mut zenoh_config::Config| {
+        config
+            .insert_json5(
+                "transport/link/tcp",
+                r#"{ so_rcvbuf: 8192, so_sndbuf: 8192 }"#,
+            )
+            .unwrap();
+        config
+            .insert_json5("transport/link/tx/queue/size/data", "16")
+            .unwrap();
+    };
+    let mut test_sessions = TestSessions::new();
+    let mut config = test_sessions.get_listener_config("tcp/127.0.0.1:0", 1);
+    configure(&mut config);
+    let replier = test_sessions.open_listener_with_cfg(config).await;
+    let mut config = test_sessions.get_connector_config();
+    configure(&mut config);
+    let querier = test_sessions.open_connector_with_cfg(config).await;
+
+    // The default reply waits for room behind the flood in the data queue of the replier, while
+    // the prioritized reply sent right after it is placed in the data high queue, which is
+    // drained first
+    let _queryable = ztimeout!(replier
+        .declare_queryable(format!("{KEY_EXPR}/query/*"))
+        .callback(|query| {
+            query
+                .reply(format!("{KEY_EXPR}/query/default"), "default")
+                .wait()
+                .unwrap();
+            query
+                .reply(format!("{KEY_EXPR}/query/prioritized"), "prioritized")
+                .priority(Priority::DataHigh)
+                .wait()
+                .unwrap();
+        }))
+    .unwrap();
+    // A slow subscriber saturates the transmission pipeline of the replier
+    let _subscriber = ztimeout!(querier
+        .declare_subscriber(FLOOD_KEY_EXPR)
+        .callback(|_| std::thread::sleep(Duration::from_millis(1))))
+    .unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    // Flood best-effort data at the priority of the query
+    let publisher = ztimeout!(replier
+        .declare_publisher(FLOOD_KEY_EXPR)
+        .priority(Priority::Data)
+        .congestion_control(CongestionControl::Drop)
+        .reliability(Reliability::BestEffort))
+    .unwrap();
+    let flooding = Arc::new(AtomicBool::new(true));
+    let flood = std::thread::spawn({
+        let flooding = flooding.clone();
+        move || {
+            let payload = vec![0u8; 16 * 1024];
+            while flooding.load(Ordering::Relaxed) {
+                publisher.put(payload.clone()).wait().unwrap();
+            }
+        }
+    });
+    tokio::time::sleep(SLEEP).await;
+
+    // Consolidation would hold the replies until the final response
+    let replies = ztimeout!(querier
+        .get(format!("{KEY_EXPR}/query/*"))
+        .consolidation(ConsolidationMode::None))
+    .unwrap();
+    let mut samples = Vec::new();
+    while let Ok(reply) = ztimeout!(replies.recv_async()) {
+        samples.push(reply.into_result().unwrap());
+    }
+    flooding.store(false, Ordering::Relaxed);
+    flood.join().unwrap();
+
+    let received: Vec<_> = samples
+        .iter()
+        .map(|s| (s.key_expr().as_str(), s.priority()))
+        .collect();
+    assert_eq!(
+        received,
+        [
+            ("test/qos/reply_order/query/prioritized", Priority::DataHigh),
+            ("test/qos/reply_order/query/default", Priority::Data),
+        ]
+    );
+
+    test_sessions.close().await;
+}
+
+#[cfg(all(feature = "unstable", feature = "stats"))]
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn qos_routed_messages_per_priority() {
+    use zenoh::sample::Locality;
+
+    let session = ztimeout!(zenoh::open(zenoh::Config::default())).unwrap();
+    let before = ztimeout!(session.info().routed_messages());
+    assert_eq!(before.total(), 0);
+
+    ztimeout!(session
+        .put("test/qos/routed", "high")
+        .priority(Priority::DataHigh))
+    .unwrap();
+    ztimeout!(session
+        .delete("test/qos/routed")
+        .priority(Priority::DataHigh))
+    .unwrap();
+    ztimeout!(session
+        .put("test/qos/routed", "background")
+        .priority(Priority::Background))
+    .unwrap();
+    // Session-local publications are not handed to the router.
+    ztimeout!(session
+        .put("test/qos/routed", "local")
+        .priority(Priority::RealTime)
+        .allowed_destination(Locality::SessionLocal))
+    .unwrap();
+
+    let routed = ztimeout!(session.info().routed_messages());
+    assert_eq!(routed.get(Priority::DataHigh), 2);
+    assert_eq!(routed.get(Priority::Background), 1);
+    assert_eq!(routed.get(Priority::RealTime), 0);
+    assert_eq!(routed.total(), 3);
+    assert_eq!(
+        routed.iter().map(|(p, _)| p).collect::<Vec<_>>(),
+        Priority::ALL
+    );
+}
@@ -168,7 +168,7 @@ async fn test_qos_overwrite_get_reply() {
     assert_eq!(query.congestion_control(), CongestionControl::Block);
     assert!(query.express());
 
-    query.reply("a/test", "reply").express(false).await.unwrap();
+    query.reply("a/test", "reply").await.unwrap();
     std::mem::drop(query);
     let reply = replies.recv_async().await.unwrap();
     // Reply inherits the QoS of the original query
@@ -179,6 +179,32 @@ async fn test_qos_overwrite_get_reply() {
     );
     assert!(!reply.result().unwrap().express());
 
+    let replies = session1
+        .get("a/test")
+        .priority(Priority::DataLow)
+        .congestion_control(CongestionControl::Drop)
+        .express(false)
+        .await
+        .unwrap();
+    let query = queryable.recv_async().await.unwrap();
+    assert_eq!(query.priority(), Priority::RealTime);
+
+    query
+        .reply("a/test", "reply")
+        .priority(Priority::RealTime)
+        .express(false)
+        .await
+        .unwrap();
+    std::mem::drop(query);
+    let reply = replies.recv_async().await.unwrap();
+    // The replier explicitly chose the priority, even though it is the overwritten one
+    assert_eq!(reply.result().unwrap().priority(), Priority::RealTime);
+    assert_eq!(
+        reply.result().unwrap().congestion_control(),
+        CongestionControl::Drop
+    );
+    assert!(!reply.result().unwrap().express());
+
     let replies = session1
         .get("a/b/test")
         .priority(Priority::DataLow)
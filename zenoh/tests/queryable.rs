@@ -16,11 +16,12 @@ use std::{time::Duration, vec};
 
 use futures::StreamExt;
 use zenoh::{
+    config::WhatAmI,
     handlers::DefaultHandler,
     query::{ConsolidationMode, Query, QueryTarget, QueryableBuilder, Reply, ReplyKeyExpr},
     sample::SampleKind,
     session::SessionGetBuilder,
-    Session,
+    Session, Wait,
 };
 use zenoh_core::ztimeout;
 
@@ -477,3 +478,176 @@ async fn test_accept_replies() {
     ztimeout!(s1.close()).expect("Failed to close session");
     ztimeout!(s2.close()).expect("Failed to close session");
 }
+
+#[cfg(feature = "unstable")]
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_queryable_max_concurrent_queries() {
+    const KEY_EXPR: &str = "test/queryable/max_concurrent_queries";
+    const QUERIES: usize = 100;
+    const MAX: usize = 5;
+
+    zenoh::init_log_from_env_or("error");
+
+    let s1 = ztimeout!(zenoh::open(zenoh::Config::default())).expect("Failed to open session s1");
+    let s2 = ztimeout!(zenoh::open(zenoh::Config::default())).expect("Failed to open session s2");
+
+    let queryable = ztimeout!(s1
+        .declare_queryable(KEY_EXPR)
+        .with(flume::unbounded())
+        .max_concurrent_queries(MAX))
+    .expect("Failed to declare queryable");
+    tokio::time::sleep(SLEEP).await;
+
+    let gets = futures::future::join_all((0..QUERIES).map(|_| async {
+        let replies = s2.get(KEY_EXPR).timeout(TIMEOUT).await.expect("get failed");
+        replies.recv_async().await.expect("no reply received")
+    }));
+
+    // Keep the accepted queries alive until all the other ones have been rejected.
+    let replier = async {
+        while queryable.shed_queries() < QUERIES - MAX {
+            tokio::time::sleep(SLEEP).await;
+        }
+        assert_eq!(queryable.len(), MAX);
+        while let Ok(query) = queryable.try_recv() {
+            query.reply(KEY_EXPR, "value").await.expect("reply failed");
+        }
+    };
+
+    let (replies, ()) = ztimeout!(futures::future::join(gets, replier));
+
+    let mut ok = 0;
+    let mut busy = 0;
+    for reply in replies {
+        match reply.result() {
+            Ok(sample) => {
+                assert_eq!(sample.payload().try_to_string().unwrap(), "value");
+                ok += 1;
+            }
+            Err(err) => {
+                assert_eq!(err.payload().try_to_string().unwrap(), "busy");
+                assert_eq!(err.encoding().to_string(), "application/error");
+                busy += 1;
+            }
+        }
+    }
+    assert_eq!(ok, MAX);
+    assert_eq!(busy, QUERIES - MAX);
+    assert_eq!(queryable.shed_queries(), QUERIES - MAX);
+
+    ztimeout!(s1.close()).expect("Failed to close session s1");
+    ztimeout!(s2.close()).expect("Failed to close session s2");
+}
+
+#[cfg(feature = "internal")]
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_queryable_reply_shared_sample() {
+    use std::sync::Arc;
+
+    use zenoh::{
+        key_expr::KeyExpr,
+        sample::{Sample, SampleBuilder},
+        Wait,
+    };
+
+    const KEY_EXPR: &str = "test/queryable/reply_shared_sample";
+    const QUERIES: usize = 1000;
+    const SIZE: usize = 4 * 1024 * 1024;
+
+    zenoh::init_log_from_env_or("error");
+
+    let session = ztimeout!(zenoh::open(zenoh::Config::default())).expect("Failed to open session");
+
+    let cached: Arc<Sample> =
+        Arc::new(SampleBuilder::put(KeyExpr::new(KEY_EXPR).unwrap(), vec![42u8; SIZE]).into());
+    let cached_ptr = cached.payload().slices().next().unwrap().as_ptr();
+
+    let replier = cached.clone();
+    let _queryable = ztimeout!(session.declare_queryable(KEY_EXPR).callback(move |query| {
+        // Alternate between borrowed and shared samples.
+        if query.parameters().contains_key("borrowed") {
+            query.reply_sample(&*replier).wait().expect("reply failed");
+        } else {
+            query
+                .reply_sample(replier.clone())
+                .wait()
+                .expect("reply failed");
+        }
+    }))
+    .expect("Failed to declare queryable");
+    tokio::time::sleep(SLEEP).await;
+
+    let replies = futures::future::join_all((0..QUERIES).map(|i| {
+        let session = &session;
+        async move {
+            let selector = if i % 2 == 0 {
+                format!("{KEY_EXPR}?borrowed")
+            } else {
+                KEY_EXPR.to_string()
+            };
+            let replies = session.get(selector).await.expect("get failed");
+            replies.recv_async().await.expect("no reply received")
+        }
+    }));
+    for reply in ztimeout!(replies) {
+        let sample = reply.result().expect("reply error");
+        assert_eq!(sample.payload().len(), SIZE);
+        let mut slices = sample.payload().slices();
+        assert_eq!(slices.next().unwrap().as_ptr(), cached_ptr);
+        assert!(slices.next().is_none());
+    }
+
+    ztimeout!(session.close()).expect("Failed to close session");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_reply_replier_zid() {
+    zenoh::init_log_from_env_or("error");
+    let key_expr = "test/queryable/replier_zid";
+
+    // Two queryable clients and a querying client, routed by a router
+    let mut test_sessions = zenoh_test::TestSessions::new();
+    let mut config = test_sessions.get_listener_config("tcp/127.0.0.1:0", 1);
+    config.set_mode(Some(WhatAmI::Router)).unwrap();
+    let _router = test_sessions.open_listener_with_cfg(config).await;
+    let mut clients = vec![];
+    for _ in 0..3 {
+        let mut config = test_sessions.get_connector_config();
+        config.set_mode(Some(WhatAmI::Client)).unwrap();
+        clients.push(test_sessions.open_connector_with_cfg(config).await);
+    }
+
+    let mut queryables = vec![];
+    for session in &clients[..2] {
+        let zid = session.zid().to_string();
+        queryables.push(
+            ztimeout!(session.declare_queryable(key_expr).callback(move |query| {
+                query.reply(key_expr, zid.clone()).wait().unwrap();
+            }))
+            .unwrap(),
+        );
+    }
+    tokio::time::sleep(SLEEP).await;
+
+    let replies = ztimeout!(clients[2]
+        .get(key_expr)
+        .target(QueryTarget::All)
+        .consolidation(ConsolidationMode::None))
+    .unwrap();
+    let mut repliers = vec![];
+    while let Ok(reply) = ztimeout!(replies.recv_async()) {
+        let zid = reply.replier_zid().unwrap();
+        let sample = reply.into_result().unwrap();
+        assert_eq!(sample.payload().try_to_string().unwrap(), zid.to_string());
+        repliers.push(zid);
+    }
+    repliers.sort();
+    let mut expected = vec![clients[0].zid(), clients[1].zid()];
+    expected.sort();
+    assert_eq!(repliers, expected);
+
+    for queryable in queryables {
+        ztimeout!(queryable.undeclare()).unwrap();
+    }
+    test_sessions.close().await;
+}
@@ -0,0 +1,93 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+// Client gateway
+//    R
+//   / \
+//  C   C (accept_clients)
+//      |
+//      C
+
+use std::time::Duration;
+
+use zenoh::sample::SampleKind;
+use zenoh_config::WhatAmI::{Client, Router};
+use zenoh_core::ztimeout;
+
+use crate::{loc, unbounded_sink, Node};
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_regions_client_gateway_putsub() {
+    zenoh_util::init_log_from_env_or("error");
+
+    let z9000 = ztimeout!(Node::new(Router, "c1aa9000")
+        .endpoints("tcp/127.0.0.1:0", &[])
+        .open());
+    let z9100 = ztimeout!(Node::new(Client, "c1aa9100").connect(&[loc!(z9000)]).open());
+    let z9200 = ztimeout!(Node::new(Client, "c1aa9200")
+        .endpoints("tcp/127.0.0.1:0", &[loc!(z9000)])
+        .gateway("{accept_clients:true}")
+        .open());
+    let z9210 = ztimeout!(Node::new(Client, "c1aa9210").connect(&[loc!(z9200)]).open());
+
+    // The gateway advertises that it accepts clients
+    let reply = ztimeout!(z9200.get(format!("@/{}/client", z9200.zid())))
+        .unwrap()
+        .recv_async()
+        .await
+        .unwrap();
+    let json: serde_json::Value =
+        serde_json::from_slice(&reply.result().unwrap().payload().to_bytes()).unwrap();
+    assert_eq!(json["accept_clients"], serde_json::Value::Bool(true));
+
+    let s9100 = ztimeout!(z9100.declare_subscriber("test").with(unbounded_sink())).unwrap();
+    let s9210 = ztimeout!(z9210.declare_subscriber("test").with(unbounded_sink())).unwrap();
+
+    ztimeout!(async {
+        loop {
+            z9210.put("test", "9210").await.unwrap();
+            z9100.put("test", "9100").await.unwrap();
+            tokio::time::sleep(Duration::from_millis(100)).await;
+
+            if s9100.count_unique_by_payload(SampleKind::Put) == 2
+                && s9210.count_unique_by_payload(SampleKind::Put) == 2
+            {
+                break;
+            }
+        }
+    });
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_regions_client_gateway_disabled() {
+    zenoh_util::init_log_from_env_or("error");
+
+    let z9000 = ztimeout!(Node::new(Router, "c1ab9000")
+        .endpoints("tcp/127.0.0.1:0", &[])
+        .open());
+    let z9200 = ztimeout!(Node::new(Client, "c1ab9200")
+        .endpoints("tcp/127.0.0.1:0", &[loc!(z9000)])
+        .open());
+
+    let reply = ztimeout!(z9200.get(format!("@/{}/client", z9200.zid())))
+        .unwrap()
+        .recv_async()
+        .await
+        .unwrap();
+    let json: serde_json::Value =
+        serde_json::from_slice(&reply.result().unwrap().payload().to_bytes()).unwrap();
+    assert_eq!(json["accept_clients"], serde_json::Value::Bool(false));
+}
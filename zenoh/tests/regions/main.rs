@@ -14,6 +14,8 @@
 
 #![cfg(feature = "internal")]
 
+#[cfg(feature = "unstable")]
+mod client_gateway;
 #[cfg(feature = "unstable")]
 mod gossip;
 #[cfg(feature = "unstable")]
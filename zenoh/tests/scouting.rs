@@ -0,0 +1,90 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#![cfg(feature = "unstable")]
+
+use std::time::Duration;
+
+use zenoh::{config::WhatAmI, Config};
+use zenoh_config::ZenohId;
+use zenoh_core::ztimeout;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+const SCOUT_TIMEOUT: Duration = Duration::from_secs(3);
+
+fn multicast_config(address: &str) -> Config {
+    let mut config = Config::default();
+    config
+        .insert_json5("scouting/multicast/enabled", "true")
+        .unwrap();
+    config
+        .insert_json5("scouting/multicast/address", &format!("\"{address}\""))
+        .unwrap();
+    config
+        .insert_json5("scouting/multicast/autoconnect", "[]")
+        .unwrap();
+    config
+}
+
+/// Returns whether a scout on `address` receives a hello from `zid` within [`SCOUT_TIMEOUT`].
+async fn scout_finds(address: &str, zid: ZenohId) -> bool {
+    let mut config = multicast_config(address);
+    config
+        .insert_json5("scouting/multicast/listen", "false")
+        .unwrap();
+    let receiver = zenoh::scout(WhatAmI::Peer, config).await.unwrap();
+    tokio::time::timeout(SCOUT_TIMEOUT, async {
+        while let Ok(hello) = receiver.recv_async().await {
+            if hello.zid() == zid {
+                return true;
+            }
+        }
+        false
+    })
+    .await
+    .unwrap_or(false)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_scouting_config_update_at_runtime() {
+    zenoh_util::init_log_from_env_or("error");
+    let address = "224.0.0.224:17449";
+
+    let mut config = multicast_config(address);
+    config
+        .insert_json5("listen/endpoints", "[\"tcp/127.0.0.1:0\"]")
+        .unwrap();
+    config
+        .insert_json5("scouting/multicast/listen", "true")
+        .unwrap();
+    let session = ztimeout!(zenoh::open(config)).unwrap();
+    let zid = session.zid();
+
+    assert!(ztimeout!(scout_finds(address, zid)));
+
+    session
+        .config()
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    // Let the runtime stop its multicast responder.
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    assert!(!ztimeout!(scout_finds(address, zid)));
+
+    session
+        .config()
+        .insert_json5("scouting/multicast/enabled", "true")
+        .unwrap();
+    assert!(ztimeout!(scout_finds(address, zid)));
+
+    ztimeout!(session.close()).unwrap();
+}
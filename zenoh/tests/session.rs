@@ -21,14 +21,12 @@ use std::{
     time::Duration,
 };
 
-#[cfg(all(feature = "internal", feature = "unstable"))]
-use zenoh::Wait;
 use zenoh::{
     key_expr::KeyExpr,
     qos::{CongestionControl, Reliability},
     query::Querier,
-    sample::SampleKind,
-    Session,
+    sample::{Locality, SampleKind},
+    Session, Wait,
 };
 use zenoh_core::ztimeout;
 #[cfg(feature = "internal")]
@@ -348,6 +346,114 @@ async fn zenoh_session_close_in_background_sync() {
     close_task_2.wait().unwrap();
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn zenoh_session_close_undeclare_all() {
+    zenoh::init_log_from_env_or("error");
+
+    let mut test_context = TestSessions::new();
+    let (peer01, peer02) = test_context.open_pairs().await;
+    let _subscriber = ztimeout!(peer01.declare_subscriber("test/close/undeclare_all")).unwrap();
+    let _queryable = ztimeout!(peer01.declare_queryable("test/close/undeclare_all")).unwrap();
+    let publisher = ztimeout!(peer02.declare_publisher("test/close/undeclare_all")).unwrap();
+    tokio::time::sleep(SLEEP).await;
+    assert!(ztimeout!(publisher.matching_status()).unwrap().matching());
+
+    let summary = ztimeout!(peer01.close().undeclare_all().with_summary()).unwrap();
+    assert_eq!(summary.undeclared_entities(), 2);
+    assert!(!summary.timed_out());
+    tokio::time::sleep(SLEEP).await;
+    assert!(!ztimeout!(publisher.matching_status()).unwrap().matching());
+
+    // Closing an already closed session is a no-op
+    let summary = ztimeout!(peer01.close().undeclare_all().with_summary()).unwrap();
+    assert_eq!(summary.undeclared_entities(), 0);
+    assert!(!summary.timed_out());
+    ztimeout!(peer01.close()).unwrap();
+
+    test_context.close().await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn zenoh_session_close_timeout_unresponsive_peer() {
+    use std::{sync::atomic::AtomicBool, time::Instant};
+
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+    };
+
+    async fn forward(
+        mut from: tokio::net::tcp::OwnedReadHalf,
+        mut to: tokio::net::tcp::OwnedWriteHalf,
+        frozen: Arc<AtomicBool>,
+    ) {
+        let mut buffer = vec![0; 65_536];
+        while !frozen.load(Ordering::SeqCst) {
+            match from.read(&mut buffer).await {
+                Ok(0) | Err(_) => return,
+                Ok(n) => {
+                    if to.write_all(&buffer[..n]).await.is_err() {
+                        return;
+                    }
+                }
+            }
+        }
+        // Keep the socket open without ever reading it again
+        std::future::pending::<()>().await;
+    }
+
+    zenoh::init_log_from_env_or("error");
+
+    let mut test_context = TestSessions::new();
+    let port = zenoh_test::get_free_tcp_port();
+    let config = test_context.get_listener_config(&format!("tcp/127.0.0.1:{port}"), 1);
+    let peer01 = test_context.open_listener_with_cfg(config).await;
+
+    // A TCP proxy between both peers which stops forwarding data once frozen
+    let proxy = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let proxy_addr = proxy.local_addr().unwrap();
+    let frozen = Arc::new(AtomicBool::new(false));
+    let c_frozen = frozen.clone();
+    tokio::spawn(async move {
+        while let Ok((client, _)) = proxy.accept().await {
+            let server = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+            let (client_rx, client_tx) = client.into_split();
+            let (server_rx, server_tx) = server.into_split();
+            tokio::spawn(forward(client_rx, server_tx, c_frozen.clone()));
+            tokio::spawn(forward(server_rx, client_tx, c_frozen.clone()));
+        }
+    });
+
+    let config = test_context
+        .get_connector_config_with_endpoint(vec![format!("tcp/{proxy_addr}").parse().unwrap()]);
+    let peer02 = test_context.open_connector_with_cfg(config).await;
+    let _subscriber = ztimeout!(peer01.declare_subscriber("test/close/unresponsive")).unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    // Wedge the link and fill the transmission pipelines of peer02
+    frozen.store(true, Ordering::SeqCst);
+    let payload = vec![0u8; 65_536];
+    for _ in 0..1_000 {
+        ztimeout!(peer02.put("test/close/unresponsive", payload.clone())).unwrap();
+    }
+
+    let close_timeout = Duration::from_secs(1);
+    let start = Instant::now();
+    let summary = ztimeout!(peer02
+        .close()
+        .timeout(close_timeout)
+        .undeclare_all()
+        .with_summary())
+    .unwrap();
+    let elapsed = start.elapsed();
+    assert!(summary.timed_out());
+    assert!(summary.dropped_batches() > 0);
+    assert!(elapsed < close_timeout + SLEEP);
+    assert!(peer02.is_closed());
+
+    test_context.close().await;
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
 async fn test_undeclare_subscribers_same_keyexpr() {
     let key_expr = "test/undeclare/subscribers";
@@ -447,3 +553,197 @@ async fn test_session_from_cloned_config() {
     // Create pub session
     let _pub_session = zenoh::open(pub_config).await.unwrap();
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_subscriber_multi_disjoint() {
+    let session = ztimeout!(zenoh::open(zenoh::Config::default())).unwrap();
+    let subscriber = ztimeout!(
+        session.declare_subscriber_multi(["test/multi/disjoint/a", "test/multi/disjoint/b"])
+    )
+    .unwrap();
+    assert_eq!(subscriber.key_exprs().len(), 2);
+
+    ztimeout!(session.put("test/multi/disjoint/a", "a")).unwrap();
+    ztimeout!(session.put("test/multi/disjoint/b", "b")).unwrap();
+    ztimeout!(session.put("test/multi/disjoint/c", "c")).unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    let received: Vec<String> = subscriber
+        .drain()
+        .map(|s| s.key_expr().to_string())
+        .collect();
+    assert_eq!(received, ["test/multi/disjoint/a", "test/multi/disjoint/b"]);
+
+    let handler = subscriber.handler().clone();
+    ztimeout!(subscriber.undeclare()).unwrap();
+    ztimeout!(session.put("test/multi/disjoint/a", "a")).unwrap();
+    tokio::time::sleep(SLEEP).await;
+    assert!(handler.try_recv().is_err());
+    ztimeout!(session.close()).unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_subscriber_multi_overlapping() {
+    let session = ztimeout!(zenoh::open(zenoh::Config::default())).unwrap();
+    let subscriber = ztimeout!(session.declare_subscriber_multi([
+        "test/multi/overlap/a/**",
+        "test/multi/overlap/a/b",
+        "test/multi/overlap/*/b",
+        "test/multi/overlap/a/**",
+    ]))
+    .unwrap();
+    // `a/b` and the duplicated `a/**` are included in the first key expression
+    let key_exprs: Vec<&str> = subscriber.key_exprs().iter().map(|k| k.as_str()).collect();
+    assert_eq!(
+        key_exprs,
+        ["test/multi/overlap/a/**", "test/multi/overlap/*/b"]
+    );
+
+    for key_expr in [
+        "test/multi/overlap/a/b",
+        "test/multi/overlap/a/c",
+        "test/multi/overlap/c/b",
+        "test/multi/overlap/c/c",
+    ] {
+        ztimeout!(session.put(key_expr, "value")).unwrap();
+    }
+    tokio::time::sleep(SLEEP).await;
+
+    let received: Vec<String> = subscriber
+        .drain()
+        .map(|s| s.key_expr().to_string())
+        .collect();
+    assert_eq!(
+        received,
+        [
+            "test/multi/overlap/a/b",
+            "test/multi/overlap/a/c",
+            "test/multi/overlap/c/b"
+        ]
+    );
+    ztimeout!(subscriber.undeclare()).unwrap();
+    ztimeout!(session.close()).unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_subscriber_allowed_kinds() {
+    zenoh_util::init_log_from_env_or("error");
+    let mut test_sessions = TestSessions::new();
+    let (peer01, peer02) = test_sessions.open_pairs().await;
+
+    let puts = ztimeout!(peer01
+        .declare_subscriber("test/kinds/**")
+        .allowed_kinds(&[SampleKind::Put]))
+    .unwrap();
+    let deletes = ztimeout!(peer01
+        .declare_subscriber("test/kinds/**")
+        .allowed_kinds(&[SampleKind::Delete]))
+    .unwrap();
+    let all = ztimeout!(peer01
+        .declare_subscriber("test/kinds/**")
+        .allowed_kinds(&[SampleKind::Put, SampleKind::Delete]))
+    .unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    for session in [&peer02, &peer01] {
+        ztimeout!(session.put("test/kinds/a", "a")).unwrap();
+        ztimeout!(session.delete("test/kinds/a")).unwrap();
+        ztimeout!(session.put("test/kinds/b", "b")).unwrap();
+    }
+    tokio::time::sleep(SLEEP).await;
+
+    let puts: Vec<SampleKind> = puts.drain().map(|s| s.kind()).collect();
+    assert_eq!(puts, [SampleKind::Put; 4]);
+    let deletes: Vec<SampleKind> = deletes.drain().map(|s| s.kind()).collect();
+    assert_eq!(deletes, [SampleKind::Delete; 2]);
+    assert_eq!(all.drain().count(), 6);
+
+    test_sessions.close().await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_sample_locality() {
+    zenoh_util::init_log_from_env_or("error");
+    let mut test_sessions = TestSessions::new();
+    let (peer01, peer02) = test_sessions.open_pairs().await;
+
+    let subscriber = ztimeout!(peer01.declare_subscriber("test/sample_locality/**")).unwrap();
+    let _queryable = ztimeout!(peer01
+        .declare_queryable("test/sample_locality/**")
+        .callback(|query| query
+            .reply(query.key_expr().clone(), "reply")
+            .wait()
+            .unwrap()))
+    .unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    ztimeout!(peer01.put("test/sample_locality/local", "local")).unwrap();
+    ztimeout!(peer02.put("test/sample_locality/remote", "remote")).unwrap();
+    for (key_expr, locality) in [
+        ("test/sample_locality/local", Locality::SessionLocal),
+        ("test/sample_locality/remote", Locality::Remote),
+    ] {
+        let sample = ztimeout!(subscriber.recv_async()).unwrap();
+        assert_eq!(sample.key_expr().as_str(), key_expr);
+        assert_eq!(sample.locality(), locality);
+    }
+
+    for (session, locality) in [
+        (&peer01, Locality::SessionLocal),
+        (&peer02, Locality::Remote),
+    ] {
+        let replies = ztimeout!(session.get("test/sample_locality/query")).unwrap();
+        let reply = ztimeout!(replies.recv_async()).unwrap();
+        assert_eq!(reply.result().unwrap().locality(), locality);
+    }
+
+    test_sessions.close().await;
+}
+
+#[cfg(feature = "unstable")]
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_subscriber_deduplicate() {
+    use zenoh::sample::SourceInfo;
+
+    zenoh_util::init_log_from_env_or("error");
+    let mut test_sessions = TestSessions::new();
+    let (peer01, peer02) = test_sessions.open_pairs().await;
+
+    let subscriber = ztimeout!(peer01
+        .declare_subscriber("test/dedup/**")
+        .deduplicate(Duration::from_secs(10)))
+    .unwrap();
+    let untimestamped = ztimeout!(peer01
+        .declare_subscriber("test/dedup/**")
+        .deduplicate(Duration::from_secs(10))
+        .dedup_untimestamped(true))
+    .unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    // Republishing the same sample simulates its redundant delivery.
+    let timestamp = peer02.new_timestamp();
+    let source_info = SourceInfo::new(peer02.id(), 1);
+    for _ in 0..2 {
+        ztimeout!(peer02
+            .put("test/dedup/a", "a")
+            .timestamp(timestamp)
+            .source_info(source_info.clone()))
+        .unwrap();
+    }
+    ztimeout!(peer02
+        .put("test/dedup/a", "a")
+        .timestamp(peer02.new_timestamp())
+        .source_info(SourceInfo::new(peer02.id(), 2)))
+    .unwrap();
+    for _ in 0..2 {
+        ztimeout!(peer02.put("test/dedup/b", "b")).unwrap();
+    }
+    tokio::time::sleep(SLEEP).await;
+
+    assert_eq!(subscriber.drain().count(), 4);
+    assert_eq!(subscriber.suppressed_duplicates(), 1);
+    assert_eq!(untimestamped.drain().count(), 3);
+    assert_eq!(untimestamped.suppressed_duplicates(), 2);
+
+    test_sessions.close().await;
+}
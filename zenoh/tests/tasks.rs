@@ -0,0 +1,162 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#![cfg(feature = "unstable")]
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, SystemTime},
+};
+
+use zenoh::{config::WhatAmI, Config, Session};
+use zenoh_core::ztimeout;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+const SLEEP: Duration = Duration::from_secs(1);
+
+async fn open(endpoints: &str, listen: bool) -> Session {
+    let mut config = Config::default();
+    config.set_mode(Some(WhatAmI::Peer)).unwrap();
+    config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    let key = if listen {
+        "listen/endpoints"
+    } else {
+        "connect/endpoints"
+    };
+    config
+        .insert_json5(key, &format!("[\"{endpoints}\"]"))
+        .unwrap();
+    ztimeout!(zenoh::open(config)).unwrap()
+}
+
+fn count(session: &Session, prefix: &str) -> usize {
+    session
+        .tasks()
+        .iter()
+        .filter(|t| t.name().starts_with(prefix))
+        .count()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn tasks_link() {
+    zenoh_util::init_log_from_env_or("error");
+    let session1 = open("tcp/127.0.0.1:27471", true).await;
+    assert_eq!(count(&session1, "zenoh:tx:"), 0);
+    assert_eq!(count(&session1, "zenoh:rx:"), 0);
+    let before = session1.tasks().len();
+
+    let session2 = open("tcp/127.0.0.1:27471", false).await;
+    tokio::time::sleep(SLEEP).await;
+    assert_eq!(count(&session1, "zenoh:tx:tcp/127.0.0.1:"), 1);
+    assert_eq!(count(&session1, "zenoh:rx:tcp/127.0.0.1:"), 1);
+    assert_eq!(count(&session2, "zenoh:tx:tcp/127.0.0.1:27471"), 1);
+    assert_eq!(count(&session2, "zenoh:rx:tcp/127.0.0.1:27471"), 1);
+    assert!(session1.tasks().len() > before);
+    assert!(session1
+        .tasks()
+        .iter()
+        .all(|t| t.spawned_at() <= SystemTime::now()));
+
+    ztimeout!(session2.close()).unwrap();
+    tokio::time::sleep(SLEEP).await;
+    assert_eq!(count(&session1, "zenoh:tx:"), 0);
+    assert_eq!(count(&session1, "zenoh:rx:"), 0);
+
+    ztimeout!(session1.close()).unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn tasks_query() {
+    zenoh_util::init_log_from_env_or("error");
+    let session = open("tcp/127.0.0.1:27472", true).await;
+    let queryable = ztimeout!(session.declare_queryable("test/tasks/query")).unwrap();
+    assert_eq!(count(&session, "zenoh:get:"), 0);
+
+    // The timeout of a query lives as long as the query
+    let replies = ztimeout!(session
+        .get("test/tasks/query")
+        .timeout(Duration::from_secs(2)))
+    .unwrap();
+    let query = ztimeout!(queryable.recv_async()).unwrap();
+    assert_eq!(count(&session, "zenoh:get:"), 1);
+    let names: Vec<String> = session
+        .tasks()
+        .into_iter()
+        .filter(|t| t.name().starts_with("zenoh:get:"))
+        .map(|t| t.name().to_string())
+        .collect();
+    assert_eq!(names[0].len(), "zenoh:get:".len() + 16);
+
+    drop(query);
+    assert!(ztimeout!(replies.recv_async()).is_err());
+    tokio::time::sleep(Duration::from_secs(3)).await;
+    assert_eq!(count(&session, "zenoh:get:"), 0);
+
+    ztimeout!(session.close()).unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn tasks_subscriber() {
+    zenoh_util::init_log_from_env_or("error");
+    let session1 = open("tcp/127.0.0.1:27473", true).await;
+    let session2 = open("tcp/127.0.0.1:27473", false).await;
+    tokio::time::sleep(SLEEP).await;
+    let before = session2.tasks().len();
+
+    // Subscribers and queryables are served by the link RX tasks: they spawn no task of their own
+    let subscriber = ztimeout!(session2.declare_subscriber("test/tasks/sub")).unwrap();
+    let queryable = ztimeout!(session2.declare_queryable("test/tasks/sub")).unwrap();
+    assert_eq!(session2.tasks().len(), before);
+    ztimeout!(subscriber.undeclare()).unwrap();
+    ztimeout!(queryable.undeclare()).unwrap();
+    assert_eq!(session2.tasks().len(), before);
+
+    // A liveliness subscriber with history delivers the tokens already known by the session,
+    // here thanks to a first liveliness subscriber, from a task of its own which completes once
+    // they are all delivered
+    let token = ztimeout!(session1.liveliness().declare_token("test/tasks/sub/token")).unwrap();
+    let tracker = ztimeout!(session2
+        .liveliness()
+        .declare_subscriber("test/tasks/sub/**"))
+    .unwrap();
+    tokio::time::sleep(SLEEP).await;
+    let delivered_from_task = Arc::new(AtomicBool::new(false));
+    let subscriber = ztimeout!(session2
+        .liveliness()
+        .declare_subscriber("test/tasks/sub/**")
+        .history(true)
+        .callback({
+            let session = session2.clone();
+            let delivered_from_task = delivered_from_task.clone();
+            move |_| {
+                if count(&session, "zenoh:liveliness_sub:") == 1 {
+                    delivered_from_task.store(true, Ordering::SeqCst);
+                }
+            }
+        }))
+    .unwrap();
+    tokio::time::sleep(SLEEP).await;
+    assert!(delivered_from_task.load(Ordering::SeqCst));
+    assert_eq!(count(&session2, "zenoh:liveliness_sub:"), 0);
+    ztimeout!(subscriber.undeclare()).unwrap();
+    ztimeout!(tracker.undeclare()).unwrap();
+    assert_eq!(session2.tasks().len(), before);
+
+    ztimeout!(token.undeclare()).unwrap();
+    ztimeout!(session2.close()).unwrap();
+    ztimeout!(session1.close()).unwrap();
+}
@@ -0,0 +1,224 @@
+//
+// Copyright (c) 2026 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#![cfg(feature = "transport_udp")]
+use std::{
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use tokio::net::UdpSocket;
+use zenoh::{
+    config::{Locator, WhatAmI},
+    Config, Session,
+};
+use zenoh_core::ztimeout;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+const SLEEP: Duration = Duration::from_secs(1);
+const PUT_PERIOD: Duration = Duration::from_millis(20);
+const MSG_COUNT: usize = 100;
+// Fragmented in 4 datagrams
+const MSG_SIZE: usize = 200_000;
+const DROP_EVERY: usize = 10;
+
+/// Forwards the datagrams between a single client and `server`, dropping every
+/// [`DROP_EVERY`]th datagram in each direction, and returns the address of the proxy.
+async fn lossy_proxy(server: SocketAddr) -> SocketAddr {
+    let front = Arc::new(UdpSocket::bind("127.0.0.1:0").await.unwrap());
+    let back = Arc::new(UdpSocket::bind("127.0.0.1:0").await.unwrap());
+    back.connect(server).await.unwrap();
+    let addr = front.local_addr().unwrap();
+
+    let (client_tx, client_rx) = tokio::sync::oneshot::channel();
+    tokio::spawn({
+        let (front, back) = (front.clone(), back.clone());
+        async move {
+            let mut buf = vec![0u8; u16::MAX as usize];
+            let mut client_tx = Some(client_tx);
+            for count in 1.. {
+                let (n, client) = front.recv_from(&mut buf).await.unwrap();
+                if let Some(tx) = client_tx.take() {
+                    tx.send(client).unwrap();
+                }
+                if count % DROP_EVERY != 0 {
+                    back.send(&buf[..n]).await.unwrap();
+                }
+            }
+        }
+    });
+    tokio::spawn(async move {
+        let mut buf = vec![0u8; u16::MAX as usize];
+        // The server only answers the client
+        let client = client_rx.await.unwrap();
+        for count in 1.. {
+            let n = back.recv(&mut buf).await.unwrap();
+            if count % DROP_EVERY != 0 {
+                front.send_to(&buf[..n], client).await.unwrap();
+            }
+        }
+    });
+    addr
+}
+
+async fn open(mode: WhatAmI, endpoints: &str, listen: bool) -> Session {
+    let mut config = Config::default();
+    config.set_mode(Some(mode)).unwrap();
+    config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    // Shared memory would carry the payloads instead of the fragmented messages
+    config
+        .insert_json5("transport/shared_memory/enabled", "false")
+        .unwrap();
+    let key = if listen {
+        "listen/endpoints"
+    } else {
+        "connect/endpoints"
+    };
+    config
+        .insert_json5(key, &format!("[\"{endpoints}\"]"))
+        .unwrap();
+    ztimeout!(zenoh::open(config)).unwrap()
+}
+
+async fn udp_locator(session: &Session) -> Locator {
+    ztimeout!(session.info().locators())
+        .into_iter()
+        .find(|l| l.protocol().as_str() == "udp")
+        .unwrap()
+}
+
+/// Returns the number of messages received through a lossy proxy.
+async fn delivered(frag_ack: bool) -> usize {
+    let key_expr = format!("test/udp_frag_ack/{frag_ack}");
+    let metadata = if frag_ack { "?frag_ack=true" } else { "" };
+    let server = open(WhatAmI::Peer, &format!("udp/127.0.0.1:0{metadata}"), true).await;
+    let locator = udp_locator(&server).await;
+    // The listener advertises the acknowledgments in its locator
+    assert_eq!(
+        locator
+            .metadata()
+            .get("frag_ack")
+            .is_some_and(|v| v == "true"),
+        frag_ack
+    );
+    let server_addr: SocketAddr = locator.address().as_str().parse().unwrap();
+    let proxy_addr = lossy_proxy(server_addr).await;
+
+    let received = Arc::new(AtomicUsize::new(0));
+    let _subscriber = ztimeout!(server.declare_subscriber(&key_expr).callback({
+        let received = received.clone();
+        move |sample| {
+            assert_eq!(sample.payload().len(), MSG_SIZE);
+            received.fetch_add(1, Ordering::SeqCst);
+        }
+    }))
+    .unwrap();
+
+    let client = open(
+        WhatAmI::Client,
+        &format!("udp/{proxy_addr}{metadata}"),
+        false,
+    )
+    .await;
+    tokio::time::sleep(SLEEP).await;
+
+    let payload = vec![0u8; MSG_SIZE];
+    for _ in 0..MSG_COUNT {
+        ztimeout!(client.put(&key_expr, payload.clone())).unwrap();
+        tokio::time::sleep(PUT_PERIOD).await;
+    }
+    tokio::time::sleep(SLEEP).await;
+
+    ztimeout!(client.close()).unwrap();
+    ztimeout!(server.close()).unwrap();
+    received.load(Ordering::SeqCst)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn udp_frag_ack_loss() {
+    zenoh_util::init_log_from_env_or("error");
+
+    let plain = delivered(false).await;
+    let frag_ack = delivered(true).await;
+
+    // About 4 messages in 10 lose one of their fragments without acknowledgments
+    assert!(
+        plain < MSG_COUNT * 8 / 10,
+        "{plain}/{MSG_COUNT} delivered without frag_ack"
+    );
+    // The lost fragments are retransmitted with acknowledgments
+    assert_eq!(
+        frag_ack, MSG_COUNT,
+        "{frag_ack}/{MSG_COUNT} delivered with frag_ack"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn udp_frag_ack_plain_connector() {
+    zenoh_util::init_log_from_env_or("error");
+
+    // A `frag_ack` listener accepts the plain connectors
+    let server = open(WhatAmI::Peer, "udp/127.0.0.1:0?frag_ack=true", true).await;
+    let locator = udp_locator(&server).await;
+    let subscriber = ztimeout!(server.declare_subscriber("test/udp_frag_ack/plain")).unwrap();
+
+    let client = open(
+        WhatAmI::Client,
+        &format!("udp/{}", locator.address()),
+        false,
+    )
+    .await;
+    tokio::time::sleep(SLEEP).await;
+
+    ztimeout!(client.put("test/udp_frag_ack/plain", vec![0u8; MSG_SIZE])).unwrap();
+    let sample = ztimeout!(subscriber.recv_async()).unwrap();
+    assert_eq!(sample.payload().len(), MSG_SIZE);
+
+    ztimeout!(client.close()).unwrap();
+    ztimeout!(server.close()).unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn udp_frag_ack_advertised_locator() {
+    zenoh_util::init_log_from_env_or("error");
+
+    // Connecting to the advertised locators only enables the acknowledgments if the listener
+    // has them, and tunes them with the connector's own configuration
+    for metadata in ["", "?frag_ack=true"] {
+        let server = open(WhatAmI::Peer, &format!("udp/127.0.0.1:0{metadata}"), true).await;
+        let locator = udp_locator(&server).await;
+        let subscriber =
+            ztimeout!(server.declare_subscriber("test/udp_frag_ack/advertised")).unwrap();
+
+        let client = open(
+            WhatAmI::Client,
+            &format!("{locator}#frag_ack_timeout=20;frag_ack_retries=5"),
+            false,
+        )
+        .await;
+        tokio::time::sleep(SLEEP).await;
+
+        ztimeout!(client.put("test/udp_frag_ack/advertised", vec![0u8; MSG_SIZE])).unwrap();
+        let sample = ztimeout!(subscriber.recv_async()).unwrap();
+        assert_eq!(sample.payload().len(), MSG_SIZE);
+
+        ztimeout!(client.close()).unwrap();
+        ztimeout!(server.close()).unwrap();
+    }
+}